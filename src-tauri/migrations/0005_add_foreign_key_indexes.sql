@@ -0,0 +1,14 @@
+-- Indexes supporting the joins and filters used by the collecting and
+-- catalog queries (foreign keys are not indexed automatically by SQLite).
+
+CREATE INDEX IF NOT EXISTS idx_collection_items_collection_id ON collection_items (collection_id);
+CREATE INDEX IF NOT EXISTS idx_collection_items_railway_model_id ON collection_items (railway_model_id);
+
+CREATE INDEX IF NOT EXISTS idx_owned_rolling_stocks_collection_item_id ON owned_rolling_stocks (collection_item_id);
+CREATE INDEX IF NOT EXISTS idx_owned_rolling_stocks_rolling_stock_id ON owned_rolling_stocks (rolling_stock_id);
+
+CREATE INDEX IF NOT EXISTS idx_railway_models_manufacturer_id ON railway_models (manufacturer_id);
+CREATE INDEX IF NOT EXISTS idx_railway_models_category ON railway_models (category);
+CREATE INDEX IF NOT EXISTS idx_railway_models_scale ON railway_models (scale);
+
+CREATE INDEX IF NOT EXISTS idx_rolling_stocks_railway_company_id ON rolling_stocks (railway_company_id);
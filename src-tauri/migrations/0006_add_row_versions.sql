@@ -0,0 +1,5 @@
+-- Row versions used for optimistic concurrency control: every guarded
+-- update matches on the version it read and increments it.
+ALTER TABLE collection_items ADD COLUMN version INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE purchase_infos ADD COLUMN version INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE railway_models ADD COLUMN version INTEGER NOT NULL DEFAULT 0;
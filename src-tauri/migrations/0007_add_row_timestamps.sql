@@ -0,0 +1,8 @@
+-- Creation and last-update timestamps (UTC, RFC3339 text) for the tables
+-- that did not have them. Rows written before this migration keep NULL.
+ALTER TABLE collection_items ADD COLUMN created_at TEXT;
+ALTER TABLE collection_items ADD COLUMN updated_at TEXT;
+ALTER TABLE purchase_infos ADD COLUMN created_at TEXT;
+ALTER TABLE purchase_infos ADD COLUMN updated_at TEXT;
+ALTER TABLE rolling_stocks ADD COLUMN created_at TEXT;
+ALTER TABLE rolling_stocks ADD COLUMN updated_at TEXT;
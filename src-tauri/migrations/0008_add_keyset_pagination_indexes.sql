@@ -0,0 +1,8 @@
+-- Indexes supporting the keyset-paginated listings, which read rows in
+-- `(sort key, id)` order starting after the last row of the previous page.
+
+CREATE INDEX IF NOT EXISTS idx_railway_models_product_code_id ON railway_models (product_code, id);
+
+-- Collection items are listed in the order they were added; items added
+-- before timestamps were recorded sort first.
+CREATE INDEX IF NOT EXISTS idx_collection_items_collection_created_at_id ON collection_items (collection_id, COALESCE(created_at, ''), id);
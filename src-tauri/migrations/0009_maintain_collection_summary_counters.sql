@@ -0,0 +1,120 @@
+-- Keep the summary counters of every collection up to date as items are
+-- added, removed or moved, and as railway models change category, instead
+-- of recomputing them from all the items after each write.
+--
+-- An item is counted according to the category of its railway model,
+-- compared case-insensitively; starter sets count as train sets. The
+-- `recompute_collection` command still recomputes the counters from the
+-- items, as a repair tool.
+
+-- The counters an item of each railway model adds to its collection.
+CREATE VIEW IF NOT EXISTS railway_model_summary_deltas AS
+SELECT
+    id AS railway_model_id,
+    category = 'LOCOMOTIVES' COLLATE NOCASE AS locomotives,
+    category = 'PASSENGER_CARS' COLLATE NOCASE AS passenger_cars,
+    category = 'FREIGHT_CARS' COLLATE NOCASE AS freight_cars,
+    category COLLATE NOCASE IN ('TRAIN_SETS', 'STARTER_SETS') AS train_sets,
+    category = 'RAILCARS' COLLATE NOCASE AS railcars,
+    category = 'ELECTRIC_MULTIPLE_UNITS' COLLATE NOCASE AS electric_multiple_units
+FROM railway_models;
+
+CREATE TRIGGER IF NOT EXISTS trg_collection_items_summary_insert
+AFTER INSERT ON collection_items
+BEGIN
+    UPDATE collections SET
+        locomotives_count = locomotives_count + d.locomotives,
+        passenger_cars_count = passenger_cars_count + d.passenger_cars,
+        freight_cars_count = freight_cars_count + d.freight_cars,
+        train_sets_count = train_sets_count + d.train_sets,
+        railcars_count = railcars_count + d.railcars,
+        electric_multiple_units_count = electric_multiple_units_count + d.electric_multiple_units
+    FROM railway_model_summary_deltas AS d
+    WHERE collections.id = NEW.collection_id AND d.railway_model_id = NEW.railway_model_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_collection_items_summary_delete
+AFTER DELETE ON collection_items
+BEGIN
+    UPDATE collections SET
+        locomotives_count = locomotives_count - d.locomotives,
+        passenger_cars_count = passenger_cars_count - d.passenger_cars,
+        freight_cars_count = freight_cars_count - d.freight_cars,
+        train_sets_count = train_sets_count - d.train_sets,
+        railcars_count = railcars_count - d.railcars,
+        electric_multiple_units_count = electric_multiple_units_count - d.electric_multiple_units
+    FROM railway_model_summary_deltas AS d
+    WHERE collections.id = OLD.collection_id AND d.railway_model_id = OLD.railway_model_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS trg_collection_items_summary_update
+AFTER UPDATE OF collection_id, railway_model_id ON collection_items
+WHEN OLD.collection_id IS NOT NEW.collection_id OR OLD.railway_model_id IS NOT NEW.railway_model_id
+BEGIN
+    UPDATE collections SET
+        locomotives_count = locomotives_count - d.locomotives,
+        passenger_cars_count = passenger_cars_count - d.passenger_cars,
+        freight_cars_count = freight_cars_count - d.freight_cars,
+        train_sets_count = train_sets_count - d.train_sets,
+        railcars_count = railcars_count - d.railcars,
+        electric_multiple_units_count = electric_multiple_units_count - d.electric_multiple_units
+    FROM railway_model_summary_deltas AS d
+    WHERE collections.id = OLD.collection_id AND d.railway_model_id = OLD.railway_model_id;
+
+    UPDATE collections SET
+        locomotives_count = locomotives_count + d.locomotives,
+        passenger_cars_count = passenger_cars_count + d.passenger_cars,
+        freight_cars_count = freight_cars_count + d.freight_cars,
+        train_sets_count = train_sets_count + d.train_sets,
+        railcars_count = railcars_count + d.railcars,
+        electric_multiple_units_count = electric_multiple_units_count + d.electric_multiple_units
+    FROM railway_model_summary_deltas AS d
+    WHERE collections.id = NEW.collection_id AND d.railway_model_id = NEW.railway_model_id;
+END;
+
+-- The view already reflects the new category here, so the old one is
+-- classified inline.
+CREATE TRIGGER IF NOT EXISTS trg_railway_models_summary_category
+AFTER UPDATE OF category ON railway_models
+WHEN OLD.category IS NOT NEW.category
+BEGIN
+    UPDATE collections SET
+        locomotives_count = locomotives_count + items.n * (d.locomotives - (OLD.category = 'LOCOMOTIVES' COLLATE NOCASE)),
+        passenger_cars_count = passenger_cars_count + items.n * (d.passenger_cars - (OLD.category = 'PASSENGER_CARS' COLLATE NOCASE)),
+        freight_cars_count = freight_cars_count + items.n * (d.freight_cars - (OLD.category = 'FREIGHT_CARS' COLLATE NOCASE)),
+        train_sets_count = train_sets_count + items.n * (d.train_sets - (OLD.category COLLATE NOCASE IN ('TRAIN_SETS', 'STARTER_SETS'))),
+        railcars_count = railcars_count + items.n * (d.railcars - (OLD.category = 'RAILCARS' COLLATE NOCASE)),
+        electric_multiple_units_count = electric_multiple_units_count + items.n * (d.electric_multiple_units - (OLD.category = 'ELECTRIC_MULTIPLE_UNITS' COLLATE NOCASE))
+    FROM (
+        SELECT collection_id, COUNT(*) AS n
+        FROM collection_items
+        WHERE railway_model_id = NEW.id
+        GROUP BY collection_id
+    ) AS items
+    JOIN railway_model_summary_deltas AS d ON d.railway_model_id = NEW.id
+    WHERE collections.id = items.collection_id;
+END;
+
+-- Start from counters that match the items.
+UPDATE collections SET
+    locomotives_count = COALESCE(totals.locomotives, 0),
+    passenger_cars_count = COALESCE(totals.passenger_cars, 0),
+    freight_cars_count = COALESCE(totals.freight_cars, 0),
+    train_sets_count = COALESCE(totals.train_sets, 0),
+    railcars_count = COALESCE(totals.railcars, 0),
+    electric_multiple_units_count = COALESCE(totals.electric_multiple_units, 0)
+FROM (
+    SELECT
+        c.id AS collection_id,
+        SUM(d.locomotives) AS locomotives,
+        SUM(d.passenger_cars) AS passenger_cars,
+        SUM(d.freight_cars) AS freight_cars,
+        SUM(d.train_sets) AS train_sets,
+        SUM(d.railcars) AS railcars,
+        SUM(d.electric_multiple_units) AS electric_multiple_units
+    FROM collections AS c
+    LEFT JOIN collection_items AS ci ON ci.collection_id = c.id
+    LEFT JOIN railway_model_summary_deltas AS d ON d.railway_model_id = ci.railway_model_id
+    GROUP BY c.id
+) AS totals
+WHERE collections.id = totals.collection_id;
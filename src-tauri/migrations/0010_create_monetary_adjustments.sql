@@ -0,0 +1,16 @@
+-- Signed corrections of what was paid for a collection item (for example a
+-- partial refund), kept next to the purchase info instead of editing its
+-- price. Amounts are in the smallest currency unit; refunds are negative.
+CREATE TABLE IF NOT EXISTS monetary_adjustments (
+    id TEXT PRIMARY KEY NOT NULL,
+    purchase_id TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    currency TEXT NOT NULL,
+    adjustment_date TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    created_at TEXT,
+    updated_at TEXT,
+    FOREIGN KEY(purchase_id) REFERENCES purchase_infos(purchase_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_monetary_adjustments_purchase_id ON monetary_adjustments(purchase_id);
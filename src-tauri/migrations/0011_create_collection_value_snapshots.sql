@@ -0,0 +1,13 @@
+-- The value of a collection per currency as it was on a given day, for
+-- charting how it changes over time. A day has at most one snapshot: taking
+-- it again replaces the rows of that day. Amounts are in the smallest
+-- currency unit.
+CREATE TABLE IF NOT EXISTS collection_value_snapshots (
+    collection_id TEXT NOT NULL,
+    snapshot_date TEXT NOT NULL,
+    currency TEXT NOT NULL,
+    amount INTEGER NOT NULL,
+    created_at TEXT,
+    PRIMARY KEY (collection_id, snapshot_date, currency),
+    FOREIGN KEY(collection_id) REFERENCES collections(id) ON DELETE CASCADE
+);
@@ -0,0 +1,21 @@
+-- The DCC decoders owned by the collector, and which owned rolling stock each
+-- one is installed in. A decoder is installed in at most one rolling stock at
+-- a time; deleting a decoder leaves the rolling stock without one. Prices are
+-- in the smallest currency unit.
+CREATE TABLE IF NOT EXISTS decoders (
+    id TEXT PRIMARY KEY NOT NULL,
+    manufacturer TEXT NOT NULL,
+    model TEXT NOT NULL,
+    dcc_interface TEXT,
+    address INTEGER NOT NULL,
+    sound_project TEXT,
+    firmware TEXT,
+    purchase_price_amount INTEGER,
+    purchase_price_currency TEXT,
+    created_at TEXT,
+    updated_at TEXT
+);
+
+ALTER TABLE owned_rolling_stocks ADD COLUMN decoder_id TEXT REFERENCES decoders(id) ON DELETE SET NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_owned_rolling_stocks_decoder_id ON owned_rolling_stocks (decoder_id) WHERE decoder_id IS NOT NULL;
@@ -0,0 +1,17 @@
+-- Changes made to an owned rolling stock after it was bought, such as
+-- weathering, added lights or replaced couplers. Costs are in the smallest
+-- currency unit.
+CREATE TABLE IF NOT EXISTS modifications (
+    id TEXT PRIMARY KEY NOT NULL,
+    owned_rolling_stock_id TEXT NOT NULL,
+    modification_type TEXT NOT NULL,
+    description TEXT NOT NULL,
+    modification_date TEXT NOT NULL,
+    cost_amount INTEGER,
+    cost_currency TEXT,
+    created_at TEXT,
+    updated_at TEXT,
+    FOREIGN KEY(owned_rolling_stock_id) REFERENCES owned_rolling_stocks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_modifications_owned_rolling_stock_id ON modifications(owned_rolling_stock_id);
@@ -0,0 +1,21 @@
+-- Trains assembled from owned rolling stocks, for the consist planner. The
+-- entries of a consist are its vehicles in order, from the head of the train
+-- to its tail; a rolling stock appears at most once in a consist.
+CREATE TABLE IF NOT EXISTS consists (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    created_at TEXT,
+    updated_at TEXT
+);
+
+CREATE TABLE IF NOT EXISTS consist_entries (
+    consist_id TEXT NOT NULL,
+    position INTEGER NOT NULL,
+    owned_rolling_stock_id TEXT NOT NULL,
+    PRIMARY KEY (consist_id, position),
+    UNIQUE (consist_id, owned_rolling_stock_id),
+    FOREIGN KEY(consist_id) REFERENCES consists(id) ON DELETE CASCADE,
+    FOREIGN KEY(owned_rolling_stock_id) REFERENCES owned_rolling_stocks(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_consist_entries_owned_rolling_stock_id ON consist_entries(owned_rolling_stock_id);
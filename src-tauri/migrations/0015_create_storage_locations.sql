@@ -0,0 +1,22 @@
+-- Where the collector keeps the owned items: numbered boxes, shelves, display
+-- cases. Locations nest (a box on a shelf), and a location cannot be deleted
+-- while items or other locations are in it. An owned rolling stock kept apart
+-- from the rest of its item, such as a car of a split set, has a location of
+-- its own.
+CREATE TABLE IF NOT EXISTS storage_locations (
+    id TEXT PRIMARY KEY NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT,
+    parent_id TEXT,
+    created_at TEXT,
+    updated_at TEXT,
+    FOREIGN KEY(parent_id) REFERENCES storage_locations(id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_storage_locations_parent_id ON storage_locations(parent_id);
+
+ALTER TABLE collection_items ADD COLUMN location_id TEXT REFERENCES storage_locations(id);
+ALTER TABLE owned_rolling_stocks ADD COLUMN location_id TEXT REFERENCES storage_locations(id);
+
+CREATE INDEX IF NOT EXISTS idx_collection_items_location_id ON collection_items(location_id);
+CREATE INDEX IF NOT EXISTS idx_owned_rolling_stocks_location_id ON owned_rolling_stocks(location_id);
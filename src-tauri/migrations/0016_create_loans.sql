@@ -0,0 +1,19 @@
+-- Collection items lent out, for example to the club layout. A loan is
+-- outstanding until `returned_on` is set; an item has at most one outstanding
+-- loan at a time.
+CREATE TABLE IF NOT EXISTS loans (
+    id TEXT PRIMARY KEY NOT NULL,
+    collection_item_id TEXT NOT NULL,
+    borrower_name TEXT NOT NULL,
+    borrower_email TEXT,
+    borrower_phone TEXT,
+    loaned_on TEXT NOT NULL,
+    due_back TEXT,
+    returned_on TEXT,
+    created_at TEXT,
+    updated_at TEXT,
+    FOREIGN KEY(collection_item_id) REFERENCES collection_items(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_loans_collection_item_id ON loans(collection_item_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_loans_outstanding ON loans(collection_item_id) WHERE returned_on IS NULL;
@@ -0,0 +1,5 @@
+-- What replacing a collection item would cost today, as entered by the
+-- collector for insurance purposes. The amount is in the smallest currency
+-- unit; both columns are NULL when no replacement value was entered.
+ALTER TABLE collection_items ADD COLUMN replacement_value_amount INTEGER;
+ALTER TABLE collection_items ADD COLUMN replacement_value_currency TEXT;
@@ -0,0 +1,16 @@
+-- Data-changing operations, written by the repositories in the same
+-- transaction as the change they describe. `changes` is a JSON summary of
+-- what was written; `origin` tells a change made from the UI (`command`)
+-- from one made by loading the demo data (`import`).
+CREATE TABLE IF NOT EXISTS audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    occurred_at TEXT NOT NULL,
+    operation TEXT NOT NULL,
+    entity_type TEXT NOT NULL,
+    entity_id TEXT NOT NULL,
+    changes TEXT NOT NULL,
+    origin TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_entity ON audit_log(entity_type, entity_id);
+CREATE INDEX IF NOT EXISTS idx_audit_log_entity_id ON audit_log(entity_id);
@@ -0,0 +1,21 @@
+-- The liveries of each railway company, with the period they were applied in.
+-- A rolling stock references its livery when it is known; the free text
+-- `livery` column stays for the rolling stocks that do not, and deleting a
+-- livery falls back to it. Livery names are unique within a railway company.
+CREATE TABLE IF NOT EXISTS liveries (
+    id TEXT PRIMARY KEY NOT NULL,
+    railway_company_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT,
+    in_use_since TEXT,
+    in_use_until TEXT,
+    created_at TEXT,
+    updated_at TEXT,
+    FOREIGN KEY(railway_company_id) REFERENCES railway_companies(id) ON DELETE CASCADE
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_liveries_railway_company_id_name ON liveries(railway_company_id, name COLLATE NOCASE);
+
+ALTER TABLE rolling_stocks ADD COLUMN livery_id TEXT REFERENCES liveries(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_rolling_stocks_livery_id ON rolling_stocks(livery_id);
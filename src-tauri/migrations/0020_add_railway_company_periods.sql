@@ -0,0 +1,5 @@
+-- The period of activity of a railway company, checked against the epochs of
+-- the rolling stocks assigned to it. Either end may be unknown; the dates are
+-- ISO 8601 (YYYY-MM-DD).
+ALTER TABLE railway_companies ADD COLUMN operating_since TEXT;
+ALTER TABLE railway_companies ADD COLUMN operating_until TEXT;
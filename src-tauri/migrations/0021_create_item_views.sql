@@ -0,0 +1,11 @@
+-- When the collector opened the detail of a collection item, for the
+-- "recently viewed" list. Only the latest views of each item are kept: older
+-- ones are pruned when a view is recorded.
+CREATE TABLE IF NOT EXISTS item_views (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    collection_item_id TEXT NOT NULL,
+    viewed_at TEXT NOT NULL,
+    FOREIGN KEY(collection_item_id) REFERENCES collection_items(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_item_views_collection_item_id ON item_views(collection_item_id, viewed_at);
@@ -0,0 +1,5 @@
+-- The manufacturer's suggested retail price of a railway model, usually
+-- taken from its announcement. The amount is in the smallest currency unit;
+-- both columns are NULL when no price was announced.
+ALTER TABLE railway_models ADD COLUMN msrp_amount INTEGER;
+ALTER TABLE railway_models ADD COLUMN msrp_currency TEXT;
@@ -0,0 +1,49 @@
+-- A short number for every collection item, unique within its collection,
+-- so that items can be referred to out loud ("number 42") instead of by id.
+--
+-- Numbers come from a per-collection counter that only grows: deleting an
+-- item leaves a gap, and its number is never handed out again. The counter
+-- is advanced by the insert itself, so concurrent inserts get sequential
+-- numbers.
+ALTER TABLE collections ADD COLUMN next_item_number INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE collection_items ADD COLUMN item_number INTEGER;
+
+-- Number the existing items in the order they were added.
+UPDATE collection_items SET item_number = numbered.item_number
+FROM (
+    SELECT id, ROW_NUMBER() OVER (PARTITION BY collection_id ORDER BY rowid) AS item_number
+    FROM collection_items
+) AS numbered
+WHERE numbered.id = collection_items.id;
+
+UPDATE collections SET next_item_number = 1 + COALESCE((
+    SELECT MAX(ci.item_number) FROM collection_items AS ci WHERE ci.collection_id = collections.id
+), 0);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_collection_items_collection_item_number
+    ON collection_items(collection_id, item_number);
+
+CREATE TRIGGER IF NOT EXISTS trg_collection_items_number_insert
+AFTER INSERT ON collection_items
+WHEN NEW.item_number IS NULL
+BEGIN
+    UPDATE collection_items SET item_number = (
+        SELECT next_item_number FROM collections WHERE id = NEW.collection_id
+    )
+    WHERE id = NEW.id;
+    UPDATE collections SET next_item_number = next_item_number + 1 WHERE id = NEW.collection_id;
+END;
+
+-- An item moved to another collection must clear its number in the same
+-- statement, since the number may be taken there; it then takes the next
+-- number of its new collection.
+CREATE TRIGGER IF NOT EXISTS trg_collection_items_number_update
+AFTER UPDATE OF collection_id, item_number ON collection_items
+WHEN NEW.item_number IS NULL
+BEGIN
+    UPDATE collection_items SET item_number = (
+        SELECT next_item_number FROM collections WHERE id = NEW.collection_id
+    )
+    WHERE id = NEW.id;
+    UPDATE collections SET next_item_number = next_item_number + 1 WHERE id = NEW.collection_id;
+END;
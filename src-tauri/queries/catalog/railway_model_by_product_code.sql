@@ -0,0 +1,5 @@
+SELECT rm.id AS "id!: _", m.name AS manufacturer, rm.product_code, rm.description, rm.scale
+FROM railway_models AS rm
+JOIN manufacturers AS m ON m.id = rm.manufacturer_id
+WHERE rm.manufacturer_id = ?1 AND rm.product_code = ?2 COLLATE NOCASE
+LIMIT 1
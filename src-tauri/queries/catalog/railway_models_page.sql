@@ -0,0 +1,18 @@
+WITH page AS (
+    SELECT rm.id, m.name AS manufacturer, rm.product_code, rm.description, rm.scale
+    FROM railway_models AS rm
+    JOIN manufacturers AS m ON m.id = rm.manufacturer_id
+    WHERE (rm.product_code, rm.id) > (?1, ?2)
+    ORDER BY rm.product_code, rm.id
+    LIMIT ?3
+)
+SELECT
+    page.id AS "id!: _", page.manufacturer AS "manufacturer!", page.product_code AS "product_code!",
+    page.description AS "description!", page.scale AS "scale!",
+    COUNT(rs.id) AS "rolling_stocks_count!: i64",
+    MIN(COALESCE(rs.length_millimeters, rs.length_inches * 25.4)) AS "min_length_over_buffers_mm?: f64",
+    MAX(COALESCE(rs.length_millimeters, rs.length_inches * 25.4)) AS "max_length_over_buffers_mm?: f64"
+FROM page
+LEFT JOIN rolling_stocks AS rs ON rs.railway_model_id = page.id
+GROUP BY page.id
+ORDER BY page.product_code, page.id
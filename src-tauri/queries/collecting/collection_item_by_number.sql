@@ -0,0 +1,30 @@
+SELECT
+    COALESCE(ci.created_at, '') AS "sort_key!: String", ci.id AS "item_id!: _", ci.collection_id AS "collection_id: _", ci.item_number AS "item_number!", ci.railway_model_id AS "railway_model_id!: _", ci.conditions, ci.notes,
+    ci.location_id AS item_location_id,
+    ci.replacement_value_amount AS item_replacement_value_amount, ci.replacement_value_currency AS item_replacement_value_currency,
+    EXISTS (SELECT 1 FROM loans AS l WHERE l.collection_item_id = ci.id AND l.returned_on IS NULL) AS "item_is_on_loan!: bool",
+    rm.msrp_amount AS "model_msrp_amount?", rm.msrp_currency AS "model_msrp_currency?",
+    ci.version AS item_version, ci.created_at AS "item_created_at: _", ci.updated_at AS "item_updated_at: _",
+    ors.id AS "owned_id?", rs.id AS "owned_rolling_stock_id?", ors.notes AS "owned_notes?", ors.location_id AS "owned_location_id?",
+    EXISTS (SELECT 1 FROM modifications AS m WHERE m.owned_rolling_stock_id = ors.id) AS "owned_is_modified!: bool",
+    d.id AS "decoder_id?", d.manufacturer AS "decoder_manufacturer?", d.model AS "decoder_model?",
+    d.dcc_interface AS "decoder_interface?", d.address AS "decoder_address?",
+    d.sound_project AS "decoder_sound_project?", d.firmware AS "decoder_firmware?",
+    d.purchase_price_amount AS "decoder_price_amount?", d.purchase_price_currency AS "decoder_price_currency?",
+    pi.purchase_id AS "purchase_id?", pi.purchase_type AS "purchase_type?",
+    pi.purchase_date AS "purchase_date?: _", pi.seller_id AS "seller_id?", pi.buyer_id AS "buyer_id?",
+    pi.sale_date AS "sale_date?: _",
+    pi.purchased_price_amount AS "purchased_price_amount?", pi.purchased_price_currency AS "purchased_price_currency?",
+    pi.sale_price_amount AS "sale_price_amount?", pi.sale_price_currency AS "sale_price_currency?",
+    pi.deposit_amount AS "deposit_amount?", pi.deposit_currency AS "deposit_currency?",
+    pi.preorder_total_amount AS "preorder_total_amount?", pi.preorder_total_currency AS "preorder_total_currency?",
+    pi.expected_date AS "expected_date?: _", pi.version AS "purchase_version?",
+    pi.created_at AS "purchase_created_at?: _", pi.updated_at AS "purchase_updated_at?: _"
+FROM collection_items AS ci
+LEFT JOIN railway_models AS rm ON rm.id = ci.railway_model_id
+LEFT JOIN owned_rolling_stocks AS ors ON ors.collection_item_id = ci.id
+LEFT JOIN rolling_stocks AS rs ON rs.id = ors.rolling_stock_id
+LEFT JOIN decoders AS d ON d.id = ors.decoder_id
+LEFT JOIN purchase_infos AS pi ON pi.collection_item_id = ci.id
+WHERE ci.collection_id = ?1 AND ci.item_number = ?2
+ORDER BY ci.rowid, ors.rowid, pi.rowid
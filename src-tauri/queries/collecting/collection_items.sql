@@ -0,0 +1,5 @@
+SELECT id AS "id!: _", collection_id AS "collection_id: _", item_number AS "item_number!", railway_model_id AS "railway_model_id!: _", conditions, notes, location_id,
+    replacement_value_amount, replacement_value_currency, version,
+    created_at AS "created_at: _", updated_at AS "updated_at: _"
+FROM collection_items
+WHERE collection_id = ?1
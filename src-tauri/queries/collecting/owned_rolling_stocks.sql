@@ -0,0 +1,5 @@
+SELECT ors.id AS "id!", ors.collection_item_id AS "collection_item_id: _", rs.id AS "rolling_stock_id?", ors.notes, ors.decoder_id, ors.location_id
+FROM owned_rolling_stocks AS ors
+JOIN collection_items AS ci ON ci.id = ors.collection_item_id
+LEFT JOIN rolling_stocks AS rs ON rs.id = ors.rolling_stock_id
+WHERE ci.collection_id = ?1
@@ -0,0 +1,9 @@
+SELECT pi.purchase_id AS "purchase_id!", pi.collection_item_id AS "collection_item_id: _", pi.purchase_type,
+    pi.purchase_date AS "purchase_date: _", pi.seller_id, pi.buyer_id, pi.sale_date AS "sale_date: _",
+    pi.purchased_price_amount, pi.purchased_price_currency, pi.sale_price_amount, pi.sale_price_currency,
+    pi.deposit_amount, pi.deposit_currency, pi.preorder_total_amount, pi.preorder_total_currency,
+    pi.expected_date AS "expected_date: _", pi.version,
+    pi.created_at AS "created_at: _", pi.updated_at AS "updated_at: _"
+FROM purchase_infos AS pi
+JOIN collection_items AS ci ON ci.id = pi.collection_item_id
+WHERE ci.collection_id = ?1
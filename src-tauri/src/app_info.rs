@@ -0,0 +1,26 @@
+use crate::catalog::infrastructure::cache::CatalogCacheMetrics;
+use crate::db::{DbPathSource, MigrationStatus, PoolMetrics};
+use serde::{Deserialize, Serialize};
+
+/// Diagnostic information about the running application.
+///
+/// Returned by the `get_app_info` command so the UI can display where data is
+/// stored and which schema version is in use (useful for bug reports).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct AppInfo {
+    /// The application version, as declared in the Tauri configuration.
+    pub app_version: String,
+    /// The resolved path of the SQLite database file.
+    pub db_path: String,
+    /// Where the database path was taken from (argument, environment,
+    /// config file or default).
+    pub db_path_source: DbPathSource,
+    /// The applied, pending and dirty database migrations.
+    pub migration_status: MigrationStatus,
+    /// The directory where log files are written, when it can be resolved.
+    pub log_dir: Option<String>,
+    /// The connection pool size, idle connections and acquire waits.
+    pub pool: PoolMetrics,
+    /// The railway model cache size and its hits and misses.
+    pub catalog_cache: CatalogCacheMetrics,
+}
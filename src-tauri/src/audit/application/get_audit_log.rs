@@ -0,0 +1,42 @@
+use crate::audit::domain::audit_entry::{AuditEntry, AuditLogFilter};
+use crate::audit::domain::repository::AuditLogRepository;
+use crate::core::domain::page::{MAX_PAGE_SIZE, Page, decode_cursor};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// The listing name carried by audit log cursors.
+pub const AUDIT_LOG_LISTING: &str = "audit_log";
+
+pub struct GetAuditLogUseCase {
+    repo: Arc<dyn AuditLogRepository>,
+}
+
+impl GetAuditLogUseCase {
+    pub fn new(repo: Arc<dyn AuditLogRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// List the audit entries matching `filter` one page at a time, newest
+    /// first.
+    ///
+    /// `cursor` is the `next_cursor` of the previous page, or `None` for the
+    /// first one; a cursor that was not returned by this listing fails with
+    /// `InvalidCursor`. `limit` is clamped to `1..=MAX_PAGE_SIZE`.
+    pub async fn execute(
+        &self,
+        filter: &AuditLogFilter,
+        cursor: Option<&str>,
+        limit: u32,
+    ) -> Result<Page<AuditEntry>> {
+        let after = cursor
+            .map(|cursor| decode_cursor(AUDIT_LOG_LISTING, cursor))
+            .transpose()?;
+        let limit = limit.clamp(1, MAX_PAGE_SIZE);
+
+        Ok(self
+            .repo
+            .get_audit_log(filter, after.as_ref(), limit)
+            .await?
+            .into_page(AUDIT_LOG_LISTING))
+    }
+}
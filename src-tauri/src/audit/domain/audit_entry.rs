@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+/// A recorded data-changing operation, to find out what changed and when.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct AuditEntry {
+    /// Identifier of the entry; later entries have greater ids.
+    pub id: i64,
+
+    /// When the change was made (UTC).
+    pub occurred_at: DateTime<Utc>,
+
+    /// What was done to the entity.
+    pub operation: AuditOperation,
+
+    /// The kind of entity changed, for example `decoder` or `consist`.
+    pub entity_type: String,
+
+    /// The identifier of the entity changed.
+    pub entity_id: String,
+
+    /// A JSON object summarising what was written: the stored fields for a
+    /// creation or an update, `null` for a deletion.
+    pub changes: String,
+
+    /// Where the change came from.
+    pub origin: AuditOrigin,
+}
+
+/// What a data-changing operation did to an entity.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, specta::Type,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOperation {
+    Create,
+    Update,
+    Delete,
+}
+
+/// Where a data-changing operation came from.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, specta::Type,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOrigin {
+    /// A command invoked from the UI.
+    Command,
+
+    /// Loading the demo data.
+    Import,
+}
+
+/// Which audit entries to list; `None` fields match every entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct AuditLogFilter {
+    /// Only entries about this kind of entity.
+    pub entity_type: Option<String>,
+
+    /// Only entries about the entity with this identifier.
+    pub entity_id: Option<String>,
+
+    /// Only entries with this origin.
+    pub origin: Option<AuditOrigin>,
+}
@@ -0,0 +1,15 @@
+use crate::audit::domain::audit_entry::{AuditEntry, AuditLogFilter};
+use crate::core::domain::page::{KeysetPage, PageKey};
+
+#[async_trait::async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    /// List up to `limit` entries matching `filter`, newest first, starting
+    /// after the entry with the `after` keys (from the newest one when
+    /// `None`).
+    async fn get_audit_log(
+        &self,
+        filter: &AuditLogFilter,
+        after: Option<&PageKey>,
+        limit: u32,
+    ) -> anyhow::Result<KeysetPage<AuditEntry>>;
+}
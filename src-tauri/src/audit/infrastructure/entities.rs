@@ -0,0 +1,20 @@
+//! Database row representations for the `audit` feature.
+//!
+//! These structs mirror the columns defined in the `0018_create_audit_log`
+//! migration and are intended only as a thin database representation
+//! (FromRow, and the target types of the `query_as!` macros). Conversion to
+//! rich domain types should happen in the repository layer.
+
+use chrono::{DateTime, Utc};
+
+/// Row mapping for the `audit_log` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct AuditEntryRow {
+    pub id: i64,
+    pub occurred_at: DateTime<Utc>,
+    pub operation: String,
+    pub entity_type: String,
+    pub entity_id: String,
+    pub changes: String,
+    pub origin: String,
+}
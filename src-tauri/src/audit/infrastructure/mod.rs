@@ -0,0 +1,4 @@
+pub mod entities;
+pub mod sink;
+pub mod sqlite;
+pub mod sqlite_repo;
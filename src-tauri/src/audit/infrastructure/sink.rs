@@ -0,0 +1,105 @@
+//! Recording data-changing operations in the audit log.
+//!
+//! The SQLite repositories hold an `AuditSink` next to their pool and hand
+//! it each change they make, together with the connection of the
+//! transaction making it: the entry is committed or rolled back with the
+//! change itself.
+
+use crate::audit::domain::audit_entry::{AuditOperation, AuditOrigin};
+use crate::audit::infrastructure::sqlite;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use sqlx::SqliteConnection;
+
+/// A change to record in the audit log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditedChange {
+    pub operation: AuditOperation,
+    pub entity_type: &'static str,
+    pub entity_id: String,
+    pub changes: serde_json::Value,
+}
+
+impl AuditedChange {
+    /// The creation of the `entity_type` with `entity_id`, stored as
+    /// `changes`.
+    pub fn created(
+        entity_type: &'static str,
+        entity_id: impl Into<String>,
+        changes: &impl Serialize,
+    ) -> Result<Self> {
+        Self::new(AuditOperation::Create, entity_type, entity_id, changes)
+    }
+
+    /// The update of the `entity_type` with `entity_id`, which now stores
+    /// `changes`.
+    pub fn updated(
+        entity_type: &'static str,
+        entity_id: impl Into<String>,
+        changes: &impl Serialize,
+    ) -> Result<Self> {
+        Self::new(AuditOperation::Update, entity_type, entity_id, changes)
+    }
+
+    /// The deletion of the `entity_type` with `entity_id`.
+    pub fn deleted(entity_type: &'static str, entity_id: impl Into<String>) -> Self {
+        Self {
+            operation: AuditOperation::Delete,
+            entity_type,
+            entity_id: entity_id.into(),
+            changes: serde_json::Value::Null,
+        }
+    }
+
+    fn new(
+        operation: AuditOperation,
+        entity_type: &'static str,
+        entity_id: impl Into<String>,
+        changes: &impl Serialize,
+    ) -> Result<Self> {
+        let entity_id = entity_id.into();
+        let changes = serde_json::to_value(changes)
+            .with_context(|| format!("summarising the changes of {entity_type} id={entity_id}"))?;
+        Ok(Self {
+            operation,
+            entity_type,
+            entity_id,
+            changes,
+        })
+    }
+}
+
+/// Where the repositories record the changes they make.
+#[async_trait::async_trait]
+pub trait AuditSink: Send + Sync {
+    /// Record `change` on `conn`, the connection of the transaction that
+    /// makes it.
+    async fn record(&self, conn: &mut SqliteConnection, change: &AuditedChange) -> Result<()>;
+}
+
+/// The sink writing to the `audit_log` table, tagging every entry with the
+/// origin of the changes.
+#[derive(Debug, Clone, Copy)]
+pub struct SqliteAuditSink {
+    origin: AuditOrigin,
+}
+
+impl SqliteAuditSink {
+    pub fn new(origin: AuditOrigin) -> Self {
+        Self { origin }
+    }
+}
+
+impl Default for SqliteAuditSink {
+    /// The sink of the changes made by commands.
+    fn default() -> Self {
+        Self::new(AuditOrigin::Command)
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditSink for SqliteAuditSink {
+    async fn record(&self, conn: &mut SqliteConnection, change: &AuditedChange) -> Result<()> {
+        sqlite::insert_audit_entry(conn, change, self.origin).await
+    }
+}
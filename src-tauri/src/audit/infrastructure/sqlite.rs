@@ -0,0 +1,74 @@
+//! SQLite helper functions (crate-internal) used to read and write the audit
+//! log.
+//!
+//! The read query is checked at compile time with `sqlx::query_as!` against
+//! the metadata in `.sqlx` (see the README); the insert runs on the
+//! connection of the transaction making the audited change.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use sqlx::SqliteExecutor;
+
+use crate::audit::domain::audit_entry::{AuditLogFilter, AuditOrigin};
+use crate::audit::infrastructure::entities::AuditEntryRow;
+use crate::audit::infrastructure::sink::AuditedChange;
+
+/// Insert an entry for `change` made from `origin`, timestamped with the
+/// current UTC time.
+pub async fn insert_audit_entry<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    change: &AuditedChange,
+    origin: AuditOrigin,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO audit_log (occurred_at, operation, entity_type, entity_id, changes, origin) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+    )
+    .bind(Utc::now())
+    .bind(change.operation.to_string())
+    .bind(change.entity_type)
+    .bind(&change.entity_id)
+    .bind(change.changes.to_string())
+    .bind(origin.to_string())
+    .execute(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "inserting audit entry for {} id={}",
+            change.entity_type, change.entity_id
+        )
+    })?;
+
+    Ok(())
+}
+
+/// Fetch up to `limit` entries matching `filter`, newest first, starting
+/// after the entry with id `before` (from the newest one when `None`).
+pub async fn get_audit_entries<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    filter: &AuditLogFilter,
+    before: Option<i64>,
+    limit: u32,
+) -> Result<Vec<AuditEntryRow>> {
+    let origin = filter.origin.map(|origin| origin.to_string());
+    let rows = sqlx::query_as!(
+        AuditEntryRow,
+        r#"SELECT id AS "id!", occurred_at AS "occurred_at: _", operation, entity_type, entity_id, changes, origin
+        FROM audit_log
+        WHERE (?1 IS NULL OR entity_type = ?1)
+            AND (?2 IS NULL OR entity_id = ?2)
+            AND (?3 IS NULL OR origin = ?3)
+            AND (?4 IS NULL OR id < ?4)
+        ORDER BY id DESC
+        LIMIT ?5"#,
+        filter.entity_type,
+        filter.entity_id,
+        origin,
+        before,
+        limit
+    )
+    .fetch_all(executor)
+    .await
+    .context("fetching audit entries")?;
+
+    Ok(rows)
+}
@@ -0,0 +1,187 @@
+use crate::audit::domain::audit_entry::{AuditEntry, AuditLogFilter, AuditOperation, AuditOrigin};
+use crate::audit::domain::repository::AuditLogRepository;
+use crate::audit::infrastructure::entities::AuditEntryRow;
+use crate::audit::infrastructure::sqlite;
+use crate::core::domain::page::{KeysetPage, PageKey};
+use anyhow::{Context, Result};
+use sqlx::SqlitePool;
+use std::str::FromStr;
+
+pub struct SqliteAuditLogRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteAuditLogRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    fn build_audit_entry(row: AuditEntryRow) -> Result<AuditEntry> {
+        let context = || format!("invalid audit_log row id={}", row.id);
+        Ok(AuditEntry {
+            operation: AuditOperation::from_str(&row.operation).with_context(context)?,
+            origin: AuditOrigin::from_str(&row.origin).with_context(context)?,
+            id: row.id,
+            occurred_at: row.occurred_at,
+            entity_type: row.entity_type,
+            entity_id: row.entity_id,
+            changes: row.changes,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogRepository for SqliteAuditLogRepository {
+    async fn get_audit_log(
+        &self,
+        filter: &AuditLogFilter,
+        after: Option<&PageKey>,
+        limit: u32,
+    ) -> Result<KeysetPage<AuditEntry>> {
+        // Entry ids grow with time, so they alone order the listing.
+        let before = after
+            .map(|key| key.id.parse::<i64>())
+            .transpose()
+            .context("invalid audit log page key")?;
+        let rows =
+            sqlite::get_audit_entries(&self.pool, filter, before, limit.saturating_add(1)).await?;
+
+        KeysetPage::from_rows(rows, limit, |row| PageKey {
+            sort_key: row.occurred_at.to_rfc3339(),
+            id: row.id.to_string(),
+        })
+        .try_map(Self::build_audit_entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::infrastructure::sink::{AuditSink, AuditedChange, SqliteAuditSink};
+    use crate::collecting::domain::decoder::Decoder;
+    use crate::collecting::domain::repository::DecoderRepository;
+    use crate::collecting::infrastructure::sqlite_decoder_repo::SqliteDecoderRepository;
+    use crate::core::infrastructure::transaction::with_transaction;
+    use anyhow::anyhow;
+    use pretty_assertions::assert_eq;
+
+    fn decoder(id: &str, address: u16) -> Decoder {
+        Decoder {
+            id: id.to_string(),
+            manufacturer: "ESU".to_string(),
+            model: "LokSound 5".to_string(),
+            dcc_interface: None,
+            address,
+            sound_project: None,
+            firmware: None,
+            purchase_price: None,
+        }
+    }
+
+    fn operations(page: &KeysetPage<AuditEntry>) -> Vec<(AuditOperation, &str)> {
+        page.items
+            .iter()
+            .map(|entry| (entry.operation, entry.entity_id.as_str()))
+            .collect()
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_repository_writes_are_audited(pool: SqlitePool) -> Result<()> {
+        let decoders = SqliteDecoderRepository::new(pool.clone());
+        decoders.create_decoder(&decoder("decoder-1", 3)).await?;
+        decoders.create_decoder(&decoder("decoder-2", 4)).await?;
+        decoders.update_decoder(&decoder("decoder-1", 5)).await?;
+        decoders.delete_decoder("decoder-1").await?;
+        let repo = SqliteAuditLogRepository::new(pool.clone());
+
+        let page = repo
+            .get_audit_log(&AuditLogFilter::default(), None, 10)
+            .await?;
+        assert_eq!(
+            operations(&page),
+            vec![
+                (AuditOperation::Delete, "decoder-1"),
+                (AuditOperation::Update, "decoder-1"),
+                (AuditOperation::Create, "decoder-2"),
+                (AuditOperation::Create, "decoder-1"),
+            ]
+        );
+        let update = &page.items[1];
+        assert_eq!(update.entity_type, "decoder");
+        assert_eq!(update.origin, AuditOrigin::Command);
+        let changes: serde_json::Value = serde_json::from_str(&update.changes)?;
+        assert_eq!(changes["address"], 5);
+        assert_eq!(page.items[0].changes, "null");
+
+        let filter = AuditLogFilter {
+            entity_id: Some("decoder-2".to_string()),
+            ..AuditLogFilter::default()
+        };
+        let page = repo.get_audit_log(&filter, None, 10).await?;
+        assert_eq!(
+            operations(&page),
+            vec![(AuditOperation::Create, "decoder-2")]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_failed_writes_leave_no_entry(pool: SqlitePool) -> Result<()> {
+        let decoders = SqliteDecoderRepository::new(pool.clone());
+        assert!(
+            decoders
+                .update_decoder(&decoder("missing", 3))
+                .await
+                .is_err()
+        );
+        let result: Result<()> = with_transaction(&pool, |conn| {
+            Box::pin(async move {
+                let change = AuditedChange::deleted("decoder", "decoder-1");
+                SqliteAuditSink::default().record(conn, &change).await?;
+                Err(anyhow!("the audited change failed"))
+            })
+        })
+        .await;
+        assert!(result.is_err());
+
+        let repo = SqliteAuditLogRepository::new(pool.clone());
+        let page = repo
+            .get_audit_log(&AuditLogFilter::default(), None, 10)
+            .await?;
+        assert!(page.items.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_audit_log_is_paged_newest_first(pool: SqlitePool) -> Result<()> {
+        let decoders = SqliteDecoderRepository::new(pool.clone());
+        for i in 1..=5 {
+            decoders
+                .create_decoder(&decoder(&format!("decoder-{i}"), i))
+                .await?;
+        }
+        let repo = SqliteAuditLogRepository::new(pool.clone());
+        let filter = AuditLogFilter {
+            entity_type: Some("decoder".to_string()),
+            origin: Some(AuditOrigin::Command),
+            ..AuditLogFilter::default()
+        };
+
+        let first = repo.get_audit_log(&filter, None, 3).await?;
+        let second = repo.get_audit_log(&filter, first.next.as_ref(), 3).await?;
+
+        let ids = |page: &KeysetPage<AuditEntry>| {
+            page.items
+                .iter()
+                .map(|entry| entry.entity_id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&first), vec!["decoder-5", "decoder-4", "decoder-3"]);
+        assert_eq!(ids(&second), vec!["decoder-2", "decoder-1"]);
+        assert!(second.next.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+//! Command handlers exposed to the Tauri frontend for the `audit` feature.
+//!
+//! These functions act as a thin adapter between the Tauri IPC layer and the
+//! application/use-case layer. They translate incoming requests into use-case
+//! invocations and map application errors into `CommandError` values suitable
+//! for returning over the IPC boundary.
+
+use crate::audit::application::get_audit_log::GetAuditLogUseCase;
+use crate::audit::domain::audit_entry::{AuditEntry, AuditLogFilter};
+use crate::audit::infrastructure::sqlite_repo::SqliteAuditLogRepository;
+use crate::core::domain::page::{InvalidCursor, Page};
+use crate::core::infrastructure::error::CommandError;
+use crate::state::AppState;
+use std::sync::Arc;
+
+/// Tauri command to list the recorded data-changing operations one page at
+/// a time, newest first.
+///
+/// Pass `cursor: null` for the first page and the returned `next_cursor`
+/// for the following ones, until it is `null`. `limit` is clamped to a
+/// sensible maximum.
+///
+/// Parameters:
+/// - `filter`: restricts the listing to an entity type, an entity id or an
+///   origin; `null` fields match every entry.
+///
+/// Returns:
+/// - `Ok(Page<AuditEntry>)` with the entries of the page.
+/// - `Err(CommandError::Validation)` when `cursor` is not a cursor returned
+///   by this command.
+/// - `Err(CommandError)` for any other failure.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_audit_log(
+    state: tauri::State<'_, AppState>,
+    filter: AuditLogFilter,
+    cursor: Option<String>,
+    limit: u32,
+) -> Result<Page<AuditEntry>, CommandError> {
+    let repo = SqliteAuditLogRepository::new(state.db_pool());
+    let use_case = GetAuditLogUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&filter, cursor.as_deref(), limit)
+        .await
+        .map_err(|e| {
+            if e.downcast_ref::<InvalidCursor>().is_some() {
+                CommandError::Validation(e.to_string())
+            } else {
+                CommandError::Unknown(e.to_string())
+            }
+        })
+}
@@ -0,0 +1,25 @@
+use crate::catalog::domain::repository::CatalogRepository;
+use crate::catalog::domain::rolling_stock_comparison::ComparisonReport;
+use crate::catalog::domain::rolling_stock_id::RollingStockId;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct CompareRollingStocksUseCase {
+    repo: Arc<dyn CatalogRepository>,
+}
+
+impl CompareRollingStocksUseCase {
+    pub fn new(repo: Arc<dyn CatalogRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Compare two rolling stocks side by side (see
+    /// `CatalogRepository::compare_rolling_stocks`).
+    pub async fn execute(
+        &self,
+        left_id: &RollingStockId,
+        right_id: &RollingStockId,
+    ) -> Result<ComparisonReport> {
+        self.repo.compare_rolling_stocks(left_id, right_id).await
+    }
+}
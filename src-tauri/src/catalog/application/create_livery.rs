@@ -0,0 +1,26 @@
+use crate::catalog::domain::livery::{Livery, LiveryDetails};
+use crate::catalog::domain::repository::LiveryRepository;
+use anyhow::Result;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct CreateLiveryUseCase {
+    repo: Arc<dyn LiveryRepository>,
+}
+
+impl CreateLiveryUseCase {
+    pub fn new(repo: Arc<dyn LiveryRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Add a livery to its railway company, returning it with its generated
+    /// id.
+    ///
+    /// A blank name or a period of use ending before it starts fails with a
+    /// `LiveryError`.
+    pub async fn execute(&self, details: LiveryDetails) -> Result<Livery> {
+        let livery = Livery::new(Uuid::new_v4().to_string(), details)?;
+        self.repo.create_livery(&livery).await?;
+        Ok(livery)
+    }
+}
@@ -0,0 +1,28 @@
+use crate::catalog::domain::duplicate_candidate::DuplicateCandidate;
+use crate::catalog::domain::repository::CatalogRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+/// Lower bound for the similarity threshold; below it nearly every pair of
+/// models of a manufacturer would be reported.
+pub const MIN_SIMILARITY_THRESHOLD: f64 = 0.3;
+
+pub struct FindPossibleDuplicatesUseCase {
+    repo: Arc<dyn CatalogRepository>,
+}
+
+impl FindPossibleDuplicatesUseCase {
+    pub fn new(repo: Arc<dyn CatalogRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Find the models that are probably duplicates of each other: same
+    /// manufacturer and scale, and descriptions at least `threshold`
+    /// similar, most similar first.
+    ///
+    /// `threshold` is clamped to `MIN_SIMILARITY_THRESHOLD..=1.0`.
+    pub async fn execute(&self, threshold: f64) -> Result<Vec<DuplicateCandidate>> {
+        let threshold = threshold.clamp(MIN_SIMILARITY_THRESHOLD, 1.0);
+        self.repo.find_possible_duplicates(threshold).await
+    }
+}
@@ -0,0 +1,126 @@
+use crate::catalog::domain::availability_status::AvailabilityStatus;
+use crate::catalog::domain::category::{
+    Category, ElectricMultipleUnitType, FreightCarType, LocomotiveType, PassengerCarType,
+    RailcarType, RollingStockCategory,
+};
+use crate::catalog::domain::control::Control;
+use crate::catalog::domain::coupling_socket::CouplingSocket;
+use crate::catalog::domain::dcc_interface::DccInterface;
+use crate::catalog::domain::enum_info::EnumMetadata;
+use crate::catalog::domain::railway_status::RailwayStatus;
+use crate::catalog::domain::reference_data::ReferenceData;
+use crate::catalog::domain::repository::CatalogRepository;
+use crate::catalog::domain::track_gauge::TrackGauge;
+use crate::catalog::domain::{
+    BodyShellType, ChassisType, FeatureFlag, PowerMethod, Scale, ServiceLevel,
+};
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct GetReferenceDataUseCase {
+    repo: Arc<dyn CatalogRepository>,
+}
+
+impl GetReferenceDataUseCase {
+    pub fn new(repo: Arc<dyn CatalogRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Collect every option list the catalog forms need.
+    ///
+    /// Manufacturers and railway companies are read from the database; the
+    /// enum option lists come from `EnumMetadata::VARIANTS` so they cannot
+    /// drift from the Rust types.
+    pub async fn execute(&self) -> Result<ReferenceData> {
+        let manufacturers = self.repo.list_manufacturers().await?;
+        let railway_companies = self.repo.list_railway_companies().await?;
+
+        Ok(ReferenceData {
+            manufacturers,
+            railway_companies,
+            scales: Scale::infos(),
+            categories: Category::infos(),
+            rolling_stock_categories: RollingStockCategory::infos(),
+            locomotive_types: LocomotiveType::infos(),
+            freight_car_types: FreightCarType::infos(),
+            passenger_car_types: PassengerCarType::infos(),
+            electric_multiple_unit_types: ElectricMultipleUnitType::infos(),
+            railcar_types: RailcarType::infos(),
+            power_methods: PowerMethod::infos(),
+            availability_statuses: AvailabilityStatus::infos(),
+            body_shell_types: BodyShellType::infos(),
+            chassis_types: ChassisType::infos(),
+            controls: Control::infos(),
+            dcc_interfaces: DccInterface::infos(),
+            coupling_sockets: CouplingSocket::infos(),
+            feature_flags: FeatureFlag::infos(),
+            service_levels: ServiceLevel::infos(),
+            track_gauges: TrackGauge::infos(),
+            railway_statuses: RailwayStatus::infos(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::sqlite_repo::SqliteCatalogRepository;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::db::init_in_memory_db_pool;
+    use pretty_assertions::assert_eq;
+
+    // An exhaustive match: adding a `Category` variant without updating
+    // `Category::ALL` fails to compile here, and the test below catches a
+    // variant listed twice or left out.
+    fn ordinal(category: Category) -> usize {
+        match category {
+            Category::Locomotives => 0,
+            Category::TrainSets => 1,
+            Category::StarterSets => 2,
+            Category::FreightCars => 3,
+            Category::PassengerCars => 4,
+            Category::ElectricMultipleUnits => 5,
+            Category::Railcars => 6,
+        }
+    }
+
+    #[tokio::test]
+    async fn every_category_appears_exactly_once() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let use_case = GetReferenceDataUseCase::new(Arc::new(SqliteCatalogRepository::new(pool)));
+
+        let reference_data = use_case.execute().await.unwrap();
+
+        let mut seen = [0usize; 7];
+        for category in &reference_data.categories {
+            seen[ordinal(category.value)] += 1;
+        }
+        assert_eq!(seen, [1; 7]);
+    }
+
+    #[tokio::test]
+    async fn includes_manufacturers_and_railway_companies() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await
+            .expect("setup railway model");
+
+        let use_case = GetReferenceDataUseCase::new(Arc::new(SqliteCatalogRepository::new(pool)));
+
+        let reference_data = use_case.execute().await.unwrap();
+
+        assert_eq!(reference_data.manufacturers.len(), 1);
+        assert_eq!(
+            reference_data.manufacturers[0].id,
+            test_data.manufacturer_id
+        );
+        assert_eq!(reference_data.railway_companies.len(), 1);
+        assert_eq!(
+            reference_data.railway_companies[0].id,
+            test_data.railway_company_id
+        );
+        let scales: Vec<Scale> = reference_data.scales.into_iter().map(|s| s.value).collect();
+        assert_eq!(scales, Scale::ALL.to_vec());
+    }
+}
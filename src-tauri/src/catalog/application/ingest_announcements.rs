@@ -0,0 +1,20 @@
+use crate::catalog::domain::announcement::{AnnouncementBatch, AnnouncementIngest};
+use crate::catalog::domain::repository::CatalogRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct IngestAnnouncementsUseCase {
+    repo: Arc<dyn CatalogRepository>,
+}
+
+impl IngestAnnouncementsUseCase {
+    pub fn new(repo: Arc<dyn CatalogRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Ingest a manufacturer's announcements (see
+    /// `CatalogRepository::ingest_announcements`).
+    pub async fn execute(&self, batch: &AnnouncementBatch) -> Result<AnnouncementIngest> {
+        self.repo.ingest_announcements(batch).await
+    }
+}
@@ -0,0 +1,133 @@
+use crate::catalog::domain::railway_model_list_entry::RailwayModelListEntry;
+use crate::catalog::domain::repository::CatalogRepository;
+use crate::core::domain::page::{MAX_PAGE_SIZE, Page, decode_cursor};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// The listing name carried by railway model cursors.
+pub const RAILWAY_MODELS_LISTING: &str = "railway_models";
+
+pub struct ListRailwayModelsUseCase {
+    repo: Arc<dyn CatalogRepository>,
+}
+
+impl ListRailwayModelsUseCase {
+    pub fn new(repo: Arc<dyn CatalogRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// List the railway models one page at a time, ordered by product code.
+    ///
+    /// `cursor` is the `next_cursor` of the previous page, or `None` for the
+    /// first one; a cursor that was not returned by this listing fails with
+    /// `InvalidCursor`. `limit` is clamped to `1..=MAX_PAGE_SIZE`.
+    pub async fn execute(
+        &self,
+        cursor: Option<&str>,
+        limit: u32,
+    ) -> Result<Page<RailwayModelListEntry>> {
+        let after = cursor
+            .map(|cursor| decode_cursor(RAILWAY_MODELS_LISTING, cursor))
+            .transpose()?;
+        let limit = limit.clamp(1, MAX_PAGE_SIZE);
+
+        Ok(self
+            .repo
+            .list_railway_models(after.as_ref(), limit)
+            .await?
+            .into_page(RAILWAY_MODELS_LISTING))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::domain::railway_model_id::RailwayModelId;
+    use crate::catalog::infrastructure::sqlite_repo::SqliteCatalogRepository;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::core::domain::page::{InvalidCursor, PageKey, encode_cursor};
+    use pretty_assertions::assert_eq;
+    use sqlx::SqlitePool;
+    use std::collections::HashSet;
+
+    async fn setup(pool: &SqlitePool, count: usize) -> Result<ListRailwayModelsUseCase> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        catalog_db.insert_manufacturer("m1", "ACME").await?;
+        for n in 0..count {
+            catalog_db
+                .insert_railway_model(
+                    &RailwayModelId::new(),
+                    "m1",
+                    &format!("HR{n:04}"),
+                    &format!("Model {n}"),
+                    "electric",
+                    "H0",
+                    "IV",
+                    "LOCOMOTIVES",
+                )
+                .await?;
+        }
+        Ok(ListRailwayModelsUseCase::new(Arc::new(
+            SqliteCatalogRepository::new(pool.clone()),
+        )))
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn pages_cover_every_railway_model_once(pool: SqlitePool) -> Result<()> {
+        let use_case = setup(&pool, 1_000).await?;
+
+        let mut seen = HashSet::new();
+        let mut product_codes = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = use_case.execute(cursor.as_deref(), 75).await?;
+            for entry in page.items {
+                let summary = entry.summary;
+                assert!(seen.insert(summary.id.to_string()), "{} twice", summary.id);
+                product_codes.push(summary.product_code.to_string());
+            }
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        assert_eq!(seen.len(), 1_000);
+        let mut sorted = product_codes.clone();
+        sorted.sort();
+        assert_eq!(product_codes, sorted);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn limit_is_clamped(pool: SqlitePool) -> Result<()> {
+        let use_case = setup(&pool, MAX_PAGE_SIZE as usize + 1).await?;
+
+        assert_eq!(use_case.execute(None, 0).await?.items.len(), 1);
+        let page = use_case.execute(None, u32::MAX).await?;
+        assert_eq!(page.items.len(), MAX_PAGE_SIZE as usize);
+        assert!(page.next_cursor.is_some());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn invalid_cursors_are_rejected(pool: SqlitePool) -> Result<()> {
+        let use_case = setup(&pool, 1).await?;
+        let foreign = encode_cursor(
+            "collection_items",
+            &PageKey {
+                sort_key: String::new(),
+                id: "rm-0000".to_string(),
+            },
+        );
+
+        for cursor in ["garbage", foreign.as_str()] {
+            let err = use_case.execute(Some(cursor), 10).await.unwrap_err();
+            assert!(err.downcast_ref::<InvalidCursor>().is_some(), "{err}");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+use crate::catalog::domain::product_code_lookup::{ProductCodeLookup, ProductCodeQuery};
+use crate::catalog::domain::repository::CatalogRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct LookupProductCodesUseCase {
+    repo: Arc<dyn CatalogRepository>,
+}
+
+impl LookupProductCodesUseCase {
+    pub fn new(repo: Arc<dyn CatalogRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Look product codes up in the catalog and the collection (see
+    /// `CatalogRepository::lookup_product_codes`).
+    pub async fn execute(&self, queries: Vec<ProductCodeQuery>) -> Result<Vec<ProductCodeLookup>> {
+        self.repo.lookup_product_codes(queries).await
+    }
+}
@@ -0,0 +1,25 @@
+use crate::catalog::domain::model_merge::ModelMerge;
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::domain::repository::CatalogRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct MergeRailwayModelsUseCase {
+    repo: Arc<dyn CatalogRepository>,
+}
+
+impl MergeRailwayModelsUseCase {
+    pub fn new(repo: Arc<dyn CatalogRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Merge the duplicate railway model `remove_id` into `keep_id` (see
+    /// `CatalogRepository::merge_railway_models`).
+    pub async fn execute(
+        &self,
+        keep_id: &RailwayModelId,
+        remove_id: &RailwayModelId,
+    ) -> Result<ModelMerge> {
+        self.repo.merge_railway_models(keep_id, remove_id).await
+    }
+}
@@ -0,0 +1,12 @@
+pub mod compare_rolling_stocks;
+pub mod create_livery;
+pub mod find_possible_duplicates;
+pub mod get_reference_data;
+pub mod ingest_announcements;
+pub mod list_railway_models;
+pub mod lookup_product_codes;
+pub mod merge_railway_models;
+pub mod search_catalog;
+pub mod suggest_liveries;
+pub mod update_livery;
+pub mod validate_product_code;
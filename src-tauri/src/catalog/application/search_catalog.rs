@@ -0,0 +1,75 @@
+use crate::catalog::domain::railway_model_summary::RailwayModelSummary;
+use crate::catalog::domain::repository::CatalogRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+/// Queries shorter than this (after trimming) return no results.
+pub const MIN_QUERY_LENGTH: usize = 2;
+
+/// Upper bound for the number of results returned by a single search.
+pub const MAX_SEARCH_LIMIT: u32 = 50;
+
+pub struct SearchCatalogUseCase {
+    repo: Arc<dyn CatalogRepository>,
+}
+
+impl SearchCatalogUseCase {
+    pub fn new(repo: Arc<dyn CatalogRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Search the catalog for models matching `query`.
+    ///
+    /// The query is trimmed and short queries (fewer than
+    /// `MIN_QUERY_LENGTH` characters) short-circuit to an empty result
+    /// without touching the database, which keeps autocomplete pickers cheap
+    /// while the user is still typing. `limit` is clamped to
+    /// `1..=MAX_SEARCH_LIMIT`.
+    pub async fn execute(&self, query: &str, limit: u32) -> Result<Vec<RailwayModelSummary>> {
+        let query = query.trim();
+        if query.chars().count() < MIN_QUERY_LENGTH {
+            return Ok(Vec::new());
+        }
+
+        let limit = limit.clamp(1, MAX_SEARCH_LIMIT);
+        self.repo.search_railway_models(query, limit).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::sqlite_repo::SqliteCatalogRepository;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::db::init_in_memory_db_pool;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn short_queries_return_no_results() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await
+            .expect("setup railway model");
+
+        let use_case = SearchCatalogUseCase::new(Arc::new(SqliteCatalogRepository::new(pool)));
+
+        assert!(use_case.execute("E", 10).await.unwrap().is_empty());
+        assert!(use_case.execute("  E  ", 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn queries_are_trimmed_and_limit_is_clamped() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await
+            .expect("setup railway model");
+
+        let use_case = SearchCatalogUseCase::new(Arc::new(SqliteCatalogRepository::new(pool)));
+
+        let results = use_case.execute("  E656 ", 0).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].product_code.to_string(), "E656");
+    }
+}
@@ -0,0 +1,76 @@
+use crate::catalog::domain::livery::Livery;
+use crate::catalog::domain::repository::LiveryRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+/// Upper bound for the number of suggestions returned at once.
+pub const MAX_SUGGESTIONS: u32 = 20;
+
+pub struct SuggestLiveriesUseCase {
+    repo: Arc<dyn LiveryRepository>,
+}
+
+impl SuggestLiveriesUseCase {
+    pub fn new(repo: Arc<dyn LiveryRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Suggest the liveries of the railway company with `railway_company_id`
+    /// matching what the collector typed so far.
+    ///
+    /// The prefix is trimmed; a blank prefix suggests every livery of the
+    /// railway company, since they are few. `limit` is clamped to
+    /// `1..=MAX_SUGGESTIONS`.
+    pub async fn execute(
+        &self,
+        railway_company_id: &str,
+        prefix: &str,
+        limit: u32,
+    ) -> Result<Vec<Livery>> {
+        let limit = limit.clamp(1, MAX_SUGGESTIONS);
+        self.repo
+            .suggest_liveries(railway_company_id, prefix.trim(), limit)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::domain::livery::{LiveryDetails, PeriodOfUse};
+    use crate::catalog::infrastructure::sqlite_livery_repo::SqliteLiveryRepository;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::db::init_in_memory_db_pool;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn blank_prefixes_suggest_every_livery_of_the_railway() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        CatalogTestDb::new(pool.clone())
+            .insert_railway_company("fs", "FS")
+            .await
+            .expect("insert railway company");
+        let repo = Arc::new(SqliteLiveryRepository::new(pool));
+        for name in ["XMPR", "Castano/Isabella"] {
+            let livery = Livery::new(
+                name.to_string(),
+                LiveryDetails {
+                    railway_company_id: "fs".to_string(),
+                    name: name.to_string(),
+                    description: None,
+                    period_of_use: PeriodOfUse::default(),
+                },
+            )
+            .unwrap();
+            repo.create_livery(&livery).await.unwrap();
+        }
+
+        let use_case = SuggestLiveriesUseCase::new(repo);
+
+        let suggestions = use_case.execute("fs", "  ", 0).await.unwrap();
+        assert_eq!(suggestions.len(), 1);
+        assert_eq!(suggestions[0].name, "Castano/Isabella");
+        let suggestions = use_case.execute("fs", " xm ", 10).await.unwrap();
+        assert_eq!(suggestions[0].name, "XMPR");
+    }
+}
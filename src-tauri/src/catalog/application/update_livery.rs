@@ -0,0 +1,23 @@
+use crate::catalog::domain::livery::{Livery, LiveryDetails};
+use crate::catalog::domain::repository::LiveryRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct UpdateLiveryUseCase {
+    repo: Arc<dyn LiveryRepository>,
+}
+
+impl UpdateLiveryUseCase {
+    pub fn new(repo: Arc<dyn LiveryRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Replace the details of the livery with `livery_id`.
+    ///
+    /// The details are checked as by `CreateLiveryUseCase`.
+    pub async fn execute(&self, livery_id: &str, details: LiveryDetails) -> Result<Livery> {
+        let livery = Livery::new(livery_id.to_string(), details)?;
+        self.repo.update_livery(&livery).await?;
+        Ok(livery)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::catalog::domain::ProductCode;
+use crate::catalog::domain::product_code_validation::ProductCodeValidation;
+use crate::catalog::domain::repository::CatalogRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct ValidateProductCodeUseCase {
+    repo: Arc<dyn CatalogRepository>,
+}
+
+impl ValidateProductCodeUseCase {
+    pub fn new(repo: Arc<dyn CatalogRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Check whether `product_code` can be used for a new model from
+    /// `manufacturer_id`.
+    ///
+    /// The code goes through `ProductCode::try_from` (trim and format checks)
+    /// exactly as it would on creation; a malformed code is reported as
+    /// `InvalidFormat` rather than as an error.
+    pub async fn execute(
+        &self,
+        manufacturer_id: &str,
+        product_code: &str,
+    ) -> Result<ProductCodeValidation> {
+        let product_code = match ProductCode::try_from(product_code) {
+            Ok(product_code) => product_code,
+            Err(e) => {
+                return Ok(ProductCodeValidation::InvalidFormat {
+                    reason: e.to_string(),
+                });
+            }
+        };
+
+        let existing = self
+            .repo
+            .find_railway_model_by_product_code(manufacturer_id, &product_code)
+            .await?;
+
+        Ok(match existing {
+            Some(model) => ProductCodeValidation::Duplicate {
+                existing_model_id: model.id,
+                description: model.description,
+            },
+            None => ProductCodeValidation::Available,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::sqlite_repo::SqliteCatalogRepository;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::db::init_in_memory_db_pool;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn unused_product_code_is_available() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await
+            .expect("setup railway model");
+
+        let use_case =
+            ValidateProductCodeUseCase::new(Arc::new(SqliteCatalogRepository::new(pool)));
+
+        let result = use_case
+            .execute(&test_data.manufacturer_id, "E444")
+            .await
+            .unwrap();
+        assert_eq!(result, ProductCodeValidation::Available);
+    }
+
+    #[tokio::test]
+    async fn existing_product_code_is_duplicate_after_normalization() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await
+            .expect("setup railway model");
+
+        let use_case =
+            ValidateProductCodeUseCase::new(Arc::new(SqliteCatalogRepository::new(pool)));
+
+        let result = use_case
+            .execute(&test_data.manufacturer_id, "  e656 ")
+            .await
+            .unwrap();
+        match result {
+            ProductCodeValidation::Duplicate {
+                existing_model_id, ..
+            } => assert_eq!(existing_model_id, test_data.railway_model_id),
+            other => panic!("expected Duplicate, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn blank_product_code_is_invalid_format() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let use_case =
+            ValidateProductCodeUseCase::new(Arc::new(SqliteCatalogRepository::new(pool)));
+
+        let result = use_case.execute("any", "   ").await.unwrap();
+        assert!(matches!(
+            result,
+            ProductCodeValidation::InvalidFormat { reason } if reason.contains("must not be empty")
+        ));
+    }
+}
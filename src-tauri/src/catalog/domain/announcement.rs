@@ -0,0 +1,258 @@
+//! Announcements of new railway models, as published by the manufacturers.
+//!
+//! Manufacturers announce their new items in lists, usually a few times a
+//! year, and update the expected delivery dates and prices as the release
+//! gets closer. Ingesting a batch creates the models it does not know as
+//! `AvailabilityStatus::Announced`, and only refreshes the delivery date and
+//! the suggested retail price of those already in the catalog, so the same
+//! list can be ingested again without duplicating anything.
+
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::domain::{Category, DeliveryDate, Epoch, PowerMethod, ProductCode, Scale};
+use crate::core::domain::MonetaryAmount;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// A list of new items announced by a manufacturer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct AnnouncementBatch {
+    /// The name of the manufacturer, as stored in the catalog (ignoring
+    /// case).
+    pub manufacturer: String,
+
+    /// The announced items.
+    pub entries: Vec<AnnouncedModel>,
+}
+
+/// An item of an announcement batch.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct AnnouncedModel {
+    /// The manufacturer's product code; it identifies the model on
+    /// re-ingestion (ignoring case).
+    pub product_code: ProductCode,
+
+    /// The description of the model.
+    pub description: String,
+
+    /// The category of the model.
+    pub category: Category,
+
+    /// The power method of the model.
+    pub power_method: PowerMethod,
+
+    /// The scale of the model.
+    pub scale: Scale,
+
+    /// The era of the model.
+    pub epoch: Epoch,
+
+    /// When the model is expected to be delivered, when announced, like
+    /// `"2026"`, `"2026/05"` or `"2026/Q3"`.
+    #[specta(type = Option<String>)]
+    pub delivery_date: Option<DeliveryDate>,
+
+    /// The manufacturer's suggested retail price, when announced.
+    pub msrp: Option<MonetaryAmount>,
+}
+
+impl AnnouncedModel {
+    /// Whether ingesting this entry changes a model whose delivery date and
+    /// suggested retail price are `delivery_date` and `msrp`.
+    ///
+    /// Values the entry does not announce are left as they are.
+    pub fn changes(
+        &self,
+        delivery_date: Option<&DeliveryDate>,
+        msrp: Option<&MonetaryAmount>,
+    ) -> bool {
+        self.delivery_date
+            .as_ref()
+            .is_some_and(|announced| Some(announced) != delivery_date)
+            || self
+                .msrp
+                .as_ref()
+                .is_some_and(|announced| Some(announced) != msrp)
+    }
+}
+
+impl AnnouncementBatch {
+    /// Check the batch before anything is stored.
+    ///
+    /// # Errors
+    ///
+    /// An `AnnouncementError` when the manufacturer or an entry's product
+    /// code or description are blank, or two entries have the same product
+    /// code (ignoring case).
+    pub fn validate(&self) -> Result<(), AnnouncementError> {
+        if self.manufacturer.trim().is_empty() {
+            return Err(AnnouncementError::BlankManufacturer);
+        }
+        let mut product_codes = HashSet::new();
+        for entry in &self.entries {
+            let product_code = entry.product_code.trim();
+            if product_code.is_empty() {
+                return Err(AnnouncementError::BlankProductCode);
+            }
+            if entry.description.trim().is_empty() {
+                return Err(AnnouncementError::BlankDescription(
+                    product_code.to_string(),
+                ));
+            }
+            if !product_codes.insert(product_code.to_lowercase()) {
+                return Err(AnnouncementError::DuplicateProductCode(
+                    product_code.to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What ingesting an announced item did.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IngestOutcome {
+    /// A new announced model was added to the catalog.
+    Created,
+    /// The delivery date or the suggested retail price of a known model
+    /// changed.
+    Updated,
+    /// The model was already in the catalog as announced.
+    Unchanged,
+}
+
+/// An announced item after ingestion.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct IngestedAnnouncement {
+    /// The product code of the item.
+    pub product_code: ProductCode,
+
+    /// The railway model the item created or matched.
+    pub railway_model_id: RailwayModelId,
+
+    /// What ingesting it did.
+    pub outcome: IngestOutcome,
+}
+
+/// What ingesting an announcement batch did, for the import summary.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct AnnouncementIngest {
+    /// Every entry of the batch, in order.
+    pub entries: Vec<IngestedAnnouncement>,
+
+    /// How many models were created.
+    pub created: u32,
+
+    /// How many known models were updated.
+    pub updated: u32,
+
+    /// How many known models were left unchanged.
+    pub unchanged: u32,
+}
+
+impl AnnouncementIngest {
+    /// Add an ingested entry to the summary.
+    pub fn record(
+        &mut self,
+        product_code: ProductCode,
+        railway_model_id: RailwayModelId,
+        outcome: IngestOutcome,
+    ) {
+        match outcome {
+            IngestOutcome::Created => self.created += 1,
+            IngestOutcome::Updated => self.updated += 1,
+            IngestOutcome::Unchanged => self.unchanged += 1,
+        }
+        self.entries.push(IngestedAnnouncement {
+            product_code,
+            railway_model_id,
+            outcome,
+        });
+    }
+}
+
+/// Why an announcement batch cannot be ingested.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AnnouncementError {
+    #[error("the manufacturer of the announcements must not be blank")]
+    BlankManufacturer,
+
+    #[error("unknown manufacturer {0:?}")]
+    UnknownManufacturer(String),
+
+    #[error("the product code of an announced model must not be blank")]
+    BlankProductCode,
+
+    #[error("the announced model {0} has no description")]
+    BlankDescription(String),
+
+    #[error("the product code {0} is announced more than once")]
+    DuplicateProductCode(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::currency::Currency;
+    use pretty_assertions::assert_eq;
+
+    fn entry(product_code: &str) -> AnnouncedModel {
+        AnnouncedModel {
+            product_code: ProductCode(product_code.to_string()),
+            description: "Electric locomotive E.656".to_string(),
+            category: Category::Locomotives,
+            power_method: PowerMethod::DC,
+            scale: Scale::H0,
+            epoch: Epoch::from("IV"),
+            delivery_date: Some(DeliveryDate::Year(2026)),
+            msrp: Some(MonetaryAmount::new(24_990, Currency::EUR)),
+        }
+    }
+
+    #[test]
+    fn it_should_reject_duplicate_product_codes() {
+        let batch = AnnouncementBatch {
+            manufacturer: "ACME".to_string(),
+            entries: vec![entry("60211"), entry("60212"), entry(" 60211 ")],
+        };
+
+        assert_eq!(
+            batch.validate(),
+            Err(AnnouncementError::DuplicateProductCode("60211".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_should_reject_blank_fields() {
+        let mut batch = AnnouncementBatch {
+            manufacturer: " ".to_string(),
+            entries: vec![entry("60211")],
+        };
+        assert_eq!(batch.validate(), Err(AnnouncementError::BlankManufacturer));
+
+        batch.manufacturer = "ACME".to_string();
+        batch.entries[0].description = String::new();
+        assert_eq!(
+            batch.validate(),
+            Err(AnnouncementError::BlankDescription("60211".to_string()))
+        );
+    }
+
+    #[test]
+    fn only_announced_values_change_a_known_model() {
+        let announced = entry("60211");
+        let msrp = MonetaryAmount::new(24_990, Currency::EUR);
+
+        assert!(!announced.changes(Some(&DeliveryDate::Year(2026)), Some(&msrp)));
+        assert!(announced.changes(Some(&DeliveryDate::Year(2025)), Some(&msrp)));
+        assert!(announced.changes(Some(&DeliveryDate::Year(2026)), None));
+
+        let unpriced = AnnouncedModel {
+            delivery_date: None,
+            msrp: None,
+            ..announced
+        };
+        assert!(!unpriced.changes(Some(&DeliveryDate::Year(2025)), Some(&msrp)));
+    }
+}
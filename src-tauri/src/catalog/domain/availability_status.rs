@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
@@ -28,6 +29,13 @@ pub enum AvailabilityStatus {
     Discontinued,
 }
 
+enum_metadata!(AvailabilityStatus {
+    Announced => ("Announced", "The railway model is just announced and not yet available."),
+    Available => ("Available", "The railway model is available for purchase."),
+    Cancelled => ("Cancelled", "The railway model production or release has been cancelled or delayed."),
+    Discontinued => ("Discontinued", "The railway model has been discontinued and is no longer produced."),
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
@@ -21,6 +22,11 @@ pub enum BodyShellType {
     MetalDieCast,
 }
 
+enum_metadata!(BodyShellType {
+    Plastic => ("Plastic", "Plastic body shell construction (typically injection moulded plastic)."),
+    MetalDieCast => ("Metal die-cast", "Metal die-cast body shell construction (heavier, metal cast components)."),
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
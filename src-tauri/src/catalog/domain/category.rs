@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
@@ -38,6 +39,19 @@ pub enum Category {
     Railcars,
 }
 
+impl Category {
+    /// Every `Category` variant, in declaration order.
+    pub const ALL: [Category; 7] = [
+        Category::Locomotives,
+        Category::TrainSets,
+        Category::StarterSets,
+        Category::FreightCars,
+        Category::PassengerCars,
+        Category::ElectricMultipleUnits,
+        Category::Railcars,
+    ];
+}
+
 /// High-level classification for different types of railway rolling stock.
 ///
 /// This categorization distinguishes between traction units, hauled vehicles,
@@ -307,6 +321,83 @@ pub enum RailcarType {
     TrailerCar,
 }
 
+enum_metadata!(Category {
+    Locomotives => ("Locomotives", "Independent traction units powered by steam, diesel, or electricity used to pull unpowered vehicles."),
+    TrainSets => ("Train sets", "Pre-configured groups of permanently or semi-permanently coupled vehicles, such as high-speed trains."),
+    StarterSets => ("Starter sets", "All-in-one entry-level packages typically including a train, track, and a power controller."),
+    FreightCars => ("Freight cars", "Vehicles designed for the transport of physical goods, raw materials, or equipment."),
+    PassengerCars => ("Passenger cars", "Vehicles designed for the transport of people, typically including seating, lighting, and climate control."),
+    ElectricMultipleUnits => ("Electric multiple units", "Self-propelled train sets consisting of multiple carriages using electricity as their motive power."),
+    Railcars => ("Railcars", "Lightweight, self-propelled vehicles (usually a single unit) designed for passenger service on branch lines."),
+});
+
+enum_metadata!(RollingStockCategory {
+    Locomotive => ("Locomotive", "Independent traction units used to haul unpowered vehicles, with steam, diesel, or electric motive power."),
+    FreightCar => ("Freight car", "Vehicles designed specifically for the transport of goods, raw materials, or heavy equipment."),
+    PassengerCar => ("Passenger car", "Vehicles designed for passenger transport, usually featuring interior seating and climate control."),
+    ElectricMultipleUnit => ("Electric multiple unit", "Self-propelled, multi-unit passenger trains that use electricity as their motive power (EMUs)."),
+    Railcar => ("Railcar", "Lightweight, self-propelled single vehicles designed for lower-capacity passenger service on branch lines."),
+});
+
+enum_metadata!(FreightCarType {
+    AutoTransportCars => ("Auto transport cars", "Specialized wagons for transporting motor vehicles, often multi-deck."),
+    BrakeWagon => ("Brake wagon", "A wagon equipped with a handbrake or a cabin for a brakeman, historically used to assist in braking the train."),
+    ContainerCars => ("Container cars", "Flat or skeleton-framed wagons designed specifically to carry standardized shipping containers (ISO containers)."),
+    CoveredFreightCars => ("Covered freight cars", "Fully enclosed wagons used for goods that must be protected from weather and theft."),
+    DeepWellFlatCars => ("Deep well flat cars", "Wagons with a lowered center section designed to carry tall loads like intermodal trailers or containers within height clearances."),
+    DumpCars => ("Dump cars", "Open-top wagons with a mechanism to tilt the body to unload bulk materials like sand or gravel."),
+    Gondola => ("Gondola", "Open-topped rail vehicles used for transporting loose bulk materials such as coal, ore, or scrap metal."),
+    HeavyGoodsWagons => ("Heavy goods wagons", "Heavy-duty flat wagons designed for extremely heavy or oversized loads, often featuring many axles."),
+    HingedCoverWagons => ("Hinged cover wagons", "Wagons with a roof that is hinged on one side, allowing for top-loading of weather-sensitive bulk goods."),
+    HopperWagon => ("Hopper wagon", "Wagons with a floor that slopes toward one or more discharge doors, used for the rapid unloading of bulk materials."),
+    RefrigeratorCars => ("Refrigerator cars", "Insulated wagons equipped with cooling systems for transporting perishable goods."),
+    SiloContainerCars => ("Silo container cars", "Specialized wagons for transporting pressurized or non-pressurized powders and granulated materials in silos."),
+    SlideTarpaulinWagon => ("Slide tarpaulin wagon", "Wagons with a flexible tarpaulin cover that slides open for easy side-loading of palletized goods."),
+    SlidingWallBoxcars => ("Sliding wall boxcars", "Boxcars with large sliding doors that make up the entire side of the wagon, allowing for forklift access."),
+    SpecialTransport => ("Special transport", "Wagons designed for niche cargo that does not fit into standard classifications."),
+    StakeWagons => ("Stake wagons", "Flat wagons equipped with vertical posts (stakes) along the sides to secure long loads like timber or pipes."),
+    SwingRoofWagon => ("Swing roof wagon", "Wagons with a roof that swings to the side to provide a wide opening for top-loading bulk cargo."),
+    TankCars => ("Tank cars", "Enclosed pressurized or non-pressurized vessels for transporting liquids, gases, or chemicals."),
+    TelescopeHoodWagons => ("Telescope hood wagons", "Wagons with several overlapping hoods that slide over each other, used for protecting steel coils or heavy machinery."),
+});
+
+enum_metadata!(LocomotiveType {
+    SteamLocomotive => ("Steam locomotive", "Locomotives powered by an external combustion engine, typically using a boiler to produce steam from coal, wood, or oil."),
+    DieselLocomotive => ("Diesel locomotive", "Locomotives powered by an internal combustion engine, usually driving an electric generator or a hydraulic transmission."),
+    ElectricLocomotive => ("Electric locomotive", "Locomotives that draw power from external sources, such as overhead catenary wires or a third rail."),
+});
+
+enum_metadata!(PassengerCarType {
+    BaggageCar => ("Baggage car", "A car usually placed between the locomotive and the rest of the train, featuring a wide-open interior for carrying checked baggage."),
+    BuffetCar => ("Buffet car", "A car providing limited food service (snacks/drinks) and a counter, smaller in scale than a full dining car."),
+    CombineCar => ("Combine car", "A hybrid car containing separate sections for both passengers and freight or baggage."),
+    CompartmentCoach => ("Compartment coach", "A coach featuring a side corridor connecting individual private compartments, each with face-to-face seating rows."),
+    DiningCar => ("Dining car", "A car dedicated to full-service meal preparation and seating for passengers."),
+    DoubleDecker => ("Double decker", "A car with two levels of passenger seating to increase capacity without increasing train length."),
+    DomeCar => ("Dome car", "A car with a glass-roofed section raised above the normal roofline, allowing 360-degree views of the scenery."),
+    DrivingTrailer => ("Driving trailer", "A control car equipped with a driver's cab, allowing the locomotive to be operated from the opposite end in a push-pull configuration."),
+    Lounge => ("Lounge", "A car featuring a bar and informal public seating, often used as a social space."),
+    Observation => ("Observation", "The final car of a train, often featuring large windows or an open rear platform for scenic viewing."),
+    OpenCoach => ("Open coach", "A coach with a central aisle and rows of seats similar to an airliner's cabin layout."),
+    RailwayPostOffice => ("Railway post office", "A specialized car used for sorting mail while in transit to expedite delivery."),
+    SleepingCar => ("Sleeping car", "A car with berths or private rooms for overnight travel, also known as a sleeper or Pullman car."),
+    Sleeperette => ("Sleeperette", "A couchette car, providing reclining seats or basic fold-down bunks for a more economical overnight option."),
+});
+
+enum_metadata!(ElectricMultipleUnitType {
+    DrivingCar => ("Driving car", "A car containing a driver's cab for controlling the train; an EMU usually has two driving cars at its outer ends."),
+    HighSpeedTrain => ("High-speed train", "A train running significantly faster than traditional rail, using specialised rolling stock and dedicated tracks."),
+    MotorCar => ("Motor car", "A car carrying the traction motors that move the train, often combined with the power car."),
+    PowerCar => ("Power car", "A car carrying the equipment to draw power from the electrified infrastructure, such as pantographs, pickup shoes, and transformers."),
+    TrailerCar => ("Trailer car", "A car carrying little or no traction or power related equipment, similar to a passenger car in a locomotive-hauled train."),
+    TrainSet => ("Train set", "A trainset working as a whole unit."),
+});
+
+enum_metadata!(RailcarType {
+    PowerCar => ("Power car", "A self-propelled passenger vehicle also capable of hauling a train."),
+    TrailerCar => ("Trailer car", "A car carrying little or no traction or power related equipment, similar to a passenger car in a locomotive-hauled train."),
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
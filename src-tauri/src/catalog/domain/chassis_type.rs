@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
@@ -21,6 +22,11 @@ pub enum ChassisType {
     MetalDieCast,
 }
 
+enum_metadata!(ChassisType {
+    Plastic => ("Plastic", "Plastic chassis construction (typically injection-moulded plastic)."),
+    MetalDieCast => ("Metal die-cast", "Metal die-cast chassis construction (heavier, metal cast chassis parts)."),
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
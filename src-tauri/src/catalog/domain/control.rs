@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::Deserialize;
 use serde::Serialize;
 use strum_macros;
@@ -46,6 +47,13 @@ impl Control {
     }
 }
 
+enum_metadata!(Control {
+    DccReady => ("DCC ready", "The model can be fitted with a DCC decoder."),
+    DccFitted => ("DCC fitted", "The model has a DCC decoder installed."),
+    DccSound => ("DCC sound", "The model has a DCC decoder installed with the sound module."),
+    NoDcc => ("No DCC", "The model has no DCC support (like no standard decoder plug)."),
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,7 @@
 use crate::catalog::domain::{CouplingSocket, FeatureFlag};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::Formatter;
 
 /// It represents the coupling configuration for a rolling stock.
 #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
@@ -13,6 +15,11 @@ pub struct Coupling {
 }
 
 impl Coupling {
+    /// Creates a builder for a coupling configuration, with every feature unknown
+    pub fn builder() -> CouplingBuilder {
+        CouplingBuilder::default()
+    }
+
     /// Creates a new rolling stock coupling configuration
     pub fn new(
         socket: CouplingSocket,
@@ -60,12 +67,88 @@ impl Coupling {
     pub fn digital_shunting(&self) -> Option<FeatureFlag> {
         self.digital_shunting
     }
+
+    /// true if the coupling configuration is known to include close couplers
+    pub fn has_close_couplers(&self) -> bool {
+        self.close_couplers == Some(FeatureFlag::Yes)
+    }
+
+    /// true if the coupling configuration is known to include digital shunting couplers
+    pub fn has_digital_shunting(&self) -> bool {
+        self.digital_shunting == Some(FeatureFlag::Yes)
+    }
+}
+
+impl fmt::Display for Coupling {
+    /// Formats the coupling as its socket followed by the features it has,
+    /// for example `"NEM 362, close couplers"`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::with_capacity(3);
+        match self.socket {
+            Some(CouplingSocket::None) => parts.push("no socket".to_string()),
+            Some(socket) => parts.push(socket.to_string().replace('_', " ")),
+            None => {}
+        }
+        if self.has_close_couplers() {
+            parts.push("close couplers".to_string());
+        }
+        if self.has_digital_shunting() {
+            parts.push("digital shunting".to_string());
+        }
+
+        if parts.is_empty() {
+            write!(f, "unknown coupling")
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
+}
+
+/// Builder for `Coupling` values, naming each feature instead of relying on
+/// the argument order of `Coupling::new`.
+///
+/// Features that are not set stay unknown (`None`).
+#[derive(Debug, Default)]
+pub struct CouplingBuilder {
+    socket: Option<CouplingSocket>,
+    close_couplers: Option<FeatureFlag>,
+    digital_shunting: Option<FeatureFlag>,
+}
+
+impl CouplingBuilder {
+    /// with the coupling socket
+    pub fn with_socket(mut self, socket: CouplingSocket) -> Self {
+        self.socket = Some(socket);
+        self
+    }
+
+    /// with the close couplers flag
+    pub fn with_close_couplers(mut self, close_couplers: FeatureFlag) -> Self {
+        self.close_couplers = Some(close_couplers);
+        self
+    }
+
+    /// with the digital shunting couplers flag
+    pub fn with_digital_shunting(mut self, digital_shunting: FeatureFlag) -> Self {
+        self.digital_shunting = Some(digital_shunting);
+        self
+    }
+
+    /// Build a new coupling configuration
+    pub fn build(self) -> Coupling {
+        Coupling {
+            socket: self.socket,
+            close_couplers: self.close_couplers,
+            digital_shunting: self.digital_shunting,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
+    use rstest::rstest;
 
     #[test]
     fn it_should_create_new_couplings() {
@@ -100,4 +183,85 @@ mod tests {
         assert_eq!(coupling.digital_shunting, Some(FeatureFlag::Yes));
         assert_eq!(coupling.close_couplers, Some(FeatureFlag::No));
     }
+
+    #[test]
+    fn it_should_build_couplings() {
+        let coupling = Coupling::builder()
+            .with_socket(CouplingSocket::Nem362)
+            .with_digital_shunting(FeatureFlag::Yes)
+            .with_close_couplers(FeatureFlag::No)
+            .build();
+
+        assert_eq!(
+            Coupling::new(CouplingSocket::Nem362, FeatureFlag::No, FeatureFlag::Yes),
+            coupling
+        );
+        assert!(coupling.has_digital_shunting());
+        assert!(!coupling.has_close_couplers());
+        assert_eq!(Coupling::default(), Coupling::builder().build());
+    }
+
+    #[test]
+    fn it_should_only_report_features_known_to_be_present() {
+        let coupling = Coupling::builder()
+            .with_close_couplers(FeatureFlag::NotApplicable)
+            .build();
+
+        assert!(!coupling.has_close_couplers());
+        assert!(!coupling.has_digital_shunting());
+        assert!(Coupling::with_close_couplers(CouplingSocket::Nem362).has_close_couplers());
+    }
+
+    #[rstest]
+    #[case(
+        Coupling::with_close_couplers(CouplingSocket::Nem362),
+        "NEM 362, close couplers"
+    )]
+    #[case(
+        Coupling::with_digital_shunting_couplers(),
+        "no socket, digital shunting"
+    )]
+    #[case(
+        Coupling::builder().with_socket(CouplingSocket::Nem355).build(),
+        "NEM 355"
+    )]
+    #[case(
+        Coupling::new(CouplingSocket::Nem362, FeatureFlag::Yes, FeatureFlag::Yes),
+        "NEM 362, close couplers, digital shunting"
+    )]
+    #[case(Coupling::default(), "unknown coupling")]
+    fn it_should_display_couplings(#[case] coupling: Coupling, #[case] expected: &str) {
+        assert_eq!(expected, coupling.to_string());
+    }
+
+    #[test]
+    fn it_should_keep_the_coupling_json_format() {
+        let json =
+            r#"{"socket":"NEM_362","close_couplers":"YES","digital_shunting":"NOT_APPLICABLE"}"#;
+
+        let coupling: Coupling = serde_json::from_str(json).expect("Invalid JSON value");
+
+        assert_eq!(
+            Coupling::builder()
+                .with_socket(CouplingSocket::Nem362)
+                .with_close_couplers(FeatureFlag::Yes)
+                .with_digital_shunting(FeatureFlag::NotApplicable)
+                .build(),
+            coupling
+        );
+        assert_eq!(json, serde_json::to_string(&coupling).unwrap());
+    }
+
+    #[test]
+    fn it_should_deserialize_couplings_with_missing_features() {
+        let coupling: Coupling = serde_json::from_str(r#"{"socket":"NONE","close_couplers":null}"#)
+            .expect("Invalid JSON value");
+
+        assert_eq!(
+            Coupling::builder()
+                .with_socket(CouplingSocket::None)
+                .build(),
+            coupling
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
@@ -58,6 +59,31 @@ pub enum CouplingSocket {
     Nem365,
 }
 
+impl CouplingSocket {
+    /// Every `CouplingSocket` variant, in declaration order.
+    pub const ALL: [CouplingSocket; 8] = [
+        CouplingSocket::None,
+        CouplingSocket::Nem355,
+        CouplingSocket::Nem356,
+        CouplingSocket::Nem357,
+        CouplingSocket::Nem359,
+        CouplingSocket::Nem360,
+        CouplingSocket::Nem362,
+        CouplingSocket::Nem365,
+    ];
+}
+
+enum_metadata!(CouplingSocket {
+    None => ("None", "The model has no coupling socket."),
+    Nem355 => ("NEM 355", "Receptacle for replaceable coupling heads in scales TT and N."),
+    Nem356 => ("NEM 356", "Coupler head for scale N."),
+    Nem357 => ("NEM 357", "Coupler head for scale N."),
+    Nem359 => ("NEM 359", "Coupler head for scale TT."),
+    Nem360 => ("NEM 360", "Standard coupling for scale H0."),
+    Nem362 => ("NEM 362", "NEM shaft 362 with close coupling mechanism."),
+    Nem365 => ("NEM 365", "Coupler head for scale 0."),
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
@@ -66,6 +67,35 @@ pub enum DccInterface {
     Mtc21,
 }
 
+impl DccInterface {
+    /// Every `DccInterface` variant, in declaration order.
+    pub const ALL: [DccInterface; 10] = [
+        DccInterface::Nem651,
+        DccInterface::Nem652,
+        DccInterface::Nem654,
+        DccInterface::Plux8,
+        DccInterface::Plux12,
+        DccInterface::Plux16,
+        DccInterface::Plux22,
+        DccInterface::Next18,
+        DccInterface::Next18S,
+        DccInterface::Mtc21,
+    ];
+}
+
+enum_metadata!(DccInterface {
+    Nem651 => ("NEM 651", "6 pin standard mechanical and electrical interface (NMRA Small)."),
+    Nem652 => ("NEM 652", "8 pin standard mechanical and electrical interface (NMRA Medium)."),
+    Nem654 => ("NEM 654", "4 pin standard mechanical and electrical interface (NMRA Large)."),
+    Plux8 => ("PluX8", "The PluX8 connector consists of two rows of 4 pins."),
+    Plux12 => ("PluX12", "The PluX12 connector consists of two rows of 6 pins."),
+    Plux16 => ("PluX16", "The PluX16 connector consists of two rows of 8 pins."),
+    Plux22 => ("PluX22", "The PluX22 connector consists of two rows of 11 pins."),
+    Next18 => ("Next18", "Standard connector for extremely tight applications, such as TT and N scale locomotives (NEM 662)."),
+    Next18S => ("Next18-S", "The Next18 connector with the sound outputs on the spare pins."),
+    Mtc21 => ("21MTC", "The 21 pin Marklin/Trix Connector, a standard adopted by both the NMRA and NEM (NEM 660)."),
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
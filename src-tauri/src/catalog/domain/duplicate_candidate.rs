@@ -0,0 +1,80 @@
+use crate::catalog::domain::railway_model_summary::RailwayModelSummary;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Two railway models from the same manufacturer and scale whose
+/// descriptions are so alike that they are probably the same model, for
+/// example imported from two sources.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct DuplicateCandidate {
+    /// One of the two models.
+    pub model: RailwayModelSummary,
+
+    /// The model that is probably a duplicate of `model`.
+    pub duplicate: RailwayModelSummary,
+
+    /// How alike the two descriptions are (see `description_similarity`),
+    /// from 0 (nothing in common) to 1 (the same once normalized).
+    pub similarity: f64,
+}
+
+/// The trigram similarity of two model descriptions, from 0 to 1.
+///
+/// Descriptions are compared case-insensitively and without punctuation, so
+/// "E.656" matches "e656". Each word is padded with two spaces in front and
+/// one behind before being split into trigrams, as PostgreSQL's `pg_trgm`
+/// does; the similarity is the share of trigrams the descriptions have in
+/// common. Two blank descriptions are not similar.
+pub fn description_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (trigrams(a), trigrams(b));
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+fn trigrams(description: &str) -> HashSet<[char; 3]> {
+    let normalized: String = description
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+
+    let mut trigrams = HashSet::new();
+    for word in normalized.split_whitespace() {
+        let padded: Vec<char> = "  "
+            .chars()
+            .chain(word.chars())
+            .chain(std::iter::once(' '))
+            .collect();
+        trigrams.extend(padded.windows(3).map(|w| [w[0], w[1], w[2]]));
+    }
+    trigrams
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn descriptions_are_normalized_before_comparing() {
+        assert_eq!(description_similarity("E.656 I serie", "e656 i SERIE"), 1.0);
+    }
+
+    #[test]
+    fn near_duplicates_score_higher_than_different_models() {
+        let near = description_similarity("FS E656 1a serie", "E.656 I serie");
+        let different = description_similarity("FS E656 1a serie", "DB BR 218 diesel");
+
+        assert!(near > 0.5, "near duplicates scored {near}");
+        assert!(different < 0.1, "different models scored {different}");
+    }
+
+    #[test]
+    fn blank_descriptions_are_not_similar() {
+        assert_eq!(description_similarity("", "  "), 0.0);
+        assert_eq!(description_similarity("E656", ""), 0.0);
+    }
+}
@@ -0,0 +1,156 @@
+//! Display metadata for the catalog enums.
+//!
+//! The frontend shows the catalog enums by a human label and a longer
+//! description rather than by their serialized values. Each enum implements
+//! `EnumMetadata`, mostly through `enum_metadata!`: its matches are
+//! exhaustive, so adding a variant fails to compile until it is described.
+
+use serde::{Deserialize, Serialize};
+
+/// A catalog enum whose variants carry a label and a description.
+pub trait EnumMetadata: Sized + Clone + 'static {
+    /// Every variant, in declaration order.
+    const VARIANTS: &'static [Self];
+
+    /// The short, human-cased label (e.g. "Freight cars"); the frontend
+    /// also uses it as the localization key.
+    fn label(&self) -> &'static str;
+
+    /// A sentence describing the variant.
+    fn description(&self) -> &'static str;
+
+    /// The variant with its label and description.
+    fn info(&self) -> EnumInfo<Self> {
+        EnumInfo {
+            value: self.clone(),
+            label: self.label().to_string(),
+            description: self.description().to_string(),
+        }
+    }
+
+    /// Every variant with its label and description, in declaration order.
+    fn infos() -> Vec<EnumInfo<Self>> {
+        Self::VARIANTS.iter().map(Self::info).collect()
+    }
+}
+
+/// An enum variant as shown in the catalog forms.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct EnumInfo<T> {
+    /// The variant, as stored and sent back to the backend.
+    pub value: T,
+
+    /// The short, human-cased label.
+    pub label: String,
+
+    /// A sentence describing the variant.
+    pub description: String,
+}
+
+/// Implement `EnumMetadata` for a unit-only enum from a
+/// `Variant => ("label", "description")` table listing every variant.
+macro_rules! enum_metadata {
+    ($enum:ty { $($variant:ident => ($label:literal, $description:literal)),+ $(,)? }) => {
+        impl $crate::catalog::domain::enum_info::EnumMetadata for $enum {
+            const VARIANTS: &'static [Self] = &[$(Self::$variant),+];
+
+            fn label(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $label),+
+                }
+            }
+
+            fn description(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $description),+
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use enum_metadata;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::domain::availability_status::AvailabilityStatus;
+    use crate::catalog::domain::category::{
+        Category, ElectricMultipleUnitType, FreightCarType, LocomotiveType, PassengerCarType,
+        RailcarType, RollingStockCategory,
+    };
+    use crate::catalog::domain::control::Control;
+    use crate::catalog::domain::coupling_socket::CouplingSocket;
+    use crate::catalog::domain::dcc_interface::DccInterface;
+    use crate::catalog::domain::railway_status::RailwayStatus;
+    use crate::catalog::domain::track_gauge::TrackGauge;
+    use crate::catalog::domain::{
+        BodyShellType, ChassisType, FeatureFlag, PowerMethod, Scale, ServiceLevel,
+    };
+    use std::collections::HashSet;
+    use std::fmt::Debug;
+
+    fn assert_described<T: EnumMetadata + Debug>() {
+        let mut labels = HashSet::new();
+        for variant in T::VARIANTS {
+            assert!(
+                !variant.label().trim().is_empty(),
+                "{variant:?} has no label"
+            );
+            assert!(
+                !variant.description().trim().is_empty(),
+                "{variant:?} has no description"
+            );
+            assert!(
+                labels.insert(variant.label()),
+                "{variant:?} repeats the label {}",
+                variant.label()
+            );
+        }
+    }
+
+    #[test]
+    fn every_variant_of_every_catalog_enum_is_described() {
+        assert_described::<AvailabilityStatus>();
+        assert_described::<BodyShellType>();
+        assert_described::<Category>();
+        assert_described::<ChassisType>();
+        assert_described::<Control>();
+        assert_described::<CouplingSocket>();
+        assert_described::<DccInterface>();
+        assert_described::<ElectricMultipleUnitType>();
+        assert_described::<FeatureFlag>();
+        assert_described::<FreightCarType>();
+        assert_described::<LocomotiveType>();
+        assert_described::<PassengerCarType>();
+        assert_described::<PowerMethod>();
+        assert_described::<RailcarType>();
+        assert_described::<RailwayStatus>();
+        assert_described::<RollingStockCategory>();
+        assert_described::<Scale>();
+        assert_described::<ServiceLevel>();
+        assert_described::<TrackGauge>();
+    }
+
+    #[test]
+    fn the_variants_match_the_all_constants() {
+        assert_eq!(Category::VARIANTS, Category::ALL);
+        assert_eq!(CouplingSocket::VARIANTS, CouplingSocket::ALL);
+        assert_eq!(DccInterface::VARIANTS, DccInterface::ALL);
+        assert_eq!(Scale::VARIANTS, Scale::ALL);
+    }
+
+    #[test]
+    fn it_should_serialize_enum_info() {
+        let info = FreightCarType::TankCars.info();
+
+        assert_eq!(
+            serde_json::to_value(&info).unwrap(),
+            serde_json::json!({
+                "value": "TANK_CARS",
+                "label": "Tank cars",
+                "description": "Enclosed pressurized or non-pressurized vessels for transporting liquids, gases, or chemicals."
+            })
+        );
+    }
+}
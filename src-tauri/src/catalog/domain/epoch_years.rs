@@ -0,0 +1,253 @@
+//! The approximate years of the modelling epochs, to tell whether a rolling
+//! stock of some epoch could have run for a railway company.
+//!
+//! Each railway dated the epochs somewhat differently (NEM 800 lists the
+//! dates for every administration), so the years are a rough guide: the
+//! checks built on them only ever warn.
+
+use crate::catalog::domain::epoch::{BaseEpoch, Epoch, EpochKind};
+use crate::catalog::domain::period_of_activity::PeriodOfActivity;
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// A span of years, from `since` up to `until` (excluded); open-ended when
+/// `until` is `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct YearRange {
+    pub since: i32,
+    pub until: Option<i32>,
+}
+
+impl YearRange {
+    /// Whether some year in this range is between the `since` and `until`
+    /// dates (both included); a missing date does not bound the period.
+    pub fn overlaps(&self, since: Option<NaiveDate>, until: Option<NaiveDate>) -> bool {
+        let starts_before_the_end = until.is_none_or(|until| self.since <= until.year());
+        let ends_after_the_start = match (self.until, since) {
+            (Some(end), Some(since)) => since.year() < end,
+            _ => true,
+        };
+        starts_before_the_end && ends_after_the_start
+    }
+}
+
+/// The table of the years of each base epoch.
+///
+/// The default table has the usual European dates; `EpochYears::new`
+/// replaces it, for example with the dates of a single administration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EpochYears {
+    ranges: Vec<(BaseEpoch, YearRange)>,
+}
+
+impl Default for EpochYears {
+    fn default() -> Self {
+        let range = |since, until| YearRange { since, until };
+        EpochYears::new(vec![
+            (BaseEpoch::I, range(1835, Some(1920))),
+            (BaseEpoch::II, range(1920, Some(1949))),
+            (BaseEpoch::III, range(1949, Some(1970))),
+            (BaseEpoch::IV, range(1970, Some(1990))),
+            (BaseEpoch::V, range(1990, Some(2006))),
+            (BaseEpoch::VI, range(2006, None)),
+        ])
+    }
+}
+
+impl EpochYears {
+    /// A table with the years of each base epoch in `ranges`; the epochs
+    /// left out have no years, so they are never checked.
+    pub fn new(ranges: Vec<(BaseEpoch, YearRange)>) -> Self {
+        Self { ranges }
+    }
+
+    fn base_years(&self, epoch: BaseEpoch) -> Option<YearRange> {
+        self.ranges
+            .iter()
+            .find(|(base, _)| *base == epoch)
+            .map(|(_, range)| *range)
+    }
+
+    /// The years of `epoch`, `None` for museum stock (which runs in any
+    /// year) and the epochs missing from the table.
+    ///
+    /// A half epoch takes the years of the whole one, and a range of epochs
+    /// spans from the start of the first to the end of the last.
+    pub fn years(&self, epoch: &EpochKind) -> Option<YearRange> {
+        match epoch {
+            EpochKind::Single { epoch, .. } => self.base_years(*epoch),
+            EpochKind::Range { start, end } => Some(YearRange {
+                since: self.base_years(*start)?.since,
+                until: self.base_years(*end)?.until,
+            }),
+            EpochKind::Museum => None,
+        }
+    }
+
+    /// The warning for a rolling stock with `rolling_stock_id` of a model
+    /// from `epoch`, assigned to the railway company with
+    /// `railway_company_id` active in `period_of_activity`, when the railway
+    /// company was not operating in any year of the epoch.
+    ///
+    /// Nothing is reported when the epoch cannot be parsed or has no years.
+    pub fn check_period_of_activity(
+        &self,
+        rolling_stock_id: &str,
+        epoch: &Epoch,
+        railway_company_id: &str,
+        period_of_activity: &PeriodOfActivity,
+    ) -> Option<ValidationWarning> {
+        let years = self.years(&EpochKind::try_from(epoch.0.as_str()).ok()?)?;
+        let operating_since = period_of_activity.operating_since().copied();
+        let operating_until = period_of_activity.operating_until().copied();
+
+        (!years.overlaps(operating_since, operating_until)).then(|| {
+            ValidationWarning::EpochOutsidePeriodOfActivity {
+                rolling_stock_id: rolling_stock_id.to_string(),
+                railway_company_id: railway_company_id.to_string(),
+                epoch: epoch.clone(),
+                epoch_years: years,
+                operating_since,
+                operating_until,
+            }
+        })
+    }
+}
+
+/// A catalog value unlikely to be right; the value is saved all the same.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ValidationWarning {
+    /// The railway company of the rolling stock was not operating during the
+    /// epoch of its model.
+    EpochOutsidePeriodOfActivity {
+        rolling_stock_id: String,
+        railway_company_id: String,
+        epoch: Epoch,
+        epoch_years: YearRange,
+        operating_since: Option<NaiveDate>,
+        operating_until: Option<NaiveDate>,
+    },
+
+    /// The road number of the rolling stock is likely mistyped (see
+    /// `validate_road_number`).
+    SuspiciousRoadNumber {
+        rolling_stock_id: String,
+        road_number: String,
+        reason: String,
+        suggestion: Option<String>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::domain::epoch::Half;
+    use crate::catalog::domain::railway_status::RailwayStatus;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    fn date(year: i32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, 6, 1).unwrap()
+    }
+
+    #[rstest]
+    #[case(EpochKind::Single { epoch: BaseEpoch::IV, half: None }, Some((1970, Some(1990))))]
+    #[case(EpochKind::Single { epoch: BaseEpoch::III, half: Some(Half::B) }, Some((1949, Some(1970))))]
+    #[case(EpochKind::Range { start: BaseEpoch::V, end: BaseEpoch::VI }, Some((1990, None)))]
+    #[case(EpochKind::Museum, None)]
+    fn epochs_have_their_years(
+        #[case] epoch: EpochKind,
+        #[case] expected: Option<(i32, Option<i32>)>,
+    ) {
+        let expected = expected.map(|(since, until)| YearRange { since, until });
+        assert_eq!(EpochYears::default().years(&epoch), expected);
+    }
+
+    #[test]
+    fn the_table_can_be_replaced() {
+        let years = EpochYears::new(vec![(
+            BaseEpoch::IV,
+            YearRange {
+                since: 1968,
+                until: Some(1985),
+            },
+        )]);
+        let iv = EpochKind::Single {
+            epoch: BaseEpoch::IV,
+            half: None,
+        };
+        let range = EpochKind::Range {
+            start: BaseEpoch::IV,
+            end: BaseEpoch::V,
+        };
+
+        assert_eq!(years.years(&iv).map(|r| r.since), Some(1968));
+        assert_eq!(years.years(&range), None);
+    }
+
+    #[rstest]
+    #[case(None, None, true)]
+    #[case(Some(date(1905)), None, true)]
+    #[case(Some(date(1990)), None, false)]
+    #[case(Some(date(1989)), None, true)]
+    #[case(None, Some(date(1970)), true)]
+    #[case(None, Some(date(1969)), false)]
+    #[case(Some(date(1949)), Some(date(1993)), true)]
+    fn ranges_overlap_periods(
+        #[case] since: Option<NaiveDate>,
+        #[case] until: Option<NaiveDate>,
+        #[case] expected: bool,
+    ) {
+        let iv = YearRange {
+            since: 1970,
+            until: Some(1990),
+        };
+        assert_eq!(iv.overlaps(since, until), expected);
+    }
+
+    #[test]
+    fn railways_closed_before_the_epoch_are_flagged() {
+        let years = EpochYears::default();
+        let db = PeriodOfActivity::inactive_railway(date(1949), date(1993));
+
+        assert_eq!(
+            years.check_period_of_activity("rs-1", &Epoch::from("IV"), "db", &db),
+            None
+        );
+        assert_eq!(
+            years.check_period_of_activity("rs-1", &Epoch::from("VI"), "db", &db),
+            Some(ValidationWarning::EpochOutsidePeriodOfActivity {
+                rolling_stock_id: "rs-1".to_string(),
+                railway_company_id: "db".to_string(),
+                epoch: Epoch::from("VI"),
+                epoch_years: YearRange {
+                    since: 2006,
+                    until: None,
+                },
+                operating_since: Some(date(1949)),
+                operating_until: Some(date(1993)),
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_epochs_and_periods_are_not_flagged() {
+        let years = EpochYears::default();
+        let unknown = PeriodOfActivity::new(None, None, RailwayStatus::Active).unwrap();
+        let db = PeriodOfActivity::inactive_railway(date(1949), date(1993));
+
+        assert_eq!(
+            years.check_period_of_activity("rs-1", &Epoch::from("VI"), "fs", &unknown),
+            None
+        );
+        assert_eq!(
+            years.check_period_of_activity("rs-1", &Epoch::from("Vm"), "db", &db),
+            None
+        );
+        assert_eq!(
+            years.check_period_of_activity("rs-1", &Epoch::from("?"), "db", &db),
+            None
+        );
+    }
+}
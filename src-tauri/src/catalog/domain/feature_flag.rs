@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
@@ -38,6 +39,12 @@ pub enum FeatureFlag {
     NotApplicable,
 }
 
+enum_metadata!(FeatureFlag {
+    Yes => ("Yes", "The feature is present and functional on the model."),
+    No => ("No", "The feature is not present on the model, though it might be expected or available on similar models."),
+    NotApplicable => ("Not applicable", "The feature is not relevant for this type of equipment."),
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,286 @@
+//! Calculators for planning a layout: gradients, vertical clearances and
+//! turnout ladders.
+//!
+//! Lengths may be given in any unit; they are converted to millimeters
+//! before being compared or combined.
+
+use crate::catalog::domain::Scale;
+use crate::core::domain::length::Length;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Why a layout calculation has no meaningful result.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LayoutCalcError {
+    /// A gradient climbs over some distance.
+    #[error("the run of a gradient must be longer than zero")]
+    ZeroRun,
+
+    /// A named input was negative.
+    #[error("the {0} cannot be negative")]
+    NegativeLength(&'static str),
+
+    /// A ladder connects at least two tracks.
+    #[error("a turnout ladder needs at least two tracks (value: {0})")]
+    TooFewTracks(u32),
+
+    /// Turnouts are described by the `n` of their `1:n` frog.
+    #[error("the frog number must be positive (value: {0})")]
+    NonPositiveFrogNumber(Decimal),
+
+    /// The inputs are so large that the result does not fit a `Decimal`.
+    #[error("the result is too large to calculate")]
+    Overflow,
+}
+
+/// The gradient of a track climbing `rise` over `run`, as a percentage.
+///
+/// # Errors
+///
+/// Returns `LayoutCalcError::ZeroRun` when `run` is zero,
+/// `LayoutCalcError::NegativeLength` when either length is negative and
+/// `LayoutCalcError::Overflow` when the gradient is too steep to calculate.
+pub fn gradient_percent(rise: Length, run: Length) -> Result<Decimal, LayoutCalcError> {
+    let rise = millimeters("rise", rise)?;
+    let run = millimeters("run", run)?;
+    if run.is_zero() {
+        return Err(LayoutCalcError::ZeroRun);
+    }
+    rise.checked_div(run)
+        .and_then(|ratio| ratio.checked_mul(dec!(100)))
+        .ok_or(LayoutCalcError::Overflow)
+}
+
+/// The minimum height above the top of the rail an overhead structure
+/// leaves to the trains of `scale`, in millimeters.
+///
+/// The values are derived from the NEM 102 clearance profiles and rounded
+/// up to the millimeter.
+pub fn minimum_vertical_clearance_mm(scale: &Scale) -> Decimal {
+    match scale {
+        Scale::H0 => dec!(65),
+        Scale::H0m => dec!(58),
+        Scale::H0e => dec!(45),
+        Scale::N => dec!(37),
+        Scale::TT => dec!(49),
+        Scale::Z => dec!(27),
+        Scale::G => dec!(230),
+        Scale::Scale1 => dec!(170),
+        Scale::Scale0 => dec!(125),
+        Scale::Scale00 => dec!(65),
+    }
+}
+
+/// A vertical clearance checked against the minimum of a scale.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct ClearanceCheck {
+    /// The clearance checked, in millimeters.
+    pub clearance_mm: f64,
+
+    /// The minimum clearance of the scale, in millimeters.
+    pub minimum_mm: f64,
+
+    /// Whether the clearance is at least the minimum.
+    pub fits: bool,
+}
+
+/// Check that an overhead structure `clearance` above the rails lets the
+/// trains of `scale` through.
+///
+/// # Errors
+///
+/// Returns `LayoutCalcError::NegativeLength` when `clearance` is negative.
+pub fn check_vertical_clearance(
+    clearance: Length,
+    scale: &Scale,
+) -> Result<ClearanceCheck, LayoutCalcError> {
+    let clearance = millimeters("clearance", clearance)?;
+    let minimum = minimum_vertical_clearance_mm(scale);
+    Ok(ClearanceCheck {
+        clearance_mm: to_f64(clearance),
+        minimum_mm: to_f64(minimum),
+        fits: clearance >= minimum,
+    })
+}
+
+/// The length, along the straight track, of a ladder of turnouts fanning
+/// out into `tracks` parallel tracks `track_spacing` apart.
+///
+/// Each turnout has a `1:frog_number` frog and is `turnout_length` long:
+/// consecutive turnouts are `track_spacing * frog_number` apart and the
+/// last one adds its own length.
+///
+/// # Errors
+///
+/// Returns `LayoutCalcError::TooFewTracks` for less than two tracks,
+/// `LayoutCalcError::NonPositiveFrogNumber` when `frog_number` is not
+/// positive, `LayoutCalcError::NegativeLength` when a length is negative
+/// and `LayoutCalcError::Overflow` when the ladder is too long to
+/// calculate.
+pub fn turnout_ladder_length(
+    tracks: u32,
+    turnout_length: Length,
+    frog_number: Decimal,
+    track_spacing: Length,
+) -> Result<Decimal, LayoutCalcError> {
+    if tracks < 2 {
+        return Err(LayoutCalcError::TooFewTracks(tracks));
+    }
+    if frog_number <= Decimal::ZERO {
+        return Err(LayoutCalcError::NonPositiveFrogNumber(frog_number));
+    }
+    let turnout_length = millimeters("turnout length", turnout_length)?;
+    let track_spacing = millimeters("track spacing", track_spacing)?;
+    Decimal::from(tracks - 2)
+        .checked_mul(track_spacing)
+        .and_then(|offset| offset.checked_mul(frog_number))
+        .and_then(|offset| offset.checked_add(turnout_length))
+        .ok_or(LayoutCalcError::Overflow)
+}
+
+/// `length` in millimeters, failing when it is negative.
+fn millimeters(name: &'static str, length: Length) -> Result<Decimal, LayoutCalcError> {
+    let millimeters = length.as_millimeters();
+    if millimeters < Decimal::ZERO {
+        return Err(LayoutCalcError::NegativeLength(name));
+    }
+    Ok(millimeters)
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn gradients_mix_units() {
+        assert_eq!(
+            gradient_percent(Length::Millimeters(dec!(50)), Length::Meters(dec!(2))),
+            Ok(dec!(2.5))
+        );
+        assert_eq!(
+            gradient_percent(Length::Inches(dec!(1)), Length::Millimeters(dec!(1270))),
+            Ok(dec!(2))
+        );
+    }
+
+    #[test]
+    fn gradients_need_a_run_and_a_rise() {
+        assert_eq!(
+            gradient_percent(Length::Millimeters(dec!(50)), Length::Meters(dec!(0))),
+            Err(LayoutCalcError::ZeroRun)
+        );
+        assert_eq!(
+            gradient_percent(Length::Millimeters(dec!(-5)), Length::Meters(dec!(1))),
+            Err(LayoutCalcError::NegativeLength("rise"))
+        );
+    }
+
+    #[test]
+    fn gradients_too_steep_to_calculate_overflow() {
+        assert_eq!(
+            gradient_percent(
+                Length::Kilometers(Decimal::MAX),
+                Length::Millimeters(dec!(0.5))
+            ),
+            Err(LayoutCalcError::Overflow)
+        );
+        assert_eq!(
+            gradient_percent(
+                Length::Millimeters(Decimal::MAX),
+                Length::Millimeters(dec!(1))
+            ),
+            Err(LayoutCalcError::Overflow)
+        );
+    }
+
+    #[test]
+    fn clearances_are_checked_against_the_scale_minimum() {
+        let check = check_vertical_clearance(Length::Millimeters(dec!(70)), &Scale::H0).unwrap();
+        assert_eq!(
+            check,
+            ClearanceCheck {
+                clearance_mm: 70.0,
+                minimum_mm: 65.0,
+                fits: true,
+            }
+        );
+
+        let check = check_vertical_clearance(Length::Inches(dec!(1)), &Scale::N).unwrap();
+        assert_eq!(check.clearance_mm, 25.4);
+        assert!(!check.fits);
+        assert_eq!(
+            check_vertical_clearance(Length::Millimeters(dec!(-1)), &Scale::N),
+            Err(LayoutCalcError::NegativeLength("clearance"))
+        );
+    }
+
+    #[test]
+    fn ladders_grow_with_the_tracks_and_the_frog_number() {
+        // Four tracks 50 mm apart: three 1:6 turnouts 300 mm apart, the
+        // last one 220 mm long.
+        assert_eq!(
+            turnout_ladder_length(
+                4,
+                Length::Millimeters(dec!(220)),
+                dec!(6),
+                Length::Meters(dec!(0.05)),
+            ),
+            Ok(dec!(820))
+        );
+        assert_eq!(
+            turnout_ladder_length(
+                2,
+                Length::Millimeters(dec!(220)),
+                dec!(6),
+                Length::Millimeters(dec!(50)),
+            ),
+            Ok(dec!(220))
+        );
+    }
+
+    #[test]
+    fn ladders_need_two_tracks_and_a_frog() {
+        let turnout = Length::Millimeters(dec!(220));
+        let spacing = Length::Millimeters(dec!(50));
+
+        assert_eq!(
+            turnout_ladder_length(1, turnout, dec!(6), spacing),
+            Err(LayoutCalcError::TooFewTracks(1))
+        );
+        assert_eq!(
+            turnout_ladder_length(3, turnout, Decimal::ZERO, spacing),
+            Err(LayoutCalcError::NonPositiveFrogNumber(Decimal::ZERO))
+        );
+        assert_eq!(
+            turnout_ladder_length(3, turnout, dec!(6), Length::Millimeters(dec!(-50))),
+            Err(LayoutCalcError::NegativeLength("track spacing"))
+        );
+    }
+
+    #[test]
+    fn ladders_too_long_to_calculate_overflow() {
+        let spacing = Length::Kilometers(Decimal::MAX);
+
+        assert_eq!(
+            turnout_ladder_length(3, Length::Millimeters(dec!(220)), dec!(6), spacing),
+            Err(LayoutCalcError::Overflow)
+        );
+        assert_eq!(
+            turnout_ladder_length(
+                3,
+                Length::Millimeters(Decimal::MAX),
+                dec!(1),
+                Length::Millimeters(dec!(50)),
+            ),
+            Err(LayoutCalcError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Domain types for the liveries of a railway company.
+//!
+//! A livery belongs to one railway company (the FS "XMPR" is not the same
+//! livery as a DB one with a similar name) and was applied during a period
+//! of use. Rolling stocks may reference their livery; the ones that do not
+//! keep the free text description they were imported with.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The period a livery was applied in; either end may be unknown.
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Default, specta::Type)]
+pub struct PeriodOfUse {
+    /// the date when the livery was first applied
+    pub since: Option<NaiveDate>,
+    /// the date when the livery was last applied, if not in use anymore
+    pub until: Option<NaiveDate>,
+}
+
+impl PeriodOfUse {
+    /// Creates a new period of use
+    pub fn new(since: Option<NaiveDate>, until: Option<NaiveDate>) -> Result<Self, LiveryError> {
+        match (since, until) {
+            (Some(since), Some(until)) if since >= until => {
+                Err(LiveryError::UntilDateBeforeSinceDate)
+            }
+            _ => Ok(PeriodOfUse { since, until }),
+        }
+    }
+}
+
+/// A livery of a railway company.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct Livery {
+    /// Unique identifier for this livery (e.g. UUID in the DB).
+    pub id: String,
+
+    /// The railway company that applied this livery.
+    pub railway_company_id: String,
+
+    /// The canonical name of the livery, for example "XMPR" or
+    /// "Castano/Isabella".
+    pub name: String,
+
+    /// A longer description of the colours and markings.
+    pub description: Option<String>,
+
+    /// When the livery was applied.
+    pub period_of_use: PeriodOfUse,
+}
+
+/// The fields of a livery the collector enters, to create or update one.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct LiveryDetails {
+    /// The railway company that applied the livery.
+    pub railway_company_id: String,
+
+    /// The canonical name of the livery.
+    pub name: String,
+
+    /// A longer description of the colours and markings.
+    pub description: Option<String>,
+
+    /// When the livery was applied.
+    pub period_of_use: PeriodOfUse,
+}
+
+impl Livery {
+    /// A livery with `id` and `details`, trimming the entered text and
+    /// treating a blank description as missing.
+    ///
+    /// Fails with `LiveryError::BlankName` when the name is blank and with
+    /// `LiveryError::UntilDateBeforeSinceDate` when the period of use ends
+    /// before it starts.
+    pub fn new(id: String, details: LiveryDetails) -> Result<Self, LiveryError> {
+        let name = details.name.trim().to_string();
+        if name.is_empty() {
+            return Err(LiveryError::BlankName);
+        }
+        let period_of_use =
+            PeriodOfUse::new(details.period_of_use.since, details.period_of_use.until)?;
+        Ok(Self {
+            id,
+            railway_company_id: details.railway_company_id,
+            name,
+            description: details
+                .description
+                .map(|d| d.trim().to_string())
+                .filter(|d| !d.is_empty()),
+            period_of_use,
+        })
+    }
+}
+
+/// Why a livery cannot be stored or applied.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LiveryError {
+    /// Liveries are looked up by name.
+    #[error("the livery name cannot be blank")]
+    BlankName,
+
+    #[error("the in use since date must happen before the until date")]
+    UntilDateBeforeSinceDate,
+
+    /// A rolling stock can only wear the liveries of its railway company.
+    #[error(
+        "the livery {livery_id} belongs to another railway company than the rolling stock {rolling_stock_id}"
+    )]
+    OtherRailwayCompany {
+        livery_id: String,
+        rolling_stock_id: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn details(name: &str, since: Option<NaiveDate>, until: Option<NaiveDate>) -> LiveryDetails {
+        LiveryDetails {
+            railway_company_id: "fs".to_string(),
+            name: name.to_string(),
+            description: Some("  ".to_string()),
+            period_of_use: PeriodOfUse { since, until },
+        }
+    }
+
+    fn date(year: i32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, 1, 1).unwrap()
+    }
+
+    #[test]
+    fn liveries_are_trimmed() {
+        let livery =
+            Livery::new("l1".to_string(), details(" XMPR ", Some(date(1995)), None)).unwrap();
+
+        assert_eq!(livery.name, "XMPR");
+        assert_eq!(livery.description, None);
+        assert_eq!(livery.period_of_use.since, Some(date(1995)));
+    }
+
+    #[test]
+    fn liveries_need_a_name_and_a_valid_period() {
+        assert_eq!(
+            Livery::new("l1".to_string(), details(" ", None, None)),
+            Err(LiveryError::BlankName)
+        );
+        assert_eq!(
+            Livery::new(
+                "l1".to_string(),
+                details("XMPR", Some(date(2000)), Some(date(1995)))
+            ),
+            Err(LiveryError::UntilDateBeforeSinceDate)
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod announcement;
 pub mod availability_status;
 pub mod body_shell_type;
 pub mod category;
@@ -7,24 +8,40 @@ pub mod coupling;
 pub mod coupling_socket;
 pub mod dcc_interface;
 pub mod delivery_date;
+pub mod duplicate_candidate;
+pub mod enum_info;
 pub mod epoch;
+pub mod epoch_years;
 pub mod feature_flag;
+pub mod layout;
 pub mod length_over_buffers;
+pub mod livery;
+pub mod model_merge;
 pub mod period_of_activity;
 pub mod power_method;
 pub mod product_code;
+pub mod product_code_lookup;
+pub mod product_code_validation;
 pub mod radius;
 pub mod railway_company;
 pub mod railway_id;
 pub mod railway_model;
 pub mod railway_model_id;
+pub mod railway_model_list_entry;
+pub mod railway_model_summary;
 pub mod railway_status;
 pub mod ratio;
+pub mod reference_data;
+pub mod repository;
+pub mod road_number;
 pub mod rolling_stock;
+pub mod rolling_stock_comparison;
 pub mod rolling_stock_id;
 pub mod rolling_stock_railway;
 pub mod scale;
 pub mod scale_gauge;
+pub mod scale_speed;
+pub mod scale_standards;
 pub mod service_level;
 pub mod technical_specifications;
 pub mod track_gauge;
@@ -42,7 +59,7 @@ pub use service_level::ServiceLevel;
 
 pub use body_shell_type::BodyShellType;
 pub use chassis_type::ChassisType;
-pub use coupling::Coupling;
+pub use coupling::{Coupling, CouplingBuilder};
 pub use coupling_socket::CouplingSocket;
 pub use feature_flag::FeatureFlag;
 pub use radius::Radius;
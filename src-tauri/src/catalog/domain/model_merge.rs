@@ -0,0 +1,34 @@
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// What merging a duplicate railway model into the one kept changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ModelMerge {
+    /// The model that was kept.
+    pub kept_id: RailwayModelId,
+
+    /// The duplicate, which no longer exists.
+    pub removed_id: RailwayModelId,
+
+    /// How many collection items now reference the kept model instead.
+    pub collection_items_moved: u32,
+
+    /// How many rolling stocks were moved to the kept model.
+    pub rolling_stocks_moved: u32,
+}
+
+/// Why two railway models cannot be merged.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MergeError {
+    /// A model cannot be merged into itself.
+    #[error("cannot merge railway model {0} into itself")]
+    SameModel(RailwayModelId),
+
+    /// Models from different manufacturers are never duplicates.
+    #[error("railway models {keep_id} and {remove_id} are from different manufacturers")]
+    DifferentManufacturers {
+        keep_id: RailwayModelId,
+        remove_id: RailwayModelId,
+    },
+}
@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
@@ -20,6 +21,12 @@ pub enum PowerMethod {
     TrixExpress,
 }
 
+enum_metadata!(PowerMethod {
+    AC => ("AC", "Alternating current (AC) power collection."),
+    DC => ("DC", "Direct current (DC) power collection."),
+    TrixExpress => ("Trix Express", "Trix Express three-rail power pickup system."),
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
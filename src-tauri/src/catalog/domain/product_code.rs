@@ -14,6 +14,12 @@ use std::ops::Deref;
 /// - The product code MUST be a non-empty, non-blank string. Constructions via
 ///   `TryFrom<&str>` / `TryFrom<String>` will return an error if the input is
 ///   empty or contains only whitespace.
+/// - The product code MUST NOT contain control characters.
+///
+/// Normalization
+/// - Leading and trailing whitespace is trimmed on construction.
+/// - Product codes are compared case-insensitively when checking for
+///   duplicates: `"hr2001"` and `"HR2001"` identify the same catalogue item.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
 #[serde(transparent)]
 #[specta(transparent)]
@@ -31,9 +37,13 @@ impl TryFrom<&str> for ProductCode {
     type Error = anyhow::Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.trim().is_empty() {
+        let value = value.trim();
+        if value.is_empty() {
             return Err(anyhow!("product code must not be empty"));
         }
+        if value.chars().any(char::is_control) {
+            return Err(anyhow!("product code must not contain control characters"));
+        }
         Ok(ProductCode(value.to_owned()))
     }
 }
@@ -42,10 +52,7 @@ impl TryFrom<String> for ProductCode {
     type Error = anyhow::Error;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        if value.trim().is_empty() {
-            return Err(anyhow!("product code must not be empty"));
-        }
-        Ok(ProductCode(value))
+        ProductCode::try_from(value.as_str())
     }
 }
 
@@ -81,6 +88,18 @@ mod tests {
         assert!(msg.contains("must not be empty"));
     }
 
+    #[test]
+    fn try_from_trims_surrounding_whitespace() {
+        let product_code = ProductCode::try_from("  HR2001 ").unwrap();
+        assert_eq!(product_code.0, "HR2001");
+    }
+
+    #[test]
+    fn try_from_control_characters_fails() {
+        let err = ProductCode::try_from("HR\t2001").expect_err("control characters should fail");
+        assert!(format!("{}", err).contains("control characters"));
+    }
+
     #[test]
     fn display_outputs_inner_string() {
         let product_code = ProductCode::try_from("X-1").unwrap();
@@ -0,0 +1,163 @@
+//! Looking up a list of product codes in the catalog and the collection.
+//!
+//! A collector pastes the product codes of an order confirmation or a
+//! dealer's list and wants to know, for each, whether the model is in the
+//! catalog and whether it is already owned. Manufacturer names and product
+//! codes are compared ignoring case and surrounding blanks, the same way
+//! announcements are matched.
+
+use crate::catalog::domain::ProductCode;
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use thiserror::Error;
+
+/// How many product codes one lookup accepts.
+pub const MAX_LOOKUP_CODES: usize = 500;
+
+/// A product code to look up.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ProductCodeQuery {
+    /// The name of the manufacturer, as stored in the catalog.
+    pub manufacturer: String,
+
+    /// The manufacturer's product code.
+    pub product_code: ProductCode,
+}
+
+impl ProductCodeQuery {
+    /// The key two queries for the same model share.
+    pub fn key(&self) -> (String, String) {
+        lookup_key(&self.manufacturer, &self.product_code)
+    }
+}
+
+/// The key of the manufacturer and product code pair, ignoring case and
+/// surrounding blanks.
+pub fn lookup_key(manufacturer: &str, product_code: &str) -> (String, String) {
+    (
+        manufacturer.trim().to_lowercase(),
+        product_code.trim().to_lowercase(),
+    )
+}
+
+/// What the catalog and the collection know about a product code.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ProductCodeLookup {
+    /// The manufacturer, as it was looked up.
+    pub manufacturer: String,
+
+    /// The product code, as it was looked up.
+    pub product_code: ProductCode,
+
+    /// The railway model with this product code, if the catalog has it.
+    pub railway_model_id: Option<RailwayModelId>,
+
+    /// Whether a collection item already refers to the railway model.
+    pub owned: bool,
+}
+
+impl ProductCodeLookup {
+    /// Classify each of `queries`, in order, given the railway model ids of
+    /// the `matches` (by `lookup_key`) and the ids of the `owned` railway
+    /// models.
+    pub fn classify(
+        queries: Vec<ProductCodeQuery>,
+        matches: &HashMap<(String, String), RailwayModelId>,
+        owned: &HashSet<RailwayModelId>,
+    ) -> Vec<ProductCodeLookup> {
+        queries
+            .into_iter()
+            .map(|query| {
+                let railway_model_id = matches.get(&query.key()).cloned();
+                let owned = railway_model_id
+                    .as_ref()
+                    .is_some_and(|id| owned.contains(id));
+                ProductCodeLookup {
+                    manufacturer: query.manufacturer,
+                    product_code: query.product_code,
+                    railway_model_id,
+                    owned,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Check the product codes before anything is looked up.
+///
+/// # Errors
+///
+/// `LookupError::TooManyCodes` when there are more than `MAX_LOOKUP_CODES`.
+pub fn validate_lookup(queries: &[ProductCodeQuery]) -> Result<(), LookupError> {
+    if queries.len() > MAX_LOOKUP_CODES {
+        return Err(LookupError::TooManyCodes(queries.len()));
+    }
+    Ok(())
+}
+
+/// Why product codes cannot be looked up.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LookupError {
+    #[error("{0} product codes cannot be looked up at once, the limit is {MAX_LOOKUP_CODES}")]
+    TooManyCodes(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn query(manufacturer: &str, product_code: &str) -> ProductCodeQuery {
+        ProductCodeQuery {
+            manufacturer: manufacturer.to_string(),
+            product_code: ProductCode(product_code.to_string()),
+        }
+    }
+
+    #[test]
+    fn it_should_classify_each_query_in_order() {
+        let known = RailwayModelId::new();
+        let owned_model = RailwayModelId::new();
+        let matches = HashMap::from([
+            (lookup_key("ACME", "60211"), known),
+            (lookup_key("ACME", "60212"), owned_model),
+        ]);
+        let owned = HashSet::from([owned_model]);
+
+        let lookups = ProductCodeLookup::classify(
+            vec![
+                query("acme ", "60211"),
+                query("ACME", "99999"),
+                query("Acme", " 60212"),
+            ],
+            &matches,
+            &owned,
+        );
+
+        let classified: Vec<(Option<RailwayModelId>, bool)> = lookups
+            .iter()
+            .map(|lookup| (lookup.railway_model_id, lookup.owned))
+            .collect();
+        assert_eq!(
+            classified,
+            vec![
+                (Some(known), false),
+                (None, false),
+                (Some(owned_model), true)
+            ]
+        );
+        assert_eq!(lookups[0].manufacturer, "acme ");
+    }
+
+    #[test]
+    fn it_should_limit_the_product_codes_of_a_lookup() {
+        let queries = vec![query("ACME", "60211"); MAX_LOOKUP_CODES + 1];
+
+        assert_eq!(validate_lookup(&queries[1..]), Ok(()));
+        assert_eq!(
+            validate_lookup(&queries),
+            Err(LookupError::TooManyCodes(MAX_LOOKUP_CODES + 1))
+        );
+    }
+}
@@ -0,0 +1,23 @@
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use serde::{Deserialize, Serialize};
+
+/// The outcome of checking a product code before creating a railway model.
+///
+/// Used by the "add model" form to give inline feedback; the checks use the
+/// same normalization as `ProductCode::try_from`, so a code reported as
+/// `Available` is accepted on creation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub enum ProductCodeValidation {
+    /// No model from the manufacturer uses this product code.
+    Available,
+
+    /// A model from the same manufacturer already uses this product code
+    /// (compared case-insensitively); carries its id and description.
+    Duplicate {
+        existing_model_id: RailwayModelId,
+        description: String,
+    },
+
+    /// The product code is not well-formed; `reason` explains why.
+    InvalidFormat { reason: String },
+}
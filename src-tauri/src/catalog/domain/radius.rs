@@ -1,7 +1,10 @@
 // src-tauri/src/catalog/domain/radius.rs
 use crate::core::domain::length::Length;
+use crate::core::domain::measure_units::MeasureUnit;
 use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Formatter;
 use thiserror::Error;
@@ -9,11 +12,34 @@ use thiserror::Error;
 /// Domain types for handling minimum drivable radii.
 ///
 /// A `Radius` wraps a `Length` expressed in millimeters and enforces
-/// that the value is non-negative.
+/// that the value is non-negative. Radii given in inches are converted to
+/// millimeters on construction, so a radius is always stored (and
+/// serialized) in millimeters; read it back with `from_millimeters`.
+///
+/// Radii compare like lengths: values less than `LENGTH_TOLERANCE_MM`
+/// apart are equal.
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, specta::Type)]
 pub struct Radius(#[serde(with = "crate::core::domain::length::serde::millimeters")] Length);
 
 impl Radius {
+    /// The first radius of H0 sectional track (360 mm).
+    pub const H0_R1: Radius = Radius(Length::Millimeters(dec!(360)));
+
+    /// The second radius of H0 sectional track (437.5 mm).
+    pub const H0_R2: Radius = Radius(Length::Millimeters(dec!(437.5)));
+
+    /// The third radius of H0 sectional track (515 mm).
+    pub const H0_R3: Radius = Radius(Length::Millimeters(dec!(515)));
+
+    /// The first radius of N sectional track (192 mm).
+    pub const N_R1: Radius = Radius(Length::Millimeters(dec!(192)));
+
+    /// The second radius of N sectional track (225.6 mm).
+    pub const N_R2: Radius = Radius(Length::Millimeters(dec!(225.6)));
+
+    /// The third radius of N sectional track (258.6 mm).
+    pub const N_R3: Radius = Radius(Length::Millimeters(dec!(258.6)));
+
     /// Create a new `Radius` from a millimeters value.
     ///
     /// The provided `value` is interpreted as millimeters. Returns
@@ -31,12 +57,53 @@ impl Radius {
         }
     }
 
+    /// Create a new `Radius` from an inches value, converted to millimeters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RadiusError::NegativeRadius` when `value` is negative.
+    pub fn from_inches(value: Decimal) -> Result<Self, RadiusError> {
+        Self::from_millimeters(MeasureUnit::Inches.to_millimeters(value))
+    }
+
     /// Return the underlying `Length` for this radius.
     ///
     /// The returned `Length` is expressed in millimeters.
     pub fn value(&self) -> Length {
         self.0
     }
+
+    /// This radius in millimeters.
+    pub fn as_millimeters(&self) -> Decimal {
+        self.0.get_value_as(MeasureUnit::Millimeters)
+    }
+
+    /// This radius in inches.
+    pub fn as_inches(&self) -> Decimal {
+        self.0.get_value_as(MeasureUnit::Inches)
+    }
+
+    /// Whether a model with this minimum radius can run on curves of the
+    /// `available` radius, that is when `available` is not tighter.
+    pub fn fits_on(&self, available: Radius) -> bool {
+        *self <= available
+    }
+}
+
+impl PartialOrd for Radius {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Radius {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self == other {
+            Ordering::Equal
+        } else {
+            self.as_millimeters().cmp(&other.as_millimeters())
+        }
+    }
 }
 
 impl fmt::Display for Radius {
@@ -74,10 +141,75 @@ mod tests {
         assert_eq!(Err(RadiusError::NegativeRadius), result);
     }
 
+    #[test]
+    fn it_should_create_a_radius_from_inches() {
+        let radius = Radius::from_inches(dec!(10)).expect("unable to create the radius");
+        assert_eq!(dec!(254.0), radius.as_millimeters());
+        assert_eq!(Length::Millimeters(dec!(254.0)), radius.value());
+        assert_eq!(
+            Err(RadiusError::NegativeRadius),
+            Radius::from_inches(dec!(-1))
+        );
+    }
+
+    #[test]
+    fn it_should_convert_a_radius_between_units() {
+        let radius = Radius::from_millimeters(dec!(360)).unwrap();
+
+        let round_trip = Radius::from_inches(radius.as_inches()).unwrap();
+
+        assert_eq!(radius, round_trip);
+        assert_eq!(
+            radius,
+            Radius::from_millimeters(round_trip.as_millimeters()).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_should_order_the_standard_radii() {
+        let mut radii = vec![
+            Radius::H0_R3,
+            Radius::N_R2,
+            Radius::H0_R1,
+            Radius::N_R3,
+            Radius::H0_R2,
+            Radius::N_R1,
+        ];
+        radii.sort();
+
+        assert_eq!(
+            vec![
+                Radius::N_R1,
+                Radius::N_R2,
+                Radius::N_R3,
+                Radius::H0_R1,
+                Radius::H0_R2,
+                Radius::H0_R3,
+            ],
+            radii
+        );
+        assert_eq!(dec!(437.5), Radius::H0_R2.as_millimeters());
+    }
+
+    #[test]
+    fn it_should_check_whether_a_radius_fits_on_a_curve() {
+        let minimum_radius = Radius::from_millimeters(dec!(437.5)).unwrap();
+
+        assert!(minimum_radius.fits_on(Radius::H0_R2));
+        assert!(minimum_radius.fits_on(Radius::H0_R3));
+        assert!(!minimum_radius.fits_on(Radius::H0_R1));
+        assert!(
+            Radius::from_inches(dec!(14.17))
+                .unwrap()
+                .fits_on(Radius::H0_R1)
+        );
+    }
+
     #[test]
     fn it_should_display_a_radius() {
         let radius = Radius::from_millimeters(dec!(360.0)).unwrap();
         assert_eq!("360.0 mm", radius.to_string());
+        assert_eq!("437.5 mm", Radius::H0_R2.to_string());
     }
 
     #[test]
@@ -91,6 +223,17 @@ mod tests {
         assert_eq!(r#"{"radius":360.0}"#, json);
     }
 
+    #[test]
+    fn it_should_serialize_a_radius_from_inches_in_millimeters() {
+        let value = TestStruct {
+            radius: Radius::from_inches(dec!(10)).unwrap(),
+        };
+
+        let json = serde_json::to_string(&value).expect("Invalid JSON value");
+
+        assert_eq!(r#"{"radius":254.0}"#, json);
+    }
+
     #[test]
     fn it_should_deserialize_radius_from_json() {
         let json = r#"{"radius":360.0}"#;
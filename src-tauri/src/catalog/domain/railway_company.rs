@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// This struct models a real-world railway company. Some fields are optional
 /// because the corresponding database columns may be nullable.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
 pub struct RailwayCompany {
     /// The common name of the railway company (not null).
     pub name: String,
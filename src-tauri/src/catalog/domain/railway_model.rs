@@ -2,6 +2,8 @@ use crate::catalog::domain::RollingStock;
 use crate::catalog::domain::availability_status::AvailabilityStatus;
 use crate::catalog::domain::railway_model_id::RailwayModelId;
 use crate::catalog::domain::{Category, DeliveryDate, Epoch, PowerMethod, ProductCode, Scale};
+use crate::core::domain::MonetaryAmount;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// A `RailwayModel` represents a manufactured model product in the catalog.
@@ -44,6 +46,15 @@ pub struct RailwayModel {
     /// the availability status
     pub availability_status: Option<AvailabilityStatus>,
 
+    /// The manufacturer's suggested retail price, when known.
+    pub msrp: Option<MonetaryAmount>,
+
     /// Rolling stock instances (specific vehicles) that correspond to this model.
     pub rolling_stocks: Vec<RollingStock>,
+
+    /// When the model was added to the catalog (UTC).
+    pub created_at: DateTime<Utc>,
+
+    /// When the model was last updated (UTC).
+    pub updated_at: DateTime<Utc>,
 }
@@ -1,55 +1,96 @@
+//! Domain-level identifier type for railway models.
+//!
+//! `RailwayModelId` is a strongly-typed wrapper around a `Uuid`, mirroring
+//! `RollingStockId`, so railway model ids are not confused with rolling stock
+//! ids or other strings. It is stored as its hyphenated string form in
+//! `TEXT` columns (see `core::infrastructure::id_column`).
+//!
+//! Semantics and usage:
+//! - Create a new random id with `RailwayModelId::new()`.
+//! - Convert from a `Uuid` using `From<Uuid>`.
+//! - Parse from a textual UUID representation via `str::FromStr`,
+//!   `TryFrom<&str>` or `TryFrom<String>`.
+//! - Obtain the underlying `Uuid` with `value()`.
+
 use anyhow::anyhow;
 use serde::{Deserialize, Serialize};
-use std::ops::Deref;
+use std::fmt;
+use std::fmt::Formatter;
+use std::str;
+use uuid::Uuid;
 
-/// A strongly-typed identifier for a railway model.
+/// A unique identifier for a railway model.
 ///
-/// This newtype wraps a `String` so that code dealing with railway model
-/// identifiers can use a distinct type instead of raw `String`s. It derives
-/// `Serialize` and `Deserialize` so it can be used directly with `serde`.
+/// This is a thin, domain-specific wrapper around `Uuid`. It is `Copy` and
+/// `Clone` which makes it convenient to pass by value.
 ///
-/// Requirements
-/// - The railway model id MUST be a non-empty, non-blank string. Constructions
-///   via `TryFrom<&str>` / `TryFrom<String>` will return an error if the input
-///   is empty or contains only whitespace.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, specta::Type)]
+/// Persistence and serialization:
+/// - The `sqlx` column support stores the value as its hyphenated string
+///   form, matching the `TEXT` id columns of the SQLite schema.
+/// - `Serialize`/`Deserialize` encode it as a plain UUID string.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize, specta::Type)]
 #[serde(transparent)]
 #[specta(transparent)]
-pub struct RailwayModelId(String);
+pub struct RailwayModelId(Uuid);
+
+impl RailwayModelId {
+    /// Create a new random railway model id.
+    pub fn new() -> Self {
+        RailwayModelId::default()
+    }
+
+    /// Return the underlying `Uuid` value.
+    pub fn value(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for RailwayModelId {
+    fn default() -> Self {
+        RailwayModelId(Uuid::new_v4())
+    }
+}
+
+impl fmt::Display for RailwayModelId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
 
-impl Deref for RailwayModelId {
-    type Target = str;
+impl str::FromStr for RailwayModelId {
+    type Err = anyhow::Error;
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
+    /// Parse a `RailwayModelId` from its string representation.
+    ///
+    /// Returns an error if the input is not a valid UUID string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id = Uuid::try_parse(s).map_err(|_| anyhow!("invalid railway model id {s:?}"))?;
+        Ok(RailwayModelId(id))
     }
 }
 
 impl TryFrom<&str> for RailwayModelId {
     type Error = anyhow::Error;
 
+    /// Parse a `RailwayModelId` from its string representation (see `FromStr`).
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        if value.trim().is_empty() {
-            return Err(anyhow!("railway model id must not be empty"));
-        }
-        Ok(RailwayModelId(value.to_owned()))
+        value.parse()
     }
 }
 
 impl TryFrom<String> for RailwayModelId {
     type Error = anyhow::Error;
 
+    /// Parse a `RailwayModelId` from its string representation (see `FromStr`).
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        if value.trim().is_empty() {
-            return Err(anyhow!("railway model id must not be empty"));
-        }
-        Ok(RailwayModelId(value))
+        value.parse()
     }
 }
 
-impl std::fmt::Display for RailwayModelId {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+impl From<Uuid> for RailwayModelId {
+    /// Convert a `Uuid` into a `RailwayModelId`.
+    fn from(id: Uuid) -> Self {
+        RailwayModelId(id)
     }
 }
 
@@ -59,39 +100,49 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     #[test]
-    fn try_from_str_success() {
-        let id = RailwayModelId::try_from("RM-2025").expect("expected valid id");
-        assert_eq!(id.0, "RM-2025");
-    }
-
-    #[test]
-    fn try_from_str_empty_fails() {
-        let err = RailwayModelId::try_from("").expect_err("empty id should fail");
-        let msg = format!("{}", err);
-        assert!(msg.contains("must not be empty"));
+    fn it_should_create_new_railway_model_id_from_str() {
+        let id = "3302b9a7-252c-4b41-8de2-eb71efb1888e"
+            .parse::<RailwayModelId>()
+            .unwrap();
+        assert_eq!(
+            RailwayModelId(Uuid::try_parse("3302b9a7-252c-4b41-8de2-eb71efb1888e").unwrap()),
+            id
+        );
+        assert_eq!(
+            RailwayModelId::try_from("3302b9a7-252c-4b41-8de2-eb71efb1888e".to_string()).unwrap(),
+            id
+        );
     }
 
     #[test]
-    fn try_from_string_blank_fails() {
-        let err = RailwayModelId::try_from("   ".to_string()).expect_err("blank id should fail");
-        let msg = format!("{}", err);
-        assert!(msg.contains("must not be empty"));
+    fn it_should_create_new_railway_model_id_from_uuid() {
+        let uuid = Uuid::new_v4();
+        let railway_model_id: RailwayModelId = uuid.into();
+        assert_eq!(uuid, railway_model_id.value());
+        assert_eq!(railway_model_id.to_string(), uuid.to_string());
     }
 
     #[test]
-    fn deref_to_str() {
-        let id = RailwayModelId::try_from("R-1").unwrap();
-        // Deref should allow &*id to be &str
-        let s: &str = &*id;
-        assert_eq!(s, "R-1");
+    fn it_should_fail_to_parse_invalid_values_as_railway_models() {
+        assert!("invalid value".parse::<RailwayModelId>().is_err());
+        assert!(RailwayModelId::try_from("rm-60211").is_err());
+        assert!(RailwayModelId::try_from(String::new()).is_err());
+        let err = RailwayModelId::try_from(" ").unwrap_err();
+        assert!(err.to_string().contains("invalid railway model id"));
     }
 
     #[test]
-    fn serde_roundtrip_as_string() {
-        let id = RailwayModelId::try_from("RM-42").unwrap();
+    fn it_should_serialize_railway_model_ids_as_strings() {
+        let id = RailwayModelId::new();
         let s = serde_json::to_string(&id).expect("serialize");
-        assert_eq!(s, "\"RM-42\"");
+        assert_eq!(s, format!("\"{}\"", id.value()));
         let de: RailwayModelId = serde_json::from_str(&s).expect("deserialize");
         assert_eq!(de, id);
+        assert!(serde_json::from_str::<RailwayModelId>("\"rm-42\"").is_err());
+    }
+
+    #[test]
+    fn it_should_generate_unique_railway_model_ids() {
+        assert_ne!(RailwayModelId::new(), RailwayModelId::new());
     }
 }
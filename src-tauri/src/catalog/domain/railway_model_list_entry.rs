@@ -0,0 +1,21 @@
+use crate::catalog::domain::railway_model_summary::RailwayModelSummary;
+use serde::{Deserialize, Serialize};
+
+/// A railway model as shown in the catalog listing: its summary, with how
+/// many rolling stocks it has and the range of their lengths over buffers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct RailwayModelListEntry {
+    /// The railway model summary.
+    pub summary: RailwayModelSummary,
+
+    /// The number of rolling stocks of the model.
+    pub rolling_stocks_count: u32,
+
+    /// The shortest length over buffers of its rolling stocks, in
+    /// millimeters, when at least one is known.
+    pub min_length_over_buffers_mm: Option<f64>,
+
+    /// The longest length over buffers of its rolling stocks, in
+    /// millimeters, when at least one is known.
+    pub max_length_over_buffers_mm: Option<f64>,
+}
@@ -0,0 +1,26 @@
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::domain::{ProductCode, Scale};
+use serde::{Deserialize, Serialize};
+
+/// A lightweight projection of a catalog `RailwayModel`.
+///
+/// Summaries carry just enough information to render a model in pickers
+/// and search results (for example when adding a new collection item)
+/// without loading the full aggregate and its rolling stocks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct RailwayModelSummary {
+    /// Unique identifier for the railway model.
+    pub id: RailwayModelId,
+
+    /// The manufacturer name (e.g. ACME, Roco).
+    pub manufacturer: String,
+
+    /// Manufacturer-assigned product code.
+    pub product_code: ProductCode,
+
+    /// Human-readable description of the model.
+    pub description: String,
+
+    /// The scale of the model (e.g. H0, N).
+    pub scale: Scale,
+}
@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
@@ -23,6 +24,11 @@ pub enum RailwayStatus {
     Inactive,
 }
 
+enum_metadata!(RailwayStatus {
+    Active => ("Active", "The railway company is still operating."),
+    Inactive => ("Inactive", "The railway company no longer operates."),
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,6 +141,13 @@ impl Ratio {
     pub fn r76_2() -> Self {
         Ratio(dec!(76.2))
     }
+
+    /// The prototype speed a model running at `model_speed` stands for, in
+    /// the same unit: at 1:87 a model running at 1 km/h looks like a train
+    /// running at 87 km/h.
+    pub fn scale_speed(&self, model_speed: Decimal) -> Decimal {
+        model_speed * self.0
+    }
 }
 
 /// Common, shared `Ratio` values as thread-safe statics.
@@ -209,6 +216,12 @@ mod tests {
             assert_eq!("1:87", ratio1.unwrap().to_string());
         }
 
+        #[test]
+        fn it_should_scale_model_speeds() {
+            assert_eq!(dec!(31.32), Ratio::r87().scale_speed(dec!(0.36)));
+            assert_eq!(dec!(28.8), Ratio::r160().scale_speed(dec!(0.18)));
+        }
+
         #[test]
         fn it_should_compare_two_ratios() {
             let ratio1 = Ratio::try_from(dec!(87)).unwrap();
@@ -0,0 +1,113 @@
+use crate::catalog::domain::availability_status::AvailabilityStatus;
+use crate::catalog::domain::category::{
+    Category, ElectricMultipleUnitType, FreightCarType, LocomotiveType, PassengerCarType,
+    RailcarType, RollingStockCategory,
+};
+use crate::catalog::domain::control::Control;
+use crate::catalog::domain::coupling_socket::CouplingSocket;
+use crate::catalog::domain::dcc_interface::DccInterface;
+use crate::catalog::domain::enum_info::EnumInfo;
+use crate::catalog::domain::railway_status::RailwayStatus;
+use crate::catalog::domain::track_gauge::TrackGauge;
+use crate::catalog::domain::{
+    BodyShellType, ChassisType, FeatureFlag, PowerMethod, Scale, ServiceLevel,
+};
+use serde::{Deserialize, Serialize};
+
+/// The option lists needed to populate the catalog forms.
+///
+/// Everything an "add model" form needs for its select inputs is returned in
+/// a single payload, so the frontend can fill every dropdown with one call.
+/// Every enum option comes with its label and description, in declaration
+/// order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ReferenceData {
+    /// Manufacturers, ordered by name.
+    pub manufacturers: Vec<ManufacturerOption>,
+
+    /// Railway companies, ordered by name.
+    pub railway_companies: Vec<RailwayCompanyOption>,
+
+    /// The available model scales.
+    pub scales: Vec<EnumInfo<Scale>>,
+
+    /// The railway model categories.
+    pub categories: Vec<EnumInfo<Category>>,
+
+    /// The rolling stock categories.
+    pub rolling_stock_categories: Vec<EnumInfo<RollingStockCategory>>,
+
+    /// The locomotive types.
+    pub locomotive_types: Vec<EnumInfo<LocomotiveType>>,
+
+    /// The freight car types.
+    pub freight_car_types: Vec<EnumInfo<FreightCarType>>,
+
+    /// The passenger car types.
+    pub passenger_car_types: Vec<EnumInfo<PassengerCarType>>,
+
+    /// The electric multiple unit car types.
+    pub electric_multiple_unit_types: Vec<EnumInfo<ElectricMultipleUnitType>>,
+
+    /// The railcar types.
+    pub railcar_types: Vec<EnumInfo<RailcarType>>,
+
+    /// The power methods.
+    pub power_methods: Vec<EnumInfo<PowerMethod>>,
+
+    /// The availability statuses of railway models.
+    pub availability_statuses: Vec<EnumInfo<AvailabilityStatus>>,
+
+    /// The body shell constructions.
+    pub body_shell_types: Vec<EnumInfo<BodyShellType>>,
+
+    /// The chassis constructions.
+    pub chassis_types: Vec<EnumInfo<ChassisType>>,
+
+    /// The DCC support levels.
+    pub controls: Vec<EnumInfo<Control>>,
+
+    /// The DCC decoder interfaces.
+    pub dcc_interfaces: Vec<EnumInfo<DccInterface>>,
+
+    /// The coupling sockets.
+    pub coupling_sockets: Vec<EnumInfo<CouplingSocket>>,
+
+    /// The feature flag values.
+    pub feature_flags: Vec<EnumInfo<FeatureFlag>>,
+
+    /// The passenger service levels.
+    pub service_levels: Vec<EnumInfo<ServiceLevel>>,
+
+    /// The track gauges.
+    pub track_gauges: Vec<EnumInfo<TrackGauge>>,
+
+    /// The railway company statuses.
+    pub railway_statuses: Vec<EnumInfo<RailwayStatus>>,
+}
+
+/// A manufacturer entry for form dropdowns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ManufacturerOption {
+    /// The manufacturer identifier.
+    pub id: String,
+
+    /// The manufacturer name (e.g. ACME, Roco).
+    pub name: String,
+}
+
+/// A railway company entry for form dropdowns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct RailwayCompanyOption {
+    /// The railway company identifier.
+    pub id: String,
+
+    /// The common (usually abbreviated) name, for example `"FS"`.
+    pub name: String,
+
+    /// The legally registered company name, when known.
+    pub registered_company_name: Option<String>,
+
+    /// Whether the company is still active, when known.
+    pub status: Option<RailwayStatus>,
+}
@@ -0,0 +1,154 @@
+use crate::catalog::domain::ProductCode;
+use crate::catalog::domain::announcement::{AnnouncementBatch, AnnouncementIngest};
+use crate::catalog::domain::duplicate_candidate::DuplicateCandidate;
+use crate::catalog::domain::livery::Livery;
+use crate::catalog::domain::model_merge::ModelMerge;
+use crate::catalog::domain::product_code_lookup::{ProductCodeLookup, ProductCodeQuery};
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::domain::railway_model_list_entry::RailwayModelListEntry;
+use crate::catalog::domain::railway_model_summary::RailwayModelSummary;
+use crate::catalog::domain::reference_data::{ManufacturerOption, RailwayCompanyOption};
+use crate::catalog::domain::rolling_stock_comparison::ComparisonReport;
+use crate::catalog::domain::rolling_stock_id::RollingStockId;
+use crate::core::domain::page::{KeysetPage, PageKey};
+
+#[async_trait::async_trait]
+pub trait CatalogRepository: Send + Sync {
+    /// Search railway models whose product code, description or manufacturer
+    /// name contains `query`, returning at most `limit` summaries.
+    ///
+    /// Results are ranked so that an exact product code match comes first,
+    /// followed by product code prefix matches and then any other match.
+    async fn search_railway_models(
+        &self,
+        query: &str,
+        limit: u32,
+    ) -> anyhow::Result<Vec<RailwayModelSummary>>;
+
+    /// Find the railway model from `manufacturer_id` whose product code
+    /// matches `product_code`, ignoring case.
+    async fn find_railway_model_by_product_code(
+        &self,
+        manufacturer_id: &str,
+        product_code: &ProductCode,
+    ) -> anyhow::Result<Option<RailwayModelSummary>>;
+
+    /// List up to `limit` railway models ordered by product code, starting
+    /// after the model with the `after` keys (from the first one when
+    /// `None`), with the rolling stock counts and lengths over buffers.
+    async fn list_railway_models(
+        &self,
+        after: Option<&PageKey>,
+        limit: u32,
+    ) -> anyhow::Result<KeysetPage<RailwayModelListEntry>>;
+
+    /// List every manufacturer, ordered by name.
+    async fn list_manufacturers(&self) -> anyhow::Result<Vec<ManufacturerOption>>;
+
+    /// List every railway company, ordered by name.
+    async fn list_railway_companies(&self) -> anyhow::Result<Vec<RailwayCompanyOption>>;
+
+    /// Find the pairs of models from the same manufacturer and scale whose
+    /// descriptions have a similarity of at least `threshold` (see
+    /// `description_similarity`), most similar first.
+    async fn find_possible_duplicates(
+        &self,
+        threshold: f64,
+    ) -> anyhow::Result<Vec<DuplicateCandidate>>;
+
+    /// Merge the railway model `remove_id` into `keep_id`: its rolling stocks
+    /// and the collection items referencing it move to the kept model, then
+    /// it is deleted. Nothing changes when any step fails.
+    ///
+    /// Fails with a `MergeError` when the two ids are the same or the models
+    /// are from different manufacturers.
+    async fn merge_railway_models(
+        &self,
+        keep_id: &RailwayModelId,
+        remove_id: &RailwayModelId,
+    ) -> anyhow::Result<ModelMerge>;
+
+    /// Compare the rolling stocks with `left_id` and `right_id` field by
+    /// field (see `ComparisonReport::compare`).
+    ///
+    /// Fails with a `ComparisonError` when they are of different categories.
+    async fn compare_rolling_stocks(
+        &self,
+        left_id: &RollingStockId,
+        right_id: &RollingStockId,
+    ) -> anyhow::Result<ComparisonReport>;
+
+    /// Ingest the models announced in `batch`, all or nothing: unknown
+    /// product codes are created as announced models, known ones get the
+    /// announced delivery date and suggested retail price.
+    ///
+    /// Fails with an `AnnouncementError` when the batch is not valid or its
+    /// manufacturer is not in the catalog.
+    async fn ingest_announcements(
+        &self,
+        batch: &AnnouncementBatch,
+    ) -> anyhow::Result<AnnouncementIngest>;
+
+    /// Look `queries` up in the catalog and the collection, in order (see
+    /// `ProductCodeLookup`).
+    ///
+    /// Fails with a `LookupError` when there are too many of them.
+    async fn lookup_product_codes(
+        &self,
+        queries: Vec<ProductCodeQuery>,
+    ) -> anyhow::Result<Vec<ProductCodeLookup>>;
+}
+
+#[async_trait::async_trait]
+pub trait LiveryRepository: Send + Sync {
+    /// Store a new livery; fails when its railway company already has a
+    /// livery with the same name (ignoring case).
+    async fn create_livery(&self, livery: &Livery) -> anyhow::Result<()>;
+
+    /// Overwrite the stored livery with the id of `livery`; fails when there
+    /// is no such livery.
+    async fn update_livery(&self, livery: &Livery) -> anyhow::Result<()>;
+
+    /// Delete a livery; the rolling stocks wearing it fall back to their
+    /// free text livery. Fails when there is no such livery.
+    async fn delete_livery(&self, livery_id: &str) -> anyhow::Result<()>;
+
+    async fn get_livery(&self, livery_id: &str) -> anyhow::Result<Option<Livery>>;
+
+    /// Every livery of the railway company with `railway_company_id`, ordered
+    /// by name.
+    async fn list_liveries(&self, railway_company_id: &str) -> anyhow::Result<Vec<Livery>>;
+
+    /// The liveries of the railway company with `railway_company_id` whose
+    /// name, or a word in it, starts with `prefix` (ignoring case), returning
+    /// at most `limit` of them.
+    ///
+    /// Results are ranked so that an exact name match comes first, followed
+    /// by name prefix matches and then word prefix matches.
+    async fn suggest_liveries(
+        &self,
+        railway_company_id: &str,
+        prefix: &str,
+        limit: u32,
+    ) -> anyhow::Result<Vec<Livery>>;
+
+    /// Make the rolling stock with `rolling_stock_id` wear the livery with
+    /// `livery_id`, or only its free text livery when `None`.
+    ///
+    /// Fails with a `LiveryError` when the livery belongs to another railway
+    /// company than the rolling stock.
+    async fn set_rolling_stock_livery(
+        &self,
+        rolling_stock_id: &str,
+        livery_id: Option<&str>,
+    ) -> anyhow::Result<()>;
+
+    /// The livery of the rolling stock with `rolling_stock_id`: the name of
+    /// the livery it references, falling back to its free text livery.
+    ///
+    /// `None` when the rolling stock has neither, or does not exist.
+    async fn get_rolling_stock_livery(
+        &self,
+        rolling_stock_id: &str,
+    ) -> anyhow::Result<Option<String>>;
+}
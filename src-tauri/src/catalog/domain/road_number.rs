@@ -0,0 +1,359 @@
+use crate::catalog::domain::category::RollingStockCategory;
+use crate::catalog::domain::epoch_years::ValidationWarning;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// A numbering convention for the road numbers of rolling stocks.
+///
+/// Conventions with a `railway` apply to the rolling stocks of that railway
+/// company only; the UIC ones apply to every railway. A convention with a
+/// check digit ends with it, after a dash, and the digit is computed with
+/// `luhn_check_digit` over the digits before it.
+#[derive(Debug)]
+pub struct RoadNumberConvention {
+    /// The railway company name, or `None` for every railway.
+    pub railway: Option<&'static str>,
+
+    /// The categories of rolling stocks numbered this way.
+    pub categories: &'static [RollingStockCategory],
+
+    /// The convention name, with an example.
+    pub name: &'static str,
+
+    /// The expected (normalized) road number format.
+    pub pattern: Regex,
+
+    /// Whether the last digit is a check digit.
+    pub check_digit: bool,
+}
+
+impl RoadNumberConvention {
+    /// Whether the convention applies to a rolling stock of `category`
+    /// from `railway`.
+    pub fn applies_to(&self, railway: &str, category: RollingStockCategory) -> bool {
+        self.railway.is_none_or(|r| r.eq_ignore_ascii_case(railway))
+            && self.categories.contains(&category)
+    }
+}
+
+const TRACTION: &[RollingStockCategory] = &[
+    RollingStockCategory::Locomotive,
+    RollingStockCategory::ElectricMultipleUnit,
+    RollingStockCategory::Railcar,
+];
+
+const SELF_PROPELLED: &[RollingStockCategory] = &[
+    RollingStockCategory::ElectricMultipleUnit,
+    RollingStockCategory::Railcar,
+];
+
+/// The known road number conventions; add a row to check a new one.
+static ROAD_NUMBER_CONVENTIONS: Lazy<Vec<RoadNumberConvention>> = Lazy::new(|| {
+    let convention = |railway, categories, name, pattern: &str, check_digit| RoadNumberConvention {
+        railway,
+        categories,
+        name,
+        pattern: Regex::new(pattern).expect("valid road number pattern"),
+        check_digit,
+    };
+    vec![
+        convention(
+            Some("FS"),
+            &[RollingStockCategory::Locomotive],
+            "FS locomotive (e.g. E.656 077)",
+            r"^(?:[A-Z][a-z]{0,2}\.)?\d{3}[A-Z]?[. ]\d{3,4}$",
+            false,
+        ),
+        convention(
+            Some("FS"),
+            SELF_PROPELLED,
+            "FS railcar (e.g. ALn 668 1518)",
+            r"^(?:A[LD]|L)[a-z]{0,2} \d{3}[. ]\d{3,4}$",
+            false,
+        ),
+        convention(
+            Some("DB"),
+            TRACTION,
+            "DB computer number (e.g. 218 217-8)",
+            r"^\d{3} \d{3}-\d$",
+            true,
+        ),
+        convention(
+            Some("DB"),
+            TRACTION,
+            "DB number before 1968 (e.g. E 10 239)",
+            r"^(?:[EV]T? )?\d{2,3} \d{3,4}$",
+            false,
+        ),
+        convention(
+            None,
+            &[RollingStockCategory::PassengerCar],
+            "UIC coach number (e.g. 61 83 19-90 105-3)",
+            r"^\d{2} \d{2} \d{2}-\d{2} \d{3}-\d(?: [A-Z]+)?$",
+            true,
+        ),
+        convention(
+            None,
+            &[RollingStockCategory::FreightCar],
+            "UIC wagon number (e.g. 21 83 181 3 105-7)",
+            r"^\d{2} \d{2} \d{3} \d \d{3}-\d(?: [A-Z]+)?$",
+            true,
+        ),
+        convention(
+            None,
+            TRACTION,
+            "UIC vehicle number (e.g. 91 83 2464 001-3)",
+            r"^\d{2} \d{2} \d{4} \d{3}-\d(?: [A-Z-]+)?$",
+            true,
+        ),
+    ]
+});
+
+/// The conventions for the road numbers of rolling stocks of `category`
+/// from `railway`, the railway specific ones first.
+pub fn road_number_conventions(
+    railway: &str,
+    category: RollingStockCategory,
+) -> Vec<&'static RoadNumberConvention> {
+    let (mut specific, general): (Vec<_>, Vec<_>) = ROAD_NUMBER_CONVENTIONS
+        .iter()
+        .filter(|c| c.applies_to(railway, category))
+        .partition(|c| c.railway.is_some());
+    specific.extend(general);
+    specific
+}
+
+/// The outcome of checking a road number; the road number is saved either
+/// way.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ValidationOutcome {
+    /// The road number follows a known convention, or none is known.
+    Ok,
+
+    /// The road number is likely mistyped; `suggestion` is the corrected
+    /// road number, when one can be worked out.
+    Warning {
+        reason: String,
+        suggestion: Option<String>,
+    },
+}
+
+/// The road number trimmed, with runs of whitespace collapsed to a single
+/// space and no spaces around dashes.
+pub fn normalize_road_number(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(" -", "-")
+        .replace("- ", "-")
+}
+
+/// The UIC check digit of `digits`: every other digit, starting from the
+/// last one, is doubled, and the check digit brings the sum of the digits
+/// of the results up to a multiple of ten.
+///
+/// The same computation is used by the DB computer numbers.
+pub fn luhn_check_digit(digits: &[u8]) -> u8 {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &digit)| {
+            let value = u32::from(digit) * if i % 2 == 0 { 2 } else { 1 };
+            value / 10 + value % 10
+        })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// `road_number` with its check digit, the last digit of the number,
+/// replaced by the right one; `None` when it is already right.
+fn correct_check_digit(road_number: &str) -> Option<String> {
+    let positions: Vec<usize> = road_number
+        .char_indices()
+        .filter(|(_, c)| c.is_ascii_digit())
+        .map(|(i, _)| i)
+        .collect();
+    let (&check_position, number_positions) = positions.split_last()?;
+    let digits: Vec<u8> = number_positions
+        .iter()
+        .map(|&i| road_number.as_bytes()[i] - b'0')
+        .collect();
+    let expected = luhn_check_digit(&digits);
+    if road_number.as_bytes()[check_position] - b'0' == expected {
+        return None;
+    }
+    let mut corrected = road_number.to_string();
+    corrected.replace_range(
+        check_position..=check_position,
+        &char::from(b'0' + expected).to_string(),
+    );
+    Some(corrected)
+}
+
+/// The warning for the rolling stock with `rolling_stock_id` when
+/// `validate_road_number` finds its road number `value` likely mistyped.
+pub fn check_road_number(
+    rolling_stock_id: &str,
+    railway: &str,
+    category: RollingStockCategory,
+    value: &str,
+) -> Option<ValidationWarning> {
+    match validate_road_number(railway, category, value) {
+        ValidationOutcome::Ok => None,
+        ValidationOutcome::Warning { reason, suggestion } => {
+            Some(ValidationWarning::SuspiciousRoadNumber {
+                rolling_stock_id: rolling_stock_id.to_string(),
+                road_number: value.to_string(),
+                reason,
+                suggestion,
+            })
+        }
+    }
+}
+
+/// Check the road number `value` of a rolling stock of `category` from
+/// `railway` (the railway company name) against the known conventions.
+///
+/// A warning is returned when the number needs normalizing (see
+/// `normalize_road_number`), when its check digit is wrong, or when it
+/// follows none of the conventions of its railway. Numbers of railways with
+/// no conventions of their own are only checked when they follow a UIC one.
+pub fn validate_road_number(
+    railway: &str,
+    category: RollingStockCategory,
+    value: &str,
+) -> ValidationOutcome {
+    let normalized = normalize_road_number(value);
+    let conventions = road_number_conventions(railway, category);
+    let matched = conventions.iter().find(|c| c.pattern.is_match(&normalized));
+
+    if let Some(convention) = matched {
+        if convention.check_digit
+            && let Some(corrected) = correct_check_digit(&normalized)
+        {
+            return ValidationOutcome::Warning {
+                reason: format!("the check digit of the {} is wrong", convention.name),
+                suggestion: Some(corrected),
+            };
+        }
+    } else if let Some(convention) = conventions.first().filter(|c| c.railway.is_some()) {
+        return ValidationOutcome::Warning {
+            reason: format!("the road number does not follow the {}", convention.name),
+            suggestion: (normalized != value).then_some(normalized),
+        };
+    }
+
+    if normalized != value {
+        ValidationOutcome::Warning {
+            reason: "the road number has extra spaces".to_string(),
+            suggestion: Some(normalized),
+        }
+    } else {
+        ValidationOutcome::Ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    fn digits(value: &str) -> Vec<u8> {
+        value.bytes().map(|b| b - b'0').collect()
+    }
+
+    #[rstest]
+    #[case("6183199010", 8)]
+    #[case("61831990105", 3)]
+    #[case("21831813105", 7)]
+    #[case("218217", 8)]
+    #[case("103245", 7)]
+    #[case("0", 0)]
+    fn it_should_compute_uic_check_digits(#[case] number: &str, #[case] expected: u8) {
+        assert_eq!(luhn_check_digit(&digits(number)), expected);
+    }
+
+    #[test]
+    fn wrong_check_digits_are_corrected() {
+        assert_eq!(
+            validate_road_number(
+                "FS",
+                RollingStockCategory::PassengerCar,
+                "61 83 19-90 105-4"
+            ),
+            ValidationOutcome::Warning {
+                reason: "the check digit of the UIC coach number (e.g. 61 83 19-90 105-3) is wrong"
+                    .to_string(),
+                suggestion: Some("61 83 19-90 105-3".to_string()),
+            }
+        );
+        assert_eq!(
+            validate_road_number(
+                "FS",
+                RollingStockCategory::PassengerCar,
+                "61 83 19-90 105-3 A"
+            ),
+            ValidationOutcome::Ok
+        );
+        assert_eq!(
+            validate_road_number("DB", RollingStockCategory::Locomotive, "218 217-8"),
+            ValidationOutcome::Ok
+        );
+    }
+
+    #[rstest]
+    #[case("FS", RollingStockCategory::Locomotive, "E.656 077")]
+    #[case("FS", RollingStockCategory::Locomotive, "E.656.077")]
+    #[case("FS", RollingStockCategory::Locomotive, "685.196")]
+    #[case("FS", RollingStockCategory::Railcar, "ALn 668.1518")]
+    #[case("FS", RollingStockCategory::ElectricMultipleUnit, "ALe 601 017")]
+    #[case("SBB", RollingStockCategory::FreightCar, "33 85 784 6 123-2")]
+    #[case("DB", RollingStockCategory::Locomotive, "E 10 239")]
+    #[case("RhB", RollingStockCategory::Locomotive, "Ge 4/4 I 603")]
+    fn well_formed_road_numbers_are_ok(
+        #[case] railway: &str,
+        #[case] category: RollingStockCategory,
+        #[case] value: &str,
+    ) {
+        assert_eq!(
+            validate_road_number(railway, category, value),
+            ValidationOutcome::Ok
+        );
+    }
+
+    #[test]
+    fn extra_spaces_are_collapsed() {
+        assert_eq!(
+            validate_road_number("FS", RollingStockCategory::Locomotive, " E.656  077"),
+            ValidationOutcome::Warning {
+                reason: "the road number has extra spaces".to_string(),
+                suggestion: Some("E.656 077".to_string()),
+            }
+        );
+        assert_eq!(
+            validate_road_number("DB", RollingStockCategory::Locomotive, "218 217 - 7"),
+            ValidationOutcome::Warning {
+                reason: "the check digit of the DB computer number (e.g. 218 217-8) is wrong"
+                    .to_string(),
+                suggestion: Some("218 217-8".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn road_numbers_off_the_railway_convention_are_flagged() {
+        assert_eq!(
+            validate_road_number("FS", RollingStockCategory::Locomotive, "E656-077"),
+            ValidationOutcome::Warning {
+                reason: "the road number does not follow the FS locomotive (e.g. E.656 077)"
+                    .to_string(),
+                suggestion: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,484 @@
+//! Side by side comparison of two catalog rolling stocks.
+//!
+//! Collectors often choose between models of the same prototype from
+//! different manufacturers or production runs. The comparison lines up the
+//! fields of two rolling stocks of the same category, including those only
+//! that category has, and tells for each whether the two agree.
+//!
+//! Values are compared as they are shown: lengths and radii in millimeters
+//! and enums by their label, so `210` and `210.0` mm are the same length.
+
+use crate::catalog::domain::Epoch;
+use crate::catalog::domain::RollingStock;
+use crate::catalog::domain::category::RollingStockCategory;
+use crate::catalog::domain::enum_info::EnumMetadata;
+use crate::catalog::domain::feature_flag::FeatureFlag;
+use crate::catalog::domain::rolling_stock_id::RollingStockId;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A field lined up by the comparison.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ComparedField {
+    /// The railway company.
+    Railway,
+    /// The era of the railway model.
+    Epoch,
+    /// The livery description.
+    Livery,
+    /// The class name of a locomotive, the type name of anything else.
+    TypeName,
+    /// The locomotive, car, multiple unit or railcar type.
+    Type,
+    /// The identification marking.
+    RoadNumber,
+    /// The prototype series.
+    Series,
+    /// The depot.
+    Depot,
+    /// The travel classes of a passenger car.
+    ServiceLevel,
+    /// The length over buffers.
+    Length,
+    /// The minimum drivable radius.
+    MinimumRadius,
+    /// The coupling socket and features.
+    Coupling,
+    /// The DCC decoder interface.
+    DccInterface,
+    /// The control method.
+    Control,
+    /// Whether it has no motor.
+    Dummy,
+    /// Whether it has lights.
+    Lights,
+    /// Whether it has interior lights.
+    InteriorLights,
+}
+
+/// How the two values of a field compare.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FieldOutcome {
+    /// Both rolling stocks have the same value.
+    Equal,
+    /// Both rolling stocks have a value, and they differ.
+    Different,
+    /// At least one of the rolling stocks has no value.
+    Missing,
+}
+
+/// A field of both rolling stocks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct FieldComparison {
+    /// The field compared.
+    pub field: ComparedField,
+
+    /// The value of the first rolling stock, as shown.
+    pub left: Option<String>,
+
+    /// The value of the second rolling stock, as shown.
+    pub right: Option<String>,
+
+    /// How the two values compare.
+    pub outcome: FieldOutcome,
+}
+
+/// The side by side comparison of two rolling stocks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ComparisonReport {
+    /// The first rolling stock.
+    pub left_id: RollingStockId,
+
+    /// The second rolling stock.
+    pub right_id: RollingStockId,
+
+    /// The category of both rolling stocks.
+    pub category: RollingStockCategory,
+
+    /// The fields of the category, in display order.
+    pub fields: Vec<FieldComparison>,
+}
+
+/// Why two rolling stocks cannot be compared.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ComparisonError {
+    /// Only rolling stocks of the same category have the same fields.
+    #[error(
+        "rolling stocks {left_id} and {right_id} are of different categories ({left_category} and {right_category})"
+    )]
+    DifferentCategories {
+        left_id: RollingStockId,
+        left_category: RollingStockCategory,
+        right_id: RollingStockId,
+        right_category: RollingStockCategory,
+    },
+}
+
+impl ComparisonReport {
+    /// Compare the rolling stock `left`, from a railway model of the
+    /// `left_epoch` era, with `right` from a model of the `right_epoch` era.
+    ///
+    /// # Errors
+    ///
+    /// `ComparisonError::DifferentCategories` when the two rolling stocks
+    /// are not of the same category.
+    pub fn compare(
+        left: &RollingStock,
+        left_epoch: &Epoch,
+        right: &RollingStock,
+        right_epoch: &Epoch,
+    ) -> Result<Self, ComparisonError> {
+        if left.category() != right.category() {
+            return Err(ComparisonError::DifferentCategories {
+                left_id: left.id(),
+                left_category: left.category(),
+                right_id: right.id(),
+                right_category: right.category(),
+            });
+        }
+
+        // Both rolling stocks are of the same variant, so their fields come
+        // in the same order.
+        let fields = field_values(left, left_epoch)
+            .into_iter()
+            .zip(field_values(right, right_epoch))
+            .map(|((field, left), (_, right))| {
+                let outcome = match (&left, &right) {
+                    (Some(left), Some(right)) if left == right => FieldOutcome::Equal,
+                    (Some(_), Some(_)) => FieldOutcome::Different,
+                    _ => FieldOutcome::Missing,
+                };
+                FieldComparison {
+                    field,
+                    left,
+                    right,
+                    outcome,
+                }
+            })
+            .collect();
+
+        Ok(ComparisonReport {
+            left_id: left.id(),
+            right_id: right.id(),
+            category: left.category(),
+            fields,
+        })
+    }
+
+    /// How many fields have different values.
+    pub fn differences(&self) -> usize {
+        self.fields
+            .iter()
+            .filter(|field| field.outcome == FieldOutcome::Different)
+            .count()
+    }
+}
+
+/// The fields of `rolling_stock` shown in a comparison, with their values.
+///
+/// Every rolling stock has the shared fields first; those of its category
+/// are placed after the type name, and the powered ones add their DCC
+/// fields after the coupling.
+fn field_values(
+    rolling_stock: &RollingStock,
+    epoch: &Epoch,
+) -> Vec<(ComparedField, Option<String>)> {
+    let mut fields = vec![
+        (
+            ComparedField::Railway,
+            Some(rolling_stock.railway().to_string()),
+        ),
+        (ComparedField::Epoch, Some(epoch.0.clone())),
+        (ComparedField::Livery, text(rolling_stock.livery())),
+    ];
+
+    match rolling_stock {
+        RollingStock::Locomotive {
+            class_name,
+            locomotive_type,
+            series,
+            depot,
+            ..
+        } => fields.extend([
+            (ComparedField::TypeName, text(Some(class_name))),
+            (ComparedField::Type, label(Some(locomotive_type))),
+            (ComparedField::RoadNumber, text(rolling_stock.road_number())),
+            (ComparedField::Series, text(series.as_deref())),
+            (ComparedField::Depot, text(depot.as_deref())),
+        ]),
+        RollingStock::ElectricMultipleUnit {
+            type_name,
+            electric_multiple_unit_type,
+            series,
+            depot,
+            ..
+        } => fields.extend([
+            (ComparedField::TypeName, text(Some(type_name))),
+            (
+                ComparedField::Type,
+                label(Some(electric_multiple_unit_type)),
+            ),
+            (ComparedField::RoadNumber, text(rolling_stock.road_number())),
+            (ComparedField::Series, text(series.as_deref())),
+            (ComparedField::Depot, text(depot.as_deref())),
+        ]),
+        RollingStock::Railcar {
+            type_name,
+            railcar_type,
+            series,
+            depot,
+            ..
+        } => fields.extend([
+            (ComparedField::TypeName, text(Some(type_name))),
+            (ComparedField::Type, label(Some(railcar_type))),
+            (ComparedField::RoadNumber, text(rolling_stock.road_number())),
+            (ComparedField::Series, text(series.as_deref())),
+            (ComparedField::Depot, text(depot.as_deref())),
+        ]),
+        RollingStock::PassengerCar {
+            type_name,
+            passenger_car_type,
+            series,
+            service_level,
+            ..
+        } => fields.extend([
+            (ComparedField::TypeName, text(Some(type_name))),
+            (ComparedField::Type, label(passenger_car_type.as_ref())),
+            (ComparedField::RoadNumber, text(rolling_stock.road_number())),
+            (ComparedField::Series, text(series.as_deref())),
+            (ComparedField::ServiceLevel, label(service_level.as_ref())),
+        ]),
+        RollingStock::FreightCar {
+            type_name,
+            freight_car_type,
+            ..
+        } => fields.extend([
+            (ComparedField::TypeName, text(Some(type_name))),
+            (ComparedField::Type, label(freight_car_type.as_ref())),
+            (ComparedField::RoadNumber, text(rolling_stock.road_number())),
+        ]),
+    }
+
+    let tech_specs = rolling_stock.technical_specifications();
+    fields.extend([
+        (
+            ComparedField::Length,
+            rolling_stock
+                .length_over_buffer()
+                .and_then(|length| length.millimeters())
+                .map(|mm| millimeters(mm.quantity())),
+        ),
+        (
+            ComparedField::MinimumRadius,
+            tech_specs
+                .and_then(|tech_specs| tech_specs.minimum_radius)
+                .map(|radius| millimeters(radius.as_millimeters())),
+        ),
+        (
+            ComparedField::Coupling,
+            tech_specs
+                .and_then(|tech_specs| tech_specs.coupling)
+                .map(|coupling| coupling.to_string()),
+        ),
+    ]);
+
+    if let RollingStock::Locomotive { is_dummy, .. }
+    | RollingStock::ElectricMultipleUnit { is_dummy, .. }
+    | RollingStock::Railcar { is_dummy, .. } = rolling_stock
+    {
+        let dummy = if *is_dummy {
+            FeatureFlag::Yes
+        } else {
+            FeatureFlag::No
+        };
+        fields.extend([
+            (
+                ComparedField::DccInterface,
+                label(rolling_stock.dcc_interface().as_ref()),
+            ),
+            (
+                ComparedField::Control,
+                label(rolling_stock.control().as_ref()),
+            ),
+            (ComparedField::Dummy, label(Some(&dummy))),
+        ]);
+    }
+
+    fields.extend([
+        (
+            ComparedField::Lights,
+            label(tech_specs.and_then(|tech_specs| tech_specs.lights.as_ref())),
+        ),
+        (
+            ComparedField::InteriorLights,
+            label(tech_specs.and_then(|tech_specs| tech_specs.interior_lights.as_ref())),
+        ),
+    ]);
+    fields
+}
+
+/// A free text value; blank text counts as no value.
+fn text(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn label<T: EnumMetadata>(value: Option<&T>) -> Option<String> {
+    value.map(|value| value.label().to_string())
+}
+
+fn millimeters(value: rust_decimal::Decimal) -> String {
+    format!("{} mm", value.round_dp(1).normalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::domain::category::{FreightCarType, LocomotiveType};
+    use crate::catalog::domain::control::Control;
+    use crate::catalog::domain::dcc_interface::DccInterface;
+    use crate::catalog::domain::length_over_buffers::LengthOverBuffers;
+    use crate::catalog::domain::railway_id::RailwayId;
+    use crate::catalog::domain::rolling_stock_railway::RollingStockRailway;
+    use crate::catalog::domain::technical_specifications::TechnicalSpecificationsBuilder;
+    use crate::catalog::domain::{Coupling, CouplingSocket, Radius};
+    use crate::core::domain::length::Length;
+    use pretty_assertions::assert_eq;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn fs() -> RollingStockRailway {
+        RollingStockRailway::new(RailwayId::new("fs"), "FS")
+    }
+
+    fn locomotive(
+        road_number: &str,
+        length_mm: Decimal,
+        dcc_interface: Option<DccInterface>,
+    ) -> RollingStock {
+        let tech_specs = TechnicalSpecificationsBuilder::default()
+            .with_minimum_radius(Radius::from_millimeters(dec!(360)).unwrap())
+            .with_coupling(Coupling::with_close_couplers(CouplingSocket::Nem362))
+            .with_lights()
+            .build();
+        RollingStock::new_locomotive(
+            RollingStockId::new(),
+            "E.656",
+            road_number,
+            None,
+            fs(),
+            LocomotiveType::ElectricLocomotive,
+            Some("Milano Smistamento"),
+            Some("castano/isabella"),
+            false,
+            Some(LengthOverBuffers::from_millimeters(Length::Millimeters(
+                length_mm,
+            ))),
+            Some(Control::DccReady),
+            dcc_interface,
+            Some(tech_specs),
+        )
+    }
+
+    fn outcome(report: &ComparisonReport, field: ComparedField) -> &FieldComparison {
+        report
+            .fields
+            .iter()
+            .find(|comparison| comparison.field == field)
+            .unwrap()
+    }
+
+    #[test]
+    fn it_should_line_up_the_fields_of_two_locomotives() {
+        let left = locomotive("E.656 077", dec!(210), Some(DccInterface::Next18));
+        let right = locomotive("E.656 208", dec!(210.0), None);
+
+        let report =
+            ComparisonReport::compare(&left, &Epoch::from("IV"), &right, &Epoch::from("V"))
+                .unwrap();
+
+        assert_eq!(report.category, RollingStockCategory::Locomotive);
+        assert_eq!(report.left_id, left.id());
+        assert_eq!(report.right_id, right.id());
+        let fields: Vec<ComparedField> = report.fields.iter().map(|f| f.field).collect();
+        assert_eq!(
+            fields,
+            vec![
+                ComparedField::Railway,
+                ComparedField::Epoch,
+                ComparedField::Livery,
+                ComparedField::TypeName,
+                ComparedField::Type,
+                ComparedField::RoadNumber,
+                ComparedField::Series,
+                ComparedField::Depot,
+                ComparedField::Length,
+                ComparedField::MinimumRadius,
+                ComparedField::Coupling,
+                ComparedField::DccInterface,
+                ComparedField::Control,
+                ComparedField::Dummy,
+                ComparedField::Lights,
+                ComparedField::InteriorLights,
+            ]
+        );
+
+        let length = outcome(&report, ComparedField::Length);
+        assert_eq!(length.left.as_deref(), Some("210 mm"));
+        assert_eq!(length.outcome, FieldOutcome::Equal);
+        assert_eq!(
+            outcome(&report, ComparedField::Coupling).left.as_deref(),
+            Some("NEM 362, close couplers")
+        );
+        assert_eq!(
+            outcome(&report, ComparedField::Epoch).outcome,
+            FieldOutcome::Different
+        );
+        assert_eq!(
+            outcome(&report, ComparedField::RoadNumber).outcome,
+            FieldOutcome::Different
+        );
+        let dcc_interface = outcome(&report, ComparedField::DccInterface);
+        assert_eq!(dcc_interface.outcome, FieldOutcome::Missing);
+        assert_eq!(dcc_interface.right, None);
+        assert_eq!(
+            outcome(&report, ComparedField::Series).outcome,
+            FieldOutcome::Missing
+        );
+        assert_eq!(
+            outcome(&report, ComparedField::Lights).outcome,
+            FieldOutcome::Equal
+        );
+        assert_eq!(report.differences(), 2);
+    }
+
+    #[test]
+    fn it_should_not_compare_a_locomotive_with_a_freight_car() {
+        let left = locomotive("E.656 077", dec!(210), None);
+        let right = RollingStock::new_freight_car(
+            RollingStockId::new(),
+            "Fals",
+            None,
+            fs(),
+            Some(FreightCarType::Gondola),
+            None,
+            None,
+            None,
+        );
+        let epoch = Epoch::from("IV");
+
+        assert_eq!(
+            ComparisonReport::compare(&left, &epoch, &right, &epoch),
+            Err(ComparisonError::DifferentCategories {
+                left_id: left.id(),
+                left_category: RollingStockCategory::Locomotive,
+                right_id: right.id(),
+                right_category: RollingStockCategory::FreightCar,
+            })
+        );
+    }
+}
@@ -2,14 +2,15 @@
 //!
 //! `RollingStockId` is a lightweight, strongly-typed wrapper around a
 //! `Uuid` used throughout the domain and persistence layers to identify a
-//! particular rolling stock instance. The type is marked `#[sqlx(transparent)]` so
-//! it stores as a plain UUID in the database, and derives `Serialize` /
-//! `Deserialize` for convenient (de)serialization in APIs.
+//! particular rolling stock instance. It is stored as its hyphenated string
+//! form in `TEXT` columns (see `core::infrastructure::id_column`), and derives
+//! `Serialize` / `Deserialize` for convenient (de)serialization in APIs.
 //!
 //! Semantics and usage:
 //! - Create a new random id with `RollingStockId::new()`.
 //! - Convert from a `Uuid` using `From<Uuid>`.
-//! - Parse from a textual UUID representation via `str::FromStr`.
+//! - Parse from a textual UUID representation via `str::FromStr` or
+//!   `TryFrom<&str>`.
 //! - Obtain the underlying `Uuid` with `value()` (it is returned by value
 //!   since `Uuid` is `Copy`).
 //!
@@ -35,8 +36,8 @@ use uuid::Uuid;
 /// to pass by value.
 ///
 /// Persistence and serialization:
-/// - `#[sqlx(transparent)]` ensures the value is stored as a regular UUID
-///   column in SQLite/Postgres when using `sqlx`.
+/// - The `sqlx` column support stores the value as its hyphenated string
+///   form, matching the `TEXT` id columns of the SQLite schema.
 /// - `Serialize`/`Deserialize` derive implementations allow easy JSON
 ///   encoding for APIs or fixtures.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize, specta::Type)]
@@ -86,6 +87,15 @@ impl str::FromStr for RollingStockId {
     }
 }
 
+impl TryFrom<&str> for RollingStockId {
+    type Error = anyhow::Error;
+
+    /// Parse a `RollingStockId` from its string representation (see `FromStr`).
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 impl From<Uuid> for RollingStockId {
     /// Convert a `Uuid` into a `RollingStockId`.
     fn from(id: Uuid) -> Self {
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt;
 
+use crate::catalog::domain::enum_info::EnumMetadata;
 use crate::catalog::domain::ratio::Ratio;
 use crate::catalog::domain::scale_gauge::Gauge;
 
@@ -36,6 +37,20 @@ pub enum Scale {
 }
 
 impl Scale {
+    /// Every built-in `Scale` variant, in declaration order.
+    pub const ALL: [Scale; 10] = [
+        Scale::H0,
+        Scale::H0m,
+        Scale::H0e,
+        Scale::N,
+        Scale::TT,
+        Scale::Z,
+        Scale::G,
+        Scale::Scale1,
+        Scale::Scale0,
+        Scale::Scale00,
+    ];
+
     /// Returns the scale `Ratio` (the denominator in `1:ratio`).
     ///
     /// Examples: `Scale::H0` -> `1:87`, `Scale::G` -> `1:22.5`.
@@ -76,10 +91,12 @@ impl Scale {
     }
 }
 
-impl fmt::Display for Scale {
-    /// Format the scale as `LABEL (1:RATIO)`, for example `H0 (1:87)`.
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let label = match self {
+impl Scale {
+    /// Returns the short scale label, for example `"H0"` or `"00"`.
+    ///
+    /// This is the compact form accepted by `Scale::try_from`.
+    pub fn label(&self) -> &'static str {
+        match self {
             Scale::H0 => "H0",
             Scale::H0m => "H0m",
             Scale::H0e => "H0e",
@@ -90,10 +107,39 @@ impl fmt::Display for Scale {
             Scale::Scale1 => "1",
             Scale::Scale0 => "0",
             Scale::Scale00 => "00",
-        };
+        }
+    }
+}
+
+impl EnumMetadata for Scale {
+    const VARIANTS: &'static [Self] = &Scale::ALL;
+
+    /// The short scale label, the same as `Scale::label`.
+    fn label(&self) -> &'static str {
+        Scale::label(self)
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            Scale::H0 => "H0 scale (1:87), on standard gauge track.",
+            Scale::H0m => "H0 narrow gauge (1:87), for metre gauge prototypes.",
+            Scale::H0e => "H0e (1:87), for narrow gauge prototypes on N gauge track.",
+            Scale::N => "N scale (1:160).",
+            Scale::TT => "TT scale (1:120).",
+            Scale::Z => "Z scale (1:220).",
+            Scale::G => "G scale (1:22.5), for garden railways.",
+            Scale::Scale1 => "1 scale (1:32).",
+            Scale::Scale0 => "0 scale (1:43.5).",
+            Scale::Scale00 => "00 (double-zero) scale (1:76.2), on H0 gauge track.",
+        }
+    }
+}
 
+impl fmt::Display for Scale {
+    /// Format the scale as `LABEL (1:RATIO)`, for example `H0 (1:87)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // Delegate the numeric ratio formatting to `Ratio`'s Display implementation.
-        write!(f, "{} ({})", label, self.ratio())
+        write!(f, "{} ({})", self.label(), self.ratio())
     }
 }
 
@@ -192,6 +238,15 @@ mod tests {
         assert_eq!(parsed, expected);
     }
 
+    #[rstest]
+    #[case(Scale::H0)]
+    #[case(Scale::N)]
+    #[case(Scale::Scale1)]
+    #[case(Scale::Scale00)]
+    fn label_round_trips_through_try_from(#[case] scale: Scale) {
+        assert_eq!(Scale::try_from(scale.label()).unwrap(), scale);
+    }
+
     #[test]
     fn try_from_invalid_returns_error() {
         let err = Scale::try_from("unknown");
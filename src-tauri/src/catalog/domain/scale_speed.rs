@@ -0,0 +1,150 @@
+use crate::catalog::domain::Scale;
+use crate::core::domain::length::Length;
+use crate::core::domain::measure_units::MeasureUnit;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Kilometers per hour in one meter per second.
+const KMH_IN_METERS_PER_SECOND: Decimal = dec!(3.6);
+
+/// The prototype speed of the reference run of `ScaleSpeed`.
+const REFERENCE_SPEED_KMH: Decimal = dec!(100);
+
+/// The speed of a model timed over a stretch of track, with the speed of
+/// the prototype it stands for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct ScaleSpeed {
+    /// The speed of the model, in km/h.
+    pub model_speed_kmh: f64,
+
+    /// The speed of the prototype at the scale of the model, in km/h.
+    pub prototype_speed_kmh: f64,
+
+    /// How many seconds a model of the same scale takes over 1 m of track
+    /// when running at 100 scale km/h, to time the runs against.
+    pub reference_seconds_per_meter: f64,
+}
+
+/// Why a scale speed cannot be calculated.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ScaleSpeedError {
+    /// A run takes some time.
+    #[error("the time must be positive (value: {0})")]
+    NonPositiveTime(Decimal),
+
+    /// A train that does not move takes forever.
+    #[error("the speed must be positive (value: {0})")]
+    NonPositiveSpeed(Decimal),
+}
+
+/// The speed of a model of `scale` that ran `distance` in `seconds`.
+///
+/// # Errors
+///
+/// Returns `ScaleSpeedError::NonPositiveTime` when `seconds` is not
+/// positive.
+pub fn calculate_scale_speed(
+    distance: Length,
+    seconds: Decimal,
+    scale: &Scale,
+) -> Result<ScaleSpeed, ScaleSpeedError> {
+    if seconds <= Decimal::ZERO {
+        return Err(ScaleSpeedError::NonPositiveTime(seconds));
+    }
+    let model_speed_kmh =
+        distance.get_value_as(MeasureUnit::Meters) / seconds * KMH_IN_METERS_PER_SECOND;
+    let reference_seconds =
+        scale_travel_time(Length::Meters(Decimal::ONE), REFERENCE_SPEED_KMH, scale)?;
+
+    Ok(ScaleSpeed {
+        model_speed_kmh: to_f64(model_speed_kmh),
+        prototype_speed_kmh: to_f64(scale.ratio().scale_speed(model_speed_kmh)),
+        reference_seconds_per_meter: to_f64(reference_seconds),
+    })
+}
+
+/// How many seconds a model of `scale` takes over `distance` when running
+/// at `prototype_speed_kmh` scale km/h; the inverse of
+/// `calculate_scale_speed`.
+///
+/// # Errors
+///
+/// Returns `ScaleSpeedError::NonPositiveSpeed` when `prototype_speed_kmh`
+/// is not positive.
+pub fn scale_travel_time(
+    distance: Length,
+    prototype_speed_kmh: Decimal,
+    scale: &Scale,
+) -> Result<Decimal, ScaleSpeedError> {
+    if prototype_speed_kmh <= Decimal::ZERO {
+        return Err(ScaleSpeedError::NonPositiveSpeed(prototype_speed_kmh));
+    }
+    let prototype_meters = scale
+        .ratio()
+        .scale_speed(distance.get_value_as(MeasureUnit::Meters));
+    Ok(prototype_meters * KMH_IN_METERS_PER_SECOND / prototype_speed_kmh)
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn h0_speeds_are_scaled_by_87() {
+        // 1 m in 10 s is 0.36 km/h; 87 times that is 31.32 km/h.
+        let speed = calculate_scale_speed(Length::Meters(dec!(1)), dec!(10), &Scale::H0).unwrap();
+
+        assert_eq!(
+            speed,
+            ScaleSpeed {
+                model_speed_kmh: 0.36,
+                prototype_speed_kmh: 31.32,
+                // 87 m at 100 km/h (27.7 m/s) take 3.132 s.
+                reference_seconds_per_meter: 3.132,
+            }
+        );
+    }
+
+    #[test]
+    fn n_speeds_are_scaled_by_160() {
+        // 500 mm in 10 s is 0.18 km/h; 160 times that is 28.8 km/h.
+        let speed =
+            calculate_scale_speed(Length::Millimeters(dec!(500)), dec!(10), &Scale::N).unwrap();
+
+        assert_eq!(speed.model_speed_kmh, 0.18);
+        assert_eq!(speed.prototype_speed_kmh, 28.8);
+        assert_eq!(speed.reference_seconds_per_meter, 5.76);
+    }
+
+    #[test]
+    fn travel_times_are_the_inverse_of_speeds() {
+        assert_eq!(
+            scale_travel_time(Length::Meters(dec!(2)), dec!(50), &Scale::H0),
+            Ok(dec!(12.528))
+        );
+        assert_eq!(
+            scale_travel_time(Length::Meters(dec!(1)), dec!(28.8), &Scale::N),
+            Ok(dec!(20))
+        );
+    }
+
+    #[test]
+    fn times_and_speeds_must_be_positive() {
+        assert_eq!(
+            calculate_scale_speed(Length::Meters(dec!(1)), Decimal::ZERO, &Scale::H0),
+            Err(ScaleSpeedError::NonPositiveTime(Decimal::ZERO))
+        );
+        assert_eq!(
+            scale_travel_time(Length::Meters(dec!(1)), dec!(-10), &Scale::H0),
+            Err(ScaleSpeedError::NonPositiveSpeed(dec!(-10)))
+        );
+    }
+}
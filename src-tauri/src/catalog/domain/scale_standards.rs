@@ -0,0 +1,153 @@
+use crate::catalog::domain::Scale;
+use crate::catalog::domain::radius::Radius;
+use crate::catalog::domain::ratio::Ratio;
+use crate::catalog::domain::scale_gauge::Gauge;
+use crate::core::domain::length::Length;
+use once_cell::sync::Lazy;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+/// The normative data of a modelling scale, after the NEM/NMRA standards.
+///
+/// The ratio and gauge are the ones of `Scale::ratio` and `Scale::gauge`.
+/// The minimum radius is the tightest curve recommended for the scale;
+/// the coupler height is the 1065 mm buffer height of European standard
+/// gauge stock at the scale ratio, above the top of the rail.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScaleStandards {
+    /// The scale these standards apply to.
+    pub scale: Scale,
+
+    /// The scale ratio (NEM 010).
+    pub ratio: Ratio,
+
+    /// The track gauge.
+    pub gauge: Gauge,
+
+    /// The tightest curve recommended for the scale.
+    pub minimum_radius: Radius,
+
+    /// The height of the couplers above the top of the rail.
+    pub coupler_height: Length,
+}
+
+/// The standards of every scale, in `Scale::ALL` order.
+static SCALE_STANDARDS: Lazy<Vec<ScaleStandards>> = Lazy::new(|| {
+    Scale::ALL
+        .iter()
+        .map(|scale| {
+            let (minimum_radius_mm, coupler_height_mm) = match scale {
+                Scale::H0 => (dec!(356), dec!(12.2)),
+                Scale::H0m => (dec!(250), dec!(12.2)),
+                Scale::H0e => (dec!(177), dec!(12.2)),
+                Scale::N => (dec!(190), dec!(6.7)),
+                Scale::TT => (dec!(267), dec!(8.9)),
+                Scale::Z => (dec!(145), dec!(4.8)),
+                Scale::G => (dec!(600), dec!(47.3)),
+                Scale::Scale1 => (dec!(1020), dec!(33.3)),
+                Scale::Scale0 => (dec!(914), dec!(24.5)),
+                Scale::Scale00 => (dec!(371), dec!(14.0)),
+            };
+            ScaleStandards {
+                scale: scale.clone(),
+                ratio: scale.ratio(),
+                gauge: scale.gauge(),
+                minimum_radius: Radius::from_millimeters(minimum_radius_mm)
+                    .expect("positive minimum radius"),
+                coupler_height: Length::Millimeters(coupler_height_mm),
+            }
+        })
+        .collect()
+});
+
+impl Scale {
+    /// The normative data of this scale.
+    pub fn standards(&self) -> &'static ScaleStandards {
+        SCALE_STANDARDS
+            .iter()
+            .find(|standards| &standards.scale == self)
+            .expect("every scale has standards")
+    }
+}
+
+impl ScaleStandards {
+    /// The warning for a rolling stock with `rolling_stock_id` declaring it
+    /// runs on curves of `minimum_radius`, when that is tighter than the
+    /// minimum of the scale: the declared radius is most likely a mistake.
+    pub fn check_minimum_radius(
+        &self,
+        rolling_stock_id: &str,
+        minimum_radius: Radius,
+    ) -> Option<StandardsWarning> {
+        (minimum_radius < self.minimum_radius).then(|| StandardsWarning::RadiusBelowMinimum {
+            rolling_stock_id: rolling_stock_id.to_string(),
+            declared_radius_mm: to_f64(minimum_radius.as_millimeters()),
+            minimum_radius_mm: to_f64(self.minimum_radius.as_millimeters()),
+        })
+    }
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+/// A catalog value at odds with the standards of its scale.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StandardsWarning {
+    /// The rolling stock declares a minimum radius tighter than the minimum
+    /// of its scale.
+    RadiusBelowMinimum {
+        rolling_stock_id: String,
+        declared_radius_mm: f64,
+        minimum_radius_mm: f64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn every_scale_has_its_standards() {
+        for scale in Scale::ALL {
+            let standards = scale.standards();
+            assert_eq!(standards.scale, scale);
+            assert_eq!(standards.ratio, scale.ratio());
+            assert_eq!(standards.gauge, scale.gauge());
+        }
+    }
+
+    #[test]
+    fn h0_and_n_standards() {
+        let h0 = Scale::H0.standards();
+        assert_eq!(h0.ratio, Ratio::r87());
+        assert_eq!(h0.gauge, Gauge::H0);
+        assert!(h0.minimum_radius.fits_on(Radius::H0_R1));
+        assert_eq!(h0.coupler_height, Length::Millimeters(dec!(12.2)));
+
+        let n = Scale::N.standards();
+        assert_eq!(n.ratio, Ratio::r160());
+        assert_eq!(n.gauge, Gauge::N);
+        assert!(n.minimum_radius.fits_on(Radius::N_R1));
+        assert!(n.coupler_height < h0.coupler_height);
+    }
+
+    #[test]
+    fn radii_tighter_than_the_minimum_are_flagged() {
+        let h0 = Scale::H0.standards();
+
+        assert_eq!(h0.check_minimum_radius("rs-1", Radius::H0_R1), None);
+        assert_eq!(
+            h0.check_minimum_radius("rs-1", Radius::N_R1),
+            Some(StandardsWarning::RadiusBelowMinimum {
+                rolling_stock_id: "rs-1".to_string(),
+                declared_radius_mm: 192.0,
+                minimum_radius_mm: 356.0,
+            })
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use std::fmt::{self, Display, Formatter};
@@ -59,6 +60,15 @@ impl TryFrom<&str> for ServiceLevel {
     }
 }
 
+enum_metadata!(ServiceLevel {
+    First => ("First class", "Passenger accommodation in first class only."),
+    Second => ("Second class", "Passenger accommodation in second class only."),
+    Third => ("Third class", "Passenger accommodation in third class only."),
+    FirstSecond => ("First and second class", "Passenger accommodation in both first and second class."),
+    SecondThird => ("Second and third class", "Passenger accommodation in both second and third class."),
+    FirstSecondThird => ("First, second and third class", "Passenger accommodation in first, second and third class."),
+});
+
 #[cfg(test)]
 mod tests {
     use super::*;
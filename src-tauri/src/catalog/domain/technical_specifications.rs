@@ -112,7 +112,11 @@ mod test {
 
     #[test]
     fn it_should_create_tech_specs() {
-        let coupling = Coupling::new(CouplingSocket::Nem362, FeatureFlag::Yes, FeatureFlag::No);
+        let coupling = Coupling::builder()
+            .with_socket(CouplingSocket::Nem362)
+            .with_close_couplers(FeatureFlag::Yes)
+            .with_digital_shunting(FeatureFlag::No)
+            .build();
 
         let radius = Radius::from_millimeters(dec!(360)).unwrap();
         let tech_specs = TechnicalSpecificationsBuilder::default()
@@ -127,6 +131,11 @@ mod test {
             .build();
 
         assert_eq!(Some(coupling), tech_specs.coupling);
+        assert!(tech_specs.coupling.is_some_and(|c| c.has_close_couplers()));
+        assert_eq!(
+            Some("NEM 362, close couplers".to_string()),
+            tech_specs.coupling.map(|c| c.to_string())
+        );
         assert_eq!(Some(radius), tech_specs.minimum_radius);
         assert_eq!(Some(ChassisType::Plastic), tech_specs.chassis);
         assert_eq!(Some(BodyShellType::MetalDieCast), tech_specs.body_shell);
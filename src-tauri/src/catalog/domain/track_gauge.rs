@@ -1,3 +1,4 @@
+use crate::catalog::domain::enum_info::enum_metadata;
 use serde::{Deserialize, Serialize};
 use strum_macros;
 use strum_macros::{Display, EnumString};
@@ -53,6 +54,14 @@ pub enum TrackGauge {
     Standard,
 }
 
+enum_metadata!(TrackGauge {
+    Broad => ("Broad gauge", "Track spaced significantly wider than 1,435 mm (4 ft 8 1/2 in)."),
+    Medium => ("Medium gauge", "A gauge whose meaning changed through history, depending on the local dominant gauge."),
+    Minimum => ("Minimum gauge", "Very narrow gauges of under 2 feet (610 mm), used for some industrial railways in mines or farms."),
+    Narrow => ("Narrow gauge", "Track spaced significantly narrower than 1,435 mm (4 ft 8 1/2 in)."),
+    Standard => ("Standard gauge", "Track spaced 1,435 mm (4 ft 8 1/2 in), dominant in most of Europe, North America and China."),
+});
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -0,0 +1,359 @@
+//! Read-through cache of railway model summaries.
+//!
+//! Every collection item is displayed with the manufacturer name and the
+//! description of its railway model. `RailwayModelCache` keeps the most
+//! recently used summaries, keyed by railway model id, so that showing the
+//! collection does not look each model up in the catalog again. Misses are
+//! loaded with a single query, and the least recently used entries are
+//! evicted once the cache is full.
+//!
+//! Entries are dropped by the catalog writes that change them (see
+//! `SqliteCatalogRepository::update_railway_model` and
+//! `merge_railway_models`) and all at once by the
+//! `clear_cache` command. Hits and misses are counted and reported by
+//! `get_app_info`.
+
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::domain::railway_model_summary::RailwayModelSummary;
+use crate::catalog::infrastructure::sqlite;
+use crate::catalog::infrastructure::sqlite_repo::SqliteCatalogRepository;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// How many railway model summaries are kept by default.
+pub const DEFAULT_CAPACITY: usize = 1_000;
+
+/// A snapshot of the railway model cache.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CatalogCacheMetrics {
+    /// The maximum number of cached summaries.
+    pub capacity: u32,
+    /// The number of cached summaries.
+    pub entries: u32,
+    /// How many lookups were answered from the cache.
+    pub hits: u32,
+    /// How many lookups had to query the database.
+    pub misses: u32,
+}
+
+#[derive(Debug)]
+struct Entry {
+    summary: RailwayModelSummary,
+    last_used: u64,
+}
+
+/// The cached summaries, with their recency order.
+#[derive(Debug)]
+struct Lru {
+    capacity: usize,
+    entries: HashMap<RailwayModelId, Entry>,
+    /// Railway model ids by the tick they were last used at, oldest first.
+    recency: BTreeMap<u64, RailwayModelId>,
+    tick: u64,
+    /// Bumped by every invalidation, so that a load that raced with one does
+    /// not store what it read.
+    generation: u64,
+    hits: u32,
+    misses: u32,
+}
+
+impl Lru {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
+            generation: 0,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn get(&mut self, railway_model_id: &RailwayModelId) -> Option<RailwayModelSummary> {
+        let tick = self.next_tick();
+        let entry = self.entries.get_mut(railway_model_id)?;
+        self.recency.remove(&entry.last_used);
+        entry.last_used = tick;
+        self.recency.insert(tick, *railway_model_id);
+        Some(entry.summary.clone())
+    }
+
+    fn insert(&mut self, summary: RailwayModelSummary) {
+        let railway_model_id = summary.id;
+        let tick = self.next_tick();
+        let entry = Entry {
+            summary,
+            last_used: tick,
+        };
+        if let Some(previous) = self.entries.insert(railway_model_id, entry) {
+            self.recency.remove(&previous.last_used);
+        }
+        self.recency.insert(tick, railway_model_id);
+
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+
+    fn remove(&mut self, railway_model_id: &RailwayModelId) {
+        self.generation += 1;
+        if let Some(entry) = self.entries.remove(railway_model_id) {
+            self.recency.remove(&entry.last_used);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.generation += 1;
+        self.entries.clear();
+        self.recency.clear();
+    }
+}
+
+/// Caches railway model summaries by id.
+///
+/// Cloning is cheap; clones share the cached entries and the counters.
+#[derive(Debug, Clone)]
+pub struct RailwayModelCache {
+    lru: Arc<Mutex<Lru>>,
+}
+
+impl Default for RailwayModelCache {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+}
+
+impl RailwayModelCache {
+    /// Create an empty cache holding at most `capacity` summaries.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            lru: Arc::new(Mutex::new(Lru::new(capacity))),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Lru> {
+        self.lru.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// The summary of the railway model with `railway_model_id`, or `None`
+    /// when there is no such model.
+    pub async fn get(
+        &self,
+        pool: &SqlitePool,
+        railway_model_id: &RailwayModelId,
+    ) -> Result<Option<RailwayModelSummary>> {
+        Ok(self
+            .get_many(pool, &[*railway_model_id])
+            .await?
+            .remove(railway_model_id))
+    }
+
+    /// The summaries of the railway models with the given ids, keyed by id.
+    ///
+    /// Cached summaries are returned as they are; the others are loaded with
+    /// one query and cached. Unknown ids are left out of the result.
+    pub async fn get_many(
+        &self,
+        pool: &SqlitePool,
+        railway_model_ids: &[RailwayModelId],
+    ) -> Result<HashMap<RailwayModelId, RailwayModelSummary>> {
+        let mut summaries = HashMap::new();
+        let mut missing = Vec::new();
+        let generation = {
+            let mut lru = self.lock();
+            let mut seen = HashSet::new();
+            for &railway_model_id in railway_model_ids {
+                if !seen.insert(railway_model_id) {
+                    continue;
+                }
+                match lru.get(&railway_model_id) {
+                    Some(summary) => {
+                        lru.hits = lru.hits.saturating_add(1);
+                        summaries.insert(railway_model_id, summary);
+                    }
+                    None => missing.push(railway_model_id),
+                }
+            }
+            let misses = u32::try_from(missing.len()).unwrap_or(u32::MAX);
+            lru.misses = lru.misses.saturating_add(misses);
+            lru.generation
+        };
+
+        if missing.is_empty() {
+            return Ok(summaries);
+        }
+
+        let loaded = sqlite::get_railway_model_summaries(pool, &missing)
+            .await?
+            .into_iter()
+            .map(SqliteCatalogRepository::build_railway_model_summary)
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut lru = self.lock();
+        for summary in loaded {
+            if lru.generation == generation {
+                lru.insert(summary.clone());
+            }
+            summaries.insert(summary.id, summary);
+        }
+
+        Ok(summaries)
+    }
+
+    /// Drop the cached summary of the railway model with `railway_model_id`.
+    pub fn invalidate(&self, railway_model_id: &RailwayModelId) {
+        self.lock().remove(railway_model_id);
+    }
+
+    /// Drop every cached summary. The hit and miss counters are kept.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// The capacity, the number of cached summaries and the hits and misses
+    /// counted so far.
+    pub fn metrics(&self) -> CatalogCacheMetrics {
+        let lru = self.lock();
+        CatalogCacheMetrics {
+            capacity: u32::try_from(lru.capacity).unwrap_or(u32::MAX),
+            entries: u32::try_from(lru.entries.len()).unwrap_or(u32::MAX),
+            hits: lru.hits,
+            misses: lru.misses,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use pretty_assertions::assert_eq;
+
+    async fn insert_models(pool: &SqlitePool, count: usize) -> Result<Vec<RailwayModelId>> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        catalog_db.insert_manufacturer("m1", "ACME").await?;
+
+        let mut railway_model_ids = Vec::new();
+        for n in 0..count {
+            let railway_model_id = RailwayModelId::new();
+            catalog_db
+                .insert_railway_model(
+                    &railway_model_id,
+                    "m1",
+                    &format!("HR{n:04}"),
+                    &format!("Model {n}"),
+                    "electric",
+                    "H0",
+                    "IV",
+                    "LOCOMOTIVES",
+                )
+                .await?;
+            railway_model_ids.push(railway_model_id);
+        }
+        Ok(railway_model_ids)
+    }
+
+    fn is_cached(cache: &RailwayModelCache, railway_model_id: &RailwayModelId) -> bool {
+        cache.lock().entries.contains_key(railway_model_id)
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn get_reads_through_and_counts_hits_and_misses(pool: SqlitePool) -> Result<()> {
+        let ids = insert_models(&pool, 1).await?;
+        let cache = RailwayModelCache::default();
+
+        let loaded = cache.get(&pool, &ids[0]).await?.expect("summary");
+        let cached = cache.get(&pool, &ids[0]).await?.expect("summary");
+        assert!(cache.get(&pool, &RailwayModelId::new()).await?.is_none());
+
+        assert_eq!(loaded, cached);
+        assert_eq!(cached.manufacturer, "ACME");
+        assert_eq!(cached.description, "Model 0");
+        assert_eq!(
+            cache.metrics(),
+            CatalogCacheMetrics {
+                capacity: DEFAULT_CAPACITY as u32,
+                entries: 1,
+                hits: 1,
+                misses: 2,
+            }
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn update_railway_model_invalidates_the_cached_summary(pool: SqlitePool) -> Result<()> {
+        let ids = insert_models(&pool, 1).await?;
+        let cache = RailwayModelCache::default();
+        let repo = SqliteCatalogRepository::new(pool.clone()).with_cache(cache.clone());
+
+        let before = cache.get(&pool, &ids[0]).await?.expect("summary");
+        assert_eq!(before.description, "Model 0");
+
+        let mut row = sqlite::get_railway_model(&pool, &ids[0])
+            .await?
+            .expect("railway model row");
+        row.description = "Model 0, renumbered".to_string();
+        repo.update_railway_model(&row).await?;
+        assert!(!is_cached(&cache, &ids[0]));
+
+        let after = cache.get(&pool, &ids[0]).await?.expect("summary");
+        assert_eq!(after.description, "Model 0, renumbered");
+        assert_eq!(cache.metrics().misses, 2);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn growth_is_bounded_by_evicting_the_least_recently_used(pool: SqlitePool) -> Result<()> {
+        let ids = insert_models(&pool, 3).await?;
+        let cache = RailwayModelCache::with_capacity(2);
+
+        cache.get(&pool, &ids[0]).await?;
+        cache.get(&pool, &ids[1]).await?;
+        cache.get(&pool, &ids[0]).await?;
+        cache.get(&pool, &ids[2]).await?;
+
+        assert_eq!(cache.metrics().entries, 2);
+        assert!(is_cached(&cache, &ids[0]));
+        assert!(!is_cached(&cache, &ids[1]));
+        assert!(is_cached(&cache, &ids[2]));
+
+        // Loading more misses at once than fit still keeps the bound.
+        let summaries = cache.get_many(&pool, &ids).await?;
+        assert_eq!(summaries.len(), 3);
+        assert_eq!(cache.metrics().entries, 2);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn clear_drops_every_entry_but_keeps_the_counters(pool: SqlitePool) -> Result<()> {
+        let ids = insert_models(&pool, 2).await?;
+        let cache = RailwayModelCache::default();
+        cache.get_many(&pool, &ids).await?;
+
+        cache.clear();
+
+        let metrics = cache.metrics();
+        assert_eq!(metrics.entries, 0);
+        assert_eq!(metrics.misses, 2);
+        assert!(cache.get(&pool, &ids[0]).await?.is_some());
+        assert_eq!(cache.metrics().misses, 3);
+
+        Ok(())
+    }
+}
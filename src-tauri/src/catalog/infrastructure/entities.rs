@@ -0,0 +1,157 @@
+//! Database row representations for the `catalog` feature.
+//!
+//! These structs mirror the columns defined in the
+//! `0001_create_railway_models_and_rolling_stocks` migration (or projections
+//! of them, plus the `version` column added by `0006_add_row_versions`) and are intended only as a thin database representation
+//! (FromRow, and the target types of the `query_as!` macros). Conversion to rich domain types should happen in the
+//! repository layer.
+
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::domain::rolling_stock_id::RollingStockId;
+use crate::core::infrastructure::id_column::text_id_column;
+use chrono::{DateTime, NaiveDate, Utc};
+
+text_id_column!(RailwayModelId);
+text_id_column!(RollingStockId);
+
+/// Row mapping for the `railway_models` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct RailwayModelRow {
+    pub id: RailwayModelId,
+    pub manufacturer_id: String,
+    pub product_code: String,
+    pub description: String,
+    pub details: Option<String>,
+    pub power_method: String,
+    pub scale: String,
+    pub epoch: String,
+    pub category: String,
+    pub delivery_date: Option<String>,
+    pub availability_status: Option<String>,
+    pub msrp_amount: Option<i64>,
+    pub msrp_currency: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub version: i64,
+}
+
+/// Row mapping for the announced values of a `railway_models` row: its
+/// delivery date and suggested retail price (see
+/// `sqlite::get_railway_model_announcement`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct RailwayModelAnnouncementRow {
+    pub id: RailwayModelId,
+    pub delivery_date: Option<String>,
+    pub msrp_amount: Option<i64>,
+    pub msrp_currency: Option<String>,
+}
+
+/// Row mapping for a `railway_models` projection joined with its manufacturer.
+#[derive(Debug, sqlx::FromRow)]
+pub struct RailwayModelSummaryRow {
+    pub id: RailwayModelId,
+    pub manufacturer: String,
+    pub product_code: String,
+    pub description: String,
+    pub scale: String,
+}
+
+/// A looked up manufacturer and product code, by their lookup keys, with the
+/// railway model that has them (see
+/// `sqlite::find_railway_models_by_product_codes`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct ProductCodeMatchRow {
+    pub manufacturer: String,
+    pub product_code: String,
+    pub railway_model_id: RailwayModelId,
+}
+
+/// Row mapping for a railway model summary with the id of its manufacturer
+/// (see `sqlite::list_railway_models_by_manufacturer_and_scale`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct RailwayModelScanRow {
+    pub id: RailwayModelId,
+    pub manufacturer_id: String,
+    pub manufacturer: String,
+    pub product_code: String,
+    pub description: String,
+    pub scale: String,
+}
+
+/// Row mapping for a railway model summary with the aggregates of its
+/// rolling stocks (see `sqlite::list_railway_models_page`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct RailwayModelListRow {
+    pub id: RailwayModelId,
+    pub manufacturer: String,
+    pub product_code: String,
+    pub description: String,
+    pub scale: String,
+    pub rolling_stocks_count: i64,
+    pub min_length_over_buffers_mm: Option<f64>,
+    pub max_length_over_buffers_mm: Option<f64>,
+}
+
+/// Row mapping for a `manufacturers` projection used by form dropdowns.
+#[derive(Debug, sqlx::FromRow)]
+pub struct ManufacturerOptionRow {
+    pub id: String,
+    pub name: String,
+}
+
+/// Row mapping for a `railway_companies` projection used by form dropdowns.
+#[derive(Debug, sqlx::FromRow)]
+pub struct RailwayCompanyOptionRow {
+    pub id: String,
+    pub name: String,
+    pub registered_company_name: Option<String>,
+    pub status: Option<String>,
+}
+
+/// Row mapping for the `liveries` table (migration `0019_create_liveries`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct LiveryRow {
+    pub id: String,
+    pub railway_company_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub in_use_since: Option<NaiveDate>,
+    pub in_use_until: Option<NaiveDate>,
+}
+
+/// Row mapping for the `rolling_stocks` table, with the era of its railway
+/// model, the name of its railway company and the livery it wears (see
+/// `sqlite::get_rolling_stock`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct RollingStockRow {
+    pub id: RollingStockId,
+    pub category: String,
+    pub railway_company_id: String,
+    pub railway_display: String,
+    pub epoch: String,
+    pub livery: Option<String>,
+    pub length_inches: Option<f64>,
+    pub length_millimeters: Option<f64>,
+    pub technical_minimum_radius_mm: Option<f64>,
+    pub technical_coupling: Option<String>,
+    pub technical_flywheel_fitted: Option<String>,
+    pub technical_body_shell: Option<String>,
+    pub technical_chassis: Option<String>,
+    pub technical_interior_lights: Option<String>,
+    pub technical_lights: Option<String>,
+    pub technical_sprung_buffers: Option<String>,
+    pub type_name: Option<String>,
+    pub class_name: Option<String>,
+    pub road_number: Option<String>,
+    pub series: Option<String>,
+    pub depot: Option<String>,
+    pub electric_multiple_unit_type: Option<String>,
+    pub freight_car_type: Option<String>,
+    pub locomotive_type: Option<String>,
+    pub passenger_car_type: Option<String>,
+    pub railcar_type: Option<String>,
+    pub service_level: Option<String>,
+    pub dcc_interface: Option<String>,
+    pub control: Option<String>,
+    pub is_dummy: bool,
+}
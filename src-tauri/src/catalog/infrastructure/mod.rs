@@ -1,2 +1,12 @@
+pub mod cache;
+
+pub mod entities;
+
+pub mod sqlite;
+
+pub mod sqlite_livery_repo;
+
+pub mod sqlite_repo;
+
 #[cfg(test)]
 pub mod testing;
@@ -0,0 +1,1113 @@
+//! SQLite helper functions (crate-internal) used to read catalog-related rows.
+//!
+//! These helpers return typed row representations defined in
+//! `crate::catalog::infrastructure::entities` and intentionally keep SQL and
+//! mapping logic separate from domain conversion. All queries use parameter
+//! binding to avoid string interpolation.
+//!
+//! The read queries are checked at compile time with the `sqlx::query_as!`
+//! macros against the metadata in `.sqlx` (see the README); the query plan
+//! test reads `queries/catalog` with `include_str!`, so those queries use
+//! `sqlx::query_file_as!`.
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use sqlx::{SqliteExecutor, SqlitePool};
+
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::infrastructure::entities::{
+    LiveryRow, ManufacturerOptionRow, ProductCodeMatchRow, RailwayCompanyOptionRow,
+    RailwayModelAnnouncementRow, RailwayModelListRow, RailwayModelRow, RailwayModelScanRow,
+    RailwayModelSummaryRow, RollingStockRow,
+};
+use crate::core::domain::page::{KeysetPage, PageKey};
+use crate::core::infrastructure::conflict::ConflictError;
+use crate::core::infrastructure::retry::{RetryPolicy, retry_on_busy};
+
+/// Fetch a single railway model row by id.
+pub async fn get_railway_model(
+    pool: &SqlitePool,
+    railway_model_id: &RailwayModelId,
+) -> Result<Option<RailwayModelRow>> {
+    let row = sqlx::query_as!(
+        RailwayModelRow,
+        r#"SELECT id AS "id!: _", manufacturer_id, product_code, description, details, power_method, scale, epoch, category, delivery_date, availability_status, msrp_amount, msrp_currency, created_at AS "created_at: _", updated_at AS "updated_at: _", version FROM railway_models WHERE id = ?1 LIMIT 1"#,
+        railway_model_id
+    )
+    .fetch_optional(pool)
+        .await
+        .with_context(|| format!("querying railway_model id={}", railway_model_id))?;
+
+    Ok(row)
+}
+
+/// Update the editable columns of a railway model, guarded by its version.
+///
+/// `row.version` must be the version the caller read: the update only
+/// applies when the stored row still has it, increments it and sets
+/// `updated_at` to the current UTC time. Returns the new version, or a
+/// `ConflictError<RailwayModelRow>` carrying the current row when a
+/// concurrent write got there first.
+pub async fn update_railway_model(pool: &SqlitePool, row: &RailwayModelRow) -> Result<i64> {
+    let sql = "UPDATE railway_models SET manufacturer_id = ?1, product_code = ?2, description = ?3, details = ?4, power_method = ?5, scale = ?6, epoch = ?7, category = ?8, delivery_date = ?9, availability_status = ?10, msrp_amount = ?14, msrp_currency = ?15, updated_at = ?13, version = version + 1
+        WHERE id = ?11 AND version = ?12";
+
+    let context = format!("updating railway_model id={}", row.id);
+    let result = retry_on_busy(&RetryPolicy::default(), &context, || {
+        sqlx::query(sql)
+            .bind(&row.manufacturer_id)
+            .bind(&row.product_code)
+            .bind(&row.description)
+            .bind(&row.details)
+            .bind(&row.power_method)
+            .bind(&row.scale)
+            .bind(&row.epoch)
+            .bind(&row.category)
+            .bind(&row.delivery_date)
+            .bind(&row.availability_status)
+            .bind(row.id)
+            .bind(row.version)
+            .bind(Utc::now())
+            .bind(row.msrp_amount)
+            .bind(&row.msrp_currency)
+            .execute(pool)
+    })
+    .await
+    .context(context)?;
+
+    if result.rows_affected() == 0 {
+        let current = get_railway_model(pool, &row.id).await?;
+        return Err(ConflictError {
+            entity: "railway_model",
+            id: row.id.to_string(),
+            expected_version: row.version,
+            current,
+        }
+        .into());
+    }
+
+    Ok(row.version + 1)
+}
+
+/// Insert a railway model.
+pub async fn insert_railway_model<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &RailwayModelRow,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO railway_models (id, manufacturer_id, product_code, description, details, power_method, scale, epoch, category, delivery_date, availability_status, msrp_amount, msrp_currency, created_at, updated_at, version)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+    )
+    .bind(row.id)
+    .bind(&row.manufacturer_id)
+    .bind(&row.product_code)
+    .bind(&row.description)
+    .bind(&row.details)
+    .bind(&row.power_method)
+    .bind(&row.scale)
+    .bind(&row.epoch)
+    .bind(&row.category)
+    .bind(&row.delivery_date)
+    .bind(&row.availability_status)
+    .bind(row.msrp_amount)
+    .bind(&row.msrp_currency)
+    .bind(row.created_at)
+    .bind(row.updated_at)
+    .bind(row.version)
+    .execute(executor)
+    .await
+    .with_context(|| format!("inserting railway_model id={}", row.id))?;
+
+    Ok(())
+}
+
+/// Fetch the delivery date and suggested retail price of the railway model
+/// from `manufacturer_id` with the given product code (ignoring case).
+pub async fn get_railway_model_announcement<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    manufacturer_id: &str,
+    product_code: &str,
+) -> Result<Option<RailwayModelAnnouncementRow>> {
+    let row = sqlx::query_as!(
+        RailwayModelAnnouncementRow,
+        r#"SELECT id AS "id!: _", delivery_date, msrp_amount, msrp_currency
+        FROM railway_models
+        WHERE manufacturer_id = ?1 AND product_code = ?2 COLLATE NOCASE
+        LIMIT 1"#,
+        manufacturer_id,
+        product_code
+    )
+    .fetch_optional(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "fetching railway_model manufacturer_id={} product_code={}",
+            manufacturer_id, product_code
+        )
+    })?;
+
+    Ok(row)
+}
+
+/// Store the announced values of `row` in its railway model, incrementing
+/// its version and setting `updated_at` to the current UTC time.
+pub async fn update_railway_model_announcement<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &RailwayModelAnnouncementRow,
+) -> Result<()> {
+    sqlx::query(
+        "UPDATE railway_models SET delivery_date = ?2, msrp_amount = ?3, msrp_currency = ?4, updated_at = ?5, version = version + 1 WHERE id = ?1",
+    )
+    .bind(row.id)
+    .bind(&row.delivery_date)
+    .bind(row.msrp_amount)
+    .bind(&row.msrp_currency)
+    .bind(Utc::now())
+    .execute(executor)
+    .await
+    .with_context(|| format!("updating railway_model announcement id={}", row.id))?;
+
+    Ok(())
+}
+
+/// Search railway models by product code, description or manufacturer name.
+///
+/// The match is a case-insensitive substring match (`LIKE`), with `%` and `_`
+/// in `query` escaped so they are matched literally. Rows are ordered by
+/// relevance: exact product code matches first, then product code prefix
+/// matches, then everything else; ties are broken by product code.
+///
+/// Returns at most `limit` rows.
+pub async fn search_railway_models(
+    pool: &SqlitePool,
+    query: &str,
+    limit: u32,
+) -> Result<Vec<RailwayModelSummaryRow>> {
+    let escaped = escape_like(query);
+    let contains = format!("%{}%", escaped);
+    let starts_with = format!("{}%", escaped);
+
+    let rows = sqlx::query_as!(
+        RailwayModelSummaryRow,
+        r#"SELECT rm.id AS "id!: _", m.name AS manufacturer, rm.product_code, rm.description, rm.scale
+        FROM railway_models rm
+        JOIN manufacturers m ON m.id = rm.manufacturer_id
+        WHERE rm.product_code LIKE ?1 ESCAPE '\' OR rm.description LIKE ?1 ESCAPE '\' OR m.name LIKE ?1 ESCAPE '\'
+        ORDER BY CASE
+            WHEN rm.product_code = ?2 COLLATE NOCASE THEN 0
+            WHEN rm.product_code LIKE ?3 ESCAPE '\' THEN 1
+            ELSE 2
+        END, rm.product_code
+        LIMIT ?4"#,
+        contains,
+        query,
+        starts_with,
+        limit
+    )
+    .fetch_all(pool)
+        .await
+        .with_context(|| format!("searching railway_models query={}", query))?;
+
+    Ok(rows)
+}
+
+/// Fetch the railway model from `manufacturer_id` with the given product code.
+///
+/// The product code is compared case-insensitively (`COLLATE NOCASE`).
+pub async fn find_railway_model_by_product_code(
+    pool: &SqlitePool,
+    manufacturer_id: &str,
+    product_code: &str,
+) -> Result<Option<RailwayModelSummaryRow>> {
+    let row = sqlx::query_file_as!(
+        RailwayModelSummaryRow,
+        "queries/catalog/railway_model_by_product_code.sql",
+        manufacturer_id,
+        product_code
+    )
+    .fetch_optional(pool)
+    .await
+    .with_context(|| {
+        format!(
+            "fetching railway_model manufacturer_id={} product_code={}",
+            manufacturer_id, product_code
+        )
+    })?;
+
+    Ok(row)
+}
+
+/// Fetch the summaries of the railway models with the given ids in a single
+/// query.
+///
+/// The ids are bound as one JSON array. Unknown ids are skipped and rows come
+/// back in no particular order.
+pub async fn get_railway_model_summaries(
+    pool: &SqlitePool,
+    railway_model_ids: &[RailwayModelId],
+) -> Result<Vec<RailwayModelSummaryRow>> {
+    let ids = serde_json::to_string(railway_model_ids).context("encoding railway_model ids")?;
+
+    let rows = sqlx::query_as!(
+        RailwayModelSummaryRow,
+        r#"SELECT rm.id AS "id!: _", m.name AS manufacturer, rm.product_code, rm.description, rm.scale
+        FROM railway_models rm
+        JOIN manufacturers m ON m.id = rm.manufacturer_id
+        WHERE rm.id IN (SELECT value FROM json_each(?1))"#,
+        ids
+    )
+    .fetch_all(pool)
+    .await
+    .with_context(|| {
+        format!(
+            "fetching railway_model summaries for {} ids",
+            railway_model_ids.len()
+        )
+    })?;
+
+    Ok(rows)
+}
+
+/// Fetch a page of railway model summaries ordered by product code and id,
+/// starting after the railway model with the `after` keys (from the first
+/// one when `None`).
+///
+/// Every row carries the number of rolling stocks of the model and the
+/// shortest and longest of their lengths over buffers (in millimeters,
+/// converted from inches when only those are known), computed by the same
+/// grouped query. Returns at most `limit` rows, with the keys of the last
+/// one when more follow.
+pub async fn list_railway_models_page(
+    pool: &SqlitePool,
+    after: Option<&PageKey>,
+    limit: u32,
+) -> Result<KeysetPage<RailwayModelListRow>> {
+    let (product_code, id) = after.map_or(("", ""), |key| (&key.sort_key, &key.id));
+    let look_ahead = limit.saturating_add(1);
+
+    let rows = sqlx::query_file_as!(
+        RailwayModelListRow,
+        "queries/catalog/railway_models_page.sql",
+        product_code,
+        id,
+        look_ahead
+    )
+    .fetch_all(pool)
+    .await
+    .with_context(|| {
+        format!(
+            "listing railway_models after product_code={} id={}",
+            product_code, id
+        )
+    })?;
+
+    Ok(KeysetPage::from_rows(rows, limit, |row| PageKey {
+        sort_key: row.product_code.clone(),
+        id: row.id.to_string(),
+    }))
+}
+
+/// Fetch every railway model with its manufacturer id, grouped by
+/// manufacturer and scale and ordered by product code within a group.
+pub async fn list_railway_models_by_manufacturer_and_scale(
+    pool: &SqlitePool,
+) -> Result<Vec<RailwayModelScanRow>> {
+    let rows = sqlx::query_as!(
+        RailwayModelScanRow,
+        r#"SELECT rm.id AS "id!: _", rm.manufacturer_id, m.name AS manufacturer, rm.product_code, rm.description, rm.scale
+        FROM railway_models rm
+        JOIN manufacturers m ON m.id = rm.manufacturer_id
+        ORDER BY rm.manufacturer_id, rm.scale, rm.product_code, rm.id"#
+    )
+    .fetch_all(pool)
+    .await
+    .context("listing railway_models by manufacturer and scale")?;
+
+    Ok(rows)
+}
+
+/// Fetch the manufacturer id of the railway model with `railway_model_id`.
+pub async fn get_railway_model_manufacturer_id<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    railway_model_id: &RailwayModelId,
+) -> Result<Option<String>> {
+    let manufacturer_id = sqlx::query_scalar!(
+        "SELECT manufacturer_id FROM railway_models WHERE id = ?1",
+        railway_model_id
+    )
+    .fetch_optional(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying manufacturer of railway_model id={}",
+            railway_model_id
+        )
+    })?;
+
+    Ok(manufacturer_id)
+}
+
+/// Move the rolling stocks of the railway model `from_id` to `to_id`,
+/// setting their `updated_at` to the current UTC time.
+///
+/// Returns the number of rolling stocks moved.
+pub async fn move_rolling_stocks<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    from_id: &RailwayModelId,
+    to_id: &RailwayModelId,
+) -> Result<u64> {
+    let result = sqlx::query(
+        "UPDATE rolling_stocks SET railway_model_id = ?2, updated_at = ?3 WHERE railway_model_id = ?1",
+    )
+    .bind(from_id)
+    .bind(to_id)
+    .bind(Utc::now())
+    .execute(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "moving rolling_stocks from railway_model id={} to id={}",
+            from_id, to_id
+        )
+    })?;
+
+    Ok(result.rows_affected())
+}
+
+/// Point the collection items referencing the railway model `from_id` to
+/// `to_id`, incrementing their version and setting their `updated_at` to the
+/// current UTC time.
+///
+/// Returns the number of collection items repointed.
+pub async fn move_collection_items<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    from_id: &RailwayModelId,
+    to_id: &RailwayModelId,
+) -> Result<u64> {
+    let result = sqlx::query(
+        "UPDATE collection_items SET railway_model_id = ?2, updated_at = ?3, version = version + 1 WHERE railway_model_id = ?1",
+    )
+    .bind(from_id)
+    .bind(to_id)
+    .bind(Utc::now())
+    .execute(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "moving collection_items from railway_model id={} to id={}",
+            from_id, to_id
+        )
+    })?;
+
+    Ok(result.rows_affected())
+}
+
+/// Delete the railway model with `railway_model_id` and the rolling stocks
+/// still attached to it.
+///
+/// Fails while collection items reference the model. Returns `false` when
+/// there is no such model.
+pub async fn delete_railway_model<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    railway_model_id: &RailwayModelId,
+) -> Result<bool> {
+    let result = sqlx::query("DELETE FROM railway_models WHERE id = ?1")
+        .bind(railway_model_id)
+        .execute(executor)
+        .await
+        .with_context(|| format!("deleting railway_model id={}", railway_model_id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Fetch the id of the manufacturer named `name`, ignoring case.
+pub async fn get_manufacturer_id_by_name<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    name: &str,
+) -> Result<Option<String>> {
+    let id = sqlx::query_scalar!(
+        r#"SELECT id AS "id!" FROM manufacturers WHERE name = ?1 COLLATE NOCASE LIMIT 1"#,
+        name
+    )
+    .fetch_optional(executor)
+    .await
+    .with_context(|| format!("querying manufacturer name={}", name))?;
+
+    Ok(id)
+}
+
+/// Fetch the railway models with the `(manufacturer, product_code)` pairs
+/// of `keys` in a single query, comparing them ignoring case.
+///
+/// The pairs are bound as one JSON array and come back as they were bound;
+/// pairs the catalog does not have are skipped.
+pub async fn find_railway_models_by_product_codes<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    keys: &[(String, String)],
+) -> Result<Vec<ProductCodeMatchRow>> {
+    let pairs = serde_json::to_string(keys).context("encoding product codes")?;
+
+    let rows = sqlx::query_as!(
+        ProductCodeMatchRow,
+        r#"SELECT json_extract(k.value, '$[0]') AS "manufacturer!: String",
+            json_extract(k.value, '$[1]') AS "product_code!: String",
+            rm.id AS "railway_model_id!: _"
+        FROM json_each(?1) AS k
+        JOIN manufacturers m ON m.name = json_extract(k.value, '$[0]') COLLATE NOCASE
+        JOIN railway_models rm ON rm.manufacturer_id = m.id
+            AND rm.product_code = json_extract(k.value, '$[1]') COLLATE NOCASE
+        ORDER BY rm.id"#,
+        pairs
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| format!("looking up {} product codes", keys.len()))?;
+
+    Ok(rows)
+}
+
+/// Fetch which of the railway models with `railway_model_ids` a collection
+/// item refers to, in a single query.
+pub async fn find_owned_railway_models<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    railway_model_ids: &[RailwayModelId],
+) -> Result<Vec<RailwayModelId>> {
+    let ids = serde_json::to_string(railway_model_ids).context("encoding railway_model ids")?;
+
+    let owned = sqlx::query_scalar!(
+        r#"SELECT DISTINCT railway_model_id AS "railway_model_id!: _"
+        FROM collection_items
+        WHERE railway_model_id IN (SELECT value FROM json_each(?1))"#,
+        ids
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "looking up the collection items of {} railway models",
+            railway_model_ids.len()
+        )
+    })?;
+
+    Ok(owned)
+}
+
+/// Fetch every manufacturer, ordered by name (case-insensitive).
+pub async fn list_manufacturers(pool: &SqlitePool) -> Result<Vec<ManufacturerOptionRow>> {
+    let rows = sqlx::query_as!(
+        ManufacturerOptionRow,
+        r#"SELECT id AS "id!", name FROM manufacturers ORDER BY name COLLATE NOCASE"#
+    )
+    .fetch_all(pool)
+    .await
+    .context("listing manufacturers")?;
+
+    Ok(rows)
+}
+
+/// Fetch every railway company, ordered by name (case-insensitive).
+pub async fn list_railway_companies(pool: &SqlitePool) -> Result<Vec<RailwayCompanyOptionRow>> {
+    let rows = sqlx::query_as!(
+        RailwayCompanyOptionRow,
+        r#"SELECT id AS "id!", name, registered_company_name, status FROM railway_companies ORDER BY name COLLATE NOCASE"#
+    )
+    .fetch_all(pool)
+        .await
+        .context("listing railway_companies")?;
+
+    Ok(rows)
+}
+
+/// Insert a livery row, setting `created_at` and `updated_at` to the current
+/// UTC time.
+pub async fn insert_livery<'e, E: SqliteExecutor<'e>>(executor: E, row: &LiveryRow) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO liveries (id, railway_company_id, name, description, in_use_since, in_use_until, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)",
+    )
+    .bind(&row.id)
+    .bind(&row.railway_company_id)
+    .bind(&row.name)
+    .bind(&row.description)
+    .bind(row.in_use_since)
+    .bind(row.in_use_until)
+    .bind(Utc::now())
+    .execute(executor)
+    .await
+    .with_context(|| format!("inserting livery id={}", row.id))?;
+
+    Ok(())
+}
+
+/// Update a livery row, setting its `updated_at` to the current UTC time.
+///
+/// Returns `false` when there is no such livery.
+pub async fn update_livery<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &LiveryRow,
+) -> Result<bool> {
+    let result = sqlx::query(
+        "UPDATE liveries SET railway_company_id = ?2, name = ?3, description = ?4, in_use_since = ?5, in_use_until = ?6, updated_at = ?7 WHERE id = ?1",
+    )
+    .bind(&row.id)
+    .bind(&row.railway_company_id)
+    .bind(&row.name)
+    .bind(&row.description)
+    .bind(row.in_use_since)
+    .bind(row.in_use_until)
+    .bind(Utc::now())
+    .execute(executor)
+    .await
+    .with_context(|| format!("updating livery id={}", row.id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete a livery row; the rolling stocks referencing it are left without a
+/// livery id (`ON DELETE SET NULL`).
+///
+/// Returns `false` when there is no such livery.
+pub async fn delete_livery<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    livery_id: &str,
+) -> Result<bool> {
+    let result = sqlx::query("DELETE FROM liveries WHERE id = ?1")
+        .bind(livery_id)
+        .execute(executor)
+        .await
+        .with_context(|| format!("deleting livery id={}", livery_id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Fetch a single livery row by id.
+pub async fn get_livery<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    livery_id: &str,
+) -> Result<Option<LiveryRow>> {
+    let row = sqlx::query_as!(
+        LiveryRow,
+        r#"SELECT id AS "id!", railway_company_id, name, description,
+            in_use_since AS "in_use_since: _", in_use_until AS "in_use_until: _"
+        FROM liveries WHERE id = ?1"#,
+        livery_id
+    )
+    .fetch_optional(executor)
+    .await
+    .with_context(|| format!("querying livery id={}", livery_id))?;
+
+    Ok(row)
+}
+
+/// Fetch the liveries of a railway company, ordered by name (case-insensitive).
+pub async fn list_liveries(pool: &SqlitePool, railway_company_id: &str) -> Result<Vec<LiveryRow>> {
+    let rows = sqlx::query_as!(
+        LiveryRow,
+        r#"SELECT id AS "id!", railway_company_id, name, description,
+            in_use_since AS "in_use_since: _", in_use_until AS "in_use_until: _"
+        FROM liveries WHERE railway_company_id = ?1
+        ORDER BY name COLLATE NOCASE"#,
+        railway_company_id
+    )
+    .fetch_all(pool)
+    .await
+    .with_context(|| format!("listing liveries railway_company_id={}", railway_company_id))?;
+
+    Ok(rows)
+}
+
+/// Fetch the liveries of a railway company whose name, or a word in it,
+/// starts with `prefix`, ranked as described by
+/// `LiveryRepository::suggest_liveries`.
+pub async fn suggest_liveries(
+    pool: &SqlitePool,
+    railway_company_id: &str,
+    prefix: &str,
+    limit: u32,
+) -> Result<Vec<LiveryRow>> {
+    let escaped = escape_like(prefix);
+    let starts_with = format!("{}%", escaped);
+    let word_starts_with = format!("% {}%", escaped);
+
+    let rows = sqlx::query_as!(
+        LiveryRow,
+        r#"SELECT id AS "id!", railway_company_id, name, description,
+            in_use_since AS "in_use_since: _", in_use_until AS "in_use_until: _"
+        FROM liveries
+        WHERE railway_company_id = ?1 AND (name LIKE ?2 ESCAPE '\' OR name LIKE ?3 ESCAPE '\')
+        ORDER BY CASE
+            WHEN name = ?4 COLLATE NOCASE THEN 0
+            WHEN name LIKE ?2 ESCAPE '\' THEN 1
+            ELSE 2
+        END, name COLLATE NOCASE
+        LIMIT ?5"#,
+        railway_company_id,
+        starts_with,
+        word_starts_with,
+        prefix,
+        limit
+    )
+    .fetch_all(pool)
+    .await
+    .with_context(|| {
+        format!(
+            "suggesting liveries railway_company_id={} prefix={}",
+            railway_company_id, prefix
+        )
+    })?;
+
+    Ok(rows)
+}
+
+/// Fetch the railway company of a rolling stock, `None` when there is no
+/// such rolling stock.
+pub async fn get_rolling_stock_railway_company_id<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    rolling_stock_id: &str,
+) -> Result<Option<String>> {
+    let railway_company_id = sqlx::query_scalar!(
+        "SELECT railway_company_id FROM rolling_stocks WHERE id = ?1",
+        rolling_stock_id
+    )
+    .fetch_optional(executor)
+    .await
+    .with_context(|| format!("querying rolling_stock id={}", rolling_stock_id))?;
+
+    Ok(railway_company_id)
+}
+
+/// Fetch a single rolling stock row by id, with the era of its railway
+/// model and its livery as by `get_rolling_stock_livery`.
+///
+/// The railway is shown as its `railway_display` text, falling back to the
+/// name of the railway company.
+pub async fn get_rolling_stock(
+    pool: &SqlitePool,
+    rolling_stock_id: &str,
+) -> Result<Option<RollingStockRow>> {
+    let row = sqlx::query_as!(
+        RollingStockRow,
+        r#"SELECT rs.id AS "id!: _", rs.category, rs.railway_company_id,
+            COALESCE(rs.railway_display, rc.name) AS "railway_display!: String", rm.epoch,
+            COALESCE(l.name, rs.livery) AS "livery?: String",
+            rs.length_inches, rs.length_millimeters, rs.technical_minimum_radius_mm,
+            rs.technical_coupling, rs.technical_flywheel_fitted, rs.technical_body_shell,
+            rs.technical_chassis, rs.technical_interior_lights, rs.technical_lights,
+            rs.technical_sprung_buffers, rs.type_name, rs.class_name, rs.road_number, rs.series,
+            rs.depot, rs.electric_multiple_unit_type, rs.freight_car_type, rs.locomotive_type,
+            rs.passenger_car_type, rs.railcar_type, rs.service_level, rs.dcc_interface,
+            rs.control, rs.is_dummy AS "is_dummy: bool"
+        FROM rolling_stocks rs
+        JOIN railway_models rm ON rm.id = rs.railway_model_id
+        JOIN railway_companies rc ON rc.id = rs.railway_company_id
+        LEFT JOIN liveries l ON l.id = rs.livery_id
+        WHERE rs.id = ?1"#,
+        rolling_stock_id
+    )
+    .fetch_optional(pool)
+    .await
+    .with_context(|| format!("querying rolling_stock id={}", rolling_stock_id))?;
+
+    Ok(row)
+}
+
+/// Set the livery id of a rolling stock, setting its `updated_at` to the
+/// current UTC time.
+///
+/// Returns `false` when there is no such rolling stock.
+pub async fn set_rolling_stock_livery<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    rolling_stock_id: &str,
+    livery_id: Option<&str>,
+) -> Result<bool> {
+    let result =
+        sqlx::query("UPDATE rolling_stocks SET livery_id = ?2, updated_at = ?3 WHERE id = ?1")
+            .bind(rolling_stock_id)
+            .bind(livery_id)
+            .bind(Utc::now())
+            .execute(executor)
+            .await
+            .with_context(|| format!("updating rolling_stock livery id={}", rolling_stock_id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Fetch the livery of a rolling stock: the name of the livery it references
+/// or, when it references none, its free text livery.
+pub async fn get_rolling_stock_livery(
+    pool: &SqlitePool,
+    rolling_stock_id: &str,
+) -> Result<Option<String>> {
+    let livery = sqlx::query_scalar!(
+        r#"SELECT COALESCE(l.name, rs.livery) AS "livery?: String"
+        FROM rolling_stocks rs
+        LEFT JOIN liveries l ON l.id = rs.livery_id
+        WHERE rs.id = ?1"#,
+        rolling_stock_id
+    )
+    .fetch_optional(pool)
+    .await
+    .with_context(|| format!("querying rolling_stock livery id={}", rolling_stock_id))?;
+
+    Ok(livery.flatten())
+}
+
+/// Escape the `LIKE` wildcards (`%`, `_`) and the escape character itself.
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+    use sqlx::SqlitePool;
+    use uuid::Uuid;
+
+    use crate::catalog::domain::railway_model_id::RailwayModelId;
+    use crate::catalog::infrastructure::sqlite::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::core::infrastructure::conflict::ConflictError;
+    use crate::test_utils::{assert_uses_indexes, assert_uses_indexes_except};
+
+    async fn insert_model(
+        catalog_db: &CatalogTestDb,
+        manufacturer_id: &str,
+        product_code: &str,
+        description: &str,
+    ) -> Result<RailwayModelId> {
+        catalog_db
+            .insert_railway_model(
+                &RailwayModelId::new(),
+                manufacturer_id,
+                product_code,
+                description,
+                "electric",
+                "H0",
+                "IV",
+                "LOCOMOTIVES",
+            )
+            .await
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn search_railway_models_ranks_exact_product_code_first(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let manufacturer_id = Uuid::new_v4().to_string();
+        catalog_db
+            .insert_manufacturer(&manufacturer_id, "ACME")
+            .await?;
+
+        insert_model(&catalog_db, &manufacturer_id, "60211", "FS E656 1a serie").await?;
+        insert_model(&catalog_db, &manufacturer_id, "E6560", "FS E656 prototype").await?;
+        let exact_id = insert_model(&catalog_db, &manufacturer_id, "E656", "FS E656 077").await?;
+
+        let rows = search_railway_models(&pool, "e656", 10).await?;
+
+        let product_codes: Vec<&str> = rows.iter().map(|r| r.product_code.as_str()).collect();
+        assert_eq!(product_codes, vec!["E656", "E6560", "60211"]);
+        assert_eq!(rows[0].id, exact_id);
+        assert_eq!(rows[0].manufacturer, "ACME");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn search_railway_models_matches_manufacturer_and_respects_limit(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let manufacturer_id = Uuid::new_v4().to_string();
+        catalog_db
+            .insert_manufacturer(&manufacturer_id, "Rivarossi")
+            .await?;
+
+        insert_model(&catalog_db, &manufacturer_id, "HR2001", "FS D345").await?;
+        insert_model(&catalog_db, &manufacturer_id, "HR2002", "FS D445").await?;
+
+        let rows = search_railway_models(&pool, "rivarossi", 1).await?;
+        assert_eq!(rows.len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn search_railway_models_treats_wildcards_literally(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let manufacturer_id = Uuid::new_v4().to_string();
+        catalog_db
+            .insert_manufacturer(&manufacturer_id, "ACME")
+            .await?;
+
+        insert_model(&catalog_db, &manufacturer_id, "60211", "FS E656").await?;
+
+        let rows = search_railway_models(&pool, "%", 10).await?;
+        assert!(rows.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn find_railway_model_by_product_code_ignores_case_and_other_manufacturers(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        catalog_db.insert_manufacturer("m1", "ACME").await?;
+        catalog_db.insert_manufacturer("m2", "Roco").await?;
+
+        let model_id = insert_model(&catalog_db, "m1", "HR2001", "FS D345").await?;
+
+        let row = find_railway_model_by_product_code(&pool, "m1", "hr2001").await?;
+        assert_eq!(row.map(|r| r.id), Some(model_id));
+
+        let row = find_railway_model_by_product_code(&pool, "m2", "HR2001").await?;
+        assert!(row.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn get_railway_model_summaries_skips_unknown_ids(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        catalog_db.insert_manufacturer("m1", "ACME").await?;
+
+        let first_id = insert_model(&catalog_db, "m1", "HR2001", "FS D345").await?;
+        let second_id = insert_model(&catalog_db, "m1", "HR2002", "FS D445").await?;
+        insert_model(&catalog_db, "m1", "HR2003", "FS D443").await?;
+
+        let rows =
+            get_railway_model_summaries(&pool, &[first_id, RailwayModelId::new(), second_id])
+                .await?;
+
+        let mut product_codes: Vec<&str> = rows.iter().map(|r| r.product_code.as_str()).collect();
+        product_codes.sort();
+        assert_eq!(product_codes, vec!["HR2001", "HR2002"]);
+        assert!(rows.iter().all(|r| r.manufacturer == "ACME"));
+
+        assert!(get_railway_model_summaries(&pool, &[]).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn list_railway_models_page_walks_every_model_once(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        catalog_db.insert_manufacturer("m1", "ACME").await?;
+        let mut expected = Vec::new();
+        for n in 0..1_000 {
+            // Every product code is shared by two models, so that pages also
+            // break between rows with the same sort key.
+            let product_code = format!("HR{:04}", n / 2);
+            let id = insert_model(&catalog_db, "m1", &product_code, "Model").await?;
+            expected.push((product_code, id.to_string()));
+        }
+        expected.sort();
+
+        let mut seen = Vec::new();
+        let mut after = None;
+        loop {
+            let page = list_railway_models_page(&pool, after.as_ref(), 64).await?;
+            assert!(page.items.len() <= 64);
+            seen.extend(
+                page.items
+                    .into_iter()
+                    .map(|row| (row.product_code, row.id.to_string())),
+            );
+            match page.next {
+                Some(next) => after = Some(next),
+                None => break,
+            }
+        }
+
+        assert_eq!(seen, expected);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn list_railway_models_page_aggregates_rolling_stocks(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        catalog_db.insert_manufacturer("m1", "ACME").await?;
+        catalog_db.insert_railway_company("fs", "FS").await?;
+        let with_rolling_stocks = insert_model(&catalog_db, "m1", "A1", "Train set").await?;
+        let without_rolling_stocks = insert_model(&catalog_db, "m1", "B1", "Empty").await?;
+        for (id, length_millimeters, length_inches) in [
+            ("rs-1", Some(210.0), None),
+            ("rs-2", None, Some(10.0)),
+            ("rs-3", None, None),
+        ] {
+            catalog_db
+                .insert_rolling_stock(id, &with_rolling_stocks, "LOCOMOTIVE", "fs", 0)
+                .await?;
+            sqlx::query(
+                "UPDATE rolling_stocks SET length_millimeters = ?1, length_inches = ?2 WHERE id = ?3",
+            )
+            .bind(length_millimeters)
+            .bind(length_inches)
+            .bind(id)
+            .execute(&pool)
+            .await?;
+        }
+
+        let page = list_railway_models_page(&pool, None, 10).await?;
+
+        assert_eq!(page.items.len(), 2);
+        let first = &page.items[0];
+        assert_eq!(first.id, with_rolling_stocks);
+        assert_eq!(first.rolling_stocks_count, 3);
+        assert_eq!(first.min_length_over_buffers_mm, Some(210.0));
+        assert_eq!(first.max_length_over_buffers_mm, Some(254.0));
+        let second = &page.items[1];
+        assert_eq!(second.id, without_rolling_stocks);
+        assert_eq!(second.rolling_stocks_count, 0);
+        assert_eq!(second.min_length_over_buffers_mm, None);
+        assert_eq!(second.max_length_over_buffers_mm, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn list_manufacturers_and_railway_companies_are_ordered_by_name(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        catalog_db.insert_manufacturer("m1", "Roco").await?;
+        catalog_db.insert_manufacturer("m2", "ACME").await?;
+        catalog_db.insert_railway_company("r1", "FS").await?;
+        catalog_db.insert_railway_company("r2", "DB").await?;
+
+        let manufacturers = list_manufacturers(&pool).await?;
+        let names: Vec<&str> = manufacturers.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["ACME", "Roco"]);
+
+        let railway_companies = list_railway_companies(&pool).await?;
+        let names: Vec<&str> = railway_companies.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["DB", "FS"]);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn update_railway_model_detects_lost_update(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let id = catalog_test_data.railway_model_id;
+
+        let mut first = get_railway_model(&pool, &id).await?.unwrap();
+        let mut second = get_railway_model(&pool, &id).await?.unwrap();
+
+        first.description = "FS E656 Caimano".to_string();
+        assert_eq!(
+            update_railway_model(&pool, &first).await?,
+            first.version + 1
+        );
+
+        second.details = Some("Sound decoder".to_string());
+        let err = update_railway_model(&pool, &second).await.unwrap_err();
+        let conflict = err
+            .downcast_ref::<ConflictError<RailwayModelRow>>()
+            .expect("conflict error");
+        let current = conflict.current.as_ref().unwrap();
+        assert_eq!(current.description, "FS E656 Caimano");
+        assert_eq!(current.details, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn update_railway_model_advances_legacy_updated_at(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let id = catalog_test_data.railway_model_id;
+
+        // Rows written by the column default use SQLite's `CURRENT_TIMESTAMP`
+        // text format rather than RFC3339.
+        sqlx::query("UPDATE railway_models SET updated_at = '2020-01-01 10:00:00' WHERE id = ?1")
+            .bind(id)
+            .execute(&pool)
+            .await?;
+        let row = get_railway_model(&pool, &id).await?.unwrap();
+        assert_eq!(row.updated_at.to_rfc3339(), "2020-01-01T10:00:00+00:00");
+
+        update_railway_model(&pool, &row).await?;
+
+        let updated = get_railway_model(&pool, &id).await?.unwrap();
+        assert!(updated.updated_at > row.updated_at);
+        assert_eq!(updated.created_at, row.created_at);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn update_railway_model_stores_the_msrp(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let id = catalog_db.setup_railway_model().await?.railway_model_id;
+        let mut row = get_railway_model(&pool, &id).await?.unwrap();
+        assert_eq!(
+            (row.msrp_amount, row.msrp_currency.as_deref()),
+            (None, None)
+        );
+
+        row.msrp_amount = Some(24_990);
+        row.msrp_currency = Some("EUR".to_string());
+        update_railway_model(&pool, &row).await?;
+
+        let updated = get_railway_model(&pool, &id).await?.unwrap();
+        assert_eq!(
+            (updated.msrp_amount, updated.msrp_currency.as_deref()),
+            (Some(24_990), Some("EUR"))
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn update_railway_model_reports_deleted_row(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let row = get_railway_model(&pool, &catalog_test_data.railway_model_id)
+            .await?
+            .unwrap();
+
+        sqlx::query("DELETE FROM rolling_stocks")
+            .execute(&pool)
+            .await?;
+        sqlx::query("DELETE FROM railway_models")
+            .execute(&pool)
+            .await?;
+
+        let err = update_railway_model(&pool, &row).await.unwrap_err();
+        let conflict = err
+            .downcast_ref::<ConflictError<RailwayModelRow>>()
+            .expect("conflict error");
+        assert!(conflict.current.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn railway_model_filters_use_indexes(pool: SqlitePool) -> Result<()> {
+        for sql in [
+            include_str!("../../../queries/catalog/railway_model_by_product_code.sql"),
+            "SELECT id FROM railway_models WHERE category = ?1",
+            "SELECT id FROM railway_models WHERE scale = ?1",
+        ] {
+            assert_uses_indexes(&pool, sql).await;
+        }
+        // The rolling stocks are only joined to the rows of the `page` CTE.
+        assert_uses_indexes_except(
+            &pool,
+            include_str!("../../../queries/catalog/railway_models_page.sql"),
+            &["SCAN page"],
+        )
+        .await;
+
+        Ok(())
+    }
+}
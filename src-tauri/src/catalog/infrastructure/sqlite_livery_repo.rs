@@ -0,0 +1,328 @@
+use crate::audit::infrastructure::sink::{AuditSink, AuditedChange, SqliteAuditSink};
+use crate::catalog::domain::livery::{Livery, LiveryError, PeriodOfUse};
+use crate::catalog::domain::repository::LiveryRepository;
+use crate::catalog::infrastructure::entities::LiveryRow;
+use crate::catalog::infrastructure::sqlite;
+use crate::core::infrastructure::transaction::with_transaction;
+use anyhow::{Result, anyhow};
+use serde_json::json;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+
+pub struct SqliteLiveryRepository {
+    pool: SqlitePool,
+    audit: Arc<dyn AuditSink>,
+}
+
+impl SqliteLiveryRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            audit: Arc::new(SqliteAuditSink::default()),
+        }
+    }
+
+    /// Record the changes made by this repository in `audit`.
+    pub fn with_audit_sink(mut self, audit: Arc<dyn AuditSink>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    fn to_row(livery: &Livery) -> LiveryRow {
+        LiveryRow {
+            id: livery.id.clone(),
+            railway_company_id: livery.railway_company_id.clone(),
+            name: livery.name.clone(),
+            description: livery.description.clone(),
+            in_use_since: livery.period_of_use.since,
+            in_use_until: livery.period_of_use.until,
+        }
+    }
+
+    fn build_livery(row: LiveryRow) -> Livery {
+        Livery {
+            id: row.id,
+            railway_company_id: row.railway_company_id,
+            name: row.name,
+            description: row.description,
+            period_of_use: PeriodOfUse {
+                since: row.in_use_since,
+                until: row.in_use_until,
+            },
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl LiveryRepository for SqliteLiveryRepository {
+    async fn create_livery(&self, livery: &Livery) -> Result<()> {
+        let row = Self::to_row(livery);
+        let change = AuditedChange::created("livery", &livery.id, livery)?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                sqlite::insert_livery(&mut *conn, &row).await?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn update_livery(&self, livery: &Livery) -> Result<()> {
+        let row = Self::to_row(livery);
+        let change = AuditedChange::updated("livery", &livery.id, livery)?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                if !sqlite::update_livery(&mut *conn, &row).await? {
+                    return Err(anyhow!("livery not found id={}", row.id));
+                }
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn delete_livery(&self, livery_id: &str) -> Result<()> {
+        let livery_id = livery_id.to_string();
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                if !sqlite::delete_livery(&mut *conn, &livery_id).await? {
+                    return Err(anyhow!("livery not found id={}", livery_id));
+                }
+                audit
+                    .record(conn, &AuditedChange::deleted("livery", livery_id))
+                    .await
+            })
+        })
+        .await
+    }
+
+    async fn get_livery(&self, livery_id: &str) -> Result<Option<Livery>> {
+        Ok(sqlite::get_livery(&self.pool, livery_id)
+            .await?
+            .map(Self::build_livery))
+    }
+
+    async fn list_liveries(&self, railway_company_id: &str) -> Result<Vec<Livery>> {
+        Ok(sqlite::list_liveries(&self.pool, railway_company_id)
+            .await?
+            .into_iter()
+            .map(Self::build_livery)
+            .collect())
+    }
+
+    async fn suggest_liveries(
+        &self,
+        railway_company_id: &str,
+        prefix: &str,
+        limit: u32,
+    ) -> Result<Vec<Livery>> {
+        Ok(
+            sqlite::suggest_liveries(&self.pool, railway_company_id, prefix, limit)
+                .await?
+                .into_iter()
+                .map(Self::build_livery)
+                .collect(),
+        )
+    }
+
+    async fn set_rolling_stock_livery(
+        &self,
+        rolling_stock_id: &str,
+        livery_id: Option<&str>,
+    ) -> Result<()> {
+        let rolling_stock_id = rolling_stock_id.to_string();
+        let livery_id = livery_id.map(str::to_string);
+        let change = AuditedChange::updated(
+            "rolling_stock",
+            &rolling_stock_id,
+            &json!({ "livery_id": livery_id }),
+        )?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let railway_company_id =
+                    sqlite::get_rolling_stock_railway_company_id(&mut *conn, &rolling_stock_id)
+                        .await?
+                        .ok_or_else(|| {
+                            anyhow!("rolling stock not found id={}", rolling_stock_id)
+                        })?;
+                if let Some(livery_id) = &livery_id {
+                    let livery = sqlite::get_livery(&mut *conn, livery_id)
+                        .await?
+                        .ok_or_else(|| anyhow!("livery not found id={}", livery_id))?;
+                    if livery.railway_company_id != railway_company_id {
+                        return Err(LiveryError::OtherRailwayCompany {
+                            livery_id: livery_id.clone(),
+                            rolling_stock_id: rolling_stock_id.clone(),
+                        }
+                        .into());
+                    }
+                }
+                sqlite::set_rolling_stock_livery(
+                    &mut *conn,
+                    &rolling_stock_id,
+                    livery_id.as_deref(),
+                )
+                .await?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn get_rolling_stock_livery(&self, rolling_stock_id: &str) -> Result<Option<String>> {
+        sqlite::get_rolling_stock_livery(&self.pool, rolling_stock_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use pretty_assertions::assert_eq;
+
+    fn livery(id: &str, railway_company_id: &str, name: &str) -> Livery {
+        Livery {
+            id: id.to_string(),
+            railway_company_id: railway_company_id.to_string(),
+            name: name.to_string(),
+            description: None,
+            period_of_use: PeriodOfUse::default(),
+        }
+    }
+
+    fn names(liveries: &[Livery]) -> Vec<&str> {
+        liveries.iter().map(|l| l.name.as_str()).collect()
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_suggestions_rank_exact_then_prefix_then_word_matches(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        catalog_db.insert_railway_company("fs", "FS").await?;
+        catalog_db.insert_railway_company("db", "DB").await?;
+        let repo = SqliteLiveryRepository::new(pool.clone());
+        for (id, railway, name) in [
+            ("l1", "fs", "Treno Verde"),
+            ("l2", "fs", "Verde magnolia"),
+            ("l3", "fs", "Castano/Isabella"),
+            ("l4", "fs", "verde"),
+            ("l5", "db", "Verkehrsrot"),
+            ("l6", "fs", "XMPR 100%"),
+        ] {
+            repo.create_livery(&livery(id, railway, name)).await?;
+        }
+
+        let suggestions = repo.suggest_liveries("fs", "Ver", 10).await?;
+        assert_eq!(
+            names(&suggestions),
+            vec!["verde", "Verde magnolia", "Treno Verde"]
+        );
+        let suggestions = repo.suggest_liveries("fs", "Verde", 2).await?;
+        assert_eq!(names(&suggestions), vec!["verde", "Verde magnolia"]);
+        let suggestions = repo.suggest_liveries("fs", "100%", 10).await?;
+        assert_eq!(names(&suggestions), vec!["XMPR 100%"]);
+        assert!(repo.suggest_liveries("fs", "ano", 10).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_rolling_stock_livery_prefers_the_linked_livery(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let data = catalog_db.setup_railway_model().await?;
+        let rolling_stock_id = &data.rolling_stock_ids[0];
+        let repo = SqliteLiveryRepository::new(pool.clone());
+
+        assert_eq!(repo.get_rolling_stock_livery(rolling_stock_id).await?, None);
+        sqlx::query("UPDATE rolling_stocks SET livery = 'xmpr' WHERE id = ?1")
+            .bind(rolling_stock_id)
+            .execute(&pool)
+            .await?;
+        assert_eq!(
+            repo.get_rolling_stock_livery(rolling_stock_id).await?,
+            Some("xmpr".to_string())
+        );
+
+        repo.create_livery(&livery("l1", &data.railway_company_id, "XMPR"))
+            .await?;
+        repo.set_rolling_stock_livery(rolling_stock_id, Some("l1"))
+            .await?;
+        assert_eq!(
+            repo.get_rolling_stock_livery(rolling_stock_id).await?,
+            Some("XMPR".to_string())
+        );
+
+        repo.delete_livery("l1").await?;
+        assert_eq!(
+            repo.get_rolling_stock_livery(rolling_stock_id).await?,
+            Some("xmpr".to_string())
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_rolling_stocks_only_wear_liveries_of_their_railway(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let data = catalog_db.setup_railway_model().await?;
+        catalog_db.insert_railway_company("db", "DB").await?;
+        let repo = SqliteLiveryRepository::new(pool.clone());
+        repo.create_livery(&livery("l1", "db", "Verkehrsrot"))
+            .await?;
+
+        let result = repo
+            .set_rolling_stock_livery(&data.rolling_stock_ids[0], Some("l1"))
+            .await;
+
+        let error = result.unwrap_err();
+        assert!(matches!(
+            error.downcast_ref::<LiveryError>(),
+            Some(LiveryError::OtherRailwayCompany { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_livery_names_are_unique_within_a_railway(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        catalog_db.insert_railway_company("fs", "FS").await?;
+        catalog_db.insert_railway_company("db", "DB").await?;
+        let repo = SqliteLiveryRepository::new(pool.clone());
+        repo.create_livery(&livery("l1", "fs", "Rosso")).await?;
+
+        assert!(
+            repo.create_livery(&livery("l2", "fs", "ROSSO"))
+                .await
+                .is_err()
+        );
+        repo.create_livery(&livery("l3", "db", "Rosso")).await?;
+
+        let mut updated = livery("l1", "fs", "Rosso fegato");
+        updated.description = Some("Dark red".to_string());
+        repo.update_livery(&updated).await?;
+        assert_eq!(repo.get_livery("l1").await?, Some(updated));
+        assert_eq!(
+            names(&repo.list_liveries("fs").await?),
+            vec!["Rosso fegato"]
+        );
+        assert!(
+            repo.update_livery(&livery("missing", "fs", "X"))
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+}
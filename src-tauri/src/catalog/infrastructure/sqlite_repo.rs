@@ -0,0 +1,1207 @@
+use crate::audit::infrastructure::sink::{AuditSink, AuditedChange, SqliteAuditSink};
+use crate::catalog::domain::announcement::{
+    AnnouncementBatch, AnnouncementError, AnnouncementIngest, IngestOutcome,
+};
+use crate::catalog::domain::availability_status::AvailabilityStatus;
+use crate::catalog::domain::category::RollingStockCategory;
+use crate::catalog::domain::duplicate_candidate::{DuplicateCandidate, description_similarity};
+use crate::catalog::domain::length_over_buffers::LengthOverBuffers;
+use crate::catalog::domain::model_merge::{MergeError, ModelMerge};
+use crate::catalog::domain::product_code_lookup::{
+    ProductCodeLookup, ProductCodeQuery, validate_lookup,
+};
+use crate::catalog::domain::railway_id::RailwayId;
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::domain::railway_model_list_entry::RailwayModelListEntry;
+use crate::catalog::domain::railway_model_summary::RailwayModelSummary;
+use crate::catalog::domain::railway_status::RailwayStatus;
+use crate::catalog::domain::reference_data::{ManufacturerOption, RailwayCompanyOption};
+use crate::catalog::domain::repository::CatalogRepository;
+use crate::catalog::domain::rolling_stock_comparison::ComparisonReport;
+use crate::catalog::domain::rolling_stock_id::RollingStockId;
+use crate::catalog::domain::rolling_stock_railway::RollingStockRailway;
+use crate::catalog::domain::technical_specifications::TechnicalSpecifications;
+use crate::catalog::domain::{
+    Coupling, CouplingSocket, DeliveryDate, Epoch, ProductCode, Radius, RollingStock, Scale,
+    ServiceLevel,
+};
+use crate::catalog::infrastructure::cache::RailwayModelCache;
+use crate::catalog::infrastructure::entities::{
+    ManufacturerOptionRow, RailwayCompanyOptionRow, RailwayModelListRow, RailwayModelRow,
+    RailwayModelScanRow, RailwayModelSummaryRow, RollingStockRow,
+};
+use crate::catalog::infrastructure::sqlite;
+use crate::core::domain::MonetaryAmount;
+use crate::core::domain::length::Length;
+use crate::core::domain::page::{KeysetPage, PageKey};
+use crate::core::infrastructure::transaction::with_transaction;
+use anyhow::{Context, Result, anyhow};
+use chrono::Utc;
+use rust_decimal::Decimal;
+use sqlx::SqlitePool;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct SqliteCatalogRepository {
+    pool: SqlitePool,
+    cache: RailwayModelCache,
+    audit: Arc<dyn AuditSink>,
+}
+
+impl SqliteCatalogRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            cache: RailwayModelCache::default(),
+            audit: Arc::new(SqliteAuditSink::default()),
+        }
+    }
+
+    /// Use `cache` (usually the application-wide one) as the railway model
+    /// cache that writes invalidate.
+    pub fn with_cache(mut self, cache: RailwayModelCache) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Record the changes made by this repository in `audit`.
+    pub fn with_audit_sink(mut self, audit: Arc<dyn AuditSink>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Update a railway model (see `sqlite::update_railway_model`) and drop
+    /// its cached summary.
+    ///
+    /// The summary is dropped on a version conflict as well: the stored row
+    /// was changed by someone else.
+    pub async fn update_railway_model(&self, row: &RailwayModelRow) -> Result<i64> {
+        let result = sqlite::update_railway_model(&self.pool, row).await;
+        self.cache.invalidate(&row.id);
+        result
+    }
+}
+
+impl SqliteCatalogRepository {
+    pub(crate) fn build_railway_model_summary(
+        row: RailwayModelSummaryRow,
+    ) -> Result<RailwayModelSummary> {
+        let scale = Scale::try_from(row.scale.as_str())
+            .with_context(|| format!("invalid scale for railway_model id={}", row.id))?;
+
+        Ok(RailwayModelSummary {
+            id: row.id,
+            manufacturer: row.manufacturer,
+            product_code: ProductCode::try_from(row.product_code)?,
+            description: row.description,
+            scale,
+        })
+    }
+
+    fn build_railway_model_list_entry(row: RailwayModelListRow) -> Result<RailwayModelListEntry> {
+        let rolling_stocks_count = u32::try_from(row.rolling_stocks_count).with_context(|| {
+            format!(
+                "invalid rolling stocks count for railway_model id={}",
+                row.id
+            )
+        })?;
+        let summary = Self::build_railway_model_summary(RailwayModelSummaryRow {
+            id: row.id,
+            manufacturer: row.manufacturer,
+            product_code: row.product_code,
+            description: row.description,
+            scale: row.scale,
+        })?;
+
+        Ok(RailwayModelListEntry {
+            summary,
+            rolling_stocks_count,
+            min_length_over_buffers_mm: row.min_length_over_buffers_mm,
+            max_length_over_buffers_mm: row.max_length_over_buffers_mm,
+        })
+    }
+
+    /// Pair up the models of one manufacturer and scale whose descriptions
+    /// are at least `threshold` similar.
+    fn duplicates_within(
+        group: &[RailwayModelScanRow],
+        threshold: f64,
+    ) -> Result<Vec<DuplicateCandidate>> {
+        let summary = |row: &RailwayModelScanRow| {
+            Self::build_railway_model_summary(RailwayModelSummaryRow {
+                id: row.id,
+                manufacturer: row.manufacturer.clone(),
+                product_code: row.product_code.clone(),
+                description: row.description.clone(),
+                scale: row.scale.clone(),
+            })
+        };
+
+        let mut candidates = Vec::new();
+        for (i, model) in group.iter().enumerate() {
+            for duplicate in &group[i + 1..] {
+                let similarity = description_similarity(&model.description, &duplicate.description);
+                if similarity >= threshold {
+                    candidates.push(DuplicateCandidate {
+                        model: summary(model)?,
+                        duplicate: summary(duplicate)?,
+                        similarity,
+                    });
+                }
+            }
+        }
+        Ok(candidates)
+    }
+
+    fn build_manufacturer_option(row: ManufacturerOptionRow) -> ManufacturerOption {
+        ManufacturerOption {
+            id: row.id,
+            name: row.name,
+        }
+    }
+
+    fn build_railway_company_option(row: RailwayCompanyOptionRow) -> Result<RailwayCompanyOption> {
+        let status = row
+            .status
+            .as_deref()
+            .map(RailwayStatus::from_str)
+            .transpose()
+            .with_context(|| format!("invalid status for railway_company id={}", row.id))?;
+
+        Ok(RailwayCompanyOption {
+            id: row.id,
+            name: row.name,
+            registered_company_name: row.registered_company_name,
+            status,
+        })
+    }
+
+    /// Build the rolling stock of `row`, with the era of its railway model.
+    ///
+    /// Only the socket of the coupling is stored. A locomotive without a
+    /// class name falls back to its type name.
+    fn build_rolling_stock(row: RollingStockRow) -> Result<(RollingStock, Epoch)> {
+        let id = row.id;
+        let category = RollingStockCategory::from_str(&row.category)
+            .with_context(|| format!("invalid category for rolling_stock id={}", id))?;
+        let railway =
+            RollingStockRailway::new(RailwayId::new(row.railway_company_id), &row.railway_display);
+        let length_over_buffer = match (row.length_millimeters, row.length_inches) {
+            (Some(mm), _) => Some(LengthOverBuffers::from_millimeters(Length::Millimeters(
+                decimal_column(mm, "length_millimeters", &id)?,
+            ))),
+            (None, Some(inches)) => Some(LengthOverBuffers::from_inches(Length::Inches(
+                decimal_column(inches, "length_inches", &id)?,
+            ))),
+            (None, None) => None,
+        };
+        let minimum_radius = row
+            .technical_minimum_radius_mm
+            .map(|mm| {
+                let mm = decimal_column(mm, "technical_minimum_radius_mm", &id)?;
+                Radius::from_millimeters(mm).with_context(|| {
+                    format!(
+                        "invalid technical_minimum_radius_mm for rolling_stock id={}",
+                        id
+                    )
+                })
+            })
+            .transpose()?;
+        let coupling = parse_column::<CouplingSocket>(
+            row.technical_coupling.as_deref(),
+            "technical_coupling",
+            &id,
+        )?
+        .map(|socket| Coupling {
+            socket: Some(socket),
+            ..Coupling::default()
+        });
+        let tech_specs = TechnicalSpecifications {
+            minimum_radius,
+            coupling,
+            flywheel_fitted: parse_column(
+                row.technical_flywheel_fitted.as_deref(),
+                "technical_flywheel_fitted",
+                &id,
+            )?,
+            body_shell: parse_column(
+                row.technical_body_shell.as_deref(),
+                "technical_body_shell",
+                &id,
+            )?,
+            chassis: parse_column(row.technical_chassis.as_deref(), "technical_chassis", &id)?,
+            interior_lights: parse_column(
+                row.technical_interior_lights.as_deref(),
+                "technical_interior_lights",
+                &id,
+            )?,
+            lights: parse_column(row.technical_lights.as_deref(), "technical_lights", &id)?,
+            sprung_buffers: parse_column(
+                row.technical_sprung_buffers.as_deref(),
+                "technical_sprung_buffers",
+                &id,
+            )?,
+        };
+        let technical_specifications =
+            (tech_specs != TechnicalSpecifications::default()).then_some(tech_specs);
+        let dcc_interface = parse_column(row.dcc_interface.as_deref(), "dcc_interface", &id)?;
+        let control = parse_column(row.control.as_deref(), "control", &id)?;
+        let type_name = row.type_name.as_deref().unwrap_or_default();
+        let required = |column: &str| format!("missing {} for rolling_stock id={}", column, id);
+
+        let rolling_stock = match category {
+            RollingStockCategory::Locomotive => RollingStock::new_locomotive(
+                id,
+                row.class_name.as_deref().unwrap_or(type_name),
+                row.road_number.as_deref().unwrap_or_default(),
+                row.series.as_deref(),
+                railway,
+                parse_column(row.locomotive_type.as_deref(), "locomotive_type", &id)?
+                    .with_context(|| required("locomotive_type"))?,
+                row.depot.as_deref(),
+                row.livery.as_deref(),
+                row.is_dummy,
+                length_over_buffer,
+                control,
+                dcc_interface,
+                technical_specifications,
+            ),
+            RollingStockCategory::ElectricMultipleUnit => RollingStock::new_electric_multiple_unit(
+                id,
+                type_name,
+                row.road_number.as_deref(),
+                row.series.as_deref(),
+                railway,
+                parse_column(
+                    row.electric_multiple_unit_type.as_deref(),
+                    "electric_multiple_unit_type",
+                    &id,
+                )?
+                .with_context(|| required("electric_multiple_unit_type"))?,
+                row.depot.as_deref(),
+                row.livery.as_deref(),
+                row.is_dummy,
+                length_over_buffer,
+                control,
+                dcc_interface,
+                technical_specifications,
+            ),
+            RollingStockCategory::Railcar => RollingStock::new_railcar(
+                id,
+                type_name,
+                row.road_number.as_deref(),
+                row.series.as_deref(),
+                railway,
+                parse_column(row.railcar_type.as_deref(), "railcar_type", &id)?
+                    .with_context(|| required("railcar_type"))?,
+                row.depot.as_deref(),
+                row.livery.as_deref(),
+                row.is_dummy,
+                length_over_buffer,
+                control,
+                dcc_interface,
+                technical_specifications,
+            ),
+            RollingStockCategory::PassengerCar => {
+                let service_level = row
+                    .service_level
+                    .as_deref()
+                    .map(|value| {
+                        ServiceLevel::try_from(value)
+                            .or_else(|_| serde_json::from_value(serde_json::json!(value)))
+                            .with_context(|| {
+                                format!("invalid service_level for rolling_stock id={}", id)
+                            })
+                    })
+                    .transpose()?;
+                RollingStock::new_passenger_car(
+                    id,
+                    type_name,
+                    row.road_number.as_deref(),
+                    row.series.as_deref(),
+                    railway,
+                    parse_column(row.passenger_car_type.as_deref(), "passenger_car_type", &id)?,
+                    service_level,
+                    row.livery.as_deref(),
+                    length_over_buffer,
+                    technical_specifications,
+                )
+            }
+            RollingStockCategory::FreightCar => RollingStock::new_freight_car(
+                id,
+                type_name,
+                row.road_number.as_deref(),
+                railway,
+                parse_column(row.freight_car_type.as_deref(), "freight_car_type", &id)?,
+                row.livery.as_deref(),
+                length_over_buffer,
+                technical_specifications,
+            ),
+        };
+        Ok((rolling_stock, Epoch(row.epoch)))
+    }
+
+    /// Load the rolling stock with `id`, failing when there is none.
+    async fn load_rolling_stock(&self, id: &RollingStockId) -> Result<(RollingStock, Epoch)> {
+        let row = sqlite::get_rolling_stock(&self.pool, &id.to_string())
+            .await?
+            .ok_or_else(|| anyhow!("rolling_stock not found id={}", id))?;
+        Self::build_rolling_stock(row)
+    }
+}
+
+/// Parse the optional enum value stored in `column` of the rolling stock
+/// with `id`.
+fn parse_column<T>(value: Option<&str>, column: &str, id: &RollingStockId) -> Result<Option<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    value
+        .map(T::from_str)
+        .transpose()
+        .with_context(|| format!("invalid {} for rolling_stock id={}", column, id))
+}
+
+/// Convert the `REAL` value stored in `column` of the rolling stock with
+/// `id` to a decimal.
+fn decimal_column(value: f64, column: &str, id: &RollingStockId) -> Result<Decimal> {
+    Decimal::try_from(value)
+        .with_context(|| format!("invalid {} for rolling_stock id={}", column, id))
+}
+
+#[async_trait::async_trait]
+impl CatalogRepository for SqliteCatalogRepository {
+    async fn search_railway_models(
+        &self,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<RailwayModelSummary>> {
+        sqlite::search_railway_models(&self.pool, query, limit)
+            .await?
+            .into_iter()
+            .map(Self::build_railway_model_summary)
+            .collect()
+    }
+
+    async fn find_railway_model_by_product_code(
+        &self,
+        manufacturer_id: &str,
+        product_code: &ProductCode,
+    ) -> Result<Option<RailwayModelSummary>> {
+        sqlite::find_railway_model_by_product_code(&self.pool, manufacturer_id, product_code)
+            .await?
+            .map(Self::build_railway_model_summary)
+            .transpose()
+    }
+
+    async fn list_railway_models(
+        &self,
+        after: Option<&PageKey>,
+        limit: u32,
+    ) -> Result<KeysetPage<RailwayModelListEntry>> {
+        sqlite::list_railway_models_page(&self.pool, after, limit)
+            .await?
+            .try_map(Self::build_railway_model_list_entry)
+    }
+
+    async fn list_manufacturers(&self) -> Result<Vec<ManufacturerOption>> {
+        Ok(sqlite::list_manufacturers(&self.pool)
+            .await?
+            .into_iter()
+            .map(Self::build_manufacturer_option)
+            .collect())
+    }
+
+    async fn list_railway_companies(&self) -> Result<Vec<RailwayCompanyOption>> {
+        sqlite::list_railway_companies(&self.pool)
+            .await?
+            .into_iter()
+            .map(Self::build_railway_company_option)
+            .collect()
+    }
+
+    async fn find_possible_duplicates(&self, threshold: f64) -> Result<Vec<DuplicateCandidate>> {
+        let rows = sqlite::list_railway_models_by_manufacturer_and_scale(&self.pool).await?;
+
+        let mut candidates = Vec::new();
+        for group in
+            rows.chunk_by(|a, b| a.manufacturer_id == b.manufacturer_id && a.scale == b.scale)
+        {
+            candidates.extend(Self::duplicates_within(group, threshold)?);
+        }
+        candidates.sort_by(|a, b| {
+            b.similarity
+                .total_cmp(&a.similarity)
+                .then_with(|| str::cmp(&a.model.product_code, &b.model.product_code))
+                .then_with(|| str::cmp(&a.duplicate.product_code, &b.duplicate.product_code))
+        });
+        Ok(candidates)
+    }
+
+    async fn compare_rolling_stocks(
+        &self,
+        left_id: &RollingStockId,
+        right_id: &RollingStockId,
+    ) -> Result<ComparisonReport> {
+        let (left, left_epoch) = self.load_rolling_stock(left_id).await?;
+        let (right, right_epoch) = self.load_rolling_stock(right_id).await?;
+
+        Ok(ComparisonReport::compare(
+            &left,
+            &left_epoch,
+            &right,
+            &right_epoch,
+        )?)
+    }
+
+    async fn ingest_announcements(&self, batch: &AnnouncementBatch) -> Result<AnnouncementIngest> {
+        batch.validate()?;
+        let batch = batch.clone();
+        let audit = Arc::clone(&self.audit);
+
+        let ingest = with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let manufacturer_id =
+                    sqlite::get_manufacturer_id_by_name(&mut *conn, batch.manufacturer.trim())
+                        .await?
+                        .ok_or_else(|| {
+                            AnnouncementError::UnknownManufacturer(batch.manufacturer.clone())
+                        })?;
+
+                let mut ingest = AnnouncementIngest::default();
+                for entry in batch.entries {
+                    let product_code = ProductCode::try_from(entry.product_code.0.as_str())?;
+                    let msrp_amount = entry
+                        .msrp
+                        .as_ref()
+                        .map(|msrp| i64::try_from(msrp.amount))
+                        .transpose()
+                        .context("suggested retail price overflow")?;
+                    let msrp_currency = entry.msrp.as_ref().map(|msrp| msrp.currency.code());
+
+                    let known = sqlite::get_railway_model_announcement(
+                        &mut *conn,
+                        &manufacturer_id,
+                        &product_code,
+                    )
+                    .await?;
+                    let (railway_model_id, outcome) = match known {
+                        None => {
+                            let now = Utc::now();
+                            let row = RailwayModelRow {
+                                id: RailwayModelId::try_from(Uuid::new_v4().to_string())?,
+                                manufacturer_id: manufacturer_id.clone(),
+                                product_code: product_code.to_string(),
+                                description: entry.description.trim().to_string(),
+                                details: None,
+                                power_method: entry.power_method.to_string(),
+                                scale: entry.scale.label().to_string(),
+                                epoch: entry.epoch.0.clone(),
+                                category: entry.category.to_string(),
+                                delivery_date: entry.delivery_date.as_ref().map(|d| d.to_string()),
+                                availability_status: Some(
+                                    AvailabilityStatus::Announced.to_string(),
+                                ),
+                                msrp_amount,
+                                msrp_currency: msrp_currency.map(str::to_string),
+                                created_at: now,
+                                updated_at: now,
+                                version: 0,
+                            };
+                            sqlite::insert_railway_model(&mut *conn, &row).await?;
+                            let change = AuditedChange::created(
+                                "railway_model",
+                                row.id.to_string(),
+                                &entry,
+                            )?;
+                            audit.record(&mut *conn, &change).await?;
+                            (row.id, IngestOutcome::Created)
+                        }
+                        Some(mut known) => {
+                            let context =
+                                || format!("invalid announcement of railway_model id={}", known.id);
+                            let delivery_date = known
+                                .delivery_date
+                                .as_deref()
+                                .map(DeliveryDate::parse)
+                                .transpose()
+                                .map_err(|e| anyhow!(e))
+                                .with_context(context)?;
+                            let msrp = MonetaryAmount::from_db(
+                                known.msrp_amount.unwrap_or(0),
+                                known.msrp_currency.as_deref(),
+                            )
+                            .with_context(context)?;
+
+                            if entry.changes(delivery_date.as_ref(), msrp.as_ref()) {
+                                if let Some(delivery_date) = &entry.delivery_date {
+                                    known.delivery_date = Some(delivery_date.to_string());
+                                }
+                                if entry.msrp.is_some() {
+                                    known.msrp_amount = msrp_amount;
+                                    known.msrp_currency = msrp_currency.map(str::to_string);
+                                }
+                                sqlite::update_railway_model_announcement(&mut *conn, &known)
+                                    .await?;
+                                let change = AuditedChange::updated(
+                                    "railway_model",
+                                    known.id.to_string(),
+                                    &entry,
+                                )?;
+                                audit.record(&mut *conn, &change).await?;
+                                (known.id, IngestOutcome::Updated)
+                            } else {
+                                (known.id, IngestOutcome::Unchanged)
+                            }
+                        }
+                    };
+                    ingest.record(product_code, railway_model_id, outcome);
+                }
+                Ok(ingest)
+            })
+        })
+        .await?;
+
+        for entry in &ingest.entries {
+            if entry.outcome == IngestOutcome::Updated {
+                self.cache.invalidate(&entry.railway_model_id);
+            }
+        }
+        Ok(ingest)
+    }
+
+    async fn lookup_product_codes(
+        &self,
+        queries: Vec<ProductCodeQuery>,
+    ) -> Result<Vec<ProductCodeLookup>> {
+        validate_lookup(&queries)?;
+        let mut keys: Vec<(String, String)> = queries.iter().map(ProductCodeQuery::key).collect();
+        keys.sort();
+        keys.dedup();
+
+        let mut matches = HashMap::new();
+        for row in sqlite::find_railway_models_by_product_codes(&self.pool, &keys).await? {
+            matches
+                .entry((row.manufacturer, row.product_code))
+                .or_insert(row.railway_model_id);
+        }
+        let railway_model_ids: Vec<RailwayModelId> = matches.values().copied().collect();
+        let owned: HashSet<RailwayModelId> =
+            sqlite::find_owned_railway_models(&self.pool, &railway_model_ids)
+                .await?
+                .into_iter()
+                .collect();
+
+        Ok(ProductCodeLookup::classify(queries, &matches, &owned))
+    }
+
+    async fn merge_railway_models(
+        &self,
+        keep_id: &RailwayModelId,
+        remove_id: &RailwayModelId,
+    ) -> Result<ModelMerge> {
+        let keep_id = *keep_id;
+        let remove_id = *remove_id;
+        if keep_id == remove_id {
+            return Err(MergeError::SameModel(keep_id).into());
+        }
+        let audit = Arc::clone(&self.audit);
+
+        let merge = with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let mut manufacturers = Vec::new();
+                for id in [&keep_id, &remove_id] {
+                    let manufacturer_id = sqlite::get_railway_model_manufacturer_id(&mut *conn, id)
+                        .await?
+                        .ok_or_else(|| anyhow!("railway_model not found id={}", id))?;
+                    manufacturers.push(manufacturer_id);
+                }
+                if manufacturers[0] != manufacturers[1] {
+                    return Err(MergeError::DifferentManufacturers { keep_id, remove_id }.into());
+                }
+
+                let rolling_stocks_moved =
+                    sqlite::move_rolling_stocks(&mut *conn, &remove_id, &keep_id).await?;
+                let collection_items_moved =
+                    sqlite::move_collection_items(&mut *conn, &remove_id, &keep_id).await?;
+                sqlite::delete_railway_model(&mut *conn, &remove_id).await?;
+
+                let merge = ModelMerge {
+                    collection_items_moved: u32::try_from(collection_items_moved)?,
+                    rolling_stocks_moved: u32::try_from(rolling_stocks_moved)?,
+                    kept_id: keep_id,
+                    removed_id: remove_id,
+                };
+                let kept =
+                    AuditedChange::updated("railway_model", merge.kept_id.to_string(), &merge)?;
+                audit.record(&mut *conn, &kept).await?;
+                let removed = AuditedChange::deleted("railway_model", merge.removed_id.to_string());
+                audit.record(conn, &removed).await?;
+                Ok(merge)
+            })
+        })
+        .await?;
+
+        self.cache.invalidate(&merge.kept_id);
+        self.cache.invalidate(&merge.removed_id);
+        Ok(merge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::domain::announcement::AnnouncedModel;
+    use crate::catalog::domain::product_code_lookup::{LookupError, MAX_LOOKUP_CODES};
+    use crate::catalog::domain::rolling_stock_comparison::{
+        ComparedField, ComparisonError, FieldOutcome,
+    };
+    use crate::catalog::domain::{Category, PowerMethod};
+    use crate::catalog::infrastructure::testing::{CatalogTestData, CatalogTestDb};
+    use crate::collecting::infrastructure::testing::CollectingTestDb;
+    use crate::core::domain::Currency;
+    use crate::test_utils::{statement_counting_pool, statements_run};
+    use pretty_assertions::assert_eq;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_search_railway_models_maps_summaries(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+
+        let repo = SqliteCatalogRepository::new(pool.clone());
+        let results = repo.search_railway_models("E656", 10).await?;
+
+        assert_eq!(results.len(), 1);
+        let summary = &results[0];
+        assert_eq!(summary.id, catalog_test_data.railway_model_id);
+        assert_eq!(summary.manufacturer, "ACME");
+        assert_eq!(summary.product_code.to_string(), "E656");
+        assert_eq!(summary.scale, Scale::H0);
+
+        Ok(())
+    }
+
+    /// Insert railway models of the manufacturer `manufacturer_id` with the
+    /// given product codes, scales and descriptions, returning their ids in
+    /// order.
+    async fn insert_models(
+        catalog_db: &CatalogTestDb,
+        manufacturer_id: &str,
+        models: &[(&str, &str, &str)],
+    ) -> Result<Vec<RailwayModelId>> {
+        let mut ids = Vec::new();
+        for (product_code, scale, description) in models {
+            let id = catalog_db
+                .insert_railway_model(
+                    &RailwayModelId::new(),
+                    manufacturer_id,
+                    &product_code.to_uppercase(),
+                    description,
+                    "electric",
+                    scale,
+                    "IV",
+                    "LOCOMOTIVES",
+                )
+                .await?;
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_find_possible_duplicates_ranks_the_most_similar_first(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let test_data = catalog_db.setup_railway_model().await?;
+        catalog_db.insert_manufacturer("roco", "Roco").await?;
+        let ids = insert_models(
+            &catalog_db,
+            &test_data.manufacturer_id,
+            &[
+                (
+                    "e656-a",
+                    "H0",
+                    "FS Class E656 electric locomotive, 1a serie",
+                ),
+                ("e656-b", "H0", "FS E.656 electric loco"),
+                ("e656-n", "N", "FS Class E656 electric locomotive"),
+                ("br218", "H0", "DB BR 218 diesel"),
+            ],
+        )
+        .await?;
+        // Another manufacturer's identical description is not a duplicate.
+        insert_models(
+            &catalog_db,
+            "roco",
+            &[("roco-e656", "H0", "FS Class E656 electric locomotive")],
+        )
+        .await?;
+        let repo = SqliteCatalogRepository::new(pool.clone());
+
+        let candidates = repo.find_possible_duplicates(0.3).await?;
+
+        let pairs: Vec<(RailwayModelId, RailwayModelId)> = candidates
+            .iter()
+            .map(|c| (c.model.id, c.duplicate.id))
+            .collect();
+        let (setup_model, e656_a, e656_b) = (test_data.railway_model_id, ids[0], ids[1]);
+        assert_eq!(
+            pairs,
+            vec![
+                (setup_model, e656_a),
+                (setup_model, e656_b),
+                (e656_a, e656_b),
+            ]
+        );
+        assert!(
+            candidates
+                .windows(2)
+                .all(|w| w[0].similarity >= w[1].similarity)
+        );
+        assert!(repo.find_possible_duplicates(1.0).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_merge_railway_models_repoints_references(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let test_data = catalog_db.setup_railway_model().await?;
+        let duplicate = insert_models(
+            &catalog_db,
+            &test_data.manufacturer_id,
+            &[("e656-dup", "H0", "E.656")],
+        )
+        .await?[0];
+        catalog_db
+            .insert_rolling_stock(
+                "rs-dup",
+                &duplicate,
+                "LOCOMOTIVE",
+                &test_data.railway_company_id,
+                0,
+            )
+            .await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection = collecting_db
+            .setup_minimal_collection(&duplicate, vec!["rs-dup"])
+            .await?;
+        let repo = SqliteCatalogRepository::new(pool.clone());
+
+        let merge = repo
+            .merge_railway_models(&test_data.railway_model_id, &duplicate)
+            .await?;
+
+        assert_eq!(merge.collection_items_moved, 1);
+        assert_eq!(merge.rolling_stocks_moved, 1);
+        assert!(
+            sqlite::get_railway_model(&pool, &duplicate)
+                .await?
+                .is_none()
+        );
+        let (item_model, item_version): (RailwayModelId, i64) =
+            sqlx::query_as("SELECT railway_model_id, version FROM collection_items WHERE id = ?1")
+                .bind(&collection.collection_item_id)
+                .fetch_one(&pool)
+                .await?;
+        assert_eq!(item_model, test_data.railway_model_id);
+        assert_eq!(item_version, 1);
+        // The owned rolling stock still references the moved rolling stock.
+        let (rs_model, owned): (RailwayModelId, i64) = sqlx::query_as(
+            "SELECT rs.railway_model_id, COUNT(ors.id) FROM rolling_stocks rs
+             JOIN owned_rolling_stocks ors ON ors.rolling_stock_id = rs.id
+             WHERE rs.id = 'rs-dup'",
+        )
+        .fetch_one(&pool)
+        .await?;
+        assert_eq!(rs_model, test_data.railway_model_id);
+        assert_eq!(owned, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_merge_railway_models_refuses_other_manufacturers(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let test_data = catalog_db.setup_railway_model().await?;
+        catalog_db.insert_manufacturer("roco", "Roco").await?;
+        let roco = insert_models(&catalog_db, "roco", &[("roco-e656", "H0", "E.656")]).await?[0];
+        let repo = SqliteCatalogRepository::new(pool.clone());
+
+        let err = repo
+            .merge_railway_models(&test_data.railway_model_id, &roco)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<MergeError>(),
+            Some(MergeError::DifferentManufacturers { .. })
+        ));
+        assert!(sqlite::get_railway_model(&pool, &roco).await?.is_some());
+
+        let err = repo.merge_railway_models(&roco, &roco).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<MergeError>(),
+            Some(MergeError::SameModel(_))
+        ));
+        assert!(
+            repo.merge_railway_models(&roco, &RailwayModelId::new())
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    /// Insert a second locomotive and a freight car into the model of
+    /// `test_data`, returning their ids.
+    async fn insert_comparable_rolling_stocks(
+        pool: &SqlitePool,
+        test_data: &CatalogTestData,
+    ) -> Result<(String, String)> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let mut ids = Vec::new();
+        for category in ["LOCOMOTIVE", "FREIGHT_CAR"] {
+            let id = Uuid::new_v4().to_string();
+            catalog_db
+                .insert_rolling_stock(
+                    &id,
+                    &test_data.railway_model_id,
+                    category,
+                    &test_data.railway_company_id,
+                    0,
+                )
+                .await?;
+            ids.push(id);
+        }
+        let sql = "UPDATE rolling_stocks SET type_name = 'E.656', locomotive_type = 'ELECTRIC_LOCOMOTIVE', length_millimeters = ?2, technical_minimum_radius_mm = 360.0, technical_coupling = 'NEM_362', dcc_interface = ?3, road_number = ?4 WHERE id = ?1";
+        for (id, length, dcc_interface, road_number) in [
+            (
+                &test_data.rolling_stock_ids[0],
+                210.0,
+                Some("NEXT_18"),
+                "E.656 077",
+            ),
+            (&ids[0], 210.0, None, "E.656 208"),
+        ] {
+            sqlx::query(sql)
+                .bind(id)
+                .bind(length)
+                .bind(dcc_interface)
+                .bind(road_number)
+                .execute(pool)
+                .await?;
+        }
+        Ok((ids.remove(0), ids.remove(0)))
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_compare_rolling_stocks_lines_up_two_locomotives(pool: SqlitePool) -> Result<()> {
+        let test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let (locomotive_id, freight_car_id) =
+            insert_comparable_rolling_stocks(&pool, &test_data).await?;
+        let left_id = RollingStockId::try_from(test_data.rolling_stock_ids[0].as_str())?;
+        let right_id = RollingStockId::try_from(locomotive_id.as_str())?;
+        let repo = SqliteCatalogRepository::new(pool.clone());
+
+        let report = repo.compare_rolling_stocks(&left_id, &right_id).await?;
+
+        assert_eq!(report.category, RollingStockCategory::Locomotive);
+        let outcomes: Vec<(ComparedField, FieldOutcome)> = report
+            .fields
+            .iter()
+            .map(|field| (field.field, field.outcome))
+            .filter(|(_, outcome)| *outcome != FieldOutcome::Missing)
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (ComparedField::Railway, FieldOutcome::Equal),
+                (ComparedField::Epoch, FieldOutcome::Equal),
+                (ComparedField::TypeName, FieldOutcome::Equal),
+                (ComparedField::Type, FieldOutcome::Equal),
+                (ComparedField::RoadNumber, FieldOutcome::Different),
+                (ComparedField::Length, FieldOutcome::Equal),
+                (ComparedField::MinimumRadius, FieldOutcome::Equal),
+                (ComparedField::Coupling, FieldOutcome::Equal),
+                (ComparedField::Dummy, FieldOutcome::Equal),
+            ]
+        );
+        let dcc_interface = report
+            .fields
+            .iter()
+            .find(|field| field.field == ComparedField::DccInterface)
+            .unwrap();
+        assert_eq!(dcc_interface.left.as_deref(), Some("Next18"));
+        assert_eq!(dcc_interface.outcome, FieldOutcome::Missing);
+
+        let freight_car_id = RollingStockId::try_from(freight_car_id.as_str())?;
+        let err = repo
+            .compare_rolling_stocks(&left_id, &freight_car_id)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ComparisonError>(),
+            Some(ComparisonError::DifferentCategories { .. })
+        ));
+        assert!(
+            repo.compare_rolling_stocks(&left_id, &RollingStockId::new())
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    fn announcement(product_code: &str, delivery_date: &str, msrp: u64) -> AnnouncedModel {
+        AnnouncedModel {
+            product_code: ProductCode(product_code.to_string()),
+            description: format!("Electric locomotive {product_code}"),
+            category: Category::Locomotives,
+            power_method: PowerMethod::DC,
+            scale: Scale::H0,
+            epoch: Epoch::from("IV"),
+            delivery_date: Some(DeliveryDate::parse(delivery_date).unwrap()),
+            msrp: Some(MonetaryAmount::new(msrp, Currency::EUR)),
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_ingest_announcements_updates_known_product_codes(pool: SqlitePool) -> Result<()> {
+        CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let repo = SqliteCatalogRepository::new(pool.clone());
+        let mut batch = AnnouncementBatch {
+            manufacturer: "acme".to_string(),
+            entries: vec![
+                announcement("60211", "2026/Q3", 24_990),
+                announcement("60212", "2026", 19_990),
+            ],
+        };
+
+        let created = repo.ingest_announcements(&batch).await?;
+        assert_eq!(
+            (created.created, created.updated, created.unchanged),
+            (2, 0, 0)
+        );
+        let again = repo.ingest_announcements(&batch).await?;
+        assert_eq!((again.created, again.updated, again.unchanged), (0, 0, 2));
+        assert_eq!(
+            again.entries[0].railway_model_id,
+            created.entries[0].railway_model_id
+        );
+
+        batch.entries[0] = announcement("60211", "2026/Q4", 26_990);
+        batch.entries[0].product_code = ProductCode("60211 ".to_string());
+        batch.entries[1].delivery_date = None;
+        let updated = repo.ingest_announcements(&batch).await?;
+        let outcomes: Vec<IngestOutcome> = updated.entries.iter().map(|e| e.outcome).collect();
+        assert_eq!(
+            outcomes,
+            vec![IngestOutcome::Updated, IngestOutcome::Unchanged]
+        );
+
+        type Stored = (String, Option<String>, Option<i64>, Option<String>);
+        let stored: Vec<Stored> = sqlx::query_as(
+            "SELECT product_code, delivery_date, msrp_amount, availability_status FROM railway_models WHERE product_code LIKE '6021%' ORDER BY product_code",
+        )
+        .fetch_all(&pool)
+        .await?;
+        assert_eq!(
+            stored,
+            vec![
+                (
+                    "60211".to_string(),
+                    Some("2026/Q4".to_string()),
+                    Some(26_990),
+                    Some("ANNOUNCED".to_string())
+                ),
+                (
+                    "60212".to_string(),
+                    Some("2026".to_string()),
+                    Some(19_990),
+                    Some("ANNOUNCED".to_string())
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_ingest_announcements_requires_a_known_manufacturer(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let repo = SqliteCatalogRepository::new(pool.clone());
+        let batch = AnnouncementBatch {
+            manufacturer: "Roco".to_string(),
+            entries: vec![announcement("70000", "2026", 19_990)],
+        };
+
+        let err = repo.ingest_announcements(&batch).await.unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<AnnouncementError>(),
+            Some(&AnnouncementError::UnknownManufacturer("Roco".to_string()))
+        );
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM railway_models")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(count, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_lookup_product_codes_classifies_matched_unmatched_and_owned(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        catalog_db.insert_manufacturer("m-acme", "ACME").await?;
+        let (e656, e656_dcc) = (RailwayModelId::new(), RailwayModelId::new());
+        for (id, product_code) in [(&e656, "60211"), (&e656_dcc, "60212")] {
+            catalog_db
+                .insert_railway_model(
+                    id,
+                    "m-acme",
+                    product_code,
+                    "Electric locomotive E.656",
+                    "DC",
+                    "H0",
+                    "IV",
+                    "LOCOMOTIVES",
+                )
+                .await?;
+        }
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        collecting_db
+            .insert_collection_item(&collection_id, &e656_dcc)
+            .await?;
+        let repo = SqliteCatalogRepository::new(statement_counting_pool(&pool).await);
+        let query = |manufacturer: &str, product_code: &str| ProductCodeQuery {
+            manufacturer: manufacturer.to_string(),
+            product_code: ProductCode(product_code.to_string()),
+        };
+
+        let before = statements_run();
+        let lookups = repo
+            .lookup_product_codes(vec![
+                query("acme", "60211"),
+                query("ACME", " 60212 "),
+                query("ACME", "99999"),
+                query("Roco", "60211"),
+                query("ACME", "60211"),
+            ])
+            .await?;
+        let statements = statements_run() - before;
+
+        let classified: Vec<(&str, Option<RailwayModelId>, bool)> = lookups
+            .iter()
+            .map(|lookup| {
+                (
+                    lookup.product_code.0.as_str(),
+                    lookup.railway_model_id,
+                    lookup.owned,
+                )
+            })
+            .collect();
+        assert_eq!(
+            classified,
+            vec![
+                ("60211", Some(e656), false),
+                (" 60212 ", Some(e656_dcc), true),
+                ("99999", None, false),
+                ("60211", None, false),
+                ("60211", Some(e656), false),
+            ]
+        );
+        assert_eq!(statements, 2);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_lookup_product_codes_limits_the_codes(pool: SqlitePool) {
+        let repo = SqliteCatalogRepository::new(pool);
+        let query = ProductCodeQuery {
+            manufacturer: "ACME".to_string(),
+            product_code: ProductCode("60211".to_string()),
+        };
+
+        let err = repo
+            .lookup_product_codes(vec![query; MAX_LOOKUP_CODES + 1])
+            .await
+            .unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<LookupError>(),
+            Some(&LookupError::TooManyCodes(MAX_LOOKUP_CODES + 1))
+        );
+    }
+
+    /// Insert `count` railway models with three rolling stocks each, next to
+    /// the one of `CatalogTestDb::setup_railway_model`, whose id is returned.
+    async fn insert_models_with_rolling_stocks(
+        pool: &SqlitePool,
+        count: i64,
+    ) -> Result<RailwayModelId> {
+        let test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        sqlx::query(
+            "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < ?1)
+             INSERT INTO railway_models (id, manufacturer_id, product_code, description, power_method, scale, epoch, category)
+             SELECT printf('00000000-0000-0000-0000-%012d', i), m.id, printf('P%05d', i), 'model', 'DC', 'H0', 'IV', 'LOCOMOTIVES'
+             FROM n, (SELECT id FROM manufacturers LIMIT 1) AS m",
+        )
+        .bind(count)
+        .execute(pool)
+        .await?;
+        sqlx::query(
+            "WITH RECURSIVE k(j) AS (SELECT 1 UNION ALL SELECT j + 1 FROM k WHERE j < 3)
+             INSERT INTO rolling_stocks (id, railway_model_id, category, railway_company_id, length_millimeters)
+             SELECT rm.id || '-' || k.j, rm.id, 'LOCOMOTIVE', rc.id, 100 + k.j
+             FROM railway_models AS rm, k, (SELECT id FROM railway_companies LIMIT 1) AS rc
+             WHERE rm.id != ?1",
+        )
+        .bind(test_data.railway_model_id)
+        .execute(pool)
+        .await?;
+        Ok(test_data.railway_model_id)
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_list_railway_models_runs_one_query_per_page(pool: SqlitePool) -> Result<()> {
+        let setup_model = insert_models_with_rolling_stocks(&pool, 150).await?;
+        let repo = SqliteCatalogRepository::new(statement_counting_pool(&pool).await);
+
+        for limit in [1, 10, 150] {
+            let before = statements_run();
+            let page = repo.list_railway_models(None, limit).await?;
+            let statements = statements_run() - before;
+
+            assert_eq!(page.items.len(), limit as usize);
+            assert!(
+                page.items
+                    .iter()
+                    .filter(|entry| entry.summary.id != setup_model)
+                    .all(|entry| entry.rolling_stocks_count == 3
+                        && entry.min_length_over_buffers_mm == Some(101.0)
+                        && entry.max_length_over_buffers_mm == Some(103.0))
+            );
+            assert!(
+                (1..=2).contains(&statements),
+                "listing {limit} railway models ran {statements} statements"
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -6,7 +6,9 @@
 //! `id` on success (the migrations use `TEXT` primary keys), and wrap errors in
 //! `anyhow::Error` with added context.
 
+use crate::catalog::domain::railway_model_id::RailwayModelId;
 use anyhow::{Context, Result};
+use chrono::Utc;
 use sqlx::SqlitePool;
 use uuid::Uuid;
 
@@ -18,7 +20,7 @@ pub struct CatalogTestData {
     /// Inserted railway company id
     pub railway_company_id: String,
     /// Inserted railway model id
-    pub railway_model_id: String,
+    pub railway_model_id: RailwayModelId,
     /// Inserted rolling stock ids (may contain multiple ids)
     pub rolling_stock_ids: Vec<String>,
 }
@@ -102,7 +104,7 @@ impl CatalogTestDb {
     #[allow(clippy::too_many_arguments)]
     pub async fn insert_railway_model(
         &self,
-        id: &str,
+        id: &RailwayModelId,
         manufacturer_id: &str,
         product_code: &str,
         description: &str,
@@ -110,9 +112,9 @@ impl CatalogTestDb {
         scale: &str,
         epoch: &str,
         category: &str,
-    ) -> Result<String> {
+    ) -> Result<RailwayModelId> {
         let sql = format!(
-            "INSERT INTO {} (id, manufacturer_id, product_code, description, power_method, scale, epoch, category) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            "INSERT INTO {} (id, manufacturer_id, product_code, description, power_method, scale, epoch, category, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?9)",
             "railway_models"
         );
         sqlx::query(&sql)
@@ -124,6 +126,7 @@ impl CatalogTestDb {
             .bind(scale)
             .bind(epoch)
             .bind(category)
+            .bind(Utc::now())
             .execute(&self.db_pool)
             .await
             .with_context(|| {
@@ -133,7 +136,7 @@ impl CatalogTestDb {
                 )
             })?;
 
-        Ok(id.to_string())
+        Ok(*id)
     }
 
     /// Insert a rolling stock record.
@@ -153,13 +156,13 @@ impl CatalogTestDb {
     pub async fn insert_rolling_stock(
         &self,
         id: &str,
-        railway_model_id: &str,
+        railway_model_id: &RailwayModelId,
         category: &str,
         railway_company_id: &str,
         is_dummy: i32,
     ) -> Result<String> {
         let sql = format!(
-            "INSERT INTO {} (id, railway_model_id, category, railway_company_id, is_dummy) VALUES (?1, ?2, ?3, ?4, ?5)",
+            "INSERT INTO {} (id, railway_model_id, category, railway_company_id, is_dummy, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?6)",
             "rolling_stocks"
         );
         sqlx::query(&sql)
@@ -168,6 +171,7 @@ impl CatalogTestDb {
             .bind(category)
             .bind(railway_company_id)
             .bind(is_dummy)
+            .bind(Utc::now())
             .execute(&self.db_pool)
             .await
             .with_context(|| {
@@ -188,7 +192,7 @@ impl CatalogTestDb {
         // Generate ids
         let manufacturer_id = Uuid::new_v4().to_string();
         let railway_company_id = Uuid::new_v4().to_string();
-        let railway_model_id = Uuid::new_v4().to_string();
+        let railway_model_id = RailwayModelId::new();
         let rolling_stock_id = Uuid::new_v4().to_string();
 
         // Insert manufacturer and company
@@ -200,7 +204,7 @@ impl CatalogTestDb {
         let product_code = "E656";
         let description = "FS Class E656 electric locomotive";
         let power_method = "electric";
-        let scale = "HO";
+        let scale = "H0";
         let epoch = "VI";
         let category = "locomotive";
 
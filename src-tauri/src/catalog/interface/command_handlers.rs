@@ -0,0 +1,455 @@
+//! Command handlers exposed to the Tauri frontend for the `catalog` feature.
+//!
+//! These functions act as a thin adapter between the Tauri IPC layer and the
+//! application/use-case layer. They translate incoming requests into use-case
+//! invocations and map application errors into `CommandError` values suitable
+//! for returning over the IPC boundary.
+
+use crate::catalog::application::compare_rolling_stocks::CompareRollingStocksUseCase;
+use crate::catalog::application::create_livery::CreateLiveryUseCase;
+use crate::catalog::application::find_possible_duplicates::FindPossibleDuplicatesUseCase;
+use crate::catalog::application::get_reference_data::GetReferenceDataUseCase;
+use crate::catalog::application::ingest_announcements::IngestAnnouncementsUseCase;
+use crate::catalog::application::list_railway_models::ListRailwayModelsUseCase;
+use crate::catalog::application::lookup_product_codes::LookupProductCodesUseCase;
+use crate::catalog::application::merge_railway_models::MergeRailwayModelsUseCase;
+use crate::catalog::application::search_catalog::SearchCatalogUseCase;
+use crate::catalog::application::suggest_liveries::SuggestLiveriesUseCase;
+use crate::catalog::application::update_livery::UpdateLiveryUseCase;
+use crate::catalog::application::validate_product_code::ValidateProductCodeUseCase;
+use crate::catalog::domain::announcement::{
+    AnnouncementBatch, AnnouncementError, AnnouncementIngest,
+};
+use crate::catalog::domain::category::RollingStockCategory;
+use crate::catalog::domain::duplicate_candidate::DuplicateCandidate;
+use crate::catalog::domain::livery::{Livery, LiveryDetails, LiveryError};
+use crate::catalog::domain::model_merge::{MergeError, ModelMerge};
+use crate::catalog::domain::product_code_lookup::{
+    LookupError, ProductCodeLookup, ProductCodeQuery,
+};
+use crate::catalog::domain::product_code_validation::ProductCodeValidation;
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::domain::railway_model_list_entry::RailwayModelListEntry;
+use crate::catalog::domain::railway_model_summary::RailwayModelSummary;
+use crate::catalog::domain::reference_data::ReferenceData;
+use crate::catalog::domain::road_number::{self, ValidationOutcome};
+use crate::catalog::domain::rolling_stock_comparison::{ComparisonError, ComparisonReport};
+use crate::catalog::domain::rolling_stock_id::RollingStockId;
+use crate::catalog::domain::scale::Scale;
+use crate::catalog::domain::scale_speed::{self, ScaleSpeed};
+use crate::catalog::infrastructure::cache::RailwayModelCache;
+use crate::catalog::infrastructure::sqlite_livery_repo::SqliteLiveryRepository;
+use crate::catalog::infrastructure::sqlite_repo::SqliteCatalogRepository;
+use crate::core::domain::length::Length;
+use crate::core::domain::page::{InvalidCursor, Page};
+use crate::core::infrastructure::error::CommandError;
+use crate::state::AppState;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+/// Tauri command to search the catalog for railway models.
+///
+/// Intended for autocomplete pickers: the query is trimmed, queries shorter
+/// than two characters return an empty list and `limit` is clamped to a
+/// sensible maximum. Results are ranked with exact product code matches
+/// first.
+///
+/// Returns:
+/// - `Ok(Vec<RailwayModelSummary>)` with the matching models.
+/// - `Err(CommandError)` when the search fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn search_catalog(
+    state: tauri::State<'_, AppState>,
+    query: String,
+    limit: u32,
+) -> Result<Vec<RailwayModelSummary>, CommandError> {
+    let repo = SqliteCatalogRepository::new(state.db_pool());
+    let use_case = SearchCatalogUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&query, limit)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to list the railway models one page at a time.
+///
+/// Models are ordered by product code and come with their rolling stock
+/// count and the range of their lengths over buffers, all read with a single
+/// query per page. Pass `cursor: null` for the first page and the returned
+/// `next_cursor` for the following ones, until it is `null`. `limit` is
+/// clamped to a sensible maximum.
+///
+/// Returns:
+/// - `Ok(Page<RailwayModelListEntry>)` with the models of the page.
+/// - `Err(CommandError::Validation)` when `cursor` is not a cursor returned
+///   by this command.
+/// - `Err(CommandError)` for any other failure.
+#[tauri::command]
+#[specta::specta]
+pub async fn list_railway_models(
+    state: tauri::State<'_, AppState>,
+    cursor: Option<String>,
+    limit: u32,
+) -> Result<Page<RailwayModelListEntry>, CommandError> {
+    let repo = SqliteCatalogRepository::new(state.db_pool());
+    let use_case = ListRailwayModelsUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(cursor.as_deref(), limit)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command returning the option lists for the catalog forms.
+///
+/// Returns manufacturers, railway companies, scales, categories, DCC
+/// interfaces and coupling sockets in one payload, so a form can populate
+/// all of its dropdowns with a single call.
+///
+/// Returns:
+/// - `Ok(ReferenceData)` with every option list.
+/// - `Err(CommandError)` when reading from the database fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_reference_data(
+    state: tauri::State<'_, AppState>,
+) -> Result<ReferenceData, CommandError> {
+    let repo = SqliteCatalogRepository::new(state.db_pool());
+    let use_case = GetReferenceDataUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute()
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to check a product code before submitting the "add model"
+/// form.
+///
+/// The code is trimmed and compared case-insensitively against the models
+/// already registered for `manufacturer_id`, using the same normalization as
+/// model creation.
+///
+/// Returns:
+/// - `Ok(ProductCodeValidation)` describing whether the code is available,
+///   already used or malformed.
+/// - `Err(CommandError)` when the lookup fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn validate_product_code(
+    state: tauri::State<'_, AppState>,
+    manufacturer_id: String,
+    product_code: String,
+) -> Result<ProductCodeValidation, CommandError> {
+    let repo = SqliteCatalogRepository::new(state.db_pool());
+    let use_case = ValidateProductCodeUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&manufacturer_id, &product_code)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to check the road number of a rolling stock as it is
+/// typed, for example "E.656 077" or the UIC number "61 83 19-90 105-3".
+///
+/// The check never blocks saving: a warning comes with the normalized or
+/// corrected road number to suggest, when one can be worked out.
+///
+/// Parameters:
+/// - `railway`: the name of the railway company of the rolling stock.
+/// - `category`: the rolling stock category.
+/// - `road_number`: the road number as typed.
+#[tauri::command]
+#[specta::specta]
+pub fn validate_road_number(
+    railway: String,
+    category: RollingStockCategory,
+    road_number: String,
+) -> ValidationOutcome {
+    road_number::validate_road_number(&railway, category, &road_number)
+}
+
+/// Tauri command to find the railway models that are probably duplicates,
+/// for example the same model imported from two sources.
+///
+/// Only models from the same manufacturer and scale are compared, by the
+/// trigram similarity of their descriptions. `threshold` (from 0 to 1) is
+/// clamped to a sensible minimum.
+///
+/// Returns:
+/// - `Ok(Vec<DuplicateCandidate>)` with the candidate pairs, most similar
+///   first.
+/// - `Err(CommandError)` when reading from the database fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn find_possible_duplicates(
+    state: tauri::State<'_, AppState>,
+    threshold: f64,
+) -> Result<Vec<DuplicateCandidate>, CommandError> {
+    let repo = SqliteCatalogRepository::new(state.db_pool());
+    let use_case = FindPossibleDuplicatesUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(threshold)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to merge a duplicate railway model into the one kept.
+///
+/// The rolling stocks of `remove_id` and the collection items referencing it
+/// move to `keep_id`, then `remove_id` is deleted, all in one transaction.
+///
+/// Arguments:
+/// - `catalog_cache`: the shared cache of railway model summaries, from
+///   which both models are dropped.
+///
+/// Returns:
+/// - `Ok(ModelMerge)` with what was moved.
+/// - `Err(CommandError::Validation)` when the ids are the same or the models
+///   are from different manufacturers.
+/// - `Err(CommandError)` for any other failure, for example an unknown id.
+#[tauri::command]
+#[specta::specta]
+pub async fn merge_railway_models(
+    state: tauri::State<'_, AppState>,
+    catalog_cache: tauri::State<'_, RailwayModelCache>,
+    keep_id: RailwayModelId,
+    remove_id: RailwayModelId,
+) -> Result<ModelMerge, CommandError> {
+    let repo =
+        SqliteCatalogRepository::new(state.db_pool()).with_cache(catalog_cache.inner().clone());
+    let use_case = MergeRailwayModelsUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&keep_id, &remove_id)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to compare two rolling stocks side by side, for choosing
+/// between models of the same prototype.
+///
+/// Their shared fields and those of their category are lined up and marked
+/// as equal, different or missing.
+///
+/// Parameters:
+/// - `a`: the identifier of the first rolling stock.
+/// - `b`: the identifier of the second rolling stock.
+///
+/// Returns:
+/// - `Ok(ComparisonReport)` with the fields of both.
+/// - `Err(CommandError::Validation)` when the rolling stocks are of
+///   different categories.
+/// - `Err(CommandError)` when a rolling stock does not exist or reading
+///   fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn compare_rolling_stocks(
+    state: tauri::State<'_, AppState>,
+    a: RollingStockId,
+    b: RollingStockId,
+) -> Result<ComparisonReport, CommandError> {
+    let repo = SqliteCatalogRepository::new(state.db_pool());
+    let use_case = CompareRollingStocksUseCase::new(Arc::new(repo));
+
+    use_case.execute(&a, &b).await.map_err(to_command_error)
+}
+
+/// Tauri command to ingest a list of new items announced by a manufacturer.
+///
+/// Product codes the catalog does not know become announced railway models;
+/// for the known ones only the delivery date and the suggested retail price
+/// are refreshed, so the same list can be ingested again.
+///
+/// Parameters:
+/// - `batch`: the manufacturer and its announced items.
+///
+/// Returns:
+/// - `Ok(AnnouncementIngest)` with what happened to each item.
+/// - `Err(CommandError::Validation)` when the manufacturer is unknown, a
+///   product code or description is blank, or a product code is repeated;
+///   nothing is stored.
+/// - `Err(CommandError)` when writing fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn ingest_announcements(
+    state: tauri::State<'_, AppState>,
+    batch: AnnouncementBatch,
+) -> Result<AnnouncementIngest, CommandError> {
+    let repo = SqliteCatalogRepository::new(state.db_pool());
+    let use_case = IngestAnnouncementsUseCase::new(Arc::new(repo));
+
+    use_case.execute(&batch).await.map_err(to_command_error)
+}
+
+/// Tauri command to tell which of a list of product codes, for example
+/// those of an order confirmation, are in the catalog and in the collection.
+///
+/// Manufacturers and product codes are compared ignoring case and
+/// surrounding blanks.
+///
+/// Parameters:
+/// - `queries`: the manufacturer and product code pairs to look up, at most
+///   `MAX_LOOKUP_CODES` of them.
+///
+/// Returns:
+/// - `Ok(Vec<ProductCodeLookup>)` with the railway model of each pair, if
+///   any, and whether it is owned, in the order of `queries`.
+/// - `Err(CommandError::Validation)` when there are too many pairs.
+/// - `Err(CommandError)` when the lookup fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn lookup_product_codes(
+    state: tauri::State<'_, AppState>,
+    queries: Vec<ProductCodeQuery>,
+) -> Result<Vec<ProductCodeLookup>, CommandError> {
+    let repo = SqliteCatalogRepository::new(state.db_pool());
+    let use_case = LookupProductCodesUseCase::new(Arc::new(repo));
+
+    use_case.execute(queries).await.map_err(to_command_error)
+}
+
+/// Tauri command to add a livery to a railway company.
+///
+/// Parameters:
+/// - `livery`: the livery details; the text fields are trimmed.
+///
+/// Returns:
+/// - `Ok(Livery)` with the stored livery and its generated id.
+/// - `Err(CommandError::Validation)` when the name is blank or the period of
+///   use ends before it starts.
+/// - `Err(CommandError)` when the livery cannot be stored, for example when
+///   the railway company already has a livery with this name.
+#[tauri::command]
+#[specta::specta]
+pub async fn create_livery(
+    state: tauri::State<'_, AppState>,
+    livery: LiveryDetails,
+) -> Result<Livery, CommandError> {
+    let repo = SqliteLiveryRepository::new(state.db_pool());
+    let use_case = CreateLiveryUseCase::new(Arc::new(repo));
+
+    use_case.execute(livery).await.map_err(to_command_error)
+}
+
+/// Tauri command to change the details of a livery.
+///
+/// The details are checked as by `create_livery`.
+///
+/// Parameters:
+/// - `id`: the identifier of the livery.
+/// - `livery`: the new livery details, replacing all the stored ones.
+///
+/// Returns:
+/// - `Ok(Livery)` with the stored livery.
+/// - `Err(CommandError::Validation)` when the details are not valid.
+/// - `Err(CommandError)` when the livery does not exist or cannot be stored.
+#[tauri::command]
+#[specta::specta]
+pub async fn update_livery(
+    state: tauri::State<'_, AppState>,
+    id: String,
+    livery: LiveryDetails,
+) -> Result<Livery, CommandError> {
+    let repo = SqliteLiveryRepository::new(state.db_pool());
+    let use_case = UpdateLiveryUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&id, livery)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to suggest the liveries of a railway company, for the
+/// livery field of the rolling stock form.
+///
+/// Parameters:
+/// - `railway_id`: the identifier of the railway company of the rolling
+///   stock.
+/// - `prefix`: what was typed so far; blank suggests every livery.
+///
+/// Returns:
+/// - `Ok(Vec<Livery>)` with the exact name match first, then the name and
+///   word prefix matches, at most `limit` of them.
+/// - `Err(CommandError)` when the liveries cannot be read.
+#[tauri::command]
+#[specta::specta]
+pub async fn suggest_liveries(
+    state: tauri::State<'_, AppState>,
+    railway_id: String,
+    prefix: String,
+    limit: u32,
+) -> Result<Vec<Livery>, CommandError> {
+    let repo = SqliteLiveryRepository::new(state.db_pool());
+    let use_case = SuggestLiveriesUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&railway_id, &prefix, limit)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to convert the speed of a model timed over a stretch of
+/// track into the speed of its prototype, for the utilities panel.
+///
+/// Parameters:
+/// - `distance`: the length of track the model ran.
+/// - `seconds`: how long the run took.
+///
+/// Returns:
+/// - `Ok(ScaleSpeed)` with the model and prototype speeds, and how long a
+///   run over 1 m takes at 100 scale km/h.
+/// - `Err(CommandError::InvalidField)` when `seconds` is not positive.
+#[tauri::command]
+#[specta::specta]
+pub fn calculate_scale_speed(
+    distance: Length,
+    seconds: Decimal,
+    scale: Scale,
+) -> Result<ScaleSpeed, CommandError> {
+    scale_speed::calculate_scale_speed(distance, seconds, &scale).map_err(|e| {
+        CommandError::InvalidField {
+            field: "seconds".to_string(),
+            message: e.to_string(),
+        }
+    })
+}
+
+/// Map a rejected page cursor, merge, livery, comparison or announcement
+/// batch to `CommandError::Validation`, anything else to `Unknown`.
+fn to_command_error(e: anyhow::Error) -> CommandError {
+    if e.downcast_ref::<InvalidCursor>().is_some()
+        || e.downcast_ref::<MergeError>().is_some()
+        || e.downcast_ref::<LiveryError>().is_some()
+        || e.downcast_ref::<ComparisonError>().is_some()
+        || e.downcast_ref::<AnnouncementError>().is_some()
+        || e.downcast_ref::<LookupError>().is_some()
+    {
+        CommandError::Validation(e.to_string())
+    } else {
+        CommandError::Unknown(e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_in_memory_db_pool;
+
+    #[tokio::test]
+    async fn invalid_cursors_map_to_validation_errors() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let use_case = ListRailwayModelsUseCase::new(Arc::new(SqliteCatalogRepository::new(pool)));
+
+        let err = use_case
+            .execute(Some("garbage"), 10)
+            .await
+            .map_err(to_command_error)
+            .unwrap_err();
+        assert!(matches!(err, CommandError::Validation(_)));
+    }
+}
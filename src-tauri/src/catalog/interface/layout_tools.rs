@@ -0,0 +1,76 @@
+//! Stateless Tauri commands exposing the layout planning calculators of
+//! `catalog::domain::layout`.
+//!
+//! Every command validates its inputs and maps a `LayoutCalcError` to
+//! `CommandError::Validation`; none of them touches the database.
+
+use crate::catalog::domain::Scale;
+use crate::catalog::domain::layout::{self, ClearanceCheck, LayoutCalcError};
+use crate::core::domain::length::Length;
+use crate::core::infrastructure::error::CommandError;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+
+/// Tauri command to compute the gradient of a track climbing `rise` over
+/// `run`, in any units.
+///
+/// Returns:
+/// - `Ok(f64)` with the gradient as a percentage.
+/// - `Err(CommandError::Validation)` when `run` is zero or a length is
+///   negative.
+#[tauri::command]
+#[specta::specta]
+pub fn calculate_gradient(rise: Length, run: Length) -> Result<f64, CommandError> {
+    layout::gradient_percent(rise, run)
+        .map(to_f64)
+        .map_err(to_command_error)
+}
+
+/// Tauri command to check that an overhead structure `clearance` above the
+/// rails lets the trains of `scale` through.
+///
+/// Returns:
+/// - `Ok(ClearanceCheck)` with the clearance, the minimum of the scale and
+///   whether the structure fits.
+/// - `Err(CommandError::Validation)` when `clearance` is negative.
+#[tauri::command]
+#[specta::specta]
+pub fn check_vertical_clearance(
+    clearance: Length,
+    scale: Scale,
+) -> Result<ClearanceCheck, CommandError> {
+    layout::check_vertical_clearance(clearance, &scale).map_err(to_command_error)
+}
+
+/// Tauri command to estimate how long a ladder of turnouts fanning out into
+/// `tracks` parallel tracks is, along the straight track.
+///
+/// Parameters:
+/// - `turnout_length`: the length of one turnout.
+/// - `frog_number`: the `n` of the `1:n` frog of the turnouts.
+/// - `track_spacing`: the distance between the centers of two tracks.
+///
+/// Returns:
+/// - `Ok(f64)` with the length of the ladder in millimeters.
+/// - `Err(CommandError::Validation)` for less than two tracks, a frog number
+///   that is not positive or a negative length.
+#[tauri::command]
+#[specta::specta]
+pub fn estimate_turnout_ladder_length(
+    tracks: u32,
+    turnout_length: Length,
+    frog_number: Decimal,
+    track_spacing: Length,
+) -> Result<f64, CommandError> {
+    layout::turnout_ladder_length(tracks, turnout_length, frog_number, track_spacing)
+        .map(to_f64)
+        .map_err(to_command_error)
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+fn to_command_error(e: LayoutCalcError) -> CommandError {
+    CommandError::Validation(e.to_string())
+}
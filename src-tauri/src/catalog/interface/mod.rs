@@ -0,0 +1,2 @@
+pub mod command_handlers;
+pub mod layout_tools;
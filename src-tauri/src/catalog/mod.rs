@@ -1,2 +1,4 @@
+pub mod application;
 pub mod domain;
 pub mod infrastructure;
+pub mod interface;
@@ -0,0 +1,93 @@
+use crate::collecting::domain::monetary_adjustment::MonetaryAdjustment;
+use crate::collecting::domain::recompute_diff::CollectionRecomputeDiff;
+use crate::collecting::domain::repository::CollectionRepository;
+use crate::core::domain::Currency;
+use anyhow::Result;
+use chrono::NaiveDate;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct AddMonetaryAdjustmentUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl AddMonetaryAdjustmentUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Record a signed adjustment of what was paid for the purchase with
+    /// `purchase_id`, then recompute the total value of its collection.
+    ///
+    /// Returns the stored adjustment and the recompute diff. An adjustment
+    /// that cannot be netted out of the purchase cost fails with an
+    /// `AdjustmentError` and changes nothing.
+    pub async fn execute(
+        &self,
+        purchase_id: &str,
+        amount: i64,
+        currency: Currency,
+        date: NaiveDate,
+        reason: &str,
+    ) -> Result<(MonetaryAdjustment, CollectionRecomputeDiff)> {
+        let adjustment = MonetaryAdjustment {
+            id: Uuid::new_v4().to_string(),
+            amount,
+            currency,
+            date,
+            reason: reason.trim().to_string(),
+        };
+
+        let collection_id = self
+            .repo
+            .add_monetary_adjustment(purchase_id, &adjustment)
+            .await?;
+        let diff = self.repo.recompute_collection(&collection_id).await?;
+
+        Ok((adjustment, diff))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::infrastructure::sqlite_repo::SqliteCollectionRepository;
+    use crate::collecting::infrastructure::testing::CollectingTestDb;
+    use pretty_assertions::assert_eq;
+    use sqlx::SqlitePool;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn adjustments_update_the_collection_total(pool: SqlitePool) -> Result<()> {
+        let catalog_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let collecting_data = CollectingTestDb::new(pool.clone())
+            .setup_minimal_collection(&catalog_data.railway_model_id, vec![])
+            .await?;
+        sqlx::query(
+            "UPDATE purchase_infos SET purchased_price_amount = 2500 WHERE purchase_id = ?1",
+        )
+        .bind(&collecting_data.purchase_info_id)
+        .execute(&pool)
+        .await?;
+        let use_case =
+            AddMonetaryAdjustmentUseCase::new(Arc::new(SqliteCollectionRepository::new(pool)));
+
+        let (adjustment, diff) = use_case
+            .execute(
+                &collecting_data.purchase_info_id,
+                -500,
+                Currency::EUR,
+                NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                "  damaged box ",
+            )
+            .await?;
+
+        assert_eq!(adjustment.reason, "damaged box");
+        assert_eq!(diff.total_values[0].currency, Currency::EUR);
+        assert_eq!(diff.total_values[0].new_amount, 2000);
+
+        Ok(())
+    }
+}
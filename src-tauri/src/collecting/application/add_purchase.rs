@@ -0,0 +1,136 @@
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::purchase_info::{NewPurchase, PurchasedInfo};
+use crate::collecting::domain::repository::CollectionRepository;
+use crate::settings::domain::repository::SettingsRepository;
+use anyhow::Result;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct AddPurchaseUseCase {
+    repo: Arc<dyn CollectionRepository>,
+    settings: Arc<dyn SettingsRepository>,
+}
+
+impl AddPurchaseUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>, settings: Arc<dyn SettingsRepository>) -> Self {
+        Self { repo, settings }
+    }
+
+    /// Record `purchase` for the collection item with `item_id`, in the
+    /// preferred currency of the settings when it has no currency.
+    pub async fn execute(
+        &self,
+        item_id: &CollectionItemId,
+        purchase: NewPurchase,
+    ) -> Result<PurchasedInfo> {
+        let default_currency = match purchase.currency {
+            Some(currency) => currency,
+            None => self.settings.get().await?.preferred_currency,
+        };
+        let purchase = purchase.into_purchased_info(Uuid::new_v4().to_string(), default_currency);
+        self.repo.add_purchase(item_id, &purchase).await?;
+        Ok(purchase)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::domain::purchase_info::PurchaseInfo;
+    use crate::collecting::infrastructure::sqlite_repo::SqliteCollectionRepository;
+    use crate::collecting::infrastructure::testing::CollectingTestDb;
+    use crate::core::domain::{Currency, MonetaryAmount};
+    use crate::settings::domain::app_settings::AppSettings;
+    use crate::settings::infrastructure::sqlite_repo::SqliteSettingsRepository;
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+    use sqlx::SqlitePool;
+
+    fn purchase(currency: Option<Currency>) -> NewPurchase {
+        NewPurchase {
+            purchase_date: NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(),
+            price: Some(12990),
+            currency,
+            seller: Some("Model shop".to_string()),
+        }
+    }
+
+    async fn setup(pool: &SqlitePool) -> Result<(AddPurchaseUseCase, Vec<CollectionItemId>)> {
+        let catalog_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection_id = collecting_db.insert_collection("My Collection").await?;
+        let mut item_ids = Vec::new();
+        for _ in 0..2 {
+            let id = collecting_db
+                .insert_collection_item(&collection_id, &catalog_data.railway_model_id)
+                .await?;
+            item_ids.push(CollectionItemId::try_from(id)?);
+        }
+
+        let settings = SqliteSettingsRepository::new(pool.clone());
+        settings
+            .update(&AppSettings {
+                preferred_currency: Currency::GBP,
+                ..AppSettings::default()
+            })
+            .await?;
+        let use_case = AddPurchaseUseCase::new(
+            Arc::new(SqliteCollectionRepository::new(pool.clone())),
+            Arc::new(settings),
+        );
+        Ok((use_case, item_ids))
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn purchases_without_a_currency_use_the_preferred_one(pool: SqlitePool) -> Result<()> {
+        let (use_case, item_ids) = setup(&pool).await?;
+
+        let in_default = use_case.execute(&item_ids[0], purchase(None)).await?;
+        let entered = use_case
+            .execute(&item_ids[1], purchase(Some(Currency::USD)))
+            .await?;
+
+        assert_eq!(
+            in_default.price,
+            Some(MonetaryAmount::new(12990, Currency::GBP))
+        );
+        assert_eq!(
+            entered.price,
+            Some(MonetaryAmount::new(12990, Currency::USD))
+        );
+
+        let collection = SqliteCollectionRepository::new(pool)
+            .get_collection()
+            .await?;
+        let stored: Vec<_> = collection
+            .items
+            .iter()
+            .map(|item| match &item.purchase_info {
+                Some(PurchaseInfo::Purchased(p)) => (p.id.clone(), p.price.clone()),
+                other => panic!("expected a purchase, got {other:?}"),
+            })
+            .collect();
+        assert!(stored.contains(&(in_default.id, in_default.price)));
+        assert!(stored.contains(&(entered.id, entered.price)));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn items_have_a_single_purchase(pool: SqlitePool) -> Result<()> {
+        let (use_case, item_ids) = setup(&pool).await?;
+        use_case.execute(&item_ids[0], purchase(None)).await?;
+
+        assert!(
+            use_case
+                .execute(&item_ids[0], purchase(None))
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::collecting::domain::decoder::DecoderWarning;
+use crate::collecting::domain::repository::DecoderRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct AttachDecoderUseCase {
+    repo: Arc<dyn DecoderRepository>,
+}
+
+impl AttachDecoderUseCase {
+    pub fn new(repo: Arc<dyn DecoderRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Install a decoder in an owned rolling stock, returning what does not
+    /// fit (see `DecoderRepository::attach_decoder`).
+    pub async fn execute(
+        &self,
+        owned_rolling_stock_id: &str,
+        decoder_id: &str,
+    ) -> Result<Vec<DecoderWarning>> {
+        self.repo
+            .attach_decoder(owned_rolling_stock_id, decoder_id)
+            .await
+    }
+}
@@ -0,0 +1,25 @@
+use crate::collecting::domain::bulk_update::{
+    BulkUpdateOptions, BulkUpdateReport, CollectionItemFilter, CollectionItemPatch,
+};
+use crate::collecting::domain::repository::CollectionRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct BulkUpdateItemsUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl BulkUpdateItemsUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        filter: &CollectionItemFilter,
+        patch: &CollectionItemPatch,
+        options: &BulkUpdateOptions,
+    ) -> Result<BulkUpdateReport> {
+        self.repo.bulk_update_items(filter, patch, options).await
+    }
+}
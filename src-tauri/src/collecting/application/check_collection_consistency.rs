@@ -0,0 +1,25 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::consistency::ConsistencyReport;
+use crate::collecting::domain::repository::CollectionRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct CheckCollectionConsistencyUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl CheckCollectionConsistencyUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        collection_id: &CollectionId,
+        repair: bool,
+    ) -> Result<ConsistencyReport> {
+        self.repo
+            .check_collection_consistency(collection_id, repair)
+            .await
+    }
+}
@@ -0,0 +1,156 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::display_currency::DisplayCurrencyListing;
+use crate::collecting::domain::repository::CollectionRepository;
+use crate::core::domain::Currency;
+use crate::exchange_rates::domain::repository::ExchangeRateRepository;
+use crate::settings::domain::repository::SettingsRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct ConvertDisplayCurrencyUseCase {
+    repo: Arc<dyn CollectionRepository>,
+    exchange_rates: Arc<dyn ExchangeRateRepository>,
+    settings: Arc<dyn SettingsRepository>,
+}
+
+impl ConvertDisplayCurrencyUseCase {
+    pub fn new(
+        repo: Arc<dyn CollectionRepository>,
+        exchange_rates: Arc<dyn ExchangeRateRepository>,
+        settings: Arc<dyn SettingsRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            exchange_rates,
+            settings,
+        }
+    }
+
+    /// The amounts of every item of the collection with `collection_id`,
+    /// converted into `display_currency` (the preferred currency of the
+    /// settings when `None`). Nothing stored is changed.
+    pub async fn execute(
+        &self,
+        collection_id: &CollectionId,
+        display_currency: Option<Currency>,
+    ) -> Result<DisplayCurrencyListing> {
+        let display_currency = match display_currency {
+            Some(currency) => currency,
+            None => self.settings.get().await?.preferred_currency,
+        };
+        let items = self.repo.list_stored_item_amounts(collection_id).await?;
+        let rates = self.exchange_rates.get_all().await?;
+        Ok(DisplayCurrencyListing::convert(
+            display_currency,
+            items,
+            &rates,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::infrastructure::sqlite_repo::SqliteCollectionRepository;
+    use crate::collecting::infrastructure::testing::CollectingTestDb;
+    use crate::core::domain::MonetaryAmount;
+    use crate::exchange_rates::infrastructure::sqlite_repo::SqliteExchangeRateRepository;
+    use crate::settings::domain::app_settings::AppSettings;
+    use crate::settings::infrastructure::sqlite_repo::SqliteSettingsRepository;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+    use sqlx::SqlitePool;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn amounts_are_converted_without_changing_what_is_stored(pool: SqlitePool) -> Result<()> {
+        let catalog_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let collecting_data = CollectingTestDb::new(pool.clone())
+            .setup_minimal_collection(&catalog_data.railway_model_id, Vec::new())
+            .await?;
+        sqlx::query("UPDATE purchase_infos SET purchased_price_amount = 10000, purchased_price_currency = 'GBP'")
+            .execute(&pool)
+            .await?;
+        sqlx::query("UPDATE collection_items SET replacement_value_amount = 5000, replacement_value_currency = 'USD'")
+            .execute(&pool)
+            .await?;
+        SqliteExchangeRateRepository::new(pool.clone())
+            .upsert(Currency::GBP, Currency::EUR, dec!(1.2))
+            .await?;
+        let settings = SqliteSettingsRepository::new(pool.clone());
+        settings
+            .update(&AppSettings {
+                preferred_currency: Currency::EUR,
+                ..AppSettings::default()
+            })
+            .await?;
+        let repo = Arc::new(SqliteCollectionRepository::new(pool.clone()));
+        let use_case = ConvertDisplayCurrencyUseCase::new(
+            repo.clone(),
+            Arc::new(SqliteExchangeRateRepository::new(pool.clone())),
+            Arc::new(settings),
+        );
+        let collection_id = CollectionId::try_from(collecting_data.collection_id.as_str())?;
+
+        let listing = use_case.execute(&collection_id, None).await?;
+
+        assert_eq!(listing.display_currency, Currency::EUR);
+        assert_eq!(listing.items.len(), 1);
+        let price = listing.items[0].price.clone().expect("a price");
+        assert_eq!(price.stored, MonetaryAmount::new(10000, Currency::GBP));
+        assert_eq!(
+            price.display,
+            Some(MonetaryAmount::new(12000, Currency::EUR))
+        );
+        let replacement_value = listing.items[0]
+            .replacement_value
+            .clone()
+            .expect("a replacement value");
+        assert_eq!(replacement_value.display, None);
+        assert_eq!(listing.missing_rates, vec![Currency::USD]);
+
+        let listing = use_case
+            .execute(&collection_id, Some(Currency::GBP))
+            .await?;
+        let price = listing.items[0].price.clone().expect("a price");
+        assert_eq!(
+            price.display,
+            Some(MonetaryAmount::new(10000, Currency::GBP))
+        );
+
+        let stored = repo.list_stored_item_amounts(&collection_id).await?;
+        assert_eq!(
+            stored[0].price,
+            Some(MonetaryAmount::new(10000, Currency::GBP))
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn preorders_are_listed_at_their_total_price(pool: SqlitePool) -> Result<()> {
+        let catalog_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let collecting_data = CollectingTestDb::new(pool.clone())
+            .setup_minimal_collection(&catalog_data.railway_model_id, Vec::new())
+            .await?;
+        sqlx::query("UPDATE purchase_infos SET purchase_type = 'preorder', purchased_price_amount = NULL, purchased_price_currency = NULL, deposit_amount = 5000, deposit_currency = 'EUR', preorder_total_amount = 32900, preorder_total_currency = 'EUR'")
+            .execute(&pool)
+            .await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection_id = CollectionId::try_from(collecting_data.collection_id.as_str())?;
+
+        let stored = repo.list_stored_item_amounts(&collection_id).await?;
+
+        assert_eq!(
+            stored[0].price,
+            Some(MonetaryAmount::new(32900, Currency::EUR))
+        );
+        assert_eq!(stored[0].replacement_value, None);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use crate::catalog::domain::radius::Radius;
+use crate::collecting::domain::consist::{ConsistDetails, ConsistPlan};
+use crate::collecting::domain::repository::ConsistRepository;
+use crate::core::domain::length::Length;
+use anyhow::{Result, anyhow};
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct CreateConsistUseCase {
+    repo: Arc<dyn ConsistRepository>,
+}
+
+impl CreateConsistUseCase {
+    pub fn new(repo: Arc<dyn ConsistRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Assemble a consist from owned rolling stocks, returning it checked
+    /// against a layout with a `longest_siding` and curves of the
+    /// `layout_radius`, when known.
+    ///
+    /// Invalid details fail with a `ConsistError`.
+    pub async fn execute(
+        &self,
+        details: ConsistDetails,
+        longest_siding: Option<Length>,
+        layout_radius: Option<Radius>,
+    ) -> Result<ConsistPlan> {
+        let details = details.validated()?;
+        let consist_id = Uuid::new_v4().to_string();
+        self.repo.create_consist(&consist_id, &details).await?;
+
+        let consist = self
+            .repo
+            .get_consist(&consist_id)
+            .await?
+            .ok_or_else(|| anyhow!("consist not found id={}", consist_id))?;
+        Ok(consist.plan(longest_siding, layout_radius))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::domain::consist::{ConsistError, ConsistWarning};
+    use crate::collecting::infrastructure::sqlite_consist_repo::SqliteConsistRepository;
+    use crate::collecting::infrastructure::testing::CollectingTestDb;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+    use sqlx::SqlitePool;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn consists_are_checked_against_the_layout(pool: SqlitePool) -> Result<()> {
+        let catalog_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        sqlx::query(
+            "UPDATE rolling_stocks SET length_millimeters = 210, technical_minimum_radius_mm = 515 WHERE id = ?1",
+        )
+        .bind(&catalog_data.rolling_stock_ids[0])
+        .execute(&pool)
+        .await?;
+        let collection = CollectingTestDb::new(pool.clone())
+            .setup_minimal_collection(
+                &catalog_data.railway_model_id,
+                catalog_data
+                    .rolling_stock_ids
+                    .iter()
+                    .map(String::as_str)
+                    .collect(),
+            )
+            .await?;
+        let use_case = CreateConsistUseCase::new(Arc::new(SqliteConsistRepository::new(pool)));
+
+        let plan = use_case
+            .execute(
+                ConsistDetails {
+                    name: " Regionale ".to_string(),
+                    owned_rolling_stock_ids: collection.owned_rolling_stock_ids.clone(),
+                },
+                Some(Length::Millimeters(dec!(200))),
+                Some(Radius::H0_R2),
+            )
+            .await?;
+
+        assert_eq!(plan.name, "Regionale");
+        assert_eq!(plan.total_length_mm, 210.0);
+        assert_eq!(plan.fits, Some(false));
+        assert_eq!(
+            plan.warnings,
+            vec![ConsistWarning::RadiusTooTight {
+                owned_rolling_stock_id: collection.owned_rolling_stock_ids[0].clone(),
+                minimum_radius_mm: 515.0,
+                layout_radius_mm: 437.5,
+            }]
+        );
+
+        let err = use_case
+            .execute(
+                ConsistDetails {
+                    name: " ".to_string(),
+                    owned_rolling_stock_ids: Vec::new(),
+                },
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ConsistError>(),
+            Some(&ConsistError::BlankName)
+        );
+
+        Ok(())
+    }
+}
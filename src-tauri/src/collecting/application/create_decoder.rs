@@ -0,0 +1,91 @@
+use crate::collecting::domain::decoder::{Decoder, DecoderDetails, SavedDecoder};
+use crate::collecting::domain::repository::DecoderRepository;
+use anyhow::Result;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub struct CreateDecoderUseCase {
+    repo: Arc<dyn DecoderRepository>,
+}
+
+impl CreateDecoderUseCase {
+    pub fn new(repo: Arc<dyn DecoderRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Add a decoder to the inventory, returning it with its generated id.
+    ///
+    /// An address outside the DCC range fails with a `DecoderError`; an
+    /// address other decoders already use is stored with a warning.
+    pub async fn execute(&self, details: DecoderDetails) -> Result<SavedDecoder> {
+        let decoder = Decoder::new(Uuid::new_v4().to_string(), details)?;
+        let with_same_address = self
+            .repo
+            .list_decoders_with_address(decoder.address)
+            .await?;
+        self.repo.create_decoder(&decoder).await?;
+
+        Ok(SavedDecoder {
+            warnings: decoder.address_warnings(&with_same_address),
+            decoder,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collecting::domain::decoder::{DecoderError, DecoderWarning};
+    use crate::collecting::infrastructure::sqlite_decoder_repo::SqliteDecoderRepository;
+    use pretty_assertions::assert_eq;
+    use sqlx::SqlitePool;
+
+    fn details(address: u16) -> DecoderDetails {
+        DecoderDetails {
+            manufacturer: "ESU".to_string(),
+            model: "LokSound 5".to_string(),
+            dcc_interface: None,
+            address,
+            sound_project: None,
+            firmware: None,
+            purchase_price: None,
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn duplicate_addresses_are_stored_with_a_warning(pool: SqlitePool) -> Result<()> {
+        let repo = Arc::new(SqliteDecoderRepository::new(pool.clone()));
+        let use_case = CreateDecoderUseCase::new(repo.clone());
+
+        let first = use_case.execute(details(3)).await?;
+        let second = use_case.execute(details(3)).await?;
+
+        assert_eq!(first.warnings, vec![]);
+        assert_eq!(
+            second.warnings,
+            vec![DecoderWarning::DuplicateAddress {
+                address: 3,
+                decoder_ids: vec![first.decoder.id],
+            }]
+        );
+        assert_eq!(repo.list_decoders().await?.len(), 2);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn addresses_outside_the_dcc_range_are_rejected(pool: SqlitePool) -> Result<()> {
+        let repo = Arc::new(SqliteDecoderRepository::new(pool.clone()));
+        let use_case = CreateDecoderUseCase::new(repo.clone());
+
+        let err = use_case.execute(details(10240)).await.unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<DecoderError>(),
+            Some(&DecoderError::AddressOutOfRange(10240))
+        );
+        assert!(repo.list_decoders().await?.is_empty());
+
+        Ok(())
+    }
+}
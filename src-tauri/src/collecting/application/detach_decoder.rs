@@ -0,0 +1,19 @@
+use crate::collecting::domain::repository::DecoderRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct DetachDecoderUseCase {
+    repo: Arc<dyn DecoderRepository>,
+}
+
+impl DetachDecoderUseCase {
+    pub fn new(repo: Arc<dyn DecoderRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Remove the decoder installed in an owned rolling stock, keeping it in
+    /// the inventory; returns its id, `None` when there was none.
+    pub async fn execute(&self, owned_rolling_stock_id: &str) -> Result<Option<String>> {
+        self.repo.detach_decoder(owned_rolling_stock_id).await
+    }
+}
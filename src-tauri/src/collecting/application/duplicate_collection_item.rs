@@ -0,0 +1,23 @@
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::item_duplication::DuplicateItemOptions;
+use crate::collecting::domain::repository::CollectionRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct DuplicateCollectionItemUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl DuplicateCollectionItemUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        item_id: &CollectionItemId,
+        options: &DuplicateItemOptions,
+    ) -> Result<CollectionItemId> {
+        self.repo.duplicate_collection_item(item_id, options).await
+    }
+}
@@ -0,0 +1,25 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::insurance_report::{InsuranceReport, ValuationMode};
+use crate::collecting::domain::repository::CollectionRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct ExportInsuranceReportUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl ExportInsuranceReportUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        collection_id: &CollectionId,
+        valuation: ValuationMode,
+    ) -> Result<InsuranceReport> {
+        self.repo
+            .get_insurance_report(collection_id, valuation)
+            .await
+    }
+}
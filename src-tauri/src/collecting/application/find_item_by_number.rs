@@ -0,0 +1,25 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::collection_item::CollectionItem;
+use crate::collecting::domain::repository::CollectionRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct FindItemByNumberUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl FindItemByNumberUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        collection_id: &CollectionId,
+        item_number: u32,
+    ) -> Result<Option<CollectionItem>> {
+        self.repo
+            .find_item_by_number(collection_id, item_number)
+            .await
+    }
+}
@@ -0,0 +1,19 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::repository::CollectionRepository;
+use crate::collecting::domain::year_review::YearReview;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct GenerateYearReviewUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl GenerateYearReviewUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, collection_id: &CollectionId, year: i32) -> Result<YearReview> {
+        self.repo.get_year_review(collection_id, year).await
+    }
+}
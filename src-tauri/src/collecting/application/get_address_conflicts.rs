@@ -0,0 +1,18 @@
+use crate::collecting::domain::decoder::AddressConflict;
+use crate::collecting::domain::repository::DecoderRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct GetAddressConflictsUseCase {
+    repo: Arc<dyn DecoderRepository>,
+}
+
+impl GetAddressConflictsUseCase {
+    pub fn new(repo: Arc<dyn DecoderRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<AddressConflict>> {
+        self.repo.find_address_conflicts().await
+    }
+}
@@ -0,0 +1,19 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::repository::CollectionRepository;
+use crate::collecting::domain::statistics::CollectionStatistics;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct GetCollectionStatisticsUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl GetCollectionStatisticsUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, collection_id: &CollectionId) -> Result<CollectionStatistics> {
+        self.repo.get_collection_statistics(collection_id).await
+    }
+}
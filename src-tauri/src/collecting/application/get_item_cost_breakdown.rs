@@ -0,0 +1,19 @@
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::cost_breakdown::ItemCostBreakdown;
+use crate::collecting::domain::repository::CollectionRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct GetItemCostBreakdownUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl GetItemCostBreakdownUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, item_id: &CollectionItemId) -> Result<ItemCostBreakdown> {
+        self.repo.get_item_cost_breakdown(item_id).await
+    }
+}
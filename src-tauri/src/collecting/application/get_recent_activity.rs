@@ -0,0 +1,18 @@
+use crate::collecting::domain::recent_activity::ActivityEvent;
+use crate::collecting::domain::repository::CollectionRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct GetRecentActivityUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl GetRecentActivityUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, limit: u32) -> Result<Vec<ActivityEvent>> {
+        self.repo.get_recent_activity(limit).await
+    }
+}
@@ -0,0 +1,25 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::repository::CollectionRepository;
+use crate::collecting::domain::value_snapshot::ValueSnapshot;
+use anyhow::Result;
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+pub struct GetValueHistoryUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl GetValueHistoryUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(
+        &self,
+        collection_id: &CollectionId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<ValueSnapshot>> {
+        self.repo.get_value_history(collection_id, from, to).await
+    }
+}
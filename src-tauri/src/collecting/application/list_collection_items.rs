@@ -0,0 +1,65 @@
+use crate::collecting::domain::collection_item::CollectionItem;
+use crate::collecting::domain::repository::CollectionRepository;
+use crate::core::domain::page::{MAX_PAGE_SIZE, Page, decode_cursor};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// The listing name carried by collection item cursors.
+pub const COLLECTION_ITEMS_LISTING: &str = "collection_items";
+
+pub struct ListCollectionItemsUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl ListCollectionItemsUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// List the collection items one page at a time, in the order they were
+    /// added.
+    ///
+    /// `cursor` is the `next_cursor` of the previous page, or `None` for the
+    /// first one; a cursor that was not returned by this listing fails with
+    /// `InvalidCursor`. `limit` is clamped to `1..=MAX_PAGE_SIZE`.
+    pub async fn execute(&self, cursor: Option<&str>, limit: u32) -> Result<Page<CollectionItem>> {
+        let after = cursor
+            .map(|cursor| decode_cursor(COLLECTION_ITEMS_LISTING, cursor))
+            .transpose()?;
+        let limit = limit.clamp(1, MAX_PAGE_SIZE);
+
+        Ok(self
+            .repo
+            .list_collection_items(after.as_ref(), limit)
+            .await?
+            .into_page(COLLECTION_ITEMS_LISTING))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::application::list_railway_models::RAILWAY_MODELS_LISTING;
+    use crate::collecting::infrastructure::sqlite_repo::SqliteCollectionRepository;
+    use crate::core::domain::page::{InvalidCursor, PageKey, encode_cursor};
+    use crate::db::init_in_memory_db_pool;
+
+    #[tokio::test]
+    async fn invalid_cursors_are_rejected() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let use_case =
+            ListCollectionItemsUseCase::new(Arc::new(SqliteCollectionRepository::new(pool)));
+        let foreign = encode_cursor(
+            RAILWAY_MODELS_LISTING,
+            &PageKey {
+                sort_key: "E656".to_string(),
+                id: "rm-1".to_string(),
+            },
+        );
+
+        for cursor in ["", "zz", foreign.as_str()] {
+            let err = use_case.execute(Some(cursor), 10).await.unwrap_err();
+            assert!(err.downcast_ref::<InvalidCursor>().is_some(), "{err}");
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::catalog::domain::radius::Radius;
+use crate::collecting::domain::consist::ConsistPlan;
+use crate::collecting::domain::repository::ConsistRepository;
+use crate::core::domain::length::Length;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct ListConsistsUseCase {
+    repo: Arc<dyn ConsistRepository>,
+}
+
+impl ListConsistsUseCase {
+    pub fn new(repo: Arc<dyn ConsistRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Every consist, checked against a layout with a `longest_siding` and
+    /// curves of the `layout_radius`, when known.
+    pub async fn execute(
+        &self,
+        longest_siding: Option<Length>,
+        layout_radius: Option<Radius>,
+    ) -> Result<Vec<ConsistPlan>> {
+        let consists = self.repo.list_consists().await?;
+        Ok(consists
+            .iter()
+            .map(|consist| consist.plan(longest_siding, layout_radius))
+            .collect())
+    }
+}
@@ -0,0 +1,18 @@
+use crate::collecting::domain::decoder::Decoder;
+use crate::collecting::domain::repository::DecoderRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct ListDecodersUseCase {
+    repo: Arc<dyn DecoderRepository>,
+}
+
+impl ListDecodersUseCase {
+    pub fn new(repo: Arc<dyn DecoderRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<Decoder>> {
+        self.repo.list_decoders().await
+    }
+}
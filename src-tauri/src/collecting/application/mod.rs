@@ -1 +1,28 @@
+pub mod add_monetary_adjustment;
+pub mod add_purchase;
+pub mod attach_decoder;
+pub mod bulk_update_items;
+pub mod check_collection_consistency;
+pub mod convert_display_currency;
+pub mod create_consist;
+pub mod create_decoder;
+pub mod detach_decoder;
+pub mod duplicate_collection_item;
+pub mod export_insurance_report;
+pub mod find_item_by_number;
+pub mod generate_year_review;
+pub mod get_address_conflicts;
 pub mod get_collection;
+pub mod get_collection_statistics;
+pub mod get_item_cost_breakdown;
+pub mod get_recent_activity;
+pub mod get_value_history;
+pub mod list_collection_items;
+pub mod list_consists;
+pub mod list_decoders;
+pub mod parse_quick_add;
+pub mod recompute_collection;
+pub mod record_item_view;
+pub mod take_value_snapshot;
+pub mod update_consist;
+pub mod update_decoder;
@@ -0,0 +1,115 @@
+use crate::catalog::domain::repository::CatalogRepository;
+use crate::collecting::domain::quick_add::{QuickAddMatch, QuickAddProposal, parse_quick_add};
+use anyhow::Result;
+use std::sync::Arc;
+
+/// How many catalog models are looked at for a quick-add entry.
+pub const MAX_CANDIDATES: u32 = 20;
+
+pub struct ParseQuickAddUseCase {
+    catalog: Arc<dyn CatalogRepository>,
+}
+
+impl ParseQuickAddUseCase {
+    pub fn new(catalog: Arc<dyn CatalogRepository>) -> Self {
+        Self { catalog }
+    }
+
+    /// Parse the quick-add `input` and look its model up in the catalog.
+    ///
+    /// Nothing is stored: the proposal is shown to the collector, who picks
+    /// the model when the match is ambiguous before the item is added.
+    pub async fn execute(&self, input: &str) -> Result<QuickAddProposal> {
+        let draft = parse_quick_add(input)?;
+        let found = self
+            .catalog
+            .search_railway_models(&draft.product_code, MAX_CANDIDATES)
+            .await?;
+        let model = QuickAddMatch::among(&draft, found);
+        Ok(QuickAddProposal { draft, model })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::domain::railway_model_id::RailwayModelId;
+    use crate::catalog::infrastructure::sqlite_repo::SqliteCatalogRepository;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::domain::quick_add::QuickAddError;
+    use crate::db::init_in_memory_db_pool;
+    use pretty_assertions::assert_eq;
+
+    fn product_codes(model: &QuickAddMatch) -> Vec<String> {
+        match model {
+            QuickAddMatch::Resolved { model } => vec![model.product_code.to_string()],
+            QuickAddMatch::Ambiguous { candidates } => candidates
+                .iter()
+                .map(|c| format!("{} {}", c.manufacturer, c.product_code))
+                .collect(),
+            QuickAddMatch::NotFound => Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn entries_are_matched_against_the_catalog() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let data = catalog_db.setup_railway_model().await.unwrap();
+        catalog_db
+            .insert_manufacturer("roco", "Roco")
+            .await
+            .unwrap();
+        for (manufacturer_id, product_code) in
+            [("roco", "E656"), (data.manufacturer_id.as_str(), "E656.2")]
+        {
+            catalog_db
+                .insert_railway_model(
+                    &RailwayModelId::new(),
+                    manufacturer_id,
+                    product_code,
+                    "Electric locomotive",
+                    "electric",
+                    "H0",
+                    "IV",
+                    "locomotive",
+                )
+                .await
+                .unwrap();
+        }
+        let use_case = ParseQuickAddUseCase::new(Arc::new(SqliteCatalogRepository::new(pool)));
+
+        let proposal = use_case.execute("acme e656 €129.90").await.unwrap();
+        assert!(matches!(proposal.model, QuickAddMatch::Resolved { .. }));
+        assert_eq!(product_codes(&proposal.model), vec!["E656"]);
+        assert_eq!(proposal.draft.price.map(|p| p.amount), Some(12990));
+
+        let proposal = use_case.execute("E656 2x").await.unwrap();
+        assert_eq!(
+            product_codes(&proposal.model),
+            vec!["ACME E656", "Roco E656"]
+        );
+
+        let proposal = use_case.execute("acme E65").await.unwrap();
+        assert_eq!(
+            product_codes(&proposal.model),
+            vec!["ACME E656", "ACME E656.2"]
+        );
+
+        let proposal = use_case.execute("Rivarossi E656").await.unwrap();
+        assert_eq!(proposal.model, QuickAddMatch::NotFound);
+    }
+
+    #[tokio::test]
+    async fn unparsable_entries_fail() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let use_case = ParseQuickAddUseCase::new(Arc::new(SqliteCatalogRepository::new(pool)));
+
+        let error = use_case.execute("2x €129.90").await.unwrap_err();
+
+        assert_eq!(
+            error.downcast_ref::<QuickAddError>(),
+            Some(&QuickAddError::MissingProductCode)
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::recompute_diff::CollectionRecomputeDiff;
+use crate::collecting::domain::repository::CollectionRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct RecomputeCollectionUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl RecomputeCollectionUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, collection_id: &CollectionId) -> Result<CollectionRecomputeDiff> {
+        self.repo.recompute_collection(collection_id).await
+    }
+}
@@ -0,0 +1,20 @@
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::repository::CollectionRepository;
+use anyhow::Result;
+use chrono::Utc;
+use std::sync::Arc;
+
+pub struct RecordItemViewUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl RecordItemViewUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Record that the collection item with `item_id` is viewed now.
+    pub async fn execute(&self, item_id: &CollectionItemId) -> Result<()> {
+        self.repo.record_item_view(item_id, Utc::now()).await
+    }
+}
@@ -0,0 +1,24 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::repository::CollectionRepository;
+use crate::collecting::domain::value_snapshot::ValueSnapshot;
+use anyhow::Result;
+use chrono::Local;
+use std::sync::Arc;
+
+pub struct TakeValueSnapshotUseCase {
+    repo: Arc<dyn CollectionRepository>,
+}
+
+impl TakeValueSnapshotUseCase {
+    pub fn new(repo: Arc<dyn CollectionRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Record the current value of the collection as today's snapshot (in
+    /// the local time zone), replacing one taken earlier today.
+    pub async fn execute(&self, collection_id: &CollectionId) -> Result<ValueSnapshot> {
+        self.repo
+            .take_value_snapshot(collection_id, Local::now().date_naive())
+            .await
+    }
+}
@@ -0,0 +1,36 @@
+use crate::catalog::domain::radius::Radius;
+use crate::collecting::domain::consist::{ConsistDetails, ConsistPlan};
+use crate::collecting::domain::repository::ConsistRepository;
+use crate::core::domain::length::Length;
+use anyhow::{Result, anyhow};
+use std::sync::Arc;
+
+pub struct UpdateConsistUseCase {
+    repo: Arc<dyn ConsistRepository>,
+}
+
+impl UpdateConsistUseCase {
+    pub fn new(repo: Arc<dyn ConsistRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Rename the consist with `consist_id` or change its vehicles,
+    /// returning it checked as by `CreateConsistUseCase`.
+    pub async fn execute(
+        &self,
+        consist_id: &str,
+        details: ConsistDetails,
+        longest_siding: Option<Length>,
+        layout_radius: Option<Radius>,
+    ) -> Result<ConsistPlan> {
+        let details = details.validated()?;
+        self.repo.update_consist(consist_id, &details).await?;
+
+        let consist = self
+            .repo
+            .get_consist(consist_id)
+            .await?
+            .ok_or_else(|| anyhow!("consist not found id={}", consist_id))?;
+        Ok(consist.plan(longest_siding, layout_radius))
+    }
+}
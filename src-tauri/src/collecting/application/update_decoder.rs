@@ -0,0 +1,32 @@
+use crate::collecting::domain::decoder::{Decoder, DecoderDetails, SavedDecoder};
+use crate::collecting::domain::repository::DecoderRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct UpdateDecoderUseCase {
+    repo: Arc<dyn DecoderRepository>,
+}
+
+impl UpdateDecoderUseCase {
+    pub fn new(repo: Arc<dyn DecoderRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Replace the details of the decoder with `decoder_id`, for example
+    /// after reprogramming its address.
+    ///
+    /// The address is checked as by `CreateDecoderUseCase`.
+    pub async fn execute(&self, decoder_id: &str, details: DecoderDetails) -> Result<SavedDecoder> {
+        let decoder = Decoder::new(decoder_id.to_string(), details)?;
+        let with_same_address = self
+            .repo
+            .list_decoders_with_address(decoder.address)
+            .await?;
+        self.repo.update_decoder(&decoder).await?;
+
+        Ok(SavedDecoder {
+            warnings: decoder.address_warnings(&with_same_address),
+            decoder,
+        })
+    }
+}
@@ -0,0 +1,217 @@
+//! Applying the same change to every collection item matching a filter.
+//!
+//! Only the fields a collector typically changes for many items at once can
+//! be patched: the condition and the storage location. A bulk update is
+//! capped at `BulkUpdateOptions::DEFAULT_LIMIT` changed items unless a higher
+//! limit is given explicitly, so a filter that is too broad is refused before
+//! anything is written.
+
+use crate::catalog::domain::category::Category;
+use crate::collecting::domain::collection_id::CollectionId;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The collection items a bulk update applies to; every criterion given
+/// must match, and an empty filter matches every item.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CollectionItemFilter {
+    /// Only the items of this collection.
+    pub collection_id: Option<CollectionId>,
+
+    /// Only the items whose railway model is from this manufacturer.
+    pub manufacturer_id: Option<String>,
+
+    /// Only the items whose railway model is in this category.
+    pub category: Option<Category>,
+
+    /// Only the items kept in this storage location (not in the locations
+    /// nested in it).
+    pub location_id: Option<String>,
+
+    /// Only the items with exactly this condition.
+    pub conditions: Option<String>,
+}
+
+/// The fields a bulk update sets; fields left `None` are not changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CollectionItemPatch {
+    /// The condition to record for the items (e.g. "mint", "used").
+    pub conditions: Option<String>,
+
+    /// Where the items are now kept.
+    pub location: Option<LocationUpdate>,
+}
+
+/// A change of the storage location of collection items.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LocationUpdate {
+    /// Move the items to the storage location with `location_id`.
+    MoveTo { location_id: String },
+
+    /// Forget where the items are kept.
+    Clear,
+}
+
+impl LocationUpdate {
+    /// The location id to store.
+    pub fn location_id(&self) -> Option<&str> {
+        match self {
+            LocationUpdate::MoveTo { location_id } => Some(location_id),
+            LocationUpdate::Clear => None,
+        }
+    }
+}
+
+impl CollectionItemPatch {
+    /// The patch with a blank condition treated as missing, failing with
+    /// `BulkUpdateError::EmptyPatch` when it would change nothing.
+    pub fn normalized(self) -> Result<Self, BulkUpdateError> {
+        let conditions = self
+            .conditions
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty());
+        if conditions.is_none() && self.location.is_none() {
+            return Err(BulkUpdateError::EmptyPatch);
+        }
+        Ok(Self {
+            conditions,
+            location: self.location,
+        })
+    }
+
+    /// Whether applying the patch changes an item with `conditions` kept in
+    /// `location_id`.
+    pub fn changes(&self, conditions: Option<&str>, location_id: Option<&str>) -> bool {
+        let conditions_change = self
+            .conditions
+            .as_deref()
+            .is_some_and(|c| Some(c) != conditions);
+        let location_change = self
+            .location
+            .as_ref()
+            .is_some_and(|l| l.location_id() != location_id);
+        conditions_change || location_change
+    }
+}
+
+/// How a bulk update is run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct BulkUpdateOptions {
+    /// Count the items that would change without writing anything.
+    pub dry_run: bool,
+
+    /// The most items the update may change, overriding `DEFAULT_LIMIT`.
+    pub limit: Option<u32>,
+}
+
+impl BulkUpdateOptions {
+    /// The most items a bulk update changes unless a limit is given.
+    pub const DEFAULT_LIMIT: u32 = 100;
+
+    /// The most items the update may change.
+    pub fn effective_limit(&self) -> u32 {
+        self.limit.unwrap_or(Self::DEFAULT_LIMIT)
+    }
+
+    /// Check that changing `changed` items stays within the limit. A dry run
+    /// is never refused, so the count can be shown before confirming.
+    pub fn check_limit(&self, changed: u32) -> Result<(), BulkUpdateError> {
+        let limit = self.effective_limit();
+        if !self.dry_run && changed > limit {
+            return Err(BulkUpdateError::LimitExceeded { changed, limit });
+        }
+        Ok(())
+    }
+}
+
+/// What a bulk update did, or would do for a dry run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct BulkUpdateReport {
+    /// How many items match the filter.
+    pub matched: u32,
+
+    /// How many of them were changed (or would be, for a dry run); items
+    /// that already have the patched values are left untouched.
+    pub changed: u32,
+
+    /// The most items the update may change.
+    pub limit: u32,
+
+    /// Whether nothing was written.
+    pub dry_run: bool,
+}
+
+/// Why a bulk update was refused.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BulkUpdateError {
+    /// The patch does not set any field.
+    #[error("the bulk update does not change any field")]
+    EmptyPatch,
+
+    /// More items would change than the limit allows.
+    #[error("the bulk update would change {changed} items, more than the limit of {limit}")]
+    LimitExceeded { changed: u32, limit: u32 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn patches_without_fields_are_rejected() {
+        let patch = CollectionItemPatch {
+            conditions: Some("  ".to_string()),
+            location: None,
+        };
+
+        assert_eq!(patch.normalized(), Err(BulkUpdateError::EmptyPatch));
+    }
+
+    #[test]
+    fn items_with_the_patched_values_do_not_change() {
+        let patch = CollectionItemPatch {
+            conditions: Some(" mint ".to_string()),
+            location: Some(LocationUpdate::MoveTo {
+                location_id: "shelf-1".to_string(),
+            }),
+        }
+        .normalized()
+        .unwrap();
+
+        assert!(!patch.changes(Some("mint"), Some("shelf-1")));
+        assert!(patch.changes(Some("used"), Some("shelf-1")));
+        assert!(patch.changes(Some("mint"), None));
+    }
+
+    #[test]
+    fn the_limit_applies_unless_dry_run() {
+        let options = BulkUpdateOptions::default();
+        let over = BulkUpdateOptions::DEFAULT_LIMIT + 1;
+
+        assert_eq!(
+            options.check_limit(over),
+            Err(BulkUpdateError::LimitExceeded {
+                changed: over,
+                limit: BulkUpdateOptions::DEFAULT_LIMIT,
+            })
+        );
+        assert_eq!(
+            BulkUpdateOptions {
+                dry_run: true,
+                limit: None,
+            }
+            .check_limit(over),
+            Ok(())
+        );
+        assert_eq!(
+            BulkUpdateOptions {
+                dry_run: false,
+                limit: Some(over),
+            }
+            .check_limit(over),
+            Ok(())
+        );
+    }
+}
@@ -2,10 +2,16 @@ use crate::collecting::domain::collection_id::CollectionId;
 use crate::collecting::domain::collection_item::CollectionItem;
 use crate::collecting::domain::summary::CollectionSummary;
 use crate::core::domain::MonetaryAmount;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+/// The id of the default collection, as stored; `CollectionId::DEFAULT` is
+/// the same id.
 pub const DEFAULT_COLLECTION_ID: &str = "052cb8be-cc5c-460d-b72c-6cec595b91d7";
 
+/// The name given to the default collection.
+pub const DEFAULT_COLLECTION_NAME: &str = "My Collection";
+
 /// Represents a user-owned collection of items.
 ///
 /// A `Collection` contains identifying information, a few aggregated summary
@@ -15,8 +21,8 @@ pub const DEFAULT_COLLECTION_ID: &str = "052cb8be-cc5c-460d-b72c-6cec595b91d7";
 /// Default behaviour:
 /// - `Collection::default()` returns an empty collection with a generated id,
 ///   the name "My Collection", a `CollectionSummary::default()` and no
-///   `total_value` (i.e. `None`). This mirrors previous code paths that
-///   returned a default when no database row existed.
+///   `total_value` (i.e. `None`), created and updated now. This mirrors
+///   previous code paths that returned a default when no database row existed.
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
 pub struct Collection {
     /// Unique identifier for the collection (typically a UUID stored as a string).
@@ -34,6 +40,17 @@ pub struct Collection {
 
     /// The list of items contained in this collection.
     pub items: Vec<CollectionItem>,
+
+    /// Problems found while loading the collection, one per stored row that
+    /// could not be read (for example a purchase date in an unrecognized
+    /// format). The affected items are still listed, without the bad data.
+    pub load_warnings: Vec<String>,
+
+    /// When the collection was created (UTC).
+    pub created_at: DateTime<Utc>,
+
+    /// When the collection was last updated (UTC).
+    pub updated_at: DateTime<Utc>,
 }
 
 impl Default for Collection {
@@ -41,11 +58,14 @@ impl Default for Collection {
     /// that expect a default when no collection is present in the database.
     fn default() -> Self {
         Collection {
-            id: CollectionId::try_from(DEFAULT_COLLECTION_ID).expect("Invalid collection ID"),
-            name: "My Collection".to_string(),
+            id: CollectionId::DEFAULT,
+            name: DEFAULT_COLLECTION_NAME.to_string(),
             summary: CollectionSummary::default(),
             total_value: None,
             items: Vec::new(),
+            load_warnings: Vec::new(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
         }
     }
 }
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
-use uuid::Uuid;
+use std::str::FromStr;
+use uuid::{Uuid, uuid};
 
 /// Identifier for a collection.
 ///
@@ -9,13 +10,18 @@ use uuid::Uuid;
 /// a valid UUID representation (for example `"550e8400-e29b-41d4-a716-446655440000"`).
 ///
 /// # Requirements
-/// - `TryFrom<&str>` / `TryFrom<String>` will return an error if the provided
-///   string is not a valid UUID.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+/// - `TryFrom<&str>` / `TryFrom<String>` / `FromStr` will return an error if
+///   the provided string is not a valid UUID.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, specta::Type)]
 #[serde(transparent)]
 #[specta(transparent)]
 pub struct CollectionId(pub Uuid);
 
+impl CollectionId {
+    /// The id of the default collection (see `DEFAULT_COLLECTION_ID`).
+    pub const DEFAULT: CollectionId = CollectionId(uuid!("052cb8be-cc5c-460d-b72c-6cec595b91d7"));
+}
+
 /// Errors that can occur when creating a `CollectionId` from a string.
 #[derive(Debug, thiserror::Error, PartialEq, Eq)]
 pub enum CollectionIdError {
@@ -54,6 +60,15 @@ impl TryFrom<String> for CollectionId {
     }
 }
 
+impl FromStr for CollectionId {
+    type Err = CollectionIdError;
+
+    /// Parse a `CollectionId` (see `TryFrom<&str>`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CollectionId::try_from(s)
+    }
+}
+
 impl From<Uuid> for CollectionId {
     fn from(u: Uuid) -> Self {
         CollectionId(u)
@@ -76,6 +91,7 @@ impl fmt::Display for CollectionId {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::collecting::domain::collection::DEFAULT_COLLECTION_ID;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -96,6 +112,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_matches_try_from() {
+        let u = Uuid::new_v4();
+        let id: CollectionId = u.to_string().parse().expect("should parse uuid");
+        assert_eq!(id, CollectionId::from(u));
+        assert_eq!(
+            "not-a-uuid".parse::<CollectionId>(),
+            Err(CollectionIdError::InvalidUuid("not-a-uuid".to_string()))
+        );
+    }
+
+    #[test]
+    fn default_constant_is_the_default_collection() {
+        assert_eq!(CollectionId::DEFAULT.to_string(), DEFAULT_COLLECTION_ID);
+        assert_ne!(CollectionId::default(), CollectionId::DEFAULT);
+    }
+
     #[test]
     fn from_uuid_and_display() {
         let u = Uuid::new_v4();
@@ -119,5 +152,9 @@ mod tests {
         let a = CollectionId::default();
         let b = CollectionId::default();
         assert_ne!(a, b, "Two generated UUIDs should not be equal");
+
+        let ids: std::collections::HashSet<CollectionId> =
+            (0..100).map(|_| CollectionId::default()).collect();
+        assert_eq!(ids.len(), 100);
     }
 }
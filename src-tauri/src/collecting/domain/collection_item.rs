@@ -1,6 +1,15 @@
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::domain::railway_model_summary::RailwayModelSummary;
 use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::cost_breakdown::{ItemCostBreakdown, ItemCostInputs};
+use crate::collecting::domain::discount;
+use crate::collecting::domain::modification::Modification;
+use crate::collecting::domain::monetary_adjustment::{AdjustmentError, MonetaryAdjustment};
 use crate::collecting::domain::owned_rolling_stock::OwnedRollingStock;
 use crate::collecting::domain::purchase_info::PurchaseInfo;
+use crate::core::domain::MonetaryAmount;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// A single item within a user's collection.
@@ -15,11 +24,20 @@ pub struct CollectionItem {
     /// Unique identifier for this collection item (e.g. UUID).
     pub id: CollectionItemId,
 
+    /// The short number of the item within its collection, for referring to
+    /// it out loud; numbers follow the order items were added and are never
+    /// reused after a deletion.
+    pub item_number: u32,
+
     /// Link to the corresponding catalog `RailwayModel` this item represents.
     ///
     /// This is a reference to the canonical model in the catalog; use this
     /// to look up full catalog details (manufacturer, product codes, etc.).
-    pub railway_model_id: String,
+    pub railway_model_id: RailwayModelId,
+
+    /// The manufacturer, product code and description of the railway model,
+    /// or `None` when it is missing from the catalog.
+    pub railway_model: Option<RailwayModelSummary>,
 
     /// Condition of the item as recorded by the owner (e.g. "mint", "used").
     pub conditions: Option<String>,
@@ -30,6 +48,220 @@ pub struct CollectionItem {
     /// The specific rolling stock instances owned that correspond to this model.
     pub rolling_stocks: Vec<OwnedRollingStock>,
 
+    /// The storage location the item is kept in (see `StorageLocation`), or
+    /// `None` when it was not recorded.
+    pub location_id: Option<String>,
+
+    /// What replacing the item would cost today, as entered by the collector
+    /// for insurance purposes, or `None` when it was not entered.
+    pub replacement_value: Option<MonetaryAmount>,
+
+    /// Whether the item is lent out (see `Loan`).
+    pub is_on_loan: bool,
+
     /// Optional purchase information associated with this collection item.
     pub purchase_info: Option<PurchaseInfo>,
+
+    /// Why the stored purchase information could not be loaded, when it is
+    /// present but invalid (for example an unknown purchase type). In that
+    /// case `purchase_info` is `None`.
+    pub purchase_info_error: Option<String>,
+
+    /// The discount on the railway model's suggested retail price of the
+    /// price paid, as a percentage (see `CollectionItem::discount_percentage`).
+    pub discount_percentage: Option<Decimal>,
+
+    /// When the item was added to the collection (UTC), or `None` for items
+    /// added before timestamps were recorded.
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// When the item was last updated (UTC), or `None` for items added
+    /// before timestamps were recorded.
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl CollectionItem {
+    /// The discount on `msrp`, the suggested retail price of the item's
+    /// railway model, of the price paid for it.
+    ///
+    /// `None` when the item was not paid for yet, its price or the MSRP is
+    /// unknown, or they are in different currencies (see
+    /// `discount::discount_percentage`).
+    pub fn discount_percentage(&self, msrp: Option<&MonetaryAmount>) -> Option<Decimal> {
+        let price = self.purchase_info.as_ref()?.purchase_price()?;
+        discount::discount_percentage(price, msrp?)
+    }
+
+    /// What owning this item cost: its acquisition cost net of
+    /// `adjustments`, plus the costs of the `modifications` made to its
+    /// rolling stocks. Pass no modifications for the acquisition cost alone;
+    /// modifications with an unknown cost are left out.
+    ///
+    /// `None` when the item has no purchase info, or its price is unknown
+    /// and there is nothing to add.
+    ///
+    /// # Errors
+    ///
+    /// As `PurchaseInfo::acquisition_cost`, with each modification cost
+    /// counted as a positive adjustment: it must be in the purchase
+    /// currency.
+    pub fn total_cost_of_ownership(
+        &self,
+        adjustments: &[MonetaryAdjustment],
+        modifications: &[Modification],
+    ) -> Result<Option<MonetaryAmount>, AdjustmentError> {
+        let Some(purchase_info) = &self.purchase_info else {
+            return Ok(None);
+        };
+        let mut costs = adjustments.to_vec();
+        for modification in modifications {
+            costs.extend(modification.as_adjustment()?);
+        }
+        purchase_info.acquisition_cost(&costs)
+    }
+
+    /// What owning this item cost, per currency: its acquisition cost net
+    /// of `related.adjustments`, plus the prices of `related.decoders` and
+    /// the costs of `related.modifications`. Decoders and modifications
+    /// with an unknown price are left out; see `ItemCostBreakdown` for the
+    /// single costs.
+    ///
+    /// # Errors
+    ///
+    /// As `ItemCostBreakdown::build`.
+    pub fn total_cost(
+        &self,
+        related: &ItemCostInputs,
+    ) -> Result<Vec<MonetaryAmount>, AdjustmentError> {
+        ItemCostBreakdown::build(self.id.clone(), self.purchase_info.as_ref(), related)
+            .map(|breakdown| breakdown.totals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collecting::domain::modification::ModificationType;
+    use crate::collecting::domain::purchase_info::PurchasedInfo;
+    use crate::core::domain::Currency;
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()
+    }
+
+    fn item(price: u64) -> CollectionItem {
+        CollectionItem {
+            id: CollectionItemId::default(),
+            item_number: 1,
+            railway_model_id: RailwayModelId::new(),
+            railway_model: None,
+            conditions: None,
+            notes: None,
+            rolling_stocks: Vec::new(),
+            location_id: None,
+            replacement_value: None,
+            is_on_loan: false,
+            purchase_info: Some(PurchaseInfo::Purchased(PurchasedInfo {
+                id: "pi-1".to_string(),
+                purchase_date: date(),
+                price: Some(MonetaryAmount::new(price, Currency::EUR)),
+                seller: None,
+            })),
+            purchase_info_error: None,
+            discount_percentage: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    fn modification(cost: Option<MonetaryAmount>) -> Modification {
+        Modification {
+            id: "mod-1".to_string(),
+            modification_type: ModificationType::Weathering,
+            description: "weathered".to_string(),
+            date: date(),
+            cost,
+        }
+    }
+
+    fn refund(amount: i64) -> MonetaryAdjustment {
+        MonetaryAdjustment {
+            id: "adj-1".to_string(),
+            amount,
+            currency: Currency::EUR,
+            date: date(),
+            reason: "refund".to_string(),
+        }
+    }
+
+    #[test]
+    fn the_discount_needs_an_msrp_in_the_purchase_currency() {
+        let item = item(20_500);
+
+        assert_eq!(
+            item.discount_percentage(Some(&MonetaryAmount::new(25_000, Currency::EUR))),
+            Some(dec!(18))
+        );
+        assert_eq!(
+            item.discount_percentage(Some(&MonetaryAmount::new(25_000, Currency::USD))),
+            None
+        );
+        assert_eq!(item.discount_percentage(None), None);
+    }
+
+    #[test]
+    fn modification_costs_add_to_the_cost_of_ownership() {
+        let item = item(10_000);
+        let modifications = [
+            modification(Some(MonetaryAmount::new(1_500, Currency::EUR))),
+            modification(None),
+        ];
+
+        let total = item.total_cost_of_ownership(&[refund(-500)], &modifications);
+        assert_eq!(total.unwrap().map(|c| c.amount), Some(10_000 - 500 + 1_500));
+        let acquisition = item.total_cost_of_ownership(&[refund(-500)], &[]);
+        assert_eq!(acquisition.unwrap().map(|c| c.amount), Some(9_500));
+    }
+
+    #[test]
+    fn modification_costs_must_be_in_the_purchase_currency() {
+        let item = item(10_000);
+        let modifications = [modification(Some(MonetaryAmount::new(
+            1_500,
+            Currency::USD,
+        )))];
+
+        assert_eq!(
+            item.total_cost_of_ownership(&[], &modifications)
+                .unwrap_err(),
+            AdjustmentError::CurrencyMismatch {
+                purchase: Currency::EUR,
+                adjustment: Currency::USD,
+            }
+        );
+    }
+
+    #[test]
+    fn the_total_cost_is_given_per_currency() {
+        let item = item(10_000);
+        let related = ItemCostInputs {
+            adjustments: vec![refund(-500)],
+            modifications: vec![
+                modification(Some(MonetaryAmount::new(1_500, Currency::EUR))),
+                modification(Some(MonetaryAmount::new(2_000, Currency::USD))),
+            ],
+            ..ItemCostInputs::default()
+        };
+
+        assert_eq!(
+            item.total_cost(&related).unwrap(),
+            vec![
+                MonetaryAmount::new(10_000 - 500 + 1_500, Currency::EUR),
+                MonetaryAmount::new(2_000, Currency::USD),
+            ]
+        );
+    }
 }
@@ -0,0 +1,322 @@
+use crate::catalog::domain::length_over_buffers::LengthOverBuffers;
+use crate::catalog::domain::radius::Radius;
+use crate::core::domain::length::Length;
+use crate::core::domain::measure_units::MeasureUnit;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A train assembled from owned rolling stocks, to check it against the
+/// layout before running it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Consist {
+    /// Unique identifier for this consist.
+    pub id: String,
+
+    /// The collector's name for the train, for example "Freccia d'Argento".
+    pub name: String,
+
+    /// The vehicles of the train, from the head of the train to its tail.
+    pub vehicles: Vec<ConsistVehicle>,
+}
+
+/// An owned rolling stock in a consist, with the catalog data the consist
+/// checks need.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsistVehicle {
+    /// The owned rolling stock.
+    pub owned_rolling_stock_id: String,
+
+    /// The length over buffers of its catalog rolling stock, when known.
+    pub length_over_buffers: Option<LengthOverBuffers>,
+
+    /// The minimum radius its catalog rolling stock can run on, when known.
+    pub minimum_radius: Option<Radius>,
+}
+
+/// The fields of a consist the collector enters, to create or update one.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct ConsistDetails {
+    /// The name of the train.
+    pub name: String,
+
+    /// The owned rolling stocks making up the train, from head to tail.
+    pub owned_rolling_stock_ids: Vec<String>,
+}
+
+impl ConsistDetails {
+    /// These details with the name trimmed, checked before storing them.
+    ///
+    /// Fails when the name is blank or a rolling stock appears twice.
+    pub fn validated(self) -> Result<Self, ConsistError> {
+        let name = self.name.trim().to_string();
+        if name.is_empty() {
+            return Err(ConsistError::BlankName);
+        }
+        for (i, id) in self.owned_rolling_stock_ids.iter().enumerate() {
+            if self.owned_rolling_stock_ids[..i].contains(id) {
+                return Err(ConsistError::DuplicateVehicle(id.clone()));
+            }
+        }
+        Ok(Self {
+            name,
+            owned_rolling_stock_ids: self.owned_rolling_stock_ids,
+        })
+    }
+}
+
+impl ConsistVehicle {
+    /// The length over buffers of this vehicle, in the unit it was recorded
+    /// in (millimeters when both are known).
+    fn length(&self) -> Option<Length> {
+        let length = self.length_over_buffers.as_ref()?;
+        length.millimeters().or(length.inches()).copied()
+    }
+}
+
+impl Consist {
+    /// The length of the train in `unit`: the sum of the lengths over
+    /// buffers of its vehicles, whatever unit each one was recorded in.
+    ///
+    /// Vehicles without a known length are left out; see `warnings`.
+    pub fn total_length(&self, unit: MeasureUnit) -> Length {
+        let millimeters: Decimal = self
+            .vehicles
+            .iter()
+            .filter_map(ConsistVehicle::length)
+            .map(|length| length.as_millimeters())
+            .sum();
+        Length::new(millimeters / unit.to_millimeters(Decimal::ONE), unit)
+    }
+
+    /// Whether the train fits on a siding of `siding` length.
+    pub fn fits_on(&self, siding: Length) -> bool {
+        self.total_length(MeasureUnit::Millimeters) <= siding
+    }
+
+    /// What makes the checks of this consist unreliable or the train unfit
+    /// for a layout whose tightest curve has the `layout_radius`.
+    pub fn warnings(&self, layout_radius: Option<Radius>) -> Vec<ConsistWarning> {
+        let mut warnings = Vec::new();
+        for vehicle in &self.vehicles {
+            if vehicle.length().is_none() {
+                warnings.push(ConsistWarning::MissingLength {
+                    owned_rolling_stock_id: vehicle.owned_rolling_stock_id.clone(),
+                });
+            }
+            if let (Some(minimum_radius), Some(layout_radius)) =
+                (vehicle.minimum_radius, layout_radius)
+                && !minimum_radius.fits_on(layout_radius)
+            {
+                warnings.push(ConsistWarning::RadiusTooTight {
+                    owned_rolling_stock_id: vehicle.owned_rolling_stock_id.clone(),
+                    minimum_radius_mm: to_f64(minimum_radius.as_millimeters()),
+                    layout_radius_mm: to_f64(layout_radius.as_millimeters()),
+                });
+            }
+        }
+        warnings
+    }
+
+    /// This consist checked against a layout with a `longest_siding` and
+    /// curves of the `layout_radius`, when known.
+    pub fn plan(
+        &self,
+        longest_siding: Option<Length>,
+        layout_radius: Option<Radius>,
+    ) -> ConsistPlan {
+        ConsistPlan {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            owned_rolling_stock_ids: self
+                .vehicles
+                .iter()
+                .map(|v| v.owned_rolling_stock_id.clone())
+                .collect(),
+            total_length_mm: to_f64(self.total_length(MeasureUnit::Millimeters).quantity()),
+            fits: longest_siding.map(|siding| self.fits_on(siding)),
+            warnings: self.warnings(layout_radius),
+        }
+    }
+}
+
+fn to_f64(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+/// A consist with the outcome of its checks, as shown by the planner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct ConsistPlan {
+    /// Unique identifier for the consist.
+    pub id: String,
+
+    /// The name of the train.
+    pub name: String,
+
+    /// The owned rolling stocks making up the train, from head to tail.
+    pub owned_rolling_stock_ids: Vec<String>,
+
+    /// The length of the train in millimeters, without the vehicles whose
+    /// length is unknown.
+    pub total_length_mm: f64,
+
+    /// Whether the train fits on the longest siding, or `None` when no
+    /// siding length was given.
+    pub fits: Option<bool>,
+
+    /// What makes the checks unreliable or the train unfit for the layout;
+    /// empty when nothing.
+    pub warnings: Vec<ConsistWarning>,
+}
+
+/// A problem found while checking a consist.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConsistWarning {
+    /// The catalog has no length over buffers for the vehicle, so the train
+    /// is longer than its total length.
+    MissingLength { owned_rolling_stock_id: String },
+
+    /// The vehicle needs wider curves than the tightest ones of the layout.
+    RadiusTooTight {
+        owned_rolling_stock_id: String,
+        minimum_radius_mm: f64,
+        layout_radius_mm: f64,
+    },
+}
+
+/// Why a consist cannot be stored.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConsistError {
+    /// A consist needs a name.
+    #[error("the consist name must not be blank")]
+    BlankName,
+
+    /// A rolling stock can appear only once in a consist.
+    #[error("owned rolling stock {0} appears more than once in the consist")]
+    DuplicateVehicle(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    fn vehicle(
+        id: &str,
+        length: Option<LengthOverBuffers>,
+        radius: Option<Radius>,
+    ) -> ConsistVehicle {
+        ConsistVehicle {
+            owned_rolling_stock_id: id.to_string(),
+            length_over_buffers: length,
+            minimum_radius: radius,
+        }
+    }
+
+    fn consist(vehicles: Vec<ConsistVehicle>) -> Consist {
+        Consist {
+            id: "consist-1".to_string(),
+            name: "Freccia d'Argento".to_string(),
+            vehicles,
+        }
+    }
+
+    #[test]
+    fn lengths_in_different_units_are_summed() {
+        let consist = consist(vec![
+            vehicle(
+                "ors-1",
+                Some(LengthOverBuffers::new(None, Some(dec!(210))).unwrap()),
+                None,
+            ),
+            vehicle(
+                "ors-2",
+                Some(LengthOverBuffers::new(Some(dec!(10)), None).unwrap()),
+                None,
+            ),
+        ]);
+
+        assert_eq!(
+            consist.total_length(MeasureUnit::Millimeters),
+            Length::Millimeters(dec!(464))
+        );
+        assert_eq!(
+            consist.total_length(MeasureUnit::Meters),
+            Length::Meters(dec!(0.464))
+        );
+        assert!(consist.fits_on(Length::Meters(dec!(0.5))));
+        assert!(!consist.fits_on(Length::Inches(dec!(18))));
+        assert!(consist.warnings(None).is_empty());
+    }
+
+    #[test]
+    fn vehicles_without_a_length_are_reported() {
+        let consist = consist(vec![
+            vehicle(
+                "ors-1",
+                Some(LengthOverBuffers::new(None, Some(dec!(210))).unwrap()),
+                None,
+            ),
+            vehicle("ors-2", None, None),
+        ]);
+
+        assert_eq!(
+            consist.total_length(MeasureUnit::Millimeters),
+            Length::Millimeters(dec!(210))
+        );
+        assert_eq!(
+            consist.warnings(None),
+            vec![ConsistWarning::MissingLength {
+                owned_rolling_stock_id: "ors-2".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn vehicles_needing_wider_curves_than_the_layout_are_reported() {
+        let length = LengthOverBuffers::new(None, Some(dec!(210))).ok();
+        let consist = consist(vec![
+            vehicle("ors-1", length, Some(Radius::H0_R1)),
+            vehicle("ors-2", length, Some(Radius::H0_R3)),
+        ]);
+
+        assert_eq!(
+            consist.warnings(Some(Radius::H0_R2)),
+            vec![ConsistWarning::RadiusTooTight {
+                owned_rolling_stock_id: "ors-2".to_string(),
+                minimum_radius_mm: 515.0,
+                layout_radius_mm: 437.5,
+            }]
+        );
+        let plan = consist.plan(Some(Length::Millimeters(dec!(400))), None);
+        assert_eq!(plan.total_length_mm, 420.0);
+        assert_eq!(plan.fits, Some(false));
+        assert!(plan.warnings.is_empty());
+    }
+
+    #[test]
+    fn details_need_a_name_and_distinct_vehicles() {
+        let details = |name: &str, ids: &[&str]| ConsistDetails {
+            name: name.to_string(),
+            owned_rolling_stock_ids: ids.iter().map(|id| id.to_string()).collect(),
+        };
+
+        assert_eq!(
+            details(" Train ", &["ors-1"]).validated().unwrap().name,
+            "Train"
+        );
+        assert_eq!(
+            details("  ", &[]).validated().unwrap_err(),
+            ConsistError::BlankName
+        );
+        assert_eq!(
+            details("Train", &["ors-1", "ors-2", "ors-1"])
+                .validated()
+                .unwrap_err(),
+            ConsistError::DuplicateVehicle("ors-1".to_string())
+        );
+    }
+}
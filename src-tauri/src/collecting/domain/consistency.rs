@@ -0,0 +1,188 @@
+//! Consistency between a collection and the catalog it refers to.
+//!
+//! Over time the catalog changes underneath a collection: rolling stocks are
+//! removed, railway models change category and the database may be edited by
+//! hand. `ConsistencyReport` lists what no longer adds up, each finding with
+//! the action that would repair it; only the safe actions (see
+//! `RepairAction::is_safe`) are ever applied automatically.
+
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::summary::CollectionSummary;
+use serde::{Deserialize, Serialize};
+
+/// The kind of an inconsistency.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InconsistencyKind {
+    /// An owned rolling stock refers to a rolling stock missing from the
+    /// catalog.
+    MissingRollingStock,
+    /// A summary counter of the collection does not match the categories of
+    /// the railway models of its items.
+    SummaryCounterMismatch,
+    /// A purchase info holds a currency the application does not support.
+    UnsupportedCurrency,
+}
+
+/// What repairs an inconsistency.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RepairAction {
+    /// Clear the reference of the owned rolling stock, keeping the rest of
+    /// it.
+    ClearRollingStockReference,
+    /// Recompute the summary counters of the collection from its items.
+    RecomputeSummaryCounters,
+    /// Edit the purchase to pick a supported currency; the amounts cannot be
+    /// converted without knowing what was meant.
+    ChooseSupportedCurrency,
+}
+
+impl RepairAction {
+    /// Whether the action can be applied without the collector's input.
+    pub fn is_safe(&self) -> bool {
+        match self {
+            RepairAction::ClearRollingStockReference | RepairAction::RecomputeSummaryCounters => {
+                true
+            }
+            RepairAction::ChooseSupportedCurrency => false,
+        }
+    }
+}
+
+/// Something that no longer adds up in a collection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct Inconsistency {
+    /// What is wrong.
+    pub kind: InconsistencyKind,
+
+    /// The id of the row at fault: the owned rolling stock, the collection
+    /// or the purchase info.
+    pub subject_id: String,
+
+    /// A description of the problem, for the collector.
+    pub details: String,
+
+    /// What repairs it.
+    pub repair: RepairAction,
+}
+
+/// The outcome of checking the consistency of a collection.
+///
+/// The inconsistencies describe the collection as it was before any repair.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ConsistencyReport {
+    /// The collection that was checked.
+    pub collection_id: CollectionId,
+
+    /// The inconsistencies found, grouped by kind.
+    pub inconsistencies: Vec<Inconsistency>,
+
+    /// Whether the safe repairs were applied.
+    pub repaired: bool,
+}
+
+impl ConsistencyReport {
+    /// Returns `true` when nothing was found.
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistencies.is_empty()
+    }
+
+    /// Whether any inconsistency can be repaired automatically.
+    pub fn has_safe_repairs(&self) -> bool {
+        self.inconsistencies
+            .iter()
+            .any(|inconsistency| inconsistency.repair.is_safe())
+    }
+}
+
+/// The summary counters of the collection with `collection_id` that differ
+/// between the `stored` counters and those `counted` from its items.
+pub fn summary_counter_mismatches(
+    collection_id: &CollectionId,
+    stored: &CollectionSummary,
+    counted: &CollectionSummary,
+) -> Vec<Inconsistency> {
+    let counters = [
+        (
+            "locomotives",
+            stored.locomotives_count,
+            counted.locomotives_count,
+        ),
+        (
+            "passenger cars",
+            stored.passenger_cars_count,
+            counted.passenger_cars_count,
+        ),
+        (
+            "freight cars",
+            stored.freight_cars_count,
+            counted.freight_cars_count,
+        ),
+        (
+            "train sets",
+            stored.train_sets_count,
+            counted.train_sets_count,
+        ),
+        ("railcars", stored.railcars_count, counted.railcars_count),
+        (
+            "electric multiple units",
+            stored.electric_multiple_units_count,
+            counted.electric_multiple_units_count,
+        ),
+    ];
+
+    counters
+        .into_iter()
+        .filter(|(_, stored, counted)| stored != counted)
+        .map(|(bucket, stored, counted)| Inconsistency {
+            kind: InconsistencyKind::SummaryCounterMismatch,
+            subject_id: collection_id.to_string(),
+            details: format!("{stored} {bucket} are counted, the items have {counted}"),
+            repair: RepairAction::RecomputeSummaryCounters,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_report_the_counters_that_differ() {
+        let collection_id = CollectionId::default();
+        let stored = CollectionSummary {
+            locomotives_count: 7,
+            freight_cars_count: 2,
+            ..CollectionSummary::default()
+        };
+        let counted = CollectionSummary {
+            locomotives_count: 6,
+            freight_cars_count: 2,
+            railcars_count: 1,
+            ..CollectionSummary::default()
+        };
+
+        let details: Vec<String> = summary_counter_mismatches(&collection_id, &stored, &counted)
+            .into_iter()
+            .map(|inconsistency| inconsistency.details)
+            .collect();
+
+        assert_eq!(
+            details,
+            vec![
+                "7 locomotives are counted, the items have 6",
+                "0 railcars are counted, the items have 1",
+            ]
+        );
+        assert!(summary_counter_mismatches(&collection_id, &stored, &stored).is_empty());
+    }
+
+    #[test]
+    fn only_safe_repairs_are_applied_automatically() {
+        assert!(RepairAction::ClearRollingStockReference.is_safe());
+        assert!(RepairAction::RecomputeSummaryCounters.is_safe());
+        assert!(!RepairAction::ChooseSupportedCurrency.is_safe());
+    }
+}
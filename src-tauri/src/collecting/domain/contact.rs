@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+/// A person the collector deals with, such as a club member borrowing an
+/// item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct Contact {
+    /// The name of the person, as the collector knows them.
+    pub name: String,
+
+    /// An email address to reach them.
+    pub email: Option<String>,
+
+    /// A phone number to reach them.
+    pub phone: Option<String>,
+}
+
+impl Contact {
+    /// This contact with the entered text trimmed and blank optional text
+    /// treated as missing.
+    pub fn trimmed(self) -> Self {
+        let optional =
+            |text: Option<String>| text.map(|t| t.trim().to_string()).filter(|t| !t.is_empty());
+        Self {
+            name: self.name.trim().to_string(),
+            email: optional(self.email),
+            phone: optional(self.phone),
+        }
+    }
+}
@@ -0,0 +1,301 @@
+//! What owning a collection item has cost so far.
+//!
+//! The cost of ownership adds up the acquisition cost (net of the monetary
+//! adjustments of the purchase), the decoders installed in the item's
+//! rolling stocks and the modifications made to them. Decoders and
+//! modifications are often bought in another currency than the model, so
+//! the totals are given per currency instead of failing on a mismatch.
+
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::decoder::Decoder;
+use crate::collecting::domain::modification::Modification;
+use crate::collecting::domain::monetary_adjustment::{AdjustmentError, MonetaryAdjustment};
+use crate::collecting::domain::purchase_info::PurchaseInfo;
+use crate::core::domain::MonetaryAmount;
+use serde::{Deserialize, Serialize};
+
+/// What the cost of a collection item is computed from, besides its
+/// purchase info.
+#[derive(Debug, Clone, Default)]
+pub struct ItemCostInputs {
+    /// The monetary adjustments of the item's purchase.
+    pub adjustments: Vec<MonetaryAdjustment>,
+
+    /// The decoders installed in the item's rolling stocks.
+    pub decoders: Vec<Decoder>,
+
+    /// The modifications made to the item's rolling stocks.
+    pub modifications: Vec<Modification>,
+}
+
+/// Where a cost of ownership comes from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CostKind {
+    /// The price paid for the item, net of its adjustments.
+    Purchase,
+    /// A decoder installed in one of its rolling stocks.
+    Decoder,
+    /// A modification made to one of its rolling stocks.
+    Modification,
+}
+
+/// A single cost in the breakdown.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CostEntry {
+    /// Where the cost comes from.
+    pub kind: CostKind,
+
+    /// The identifier of the purchase info, decoder or modification.
+    pub id: String,
+
+    /// The seller, the decoder manufacturer and model, or what the
+    /// modification was, when known.
+    pub description: Option<String>,
+
+    /// What it cost.
+    pub cost: MonetaryAmount,
+}
+
+/// What owning a collection item has cost, for its detail screen.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ItemCostBreakdown {
+    /// The collection item the costs are for.
+    pub collection_item_id: CollectionItemId,
+
+    /// Every known cost: the purchase first, then the decoders and the
+    /// modifications.
+    pub entries: Vec<CostEntry>,
+
+    /// How many decoders and modifications have no known price and are
+    /// left out of the totals.
+    pub unpriced: u32,
+
+    /// The costs added up per currency, in the order the currencies first
+    /// appear in `entries`.
+    pub totals: Vec<MonetaryAmount>,
+}
+
+impl ItemCostBreakdown {
+    /// Break down the costs of the collection item with
+    /// `collection_item_id`, bought as `purchase_info` (`None` when it was
+    /// not recorded).
+    ///
+    /// # Errors
+    ///
+    /// As `PurchaseInfo::acquisition_cost` for the purchase and its
+    /// adjustments, and `AdjustmentError::Overflow` when a total does not
+    /// fit a monetary amount.
+    pub fn build(
+        collection_item_id: CollectionItemId,
+        purchase_info: Option<&PurchaseInfo>,
+        inputs: &ItemCostInputs,
+    ) -> Result<Self, AdjustmentError> {
+        let mut entries = Vec::new();
+        let mut unpriced = 0;
+
+        let acquisition = match purchase_info {
+            Some(purchase_info) => purchase_info
+                .acquisition_cost(&inputs.adjustments)?
+                .map(|cost| (purchase_info, cost)),
+            None if !inputs.adjustments.is_empty() => return Err(AdjustmentError::UnknownCost),
+            None => None,
+        };
+        if let Some((purchase_info, cost)) = acquisition {
+            entries.push(CostEntry {
+                kind: CostKind::Purchase,
+                id: purchase_info.id().to_string(),
+                description: purchase_info.seller().map(str::to_string),
+                cost,
+            });
+        }
+        for decoder in &inputs.decoders {
+            match &decoder.purchase_price {
+                Some(cost) => entries.push(CostEntry {
+                    kind: CostKind::Decoder,
+                    id: decoder.id.clone(),
+                    description: Some(format!("{} {}", decoder.manufacturer, decoder.model)),
+                    cost: cost.clone(),
+                }),
+                None => unpriced += 1,
+            }
+        }
+        for modification in &inputs.modifications {
+            match &modification.cost {
+                Some(cost) => entries.push(CostEntry {
+                    kind: CostKind::Modification,
+                    id: modification.id.clone(),
+                    description: Some(modification.description.clone()),
+                    cost: cost.clone(),
+                }),
+                None => unpriced += 1,
+            }
+        }
+
+        let mut totals: Vec<MonetaryAmount> = Vec::new();
+        for entry in &entries {
+            match totals
+                .iter_mut()
+                .find(|total| total.currency == entry.cost.currency)
+            {
+                Some(total) => {
+                    *total = total
+                        .add_same_currency(&entry.cost)
+                        .map_err(|_| AdjustmentError::Overflow)?;
+                }
+                None => totals.push(entry.cost.clone()),
+            }
+        }
+
+        Ok(Self {
+            collection_item_id,
+            entries,
+            unpriced,
+            totals,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collecting::domain::modification::ModificationType;
+    use crate::collecting::domain::purchase_info::PurchasedInfo;
+    use crate::core::domain::Currency;
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()
+    }
+
+    fn purchase(price: u64) -> PurchaseInfo {
+        PurchaseInfo::Purchased(PurchasedInfo {
+            id: "pi-1".to_string(),
+            purchase_date: date(),
+            price: Some(MonetaryAmount::new(price, Currency::EUR)),
+            seller: Some("Shop".to_string()),
+        })
+    }
+
+    fn decoder(id: &str, price: Option<MonetaryAmount>) -> Decoder {
+        Decoder {
+            id: id.to_string(),
+            manufacturer: "ESU".to_string(),
+            model: "LokSound 5".to_string(),
+            dcc_interface: None,
+            address: 3,
+            sound_project: None,
+            firmware: None,
+            purchase_price: price,
+        }
+    }
+
+    fn modification(id: &str, cost: Option<MonetaryAmount>) -> Modification {
+        Modification {
+            id: id.to_string(),
+            modification_type: ModificationType::Lighting,
+            description: "interior lighting".to_string(),
+            date: date(),
+            cost,
+        }
+    }
+
+    #[test]
+    fn costs_are_totalled_per_currency() {
+        let inputs = ItemCostInputs {
+            adjustments: vec![MonetaryAdjustment {
+                id: "adj-1".to_string(),
+                amount: -500,
+                currency: Currency::EUR,
+                date: date(),
+                reason: "refund".to_string(),
+            }],
+            decoders: vec![
+                decoder("dec-1", Some(MonetaryAmount::new(11_900, Currency::USD))),
+                decoder("dec-2", None),
+            ],
+            modifications: vec![
+                modification("mod-1", Some(MonetaryAmount::new(2_490, Currency::EUR))),
+                modification("mod-2", Some(MonetaryAmount::new(1_000, Currency::USD))),
+            ],
+        };
+
+        let breakdown = ItemCostBreakdown::build(
+            CollectionItemId::default(),
+            Some(&purchase(10_000)),
+            &inputs,
+        )
+        .unwrap();
+
+        let kinds: Vec<CostKind> = breakdown.entries.iter().map(|e| e.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                CostKind::Purchase,
+                CostKind::Decoder,
+                CostKind::Modification,
+                CostKind::Modification,
+            ]
+        );
+        assert_eq!(breakdown.entries[0].cost.amount, 9_500);
+        assert_eq!(breakdown.entries[0].description.as_deref(), Some("Shop"));
+        assert_eq!(
+            breakdown.entries[1].description.as_deref(),
+            Some("ESU LokSound 5")
+        );
+        assert_eq!(breakdown.unpriced, 1);
+        assert_eq!(
+            breakdown.totals,
+            vec![
+                MonetaryAmount::new(9_500 + 2_490, Currency::EUR),
+                MonetaryAmount::new(11_900 + 1_000, Currency::USD),
+            ]
+        );
+    }
+
+    #[test]
+    fn items_without_purchase_info_only_count_their_extras() {
+        let inputs = ItemCostInputs {
+            modifications: vec![modification(
+                "mod-1",
+                Some(MonetaryAmount::new(2_490, Currency::EUR)),
+            )],
+            ..ItemCostInputs::default()
+        };
+
+        let breakdown =
+            ItemCostBreakdown::build(CollectionItemId::default(), None, &inputs).unwrap();
+
+        assert_eq!(
+            breakdown.totals,
+            vec![MonetaryAmount::new(2_490, Currency::EUR)]
+        );
+    }
+
+    #[test]
+    fn adjustments_must_be_in_the_purchase_currency() {
+        let inputs = ItemCostInputs {
+            adjustments: vec![MonetaryAdjustment {
+                id: "adj-1".to_string(),
+                amount: 500,
+                currency: Currency::USD,
+                date: date(),
+                reason: "customs".to_string(),
+            }],
+            ..ItemCostInputs::default()
+        };
+
+        assert_eq!(
+            ItemCostBreakdown::build(
+                CollectionItemId::default(),
+                Some(&purchase(10_000)),
+                &inputs
+            ),
+            Err(AdjustmentError::CurrencyMismatch {
+                purchase: Currency::EUR,
+                adjustment: Currency::USD,
+            })
+        );
+    }
+}
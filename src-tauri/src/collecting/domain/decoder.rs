@@ -0,0 +1,329 @@
+use crate::catalog::domain::dcc_interface::DccInterface;
+use crate::core::domain::MonetaryAmount;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A DCC decoder owned by the collector.
+///
+/// Decoders are tracked on their own, since they move between locomotives:
+/// an owned rolling stock references the decoder installed in it (see
+/// `OwnedRollingStock::decoder`), and a decoder is installed in at most one
+/// rolling stock at a time.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct Decoder {
+    /// Unique identifier for this decoder (e.g. UUID in the DB).
+    pub id: String,
+
+    /// The decoder manufacturer, for example "ESU" or "Zimo".
+    pub manufacturer: String,
+
+    /// The manufacturer's model name or product code, for example
+    /// "LokSound 5".
+    pub model: String,
+
+    /// The connector of the decoder, or `None` for a hardwired decoder.
+    pub dcc_interface: Option<DccInterface>,
+
+    /// The DCC address the decoder is programmed to, between
+    /// `Decoder::MIN_ADDRESS` and `Decoder::MAX_ADDRESS`.
+    pub address: u16,
+
+    /// The sound project loaded on a sound decoder.
+    pub sound_project: Option<String>,
+
+    /// The firmware version the decoder runs.
+    pub firmware: Option<String>,
+
+    /// What was paid for the decoder, when known.
+    pub purchase_price: Option<MonetaryAmount>,
+}
+
+/// The fields of a decoder the collector enters, to create or update one.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct DecoderDetails {
+    /// The decoder manufacturer.
+    pub manufacturer: String,
+
+    /// The manufacturer's model name or product code.
+    pub model: String,
+
+    /// The connector of the decoder, or `None` for a hardwired decoder.
+    pub dcc_interface: Option<DccInterface>,
+
+    /// The DCC address the decoder is programmed to.
+    pub address: u16,
+
+    /// The sound project loaded on a sound decoder.
+    pub sound_project: Option<String>,
+
+    /// The firmware version the decoder runs.
+    pub firmware: Option<String>,
+
+    /// What was paid for the decoder, when known.
+    pub purchase_price: Option<MonetaryAmount>,
+}
+
+impl Decoder {
+    /// The lowest DCC address a decoder can be programmed to; 0 is the
+    /// broadcast address.
+    pub const MIN_ADDRESS: u16 = 1;
+
+    /// The highest extended (long) DCC address.
+    pub const MAX_ADDRESS: u16 = 10239;
+
+    /// A decoder with `id` and `details`, trimming the entered text and
+    /// treating blank optional text as missing.
+    ///
+    /// Fails with `DecoderError::AddressOutOfRange` when the address is not
+    /// a valid DCC address.
+    pub fn new(id: String, details: DecoderDetails) -> Result<Self, DecoderError> {
+        if !(Self::MIN_ADDRESS..=Self::MAX_ADDRESS).contains(&details.address) {
+            return Err(DecoderError::AddressOutOfRange(details.address));
+        }
+        let optional =
+            |text: Option<String>| text.map(|t| t.trim().to_string()).filter(|t| !t.is_empty());
+        Ok(Self {
+            id,
+            manufacturer: details.manufacturer.trim().to_string(),
+            model: details.model.trim().to_string(),
+            dcc_interface: details.dcc_interface,
+            address: details.address,
+            sound_project: optional(details.sound_project),
+            firmware: optional(details.firmware),
+            purchase_price: details.purchase_price,
+        })
+    }
+
+    /// The warnings about installing this decoder in a rolling stock whose
+    /// catalog entry has the `rolling_stock_interface` connector.
+    ///
+    /// A decoder with another connector than the rolling stock usually needs
+    /// an adapter or some soldering, so it is reported but allowed. Nothing
+    /// is reported when either connector is unknown.
+    pub fn installation_warnings(
+        &self,
+        rolling_stock_interface: Option<DccInterface>,
+    ) -> Vec<DecoderWarning> {
+        match (self.dcc_interface, rolling_stock_interface) {
+            (Some(decoder), Some(rolling_stock)) if decoder != rolling_stock => {
+                vec![DecoderWarning::InterfaceMismatch {
+                    decoder,
+                    rolling_stock,
+                }]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// The warnings about this decoder's address, given the decoders
+    /// `with_same_address` (which may include this one).
+    pub fn address_warnings(&self, with_same_address: &[Decoder]) -> Vec<DecoderWarning> {
+        let decoder_ids: Vec<String> = with_same_address
+            .iter()
+            .filter(|d| d.id != self.id && d.address == self.address)
+            .map(|d| d.id.clone())
+            .collect();
+        if decoder_ids.is_empty() {
+            Vec::new()
+        } else {
+            vec![DecoderWarning::DuplicateAddress {
+                address: self.address,
+                decoder_ids,
+            }]
+        }
+    }
+}
+
+/// A problem with a decoder operation that did not prevent it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DecoderWarning {
+    /// The decoder connector differs from the DCC interface stored for the
+    /// rolling stock it was installed in.
+    InterfaceMismatch {
+        decoder: DccInterface,
+        rolling_stock: DccInterface,
+    },
+
+    /// Other decoders are programmed to the same address; rolling stocks
+    /// fitted with them respond to the same commands on the layout.
+    DuplicateAddress {
+        address: u16,
+        decoder_ids: Vec<String>,
+    },
+}
+
+/// A decoder as stored, with the warnings about its details.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct SavedDecoder {
+    /// The stored decoder.
+    pub decoder: Decoder,
+
+    /// What is wrong with the decoder without preventing it from being
+    /// stored; empty when nothing.
+    pub warnings: Vec<DecoderWarning>,
+}
+
+/// Owned rolling stocks fitted with decoders programmed to the same DCC
+/// address.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct AddressConflict {
+    /// The shared address.
+    pub address: u16,
+
+    /// The rolling stocks using the address, with their decoders; at least
+    /// two.
+    pub installations: Vec<DecoderInstallation>,
+}
+
+/// A decoder installed in an owned rolling stock.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct DecoderInstallation {
+    /// The owned rolling stock the decoder is installed in.
+    pub owned_rolling_stock_id: String,
+
+    /// The installed decoder.
+    pub decoder_id: String,
+}
+
+/// Why a decoder cannot be stored or installed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DecoderError {
+    /// The decoder is installed in another owned rolling stock; detach it
+    /// from there first.
+    #[error("decoder {decoder_id} is already installed in owned rolling stock {installed_in}")]
+    AlreadyInstalled {
+        decoder_id: String,
+        installed_in: String,
+    },
+
+    /// The address is not a DCC address a decoder can be programmed to.
+    #[error(
+        "address {0} is outside the DCC address range {min}-{max}",
+        min = Decoder::MIN_ADDRESS,
+        max = Decoder::MAX_ADDRESS
+    )]
+    AddressOutOfRange(u16),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn decoder(dcc_interface: Option<DccInterface>) -> Decoder {
+        Decoder {
+            id: "dec-1".to_string(),
+            manufacturer: "ESU".to_string(),
+            model: "LokSound 5".to_string(),
+            dcc_interface,
+            address: 3,
+            sound_project: None,
+            firmware: None,
+            purchase_price: None,
+        }
+    }
+
+    fn details(address: u16) -> DecoderDetails {
+        DecoderDetails {
+            manufacturer: " ESU ".to_string(),
+            model: "LokSound 5 ".to_string(),
+            dcc_interface: Some(DccInterface::Mtc21),
+            address,
+            sound_project: Some("  ".to_string()),
+            firmware: Some(" 5.10.2".to_string()),
+            purchase_price: None,
+        }
+    }
+
+    #[test]
+    fn entered_text_is_trimmed() {
+        let decoder = Decoder::new("dec-1".to_string(), details(3)).unwrap();
+
+        assert_eq!(decoder.manufacturer, "ESU");
+        assert_eq!(decoder.model, "LokSound 5");
+        assert_eq!(decoder.sound_project, None);
+        assert_eq!(decoder.firmware.as_deref(), Some("5.10.2"));
+    }
+
+    #[test]
+    fn addresses_must_be_dcc_addresses() {
+        assert!(Decoder::new("dec-1".to_string(), details(1)).is_ok());
+        assert!(Decoder::new("dec-1".to_string(), details(10239)).is_ok());
+        for address in [0, 10240] {
+            let err = Decoder::new("dec-1".to_string(), details(address)).unwrap_err();
+            assert_eq!(err, DecoderError::AddressOutOfRange(address));
+        }
+        assert_eq!(
+            DecoderError::AddressOutOfRange(0).to_string(),
+            "address 0 is outside the DCC address range 1-10239"
+        );
+    }
+
+    #[test]
+    fn a_different_connector_is_a_warning() {
+        let plux = decoder(Some(DccInterface::Plux22));
+
+        assert_eq!(
+            plux.installation_warnings(Some(DccInterface::Mtc21)),
+            vec![DecoderWarning::InterfaceMismatch {
+                decoder: DccInterface::Plux22,
+                rolling_stock: DccInterface::Mtc21,
+            }]
+        );
+        assert!(
+            plux.installation_warnings(Some(DccInterface::Plux22))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn unknown_connectors_are_not_compared() {
+        assert!(
+            decoder(None)
+                .installation_warnings(Some(DccInterface::Mtc21))
+                .is_empty()
+        );
+        assert!(
+            decoder(Some(DccInterface::Plux22))
+                .installation_warnings(None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn other_decoders_on_the_same_address_are_a_warning() {
+        let mut other = decoder(None);
+        other.id = "dec-2".to_string();
+        let this = decoder(None);
+
+        assert_eq!(
+            this.address_warnings(&[this.clone(), other]),
+            vec![DecoderWarning::DuplicateAddress {
+                address: 3,
+                decoder_ids: vec!["dec-2".to_string()],
+            }]
+        );
+        assert!(
+            this.address_warnings(std::slice::from_ref(&this))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn warnings_are_tagged_by_type() {
+        let warning = DecoderWarning::InterfaceMismatch {
+            decoder: DccInterface::Plux22,
+            rolling_stock: DccInterface::Next18,
+        };
+
+        assert_eq!(
+            serde_json::to_value(&warning).unwrap(),
+            serde_json::json!({
+                "type": "interface_mismatch",
+                "decoder": "PLUX_22",
+                "rolling_stock": "NEXT_18",
+            })
+        );
+    }
+}
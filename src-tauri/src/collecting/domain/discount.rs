@@ -0,0 +1,79 @@
+//! How much less than the manufacturer's suggested retail price (MSRP) the
+//! collector paid for an item.
+//!
+//! A discount is only known when the price paid and the MSRP are in the same
+//! currency: amounts are never converted, so an item bought in another
+//! currency simply has no discount.
+
+use crate::core::domain::MonetaryAmount;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// The discount on `msrp` of paying `price`, as a percentage rounded to one
+/// decimal place (`18.0` for 18% off); negative when the price is above
+/// the MSRP.
+///
+/// `None` when the amounts are in different currencies or the MSRP is zero.
+pub fn discount_percentage(price: &MonetaryAmount, msrp: &MonetaryAmount) -> Option<Decimal> {
+    if price.currency != msrp.currency || msrp.amount == 0 {
+        return None;
+    }
+    let price = Decimal::from(price.amount);
+    let msrp = Decimal::from(msrp.amount);
+    Some(((msrp - price) / msrp * dec!(100)).round_dp(1))
+}
+
+/// The average of `discounts`, rounded to one decimal place, or `None` when
+/// there are none.
+pub fn average_discount(discounts: impl IntoIterator<Item = Decimal>) -> Option<Decimal> {
+    let (sum, count) = discounts
+        .into_iter()
+        .fold((Decimal::ZERO, 0u32), |(sum, count), discount| {
+            (sum + discount, count + 1)
+        });
+    (count > 0).then(|| (sum / Decimal::from(count)).round_dp(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::Currency;
+    use pretty_assertions::assert_eq;
+
+    fn eur(amount: u64) -> MonetaryAmount {
+        MonetaryAmount::new(amount, Currency::EUR)
+    }
+
+    #[test]
+    fn it_should_compute_the_discount_in_the_same_currency() {
+        assert_eq!(
+            discount_percentage(&eur(20_500), &eur(25_000)),
+            Some(dec!(18))
+        );
+        assert_eq!(
+            discount_percentage(&eur(10_000), &eur(30_000)),
+            Some(dec!(66.7))
+        );
+        assert_eq!(
+            discount_percentage(&eur(27_500), &eur(25_000)),
+            Some(dec!(-10))
+        );
+    }
+
+    #[test]
+    fn it_should_not_convert_between_currencies() {
+        let price = MonetaryAmount::new(20_500, Currency::USD);
+
+        assert_eq!(discount_percentage(&price, &eur(25_000)), None);
+        assert_eq!(discount_percentage(&eur(0), &eur(0)), None);
+    }
+
+    #[test]
+    fn it_should_average_the_discounts() {
+        assert_eq!(
+            average_discount([dec!(18), dec!(10), dec!(-2.5)]),
+            Some(dec!(8.5))
+        );
+        assert_eq!(average_discount([]), None);
+    }
+}
@@ -0,0 +1,198 @@
+//! The amounts of a collection converted into a display currency.
+//!
+//! Amounts are stored in the currency they were entered in and are never
+//! rewritten when the collector switches currency: the conversion is only for
+//! display, so every `DisplayAmount` carries the stored amount next to its
+//! converted value.
+
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::core::domain::{Currency, MonetaryAmount};
+use crate::exchange_rates::domain::conversion::ExchangeRates;
+use crate::exchange_rates::domain::error::ExchangeRateError;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// The amounts of a collection item, as stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredItemAmounts {
+    pub collection_item_id: CollectionItemId,
+
+    /// What the item cost: the price paid, or the total price of a preorder.
+    pub price: Option<MonetaryAmount>,
+
+    /// What replacing the item would cost today.
+    pub replacement_value: Option<MonetaryAmount>,
+}
+
+/// A stored amount together with its value in the display currency.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct DisplayAmount {
+    /// The amount as stored, in the currency it was entered in.
+    pub stored: MonetaryAmount,
+
+    /// The amount in the display currency, or `None` when no exchange rate
+    /// links the two currencies.
+    pub display: Option<MonetaryAmount>,
+
+    /// The rate applied to the stored amount, `None` when not converted.
+    pub rate: Option<Decimal>,
+}
+
+/// The amounts of a collection item, in their stored and display currencies.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct ItemDisplayAmounts {
+    pub collection_item_id: CollectionItemId,
+
+    /// What the item cost (see `StoredItemAmounts::price`).
+    pub price: Option<DisplayAmount>,
+
+    /// What replacing the item would cost today.
+    pub replacement_value: Option<DisplayAmount>,
+}
+
+/// The amounts of every item of a collection, for display in one currency.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct DisplayCurrencyListing {
+    /// The currency the amounts are displayed in.
+    pub display_currency: Currency,
+
+    /// The items in the order they were added.
+    pub items: Vec<ItemDisplayAmounts>,
+
+    /// The stored currencies with no exchange rate into the display currency;
+    /// their amounts have no display value.
+    pub missing_rates: Vec<Currency>,
+}
+
+impl DisplayCurrencyListing {
+    /// Convert the amounts of `items` into `display_currency` with `rates`.
+    pub fn convert(
+        display_currency: Currency,
+        items: Vec<StoredItemAmounts>,
+        rates: &ExchangeRates,
+    ) -> Self {
+        let mut missing_rates = Vec::new();
+        let mut display = |stored: MonetaryAmount| -> DisplayAmount {
+            match rates.convert(&stored, display_currency) {
+                Ok(preview) => DisplayAmount {
+                    stored,
+                    display: Some(preview.amount),
+                    rate: Some(preview.rate),
+                },
+                Err(e) => {
+                    if let ExchangeRateError::MissingRate(from, _) = e
+                        && !missing_rates.contains(&from)
+                    {
+                        missing_rates.push(from);
+                    }
+                    DisplayAmount {
+                        stored,
+                        display: None,
+                        rate: None,
+                    }
+                }
+            }
+        };
+
+        let items = items
+            .into_iter()
+            .map(|item| ItemDisplayAmounts {
+                collection_item_id: item.collection_item_id,
+                price: item.price.map(&mut display),
+                replacement_value: item.replacement_value.map(&mut display),
+            })
+            .collect();
+
+        Self {
+            display_currency,
+            items,
+            missing_rates,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchange_rates::domain::exchange_rate::ExchangeRate;
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    fn rates() -> ExchangeRates {
+        ExchangeRates::new(vec![ExchangeRate {
+            from: Currency::GBP,
+            to: Currency::EUR,
+            rate: dec!(1.2),
+            updated_at: NaiveDate::from_ymd_opt(2026, 1, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+        }])
+    }
+
+    fn item(
+        price: Option<MonetaryAmount>,
+        replacement_value: Option<MonetaryAmount>,
+    ) -> StoredItemAmounts {
+        StoredItemAmounts {
+            collection_item_id: CollectionItemId::from(Uuid::new_v4()),
+            price,
+            replacement_value,
+        }
+    }
+
+    #[test]
+    fn amounts_keep_their_stored_value() {
+        let gbp = MonetaryAmount::new(1000, Currency::GBP);
+        let eur = MonetaryAmount::new(500, Currency::EUR);
+
+        let listing = DisplayCurrencyListing::convert(
+            Currency::EUR,
+            vec![item(Some(gbp.clone()), Some(eur.clone())), item(None, None)],
+            &rates(),
+        );
+
+        assert_eq!(listing.display_currency, Currency::EUR);
+        assert_eq!(
+            listing.items[0].price,
+            Some(DisplayAmount {
+                stored: gbp,
+                display: Some(MonetaryAmount::new(1200, Currency::EUR)),
+                rate: Some(dec!(1.2)),
+            })
+        );
+        assert_eq!(
+            listing.items[0].replacement_value,
+            Some(DisplayAmount {
+                stored: eur.clone(),
+                display: Some(eur),
+                rate: Some(Decimal::ONE),
+            })
+        );
+        assert_eq!(listing.items[1].price, None);
+        assert!(listing.missing_rates.is_empty());
+    }
+
+    #[test]
+    fn currencies_without_a_rate_are_reported_once() {
+        let usd = MonetaryAmount::new(1000, Currency::USD);
+
+        let listing = DisplayCurrencyListing::convert(
+            Currency::EUR,
+            vec![item(Some(usd.clone()), Some(usd.clone()))],
+            &rates(),
+        );
+
+        assert_eq!(
+            listing.items[0].price,
+            Some(DisplayAmount {
+                stored: usd,
+                display: None,
+                rate: None,
+            })
+        );
+        assert_eq!(listing.missing_rates, vec![Currency::USD]);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::catalog::domain::ProductCode;
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::statistics::CurrencyValue;
+use crate::core::domain::MonetaryAmount;
+use crate::core::domain::error::Error;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// How an insurance report values the items of a collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ValuationMode {
+    /// Each item at the price it was bought for.
+    PurchasePrice,
+
+    /// Each item at what replacing it would cost, as entered by the
+    /// collector; items without a replacement value fall back to their
+    /// purchase price.
+    ReplacementValue,
+}
+
+impl ValuationMode {
+    /// The value insured for an item with `purchase_price` and
+    /// `replacement_value`, or `None` when it is unknown.
+    pub fn insured_value(
+        &self,
+        purchase_price: Option<&MonetaryAmount>,
+        replacement_value: Option<&MonetaryAmount>,
+    ) -> Option<MonetaryAmount> {
+        match self {
+            ValuationMode::PurchasePrice => purchase_price.cloned(),
+            ValuationMode::ReplacementValue => replacement_value.or(purchase_price).cloned(),
+        }
+    }
+}
+
+/// The items of a collection listed for its insurer, with their values.
+///
+/// Sold items are no longer insured and are left out. Totals are per
+/// currency, ordered by currency code, and leave out the items whose value is
+/// unknown.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct InsuranceReport {
+    /// The collection the report is about.
+    pub collection_id: CollectionId,
+
+    /// How the items were valued.
+    pub valuation: ValuationMode,
+
+    /// The insured items, ordered by manufacturer and product code.
+    pub lines: Vec<InsuranceReportLine>,
+
+    /// The sum of the insured values, per currency.
+    pub totals: Vec<CurrencyValue>,
+}
+
+/// An item listed in an `InsuranceReport`.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct InsuranceReportLine {
+    /// The collection item.
+    pub collection_item_id: CollectionItemId,
+
+    /// The manufacturer name of its railway model.
+    pub manufacturer: String,
+
+    /// The manufacturer-assigned product code of its railway model.
+    pub product_code: ProductCode,
+
+    /// The description of its railway model.
+    pub description: String,
+
+    /// The road numbers of its rolling stocks, when the catalog has them.
+    pub road_numbers: Vec<String>,
+
+    /// When the item was bought, when recorded.
+    pub purchase_date: Option<NaiveDate>,
+
+    /// What the item was bought for, when known.
+    pub purchase_price: Option<MonetaryAmount>,
+
+    /// What replacing the item would cost, when entered.
+    pub replacement_value: Option<MonetaryAmount>,
+
+    /// The value insured under the report valuation (see
+    /// `ValuationMode::insured_value`), or `None` when it is unknown.
+    pub insured_value: Option<MonetaryAmount>,
+}
+
+impl InsuranceReport {
+    /// A report on `lines`, totalling their insured values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::Overflow` when a total does not fit an amount.
+    pub fn new(
+        collection_id: CollectionId,
+        valuation: ValuationMode,
+        lines: Vec<InsuranceReportLine>,
+    ) -> Result<Self, Error> {
+        let mut totals: Vec<CurrencyValue> = Vec::new();
+        for value in lines.iter().filter_map(|line| line.insured_value.as_ref()) {
+            match totals.iter_mut().find(|t| t.currency == value.currency) {
+                Some(total) => {
+                    total.amount = total
+                        .amount
+                        .checked_add(value.amount)
+                        .ok_or(Error::Overflow)?;
+                }
+                None => totals.push(CurrencyValue {
+                    currency: value.currency,
+                    amount: value.amount,
+                }),
+            }
+        }
+        totals.sort_by_key(|total| total.currency.code());
+
+        Ok(Self {
+            collection_id,
+            valuation,
+            lines,
+            totals,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::Currency;
+    use pretty_assertions::assert_eq;
+
+    fn line(
+        purchase_price: Option<MonetaryAmount>,
+        replacement_value: Option<MonetaryAmount>,
+        valuation: ValuationMode,
+    ) -> InsuranceReportLine {
+        InsuranceReportLine {
+            collection_item_id: CollectionItemId::default(),
+            manufacturer: "ACME".to_string(),
+            product_code: ProductCode("60000".to_string()),
+            description: "FS E.656".to_string(),
+            road_numbers: vec!["E.656.001".to_string()],
+            purchase_date: NaiveDate::from_ymd_opt(2024, 3, 1),
+            insured_value: valuation
+                .insured_value(purchase_price.as_ref(), replacement_value.as_ref()),
+            purchase_price,
+            replacement_value,
+        }
+    }
+
+    fn eur(amount: u64) -> Option<MonetaryAmount> {
+        Some(MonetaryAmount::new(amount, Currency::EUR))
+    }
+
+    #[test]
+    fn replacement_values_fall_back_to_the_purchase_price() {
+        let valuation = ValuationMode::ReplacementValue;
+
+        let value = valuation.insured_value(eur(10_000).as_ref(), eur(15_000).as_ref());
+        assert_eq!(value.map(|v| v.amount), Some(15_000));
+        let value = valuation.insured_value(eur(10_000).as_ref(), None);
+        assert_eq!(value.map(|v| v.amount), Some(10_000));
+        let value =
+            ValuationMode::PurchasePrice.insured_value(eur(10_000).as_ref(), eur(15_000).as_ref());
+        assert_eq!(value.map(|v| v.amount), Some(10_000));
+        assert!(valuation.insured_value(None, None).is_none());
+    }
+
+    #[test]
+    fn totals_are_per_currency() {
+        let valuation = ValuationMode::ReplacementValue;
+        let lines = vec![
+            line(eur(10_000), eur(15_000), valuation),
+            line(
+                Some(MonetaryAmount::new(5_000, Currency::USD)),
+                None,
+                valuation,
+            ),
+            line(eur(2_500), None, valuation),
+            line(None, None, valuation),
+        ];
+
+        let report = InsuranceReport::new(CollectionId::default(), valuation, lines).unwrap();
+
+        assert_eq!(
+            report.totals,
+            vec![
+                CurrencyValue {
+                    currency: Currency::EUR,
+                    amount: 17_500,
+                },
+                CurrencyValue {
+                    currency: Currency::USD,
+                    amount: 5_000,
+                },
+            ]
+        );
+        assert_eq!(report.lines.len(), 4);
+    }
+
+    #[test]
+    fn totals_must_fit_an_amount() {
+        let valuation = ValuationMode::PurchasePrice;
+        let lines = vec![
+            line(eur(u64::MAX), None, valuation),
+            line(eur(1), None, valuation),
+        ];
+
+        assert!(matches!(
+            InsuranceReport::new(CollectionId::default(), valuation, lines),
+            Err(Error::Overflow)
+        ));
+    }
+}
@@ -0,0 +1,36 @@
+//! Duplicating a collection item as a template for a second identical one.
+//!
+//! The duplicate references the same railway model and copies the
+//! conditions and notes of the original, but not what belongs to the
+//! physical item: its purchase info, replacement value, storage location and
+//! loans, nor the notes (serial numbers) and decoders of its rolling stocks.
+
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// What to duplicate along with a collection item.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct DuplicateItemOptions {
+    /// Whether the duplicate owns the same catalog rolling stocks as the
+    /// original, each with a fresh owned rolling stock id.
+    pub clone_rolling_stocks: bool,
+
+    /// Whether to duplicate the item even though the collection already has
+    /// an item for its railway model. When `false` the duplicate is refused
+    /// with `DuplicateItemError::AlreadyInCollection`, so the collector can
+    /// confirm first.
+    pub allow_duplicates: bool,
+}
+
+/// Why a collection item was not duplicated.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum DuplicateItemError {
+    /// The collection already has items for the railway model and
+    /// duplicates were not allowed.
+    #[error("the railway model {railway_model_id} is already in the collection")]
+    AlreadyInCollection {
+        railway_model_id: RailwayModelId,
+        collection_item_ids: Vec<String>,
+    },
+}
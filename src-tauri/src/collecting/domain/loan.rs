@@ -0,0 +1,207 @@
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::contact::Contact;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A collection item lent to someone, for example to run on the club
+/// layout.
+///
+/// A loan is outstanding until the item comes back; an item has at most one
+/// outstanding loan, and cannot be sold while it is on loan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct Loan {
+    /// Unique identifier for this loan.
+    pub id: String,
+
+    /// The item lent.
+    pub collection_item_id: CollectionItemId,
+
+    /// Who borrowed the item.
+    pub borrower: Contact,
+
+    /// When the item was lent (ISO `YYYY-MM-DD`).
+    pub loaned_on: NaiveDate,
+
+    /// When the item is due back, if agreed.
+    pub due_back: Option<NaiveDate>,
+
+    /// When the item came back, or `None` while it is on loan.
+    pub returned_on: Option<NaiveDate>,
+}
+
+impl Loan {
+    /// A new, outstanding loan of an item to `borrower`.
+    ///
+    /// Fails when the borrower has no name or the item is due back before
+    /// it is lent.
+    pub fn new(
+        id: String,
+        collection_item_id: CollectionItemId,
+        borrower: Contact,
+        loaned_on: NaiveDate,
+        due_back: Option<NaiveDate>,
+    ) -> Result<Self, LoanError> {
+        let borrower = borrower.trimmed();
+        if borrower.name.is_empty() {
+            return Err(LoanError::BlankBorrower);
+        }
+        if let Some(due_back) = due_back
+            && due_back < loaned_on
+        {
+            return Err(LoanError::DueBeforeLoaned {
+                loaned_on,
+                due_back,
+            });
+        }
+        Ok(Self {
+            id,
+            collection_item_id,
+            borrower,
+            loaned_on,
+            due_back,
+            returned_on: None,
+        })
+    }
+
+    /// Whether the item has not come back yet.
+    pub fn is_outstanding(&self) -> bool {
+        self.returned_on.is_none()
+    }
+
+    /// Whether the item is still out after the day it was due back.
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        self.is_outstanding() && self.due_back.is_some_and(|due_back| due_back < today)
+    }
+
+    /// This loan with the item back on `returned_on`.
+    pub fn returned(self, returned_on: NaiveDate) -> Result<Self, LoanError> {
+        if returned_on < self.loaned_on {
+            return Err(LoanError::ReturnedBeforeLoaned {
+                loaned_on: self.loaned_on,
+                returned_on,
+            });
+        }
+        Ok(Self {
+            returned_on: Some(returned_on),
+            ..self
+        })
+    }
+}
+
+/// Why an item cannot be lent, returned or sold.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LoanError {
+    /// A loan needs to say who has the item.
+    #[error("the borrower name must not be blank")]
+    BlankBorrower,
+
+    /// An item cannot be due back before it is lent.
+    #[error("the item cannot be due back on {due_back}, before it is lent on {loaned_on}")]
+    DueBeforeLoaned {
+        loaned_on: NaiveDate,
+        due_back: NaiveDate,
+    },
+
+    /// An item cannot come back before it is lent.
+    #[error("the item cannot be returned on {returned_on}, before it was lent on {loaned_on}")]
+    ReturnedBeforeLoaned {
+        loaned_on: NaiveDate,
+        returned_on: NaiveDate,
+    },
+
+    /// The item is lent out: it cannot be lent again or sold until it is
+    /// returned.
+    #[error("collection item {0} is on loan until it is returned")]
+    OnLoan(CollectionItemId),
+
+    /// There is no outstanding loan of the item to return.
+    #[error("collection item {0} is not on loan")]
+    NotOnLoan(CollectionItemId),
+
+    /// A sold item is no longer the collector's to lend.
+    #[error("collection item {0} was sold")]
+    Sold(CollectionItemId),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    fn borrower(name: &str) -> Contact {
+        Contact {
+            name: name.to_string(),
+            email: Some(" ".to_string()),
+            phone: None,
+        }
+    }
+
+    fn loan(due_back: Option<NaiveDate>) -> Result<Loan, LoanError> {
+        Loan::new(
+            "loan-1".to_string(),
+            CollectionItemId::default(),
+            borrower(" Mario Rossi "),
+            date(10),
+            due_back,
+        )
+    }
+
+    #[test]
+    fn new_loans_are_outstanding() {
+        let loan = loan(Some(date(20))).unwrap();
+
+        assert!(loan.is_outstanding());
+        assert_eq!(loan.borrower.name, "Mario Rossi");
+        assert_eq!(loan.borrower.email, None);
+    }
+
+    #[test]
+    fn loans_are_overdue_after_the_due_date() {
+        let loan = loan(Some(date(20))).unwrap();
+
+        assert!(!loan.is_overdue(date(20)));
+        assert!(loan.is_overdue(date(21)));
+        assert!(
+            !loan
+                .clone()
+                .returned(date(25))
+                .unwrap()
+                .is_overdue(date(26))
+        );
+        assert!(!self::loan(None).unwrap().is_overdue(date(31)));
+    }
+
+    #[test]
+    fn dates_must_follow_the_loan() {
+        assert_eq!(
+            loan(Some(date(9))).unwrap_err(),
+            LoanError::DueBeforeLoaned {
+                loaned_on: date(10),
+                due_back: date(9),
+            }
+        );
+        assert_eq!(
+            loan(None).unwrap().returned(date(9)).unwrap_err(),
+            LoanError::ReturnedBeforeLoaned {
+                loaned_on: date(10),
+                returned_on: date(9),
+            }
+        );
+        assert_eq!(
+            Loan::new(
+                "loan-1".to_string(),
+                CollectionItemId::default(),
+                borrower(" "),
+                date(10),
+                None,
+            )
+            .unwrap_err(),
+            LoanError::BlankBorrower
+        );
+    }
+}
@@ -1,8 +1,29 @@
+pub mod bulk_update;
 pub mod collection;
 pub mod collection_id;
 pub mod collection_item;
 pub mod collection_item_id;
+pub mod consist;
+pub mod consistency;
+pub mod contact;
+pub mod cost_breakdown;
+pub mod decoder;
+pub mod discount;
+pub mod display_currency;
+pub mod insurance_report;
+pub mod item_duplication;
+pub mod loan;
+pub mod modification;
+pub mod monetary_adjustment;
 pub mod owned_rolling_stock;
 pub mod purchase_info;
+pub mod quick_add;
+pub mod recent_activity;
+pub mod recompute_diff;
 pub mod repository;
+pub mod statistics;
+pub mod storage_location;
 pub mod summary;
+pub mod value_policy;
+pub mod value_snapshot;
+pub mod year_review;
@@ -0,0 +1,99 @@
+use crate::collecting::domain::monetary_adjustment::{AdjustmentError, MonetaryAdjustment};
+use crate::core::domain::MonetaryAmount;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+/// A change the collector made to an owned rolling stock after buying it.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct Modification {
+    /// Unique identifier for this modification.
+    pub id: String,
+
+    /// What kind of change it was.
+    pub modification_type: ModificationType,
+
+    /// What was done, for example "weathered with oils, rusty roof".
+    pub description: String,
+
+    /// Date of the modification (ISO `YYYY-MM-DD`).
+    pub date: NaiveDate,
+
+    /// What the modification cost (parts, paint, workshop), when known.
+    pub cost: Option<MonetaryAmount>,
+}
+
+impl Modification {
+    /// The cost of this modification as a positive adjustment of the
+    /// purchase cost, or `None` when the cost is unknown.
+    pub fn as_adjustment(&self) -> Result<Option<MonetaryAdjustment>, AdjustmentError> {
+        let Some(cost) = &self.cost else {
+            return Ok(None);
+        };
+        Ok(Some(MonetaryAdjustment {
+            id: self.id.clone(),
+            amount: i64::try_from(cost.amount).map_err(|_| AdjustmentError::Overflow)?,
+            currency: cost.currency,
+            date: self.date,
+            reason: self.description.clone(),
+        }))
+    }
+}
+
+/// The kinds of modification made to rolling stocks.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, specta::Type,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[strum(ascii_case_insensitive)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ModificationType {
+    /// Paint and powders making the model look used.
+    Weathering,
+    /// Added or changed lights, for example interior lighting.
+    Lighting,
+    /// Replaced couplers, for example close couplers or knuckle couplers.
+    Couplers,
+    /// Added details such as handrails, hoses or figures.
+    Detailing,
+    /// A new livery or numbering.
+    Repaint,
+    /// Any other change.
+    Other,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::Currency;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use std::str::FromStr;
+
+    #[rstest]
+    #[case(ModificationType::Weathering, "WEATHERING")]
+    #[case(ModificationType::Couplers, "COUPLERS")]
+    #[case(ModificationType::Other, "OTHER")]
+    fn modification_types_round_trip(#[case] input: ModificationType, #[case] expected: &str) {
+        assert_eq!(input.to_string(), expected);
+        assert_eq!(ModificationType::from_str(expected), Ok(input));
+    }
+
+    #[test]
+    fn costs_count_as_positive_adjustments() {
+        let mut modification = Modification {
+            id: "mod-1".to_string(),
+            modification_type: ModificationType::Lighting,
+            description: "interior lighting".to_string(),
+            date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            cost: Some(MonetaryAmount::new(2_490, Currency::EUR)),
+        };
+
+        let adjustment = modification.as_adjustment().unwrap().unwrap();
+        assert_eq!(adjustment.amount, 2_490);
+        assert_eq!(adjustment.currency, Currency::EUR);
+        assert_eq!(adjustment.reason, "interior lighting");
+        modification.cost = None;
+        assert_eq!(modification.as_adjustment(), Ok(None));
+    }
+}
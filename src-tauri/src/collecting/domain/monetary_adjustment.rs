@@ -0,0 +1,81 @@
+use crate::core::domain::currency::Currency;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A signed correction of what was paid for a collection item.
+///
+/// Adjustments are recorded against a purchase info instead of editing its
+/// price, so the history is kept: a partial refund from the shop is a
+/// negative adjustment, a fee paid afterwards (for example customs) a
+/// positive one. They are netted out of the purchase cost by
+/// `PurchaseInfo::acquisition_cost` and of the collection total value.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct MonetaryAdjustment {
+    /// Unique identifier for this adjustment.
+    pub id: String,
+
+    /// The signed amount in the smallest currency unit; negative for refunds.
+    pub amount: i64,
+
+    /// Currency of the amount; the same as the adjusted purchase price.
+    pub currency: Currency,
+
+    /// Date of the adjustment (ISO `YYYY-MM-DD`).
+    pub date: NaiveDate,
+
+    /// Why the cost was adjusted, for example "partial refund, damaged box".
+    pub reason: String,
+}
+
+/// Why adjustments cannot be applied to a purchase cost.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AdjustmentError {
+    /// The purchase has no known price to adjust.
+    #[error("the purchase has no known price to adjust")]
+    UnknownCost,
+
+    /// An adjustment is in another currency than the purchase price.
+    #[error("adjustment in {adjustment:?} does not match the purchase currency {purchase:?}")]
+    CurrencyMismatch {
+        purchase: Currency,
+        adjustment: Currency,
+    },
+
+    /// The adjustments would bring the cost below zero, for example a refund
+    /// larger than the price paid.
+    #[error("adjustments would bring the cost of {cost} below zero, to {adjusted}")]
+    NegativeCost { cost: u64, adjusted: i128 },
+
+    /// The adjusted cost does not fit a monetary amount.
+    #[error("adjusted cost overflow")]
+    Overflow,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn adjustments_serialize_with_their_signed_amount() {
+        let adjustment = MonetaryAdjustment {
+            id: "adj-1".to_string(),
+            amount: -500,
+            currency: Currency::EUR,
+            date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            reason: "partial refund".to_string(),
+        };
+
+        let json = serde_json::to_string(&adjustment).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"id":"adj-1","amount":-500,"currency":"EUR","date":"2024-03-01","reason":"partial refund"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<MonetaryAdjustment>(&json).unwrap(),
+            adjustment
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::collecting::domain::decoder::Decoder;
 use serde::{Deserialize, Serialize};
 
 /// A lightweight view of rolling stock that references catalog model data.
@@ -13,10 +14,24 @@ pub struct OwnedRollingStock {
     /// Unique identifier for this owned rolling stock record (e.g. UUID in the DB).
     pub id: String,
 
-    /// Identifier of the related rolling stock in the catalog (or the owned rolling stock id when catalog id is not available).
-    pub rolling_stock_id: String,
+    /// Identifier of the related rolling stock in the catalog, or `None` when
+    /// the owned instance is not linked to a catalog rolling stock (or the
+    /// linked one no longer exists).
+    pub rolling_stock_id: Option<String>,
 
     /// Free-form notes associated with this owned instance.
     /// Use this for short owner notes or a brief textual label.
     pub notes: String,
+
+    /// The DCC decoder installed in this rolling stock, if any.
+    pub decoder: Option<Decoder>,
+
+    /// The storage location of this rolling stock when it is kept apart from
+    /// the rest of its item, for example a car of a split set; `None` when it
+    /// is kept with the item.
+    pub location_id: Option<String>,
+
+    /// Whether any modification (weathering, lighting, ...) was recorded for
+    /// this rolling stock.
+    pub is_modified: bool,
 }
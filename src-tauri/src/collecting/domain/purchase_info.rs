@@ -1,6 +1,8 @@
-use crate::core::domain::MonetaryAmount;
+use crate::collecting::domain::monetary_adjustment::{AdjustmentError, MonetaryAdjustment};
+use crate::core::domain::{Currency, MonetaryAmount};
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Purchase information associated with a `CollectionItem`.
 ///
@@ -9,12 +11,14 @@ use serde::{Deserialize, Serialize};
 /// three supported acquisition states:
 ///
 /// - `Purchased`: the item was purchased and its value is counted in the
-///   collection total (unless explicitly excluded elsewhere).
+///   collection total.
 /// - `Sold`: the item was sold; we keep a record of the original purchase
 ///   and the sale, but the item is excluded from the collection's monetary
-///   total value.
+///   total value and counts in its realized gains instead.
 /// - `PreOrdered`: the item is pre-ordered from a seller; the collector may
 ///   have paid a deposit and the full total price is known as well.
+///
+/// `CollectionValuePolicy` is the single place implementing these rules.
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
 #[serde(tag = "type", rename_all = "lowercase")]
 #[specta(tag = "kind", content = "data")]
@@ -52,6 +56,65 @@ impl PurchaseInfo {
             PurchaseInfo::PreOrdered(po) => po.seller.as_deref(),
         }
     }
+
+    /// Return the price paid for a purchased or sold item, if known.
+    ///
+    /// Preorders are not paid yet, so they have none.
+    pub fn purchase_price(&self) -> Option<&MonetaryAmount> {
+        match self {
+            PurchaseInfo::Purchased(p) => p.price.as_ref(),
+            PurchaseInfo::Sold(s) => s.purchase_price.as_ref(),
+            PurchaseInfo::PreOrdered(_) => None,
+        }
+    }
+
+    /// Return what the item cost, net of `adjustments`.
+    ///
+    /// The cost is the price paid for purchased and sold items and the total
+    /// price of a preorder; `None` when the price is unknown and there is
+    /// nothing to adjust.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AdjustmentError` when there are adjustments but no known
+    /// price, when an adjustment is in another currency, or when the
+    /// adjustments would bring the cost below zero.
+    pub fn acquisition_cost(
+        &self,
+        adjustments: &[MonetaryAdjustment],
+    ) -> Result<Option<MonetaryAmount>, AdjustmentError> {
+        let price = match self {
+            PurchaseInfo::Purchased(p) => p.price.as_ref(),
+            PurchaseInfo::Sold(s) => s.purchase_price.as_ref(),
+            PurchaseInfo::PreOrdered(po) => Some(&po.total_price),
+        };
+        let Some(price) = price else {
+            return if adjustments.is_empty() {
+                Ok(None)
+            } else {
+                Err(AdjustmentError::UnknownCost)
+            };
+        };
+
+        let mut adjusted = i128::from(price.amount);
+        for adjustment in adjustments {
+            if adjustment.currency != price.currency {
+                return Err(AdjustmentError::CurrencyMismatch {
+                    purchase: price.currency,
+                    adjustment: adjustment.currency,
+                });
+            }
+            adjusted += i128::from(adjustment.amount);
+        }
+        if adjusted < 0 {
+            return Err(AdjustmentError::NegativeCost {
+                cost: price.amount,
+                adjusted,
+            });
+        }
+        let amount = u64::try_from(adjusted).map_err(|_| AdjustmentError::Overflow)?;
+        Ok(Some(MonetaryAmount::new(amount, price.currency)))
+    }
 }
 
 /// Details for a purchased item.
@@ -76,12 +139,50 @@ pub struct PurchasedInfo {
     pub seller: Option<String>,
 }
 
+/// A purchase entered by the collector, before it is stored.
+///
+/// The currency may be left out, in which case the purchase is recorded in
+/// the preferred currency of the settings (see `AppSettings`).
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct NewPurchase {
+    /// Date when the item was purchased (ISO `YYYY-MM-DD`).
+    pub purchase_date: NaiveDate,
+
+    /// The price paid in the smallest unit of the currency, if known.
+    pub price: Option<u64>,
+
+    /// The currency of the price, or `None` for the preferred currency.
+    pub currency: Option<Currency>,
+
+    /// Optional seller identifier or human-friendly name.
+    pub seller: Option<String>,
+}
+
+impl NewPurchase {
+    /// The purchase with `id`, its price in `default_currency` when no
+    /// currency was entered; a blank seller is treated as missing.
+    pub fn into_purchased_info(self, id: String, default_currency: Currency) -> PurchasedInfo {
+        let currency = self.currency.unwrap_or(default_currency);
+        PurchasedInfo {
+            id,
+            purchase_date: self.purchase_date,
+            price: self
+                .price
+                .map(|amount| MonetaryAmount::new(amount, currency)),
+            seller: self
+                .seller
+                .map(|seller| seller.trim().to_string())
+                .filter(|seller| !seller.is_empty()),
+        }
+    }
+}
+
 /// Details for an item that was sold.
 ///
 /// We keep both the original purchase information and the sale data so the
 /// application can show provenance (what was paid originally) together with
-/// the sale outcome. The item should be excluded from collection value
-/// aggregations once sold.
+/// the sale outcome. Once sold, the item is excluded from collection value
+/// aggregations (see `CollectionValuePolicy`).
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
 pub struct SoldInfo {
     /// Unique identifier for the original purchase record (or the sale record,
@@ -116,9 +217,11 @@ pub struct SoldInfo {
 /// Details for a pre-order entry.
 ///
 /// Preorders record at least the deposit paid and the total price expected
-/// for the item. Both monetary amounts must use the same currency; use the
-/// `validate_currencies_match` helper to assert that condition.
+/// for the item. Both monetary amounts must use the same currency and the
+/// deposit cannot exceed the total price (see `validate`); deserializing a
+/// preorder that breaks either rule fails.
 #[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(try_from = "RawPreOrderInfo")]
 pub struct PreOrderInfo {
     /// Unique identifier for this preorder record.
     pub id: String,
@@ -141,18 +244,83 @@ pub struct PreOrderInfo {
 
 impl PreOrderInfo {
     /// Validate that the preorder `deposit` and `total_price` share the same
-    /// currency.
-    ///
-    /// Returns `Ok(())` when currencies match, otherwise returns
-    /// `crate::core::domain::error::Error::CurrencyMismatch`.
-    pub fn validate_currencies_match(&self) -> Result<(), crate::core::domain::error::Error> {
-        if self.deposit.currency != self.total_price.currency {
-            return Err(crate::core::domain::error::Error::CurrencyMismatch);
+    /// currency and that the deposit does not exceed the total price.
+    pub fn validate(&self) -> Result<(), PreOrderError> {
+        Self::validate_amounts(&self.deposit, &self.total_price)
+    }
+
+    /// The rules of `validate`, for a `deposit` and `total_price` that are
+    /// not part of a `PreOrderInfo` yet (for example a row to be stored).
+    pub fn validate_amounts(
+        deposit: &MonetaryAmount,
+        total_price: &MonetaryAmount,
+    ) -> Result<(), PreOrderError> {
+        if deposit.currency != total_price.currency {
+            return Err(PreOrderError::CurrencyMismatch {
+                deposit: deposit.currency,
+                total_price: total_price.currency,
+            });
+        }
+        if deposit.amount > total_price.amount {
+            return Err(PreOrderError::DepositExceedsTotal {
+                deposit: deposit.amount,
+                total_price: total_price.amount,
+            });
         }
         Ok(())
     }
 }
 
+/// The serialized form of a `PreOrderInfo`, validated on the way in.
+#[derive(Deserialize)]
+struct RawPreOrderInfo {
+    id: String,
+    order_date: NaiveDate,
+    deposit: MonetaryAmount,
+    total_price: MonetaryAmount,
+    seller: Option<String>,
+    expected_date: Option<NaiveDate>,
+}
+
+impl TryFrom<RawPreOrderInfo> for PreOrderInfo {
+    type Error = PreOrderError;
+
+    fn try_from(raw: RawPreOrderInfo) -> Result<Self, Self::Error> {
+        let preorder = PreOrderInfo {
+            id: raw.id,
+            order_date: raw.order_date,
+            deposit: raw.deposit,
+            total_price: raw.total_price,
+            seller: raw.seller,
+            expected_date: raw.expected_date,
+        };
+        preorder.validate()?;
+        Ok(preorder)
+    }
+}
+
+/// Why the deposit of a preorder is inconsistent with its total price.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PreOrderError {
+    /// The deposit and the total price are in different currencies.
+    #[error("the deposit is in {deposit:?} but the total price is in {total_price:?}")]
+    CurrencyMismatch {
+        deposit: Currency,
+        total_price: Currency,
+    },
+
+    /// More was paid as deposit than the item costs.
+    #[error("the deposit of {deposit} exceeds the total price of {total_price}")]
+    DepositExceedsTotal { deposit: u64, total_price: u64 },
+}
+
+impl PreOrderError {
+    /// The `PreOrderInfo` field the error is reported on.
+    pub fn field(&self) -> &'static str {
+        "deposit"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -173,6 +341,35 @@ mod tests {
         assert_eq!(pi.seller(), Some("shop-1"));
     }
 
+    #[test]
+    fn new_purchases_fall_back_to_the_default_currency() {
+        let purchase = NewPurchase {
+            purchase_date: NaiveDate::from_ymd_opt(2024, 3, 15).unwrap(),
+            price: Some(12990),
+            currency: None,
+            seller: Some("  ".to_string()),
+        };
+
+        let in_default = purchase
+            .clone()
+            .into_purchased_info("p1".to_string(), Currency::GBP);
+        assert_eq!(
+            in_default.price,
+            Some(MonetaryAmount::new(12990, Currency::GBP))
+        );
+        assert_eq!(in_default.seller, None);
+
+        let entered = NewPurchase {
+            currency: Some(Currency::USD),
+            ..purchase
+        }
+        .into_purchased_info("p1".to_string(), Currency::GBP);
+        assert_eq!(
+            entered.price,
+            Some(MonetaryAmount::new(12990, Currency::USD))
+        );
+    }
+
     #[test]
     fn sold_id_and_seller_accessor() {
         let s = SoldInfo {
@@ -203,7 +400,129 @@ mod tests {
         let pi = PurchaseInfo::PreOrdered(preorder.clone());
         // seller is None
         assert_eq!(pi.seller(), None);
-        // validate currencies should fail due to mismatch
-        assert!(preorder.validate_currencies_match().is_err());
+        // validate should fail due to the currency mismatch
+        assert_eq!(
+            preorder.validate(),
+            Err(PreOrderError::CurrencyMismatch {
+                deposit: Currency::EUR,
+                total_price: Currency::USD,
+            })
+        );
+    }
+
+    fn preorder_json(deposit: (u64, &str), total_price: (u64, &str)) -> String {
+        format!(
+            r#"{{"type":"preordered","id":"pre1","order_date":"2025-06-01","deposit":{{"amount":{},"currency":"{}"}},"total_price":{{"amount":{},"currency":"{}"}},"seller":null,"expected_date":null}}"#,
+            deposit.0, deposit.1, total_price.0, total_price.1
+        )
+    }
+
+    #[test]
+    fn preorders_deserialize_only_when_consistent() {
+        let ok = serde_json::from_str::<PurchaseInfo>(&preorder_json((500, "EUR"), (1000, "EUR")));
+        assert!(matches!(ok, Ok(PurchaseInfo::PreOrdered(_))));
+
+        let mismatch =
+            serde_json::from_str::<PurchaseInfo>(&preorder_json((500, "EUR"), (1000, "USD")))
+                .unwrap_err();
+        assert!(
+            mismatch.to_string().contains("total price is in USD"),
+            "{mismatch}"
+        );
+
+        let exceeding =
+            serde_json::from_str::<PurchaseInfo>(&preorder_json((1500, "EUR"), (1000, "EUR")))
+                .unwrap_err();
+        assert!(
+            exceeding
+                .to_string()
+                .contains("deposit of 1500 exceeds the total price of 1000"),
+            "{exceeding}"
+        );
+    }
+
+    #[test]
+    fn a_deposit_may_cover_the_whole_price() {
+        let deposit = MonetaryAmount::new(1000, Currency::EUR);
+
+        assert_eq!(PreOrderInfo::validate_amounts(&deposit, &deposit), Ok(()));
+        assert_eq!(
+            PreOrderInfo::validate_amounts(&MonetaryAmount::new(1001, Currency::EUR), &deposit),
+            Err(PreOrderError::DepositExceedsTotal {
+                deposit: 1001,
+                total_price: 1000,
+            })
+        );
+    }
+
+    fn purchased(price: Option<MonetaryAmount>) -> PurchaseInfo {
+        PurchaseInfo::Purchased(PurchasedInfo {
+            id: "p1".to_string(),
+            purchase_date: NaiveDate::from_ymd_opt(2023, 10, 1).unwrap(),
+            price,
+            seller: None,
+        })
+    }
+
+    fn adjustment(amount: i64, currency: Currency) -> MonetaryAdjustment {
+        MonetaryAdjustment {
+            id: format!("adj{amount}"),
+            amount,
+            currency,
+            date: NaiveDate::from_ymd_opt(2023, 11, 1).unwrap(),
+            reason: "refund".to_string(),
+        }
+    }
+
+    #[test]
+    fn acquisition_cost_nets_out_adjustments() {
+        let pi = purchased(Some(MonetaryAmount::new(1500, Currency::EUR)));
+
+        let cost = pi
+            .acquisition_cost(&[
+                adjustment(-500, Currency::EUR),
+                adjustment(200, Currency::EUR),
+            ])
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(cost.amount, 1200);
+        assert_eq!(cost.currency, Currency::EUR);
+        assert!(matches!(purchased(None).acquisition_cost(&[]), Ok(None)));
+    }
+
+    #[test]
+    fn acquisition_cost_rejects_invalid_adjustments() {
+        let pi = purchased(Some(MonetaryAmount::new(1500, Currency::EUR)));
+
+        assert_eq!(
+            pi.acquisition_cost(&[adjustment(-1501, Currency::EUR)])
+                .unwrap_err(),
+            AdjustmentError::NegativeCost {
+                cost: 1500,
+                adjusted: -1,
+            }
+        );
+        assert_eq!(
+            pi.acquisition_cost(&[adjustment(-100, Currency::USD)])
+                .unwrap_err(),
+            AdjustmentError::CurrencyMismatch {
+                purchase: Currency::EUR,
+                adjustment: Currency::USD,
+            }
+        );
+        assert_eq!(
+            purchased(None)
+                .acquisition_cost(&[adjustment(-100, Currency::EUR)])
+                .unwrap_err(),
+            AdjustmentError::UnknownCost
+        );
+        // Refunding the whole price is fine.
+        assert_eq!(
+            pi.acquisition_cost(&[adjustment(-1500, Currency::EUR)])
+                .unwrap()
+                .map(|cost| cost.amount),
+            Some(0)
+        );
     }
 }
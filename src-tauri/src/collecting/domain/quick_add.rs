@@ -0,0 +1,343 @@
+//! Parsing of the one-line quick-add entries of the command palette.
+//!
+//! An entry like `acme 60211 2x €129.90 2024-03-15` names the model to add
+//! (an optional manufacturer hint and its product code), how many were
+//! bought, the price paid and the purchase date; only the product code is
+//! required and the other parts may come in any order. Parsing only reads
+//! the text: `ParseQuickAddUseCase` then looks the model up in the catalog.
+
+use crate::catalog::domain::ProductCode;
+use crate::catalog::domain::railway_model_summary::RailwayModelSummary;
+use crate::core::domain::MonetaryAmount;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A collection item typed in the command palette, to confirm before it is
+/// added.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct QuickAddDraft {
+    /// The start of the manufacturer name, for example "acme", when typed.
+    pub manufacturer_hint: Option<String>,
+
+    /// The product code of the model.
+    pub product_code: ProductCode,
+
+    /// How many were bought; 1 when not typed.
+    pub quantity: u32,
+
+    /// The price paid, when typed.
+    pub price: Option<MonetaryAmount>,
+
+    /// The purchase date, when typed.
+    pub purchase_date: Option<NaiveDate>,
+}
+
+/// Why a quick-add entry cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum QuickAddError {
+    #[error("nothing to add")]
+    Empty,
+
+    /// The entry has a quantity, price or date but no model.
+    #[error("the product code is missing")]
+    MissingProductCode,
+
+    #[error("the quantity must be at least 1 (value: {0})")]
+    InvalidQuantity(String),
+
+    /// A quantity, price or date was typed twice.
+    #[error("the {0} is given more than once")]
+    Repeated(&'static str),
+}
+
+impl QuickAddError {
+    /// The draft field the error is about.
+    pub fn field(&self) -> &'static str {
+        match self {
+            QuickAddError::Empty | QuickAddError::MissingProductCode => "product_code",
+            QuickAddError::InvalidQuantity(_) => "quantity",
+            QuickAddError::Repeated(field) => field,
+        }
+    }
+}
+
+/// The catalog models a quick-add draft may refer to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum QuickAddMatch {
+    /// A single model has the product code (from the hinted manufacturer).
+    Resolved { model: RailwayModelSummary },
+
+    /// Several models have the product code, or none has it exactly but some
+    /// come close; the collector picks one.
+    Ambiguous {
+        candidates: Vec<RailwayModelSummary>,
+    },
+
+    /// No model comes close.
+    NotFound,
+}
+
+/// A parsed quick-add entry together with the models it may refer to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct QuickAddProposal {
+    pub draft: QuickAddDraft,
+    pub model: QuickAddMatch,
+}
+
+/// Parse a quick-add entry.
+///
+/// The parts are separated by whitespace:
+/// - a quantity is a number with an `x` before or after it (`2x`, `x2`);
+/// - a price is anything `MonetaryAmount::parse` reads, also split around the
+///   currency code (`129.90 EUR`);
+/// - a date is ISO 8601 (`2024-03-15`);
+/// - of the other words, the last one with a digit (or the last one when
+///   none has a digit) is the product code and the words before it are the
+///   manufacturer hint.
+///
+/// # Errors
+///
+/// Returns `QuickAddError::Empty` for a blank entry,
+/// `QuickAddError::MissingProductCode` when only a quantity, price or date is
+/// typed, `QuickAddError::InvalidQuantity` for a zero quantity and
+/// `QuickAddError::Repeated` when a quantity, price or date is typed twice.
+pub fn parse_quick_add(input: &str) -> Result<QuickAddDraft, QuickAddError> {
+    let tokens: Vec<&str> = input.split_whitespace().collect();
+    if tokens.is_empty() {
+        return Err(QuickAddError::Empty);
+    }
+
+    let mut quantity = None;
+    let mut price = None;
+    let mut purchase_date = None;
+    let mut words = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = tokens[i];
+        if let Some(q) = parse_quantity(token)? {
+            set_once(&mut quantity, q, "quantity")?;
+        } else if let Ok(date) = NaiveDate::parse_from_str(token, "%Y-%m-%d") {
+            set_once(&mut purchase_date, date, "purchase_date")?;
+        } else if let Ok(amount) = MonetaryAmount::parse(token) {
+            set_once(&mut price, amount, "price")?;
+        } else if let Some(amount) = tokens
+            .get(i + 1)
+            .and_then(|next| MonetaryAmount::parse(&format!("{token} {next}")).ok())
+        {
+            set_once(&mut price, amount, "price")?;
+            i += 1;
+        } else {
+            words.push(token);
+        }
+        i += 1;
+    }
+
+    let code_at = words
+        .iter()
+        .rposition(|word| word.chars().any(|c| c.is_ascii_digit()))
+        .or_else(|| words.len().checked_sub(1))
+        .ok_or(QuickAddError::MissingProductCode)?;
+    let product_code =
+        ProductCode::try_from(words[code_at]).map_err(|_| QuickAddError::MissingProductCode)?;
+    let hint = words[..code_at].join(" ");
+
+    Ok(QuickAddDraft {
+        manufacturer_hint: (!hint.is_empty()).then_some(hint),
+        product_code,
+        quantity: quantity.unwrap_or(1),
+        price,
+        purchase_date,
+    })
+}
+
+/// The quantity in `token` (`2x`, `X2`), `None` when it is not a quantity.
+fn parse_quantity(token: &str) -> Result<Option<u32>, QuickAddError> {
+    let lower = token.to_ascii_lowercase();
+    let Some(digits) = lower
+        .strip_suffix('x')
+        .or_else(|| lower.strip_prefix('x'))
+        .filter(|d| !d.is_empty() && d.chars().all(|c| c.is_ascii_digit()))
+    else {
+        return Ok(None);
+    };
+    match digits.parse::<u32>() {
+        Ok(quantity) if quantity > 0 => Ok(Some(quantity)),
+        _ => Err(QuickAddError::InvalidQuantity(token.to_string())),
+    }
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T, field: &'static str) -> Result<(), QuickAddError> {
+    if slot.replace(value).is_some() {
+        return Err(QuickAddError::Repeated(field));
+    }
+    Ok(())
+}
+
+impl QuickAddMatch {
+    /// The match of `draft` among the catalog `found` searching for its
+    /// product code: the models with exactly that product code (ignoring
+    /// case) from a manufacturer starting with the hint, or when there are
+    /// none the other models found from such a manufacturer.
+    pub fn among(draft: &QuickAddDraft, found: Vec<RailwayModelSummary>) -> Self {
+        let hint = draft.manufacturer_hint.as_deref().map(str::to_lowercase);
+        let (exact, close): (Vec<_>, Vec<_>) = found
+            .into_iter()
+            .filter(|model| {
+                hint.as_deref()
+                    .is_none_or(|hint| model.manufacturer.to_lowercase().starts_with(hint))
+            })
+            .partition(|model| model.product_code.eq_ignore_ascii_case(&draft.product_code));
+
+        match (exact.len(), close.is_empty()) {
+            (1, _) => QuickAddMatch::Resolved {
+                model: exact.into_iter().next().expect("one exact match"),
+            },
+            (0, true) => QuickAddMatch::NotFound,
+            (0, false) => QuickAddMatch::Ambiguous { candidates: close },
+            _ => QuickAddMatch::Ambiguous { candidates: exact },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::domain::Scale;
+    use crate::catalog::domain::railway_model_id::RailwayModelId;
+    use crate::core::domain::Currency;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn summary(manufacturer: &str, product_code: &str) -> RailwayModelSummary {
+        RailwayModelSummary {
+            id: RailwayModelId::new(),
+            manufacturer: manufacturer.to_string(),
+            product_code: ProductCode::try_from(product_code).unwrap(),
+            description: "Electric locomotive".to_string(),
+            scale: Scale::H0,
+        }
+    }
+
+    #[test]
+    fn full_entries_are_parsed() {
+        let draft = parse_quick_add("acme 60211 2x €129.90 2024-03-15").unwrap();
+
+        assert_eq!(draft.manufacturer_hint.as_deref(), Some("acme"));
+        assert_eq!(draft.product_code.to_string(), "60211");
+        assert_eq!(draft.quantity, 2);
+        let price = draft.price.unwrap();
+        assert_eq!((price.amount, price.currency), (12990, Currency::EUR));
+        assert_eq!(draft.purchase_date, Some(date(2024, 3, 15)));
+    }
+
+    #[test]
+    fn parts_come_in_any_order() {
+        let draft = parse_quick_add("2024-03-15 x3 129,90 EUR Roco 73262").unwrap();
+
+        assert_eq!(draft.manufacturer_hint.as_deref(), Some("Roco"));
+        assert_eq!(draft.product_code.to_string(), "73262");
+        assert_eq!(draft.quantity, 3);
+        assert_eq!(draft.price.unwrap().amount, 12990);
+        assert_eq!(draft.purchase_date, Some(date(2024, 3, 15)));
+    }
+
+    #[rstest]
+    #[case("60211", None, "60211")]
+    #[case("  HR2877  ", None, "HR2877")]
+    #[case("Rivarossi HR2877", Some("Rivarossi"), "HR2877")]
+    #[case("Hornby Rivarossi HR2877", Some("Hornby Rivarossi"), "HR2877")]
+    #[case("acme E656 red", Some("acme"), "E656")]
+    #[case("acme", None, "acme")]
+    #[case("acme big", Some("acme"), "big")]
+    fn partial_entries_have_defaults(
+        #[case] input: &str,
+        #[case] hint: Option<&str>,
+        #[case] product_code: &str,
+    ) {
+        let draft = parse_quick_add(input).unwrap();
+
+        assert_eq!(draft.manufacturer_hint.as_deref(), hint);
+        assert_eq!(draft.product_code.to_string(), product_code);
+        assert_eq!(draft.quantity, 1);
+        assert!(draft.price.is_none());
+        assert_eq!(draft.purchase_date, None);
+    }
+
+    #[test]
+    fn numbers_without_a_currency_are_product_codes() {
+        let draft = parse_quick_add("acme 129.90").unwrap();
+        assert_eq!(draft.product_code.to_string(), "129.90");
+        assert!(draft.price.is_none());
+    }
+
+    #[rstest]
+    #[case("", QuickAddError::Empty)]
+    #[case("   ", QuickAddError::Empty)]
+    #[case("2x €129.90", QuickAddError::MissingProductCode)]
+    #[case("2024-03-15", QuickAddError::MissingProductCode)]
+    #[case("acme 60211 0x", QuickAddError::InvalidQuantity("0x".to_string()))]
+    #[case("acme 60211 2x 3x", QuickAddError::Repeated("quantity"))]
+    #[case("acme 60211 €10 $12", QuickAddError::Repeated("price"))]
+    #[case(
+        "60211 2024-03-15 2024-03-16",
+        QuickAddError::Repeated("purchase_date")
+    )]
+    fn invalid_entries_are_rejected(#[case] input: &str, #[case] expected: QuickAddError) {
+        assert_eq!(parse_quick_add(input), Err(expected));
+    }
+
+    #[test]
+    fn a_single_exact_match_is_resolved() {
+        let draft = parse_quick_add("acme 60211").unwrap();
+        let exact = summary("ACME", "60211");
+        let found = vec![
+            exact.clone(),
+            summary("ACME", "60211A"),
+            summary("Roco", "60211"),
+        ];
+
+        assert_eq!(
+            QuickAddMatch::among(&draft, found),
+            QuickAddMatch::Resolved { model: exact }
+        );
+    }
+
+    #[test]
+    fn several_exact_matches_are_ambiguous() {
+        let draft = parse_quick_add("60211").unwrap();
+        let acme = summary("ACME", "60211");
+        let roco = summary("Roco", "60211");
+        let found = vec![acme.clone(), roco.clone(), summary("ACME", "60211A")];
+
+        assert_eq!(
+            QuickAddMatch::among(&draft, found),
+            QuickAddMatch::Ambiguous {
+                candidates: vec![acme, roco]
+            }
+        );
+    }
+
+    #[test]
+    fn close_matches_are_candidates() {
+        let draft = parse_quick_add("acme 6021").unwrap();
+        let close = summary("ACME", "60211");
+        let found = vec![close.clone(), summary("Roco", "60210")];
+
+        assert_eq!(
+            QuickAddMatch::among(&draft, found),
+            QuickAddMatch::Ambiguous {
+                candidates: vec![close]
+            }
+        );
+        assert_eq!(
+            QuickAddMatch::among(&draft, vec![summary("Roco", "60210")]),
+            QuickAddMatch::NotFound
+        );
+    }
+}
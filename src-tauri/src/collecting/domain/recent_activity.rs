@@ -0,0 +1,48 @@
+//! The latest things that happened to the items of the collection, for the
+//! home screen.
+//!
+//! Items added and modified are known from their `created_at` and
+//! `updated_at` timestamps; views are recorded on purpose when the collector
+//! opens an item. Only the latest `MAX_VIEWS_PER_ITEM` views of each item are
+//! kept, so the views cannot grow without bound.
+
+use crate::catalog::domain::railway_model_summary::RailwayModelSummary;
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+/// How many views of each collection item are kept.
+pub const MAX_VIEWS_PER_ITEM: u32 = 10;
+
+/// What happened to a collection item.
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, EnumString, Display, Serialize, Deserialize, specta::Type,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ActivityKind {
+    /// The item was added to the collection.
+    Added,
+    /// The item was last updated.
+    Modified,
+    /// The collector last opened the item.
+    Viewed,
+}
+
+/// Something that happened to a collection item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ActivityEvent {
+    /// What happened.
+    pub kind: ActivityKind,
+
+    /// The collection item it happened to.
+    pub collection_item_id: CollectionItemId,
+
+    /// The manufacturer, product code and description of the item's
+    /// railway model, or `None` when it is missing from the catalog.
+    pub railway_model: Option<RailwayModelSummary>,
+
+    /// When it happened (UTC).
+    pub occurred_at: DateTime<Utc>,
+}
@@ -0,0 +1,40 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::summary::CollectionSummary;
+use crate::core::domain::Currency;
+use serde::{Deserialize, Serialize};
+
+/// The outcome of recomputing a collection's summary counters and total value.
+///
+/// Both the values stored before the recomputation and the freshly computed
+/// ones are reported, so the UI can show what changed (for example
+/// "locomotives: 12 → 13").
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CollectionRecomputeDiff {
+    /// The recomputed collection.
+    pub collection_id: CollectionId,
+
+    /// The summary counters stored before the recomputation.
+    pub old_summary: CollectionSummary,
+
+    /// The summary counters computed from the collection items.
+    pub new_summary: CollectionSummary,
+
+    /// The total value per currency, before and after the recomputation.
+    pub total_values: Vec<TotalValueDiff>,
+}
+
+/// The total value of a collection in a single currency, before and after a
+/// recomputation.
+///
+/// Amounts are in the smallest currency unit, as in `MonetaryAmount`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct TotalValueDiff {
+    /// The currency of both amounts.
+    pub currency: Currency,
+
+    /// The amount before the recomputation.
+    pub old_amount: u64,
+
+    /// The amount after the recomputation.
+    pub new_amount: u64,
+}
@@ -1,6 +1,363 @@
+use crate::collecting::domain::bulk_update::{
+    BulkUpdateOptions, BulkUpdateReport, CollectionItemFilter, CollectionItemPatch,
+};
 use crate::collecting::domain::collection::Collection;
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::collection_item::CollectionItem;
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::consist::{Consist, ConsistDetails};
+use crate::collecting::domain::consistency::ConsistencyReport;
+use crate::collecting::domain::cost_breakdown::ItemCostBreakdown;
+use crate::collecting::domain::decoder::{AddressConflict, Decoder, DecoderWarning};
+use crate::collecting::domain::display_currency::StoredItemAmounts;
+use crate::collecting::domain::insurance_report::{InsuranceReport, ValuationMode};
+use crate::collecting::domain::item_duplication::DuplicateItemOptions;
+use crate::collecting::domain::loan::Loan;
+use crate::collecting::domain::modification::Modification;
+use crate::collecting::domain::monetary_adjustment::MonetaryAdjustment;
+use crate::collecting::domain::purchase_info::PurchasedInfo;
+use crate::collecting::domain::recent_activity::ActivityEvent;
+use crate::collecting::domain::recompute_diff::CollectionRecomputeDiff;
+use crate::collecting::domain::statistics::CollectionStatistics;
+use crate::collecting::domain::storage_location::{StorageLocation, StoredItem};
+use crate::collecting::domain::value_snapshot::ValueSnapshot;
+use crate::collecting::domain::year_review::YearReview;
+use crate::core::domain::page::{KeysetPage, PageKey};
+use chrono::{DateTime, NaiveDate, Utc};
 
 #[async_trait::async_trait]
 pub trait CollectionRepository: Send + Sync {
     async fn get_collection(&self) -> anyhow::Result<Collection>;
+
+    /// List up to `limit` items of the collection in the order they were
+    /// added, starting after the item with the `after` keys (from the first
+    /// one when `None`).
+    async fn list_collection_items(
+        &self,
+        after: Option<&PageKey>,
+        limit: u32,
+    ) -> anyhow::Result<KeysetPage<CollectionItem>>;
+
+    /// The item of the collection with `collection_id` numbered
+    /// `item_number`, or `None` when there is no such item.
+    async fn find_item_by_number(
+        &self,
+        collection_id: &CollectionId,
+        item_number: u32,
+    ) -> anyhow::Result<Option<CollectionItem>>;
+
+    /// Add a copy of the collection item with `item_id` to its collection,
+    /// returning the id of the copy (see `DuplicateItemOptions` for what is
+    /// copied).
+    ///
+    /// Unless `options.allow_duplicates` is set, fails with
+    /// `DuplicateItemError::AlreadyInCollection` when the collection already
+    /// has items for the railway model; nothing is stored then.
+    async fn duplicate_collection_item(
+        &self,
+        item_id: &CollectionItemId,
+        options: &DuplicateItemOptions,
+    ) -> anyhow::Result<CollectionItemId>;
+
+    /// Apply `patch` to every collection item matching `filter` in a single
+    /// transaction, reporting how many items changed.
+    ///
+    /// Fails with `BulkUpdateError::LimitExceeded`, writing nothing, when
+    /// more items would change than `options` allow; a dry run only counts
+    /// them.
+    async fn bulk_update_items(
+        &self,
+        filter: &CollectionItemFilter,
+        patch: &CollectionItemPatch,
+        options: &BulkUpdateOptions,
+    ) -> anyhow::Result<BulkUpdateReport>;
+
+    /// Record `purchase` as the purchase info of the collection item with
+    /// `item_id`.
+    ///
+    /// Fails when the item does not exist or already has a purchase info.
+    async fn add_purchase(
+        &self,
+        item_id: &CollectionItemId,
+        purchase: &PurchasedInfo,
+    ) -> anyhow::Result<()>;
+
+    /// The stored price and replacement value of every item of a
+    /// collection, in the order the items were added.
+    async fn list_stored_item_amounts(
+        &self,
+        collection_id: &CollectionId,
+    ) -> anyhow::Result<Vec<StoredItemAmounts>>;
+
+    /// Recompute the summary counters and total value of a collection from
+    /// its items, returning what changed.
+    async fn recompute_collection(
+        &self,
+        collection_id: &CollectionId,
+    ) -> anyhow::Result<CollectionRecomputeDiff>;
+
+    /// Check a collection against the catalog it refers to (see
+    /// `ConsistencyReport`).
+    ///
+    /// With `repair` the safe repairs are applied, all of them or none.
+    async fn check_collection_consistency(
+        &self,
+        collection_id: &CollectionId,
+        repair: bool,
+    ) -> anyhow::Result<ConsistencyReport>;
+
+    /// Compute the value and realized gains of a collection from its items
+    /// (see `CollectionValuePolicy`).
+    async fn get_collection_statistics(
+        &self,
+        collection_id: &CollectionId,
+    ) -> anyhow::Result<CollectionStatistics>;
+
+    /// Summarize what was bought and sold in a collection during `year`
+    /// (see `YearReview`).
+    async fn get_year_review(
+        &self,
+        collection_id: &CollectionId,
+        year: i32,
+    ) -> anyhow::Result<YearReview>;
+
+    /// List the items of a collection for its insurer, valued as
+    /// `valuation` (see `InsuranceReport`).
+    async fn get_insurance_report(
+        &self,
+        collection_id: &CollectionId,
+        valuation: ValuationMode,
+    ) -> anyhow::Result<InsuranceReport>;
+
+    /// Record `adjustment` against the purchase info with `purchase_id`,
+    /// returning the id of the collection the purchase belongs to.
+    ///
+    /// Fails with an `AdjustmentError` when the adjustment cannot be netted
+    /// out of the purchase cost, for example a refund larger than what is
+    /// left of the price; nothing is stored then. The collection total value
+    /// is not updated (see `recompute_collection`).
+    async fn add_monetary_adjustment(
+        &self,
+        purchase_id: &str,
+        adjustment: &MonetaryAdjustment,
+    ) -> anyhow::Result<CollectionId>;
+
+    /// The monetary adjustments of the purchase info with `purchase_id`,
+    /// oldest first.
+    async fn list_monetary_adjustments(
+        &self,
+        purchase_id: &str,
+    ) -> anyhow::Result<Vec<MonetaryAdjustment>>;
+
+    /// Record the current value of a collection as its snapshot for `date`,
+    /// replacing the one already taken that day.
+    async fn take_value_snapshot(
+        &self,
+        collection_id: &CollectionId,
+        date: NaiveDate,
+    ) -> anyhow::Result<ValueSnapshot>;
+
+    /// The value snapshots of a collection taken between `from` and `to`
+    /// (both included), oldest first.
+    async fn get_value_history(
+        &self,
+        collection_id: &CollectionId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> anyhow::Result<Vec<ValueSnapshot>>;
+
+    /// Record `modification` for the owned rolling stock with
+    /// `owned_rolling_stock_id`.
+    async fn add_modification(
+        &self,
+        owned_rolling_stock_id: &str,
+        modification: &Modification,
+    ) -> anyhow::Result<()>;
+
+    /// Replace the details of a recorded modification.
+    async fn update_modification(&self, modification: &Modification) -> anyhow::Result<()>;
+
+    /// Delete the modification with `modification_id`.
+    async fn delete_modification(&self, modification_id: &str) -> anyhow::Result<()>;
+
+    /// The modifications of the owned rolling stock with
+    /// `owned_rolling_stock_id`, oldest first.
+    async fn list_modifications(
+        &self,
+        owned_rolling_stock_id: &str,
+    ) -> anyhow::Result<Vec<Modification>>;
+
+    /// What owning the collection item with `item_id` has cost: its
+    /// purchase, the decoders installed in its rolling stocks and the
+    /// modifications made to them, totalled per currency.
+    async fn get_item_cost_breakdown(
+        &self,
+        item_id: &CollectionItemId,
+    ) -> anyhow::Result<ItemCostBreakdown>;
+
+    /// Record that the collection item with `item_id` was viewed at
+    /// `viewed_at`; only its latest `MAX_VIEWS_PER_ITEM` views are kept.
+    async fn record_item_view(
+        &self,
+        item_id: &CollectionItemId,
+        viewed_at: DateTime<Utc>,
+    ) -> anyhow::Result<()>;
+
+    /// The latest `limit` items added, modified or viewed, newest first.
+    async fn get_recent_activity(&self, limit: u32) -> anyhow::Result<Vec<ActivityEvent>>;
+}
+
+/// The decoders owned by the collector and the rolling stocks they are
+/// installed in.
+#[async_trait::async_trait]
+pub trait DecoderRepository: Send + Sync {
+    async fn create_decoder(&self, decoder: &Decoder) -> anyhow::Result<()>;
+
+    /// Overwrite the stored decoder with the id of `decoder`; fails when
+    /// there is no such decoder.
+    async fn update_decoder(&self, decoder: &Decoder) -> anyhow::Result<()>;
+
+    /// Delete a decoder, uninstalling it from its rolling stock; fails when
+    /// there is no such decoder.
+    async fn delete_decoder(&self, decoder_id: &str) -> anyhow::Result<()>;
+
+    async fn get_decoder(&self, decoder_id: &str) -> anyhow::Result<Option<Decoder>>;
+
+    /// Every decoder, ordered by manufacturer and model.
+    async fn list_decoders(&self) -> anyhow::Result<Vec<Decoder>>;
+
+    /// The decoders programmed to `address`, ordered by id.
+    async fn list_decoders_with_address(&self, address: u16) -> anyhow::Result<Vec<Decoder>>;
+
+    /// The DCC addresses used by the decoders of more than one owned rolling
+    /// stock, ordered by address. Decoders that are not installed are left
+    /// out.
+    async fn find_address_conflicts(&self) -> anyhow::Result<Vec<AddressConflict>>;
+
+    /// Install the decoder with `decoder_id` in an owned rolling stock,
+    /// replacing the decoder installed there before.
+    ///
+    /// Fails with a `DecoderError` when the decoder is installed in another
+    /// rolling stock. A decoder whose connector differs from the DCC
+    /// interface stored for the rolling stock is still installed; the
+    /// returned warnings describe the mismatch.
+    async fn attach_decoder(
+        &self,
+        owned_rolling_stock_id: &str,
+        decoder_id: &str,
+    ) -> anyhow::Result<Vec<DecoderWarning>>;
+
+    /// Remove the decoder installed in an owned rolling stock, returning its
+    /// id (`None` when there was none).
+    async fn detach_decoder(&self, owned_rolling_stock_id: &str) -> anyhow::Result<Option<String>>;
+}
+
+/// The consists assembled from owned rolling stocks.
+#[async_trait::async_trait]
+pub trait ConsistRepository: Send + Sync {
+    /// Store a consist with `consist_id` and `details`; fails when an owned
+    /// rolling stock does not exist.
+    async fn create_consist(
+        &self,
+        consist_id: &str,
+        details: &ConsistDetails,
+    ) -> anyhow::Result<()>;
+
+    /// Replace the name and vehicles of a consist; fails when there is no
+    /// such consist.
+    async fn update_consist(
+        &self,
+        consist_id: &str,
+        details: &ConsistDetails,
+    ) -> anyhow::Result<()>;
+
+    /// Delete a consist; fails when there is no such consist.
+    async fn delete_consist(&self, consist_id: &str) -> anyhow::Result<()>;
+
+    /// The consist with `consist_id`, with the catalog data of its vehicles.
+    async fn get_consist(&self, consist_id: &str) -> anyhow::Result<Option<Consist>>;
+
+    /// Every consist, ordered by name.
+    async fn list_consists(&self) -> anyhow::Result<Vec<Consist>>;
+}
+
+/// The storage locations where owned items are kept.
+#[async_trait::async_trait]
+pub trait StorageLocationRepository: Send + Sync {
+    /// Store a new location; fails when its parent does not exist.
+    async fn create_location(&self, location: &StorageLocation) -> anyhow::Result<()>;
+
+    /// Replace the name, description and parent of a location; fails with a
+    /// `StorageLocationError::Cycle` when the new parent is the location
+    /// itself or a location inside it, or when there is no such location.
+    async fn update_location(&self, location: &StorageLocation) -> anyhow::Result<()>;
+
+    /// Delete a location.
+    ///
+    /// A location still holding items or other locations can only be
+    /// deleted by moving them into `reassign_to` first; without it, fails
+    /// with a `StorageLocationError::NotEmpty`.
+    async fn delete_location(
+        &self,
+        location_id: &str,
+        reassign_to: Option<&str>,
+    ) -> anyhow::Result<()>;
+
+    async fn get_location(&self, location_id: &str) -> anyhow::Result<Option<StorageLocation>>;
+
+    /// Every location, ordered by name.
+    async fn list_locations(&self) -> anyhow::Result<Vec<StorageLocation>>;
+
+    /// Move a collection item to the location with `location_id`, or with
+    /// `None` record it as not stored anywhere in particular.
+    async fn move_item(
+        &self,
+        collection_item_id: &str,
+        location_id: Option<&str>,
+    ) -> anyhow::Result<()>;
+
+    /// Move an owned rolling stock apart from the rest of its item, or with
+    /// `None` back with its item.
+    async fn move_rolling_stock(
+        &self,
+        owned_rolling_stock_id: &str,
+        location_id: Option<&str>,
+    ) -> anyhow::Result<()>;
+
+    /// What is kept in the location with `location_id` or, when
+    /// `recursive`, also in the locations nested in it.
+    async fn find_items_by_location(
+        &self,
+        location_id: &str,
+        recursive: bool,
+    ) -> anyhow::Result<Vec<StoredItem>>;
+}
+
+/// The loans of collection items, for example to club members.
+#[async_trait::async_trait]
+pub trait LoanRepository: Send + Sync {
+    /// Store a new outstanding loan.
+    ///
+    /// Fails with a `LoanError::OnLoan` when the item is already on loan,
+    /// with a `LoanError::Sold` when it was sold, or when there is no such
+    /// item.
+    async fn lend_item(&self, loan: &Loan) -> anyhow::Result<()>;
+
+    /// Record that the item with `collection_item_id` came back on
+    /// `returned_on`, returning the closed loan.
+    ///
+    /// Fails with a `LoanError::NotOnLoan` when the item is not on loan.
+    async fn return_item(
+        &self,
+        collection_item_id: &CollectionItemId,
+        returned_on: NaiveDate,
+    ) -> anyhow::Result<Loan>;
+
+    /// The loans whose items have not come back yet or, when
+    /// `overdue_only`, only those that should have been back before `today`.
+    async fn list_outstanding_loans(
+        &self,
+        overdue_only: bool,
+        today: NaiveDate,
+    ) -> anyhow::Result<Vec<Loan>>;
 }
@@ -0,0 +1,46 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::core::domain::Currency;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Monetary figures about a collection, computed from its items on demand.
+///
+/// Both figures follow `CollectionValuePolicy`: sold items are left out of
+/// the value and count in the realized gains instead. Collections priced in
+/// several currencies get one entry per currency, ordered by currency code.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CollectionStatistics {
+    /// The collection these figures are about.
+    pub collection_id: CollectionId,
+
+    /// The value of the items still in the collection, per currency.
+    pub total_values: Vec<CurrencyValue>,
+
+    /// The gains realized by selling items, per currency.
+    pub realized_gains: Vec<RealizedGain>,
+
+    /// The average discount on the suggested retail price of the purchased
+    /// and sold items, as a percentage (see `discount::average_discount`);
+    /// `None` when no item has a price and an MSRP in the same currency.
+    pub average_discount: Option<Decimal>,
+}
+
+/// An amount in a single currency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct CurrencyValue {
+    /// The currency of the amount.
+    pub currency: Currency,
+
+    /// The amount in the smallest currency unit.
+    pub amount: u64,
+}
+
+/// The sale prices of sold items minus what they cost, in a single currency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct RealizedGain {
+    /// The currency of the amount.
+    pub currency: Currency,
+
+    /// The gain in the smallest currency unit; negative for a loss.
+    pub amount: i64,
+}
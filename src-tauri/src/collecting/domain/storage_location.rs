@@ -0,0 +1,235 @@
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A place where owned items are kept, such as a numbered box, a shelf or a
+/// display case.
+///
+/// Locations nest: a box can be on a shelf, which is in a cabinet. A
+/// collection item references the location it is kept in (see
+/// `CollectionItem::location_id`); an owned rolling stock kept apart from the
+/// rest of its item has a location of its own.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct StorageLocation {
+    /// Unique identifier for this location (e.g. UUID in the DB).
+    pub id: String,
+
+    /// The collector's name for the location, for example "Box 12".
+    pub name: String,
+
+    /// What or where the location is, for example "top shelf, left".
+    pub description: Option<String>,
+
+    /// The location this one is in, or `None` for a top-level location.
+    pub parent_id: Option<String>,
+}
+
+/// The fields of a storage location the collector enters, to create or
+/// update one.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct StorageLocationDetails {
+    /// The name of the location.
+    pub name: String,
+
+    /// What or where the location is.
+    pub description: Option<String>,
+
+    /// The location this one is in, or `None` for a top-level location.
+    pub parent_id: Option<String>,
+}
+
+impl StorageLocation {
+    /// A location with `id` and `details`, trimming the entered text and
+    /// treating a blank description as missing.
+    pub fn new(id: String, details: StorageLocationDetails) -> Result<Self, StorageLocationError> {
+        let name = details.name.trim().to_string();
+        if name.is_empty() {
+            return Err(StorageLocationError::BlankName);
+        }
+        Ok(Self {
+            id,
+            name,
+            description: details
+                .description
+                .map(|d| d.trim().to_string())
+                .filter(|d| !d.is_empty()),
+            parent_id: details.parent_id,
+        })
+    }
+
+    /// Whether `location_id` is this location or one of those it is in,
+    /// following the parent chain through `locations`.
+    pub fn is_within(&self, location_id: &str, locations: &[StorageLocation]) -> bool {
+        let mut current = Some(self);
+        // A chain longer than the number of locations has a cycle, which
+        // `check_parent` keeps out of the database; stop rather than loop.
+        for _ in 0..=locations.len() {
+            let Some(location) = current else {
+                return false;
+            };
+            if location.id == location_id {
+                return true;
+            }
+            current = location
+                .parent_id
+                .as_deref()
+                .and_then(|parent_id| locations.iter().find(|l| l.id == parent_id));
+        }
+        false
+    }
+
+    /// Check that this location can be stored with its parent, given the
+    /// stored `locations`.
+    ///
+    /// Fails with `StorageLocationError::Cycle` when the parent is this
+    /// location or one of the locations inside it.
+    pub fn check_parent(&self, locations: &[StorageLocation]) -> Result<(), StorageLocationError> {
+        let Some(parent_id) = &self.parent_id else {
+            return Ok(());
+        };
+        let creates_cycle = match locations.iter().find(|l| &l.id == parent_id) {
+            Some(parent) => parent.is_within(&self.id, locations),
+            None => parent_id == &self.id,
+        };
+        if creates_cycle {
+            return Err(StorageLocationError::Cycle {
+                location_id: self.id.clone(),
+                parent_id: parent_id.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Something kept in a storage location: a whole collection item, or an
+/// owned rolling stock kept apart from the rest of its item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct StoredItem {
+    /// The collection item, or the item the rolling stock belongs to.
+    pub collection_item_id: CollectionItemId,
+
+    /// The catalog railway model of the item.
+    pub railway_model_id: RailwayModelId,
+
+    /// The owned rolling stock kept there, or `None` when it is the item.
+    pub owned_rolling_stock_id: Option<String>,
+
+    /// The location it is kept in.
+    pub location_id: String,
+}
+
+/// Why a storage location cannot be stored or deleted.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum StorageLocationError {
+    /// A location needs a name.
+    #[error("the storage location name must not be blank")]
+    BlankName,
+
+    /// A location cannot be inside itself, directly or through the
+    /// locations inside it.
+    #[error("storage location {location_id} cannot be put inside {parent_id}, which is inside it")]
+    Cycle {
+        location_id: String,
+        parent_id: String,
+    },
+
+    /// Items or nested locations must be moved elsewhere before deleting
+    /// their location.
+    #[error(
+        "storage location {location_id} still holds {items} items and {locations} locations; move them elsewhere first"
+    )]
+    NotEmpty {
+        location_id: String,
+        items: u32,
+        locations: u32,
+    },
+
+    /// The contents of a deleted location cannot be moved into it or into a
+    /// location inside it.
+    #[error(
+        "cannot move the contents of storage location {location_id} into {target_id}, which is inside it"
+    )]
+    InvalidReassignment {
+        location_id: String,
+        target_id: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn location(id: &str, parent_id: Option<&str>) -> StorageLocation {
+        StorageLocation {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: None,
+            parent_id: parent_id.map(String::from),
+        }
+    }
+
+    /// A cabinet holding a shelf holding a box, and a separate display case.
+    fn locations() -> Vec<StorageLocation> {
+        vec![
+            location("cabinet", None),
+            location("shelf", Some("cabinet")),
+            location("box", Some("shelf")),
+            location("display", None),
+        ]
+    }
+
+    #[test]
+    fn entered_text_is_trimmed() {
+        let details = |name: &str, description: &str| StorageLocationDetails {
+            name: name.to_string(),
+            description: Some(description.to_string()),
+            parent_id: None,
+        };
+
+        let location = StorageLocation::new("box".to_string(), details(" Box 12 ", "  ")).unwrap();
+        assert_eq!(location.name, "Box 12");
+        assert_eq!(location.description, None);
+        assert_eq!(
+            StorageLocation::new("box".to_string(), details(" ", "")).unwrap_err(),
+            StorageLocationError::BlankName
+        );
+    }
+
+    #[test]
+    fn locations_are_within_their_parents() {
+        let locations = locations();
+        let inner_box = &locations[2];
+
+        assert!(inner_box.is_within("box", &locations));
+        assert!(inner_box.is_within("shelf", &locations));
+        assert!(inner_box.is_within("cabinet", &locations));
+        assert!(!inner_box.is_within("display", &locations));
+    }
+
+    #[test]
+    fn a_location_cannot_be_put_inside_itself() {
+        let locations = locations();
+
+        assert!(
+            location("cabinet", Some("display"))
+                .check_parent(&locations)
+                .is_ok()
+        );
+        assert!(
+            location("new", Some("box"))
+                .check_parent(&locations)
+                .is_ok()
+        );
+        for parent_id in ["cabinet", "shelf", "box"] {
+            assert_eq!(
+                location("cabinet", Some(parent_id)).check_parent(&locations),
+                Err(StorageLocationError::Cycle {
+                    location_id: "cabinet".to_string(),
+                    parent_id: parent_id.to_string(),
+                })
+            );
+        }
+    }
+}
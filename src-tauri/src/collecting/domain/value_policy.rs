@@ -0,0 +1,166 @@
+use crate::collecting::domain::monetary_adjustment::{AdjustmentError, MonetaryAdjustment};
+use crate::collecting::domain::purchase_info::PurchaseInfo;
+use crate::collecting::domain::statistics::RealizedGain;
+use crate::core::domain::MonetaryAmount;
+
+/// The rule deciding what a collection item adds to the collection value.
+///
+/// Only purchased items count, at their cost net of monetary adjustments:
+/// sold items are no longer part of the collection and pre-ordered items are
+/// not owned yet. A sold item contributes a realized gain instead, what it
+/// was sold for minus what it cost.
+///
+/// The recomputed total value and the collection statistics are computed in
+/// SQL, which selects the purchase infos by the purchase types given here.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CollectionValuePolicy;
+
+impl CollectionValuePolicy {
+    /// The stored `purchase_type` of the purchase infos counted in the
+    /// collection value.
+    pub const VALUED_PURCHASE_TYPE: &'static str = "purchased";
+
+    /// The stored `purchase_type` of the purchase infos with a realized gain.
+    pub const REALIZED_PURCHASE_TYPE: &'static str = "sold";
+
+    /// Whether an item with `purchase_info` counts in the collection value.
+    pub fn counts_towards_total(&self, purchase_info: &PurchaseInfo) -> bool {
+        matches!(purchase_info, PurchaseInfo::Purchased(_))
+    }
+
+    /// What an item with `purchase_info` and `adjustments` adds to the
+    /// collection value: its acquisition cost when it counts, otherwise (or
+    /// when the price is unknown) `None`.
+    pub fn item_value(
+        &self,
+        purchase_info: &PurchaseInfo,
+        adjustments: &[MonetaryAdjustment],
+    ) -> Result<Option<MonetaryAmount>, AdjustmentError> {
+        if self.counts_towards_total(purchase_info) {
+            purchase_info.acquisition_cost(adjustments)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// The gain realized by selling an item: the sale price minus the
+    /// acquisition cost, negative for a loss.
+    ///
+    /// `None` when the item is not sold, its purchase price is unknown or the
+    /// sale was in another currency than the purchase.
+    pub fn realized_gain(
+        &self,
+        purchase_info: &PurchaseInfo,
+        adjustments: &[MonetaryAdjustment],
+    ) -> Result<Option<RealizedGain>, AdjustmentError> {
+        let PurchaseInfo::Sold(sold) = purchase_info else {
+            return Ok(None);
+        };
+        let Some(cost) = purchase_info.acquisition_cost(adjustments)? else {
+            return Ok(None);
+        };
+        if cost.currency != sold.sale_price.currency {
+            return Ok(None);
+        }
+        let amount = i64::try_from(i128::from(sold.sale_price.amount) - i128::from(cost.amount))
+            .map_err(|_| AdjustmentError::Overflow)?;
+        Ok(Some(RealizedGain {
+            currency: cost.currency,
+            amount,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collecting::domain::purchase_info::{PreOrderInfo, PurchasedInfo, SoldInfo};
+    use crate::core::domain::Currency;
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()
+    }
+
+    fn purchased(price: u64) -> PurchaseInfo {
+        PurchaseInfo::Purchased(PurchasedInfo {
+            id: "pi-1".to_string(),
+            purchase_date: date(),
+            price: Some(MonetaryAmount::new(price, Currency::EUR)),
+            seller: None,
+        })
+    }
+
+    fn sold(price: u64, sale_price: MonetaryAmount) -> PurchaseInfo {
+        PurchaseInfo::Sold(SoldInfo {
+            id: "pi-1".to_string(),
+            purchase_date: date(),
+            purchase_price: Some(MonetaryAmount::new(price, Currency::EUR)),
+            sale_date: date(),
+            sale_price,
+            buyer: None,
+            seller: None,
+        })
+    }
+
+    fn refund(amount: i64) -> MonetaryAdjustment {
+        MonetaryAdjustment {
+            id: "adj-1".to_string(),
+            amount,
+            currency: Currency::EUR,
+            date: date(),
+            reason: "refund".to_string(),
+        }
+    }
+
+    #[test]
+    fn only_purchased_items_count_towards_the_total() {
+        let policy = CollectionValuePolicy;
+        let preorder = PurchaseInfo::PreOrdered(PreOrderInfo {
+            id: "pi-1".to_string(),
+            order_date: date(),
+            deposit: MonetaryAmount::new(1_000, Currency::EUR),
+            total_price: MonetaryAmount::new(10_000, Currency::EUR),
+            seller: None,
+            expected_date: None,
+        });
+
+        let value = policy.item_value(&purchased(10_000), &[refund(-500)]);
+        assert_eq!(value.unwrap().map(|v| v.amount), Some(9_500));
+        let sold = sold(10_000, MonetaryAmount::new(15_000, Currency::EUR));
+        assert!(policy.item_value(&sold, &[]).unwrap().is_none());
+        assert!(policy.item_value(&preorder, &[]).unwrap().is_none());
+    }
+
+    #[test]
+    fn sold_items_realize_a_gain_or_a_loss() {
+        let policy = CollectionValuePolicy;
+
+        let gain = sold(10_000, MonetaryAmount::new(15_000, Currency::EUR));
+        assert_eq!(
+            policy.realized_gain(&gain, &[refund(-500)]),
+            Ok(Some(RealizedGain {
+                currency: Currency::EUR,
+                amount: 5_500,
+            }))
+        );
+        let loss = sold(10_000, MonetaryAmount::new(8_000, Currency::EUR));
+        assert_eq!(
+            policy.realized_gain(&loss, &[]),
+            Ok(Some(RealizedGain {
+                currency: Currency::EUR,
+                amount: -2_000,
+            }))
+        );
+    }
+
+    #[test]
+    fn no_gain_without_a_comparable_sale() {
+        let policy = CollectionValuePolicy;
+        let other_currency = sold(10_000, MonetaryAmount::new(15_000, Currency::USD));
+
+        assert_eq!(policy.realized_gain(&purchased(10_000), &[]), Ok(None));
+        assert_eq!(policy.realized_gain(&other_currency, &[]), Ok(None));
+    }
+}
@@ -0,0 +1,22 @@
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::statistics::CurrencyValue;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// The value of a collection as it was on a given day.
+///
+/// Snapshots are taken at most once per day: taking one again on the same
+/// day replaces it. The values follow `CollectionValuePolicy`, with one entry
+/// per currency ordered by currency code; a collection with no priced items
+/// has a single zero value in its stored total value currency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ValueSnapshot {
+    /// The collection this snapshot is about.
+    pub collection_id: CollectionId,
+
+    /// The day the snapshot was taken.
+    pub date: NaiveDate,
+
+    /// The value of the items in the collection on that day, per currency.
+    pub values: Vec<CurrencyValue>,
+}
@@ -0,0 +1,109 @@
+//! A summary of what happened to a collection during one calendar year.
+//!
+//! Purchases are the items bought during the year, whether or not they were
+//! sold afterwards; pre-ordered items are not owned yet and are left out.
+//! Sales are the items sold during the year, whenever they were bought.
+//! Monetary figures follow `CollectionValuePolicy`: costs are net of their
+//! monetary adjustments and there is one entry per currency, ordered by
+//! currency code.
+
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::statistics::{CurrencyValue, RealizedGain};
+use crate::core::domain::MonetaryAmount;
+use serde::{Deserialize, Serialize};
+
+/// What was bought and sold in a collection during one calendar year.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct YearReview {
+    /// The collection these figures are about.
+    pub collection_id: CollectionId,
+
+    /// The calendar year under review.
+    pub year: i32,
+
+    /// How many items were bought during the year.
+    pub items_bought: u32,
+
+    /// What the items bought during the year cost, per currency.
+    pub total_spent: Vec<CurrencyValue>,
+
+    /// The most expensive item bought during the year, per currency.
+    pub biggest_purchases: Vec<YearPurchase>,
+
+    /// The manufacturer of most of the items bought during the year, `None`
+    /// when nothing was bought. Ties go to the first manufacturer by name.
+    pub top_manufacturer: Option<ManufacturerPurchases>,
+
+    /// How many items were sold during the year.
+    pub items_sold: u32,
+
+    /// What the items sold during the year were sold for, per currency.
+    pub sale_proceeds: Vec<CurrencyValue>,
+
+    /// The sale prices of the items sold during the year minus what they
+    /// cost, per currency (see `CollectionValuePolicy::realized_gain`).
+    pub net_proceeds: Vec<RealizedGain>,
+}
+
+impl YearReview {
+    /// The review of a year with no purchases nor sales.
+    pub fn empty(collection_id: CollectionId, year: i32) -> Self {
+        Self {
+            collection_id,
+            year,
+            items_bought: 0,
+            total_spent: Vec::new(),
+            biggest_purchases: Vec::new(),
+            top_manufacturer: None,
+            items_sold: 0,
+            sale_proceeds: Vec::new(),
+            net_proceeds: Vec::new(),
+        }
+    }
+}
+
+/// An item bought during the year under review.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct YearPurchase {
+    pub collection_item_id: CollectionItemId,
+
+    /// The name of the manufacturer of the railway model.
+    pub manufacturer: String,
+
+    /// The product code of the railway model.
+    pub product_code: String,
+
+    /// What the item cost, net of its monetary adjustments.
+    pub price: MonetaryAmount,
+}
+
+/// How many of the items bought during the year came from a manufacturer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ManufacturerPurchases {
+    pub manufacturer_id: String,
+
+    /// The name of the manufacturer.
+    pub name: String,
+
+    /// How many items from the manufacturer were bought during the year.
+    pub items_bought: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn empty_reviews_serialize_with_zeroed_figures() {
+        let collection_id = CollectionId::try_from("6f1c0e2a-4b7d-4f3e-9a51-2d8c7b6e1f04").unwrap();
+
+        let json = serde_json::to_string(&YearReview::empty(collection_id, 2025)).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"collection_id":"6f1c0e2a-4b7d-4f3e-9a51-2d8c7b6e1f04","year":2025,"items_bought":0,"total_spent":[],"biggest_purchases":[],"top_manufacturer":null,"items_sold":0,"sale_proceeds":[],"net_proceeds":[]}"#
+        );
+    }
+}
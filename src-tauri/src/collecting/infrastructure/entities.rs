@@ -1,15 +1,28 @@
 //! Database row representations for the `collecting` feature.
 //!
 //! These structs mirror the columns defined in the `0002_create_collection_schema` migration
-//! and are intended only as a thin database representation (FromRow). Conversion to rich
-//! domain types should happen in the repository layer.
+//! (plus the `version` and timestamp columns added by `0006_add_row_versions` and
+//! `0007_add_row_timestamps`, and the `0010_create_monetary_adjustments`,
+//! `0011_create_collection_value_snapshots`, `0012_create_decoders`,
+//! `0013_create_modifications`, `0014_create_consists`, `0015_create_storage_locations`
+//! and `0016_create_loans` tables, and the replacement values of
+//! `0017_add_replacement_values`) and are intended only as a thin database
+//! representation (FromRow, and the target types of the `query_as!` macros). Conversion
+//! to rich domain types should happen in the repository layer.
 
-use chrono::{NaiveDate, NaiveDateTime};
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::core::infrastructure::id_column::text_id_column;
+use chrono::{DateTime, NaiveDate, Utc};
+
+text_id_column!(CollectionId);
+text_id_column!(CollectionItemId);
 
 /// Row mapping for the `collections` table.
 #[derive(Debug, sqlx::FromRow)]
 pub struct CollectionRow {
-    pub id: String,
+    pub id: CollectionId,
     pub name: String,
     pub locomotives_count: i64,
     pub passenger_cars_count: i64,
@@ -19,36 +32,134 @@ pub struct CollectionRow {
     pub electric_multiple_units_count: i64,
     pub total_value_amount: i64,
     pub total_value_currency: String,
-    pub created_at: NaiveDateTime,
-    pub updated_at: NaiveDateTime,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
 
 /// Row mapping for the `collection_items` table.
 #[derive(Debug, sqlx::FromRow)]
 pub struct CollectionItemRow {
-    pub id: String,
-    pub collection_id: String,
-    pub railway_model_id: String,
+    pub id: CollectionItemId,
+    pub collection_id: CollectionId,
+    pub item_number: i64,
+    pub railway_model_id: RailwayModelId,
     pub conditions: Option<String>,
     pub notes: Option<String>,
+    pub location_id: Option<String>,
+    pub replacement_value_amount: Option<i64>,
+    pub replacement_value_currency: Option<String>,
+    pub version: i64,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
 }
 
 /// Row mapping for the `owned_rolling_stocks` table.
 #[derive(Debug, sqlx::FromRow)]
 pub struct OwnedRollingStockRow {
     pub id: String,
-    pub collection_item_id: String,
+    pub collection_item_id: CollectionItemId,
     pub rolling_stock_id: Option<String>,
     pub notes: Option<String>,
+    pub decoder_id: Option<String>,
+    pub location_id: Option<String>,
 }
 
 /// Row mapping for the `purchase_infos` table.
 #[derive(Debug, sqlx::FromRow)]
 pub struct PurchaseInfoRow {
     pub purchase_id: String,
-    pub collection_item_id: String,
+    pub collection_item_id: CollectionItemId,
+    pub purchase_type: Option<String>,
+    /// As stored: usually ISO, but legacy rows may hold another format (see
+    /// `purchase_date::parse_purchase_date`).
+    pub purchase_date: String,
+    pub seller_id: Option<String>,
+    pub buyer_id: Option<String>,
+    pub sale_date: Option<NaiveDate>,
+    pub purchased_price_amount: Option<i64>,
+    pub purchased_price_currency: Option<String>,
+    pub sale_price_amount: Option<i64>,
+    pub sale_price_currency: Option<String>,
+    pub deposit_amount: Option<i64>,
+    pub deposit_currency: Option<String>,
+    pub preorder_total_amount: Option<i64>,
+    pub preorder_total_currency: Option<String>,
+    pub expected_date: Option<NaiveDate>,
+    pub version: i64,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Row mapping for the `monetary_adjustments` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct MonetaryAdjustmentRow {
+    pub id: String,
+    pub purchase_id: String,
+    pub amount: i64,
+    pub currency: String,
+    pub adjustment_date: NaiveDate,
+    pub reason: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Row mapping for the `modifications` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct ModificationRow {
+    pub id: String,
+    pub owned_rolling_stock_id: String,
+    pub modification_type: String,
+    pub description: String,
+    pub modification_date: NaiveDate,
+    pub cost_amount: Option<i64>,
+    pub cost_currency: Option<String>,
+}
+
+/// One row of `sqlite::for_each_collection_detail`: a collection item joined
+/// with the suggested retail price of its railway model, one of its owned
+/// rolling stocks (and the decoder installed in it) and one of its purchase
+/// infos.
+///
+/// The owned rolling stock, decoder and purchase info columns are all `NULL`
+/// when the item has none; an item with several owned rolling stocks and
+/// purchase infos appears once per combination.
+#[derive(Debug, sqlx::FromRow)]
+pub struct CollectionDetailRow {
+    /// The item's keyset pagination sort key: `created_at` as stored, or an
+    /// empty string for legacy rows without one.
+    pub sort_key: String,
+    pub item_id: CollectionItemId,
+    pub collection_id: CollectionId,
+    pub item_number: i64,
+    pub railway_model_id: RailwayModelId,
+    pub conditions: Option<String>,
+    pub notes: Option<String>,
+    pub item_location_id: Option<String>,
+    pub item_replacement_value_amount: Option<i64>,
+    pub item_replacement_value_currency: Option<String>,
+    pub item_is_on_loan: bool,
+    pub model_msrp_amount: Option<i64>,
+    pub model_msrp_currency: Option<String>,
+    pub item_version: i64,
+    pub item_created_at: Option<DateTime<Utc>>,
+    pub item_updated_at: Option<DateTime<Utc>>,
+    pub owned_id: Option<String>,
+    pub owned_rolling_stock_id: Option<String>,
+    pub owned_notes: Option<String>,
+    pub owned_location_id: Option<String>,
+    pub owned_is_modified: bool,
+    pub decoder_id: Option<String>,
+    pub decoder_manufacturer: Option<String>,
+    pub decoder_model: Option<String>,
+    pub decoder_interface: Option<String>,
+    pub decoder_address: Option<i64>,
+    pub decoder_sound_project: Option<String>,
+    pub decoder_firmware: Option<String>,
+    pub decoder_price_amount: Option<i64>,
+    pub decoder_price_currency: Option<String>,
+    pub purchase_id: Option<String>,
     pub purchase_type: Option<String>,
-    pub purchase_date: NaiveDate,
+    pub purchase_date: Option<String>,
     pub seller_id: Option<String>,
     pub buyer_id: Option<String>,
     pub sale_date: Option<NaiveDate>,
@@ -61,4 +172,216 @@ pub struct PurchaseInfoRow {
     pub preorder_total_amount: Option<i64>,
     pub preorder_total_currency: Option<String>,
     pub expected_date: Option<NaiveDate>,
+    pub purchase_version: Option<i64>,
+    pub purchase_created_at: Option<DateTime<Utc>>,
+    pub purchase_updated_at: Option<DateTime<Utc>>,
+}
+
+/// Summary counters computed from a collection's items (see
+/// `sqlite::compute_collection_summary`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct CollectionSummaryRow {
+    pub locomotives_count: i64,
+    pub passenger_cars_count: i64,
+    pub freight_cars_count: i64,
+    pub train_sets_count: i64,
+    pub railcars_count: i64,
+    pub electric_multiple_units_count: i64,
+}
+
+/// An amount summed over a collection's items in one currency, for example
+/// their purchase prices.
+#[derive(Debug, sqlx::FromRow)]
+pub struct CurrencyTotalRow {
+    pub currency: String,
+    pub amount: i64,
+}
+
+/// The price paid for an item next to the suggested retail price of its
+/// railway model (see `sqlite::get_collection_discount_prices`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct DiscountPriceRow {
+    pub price_amount: i64,
+    pub price_currency: String,
+    pub msrp_amount: i64,
+    pub msrp_currency: String,
+}
+
+/// An owned rolling stock whose rolling stock is missing from the catalog
+/// (see `sqlite::find_missing_rolling_stock_references`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct MissingRollingStockRow {
+    pub owned_rolling_stock_id: String,
+    pub collection_item_id: String,
+    pub rolling_stock_id: String,
+}
+
+/// The currencies stored on a purchase info (see
+/// `sqlite::get_purchase_currencies`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct PurchaseCurrenciesRow {
+    pub purchase_id: String,
+    pub purchased_price_currency: Option<String>,
+    pub sale_price_currency: Option<String>,
+    pub deposit_currency: Option<String>,
+    pub preorder_total_currency: Option<String>,
+}
+
+/// Row mapping for the `collection_value_snapshots` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct ValueSnapshotRow {
+    pub collection_id: CollectionId,
+    pub snapshot_date: NaiveDate,
+    pub currency: String,
+    pub amount: i64,
+}
+
+/// Row mapping for the `decoders` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct DecoderRow {
+    pub id: String,
+    pub manufacturer: String,
+    pub model: String,
+    pub dcc_interface: Option<String>,
+    pub address: i64,
+    pub sound_project: Option<String>,
+    pub firmware: Option<String>,
+    pub purchase_price_amount: Option<i64>,
+    pub purchase_price_currency: Option<String>,
+}
+
+/// Row mapping for the `consists` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct ConsistRow {
+    pub id: String,
+    pub name: String,
+}
+
+/// A `consist_entries` row joined with the length and minimum radius of the
+/// catalog rolling stock of its owned rolling stock (see
+/// `sqlite::get_consist_vehicles`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct ConsistVehicleRow {
+    pub consist_id: String,
+    pub owned_rolling_stock_id: String,
+    pub length_millimeters: Option<f64>,
+    pub length_inches: Option<f64>,
+    pub minimum_radius_mm: Option<f64>,
+}
+
+/// Row mapping for the `loans` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct LoanRow {
+    pub id: String,
+    pub collection_item_id: CollectionItemId,
+    pub borrower_name: String,
+    pub borrower_email: Option<String>,
+    pub borrower_phone: Option<String>,
+    pub loaned_on: NaiveDate,
+    pub due_back: Option<NaiveDate>,
+    pub returned_on: Option<NaiveDate>,
+}
+
+/// Row mapping for the `storage_locations` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct StorageLocationRow {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub parent_id: Option<String>,
+}
+
+/// A collection item or owned rolling stock kept in a storage location (see
+/// `sqlite::find_items_by_location`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct StoredItemRow {
+    pub collection_item_id: CollectionItemId,
+    pub railway_model_id: RailwayModelId,
+    pub owned_rolling_stock_id: Option<String>,
+    pub location_id: String,
+}
+
+/// A decoder installed in an owned rolling stock, with the decoder address
+/// (see `sqlite::get_address_conflicts`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct DecoderInstallationRow {
+    pub address: i64,
+    pub owned_rolling_stock_id: String,
+    pub decoder_id: String,
+}
+
+/// A collection item listed in an insurance report, with its catalog and
+/// purchase data (see `sqlite::get_insurance_report_rows`). `road_numbers`
+/// holds the road numbers of its rolling stocks, one per line.
+#[derive(Debug, sqlx::FromRow)]
+pub struct InsuranceReportRow {
+    pub collection_item_id: CollectionItemId,
+    pub manufacturer: String,
+    pub product_code: String,
+    pub description: String,
+    pub road_numbers: Option<String>,
+    pub purchase_date: Option<NaiveDate>,
+    pub purchased_price_amount: Option<i64>,
+    pub purchased_price_currency: Option<String>,
+    pub replacement_value_amount: Option<i64>,
+    pub replacement_value_currency: Option<String>,
+}
+
+/// The fields of a collection item a bulk update may change (see
+/// `sqlite::find_collection_items_by_filter`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct FilteredItemRow {
+    pub id: String,
+    pub conditions: Option<String>,
+    pub location_id: Option<String>,
+}
+
+/// The stored amounts of a collection item (see
+/// `sqlite::get_stored_item_amounts`): its price is the price paid, or the
+/// total price of a preorder.
+#[derive(Debug, sqlx::FromRow)]
+pub struct StoredItemAmountsRow {
+    pub collection_item_id: CollectionItemId,
+    pub price_amount: Option<i64>,
+    pub price_currency: Option<String>,
+    pub replacement_value_amount: Option<i64>,
+    pub replacement_value_currency: Option<String>,
+}
+
+/// How many items of a collection were bought and sold during a year (see
+/// `sqlite::count_year_purchases_and_sales`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct YearCountsRow {
+    pub items_bought: i64,
+    pub items_sold: i64,
+}
+
+/// The most expensive item of a collection bought during a year in one
+/// currency (see `sqlite::get_year_biggest_purchases`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct YearPurchaseRow {
+    pub collection_item_id: CollectionItemId,
+    pub manufacturer: String,
+    pub product_code: String,
+    pub currency: String,
+    pub amount: i64,
+}
+
+/// The manufacturer of most of the items of a collection bought during a
+/// year (see `sqlite::get_year_top_manufacturer`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct ManufacturerPurchasesRow {
+    pub manufacturer_id: String,
+    pub name: String,
+    pub items_bought: i64,
+}
+
+/// An event of the recent activity of the collection items (see
+/// `sqlite::get_recent_activity`).
+#[derive(Debug, sqlx::FromRow)]
+pub struct ActivityRow {
+    pub kind: String,
+    pub collection_item_id: CollectionItemId,
+    pub railway_model_id: RailwayModelId,
+    pub occurred_at: DateTime<Utc>,
 }
@@ -1,8 +1,18 @@
 pub mod entities;
 
+pub mod purchase_date;
+
 pub mod sqlite;
 
+pub mod sqlite_consist_repo;
+
+pub mod sqlite_decoder_repo;
+
+pub mod sqlite_loan_repo;
+
 pub mod sqlite_repo;
 
+pub mod sqlite_storage_location_repo;
+
 #[cfg(test)]
 pub mod testing;
@@ -0,0 +1,86 @@
+//! Tolerant parsing of stored purchase dates.
+//!
+//! `purchase_infos.purchase_date` is a `TEXT` column. The application writes
+//! ISO `YYYY-MM-DD` dates, but rows written by earlier versions or imported
+//! from spreadsheets may hold a regional date (`DD/MM/YYYY`, `DD.MM.YYYY`) or
+//! a date-time. `parse_purchase_date` accepts those too, so that one such row
+//! does not fail the whole collection load, and
+//! `sqlite::normalize_purchase_dates` rewrites them to ISO.
+
+use chrono::NaiveDate;
+use thiserror::Error;
+
+/// The format the application writes.
+pub const ISO_FORMAT: &str = "%Y-%m-%d";
+
+/// The legacy formats accepted after ISO, in the order they are tried.
+/// Regional dates are read day first.
+const LEGACY_FORMATS: &[&str] = &["%d/%m/%Y", "%d.%m.%Y", "%d-%m-%Y", "%Y/%m/%d"];
+
+/// A stored purchase date in none of the accepted formats.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unrecognized purchase date {0:?}")]
+pub struct InvalidPurchaseDate(pub String);
+
+/// Parse a stored purchase date: ISO first, then the legacy regional
+/// formats, then the date part of a date-time such as `2024-03-01T10:15:00Z`
+/// or `01/03/2024 10:15`.
+pub fn parse_purchase_date(raw: &str) -> Result<NaiveDate, InvalidPurchaseDate> {
+    let value = raw.trim();
+    parse_date(value)
+        .or_else(|| date_time_prefix(value).and_then(parse_date))
+        .ok_or_else(|| InvalidPurchaseDate(raw.to_string()))
+}
+
+fn parse_date(value: &str) -> Option<NaiveDate> {
+    std::iter::once(ISO_FORMAT)
+        .chain(LEGACY_FORMATS.iter().copied())
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+}
+
+/// The date part of a date-time: what comes before a `T` or a space that is
+/// followed by a time.
+fn date_time_prefix(value: &str) -> Option<&str> {
+    let (date, time) = value.split_once(['T', ' '])?;
+    time.starts_with(|c: char| c.is_ascii_digit())
+        .then_some(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::iso("2024-03-01")]
+    #[case::iso_with_whitespace(" 2024-03-01\n")]
+    #[case::day_first_slashes("01/03/2024")]
+    #[case::day_first_dots("01.03.2024")]
+    #[case::day_first_dashes("01-03-2024")]
+    #[case::year_first_slashes("2024/03/01")]
+    #[case::unpadded("1/3/2024")]
+    #[case::rfc3339("2024-03-01T10:15:00Z")]
+    #[case::rfc3339_with_offset("2024-03-01T23:30:00+01:00")]
+    #[case::sqlite_date_time("2024-03-01 10:15:00")]
+    #[case::regional_date_time("01/03/2024 10:15")]
+    fn accepted_formats(#[case] raw: &str) {
+        assert_eq!(
+            parse_purchase_date(raw),
+            Ok(NaiveDate::from_ymd_opt(2024, 3, 1).unwrap())
+        );
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::garbage("last spring")]
+    #[case::out_of_range("31/02/2024")]
+    #[case::month_first("03/31/2024")]
+    #[case::text_after_date("2024-03-01 ca.")]
+    fn rejected_values(#[case] raw: &str) {
+        assert_eq!(
+            parse_purchase_date(raw),
+            Err(InvalidPurchaseDate(raw.to_string()))
+        );
+    }
+}
@@ -3,168 +3,2600 @@
 //! These helpers return typed row representations defined in
 //! `crate::collecting::infrastructure::entities` and intentionally keep SQL and
 //! mapping logic separate from domain conversion. All queries use parameter
-//! binding via `sqlx::query_as(...).bind(...)` to avoid string interpolation.
+//! binding to avoid string interpolation.
+//!
+//! The read queries are checked at compile time with the `sqlx::query_as!`
+//! macros against the metadata in `.sqlx` (see the README). Queries whose text
+//! tests also need, for example to check their query plan, live in
+//! `queries/collecting` and are checked with `sqlx::query_file_as!`; writes
+//! and the summary aggregates use the runtime `sqlx::query(...).bind(...)`.
 
 use anyhow::{Context, Result};
-use sqlx::SqlitePool;
+use chrono::{DateTime, NaiveDate, Utc};
+use futures_util::TryStreamExt;
+use futures_util::stream::BoxStream;
+use log::warn;
+use sqlx::{SqliteConnection, SqliteExecutor, SqlitePool};
+use uuid::Uuid;
 
 use crate::collecting::infrastructure::entities::{
-    CollectionItemRow, CollectionRow, OwnedRollingStockRow, PurchaseInfoRow,
+    ActivityRow, CollectionDetailRow, CollectionItemRow, CollectionRow, CollectionSummaryRow,
+    ConsistRow, ConsistVehicleRow, CurrencyTotalRow, DecoderInstallationRow, DecoderRow,
+    DiscountPriceRow, FilteredItemRow, InsuranceReportRow, LoanRow, ManufacturerPurchasesRow,
+    MissingRollingStockRow, ModificationRow, MonetaryAdjustmentRow, OwnedRollingStockRow,
+    PurchaseCurrenciesRow, PurchaseInfoRow, StorageLocationRow, StoredItemAmountsRow,
+    StoredItemRow, ValueSnapshotRow, YearCountsRow, YearPurchaseRow,
 };
+use crate::collecting::infrastructure::purchase_date::{ISO_FORMAT, parse_purchase_date};
 
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::collecting::domain::bulk_update::{CollectionItemFilter, CollectionItemPatch};
+use crate::collecting::domain::collection::{DEFAULT_COLLECTION_ID, DEFAULT_COLLECTION_NAME};
 use crate::collecting::domain::collection_id::CollectionId;
 use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::loan::LoanError;
+use crate::collecting::domain::purchase_info::{PreOrderInfo, PurchasedInfo};
+use crate::collecting::domain::value_policy::CollectionValuePolicy;
+use crate::core::domain::MonetaryAmount;
+use crate::core::domain::page::PageKey;
+use crate::core::infrastructure::conflict::ConflictError;
+use crate::core::infrastructure::retry::{RetryPolicy, retry_on_busy};
+use crate::core::infrastructure::transaction::with_transaction;
 
 /// Fetch a single collection row by id.
 ///
 /// Parameters:
-/// - `pool`: SQLite connection pool (moved/cloned by caller).
-/// - `collection_id`: domain newtype identifying the collection. The function
-///   binds the string form of the id to the SQL query.
+/// - `executor`: SQLite connection pool or connection (for example an open
+///   transaction).
+/// - `collection_id`: domain newtype identifying the collection, bound
+///   directly to the SQL query.
 ///
 /// Returns `Ok(Some(CollectionRow))` if found, `Ok(None)` if not found, or an
 /// `Err` on database errors.
-pub async fn get_collection(
-    pool: &SqlitePool,
+pub async fn get_collection<'e, E: SqliteExecutor<'e>>(
+    executor: E,
     collection_id: CollectionId,
 ) -> Result<Option<CollectionRow>> {
-    let sql = "SELECT id, name, locomotives_count, passenger_cars_count, freight_cars_count, train_sets_count, railcars_count, electric_multiple_units_count, total_value_amount, total_value_currency, created_at, updated_at FROM collections WHERE id = ?1 LIMIT 1";
-
-    let row = sqlx::query_as::<_, CollectionRow>(sql)
-        .bind(collection_id.to_string())
-        .fetch_optional(pool)
+    let row = sqlx::query_as!(
+        CollectionRow,
+        r#"SELECT id AS "id!: _", name, locomotives_count, passenger_cars_count, freight_cars_count, train_sets_count, railcars_count, electric_multiple_units_count, total_value_amount, total_value_currency, created_at AS "created_at: _", updated_at AS "updated_at: _" FROM collections WHERE id = ?1 LIMIT 1"#,
+        collection_id
+    )
+    .fetch_optional(executor)
         .await
         .with_context(|| format!("querying collection id={}", collection_id))?;
 
     Ok(row)
 }
 
-/// Fetch a single collection item row by its id.
-///
-/// Binds the `collection_item_id` string form to the query and returns the
-/// corresponding `CollectionItemRow` if present.
-pub async fn get_collection_item(
-    pool: &SqlitePool,
-    collection_item_id: CollectionItemId,
-) -> Result<Option<CollectionItemRow>> {
-    let sql = "SELECT id, collection_id, railway_model_id, conditions, notes FROM collection_items WHERE id = ?1 LIMIT 1";
+/// Fetch a single collection item row by its id.
+///
+/// Binds `collection_item_id` to the query and returns the corresponding
+/// `CollectionItemRow` if present.
+pub async fn get_collection_item<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: CollectionItemId,
+) -> Result<Option<CollectionItemRow>> {
+    let row = sqlx::query_as!(
+        CollectionItemRow,
+        r#"SELECT id AS "id!: _", collection_id AS "collection_id: _", item_number AS "item_number!", railway_model_id AS "railway_model_id!: _", conditions, notes, location_id, replacement_value_amount, replacement_value_currency, version, created_at AS "created_at: _", updated_at AS "updated_at: _" FROM collection_items WHERE id = ?1 LIMIT 1"#,
+        collection_item_id
+    )
+    .fetch_optional(executor)
+        .await
+        .with_context(|| format!("querying collection_item id={}", collection_item_id))?;
+
+    Ok(row)
+}
+
+/// Fetch all collection items belonging to a collection.
+///
+/// Returns a vector of `CollectionItemRow`. The `collection_id` is bound as a
+/// parameter to the query to avoid string concatenation.
+pub async fn get_collection_items(
+    pool: &SqlitePool,
+    collection_id: &CollectionId,
+) -> Result<Vec<CollectionItemRow>> {
+    let rows = sqlx::query_file_as!(
+        CollectionItemRow,
+        "queries/collecting/collection_items.sql",
+        collection_id
+    )
+    .fetch_all(pool)
+    .await
+    .with_context(|| {
+        format!(
+            "querying collection_items for collection_id={}",
+            collection_id
+        )
+    })?;
+
+    Ok(rows)
+}
+
+/// Insert a collection item row referencing `railway_model_id`.
+///
+/// `created_at` and `updated_at` are set to the current UTC time.
+pub async fn insert_collection_item<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &str,
+    collection_id: &str,
+    railway_model_id: &RailwayModelId,
+) -> Result<()> {
+    let sql = "INSERT INTO collection_items (id, collection_id, railway_model_id, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?4)";
+
+    sqlx::query(sql)
+        .bind(collection_item_id)
+        .bind(collection_id)
+        .bind(railway_model_id)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "inserting collection_item id={} collection_id={}",
+                collection_item_id, collection_id
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Insert an owned rolling stock row attached to a collection item.
+pub async fn insert_owned_rolling_stock<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    owned_rolling_stock_id: &str,
+    collection_item_id: &str,
+    rolling_stock_id: &str,
+) -> Result<()> {
+    let sql = "INSERT INTO owned_rolling_stocks (id, collection_item_id, rolling_stock_id) VALUES (?1, ?2, ?3)";
+
+    sqlx::query(sql)
+        .bind(owned_rolling_stock_id)
+        .bind(collection_item_id)
+        .bind(rolling_stock_id)
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "inserting owned_rolling_stock id={} collection_item_id={}",
+                owned_rolling_stock_id, collection_item_id
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Create the default collection row if it does not exist yet.
+///
+/// The row gets zeroed counters and a zero EUR total. Calling this again, or
+/// concurrently, is harmless (`INSERT OR IGNORE`). Returns `true` when the
+/// row was created by this call.
+pub async fn ensure_default_collection<'e, E: SqliteExecutor<'e>>(executor: E) -> Result<bool> {
+    let sql = "INSERT OR IGNORE INTO collections (id, name, total_value_amount, total_value_currency, created_at, updated_at) VALUES (?1, ?2, 0, 'EUR', ?3, ?3)";
+
+    let result = sqlx::query(sql)
+        .bind(DEFAULT_COLLECTION_ID)
+        .bind(DEFAULT_COLLECTION_NAME)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .context("creating the default collection")?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Add an item for `railway_model_id` to a collection, owning the given
+/// rolling stocks, as a single transaction.
+///
+/// Either the item and all of its owned rolling stocks are stored, or none
+/// of them is. When the target is the default collection, its row is created
+/// first if needed. Returns the generated collection item id.
+pub async fn add_collection_item(
+    pool: &SqlitePool,
+    collection_id: &CollectionId,
+    railway_model_id: &RailwayModelId,
+    rolling_stock_ids: &[&str],
+) -> Result<String> {
+    let collection_item_id = Uuid::new_v4().to_string();
+    let collection_id = collection_id.to_string();
+    let railway_model_id = *railway_model_id;
+    let rolling_stock_ids: Vec<String> =
+        rolling_stock_ids.iter().map(|id| id.to_string()).collect();
+
+    with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            if collection_id == DEFAULT_COLLECTION_ID {
+                ensure_default_collection(&mut *conn).await?;
+            }
+            insert_collection_item(
+                &mut *conn,
+                &collection_item_id,
+                &collection_id,
+                &railway_model_id,
+            )
+            .await?;
+            for rolling_stock_id in &rolling_stock_ids {
+                insert_owned_rolling_stock(
+                    &mut *conn,
+                    &Uuid::new_v4().to_string(),
+                    &collection_item_id,
+                    rolling_stock_id,
+                )
+                .await?;
+            }
+            Ok(collection_item_id)
+        })
+    })
+    .await
+}
+
+/// The ids of the items of the collection with `collection_id` that
+/// reference `railway_model_id`, in the order they were added.
+pub async fn find_collection_items_by_railway_model<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+    railway_model_id: &RailwayModelId,
+) -> Result<Vec<String>> {
+    let ids = sqlx::query_scalar!(
+        r#"SELECT id AS "id!" FROM collection_items WHERE collection_id = ?1 AND railway_model_id = ?2 ORDER BY created_at, id"#,
+        collection_id,
+        railway_model_id
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying collection_items with railway_model_id={}",
+            railway_model_id
+        )
+    })?;
+
+    Ok(ids)
+}
+
+/// Insert a copy of the collection item with `source_id` as
+/// `collection_item_id`, in the same collection and with the same railway
+/// model, conditions and notes.
+///
+/// `created_at` and `updated_at` are set to the current UTC time. Returns
+/// `false` when there is no item with `source_id`.
+pub async fn copy_collection_item<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &str,
+    source_id: &str,
+) -> Result<bool> {
+    let sql = "INSERT INTO collection_items (id, collection_id, railway_model_id, conditions, notes, created_at, updated_at) SELECT ?1, collection_id, railway_model_id, conditions, notes, ?3, ?3 FROM collection_items WHERE id = ?2";
+
+    let result = sqlx::query(sql)
+        .bind(collection_item_id)
+        .bind(source_id)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "copying collection_item id={} into id={}",
+                source_id, collection_item_id
+            )
+        })?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// The ids of the owned rolling stocks of the collection item with
+/// `collection_item_id`, in the order they were added.
+pub async fn get_collection_item_owned_rolling_stock_ids<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &str,
+) -> Result<Vec<String>> {
+    let ids = sqlx::query_scalar!(
+        r#"SELECT id AS "id!" FROM owned_rolling_stocks WHERE collection_item_id = ?1 ORDER BY rowid"#,
+        collection_item_id
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying owned_rolling_stocks of collection_item id={}",
+            collection_item_id
+        )
+    })?;
+
+    Ok(ids)
+}
+
+/// Insert a copy of the owned rolling stock with `source_id` as
+/// `owned_rolling_stock_id`, attached to the collection item with
+/// `collection_item_id` and referencing the same catalog rolling stock.
+///
+/// The notes, decoder and location of the source are not copied.
+pub async fn copy_owned_rolling_stock<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    owned_rolling_stock_id: &str,
+    collection_item_id: &str,
+    source_id: &str,
+) -> Result<()> {
+    let sql = "INSERT INTO owned_rolling_stocks (id, collection_item_id, rolling_stock_id) SELECT ?1, ?2, rolling_stock_id FROM owned_rolling_stocks WHERE id = ?3";
+
+    sqlx::query(sql)
+        .bind(owned_rolling_stock_id)
+        .bind(collection_item_id)
+        .bind(source_id)
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "copying owned_rolling_stock id={} into id={}",
+                source_id, owned_rolling_stock_id
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Update the conditions, notes and replacement value of a collection item,
+/// guarded by its version.
+///
+/// `row.version` must be the version the caller read: the update only
+/// applies when the stored row still has it, increments it and sets
+/// `updated_at` to the current UTC time. Returns the
+/// new version, or a `ConflictError<CollectionItemRow>` carrying the current
+/// row when a concurrent write got there first.
+pub async fn update_collection_item(pool: &SqlitePool, row: &CollectionItemRow) -> Result<i64> {
+    let sql = "UPDATE collection_items SET conditions = ?1, notes = ?2, replacement_value_amount = ?6, replacement_value_currency = ?7, updated_at = ?5, version = version + 1 WHERE id = ?3 AND version = ?4";
+
+    let context = format!("updating collection_item id={}", row.id);
+    let result = retry_on_busy(&RetryPolicy::default(), &context, || {
+        sqlx::query(sql)
+            .bind(&row.conditions)
+            .bind(&row.notes)
+            .bind(&row.id)
+            .bind(row.version)
+            .bind(Utc::now())
+            .bind(row.replacement_value_amount)
+            .bind(&row.replacement_value_currency)
+            .execute(pool)
+    })
+    .await
+    .context(context)?;
+
+    if result.rows_affected() == 0 {
+        let current = get_collection_item(pool, row.id.clone()).await?;
+        return Err(ConflictError {
+            entity: "collection_item",
+            id: row.id.to_string(),
+            expected_version: row.version,
+            current,
+        }
+        .into());
+    }
+
+    Ok(row.version + 1)
+}
+
+/// Fetch a single owned rolling stock row by id.
+///
+/// The function accepts the raw owned rolling stock id string and returns the
+/// matching `OwnedRollingStockRow` if present.
+pub async fn get_owned_rolling_stock<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    owned_rolling_stock_id: String,
+) -> Result<Option<OwnedRollingStockRow>> {
+    let row = sqlx::query_as!(
+        OwnedRollingStockRow,
+        r#"SELECT id AS "id!", collection_item_id AS "collection_item_id: _", rolling_stock_id, notes, decoder_id, location_id FROM owned_rolling_stocks WHERE id = ?1 LIMIT 1"#,
+        owned_rolling_stock_id
+    )
+    .fetch_optional(executor)
+        .await
+        .context("querying owned_rolling_stock by id")?;
+
+    Ok(row)
+}
+
+/// Fetch all owned rolling stocks that belong to a collection.
+///
+/// This performs a join from `owned_rolling_stocks` to `collection_items` and
+/// filters by `collection_items.collection_id = ?` using parameter binding.
+/// The catalog reference is resolved with a left join, so `rolling_stock_id`
+/// is `None` unless it refers to an existing rolling stock.
+pub async fn get_owned_rolling_stocks(
+    pool: &SqlitePool,
+    collection_id: &CollectionId,
+) -> Result<Vec<OwnedRollingStockRow>> {
+    let rows = sqlx::query_file_as!(
+        OwnedRollingStockRow,
+        "queries/collecting/owned_rolling_stocks.sql",
+        collection_id
+    )
+    .fetch_all(pool)
+    .await
+    .with_context(|| {
+        format!(
+            "querying owned_rolling_stocks for collection_id={}",
+            collection_id
+        )
+    })?;
+
+    Ok(rows)
+}
+
+/// Fetch a single purchase_info by id.
+///
+/// Accepts the raw purchase info id string and returns the typed
+/// `PurchaseInfoRow` if present.
+pub async fn get_purchase_info<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    purchase_info_id: String,
+) -> Result<Option<PurchaseInfoRow>> {
+    let row = sqlx::query_as!(
+        PurchaseInfoRow,
+        r#"SELECT purchase_id AS "purchase_id!", collection_item_id AS "collection_item_id: _", purchase_type, purchase_date AS "purchase_date: _", seller_id, buyer_id, sale_date AS "sale_date: _", purchased_price_amount, purchased_price_currency, sale_price_amount, sale_price_currency, deposit_amount, deposit_currency, preorder_total_amount, preorder_total_currency, expected_date AS "expected_date: _", version, created_at AS "created_at: _", updated_at AS "updated_at: _" FROM purchase_infos WHERE purchase_id = ?1 LIMIT 1"#,
+        purchase_info_id
+    )
+    .fetch_optional(executor)
+        .await
+        .context("querying purchase_info by id")?;
+
+    Ok(row)
+}
+
+/// Fetch the purchase info of the collection item with
+/// `collection_item_id`, if it has one.
+pub async fn get_item_purchase_info<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &CollectionItemId,
+) -> Result<Option<PurchaseInfoRow>> {
+    let row = sqlx::query_as!(
+        PurchaseInfoRow,
+        r#"SELECT purchase_id AS "purchase_id!", collection_item_id AS "collection_item_id: _", purchase_type, purchase_date AS "purchase_date: _", seller_id, buyer_id, sale_date AS "sale_date: _", purchased_price_amount, purchased_price_currency, sale_price_amount, sale_price_currency, deposit_amount, deposit_currency, preorder_total_amount, preorder_total_currency, expected_date AS "expected_date: _", version, created_at AS "created_at: _", updated_at AS "updated_at: _" FROM purchase_infos WHERE collection_item_id = ?1 ORDER BY created_at, purchase_id LIMIT 1"#,
+        collection_item_id
+    )
+    .fetch_optional(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying the purchase_info of collection_item id={}",
+            collection_item_id
+        )
+    })?;
+
+    Ok(row)
+}
+
+/// Insert a `purchased` purchase_info row for the collection item with
+/// `collection_item_id`.
+///
+/// `created_at` and `updated_at` are set to the current UTC time.
+pub async fn insert_purchased_info<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &CollectionItemId,
+    purchase: &PurchasedInfo,
+) -> Result<()> {
+    let sql = "INSERT INTO purchase_infos (purchase_id, collection_item_id, purchase_type, purchase_date, seller_id, purchased_price_amount, purchased_price_currency, created_at, updated_at) VALUES (?1, ?2, 'purchased', ?3, ?4, ?5, ?6, ?7, ?7)";
+
+    let amount = purchase
+        .price
+        .as_ref()
+        .map(|price| i64::try_from(price.amount))
+        .transpose()
+        .with_context(|| format!("purchase price overflow purchase_id={}", purchase.id))?;
+    let currency = purchase.price.as_ref().map(|price| price.currency.code());
+    sqlx::query(sql)
+        .bind(&purchase.id)
+        .bind(collection_item_id)
+        .bind(purchase.purchase_date.format(ISO_FORMAT).to_string())
+        .bind(&purchase.seller)
+        .bind(amount)
+        .bind(currency)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "inserting purchase_info purchase_id={} collection_item_id={}",
+                purchase.id, collection_item_id
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Whether the collection item with `collection_item_id` has a purchase
+/// info.
+pub async fn has_purchase_info<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &CollectionItemId,
+) -> Result<bool> {
+    let found = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM purchase_infos WHERE collection_item_id = ?1) AS "found!: bool""#,
+        collection_item_id
+    )
+    .fetch_one(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying the purchase_info of collection_item id={}",
+            collection_item_id
+        )
+    })?;
+
+    Ok(found)
+}
+
+/// Update the editable columns of a purchase_info, guarded by its version.
+///
+/// `row.version` must be the version the caller read: the update only
+/// applies when the stored row still has it, increments it and sets
+/// `updated_at` to the current UTC time. Returns the new version, or a
+/// `ConflictError<PurchaseInfoRow>` carrying the current
+/// row when a concurrent write got there first.
+///
+/// A preorder whose deposit is in another currency than its total price, or
+/// exceeds it, is rejected with a `PreOrderError` before anything is written,
+/// and so is marking an item on loan as sold, with a `LoanError::OnLoan`.
+pub async fn update_purchase_info(pool: &SqlitePool, row: &PurchaseInfoRow) -> Result<i64> {
+    validate_preorder_row(row)
+        .with_context(|| format!("invalid purchase_info purchase_id={}", row.purchase_id))?;
+    if row.purchase_type.as_deref() == Some("sold")
+        && get_outstanding_loan(pool, &row.collection_item_id)
+            .await?
+            .is_some()
+    {
+        return Err(LoanError::OnLoan(row.collection_item_id.clone()).into());
+    }
+
+    let sql = "UPDATE purchase_infos SET purchase_type = ?1, purchase_date = ?2, seller_id = ?3, buyer_id = ?4, sale_date = ?5, purchased_price_amount = ?6, purchased_price_currency = ?7, sale_price_amount = ?8, sale_price_currency = ?9, deposit_amount = ?10, deposit_currency = ?11, preorder_total_amount = ?12, preorder_total_currency = ?13, expected_date = ?14, updated_at = ?17, version = version + 1
+        WHERE purchase_id = ?15 AND version = ?16";
+
+    let context = format!("updating purchase_info purchase_id={}", row.purchase_id);
+    let result = retry_on_busy(&RetryPolicy::default(), &context, || {
+        sqlx::query(sql)
+            .bind(&row.purchase_type)
+            .bind(&row.purchase_date)
+            .bind(&row.seller_id)
+            .bind(&row.buyer_id)
+            .bind(row.sale_date)
+            .bind(row.purchased_price_amount)
+            .bind(&row.purchased_price_currency)
+            .bind(row.sale_price_amount)
+            .bind(&row.sale_price_currency)
+            .bind(row.deposit_amount)
+            .bind(&row.deposit_currency)
+            .bind(row.preorder_total_amount)
+            .bind(&row.preorder_total_currency)
+            .bind(row.expected_date)
+            .bind(&row.purchase_id)
+            .bind(row.version)
+            .bind(Utc::now())
+            .execute(pool)
+    })
+    .await
+    .context(context)?;
+
+    if result.rows_affected() == 0 {
+        let current = get_purchase_info(pool, row.purchase_id.clone()).await?;
+        return Err(ConflictError {
+            entity: "purchase_info",
+            id: row.purchase_id.clone(),
+            expected_version: row.version,
+            current,
+        }
+        .into());
+    }
+
+    Ok(row.version + 1)
+}
+
+/// Check the deposit of a preorder row against its total price (see
+/// `PreOrderInfo::validate_amounts`). Other rows, and preorders missing
+/// either amount, pass.
+fn validate_preorder_row(row: &PurchaseInfoRow) -> Result<()> {
+    if row.purchase_type.as_deref() != Some("preorder") {
+        return Ok(());
+    }
+    let deposit = MonetaryAmount::from_db(
+        row.deposit_amount.unwrap_or(0),
+        row.deposit_currency.as_deref(),
+    )?;
+    let total_price = MonetaryAmount::from_db(
+        row.preorder_total_amount.unwrap_or(0),
+        row.preorder_total_currency.as_deref(),
+    )?;
+    if let (Some(deposit), Some(total_price)) = (deposit, total_price) {
+        PreOrderInfo::validate_amounts(&deposit, &total_price)?;
+    }
+    Ok(())
+}
+
+/// Rewrite the purchase dates stored in a recognized legacy format (see
+/// `purchase_date::parse_purchase_date`) to ISO `YYYY-MM-DD`, as a single
+/// transaction.
+///
+/// Rows already in ISO form are not read. A value in no recognized format is
+/// logged and left as it is: the collection still loads, reporting it as a
+/// load warning. Versions and `updated_at` are kept, since the date itself
+/// does not change. Returns the number of rows rewritten.
+pub async fn normalize_purchase_dates(pool: &SqlitePool) -> Result<u64> {
+    with_transaction(pool, |conn| {
+        Box::pin(async move {
+            let rows = sqlx::query!(
+                r#"SELECT purchase_id AS "purchase_id!", purchase_date FROM purchase_infos WHERE purchase_date NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]'"#
+            )
+            .fetch_all(&mut *conn)
+            .await
+            .context("querying legacy purchase dates")?;
+
+            let mut rewritten = 0;
+            for row in rows {
+                let date = match parse_purchase_date(&row.purchase_date) {
+                    Ok(date) => date,
+                    Err(e) => {
+                        warn!("Keeping purchase_infos row id={}: {e}", row.purchase_id);
+                        continue;
+                    }
+                };
+                rewritten += sqlx::query(
+                    "UPDATE purchase_infos SET purchase_date = ?1 WHERE purchase_id = ?2",
+                )
+                .bind(date.format(ISO_FORMAT).to_string())
+                .bind(&row.purchase_id)
+                .execute(&mut *conn)
+                .await
+                .with_context(|| {
+                    format!("normalizing purchase_date of purchase_id={}", row.purchase_id)
+                })?
+                .rows_affected();
+            }
+            Ok(rewritten)
+        })
+    })
+    .await
+}
+
+/// Fetch the monetary adjustments of a purchase info, oldest first.
+pub async fn get_monetary_adjustments<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    purchase_id: &str,
+) -> Result<Vec<MonetaryAdjustmentRow>> {
+    let rows = sqlx::query_as!(
+        MonetaryAdjustmentRow,
+        r#"SELECT id AS "id!", purchase_id, amount, currency, adjustment_date AS "adjustment_date: _", reason, created_at AS "created_at: _", updated_at AS "updated_at: _" FROM monetary_adjustments WHERE purchase_id = ?1 ORDER BY adjustment_date, created_at, id"#,
+        purchase_id
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| format!("querying monetary_adjustments for purchase_id={}", purchase_id))?;
+
+    Ok(rows)
+}
+
+/// Insert a monetary adjustment row.
+///
+/// `created_at` and `updated_at` are set to the current UTC time; the ones
+/// in `row` are ignored.
+pub async fn insert_monetary_adjustment<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &MonetaryAdjustmentRow,
+) -> Result<()> {
+    let sql = "INSERT INTO monetary_adjustments (id, purchase_id, amount, currency, adjustment_date, reason, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)";
+
+    sqlx::query(sql)
+        .bind(&row.id)
+        .bind(&row.purchase_id)
+        .bind(row.amount)
+        .bind(&row.currency)
+        .bind(row.adjustment_date)
+        .bind(&row.reason)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "inserting monetary_adjustment id={} purchase_id={}",
+                row.id, row.purchase_id
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Fetch the modifications of an owned rolling stock, oldest first.
+pub async fn get_modifications<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    owned_rolling_stock_id: &str,
+) -> Result<Vec<ModificationRow>> {
+    let rows = sqlx::query_as!(
+        ModificationRow,
+        r#"SELECT id AS "id!", owned_rolling_stock_id, modification_type, description, modification_date AS "modification_date: _", cost_amount, cost_currency FROM modifications WHERE owned_rolling_stock_id = ?1 ORDER BY modification_date, created_at, id"#,
+        owned_rolling_stock_id
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying modifications for owned_rolling_stock_id={}",
+            owned_rolling_stock_id
+        )
+    })?;
+
+    Ok(rows)
+}
+
+/// Fetch the modifications made to the owned rolling stocks of the
+/// collection item with `collection_item_id`, oldest first.
+pub async fn get_item_modifications<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &CollectionItemId,
+) -> Result<Vec<ModificationRow>> {
+    let rows = sqlx::query_as!(
+        ModificationRow,
+        r#"SELECT m.id AS "id!", m.owned_rolling_stock_id, m.modification_type, m.description, m.modification_date AS "modification_date: _", m.cost_amount, m.cost_currency FROM modifications m JOIN owned_rolling_stocks o ON o.id = m.owned_rolling_stock_id WHERE o.collection_item_id = ?1 ORDER BY m.modification_date, m.created_at, m.id"#,
+        collection_item_id
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying modifications for collection_item id={}",
+            collection_item_id
+        )
+    })?;
+
+    Ok(rows)
+}
+
+/// Insert a modification row.
+///
+/// `created_at` and `updated_at` are set to the current UTC time.
+pub async fn insert_modification<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &ModificationRow,
+) -> Result<()> {
+    let sql = "INSERT INTO modifications (id, owned_rolling_stock_id, modification_type, description, modification_date, cost_amount, cost_currency, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?8)";
+
+    sqlx::query(sql)
+        .bind(&row.id)
+        .bind(&row.owned_rolling_stock_id)
+        .bind(&row.modification_type)
+        .bind(&row.description)
+        .bind(row.modification_date)
+        .bind(row.cost_amount)
+        .bind(&row.cost_currency)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "inserting modification id={} owned_rolling_stock_id={}",
+                row.id, row.owned_rolling_stock_id
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Update the details of a modification; the owned rolling stock it belongs
+/// to is kept.
+///
+/// Returns `false` when there is no such modification.
+pub async fn update_modification<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &ModificationRow,
+) -> Result<bool> {
+    let sql = "UPDATE modifications SET modification_type = ?2, description = ?3, modification_date = ?4, cost_amount = ?5, cost_currency = ?6, updated_at = ?7 WHERE id = ?1";
+
+    let result = sqlx::query(sql)
+        .bind(&row.id)
+        .bind(&row.modification_type)
+        .bind(&row.description)
+        .bind(row.modification_date)
+        .bind(row.cost_amount)
+        .bind(&row.cost_currency)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| format!("updating modification id={}", row.id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete the modification with `modification_id`.
+///
+/// Returns `false` when there is no such modification.
+pub async fn delete_modification<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    modification_id: &str,
+) -> Result<bool> {
+    let result = sqlx::query("DELETE FROM modifications WHERE id = ?1")
+        .bind(modification_id)
+        .execute(executor)
+        .await
+        .with_context(|| format!("deleting modification id={}", modification_id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Fetch every consist, ordered by name (case-insensitive).
+pub async fn get_consists<'e, E: SqliteExecutor<'e>>(executor: E) -> Result<Vec<ConsistRow>> {
+    let rows = sqlx::query_as!(
+        ConsistRow,
+        r#"SELECT id AS "id!", name FROM consists ORDER BY name COLLATE NOCASE, id"#
+    )
+    .fetch_all(executor)
+    .await
+    .context("listing consists")?;
+
+    Ok(rows)
+}
+
+/// Fetch a single consist row by id.
+pub async fn get_consist<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    consist_id: &str,
+) -> Result<Option<ConsistRow>> {
+    let row = sqlx::query_as!(
+        ConsistRow,
+        r#"SELECT id AS "id!", name FROM consists WHERE id = ?1"#,
+        consist_id
+    )
+    .fetch_optional(executor)
+    .await
+    .with_context(|| format!("querying consist id={}", consist_id))?;
+
+    Ok(row)
+}
+
+/// Fetch the vehicles of the consist with `consist_id`, or of every consist
+/// when `None`, ordered by consist and position.
+///
+/// The lengths and minimum radius come from the catalog rolling stock of
+/// each owned rolling stock; they are `NULL` when it is not linked to one.
+pub async fn get_consist_vehicles<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    consist_id: Option<&str>,
+) -> Result<Vec<ConsistVehicleRow>> {
+    let rows = sqlx::query_as!(
+        ConsistVehicleRow,
+        r#"SELECT ce.consist_id, ce.owned_rolling_stock_id,
+            rs.length_millimeters AS "length_millimeters?: f64", rs.length_inches AS "length_inches?: f64",
+            rs.technical_minimum_radius_mm AS "minimum_radius_mm?: f64"
+        FROM consist_entries AS ce
+        JOIN owned_rolling_stocks AS ors ON ors.id = ce.owned_rolling_stock_id
+        LEFT JOIN rolling_stocks AS rs ON rs.id = ors.rolling_stock_id
+        WHERE ?1 IS NULL OR ce.consist_id = ?1
+        ORDER BY ce.consist_id, ce.position"#,
+        consist_id
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| format!("querying consist_entries consist_id={:?}", consist_id))?;
+
+    Ok(rows)
+}
+
+/// Insert a consist row; its entries are stored by `replace_consist_entries`.
+///
+/// `created_at` and `updated_at` are set to the current UTC time.
+pub async fn insert_consist<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &ConsistRow,
+) -> Result<()> {
+    sqlx::query("INSERT INTO consists (id, name, created_at, updated_at) VALUES (?1, ?2, ?3, ?3)")
+        .bind(&row.id)
+        .bind(&row.name)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| format!("inserting consist id={}", row.id))?;
+
+    Ok(())
+}
+
+/// Update the name of a consist, setting its `updated_at` to the current UTC
+/// time.
+///
+/// Returns `false` when there is no such consist.
+pub async fn update_consist<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &ConsistRow,
+) -> Result<bool> {
+    let result = sqlx::query("UPDATE consists SET name = ?2, updated_at = ?3 WHERE id = ?1")
+        .bind(&row.id)
+        .bind(&row.name)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| format!("updating consist id={}", row.id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete the consist with `consist_id` and its entries.
+///
+/// Returns `false` when there is no such consist.
+pub async fn delete_consist<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    consist_id: &str,
+) -> Result<bool> {
+    let result = sqlx::query("DELETE FROM consists WHERE id = ?1")
+        .bind(consist_id)
+        .execute(executor)
+        .await
+        .with_context(|| format!("deleting consist id={}", consist_id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Replace the entries of the consist with `consist_id` with the owned
+/// rolling stocks in `owned_rolling_stock_ids`, in that order.
+///
+/// Run it in a transaction so a failing insert (for example an unknown
+/// owned rolling stock) does not leave the consist half replaced.
+pub async fn replace_consist_entries(
+    conn: &mut SqliteConnection,
+    consist_id: &str,
+    owned_rolling_stock_ids: &[String],
+) -> Result<()> {
+    sqlx::query("DELETE FROM consist_entries WHERE consist_id = ?1")
+        .bind(consist_id)
+        .execute(&mut *conn)
+        .await
+        .with_context(|| format!("deleting consist_entries consist_id={}", consist_id))?;
+
+    let sql = "INSERT INTO consist_entries (consist_id, position, owned_rolling_stock_id) VALUES (?1, ?2, ?3)";
+    for (position, owned_rolling_stock_id) in owned_rolling_stock_ids.iter().enumerate() {
+        sqlx::query(sql)
+            .bind(consist_id)
+            .bind(i64::try_from(position)?)
+            .bind(owned_rolling_stock_id)
+            .execute(&mut *conn)
+            .await
+            .with_context(|| {
+                format!(
+                    "inserting consist_entry consist_id={} owned_rolling_stock_id={}",
+                    consist_id, owned_rolling_stock_id
+                )
+            })?;
+    }
+
+    Ok(())
+}
+
+/// Insert a decoder row.
+///
+/// `created_at` and `updated_at` are set to the current UTC time.
+pub async fn insert_decoder<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &DecoderRow,
+) -> Result<()> {
+    let sql = "INSERT INTO decoders (id, manufacturer, model, dcc_interface, address, sound_project, firmware, purchase_price_amount, purchase_price_currency, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?10)";
+
+    sqlx::query(sql)
+        .bind(&row.id)
+        .bind(&row.manufacturer)
+        .bind(&row.model)
+        .bind(&row.dcc_interface)
+        .bind(row.address)
+        .bind(&row.sound_project)
+        .bind(&row.firmware)
+        .bind(row.purchase_price_amount)
+        .bind(&row.purchase_price_currency)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| format!("inserting decoder id={}", row.id))?;
+
+    Ok(())
+}
+
+/// Overwrite the decoder row with the id of `row`, setting `updated_at` to
+/// the current UTC time.
+///
+/// Returns `false` when there is no such decoder.
+pub async fn update_decoder<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &DecoderRow,
+) -> Result<bool> {
+    let sql = "UPDATE decoders SET manufacturer = ?2, model = ?3, dcc_interface = ?4, address = ?5, sound_project = ?6, firmware = ?7, purchase_price_amount = ?8, purchase_price_currency = ?9, updated_at = ?10 WHERE id = ?1";
+
+    let result = sqlx::query(sql)
+        .bind(&row.id)
+        .bind(&row.manufacturer)
+        .bind(&row.model)
+        .bind(&row.dcc_interface)
+        .bind(row.address)
+        .bind(&row.sound_project)
+        .bind(&row.firmware)
+        .bind(row.purchase_price_amount)
+        .bind(&row.purchase_price_currency)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| format!("updating decoder id={}", row.id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete the decoder with `decoder_id`; the rolling stock it was installed
+/// in is left without a decoder.
+///
+/// Returns `false` when there is no such decoder.
+pub async fn delete_decoder<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    decoder_id: &str,
+) -> Result<bool> {
+    let result = sqlx::query("DELETE FROM decoders WHERE id = ?1")
+        .bind(decoder_id)
+        .execute(executor)
+        .await
+        .with_context(|| format!("deleting decoder id={}", decoder_id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Fetch a single decoder by id.
+pub async fn get_decoder<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    decoder_id: &str,
+) -> Result<Option<DecoderRow>> {
+    let row = sqlx::query_as!(
+        DecoderRow,
+        r#"SELECT id AS "id!", manufacturer, model, dcc_interface, address, sound_project, firmware, purchase_price_amount, purchase_price_currency FROM decoders WHERE id = ?1 LIMIT 1"#,
+        decoder_id
+    )
+    .fetch_optional(executor)
+    .await
+    .with_context(|| format!("querying decoder id={}", decoder_id))?;
+
+    Ok(row)
+}
+
+/// Fetch every decoder, ordered by manufacturer, model and id.
+pub async fn get_decoders<'e, E: SqliteExecutor<'e>>(executor: E) -> Result<Vec<DecoderRow>> {
+    let rows = sqlx::query_as!(
+        DecoderRow,
+        r#"SELECT id AS "id!", manufacturer, model, dcc_interface, address, sound_project, firmware, purchase_price_amount, purchase_price_currency FROM decoders ORDER BY manufacturer, model, id"#
+    )
+    .fetch_all(executor)
+    .await
+    .context("querying decoders")?;
+
+    Ok(rows)
+}
+
+/// Fetch the decoders installed in the owned rolling stocks of the
+/// collection item with `collection_item_id`, ordered by id.
+pub async fn get_item_decoders<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &CollectionItemId,
+) -> Result<Vec<DecoderRow>> {
+    let rows = sqlx::query_as!(
+        DecoderRow,
+        r#"SELECT d.id AS "id!", d.manufacturer, d.model, d.dcc_interface, d.address, d.sound_project, d.firmware, d.purchase_price_amount, d.purchase_price_currency FROM decoders d JOIN owned_rolling_stocks o ON o.decoder_id = d.id WHERE o.collection_item_id = ?1 ORDER BY d.id"#,
+        collection_item_id
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying decoders installed in collection_item id={}",
+            collection_item_id
+        )
+    })?;
+
+    Ok(rows)
+}
+
+/// Fetch the decoders programmed to `address`, ordered by id.
+pub async fn get_decoders_by_address<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    address: i64,
+) -> Result<Vec<DecoderRow>> {
+    let rows = sqlx::query_as!(
+        DecoderRow,
+        r#"SELECT id AS "id!", manufacturer, model, dcc_interface, address, sound_project, firmware, purchase_price_amount, purchase_price_currency FROM decoders WHERE address = ?1 ORDER BY id"#,
+        address
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| format!("querying decoders with address={}", address))?;
+
+    Ok(rows)
+}
+
+/// Query used by `get_address_conflicts`.
+const ADDRESS_CONFLICTS_SQL: &str =
+    "SELECT d.address, ors.id AS owned_rolling_stock_id, d.id AS decoder_id
+        FROM owned_rolling_stocks ors
+        JOIN decoders d ON d.id = ors.decoder_id
+        WHERE d.address IN (
+            SELECT d2.address
+            FROM owned_rolling_stocks ors2
+            JOIN decoders d2 ON d2.id = ors2.decoder_id
+            GROUP BY d2.address
+            HAVING COUNT(*) > 1
+        )
+        ORDER BY d.address, ors.id";
+
+/// Fetch the decoder installations whose address is shared with another
+/// installed decoder, ordered by address and owned rolling stock id.
+pub async fn get_address_conflicts<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+) -> Result<Vec<DecoderInstallationRow>> {
+    let rows = sqlx::query_as::<_, DecoderInstallationRow>(ADDRESS_CONFLICTS_SQL)
+        .fetch_all(executor)
+        .await
+        .context("querying decoder address conflicts")?;
+
+    Ok(rows)
+}
+
+/// The id of the owned rolling stock the decoder with `decoder_id` is
+/// installed in, if any.
+pub async fn get_decoder_installation<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    decoder_id: &str,
+) -> Result<Option<String>> {
+    let owned_id = sqlx::query_scalar::<_, String>(
+        "SELECT id FROM owned_rolling_stocks WHERE decoder_id = ?1 LIMIT 1",
+    )
+    .bind(decoder_id)
+    .fetch_optional(executor)
+    .await
+    .with_context(|| format!("querying the installation of decoder id={}", decoder_id))?;
+
+    Ok(owned_id)
+}
+
+/// The DCC interface stored for the catalog rolling stock of an owned
+/// rolling stock.
+///
+/// `None` when there is no owned rolling stock with `owned_rolling_stock_id`;
+/// `Some(None)` when it has no catalog rolling stock or the catalog does not
+/// record an interface.
+pub async fn get_owned_rolling_stock_dcc_interface<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    owned_rolling_stock_id: &str,
+) -> Result<Option<Option<String>>> {
+    let dcc_interface = sqlx::query_scalar::<_, Option<String>>(
+        "SELECT rs.dcc_interface FROM owned_rolling_stocks AS ors LEFT JOIN rolling_stocks AS rs ON rs.id = ors.rolling_stock_id WHERE ors.id = ?1",
+    )
+    .bind(owned_rolling_stock_id)
+    .fetch_optional(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying the dcc interface of owned_rolling_stock id={}",
+            owned_rolling_stock_id
+        )
+    })?;
+
+    Ok(dcc_interface)
+}
+
+/// Set (or with `None` clear) the decoder installed in an owned rolling
+/// stock.
+///
+/// Returns `false` when there is no owned rolling stock with
+/// `owned_rolling_stock_id`.
+pub async fn set_owned_rolling_stock_decoder<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    owned_rolling_stock_id: &str,
+    decoder_id: Option<&str>,
+) -> Result<bool> {
+    let result = sqlx::query("UPDATE owned_rolling_stocks SET decoder_id = ?2 WHERE id = ?1")
+        .bind(owned_rolling_stock_id)
+        .bind(decoder_id)
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "setting the decoder of owned_rolling_stock id={} to {:?}",
+                owned_rolling_stock_id, decoder_id
+            )
+        })?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Fetch every storage location, ordered by name (case-insensitive).
+pub async fn get_storage_locations<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+) -> Result<Vec<StorageLocationRow>> {
+    let rows = sqlx::query_as!(
+        StorageLocationRow,
+        r#"SELECT id AS "id!", name, description, parent_id FROM storage_locations ORDER BY name COLLATE NOCASE, id"#
+    )
+    .fetch_all(executor)
+    .await
+    .context("listing storage_locations")?;
+
+    Ok(rows)
+}
+
+/// Fetch a single storage location row by id.
+pub async fn get_storage_location<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    location_id: &str,
+) -> Result<Option<StorageLocationRow>> {
+    let row = sqlx::query_as!(
+        StorageLocationRow,
+        r#"SELECT id AS "id!", name, description, parent_id FROM storage_locations WHERE id = ?1"#,
+        location_id
+    )
+    .fetch_optional(executor)
+    .await
+    .with_context(|| format!("querying storage_location id={}", location_id))?;
+
+    Ok(row)
+}
+
+/// Insert a storage location row.
+///
+/// `created_at` and `updated_at` are set to the current UTC time.
+pub async fn insert_storage_location<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &StorageLocationRow,
+) -> Result<()> {
+    let sql = "INSERT INTO storage_locations (id, name, description, parent_id, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?5)";
+
+    sqlx::query(sql)
+        .bind(&row.id)
+        .bind(&row.name)
+        .bind(&row.description)
+        .bind(&row.parent_id)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| format!("inserting storage_location id={}", row.id))?;
+
+    Ok(())
+}
+
+/// Update a storage location row, setting its `updated_at` to the current
+/// UTC time.
+///
+/// Returns `false` when there is no such location.
+pub async fn update_storage_location<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    row: &StorageLocationRow,
+) -> Result<bool> {
+    let sql = "UPDATE storage_locations SET name = ?2, description = ?3, parent_id = ?4, updated_at = ?5 WHERE id = ?1";
+
+    let result = sqlx::query(sql)
+        .bind(&row.id)
+        .bind(&row.name)
+        .bind(&row.description)
+        .bind(&row.parent_id)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| format!("updating storage_location id={}", row.id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete the storage location with `location_id`.
+///
+/// Fails while items or other locations reference it (see
+/// `count_storage_location_contents`). Returns `false` when there is no such
+/// location.
+pub async fn delete_storage_location<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    location_id: &str,
+) -> Result<bool> {
+    let result = sqlx::query("DELETE FROM storage_locations WHERE id = ?1")
+        .bind(location_id)
+        .execute(executor)
+        .await
+        .with_context(|| format!("deleting storage_location id={}", location_id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Count what is directly in the storage location with `location_id`: the
+/// collection items and owned rolling stocks kept there, and the locations
+/// nested in it.
+pub async fn count_storage_location_contents(
+    conn: &mut SqliteConnection,
+    location_id: &str,
+) -> Result<(i64, i64)> {
+    let items = sqlx::query_scalar!(
+        r#"SELECT (SELECT COUNT(*) FROM collection_items WHERE location_id = ?1)
+            + (SELECT COUNT(*) FROM owned_rolling_stocks WHERE location_id = ?1) AS "items!: i64""#,
+        location_id
+    )
+    .fetch_one(&mut *conn)
+    .await
+    .with_context(|| format!("counting the items in storage_location id={}", location_id))?;
+    let locations = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "locations!: i64" FROM storage_locations WHERE parent_id = ?1"#,
+        location_id
+    )
+    .fetch_one(&mut *conn)
+    .await
+    .with_context(|| {
+        format!(
+            "counting the locations in storage_location id={}",
+            location_id
+        )
+    })?;
+
+    Ok((items, locations))
+}
+
+/// Move everything directly in the storage location `from_id` (items,
+/// owned rolling stocks and nested locations) into `to_id`.
+///
+/// Moved collection items get a new version, as by
+/// `set_collection_item_location`.
+pub async fn move_storage_location_contents(
+    conn: &mut SqliteConnection,
+    from_id: &str,
+    to_id: &str,
+) -> Result<()> {
+    let context = || {
+        format!(
+            "moving the contents of storage_location id={} to id={}",
+            from_id, to_id
+        )
+    };
+    sqlx::query("UPDATE collection_items SET location_id = ?2, updated_at = ?3, version = version + 1 WHERE location_id = ?1")
+        .bind(from_id)
+        .bind(to_id)
+        .bind(Utc::now())
+        .execute(&mut *conn)
+        .await
+        .with_context(context)?;
+    sqlx::query("UPDATE owned_rolling_stocks SET location_id = ?2 WHERE location_id = ?1")
+        .bind(from_id)
+        .bind(to_id)
+        .execute(&mut *conn)
+        .await
+        .with_context(context)?;
+    sqlx::query(
+        "UPDATE storage_locations SET parent_id = ?2, updated_at = ?3 WHERE parent_id = ?1",
+    )
+    .bind(from_id)
+    .bind(to_id)
+    .bind(Utc::now())
+    .execute(&mut *conn)
+    .await
+    .with_context(context)?;
+
+    Ok(())
+}
+
+/// Set (or with `None` clear) the storage location of a collection item,
+/// incrementing its version and setting its `updated_at` to the current UTC
+/// time.
+///
+/// Returns `false` when there is no collection item with
+/// `collection_item_id`.
+pub async fn set_collection_item_location<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &str,
+    location_id: Option<&str>,
+) -> Result<bool> {
+    let sql = "UPDATE collection_items SET location_id = ?2, updated_at = ?3, version = version + 1 WHERE id = ?1";
+
+    let result = sqlx::query(sql)
+        .bind(collection_item_id)
+        .bind(location_id)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "setting the location of collection_item id={} to {:?}",
+                collection_item_id, location_id
+            )
+        })?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Fetch the condition and storage location of the collection items
+/// matching `filter`, in the order they were added.
+pub async fn find_collection_items_by_filter<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    filter: &CollectionItemFilter,
+) -> Result<Vec<FilteredItemRow>> {
+    let category = filter.category.map(|c| c.to_string());
+    let rows = sqlx::query_as!(
+        FilteredItemRow,
+        r#"SELECT ci.id AS "id!", ci.conditions, ci.location_id
+        FROM collection_items AS ci
+        JOIN railway_models AS rm ON rm.id = ci.railway_model_id
+        WHERE (?1 IS NULL OR ci.collection_id = ?1)
+            AND (?2 IS NULL OR rm.manufacturer_id = ?2)
+            AND (?3 IS NULL OR rm.category = ?3)
+            AND (?4 IS NULL OR ci.location_id = ?4)
+            AND (?5 IS NULL OR ci.conditions = ?5)
+        ORDER BY ci.created_at, ci.id"#,
+        filter.collection_id,
+        filter.manufacturer_id,
+        category,
+        filter.location_id,
+        filter.conditions
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| format!("querying the collection items matching {:?}", filter))?;
+
+    Ok(rows)
+}
+
+/// Apply `patch` to the collection item with `collection_item_id`,
+/// incrementing its version and setting its `updated_at` to the current UTC
+/// time. Fields the patch leaves `None` are not changed.
+///
+/// Returns `false` when there is no collection item with
+/// `collection_item_id`.
+pub async fn patch_collection_item<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &str,
+    patch: &CollectionItemPatch,
+) -> Result<bool> {
+    let sql = "UPDATE collection_items SET conditions = COALESCE(?2, conditions), location_id = CASE WHEN ?3 THEN ?4 ELSE location_id END, updated_at = ?5, version = version + 1 WHERE id = ?1";
+
+    let result = sqlx::query(sql)
+        .bind(collection_item_id)
+        .bind(patch.conditions.as_deref())
+        .bind(patch.location.is_some())
+        .bind(patch.location.as_ref().and_then(|l| l.location_id()))
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| format!("patching collection_item id={}", collection_item_id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Set (or with `None` clear) the storage location of an owned rolling stock
+/// kept apart from the rest of its item.
+///
+/// Returns `false` when there is no owned rolling stock with
+/// `owned_rolling_stock_id`.
+pub async fn set_owned_rolling_stock_location<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    owned_rolling_stock_id: &str,
+    location_id: Option<&str>,
+) -> Result<bool> {
+    let result = sqlx::query("UPDATE owned_rolling_stocks SET location_id = ?2 WHERE id = ?1")
+        .bind(owned_rolling_stock_id)
+        .bind(location_id)
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "setting the location of owned_rolling_stock id={} to {:?}",
+                owned_rolling_stock_id, location_id
+            )
+        })?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Fetch the collection items and the owned rolling stocks kept in the
+/// storage location with `location_id` or, when `recursive`, in it and in
+/// the locations nested in it at any depth.
+///
+/// Rows are ordered by location, collection item and owned rolling stock; an
+/// item row comes before the rows of its rolling stocks.
+pub async fn find_items_by_location<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    location_id: &str,
+    recursive: bool,
+) -> Result<Vec<StoredItemRow>> {
+    let rows = sqlx::query_as!(
+        StoredItemRow,
+        r#"WITH RECURSIVE locations(id) AS (
+            SELECT id FROM storage_locations WHERE id = ?1
+            UNION
+            SELECT sl.id FROM storage_locations AS sl JOIN locations AS l ON sl.parent_id = l.id WHERE ?2
+        )
+        SELECT ci.id AS "collection_item_id!: _", ci.railway_model_id AS "railway_model_id!: _",
+            CAST(NULL AS TEXT) AS "owned_rolling_stock_id?: String", ci.location_id AS "location_id!"
+        FROM collection_items AS ci
+        WHERE ci.location_id IN (SELECT id FROM locations)
+        UNION ALL
+        SELECT ors.collection_item_id, ci.railway_model_id, ors.id, ors.location_id
+        FROM owned_rolling_stocks AS ors
+        JOIN collection_items AS ci ON ci.id = ors.collection_item_id
+        WHERE ors.location_id IN (SELECT id FROM locations)
+        ORDER BY 4, 1, 3"#,
+        location_id,
+        recursive
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying the items in storage_location id={} recursive={}",
+            location_id, recursive
+        )
+    })?;
+
+    Ok(rows)
+}
+
+/// Fetch the outstanding loan of the collection item with
+/// `collection_item_id`, if it is on loan.
+pub async fn get_outstanding_loan<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &CollectionItemId,
+) -> Result<Option<LoanRow>> {
+    let row = sqlx::query_as!(
+        LoanRow,
+        r#"SELECT id AS "id!", collection_item_id AS "collection_item_id: _", borrower_name, borrower_email, borrower_phone,
+            loaned_on AS "loaned_on: _", due_back AS "due_back: _", returned_on AS "returned_on: _"
+        FROM loans WHERE collection_item_id = ?1 AND returned_on IS NULL"#,
+        collection_item_id
+    )
+    .fetch_optional(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying the outstanding loan of collection_item id={}",
+            collection_item_id
+        )
+    })?;
+
+    Ok(row)
+}
+
+/// Fetch the outstanding loans, or with `overdue_on` only those due back
+/// before that day, ordered by due date (loans without one last) and loan
+/// date.
+pub async fn get_outstanding_loans<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    overdue_on: Option<NaiveDate>,
+) -> Result<Vec<LoanRow>> {
+    let rows = sqlx::query_as!(
+        LoanRow,
+        r#"SELECT id AS "id!", collection_item_id AS "collection_item_id: _", borrower_name, borrower_email, borrower_phone,
+            loaned_on AS "loaned_on: _", due_back AS "due_back: _", returned_on AS "returned_on: _"
+        FROM loans
+        WHERE returned_on IS NULL AND (?1 IS NULL OR due_back < ?1)
+        ORDER BY due_back IS NULL, due_back, loaned_on, id"#,
+        overdue_on
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| format!("querying outstanding loans overdue_on={:?}", overdue_on))?;
+
+    Ok(rows)
+}
+
+/// Insert a loan row.
+///
+/// `created_at` and `updated_at` are set to the current UTC time. Fails when
+/// the item already has an outstanding loan (`idx_loans_outstanding`).
+pub async fn insert_loan<'e, E: SqliteExecutor<'e>>(executor: E, row: &LoanRow) -> Result<()> {
+    let sql = "INSERT INTO loans (id, collection_item_id, borrower_name, borrower_email, borrower_phone, loaned_on, due_back, returned_on, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?9)";
+
+    sqlx::query(sql)
+        .bind(&row.id)
+        .bind(&row.collection_item_id)
+        .bind(&row.borrower_name)
+        .bind(&row.borrower_email)
+        .bind(&row.borrower_phone)
+        .bind(row.loaned_on)
+        .bind(row.due_back)
+        .bind(row.returned_on)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "inserting loan id={} collection_item_id={}",
+                row.id, row.collection_item_id
+            )
+        })?;
+
+    Ok(())
+}
+
+/// Record the return of the loan with `loan_id` on `returned_on`, setting
+/// its `updated_at` to the current UTC time.
+///
+/// Returns `false` when there is no such loan.
+pub async fn set_loan_returned<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    loan_id: &str,
+    returned_on: NaiveDate,
+) -> Result<bool> {
+    let result = sqlx::query("UPDATE loans SET returned_on = ?2, updated_at = ?3 WHERE id = ?1")
+        .bind(loan_id)
+        .bind(returned_on)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| format!("returning loan id={}", loan_id))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Whether the collection item with `collection_item_id` was sold, going by
+/// its purchase info.
+pub async fn is_collection_item_sold<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &CollectionItemId,
+) -> Result<bool> {
+    let sold = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM purchase_infos WHERE collection_item_id = ?1 AND purchase_type = 'sold') AS "sold!: bool""#,
+        collection_item_id
+    )
+    .fetch_one(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying whether collection_item id={} was sold",
+            collection_item_id
+        )
+    })?;
+
+    Ok(sold)
+}
+
+/// Fetch all purchase infos associated to a collection (via collection_items).
+///
+/// Joins `purchase_infos` to `collection_items` and binds the collection id
+/// parameter to prevent SQL injection.
+pub async fn get_purchase_infos(
+    pool: &SqlitePool,
+    collection_id: &CollectionId,
+) -> Result<Vec<PurchaseInfoRow>> {
+    let rows = sqlx::query_file_as!(
+        PurchaseInfoRow,
+        "queries/collecting/purchase_infos.sql",
+        collection_id
+    )
+    .fetch_all(pool)
+    .await
+    .with_context(|| {
+        format!(
+            "querying purchase_infos for collection_id={}",
+            collection_id
+        )
+    })?;
+
+    Ok(rows)
+}
+
+/// Stream the items of a collection joined with their owned rolling stocks
+/// and purchase infos, calling `handle` for every row as it arrives.
+///
+/// This loads everything `get_collection_items`, `get_owned_rolling_stocks`
+/// and `get_purchase_infos` return in a single round trip. Rows are ordered by
+/// item (in insertion order), so all rows of one item are contiguous; within an
+/// item they are ordered by owned rolling stock, then purchase info. As with
+/// `get_owned_rolling_stocks`, `owned_rolling_stock_id` is `None` unless it
+/// refers to an existing rolling stock. An error from `handle` stops the
+/// stream and is returned.
+pub async fn for_each_collection_detail<F>(
+    pool: &SqlitePool,
+    collection_id: &CollectionId,
+    handle: F,
+) -> Result<()>
+where
+    F: FnMut(CollectionDetailRow) -> Result<()>,
+{
+    let rows = sqlx::query_file_as!(
+        CollectionDetailRow,
+        "queries/collecting/collection_details.sql",
+        collection_id
+    )
+    .fetch(pool);
+
+    drain_detail_rows(rows, handle, || {
+        format!(
+            "querying collection details for collection_id={}",
+            collection_id
+        )
+    })
+    .await
+}
+
+/// Like `for_each_collection_detail`, for a page of at most `limit` items
+/// ordered by `created_at` (as stored, legacy rows without one first) and
+/// id, starting after the item with the `after` keys (from the first one
+/// when `None`).
+pub async fn for_each_collection_items_page_detail<F>(
+    pool: &SqlitePool,
+    collection_id: &CollectionId,
+    after: Option<&PageKey>,
+    limit: u32,
+    handle: F,
+) -> Result<()>
+where
+    F: FnMut(CollectionDetailRow) -> Result<()>,
+{
+    let (sort_key, id) = after.map_or(("", ""), |key| (&key.sort_key, &key.id));
+    let rows = sqlx::query_file_as!(
+        CollectionDetailRow,
+        "queries/collecting/collection_items_page.sql",
+        collection_id,
+        sort_key,
+        id,
+        limit
+    )
+    .fetch(pool);
+
+    drain_detail_rows(rows, handle, || {
+        format!(
+            "querying collection items page for collection_id={} after sort_key={:?} id={}",
+            collection_id, sort_key, id
+        )
+    })
+    .await
+}
+
+/// Like `for_each_collection_detail`, for the item of the collection with
+/// `item_number` only; `handle` is not called when there is none.
+pub async fn for_each_collection_item_by_number_detail<F>(
+    pool: &SqlitePool,
+    collection_id: &CollectionId,
+    item_number: u32,
+    handle: F,
+) -> Result<()>
+where
+    F: FnMut(CollectionDetailRow) -> Result<()>,
+{
+    let rows = sqlx::query_file_as!(
+        CollectionDetailRow,
+        "queries/collecting/collection_item_by_number.sql",
+        collection_id,
+        item_number
+    )
+    .fetch(pool);
+
+    drain_detail_rows(rows, handle, || {
+        format!(
+            "querying collection item number={} for collection_id={}",
+            item_number, collection_id
+        )
+    })
+    .await
+}
+
+/// Call `handle` for every detail row, stopping at the first error.
+async fn drain_detail_rows<F>(
+    mut rows: BoxStream<'_, sqlx::Result<CollectionDetailRow>>,
+    mut handle: F,
+    context: impl Fn() -> String,
+) -> Result<()>
+where
+    F: FnMut(CollectionDetailRow) -> Result<()>,
+{
+    while let Some(row) = rows.try_next().await.with_context(&context)? {
+        handle(row)?;
+    }
+
+    Ok(())
+}
+
+/// Query used by `compute_collection_summary`.
+const COLLECTION_SUMMARY_SQL: &str = "SELECT
+            COALESCE(SUM(rm.category = 'LOCOMOTIVES' COLLATE NOCASE), 0) AS locomotives_count,
+            COALESCE(SUM(rm.category = 'PASSENGER_CARS' COLLATE NOCASE), 0) AS passenger_cars_count,
+            COALESCE(SUM(rm.category = 'FREIGHT_CARS' COLLATE NOCASE), 0) AS freight_cars_count,
+            COALESCE(SUM(rm.category COLLATE NOCASE IN ('TRAIN_SETS', 'STARTER_SETS')), 0) AS train_sets_count,
+            COALESCE(SUM(rm.category = 'RAILCARS' COLLATE NOCASE), 0) AS railcars_count,
+            COALESCE(SUM(rm.category = 'ELECTRIC_MULTIPLE_UNITS' COLLATE NOCASE), 0) AS electric_multiple_units_count
+        FROM collection_items ci
+        JOIN railway_models rm ON rm.id = ci.railway_model_id
+        WHERE ci.collection_id = ?1";
+
+/// Compute the summary counters of a collection from its items.
+///
+/// Each collection item is counted once, according to the category of the
+/// railway model it references (compared case-insensitively). Starter sets
+/// are counted as train sets.
+pub async fn compute_collection_summary<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+) -> Result<CollectionSummaryRow> {
+    let row = sqlx::query_as::<_, CollectionSummaryRow>(COLLECTION_SUMMARY_SQL)
+        .bind(collection_id)
+        .fetch_one(executor)
+        .await
+        .with_context(|| format!("computing summary for collection_id={}", collection_id))?;
+
+    Ok(row)
+}
+
+/// Query used by `compute_collection_total_values`; `?2` is the valued
+/// purchase type of the `CollectionValuePolicy`.
+const COLLECTION_TOTAL_VALUES_SQL: &str = "SELECT pi.purchased_price_currency AS currency,
+            SUM(pi.purchased_price_amount + COALESCE((
+                SELECT SUM(ma.amount)
+                FROM monetary_adjustments ma
+                WHERE ma.purchase_id = pi.purchase_id
+                    AND ma.currency = pi.purchased_price_currency
+            ), 0)) AS amount
+        FROM purchase_infos pi
+        JOIN collection_items ci ON ci.id = pi.collection_item_id
+        WHERE ci.collection_id = ?1
+            AND pi.purchase_type = ?2
+            AND pi.purchased_price_amount IS NOT NULL
+            AND pi.purchased_price_currency IS NOT NULL
+        GROUP BY pi.purchased_price_currency
+        ORDER BY pi.purchased_price_currency";
+
+/// Compute the total purchase price of a collection's items, per currency.
+///
+/// The items counted are those of `CollectionValuePolicy`: only items with a
+/// `purchased` purchase info and a known price contribute, sold items are
+/// excluded from the collection value and pre-ordered items are not owned
+/// yet. Prices are net of their monetary adjustments. Rows are ordered by
+/// currency code.
+pub async fn compute_collection_total_values<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+) -> Result<Vec<CurrencyTotalRow>> {
+    let rows = sqlx::query_as::<_, CurrencyTotalRow>(COLLECTION_TOTAL_VALUES_SQL)
+        .bind(collection_id)
+        .bind(CollectionValuePolicy::VALUED_PURCHASE_TYPE)
+        .fetch_all(executor)
+        .await
+        .with_context(|| format!("computing total values for collection_id={}", collection_id))?;
+
+    Ok(rows)
+}
+
+/// Query used by `compute_collection_realized_gains`; `?2` is the realized
+/// purchase type of the `CollectionValuePolicy`.
+const COLLECTION_REALIZED_GAINS_SQL: &str = "SELECT pi.purchased_price_currency AS currency,
+            SUM(pi.sale_price_amount - pi.purchased_price_amount - COALESCE((
+                SELECT SUM(ma.amount)
+                FROM monetary_adjustments ma
+                WHERE ma.purchase_id = pi.purchase_id
+                    AND ma.currency = pi.purchased_price_currency
+            ), 0)) AS amount
+        FROM purchase_infos pi
+        JOIN collection_items ci ON ci.id = pi.collection_item_id
+        WHERE ci.collection_id = ?1
+            AND pi.purchase_type = ?2
+            AND pi.purchased_price_amount IS NOT NULL
+            AND pi.sale_price_amount IS NOT NULL
+            AND pi.sale_price_currency = pi.purchased_price_currency
+        GROUP BY pi.purchased_price_currency
+        ORDER BY pi.purchased_price_currency";
+
+/// Compute the gains realized by selling a collection's items, per currency.
+///
+/// Following `CollectionValuePolicy::realized_gain`, a sold item contributes
+/// its sale price minus its purchase price net of monetary adjustments
+/// (negative for a loss); items whose purchase price is unknown or that were
+/// sold in another currency are left out. Rows are ordered by currency code.
+pub async fn compute_collection_realized_gains<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+) -> Result<Vec<CurrencyTotalRow>> {
+    let rows = sqlx::query_as::<_, CurrencyTotalRow>(COLLECTION_REALIZED_GAINS_SQL)
+        .bind(collection_id)
+        .bind(CollectionValuePolicy::REALIZED_PURCHASE_TYPE)
+        .fetch_all(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "computing realized gains for collection_id={}",
+                collection_id
+            )
+        })?;
+
+    Ok(rows)
+}
+
+/// Query used by `get_collection_discount_prices`; `?2` and `?3` are the
+/// valued and realized purchase types of the `CollectionValuePolicy`.
+const COLLECTION_DISCOUNT_PRICES_SQL: &str = "SELECT pi.purchased_price_amount AS price_amount,
+            pi.purchased_price_currency AS price_currency,
+            rm.msrp_amount, rm.msrp_currency
+        FROM purchase_infos pi
+        JOIN collection_items ci ON ci.id = pi.collection_item_id
+        JOIN railway_models rm ON rm.id = ci.railway_model_id
+        WHERE ci.collection_id = ?1
+            AND pi.purchase_type IN (?2, ?3)
+            AND pi.purchased_price_amount IS NOT NULL
+            AND pi.purchased_price_currency IS NOT NULL
+            AND rm.msrp_amount IS NOT NULL
+            AND rm.msrp_currency IS NOT NULL";
+
+/// Fetch the price paid for each purchased or sold item of a collection
+/// with the suggested retail price of its railway model, for the items
+/// where both are known.
+pub async fn get_collection_discount_prices<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+) -> Result<Vec<DiscountPriceRow>> {
+    let rows = sqlx::query_as::<_, DiscountPriceRow>(COLLECTION_DISCOUNT_PRICES_SQL)
+        .bind(collection_id)
+        .bind(CollectionValuePolicy::VALUED_PURCHASE_TYPE)
+        .bind(CollectionValuePolicy::REALIZED_PURCHASE_TYPE)
+        .fetch_all(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "fetching discount prices for collection_id={}",
+                collection_id
+            )
+        })?;
+
+    Ok(rows)
+}
+
+/// Query used by `find_missing_rolling_stock_references`.
+const MISSING_ROLLING_STOCKS_SQL: &str = "SELECT ors.id AS owned_rolling_stock_id,
+            ors.collection_item_id,
+            ors.rolling_stock_id
+        FROM collection_items ci
+        JOIN owned_rolling_stocks ors ON ors.collection_item_id = ci.id
+        WHERE ci.collection_id = ?1
+            AND ors.rolling_stock_id IS NOT NULL
+            AND NOT EXISTS (SELECT 1 FROM rolling_stocks rs WHERE rs.id = ors.rolling_stock_id)
+        ORDER BY ors.id";
+
+/// Fetch the owned rolling stocks of a collection that refer to a rolling
+/// stock missing from the catalog.
+///
+/// The foreign key prevents this, but not when the database was written
+/// with foreign keys disabled, for instance by hand or by an old import.
+pub async fn find_missing_rolling_stock_references<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+) -> Result<Vec<MissingRollingStockRow>> {
+    let rows = sqlx::query_as::<_, MissingRollingStockRow>(MISSING_ROLLING_STOCKS_SQL)
+        .bind(collection_id)
+        .fetch_all(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "finding missing rolling stocks for collection_id={}",
+                collection_id
+            )
+        })?;
+
+    Ok(rows)
+}
+
+/// Clear the references of the owned rolling stocks with `ids` to their
+/// rolling stock, returning how many were cleared.
+pub async fn clear_rolling_stock_references<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    ids: &[String],
+) -> Result<u64> {
+    if ids.is_empty() {
+        return Ok(0);
+    }
+    let placeholders = vec!["?"; ids.len()].join(", ");
+    let sql = format!(
+        "UPDATE owned_rolling_stocks SET rolling_stock_id = NULL WHERE id IN ({placeholders})"
+    );
+    let mut query = sqlx::query(&sql);
+    for id in ids {
+        query = query.bind(id);
+    }
+    let result = query
+        .execute(executor)
+        .await
+        .context("clearing rolling stock references")?;
+
+    Ok(result.rows_affected())
+}
+
+/// Query used by `get_purchase_currencies`.
+const PURCHASE_CURRENCIES_SQL: &str = "SELECT pi.purchase_id,
+            pi.purchased_price_currency,
+            pi.sale_price_currency,
+            pi.deposit_currency,
+            pi.preorder_total_currency
+        FROM collection_items ci
+        JOIN purchase_infos pi ON pi.collection_item_id = ci.id
+        WHERE ci.collection_id = ?1
+        ORDER BY pi.purchase_id";
+
+/// Fetch the currencies stored on each purchase info of a collection.
+pub async fn get_purchase_currencies<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+) -> Result<Vec<PurchaseCurrenciesRow>> {
+    let rows = sqlx::query_as::<_, PurchaseCurrenciesRow>(PURCHASE_CURRENCIES_SQL)
+        .bind(collection_id)
+        .fetch_all(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "fetching purchase currencies for collection_id={}",
+                collection_id
+            )
+        })?;
+
+    Ok(rows)
+}
+
+/// Fetch the stored price and replacement value of every item of a
+/// collection in a single query, in the order the items were added.
+pub async fn get_stored_item_amounts<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+) -> Result<Vec<StoredItemAmountsRow>> {
+    let rows = sqlx::query_as!(
+        StoredItemAmountsRow,
+        r#"SELECT ci.id AS "collection_item_id!: _",
+            CASE WHEN pi.purchase_type = 'preorder' THEN pi.preorder_total_amount ELSE pi.purchased_price_amount END AS "price_amount?: i64",
+            CASE WHEN pi.purchase_type = 'preorder' THEN pi.preorder_total_currency ELSE pi.purchased_price_currency END AS "price_currency?: String",
+            ci.replacement_value_amount, ci.replacement_value_currency
+        FROM collection_items AS ci
+        LEFT JOIN purchase_infos AS pi ON pi.collection_item_id = ci.id
+        WHERE ci.collection_id = ?1
+        ORDER BY ci.created_at, ci.id"#,
+        collection_id
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying the stored amounts of collection_id={}",
+            collection_id
+        )
+    })?;
+
+    Ok(rows)
+}
+
+/// The first day of `year` and of the year after, bounding the dates of a
+/// year review.
+fn year_bounds(year: i32) -> Result<(NaiveDate, NaiveDate)> {
+    let bounds = NaiveDate::from_ymd_opt(year, 1, 1).zip(
+        year.checked_add(1)
+            .and_then(|next| NaiveDate::from_ymd_opt(next, 1, 1)),
+    );
+    bounds.with_context(|| format!("invalid review year={}", year))
+}
+
+/// Query used by `count_year_purchases_and_sales`; `?2` and `?3` bound the
+/// year, `?4` and `?5` are the valued and realized purchase types of the
+/// `CollectionValuePolicy`.
+const YEAR_COUNTS_SQL: &str = "SELECT
+            COUNT(CASE WHEN pi.purchase_type IN (?4, ?5)
+                AND pi.purchase_date >= ?2 AND pi.purchase_date < ?3 THEN 1 END) AS items_bought,
+            COUNT(CASE WHEN pi.purchase_type = ?5
+                AND pi.sale_date >= ?2 AND pi.sale_date < ?3 THEN 1 END) AS items_sold
+        FROM purchase_infos pi
+        JOIN collection_items ci ON ci.id = pi.collection_item_id
+        WHERE ci.collection_id = ?1";
+
+/// Count the items of a collection bought and sold during `year`.
+///
+/// Bought items are the purchased and sold ones with a purchase date in the
+/// year; sold items are those with a sale date in the year.
+pub async fn count_year_purchases_and_sales<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+    year: i32,
+) -> Result<YearCountsRow> {
+    let (start, end) = year_bounds(year)?;
+    let row = sqlx::query_as::<_, YearCountsRow>(YEAR_COUNTS_SQL)
+        .bind(collection_id)
+        .bind(start)
+        .bind(end)
+        .bind(CollectionValuePolicy::VALUED_PURCHASE_TYPE)
+        .bind(CollectionValuePolicy::REALIZED_PURCHASE_TYPE)
+        .fetch_one(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "counting purchases and sales of year={} for collection_id={}",
+                year, collection_id
+            )
+        })?;
+
+    Ok(row)
+}
+
+/// Query used by `get_year_spending`; the parameters are those of
+/// `YEAR_COUNTS_SQL`.
+const YEAR_SPENDING_SQL: &str = "SELECT pi.purchased_price_currency AS currency,
+            SUM(pi.purchased_price_amount + COALESCE((
+                SELECT SUM(ma.amount)
+                FROM monetary_adjustments ma
+                WHERE ma.purchase_id = pi.purchase_id
+                    AND ma.currency = pi.purchased_price_currency
+            ), 0)) AS amount
+        FROM purchase_infos pi
+        JOIN collection_items ci ON ci.id = pi.collection_item_id
+        WHERE ci.collection_id = ?1
+            AND pi.purchase_type IN (?4, ?5)
+            AND pi.purchase_date >= ?2 AND pi.purchase_date < ?3
+            AND pi.purchased_price_amount IS NOT NULL
+            AND pi.purchased_price_currency IS NOT NULL
+        GROUP BY pi.purchased_price_currency
+        ORDER BY pi.purchased_price_currency";
+
+/// Compute what the items of a collection bought during `year` cost, net of
+/// their monetary adjustments, per currency. Items with an unknown price are
+/// left out. Rows are ordered by currency code.
+pub async fn get_year_spending<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+    year: i32,
+) -> Result<Vec<CurrencyTotalRow>> {
+    let (start, end) = year_bounds(year)?;
+    let rows = sqlx::query_as::<_, CurrencyTotalRow>(YEAR_SPENDING_SQL)
+        .bind(collection_id)
+        .bind(start)
+        .bind(end)
+        .bind(CollectionValuePolicy::VALUED_PURCHASE_TYPE)
+        .bind(CollectionValuePolicy::REALIZED_PURCHASE_TYPE)
+        .fetch_all(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "computing spending of year={} for collection_id={}",
+                year, collection_id
+            )
+        })?;
+
+    Ok(rows)
+}
+
+/// Query used by `get_year_biggest_purchases`; the parameters are those of
+/// `YEAR_COUNTS_SQL`.
+const YEAR_BIGGEST_PURCHASES_SQL: &str = "WITH costs AS (
+            SELECT ci.id AS collection_item_id,
+                m.name AS manufacturer,
+                rm.product_code,
+                pi.purchased_price_currency AS currency,
+                pi.purchased_price_amount + COALESCE((
+                    SELECT SUM(ma.amount)
+                    FROM monetary_adjustments ma
+                    WHERE ma.purchase_id = pi.purchase_id
+                        AND ma.currency = pi.purchased_price_currency
+                ), 0) AS amount
+            FROM purchase_infos pi
+            JOIN collection_items ci ON ci.id = pi.collection_item_id
+            JOIN railway_models rm ON rm.id = ci.railway_model_id
+            JOIN manufacturers m ON m.id = rm.manufacturer_id
+            WHERE ci.collection_id = ?1
+                AND pi.purchase_type IN (?4, ?5)
+                AND pi.purchase_date >= ?2 AND pi.purchase_date < ?3
+                AND pi.purchased_price_amount IS NOT NULL
+                AND pi.purchased_price_currency IS NOT NULL
+        ), ranked AS (
+            SELECT *, ROW_NUMBER() OVER (
+                PARTITION BY currency ORDER BY amount DESC, collection_item_id
+            ) AS position
+            FROM costs
+        )
+        SELECT collection_item_id, manufacturer, product_code, currency, amount
+        FROM ranked
+        WHERE position = 1
+        ORDER BY currency";
+
+/// Find the most expensive item of a collection bought during `year` in each
+/// currency, by its cost net of monetary adjustments. Rows are ordered by
+/// currency code.
+pub async fn get_year_biggest_purchases<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+    year: i32,
+) -> Result<Vec<YearPurchaseRow>> {
+    let (start, end) = year_bounds(year)?;
+    let rows = sqlx::query_as::<_, YearPurchaseRow>(YEAR_BIGGEST_PURCHASES_SQL)
+        .bind(collection_id)
+        .bind(start)
+        .bind(end)
+        .bind(CollectionValuePolicy::VALUED_PURCHASE_TYPE)
+        .bind(CollectionValuePolicy::REALIZED_PURCHASE_TYPE)
+        .fetch_all(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "finding the biggest purchases of year={} for collection_id={}",
+                year, collection_id
+            )
+        })?;
+
+    Ok(rows)
+}
+
+/// Query used by `get_year_top_manufacturer`; the parameters are those of
+/// `YEAR_COUNTS_SQL`.
+const YEAR_TOP_MANUFACTURER_SQL: &str = "SELECT m.id AS manufacturer_id,
+            m.name,
+            COUNT(*) AS items_bought
+        FROM purchase_infos pi
+        JOIN collection_items ci ON ci.id = pi.collection_item_id
+        JOIN railway_models rm ON rm.id = ci.railway_model_id
+        JOIN manufacturers m ON m.id = rm.manufacturer_id
+        WHERE ci.collection_id = ?1
+            AND pi.purchase_type IN (?4, ?5)
+            AND pi.purchase_date >= ?2 AND pi.purchase_date < ?3
+        GROUP BY m.id, m.name
+        ORDER BY items_bought DESC, m.name
+        LIMIT 1";
 
-    let row = sqlx::query_as::<_, CollectionItemRow>(sql)
-        .bind(collection_item_id.to_string())
-        .fetch_optional(pool)
+/// Find the manufacturer of most of the items of a collection bought during
+/// `year`, ties going to the first by name; `None` when nothing was bought.
+pub async fn get_year_top_manufacturer<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+    year: i32,
+) -> Result<Option<ManufacturerPurchasesRow>> {
+    let (start, end) = year_bounds(year)?;
+    let row = sqlx::query_as::<_, ManufacturerPurchasesRow>(YEAR_TOP_MANUFACTURER_SQL)
+        .bind(collection_id)
+        .bind(start)
+        .bind(end)
+        .bind(CollectionValuePolicy::VALUED_PURCHASE_TYPE)
+        .bind(CollectionValuePolicy::REALIZED_PURCHASE_TYPE)
+        .fetch_optional(executor)
         .await
-        .with_context(|| format!("querying collection_item id={}", collection_item_id))?;
+        .with_context(|| {
+            format!(
+                "finding the top manufacturer of year={} for collection_id={}",
+                year, collection_id
+            )
+        })?;
 
     Ok(row)
 }
 
-/// Fetch all collection items belonging to a collection.
-///
-/// Returns a vector of `CollectionItemRow`. The `collection_id` is bound as a
-/// parameter to the query to avoid string concatenation.
-pub async fn get_collection_items(
-    pool: &SqlitePool,
-    collection_id: &CollectionId,
-) -> Result<Vec<CollectionItemRow>> {
-    let sql = "SELECT id, collection_id, railway_model_id, conditions, notes FROM collection_items WHERE collection_id = ?1";
+/// Query used by `get_year_sale_proceeds`; `?2` and `?3` bound the year and
+/// `?4` is the realized purchase type of the `CollectionValuePolicy`.
+const YEAR_SALE_PROCEEDS_SQL: &str = "SELECT pi.sale_price_currency AS currency,
+            SUM(pi.sale_price_amount) AS amount
+        FROM purchase_infos pi
+        JOIN collection_items ci ON ci.id = pi.collection_item_id
+        WHERE ci.collection_id = ?1
+            AND pi.purchase_type = ?4
+            AND pi.sale_date >= ?2 AND pi.sale_date < ?3
+            AND pi.sale_price_amount IS NOT NULL
+            AND pi.sale_price_currency IS NOT NULL
+        GROUP BY pi.sale_price_currency
+        ORDER BY pi.sale_price_currency";
 
-    let rows = sqlx::query_as::<_, CollectionItemRow>(sql)
-        .bind(collection_id.to_string())
-        .fetch_all(pool)
+/// Compute what the items of a collection sold during `year` were sold for,
+/// per currency. Rows are ordered by currency code.
+pub async fn get_year_sale_proceeds<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+    year: i32,
+) -> Result<Vec<CurrencyTotalRow>> {
+    let (start, end) = year_bounds(year)?;
+    let rows = sqlx::query_as::<_, CurrencyTotalRow>(YEAR_SALE_PROCEEDS_SQL)
+        .bind(collection_id)
+        .bind(start)
+        .bind(end)
+        .bind(CollectionValuePolicy::REALIZED_PURCHASE_TYPE)
+        .fetch_all(executor)
         .await
         .with_context(|| {
             format!(
-                "querying collection_items for collection_id={}",
-                collection_id
+                "computing sale proceeds of year={} for collection_id={}",
+                year, collection_id
             )
         })?;
 
     Ok(rows)
 }
 
-/// Fetch a single owned rolling stock row by id.
-///
-/// The function accepts the raw owned rolling stock id string and returns the
-/// matching `OwnedRollingStockRow` if present.
-pub async fn get_owned_rolling_stock(
-    pool: &SqlitePool,
-    owned_rolling_stock_id: String,
-) -> Result<Option<OwnedRollingStockRow>> {
-    let sql = "SELECT id, collection_item_id, rolling_stock_id, notes FROM owned_rolling_stocks WHERE id = ?1 LIMIT 1";
+/// Query used by `get_year_net_proceeds`; the parameters are those of
+/// `YEAR_SALE_PROCEEDS_SQL`.
+const YEAR_NET_PROCEEDS_SQL: &str = "SELECT pi.purchased_price_currency AS currency,
+            SUM(pi.sale_price_amount - pi.purchased_price_amount - COALESCE((
+                SELECT SUM(ma.amount)
+                FROM monetary_adjustments ma
+                WHERE ma.purchase_id = pi.purchase_id
+                    AND ma.currency = pi.purchased_price_currency
+            ), 0)) AS amount
+        FROM purchase_infos pi
+        JOIN collection_items ci ON ci.id = pi.collection_item_id
+        WHERE ci.collection_id = ?1
+            AND pi.purchase_type = ?4
+            AND pi.sale_date >= ?2 AND pi.sale_date < ?3
+            AND pi.purchased_price_amount IS NOT NULL
+            AND pi.sale_price_amount IS NOT NULL
+            AND pi.sale_price_currency = pi.purchased_price_currency
+        GROUP BY pi.purchased_price_currency
+        ORDER BY pi.purchased_price_currency";
 
-    let row = sqlx::query_as::<_, OwnedRollingStockRow>(sql)
-        .bind(owned_rolling_stock_id)
-        .fetch_optional(pool)
+/// Compute the gains realized by the items of a collection sold during
+/// `year`, per currency, as `compute_collection_realized_gains` does for the
+/// whole collection. Rows are ordered by currency code.
+pub async fn get_year_net_proceeds<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+    year: i32,
+) -> Result<Vec<CurrencyTotalRow>> {
+    let (start, end) = year_bounds(year)?;
+    let rows = sqlx::query_as::<_, CurrencyTotalRow>(YEAR_NET_PROCEEDS_SQL)
+        .bind(collection_id)
+        .bind(start)
+        .bind(end)
+        .bind(CollectionValuePolicy::REALIZED_PURCHASE_TYPE)
+        .fetch_all(executor)
         .await
-        .context("querying owned_rolling_stock by id")?;
+        .with_context(|| {
+            format!(
+                "computing net proceeds of year={} for collection_id={}",
+                year, collection_id
+            )
+        })?;
 
-    Ok(row)
+    Ok(rows)
 }
 
-/// Fetch all owned rolling stocks that belong to a collection.
+/// Record that the collection item with `collection_item_id` was viewed at
+/// `viewed_at`, keeping only its latest `keep` views.
 ///
-/// This performs a join from `owned_rolling_stocks` to `collection_items` and
-/// filters by `collection_items.collection_id = ?` using parameter binding.
-pub async fn get_owned_rolling_stocks(
-    pool: &SqlitePool,
-    collection_id: &CollectionId,
-) -> Result<Vec<OwnedRollingStockRow>> {
-    let sql = "SELECT ors.id, ors.collection_item_id, ors.rolling_stock_id, ors.notes FROM owned_rolling_stocks AS ors JOIN collection_items AS ci ON ci.id = ors.collection_item_id WHERE ci.collection_id = ?1";
-
-    let rows = sqlx::query_as::<_, OwnedRollingStockRow>(sql)
-        .bind(collection_id.to_string())
-        .fetch_all(pool)
+/// The view is inserted and the older ones pruned in the same transaction,
+/// so the views of an item never exceed `keep`.
+pub async fn record_item_view(
+    conn: &mut SqliteConnection,
+    collection_item_id: &CollectionItemId,
+    viewed_at: DateTime<Utc>,
+    keep: u32,
+) -> Result<()> {
+    sqlx::query("INSERT INTO item_views (collection_item_id, viewed_at) VALUES (?1, ?2)")
+        .bind(collection_item_id)
+        .bind(viewed_at)
+        .execute(&mut *conn)
         .await
         .with_context(|| {
             format!(
-                "querying owned_rolling_stocks for collection_id={}",
-                collection_id
+                "inserting item_view collection_item_id={}",
+                collection_item_id
             )
         })?;
+    sqlx::query(
+        "DELETE FROM item_views WHERE collection_item_id = ?1 AND id NOT IN (SELECT id FROM item_views WHERE collection_item_id = ?1 ORDER BY viewed_at DESC, id DESC LIMIT ?2)",
+    )
+    .bind(collection_item_id)
+    .bind(keep)
+    .execute(&mut *conn)
+    .await
+    .with_context(|| format!("pruning item_views collection_item_id={}", collection_item_id))?;
+
+    Ok(())
+}
+
+/// Query used by `get_recent_activity`: `?1` is the most events returned.
+const RECENT_ACTIVITY_SQL: &str = "SELECT kind, collection_item_id, railway_model_id, occurred_at
+        FROM (
+            SELECT 'ADDED' AS kind, id AS collection_item_id, railway_model_id,
+                created_at AS occurred_at
+            FROM collection_items
+            WHERE created_at IS NOT NULL
+            UNION ALL
+            SELECT 'MODIFIED', id, railway_model_id, updated_at
+            FROM collection_items
+            WHERE updated_at IS NOT NULL AND updated_at > COALESCE(created_at, '')
+            UNION ALL
+            SELECT 'VIEWED', iv.collection_item_id, ci.railway_model_id, MAX(iv.viewed_at)
+            FROM item_views iv
+            JOIN collection_items ci ON ci.id = iv.collection_item_id
+            GROUP BY iv.collection_item_id
+        )
+        ORDER BY occurred_at DESC, kind, collection_item_id
+        LIMIT ?1";
+
+/// Fetch the latest `limit` events of the collection items, newest first:
+/// when they were added, last updated (unless never updated since being
+/// added) and last viewed.
+pub async fn get_recent_activity<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    limit: u32,
+) -> Result<Vec<ActivityRow>> {
+    let rows = sqlx::query_as::<_, ActivityRow>(RECENT_ACTIVITY_SQL)
+        .bind(limit)
+        .fetch_all(executor)
+        .await
+        .context("querying the recent activity")?;
 
     Ok(rows)
 }
 
-/// Fetch a single purchase_info by id.
-///
-/// Accepts the raw purchase info id string and returns the typed
-/// `PurchaseInfoRow` if present.
-pub async fn get_purchase_info(
-    pool: &SqlitePool,
-    purchase_info_id: String,
-) -> Result<Option<PurchaseInfoRow>> {
-    let sql = "SELECT purchase_id, collection_item_id, purchase_type, purchase_date, seller_id, buyer_id, sale_date, purchased_price_amount, purchased_price_currency, sale_price_amount, sale_price_currency, deposit_amount, deposit_currency, preorder_total_amount, preorder_total_currency, expected_date FROM purchase_infos WHERE purchase_id = ?1 LIMIT 1";
+/// Fetch the items of a collection listed in its insurance report: all but
+/// the sold ones (the realized purchase type of the `CollectionValuePolicy`),
+/// ordered by manufacturer name and product code.
+pub async fn get_insurance_report_rows<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+) -> Result<Vec<InsuranceReportRow>> {
+    let sold = CollectionValuePolicy::REALIZED_PURCHASE_TYPE;
+    let rows = sqlx::query_as!(
+        InsuranceReportRow,
+        r#"SELECT ci.id AS "collection_item_id!: _", m.name AS manufacturer, rm.product_code, rm.description,
+            (
+                SELECT group_concat(road_number, char(10))
+                FROM (
+                    SELECT rs.road_number
+                    FROM owned_rolling_stocks AS ors
+                    JOIN rolling_stocks AS rs ON rs.id = ors.rolling_stock_id
+                    WHERE ors.collection_item_id = ci.id AND rs.road_number IS NOT NULL
+                    ORDER BY ors.rowid
+                )
+            ) AS "road_numbers?: String",
+            pi.purchase_date AS "purchase_date?: _",
+            pi.purchased_price_amount AS "purchased_price_amount?",
+            pi.purchased_price_currency AS "purchased_price_currency?",
+            ci.replacement_value_amount, ci.replacement_value_currency
+        FROM collection_items AS ci
+        JOIN railway_models AS rm ON rm.id = ci.railway_model_id
+        JOIN manufacturers AS m ON m.id = rm.manufacturer_id
+        LEFT JOIN purchase_infos AS pi ON pi.collection_item_id = ci.id
+        WHERE ci.collection_id = ?1 AND pi.purchase_type IS NOT ?2
+        ORDER BY m.name, rm.product_code, ci.id"#,
+        collection_id,
+        sold
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "fetching insurance report rows for collection_id={}",
+            collection_id
+        )
+    })?;
+
+    Ok(rows)
+}
+
+/// Store the summary counters and total value of a collection.
+pub async fn update_collection_totals<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+    summary: &CollectionSummaryRow,
+    total_value_amount: i64,
+    total_value_currency: &str,
+) -> Result<()> {
+    let sql = "UPDATE collections SET
+            locomotives_count = ?2,
+            passenger_cars_count = ?3,
+            freight_cars_count = ?4,
+            train_sets_count = ?5,
+            railcars_count = ?6,
+            electric_multiple_units_count = ?7,
+            total_value_amount = ?8,
+            total_value_currency = ?9,
+            updated_at = ?10
+        WHERE id = ?1";
 
-    let row = sqlx::query_as::<_, PurchaseInfoRow>(sql)
-        .bind(purchase_info_id)
-        .fetch_optional(pool)
+    sqlx::query(sql)
+        .bind(collection_id)
+        .bind(summary.locomotives_count)
+        .bind(summary.passenger_cars_count)
+        .bind(summary.freight_cars_count)
+        .bind(summary.train_sets_count)
+        .bind(summary.railcars_count)
+        .bind(summary.electric_multiple_units_count)
+        .bind(total_value_amount)
+        .bind(total_value_currency)
+        .bind(Utc::now())
+        .execute(executor)
         .await
-        .context("querying purchase_info by id")?;
+        .with_context(|| format!("updating totals for collection_id={}", collection_id))?;
 
-    Ok(row)
+    Ok(())
 }
 
-/// Fetch all purchase infos associated to a collection (via collection_items).
-///
-/// Joins `purchase_infos` to `collection_items` and binds the collection id
-/// parameter to prevent SQL injection.
-pub async fn get_purchase_infos(
-    pool: &SqlitePool,
+/// Store the summary counters of a collection, leaving its total value as
+/// it is.
+pub async fn update_collection_summary_counters<'e, E: SqliteExecutor<'e>>(
+    executor: E,
     collection_id: &CollectionId,
-) -> Result<Vec<PurchaseInfoRow>> {
-    let sql = "SELECT pi.purchase_id, pi.collection_item_id, pi.purchase_type, pi.purchase_date, pi.seller_id, pi.buyer_id, pi.sale_date, pi.purchased_price_amount, pi.purchased_price_currency, pi.sale_price_amount, pi.sale_price_currency, pi.deposit_amount, pi.deposit_currency, pi.preorder_total_amount, pi.preorder_total_currency, pi.expected_date FROM purchase_infos pi JOIN collection_items ci ON ci.id = pi.collection_item_id WHERE ci.collection_id = ?1";
+    summary: &CollectionSummaryRow,
+) -> Result<()> {
+    let sql = "UPDATE collections SET
+            locomotives_count = ?2,
+            passenger_cars_count = ?3,
+            freight_cars_count = ?4,
+            train_sets_count = ?5,
+            railcars_count = ?6,
+            electric_multiple_units_count = ?7,
+            updated_at = ?8
+        WHERE id = ?1";
 
-    let rows = sqlx::query_as::<_, PurchaseInfoRow>(sql)
-        .bind(collection_id.to_string())
-        .fetch_all(pool)
+    sqlx::query(sql)
+        .bind(collection_id)
+        .bind(summary.locomotives_count)
+        .bind(summary.passenger_cars_count)
+        .bind(summary.freight_cars_count)
+        .bind(summary.train_sets_count)
+        .bind(summary.railcars_count)
+        .bind(summary.electric_multiple_units_count)
+        .bind(Utc::now())
+        .execute(executor)
         .await
         .with_context(|| {
             format!(
-                "querying purchase_infos for collection_id={}",
+                "updating summary counters for collection_id={}",
                 collection_id
             )
         })?;
 
+    Ok(())
+}
+
+/// Replace the value snapshot of a collection for `snapshot_date` with
+/// `rows`.
+///
+/// The rows already stored for that day are deleted first, so taking a
+/// snapshot twice on the same day keeps only the second one. `created_at` is
+/// set to the current UTC time. Run it in a transaction so a failing insert
+/// does not leave the day without its previous snapshot.
+pub async fn replace_value_snapshot(
+    conn: &mut SqliteConnection,
+    collection_id: &CollectionId,
+    snapshot_date: NaiveDate,
+    rows: &[ValueSnapshotRow],
+) -> Result<()> {
+    sqlx::query(
+        "DELETE FROM collection_value_snapshots WHERE collection_id = ?1 AND snapshot_date = ?2",
+    )
+    .bind(collection_id)
+    .bind(snapshot_date)
+    .execute(&mut *conn)
+    .await
+    .with_context(|| {
+        format!(
+            "deleting value snapshot collection_id={} date={}",
+            collection_id, snapshot_date
+        )
+    })?;
+
+    let sql = "INSERT INTO collection_value_snapshots (collection_id, snapshot_date, currency, amount, created_at) VALUES (?1, ?2, ?3, ?4, ?5)";
+    let created_at = Utc::now();
+    for row in rows {
+        sqlx::query(sql)
+            .bind(&row.collection_id)
+            .bind(row.snapshot_date)
+            .bind(&row.currency)
+            .bind(row.amount)
+            .bind(created_at)
+            .execute(&mut *conn)
+            .await
+            .with_context(|| {
+                format!(
+                    "inserting value snapshot collection_id={} date={} currency={}",
+                    row.collection_id, row.snapshot_date, row.currency
+                )
+            })?;
+    }
+
+    Ok(())
+}
+
+/// Fetch the value snapshots of a collection taken between `from` and `to`
+/// (both included), ordered by date and currency code.
+pub async fn get_value_snapshots<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_id: &CollectionId,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<ValueSnapshotRow>> {
+    let rows = sqlx::query_as!(
+        ValueSnapshotRow,
+        r#"SELECT collection_id AS "collection_id: _", snapshot_date AS "snapshot_date: _", currency, amount FROM collection_value_snapshots WHERE collection_id = ?1 AND snapshot_date BETWEEN ?2 AND ?3 ORDER BY snapshot_date, currency"#,
+        collection_id,
+        from,
+        to
+    )
+    .fetch_all(executor)
+    .await
+    .with_context(|| {
+        format!(
+            "querying value snapshots for collection_id={} from={} to={}",
+            collection_id, from, to
+        )
+    })?;
+
     Ok(rows)
 }
 
@@ -173,9 +2605,14 @@ mod tests {
     use anyhow::Result;
     use sqlx::SqlitePool;
 
+    use crate::catalog::domain::railway_model_id::RailwayModelId;
     use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::domain::purchase_info::PreOrderError;
     use crate::collecting::infrastructure::sqlite::*;
     use crate::collecting::infrastructure::testing::CollectingTestDb;
+    use crate::core::infrastructure::conflict::ConflictError;
+    use crate::test_utils::{assert_uses_indexes, query_plan};
+    use std::time::{Duration, Instant};
 
     #[sqlx::test(migrations = "./migrations")]
     async fn get_collection_returns_row(pool: SqlitePool) -> Result<()> {
@@ -199,7 +2636,349 @@ mod tests {
         let collection = get_collection(&pool, collection_id.clone()).await?;
         assert!(collection.is_some());
         let collection = collection.unwrap();
-        assert_eq!(collection.id, collection_id.to_string());
+        assert_eq!(collection.id, collection_id);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn add_collection_item_inserts_item_and_owned_rolling_stocks(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let collection_id = CollectionId::try_from(collection_id.as_str())?;
+
+        let rolling_stock_ids: Vec<&str> = catalog_test_data
+            .rolling_stock_ids
+            .iter()
+            .map(|s| s.as_str())
+            .collect();
+        let collection_item_id = add_collection_item(
+            &pool,
+            &collection_id,
+            &catalog_test_data.railway_model_id,
+            &rolling_stock_ids,
+        )
+        .await?;
+
+        let items = get_collection_items(&pool, &collection_id).await?;
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].id.to_string(), collection_item_id);
+        let owned = get_owned_rolling_stocks(&pool, &collection_id).await?;
+        assert_eq!(owned.len(), rolling_stock_ids.len());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn add_collection_item_rolls_back_when_a_step_fails(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let collection_id = CollectionId::try_from(collection_id.as_str())?;
+
+        // The second rolling stock does not exist: its insert violates the
+        // foreign key after the collection item has already been inserted.
+        let rolling_stock_ids = [
+            catalog_test_data.rolling_stock_ids[0].as_str(),
+            "missing-rolling-stock",
+        ];
+        let result = add_collection_item(
+            &pool,
+            &collection_id,
+            &catalog_test_data.railway_model_id,
+            &rolling_stock_ids,
+        )
+        .await;
+        assert!(result.is_err());
+
+        let items = get_collection_items(&pool, &collection_id).await?;
+        assert!(items.is_empty());
+        let owned_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM owned_rolling_stocks")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(owned_count, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn ensure_default_collection_is_idempotent(pool: SqlitePool) -> Result<()> {
+        assert!(ensure_default_collection(&pool).await?);
+        assert!(!ensure_default_collection(&pool).await?);
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM collections WHERE id = ?")
+            .bind(DEFAULT_COLLECTION_ID)
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(count, 1);
+
+        let collection_id = CollectionId::DEFAULT;
+        let collection = get_collection(&pool, collection_id).await?.unwrap();
+        assert_eq!(collection.name, DEFAULT_COLLECTION_NAME);
+        assert_eq!(collection.locomotives_count, 0);
+        assert_eq!(collection.total_value_amount, 0);
+        assert_eq!(collection.total_value_currency, "EUR");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn add_collection_item_after_a_fresh_init(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        ensure_default_collection(&pool).await?;
+
+        let collection_id = CollectionId::DEFAULT;
+        add_collection_item(
+            &pool,
+            &collection_id,
+            &catalog_test_data.railway_model_id,
+            &[catalog_test_data.rolling_stock_ids[0].as_str()],
+        )
+        .await?;
+
+        let items = get_collection_items(&pool, &collection_id).await?;
+        assert_eq!(items.len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn add_collection_item_creates_the_missing_default_collection(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+
+        let collection_id = CollectionId::DEFAULT;
+        add_collection_item(
+            &pool,
+            &collection_id,
+            &catalog_test_data.railway_model_id,
+            &[],
+        )
+        .await?;
+
+        assert!(
+            get_collection(&pool, collection_id.clone())
+                .await?
+                .is_some()
+        );
+        assert_eq!(get_collection_items(&pool, &collection_id).await?.len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn update_collection_item_detects_lost_update(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let data = collecting_db
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, vec![])
+            .await?;
+        let collection_item_id = CollectionItemId::try_from(data.collection_item_id.as_str())?;
+
+        // Two windows read the same item...
+        let mut first = get_collection_item(&pool, collection_item_id.clone())
+            .await?
+            .unwrap();
+        let mut second = get_collection_item(&pool, collection_item_id.clone())
+            .await?
+            .unwrap();
+
+        // ...the first one saves...
+        first.notes = Some("repainted".to_string());
+        assert_eq!(
+            update_collection_item(&pool, &first).await?,
+            first.version + 1
+        );
+
+        // ...and the second one must not overwrite it silently.
+        second.notes = Some("original box".to_string());
+        let err = update_collection_item(&pool, &second).await.unwrap_err();
+        let conflict = err
+            .downcast_ref::<ConflictError<CollectionItemRow>>()
+            .expect("conflict error");
+        assert_eq!(conflict.expected_version, second.version);
+        let current = conflict.current.as_ref().unwrap();
+        assert_eq!(current.notes.as_deref(), Some("repainted"));
+        assert_eq!(current.version, first.version + 1);
+
+        let stored = get_collection_item(&pool, collection_item_id)
+            .await?
+            .unwrap();
+        assert_eq!(stored.notes.as_deref(), Some("repainted"));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn updates_advance_updated_at(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let data = collecting_db
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, vec![])
+            .await?;
+        let collection_item_id = CollectionItemId::try_from(data.collection_item_id.as_str())?;
+
+        let item = get_collection_item(&pool, collection_item_id.clone())
+            .await?
+            .unwrap();
+        let purchase_info = get_purchase_info(&pool, data.purchase_info_id.clone())
+            .await?
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        update_collection_item(&pool, &item).await?;
+        update_purchase_info(&pool, &purchase_info).await?;
+
+        let updated_item = get_collection_item(&pool, collection_item_id)
+            .await?
+            .unwrap();
+        assert_eq!(updated_item.created_at, item.created_at);
+        assert!(updated_item.updated_at > item.updated_at);
+        let updated_purchase_info = get_purchase_info(&pool, data.purchase_info_id)
+            .await?
+            .unwrap();
+        assert!(updated_purchase_info.updated_at > purchase_info.updated_at);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn update_purchase_info_detects_lost_update(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let data = collecting_db
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, vec![])
+            .await?;
+
+        let mut first = get_purchase_info(&pool, data.purchase_info_id.clone())
+            .await?
+            .unwrap();
+        let mut second = get_purchase_info(&pool, data.purchase_info_id.clone())
+            .await?
+            .unwrap();
+
+        first.purchased_price_amount = Some(12_500);
+        update_purchase_info(&pool, &first).await?;
+
+        second.purchased_price_amount = Some(9_900);
+        let err = update_purchase_info(&pool, &second).await.unwrap_err();
+        let conflict = err
+            .downcast_ref::<ConflictError<PurchaseInfoRow>>()
+            .expect("conflict error");
+        let current = conflict.current.as_ref().unwrap();
+        assert_eq!(current.purchased_price_amount, Some(12_500));
+
+        // Retrying with the current version succeeds.
+        second.version = current.version;
+        assert_eq!(
+            update_purchase_info(&pool, &second).await?,
+            current.version + 1
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn update_purchase_info_rejects_inconsistent_preorders(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let data = collecting_db
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, vec![])
+            .await?;
+        let mut preorder = get_purchase_info(&pool, data.purchase_info_id.clone())
+            .await?
+            .unwrap();
+        preorder.purchase_type = Some("preorder".to_string());
+        preorder.preorder_total_amount = Some(10_000);
+        preorder.preorder_total_currency = Some("EUR".to_string());
+
+        preorder.deposit_amount = Some(1_000);
+        preorder.deposit_currency = Some("USD".to_string());
+        let err = update_purchase_info(&pool, &preorder).await.unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<PreOrderError>(),
+            Some(PreOrderError::CurrencyMismatch { .. })
+        ));
+
+        preorder.deposit_amount = Some(12_000);
+        preorder.deposit_currency = Some("EUR".to_string());
+        let err = update_purchase_info(&pool, &preorder).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<PreOrderError>(),
+            Some(&PreOrderError::DepositExceedsTotal {
+                deposit: 12_000,
+                total_price: 10_000,
+            })
+        );
+
+        // Nothing was written.
+        let stored = get_purchase_info(&pool, data.purchase_info_id.clone())
+            .await?
+            .unwrap();
+        assert_eq!(stored.purchase_type.as_deref(), Some("purchased"));
+        assert_eq!(stored.version, preorder.version);
+
+        preorder.deposit_amount = Some(10_000);
+        assert_eq!(
+            update_purchase_info(&pool, &preorder).await?,
+            preorder.version + 1
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn normalize_purchase_dates_rewrites_legacy_formats(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let data = collecting_db
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, vec![])
+            .await?;
+        let stored = [
+            ("pi-iso", "2024-03-01"),
+            ("pi-regional", "01/03/2024"),
+            ("pi-date-time", "2024-03-01T10:15:00Z"),
+            ("pi-invalid", "last spring"),
+        ];
+        for (purchase_id, purchase_date) in stored {
+            sqlx::query(
+                "INSERT INTO purchase_infos (purchase_id, collection_item_id, purchase_type, purchase_date) VALUES (?1, ?2, 'purchased', ?3)",
+            )
+            .bind(purchase_id)
+            .bind(&data.collection_item_id)
+            .bind(purchase_date)
+            .execute(&pool)
+            .await?;
+        }
+
+        assert_eq!(normalize_purchase_dates(&pool).await?, 2);
+        assert_eq!(normalize_purchase_dates(&pool).await?, 0);
+
+        for (purchase_id, expected) in [
+            ("pi-iso", "2024-03-01"),
+            ("pi-regional", "2024-03-01"),
+            ("pi-date-time", "2024-03-01"),
+            ("pi-invalid", "last spring"),
+        ] {
+            let row = get_purchase_info(&pool, purchase_id.to_string())
+                .await?
+                .unwrap();
+            assert_eq!(row.purchase_date, expected, "{purchase_id}");
+            assert_eq!(row.version, 0, "{purchase_id}");
+        }
 
         Ok(())
     }
@@ -229,12 +3008,12 @@ mod tests {
         // collection items
         let items = get_collection_items(&pool, &collection_id).await?;
         assert_eq!(items.len(), 1);
-        assert_eq!(items[0].id, collection_item_id.to_string());
+        assert_eq!(items[0].id, collection_item_id);
 
         let item = get_collection_item(&pool, collection_item_id.clone()).await?;
         assert!(item.is_some());
         let item = item.unwrap();
-        assert_eq!(item.collection_id, collection_id.to_string());
+        assert_eq!(item.collection_id, collection_id);
 
         // owned rolling stocks
         let ors = get_owned_rolling_stocks(&pool, &collection_id).await?;
@@ -249,7 +3028,7 @@ mod tests {
         // purchase infos
         let pis = get_purchase_infos(&pool, &collection_id).await?;
         assert_eq!(pis.len(), 1);
-        assert_eq!(pis[0].collection_item_id, collection_item_id.to_string());
+        assert_eq!(pis[0].collection_item_id, collection_item_id);
 
         let pi = get_purchase_info(&pool, data.purchase_info_id.clone()).await?;
         assert!(pi.is_some());
@@ -258,4 +3037,284 @@ mod tests {
 
         Ok(())
     }
+
+    /// The stored counters of a collection and the ones recomputed from its
+    /// items, in `CollectionSummaryRow` field order.
+    async fn stored_and_recomputed_counters(
+        pool: &SqlitePool,
+        collection_id: &str,
+    ) -> Result<([i64; 6], [i64; 6])> {
+        let collection_id = CollectionId::try_from(collection_id)?;
+        let stored = get_collection(pool, collection_id.clone())
+            .await?
+            .expect("collection row");
+        let recomputed = compute_collection_summary(pool, &collection_id).await?;
+        Ok((
+            [
+                stored.locomotives_count,
+                stored.passenger_cars_count,
+                stored.freight_cars_count,
+                stored.train_sets_count,
+                stored.railcars_count,
+                stored.electric_multiple_units_count,
+            ],
+            [
+                recomputed.locomotives_count,
+                recomputed.passenger_cars_count,
+                recomputed.freight_cars_count,
+                recomputed.train_sets_count,
+                recomputed.railcars_count,
+                recomputed.electric_multiple_units_count,
+            ],
+        ))
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn summary_counters_follow_random_writes(pool: SqlitePool) -> Result<()> {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        const CATEGORIES: [&str; 9] = [
+            "LOCOMOTIVES",
+            "locomotives",
+            "PASSENGER_CARS",
+            "FREIGHT_CARS",
+            "TRAIN_SETS",
+            "STARTER_SETS",
+            "RAILCARS",
+            "ELECTRIC_MULTIPLE_UNITS",
+            "OTHER",
+        ];
+
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        catalog_db.insert_manufacturer("m1", "ACME").await?;
+        let mut railway_model_ids = Vec::new();
+        for (n, category) in CATEGORIES.iter().enumerate() {
+            railway_model_ids.push(
+                catalog_db
+                    .insert_railway_model(
+                        &RailwayModelId::new(),
+                        "m1",
+                        &format!("P{n}"),
+                        "model",
+                        "electric",
+                        "H0",
+                        "IV",
+                        category,
+                    )
+                    .await?,
+            );
+        }
+        let second_collection_id = "00000000-0000-0000-0000-000000000002";
+        sqlx::query("INSERT INTO collections (id, name) VALUES (?1, 'Second')")
+            .bind(second_collection_id)
+            .execute(&pool)
+            .await?;
+        let collection_ids = [
+            collecting_db.insert_collection("First").await?,
+            second_collection_id.to_string(),
+        ];
+
+        let mut rng = StdRng::seed_from_u64(2164);
+        let mut item_ids: Vec<String> = Vec::new();
+        for step in 0..300 {
+            let collection_id = &collection_ids[rng.random_range(0..collection_ids.len())];
+            let railway_model_id = &railway_model_ids[rng.random_range(0..railway_model_ids.len())];
+            let operation = if item_ids.is_empty() {
+                0
+            } else {
+                rng.random_range(0..5)
+            };
+            match operation {
+                0 | 1 => {
+                    let item_id = collecting_db
+                        .insert_collection_item(collection_id, railway_model_id)
+                        .await?;
+                    item_ids.push(item_id);
+                }
+                2 => {
+                    let item_id = item_ids.swap_remove(rng.random_range(0..item_ids.len()));
+                    sqlx::query("DELETE FROM collection_items WHERE id = ?1")
+                        .bind(&item_id)
+                        .execute(&pool)
+                        .await?;
+                }
+                3 => {
+                    let item_id = &item_ids[rng.random_range(0..item_ids.len())];
+                    sqlx::query(
+                        "UPDATE collection_items SET collection_id = ?1, railway_model_id = ?2, item_number = NULL WHERE id = ?3",
+                    )
+                    .bind(collection_id)
+                    .bind(railway_model_id)
+                    .bind(item_id)
+                    .execute(&pool)
+                    .await?;
+                }
+                _ => {
+                    let category = CATEGORIES[rng.random_range(0..CATEGORIES.len())];
+                    sqlx::query("UPDATE railway_models SET category = ?1 WHERE id = ?2")
+                        .bind(category)
+                        .bind(railway_model_id)
+                        .execute(&pool)
+                        .await?;
+                }
+            }
+
+            for collection_id in &collection_ids {
+                let (stored, recomputed) =
+                    stored_and_recomputed_counters(&pool, collection_id).await?;
+                assert_eq!(
+                    stored, recomputed,
+                    "counters of {collection_id} after step {step} (operation {operation})"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn migration_brings_stale_counters_in_sync(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        catalog_db.insert_manufacturer("m1", "ACME").await?;
+        let railway_model_id = catalog_db
+            .insert_railway_model(
+                &RailwayModelId::new(),
+                "m1",
+                "E656",
+                "model",
+                "electric",
+                "H0",
+                "IV",
+                "LOCOMOTIVES",
+            )
+            .await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection_id = collecting_db.insert_collection("Stale").await?;
+        collecting_db
+            .insert_collection_item(&collection_id, &railway_model_id)
+            .await?;
+        sqlx::query("UPDATE collections SET locomotives_count = 7, railcars_count = 3")
+            .execute(&pool)
+            .await?;
+
+        sqlx::raw_sql(include_str!(
+            "../../../migrations/0009_maintain_collection_summary_counters.sql"
+        ))
+        .execute(&pool)
+        .await?;
+
+        let (stored, recomputed) = stored_and_recomputed_counters(&pool, &collection_id).await?;
+        assert_eq!(stored, recomputed);
+        assert_eq!(stored, [1, 0, 0, 0, 0, 0]);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn collection_queries_use_indexes(pool: SqlitePool) -> Result<()> {
+        for sql in [
+            include_str!("../../../queries/collecting/collection_items.sql"),
+            include_str!("../../../queries/collecting/owned_rolling_stocks.sql"),
+            include_str!("../../../queries/collecting/purchase_infos.sql"),
+            include_str!("../../../queries/collecting/collection_details.sql"),
+            include_str!("../../../queries/collecting/collection_item_by_number.sql"),
+            COLLECTION_SUMMARY_SQL,
+            COLLECTION_TOTAL_VALUES_SQL,
+            COLLECTION_REALIZED_GAINS_SQL,
+            COLLECTION_DISCOUNT_PRICES_SQL,
+            MISSING_ROLLING_STOCKS_SQL,
+            PURCHASE_CURRENCIES_SQL,
+        ] {
+            assert_uses_indexes(&pool, sql).await;
+        }
+
+        // The keyset condition seeks into the index (the `>=` bound makes the
+        // expression column searchable); the outer query then only reads the
+        // rows of the `page` CTE.
+        let plan = query_plan(
+            &pool,
+            include_str!("../../../queries/collecting/collection_items_page.sql"),
+        )
+        .await;
+        assert!(
+            plan.iter().any(|step| step
+                == "SEARCH ci USING INDEX idx_collection_items_collection_created_at_id (collection_id=? AND <expr>>?)"),
+            "expected a keyset seek, got plan {:?}",
+            plan
+        );
+        assert!(
+            plan.iter()
+                .filter(|step| step.starts_with("SCAN"))
+                .all(|step| step == "SCAN page"),
+            "expected no full table scan, got plan {:?}",
+            plan
+        );
+
+        Ok(())
+    }
+
+    /// Seed 5,000 collection items (each with an owned rolling stock and a
+    /// purchase info) spread over 10 collections.
+    async fn seed_large_dataset(pool: &SqlitePool) -> Result<()> {
+        let statements = [
+            "INSERT INTO manufacturers (id, name) VALUES ('m', 'ACME')",
+            "WITH RECURSIVE seq(n) AS (SELECT 0 UNION ALL SELECT n + 1 FROM seq WHERE n < 49)
+            INSERT INTO railway_models (id, manufacturer_id, product_code, description, power_method, scale, epoch, category)
+            SELECT 'rm-' || n, 'm', 'P' || n, 'model ' || n, 'DC', 'H0', 'IV', 'LOCOMOTIVES' FROM seq",
+            "WITH RECURSIVE seq(n) AS (SELECT 0 UNION ALL SELECT n + 1 FROM seq WHERE n < 9)
+            INSERT INTO collections (id, name) SELECT '00000000-0000-0000-0000-00000000000' || n, 'collection ' || n FROM seq",
+            "WITH RECURSIVE seq(n) AS (SELECT 0 UNION ALL SELECT n + 1 FROM seq WHERE n < 4999)
+            INSERT INTO collection_items (id, collection_id, railway_model_id)
+            SELECT printf('00000000-0000-0000-0001-%012d', n), '00000000-0000-0000-0000-00000000000' || (n % 10), 'rm-' || (n % 50) FROM seq",
+            "INSERT INTO owned_rolling_stocks (id, collection_item_id) SELECT 'ors-' || id, id FROM collection_items",
+            "INSERT INTO purchase_infos (purchase_id, collection_item_id, purchase_type, purchase_date, purchased_price_amount, purchased_price_currency)
+            SELECT 'pi-' || id, id, 'purchased', '2024-01-01', 1000, 'EUR' FROM collection_items",
+        ];
+        for sql in statements {
+            sqlx::query(sql).execute(pool).await?;
+        }
+        sqlx::query("ANALYZE").execute(pool).await?;
+
+        Ok(())
+    }
+
+    /// Compare the hot collection queries with and without the foreign key
+    /// indexes on a seeded dataset. Run with `cargo test -- --ignored --nocapture`.
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "benchmark"]
+    async fn benchmark_collection_queries_with_and_without_indexes(pool: SqlitePool) -> Result<()> {
+        seed_large_dataset(&pool).await?;
+        let collection_id = CollectionId::try_from("00000000-0000-0000-0000-000000000003")?;
+
+        async fn time_queries(pool: &SqlitePool, collection_id: &CollectionId) -> Result<Duration> {
+            let start = Instant::now();
+            for _ in 0..20 {
+                get_owned_rolling_stocks(pool, collection_id).await?;
+                get_purchase_infos(pool, collection_id).await?;
+                compute_collection_summary(pool, collection_id).await?;
+            }
+            Ok(start.elapsed())
+        }
+
+        let with_indexes = time_queries(&pool, &collection_id).await?;
+
+        for index in [
+            "idx_collection_items_collection_id",
+            "idx_owned_rolling_stocks_collection_item_id",
+            "idx_purchase_infos_collection_item",
+        ] {
+            sqlx::query(&format!("DROP INDEX {}", index))
+                .execute(&pool)
+                .await?;
+        }
+        let without_indexes = time_queries(&pool, &collection_id).await?;
+
+        eprintln!(
+            "collection queries x20 on 5,000 items: with indexes {:?}, without {:?}",
+            with_indexes, without_indexes
+        );
+        Ok(())
+    }
 }
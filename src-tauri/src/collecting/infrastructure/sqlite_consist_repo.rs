@@ -0,0 +1,313 @@
+use crate::audit::infrastructure::sink::{AuditSink, AuditedChange, SqliteAuditSink};
+use crate::catalog::domain::length_over_buffers::LengthOverBuffers;
+use crate::catalog::domain::radius::Radius;
+use crate::collecting::domain::consist::{Consist, ConsistDetails, ConsistVehicle};
+use crate::collecting::domain::repository::ConsistRepository;
+use crate::collecting::infrastructure::entities::{ConsistRow, ConsistVehicleRow};
+use crate::collecting::infrastructure::sqlite;
+use crate::core::infrastructure::transaction::with_transaction;
+use anyhow::{Context, Result, anyhow};
+use rust_decimal::Decimal;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+
+pub struct SqliteConsistRepository {
+    pool: SqlitePool,
+    audit: Arc<dyn AuditSink>,
+}
+
+impl SqliteConsistRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            audit: Arc::new(SqliteAuditSink::default()),
+        }
+    }
+
+    /// Record the changes made by this repository in `audit`.
+    pub fn with_audit_sink(mut self, audit: Arc<dyn AuditSink>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    fn build_vehicle(row: ConsistVehicleRow) -> Result<ConsistVehicle> {
+        let context = || {
+            format!(
+                "invalid catalog data of owned_rolling_stock id={}",
+                row.owned_rolling_stock_id
+            )
+        };
+        let decimal = |value: Option<f64>| value.map(Decimal::try_from).transpose();
+        let length_over_buffers = match (row.length_inches, row.length_millimeters) {
+            (None, None) => None,
+            (inches, millimeters) => Some(
+                LengthOverBuffers::new(
+                    decimal(inches).with_context(context)?,
+                    decimal(millimeters).with_context(context)?,
+                )
+                .with_context(context)?,
+            ),
+        };
+        let minimum_radius = decimal(row.minimum_radius_mm)
+            .with_context(context)?
+            .map(Radius::from_millimeters)
+            .transpose()
+            .with_context(context)?;
+
+        Ok(ConsistVehicle {
+            owned_rolling_stock_id: row.owned_rolling_stock_id,
+            length_over_buffers,
+            minimum_radius,
+        })
+    }
+
+    /// Pair each consist row with its vehicles; `vehicles` are ordered by
+    /// consist and position.
+    fn build_consists(
+        rows: Vec<ConsistRow>,
+        vehicles: Vec<ConsistVehicleRow>,
+    ) -> Result<Vec<Consist>> {
+        let mut consists: Vec<Consist> = rows
+            .into_iter()
+            .map(|row| Consist {
+                id: row.id,
+                name: row.name,
+                vehicles: Vec::new(),
+            })
+            .collect();
+        for vehicle in vehicles {
+            let consist = consists
+                .iter_mut()
+                .find(|c| c.id == vehicle.consist_id)
+                .with_context(|| format!("consist not found id={}", vehicle.consist_id))?;
+            consist.vehicles.push(Self::build_vehicle(vehicle)?);
+        }
+        Ok(consists)
+    }
+}
+
+#[async_trait::async_trait]
+impl ConsistRepository for SqliteConsistRepository {
+    async fn create_consist(&self, consist_id: &str, details: &ConsistDetails) -> Result<()> {
+        let row = ConsistRow {
+            id: consist_id.to_string(),
+            name: details.name.clone(),
+        };
+        let owned_rolling_stock_ids = details.owned_rolling_stock_ids.clone();
+        let change = AuditedChange::created("consist", consist_id, details)?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                sqlite::insert_consist(&mut *conn, &row).await?;
+                sqlite::replace_consist_entries(&mut *conn, &row.id, &owned_rolling_stock_ids)
+                    .await?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn update_consist(&self, consist_id: &str, details: &ConsistDetails) -> Result<()> {
+        let row = ConsistRow {
+            id: consist_id.to_string(),
+            name: details.name.clone(),
+        };
+        let owned_rolling_stock_ids = details.owned_rolling_stock_ids.clone();
+        let change = AuditedChange::updated("consist", consist_id, details)?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                if !sqlite::update_consist(&mut *conn, &row).await? {
+                    return Err(anyhow!("consist not found id={}", row.id));
+                }
+                sqlite::replace_consist_entries(&mut *conn, &row.id, &owned_rolling_stock_ids)
+                    .await?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn delete_consist(&self, consist_id: &str) -> Result<()> {
+        let consist_id = consist_id.to_string();
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                if !sqlite::delete_consist(&mut *conn, &consist_id).await? {
+                    return Err(anyhow!("consist not found id={}", consist_id));
+                }
+                audit
+                    .record(conn, &AuditedChange::deleted("consist", consist_id))
+                    .await
+            })
+        })
+        .await
+    }
+
+    async fn get_consist(&self, consist_id: &str) -> Result<Option<Consist>> {
+        let Some(row) = sqlite::get_consist(&self.pool, consist_id).await? else {
+            return Ok(None);
+        };
+        let vehicles = sqlite::get_consist_vehicles(&self.pool, Some(consist_id)).await?;
+        Ok(Self::build_consists(vec![row], vehicles)?.pop())
+    }
+
+    async fn list_consists(&self) -> Result<Vec<Consist>> {
+        let rows = sqlite::get_consists(&self.pool).await?;
+        let vehicles = sqlite::get_consist_vehicles(&self.pool, None).await?;
+        Self::build_consists(rows, vehicles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::domain::consist::ConsistWarning;
+    use crate::collecting::infrastructure::testing::CollectingTestDb;
+    use crate::core::domain::length::Length;
+    use crate::core::domain::measure_units::MeasureUnit;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    /// A collection item owning three rolling stocks: one 210 mm long, one
+    /// 10 in long and one not linked to the catalog. Returns their owned
+    /// rolling stock ids in that order.
+    async fn setup_vehicles(pool: &SqlitePool) -> Result<Vec<String>> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_data = catalog_db.setup_railway_model().await?;
+        catalog_db
+            .insert_rolling_stock(
+                "rs-2",
+                &catalog_data.railway_model_id,
+                "PASSENGER_CAR",
+                &catalog_data.railway_company_id,
+                0,
+            )
+            .await?;
+        sqlx::query(
+            "UPDATE rolling_stocks SET length_millimeters = 210, technical_minimum_radius_mm = 360 WHERE id = ?1",
+        )
+        .bind(&catalog_data.rolling_stock_ids[0])
+        .execute(pool)
+        .await?;
+        sqlx::query("UPDATE rolling_stocks SET length_inches = 10 WHERE id = 'rs-2'")
+            .execute(pool)
+            .await?;
+
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let item_id = collecting_db
+            .insert_collection_item(&collection_id, &catalog_data.railway_model_id)
+            .await?;
+        let mut ids = Vec::new();
+        for rolling_stock_id in [catalog_data.rolling_stock_ids[0].as_str(), "rs-2"] {
+            ids.push(
+                collecting_db
+                    .insert_owned_rolling_stock(&item_id, rolling_stock_id)
+                    .await?,
+            );
+        }
+        sqlx::query(
+            "INSERT INTO owned_rolling_stocks (id, collection_item_id) VALUES ('ors-unlinked', ?1)",
+        )
+        .bind(&item_id)
+        .execute(pool)
+        .await?;
+        ids.push("ors-unlinked".to_string());
+        Ok(ids)
+    }
+
+    fn details(name: &str, ids: &[String]) -> ConsistDetails {
+        ConsistDetails {
+            name: name.to_string(),
+            owned_rolling_stock_ids: ids.to_vec(),
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_consists_crud(pool: SqlitePool) -> Result<()> {
+        let ids = setup_vehicles(&pool).await?;
+        let repo = SqliteConsistRepository::new(pool.clone());
+
+        repo.create_consist("consist-1", &details("Regionale", &ids[..2]))
+            .await?;
+        let consist = repo.get_consist("consist-1").await?.unwrap();
+        assert_eq!(consist.name, "Regionale");
+        let vehicle_ids: Vec<&str> = consist
+            .vehicles
+            .iter()
+            .map(|v| v.owned_rolling_stock_id.as_str())
+            .collect();
+        assert_eq!(vehicle_ids, vec![ids[0].as_str(), ids[1].as_str()]);
+        assert_eq!(consist.vehicles[0].minimum_radius, Some(Radius::H0_R1));
+
+        let reversed = vec![ids[2].clone(), ids[1].clone(), ids[0].clone()];
+        repo.update_consist("consist-1", &details("Espresso", &reversed))
+            .await?;
+        repo.create_consist("consist-2", &details("Accelerato", &ids[..1]))
+            .await?;
+        let consists = repo.list_consists().await?;
+        let names: Vec<&str> = consists.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["Accelerato", "Espresso"]);
+        let vehicle_ids: Vec<String> = consists[1]
+            .vehicles
+            .iter()
+            .map(|v| v.owned_rolling_stock_id.clone())
+            .collect();
+        assert_eq!(vehicle_ids, reversed);
+
+        repo.delete_consist("consist-1").await?;
+        assert!(repo.get_consist("consist-1").await?.is_none());
+        assert!(repo.delete_consist("consist-1").await.is_err());
+        assert!(
+            repo.update_consist("consist-1", &details("Espresso", &[]))
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_consist_lengths_come_from_the_catalog(pool: SqlitePool) -> Result<()> {
+        let ids = setup_vehicles(&pool).await?;
+        let repo = SqliteConsistRepository::new(pool.clone());
+        repo.create_consist("consist-1", &details("Regionale", &ids))
+            .await?;
+
+        let consist = repo.get_consist("consist-1").await?.unwrap();
+
+        assert_eq!(
+            consist.total_length(MeasureUnit::Millimeters),
+            Length::Millimeters(dec!(464))
+        );
+        assert_eq!(
+            consist.warnings(None),
+            vec![ConsistWarning::MissingLength {
+                owned_rolling_stock_id: "ors-unlinked".to_string(),
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_consist_with_unknown_vehicle_is_not_stored(pool: SqlitePool) -> Result<()> {
+        let ids = setup_vehicles(&pool).await?;
+        let repo = SqliteConsistRepository::new(pool.clone());
+
+        let vehicles = vec![ids[0].clone(), "missing".to_string()];
+        assert!(
+            repo.create_consist("consist-1", &details("Regionale", &vehicles))
+                .await
+                .is_err()
+        );
+        assert!(repo.list_consists().await?.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,507 @@
+use crate::audit::infrastructure::sink::{AuditSink, AuditedChange, SqliteAuditSink};
+use crate::catalog::domain::dcc_interface::DccInterface;
+use crate::collecting::domain::decoder::{
+    AddressConflict, Decoder, DecoderError, DecoderInstallation, DecoderWarning,
+};
+use crate::collecting::domain::repository::DecoderRepository;
+use crate::collecting::infrastructure::entities::DecoderRow;
+use crate::collecting::infrastructure::sqlite;
+use crate::core::domain::{Currency, MonetaryAmount};
+use crate::core::infrastructure::transaction::with_transaction;
+use anyhow::{Context, Result, anyhow};
+use log::warn;
+use sqlx::SqlitePool;
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct SqliteDecoderRepository {
+    pool: SqlitePool,
+    audit: Arc<dyn AuditSink>,
+}
+
+impl SqliteDecoderRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            audit: Arc::new(SqliteAuditSink::default()),
+        }
+    }
+
+    /// Record the changes made by this repository in `audit`.
+    pub fn with_audit_sink(mut self, audit: Arc<dyn AuditSink>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    fn to_row(decoder: &Decoder) -> Result<DecoderRow> {
+        let purchase_price_amount = decoder
+            .purchase_price
+            .as_ref()
+            .map(|p| i64::try_from(p.amount))
+            .transpose()
+            .with_context(|| format!("decoder purchase price overflow id={}", decoder.id))?;
+        Ok(DecoderRow {
+            id: decoder.id.clone(),
+            manufacturer: decoder.manufacturer.clone(),
+            model: decoder.model.clone(),
+            dcc_interface: decoder.dcc_interface.map(|i| i.to_string()),
+            address: i64::from(decoder.address),
+            sound_project: decoder.sound_project.clone(),
+            firmware: decoder.firmware.clone(),
+            purchase_price_amount,
+            purchase_price_currency: decoder
+                .purchase_price
+                .as_ref()
+                .map(|p| p.currency.code().to_string()),
+        })
+    }
+
+    /// Map a `decoders` row to a `Decoder`; shared with the collection
+    /// detail load, which reads the installed decoders.
+    pub(crate) fn build_decoder(row: DecoderRow) -> Result<Decoder> {
+        let context = || format!("invalid decoders row id={}", row.id);
+        let dcc_interface = row
+            .dcc_interface
+            .as_deref()
+            .map(DccInterface::from_str)
+            .transpose()
+            .with_context(context)?;
+        let address = u16::try_from(row.address).with_context(context)?;
+        let purchase_price = match (row.purchase_price_amount, &row.purchase_price_currency) {
+            (Some(amount), Some(currency)) => Some(MonetaryAmount::new(
+                u64::try_from(amount).with_context(context)?,
+                Currency::from_code(currency)
+                    .map_err(|e| anyhow!(e))
+                    .with_context(context)?,
+            )),
+            _ => None,
+        };
+
+        Ok(Decoder {
+            dcc_interface,
+            address,
+            purchase_price,
+            id: row.id,
+            manufacturer: row.manufacturer,
+            model: row.model,
+            sound_project: row.sound_project,
+            firmware: row.firmware,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl DecoderRepository for SqliteDecoderRepository {
+    async fn create_decoder(&self, decoder: &Decoder) -> Result<()> {
+        let row = Self::to_row(decoder)?;
+        let change = AuditedChange::created("decoder", &decoder.id, decoder)?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                sqlite::insert_decoder(&mut *conn, &row).await?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn update_decoder(&self, decoder: &Decoder) -> Result<()> {
+        let row = Self::to_row(decoder)?;
+        let change = AuditedChange::updated("decoder", &decoder.id, decoder)?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                if !sqlite::update_decoder(&mut *conn, &row).await? {
+                    return Err(anyhow!("decoder not found id={}", row.id));
+                }
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn delete_decoder(&self, decoder_id: &str) -> Result<()> {
+        let decoder_id = decoder_id.to_string();
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                if !sqlite::delete_decoder(&mut *conn, &decoder_id).await? {
+                    return Err(anyhow!("decoder not found id={}", decoder_id));
+                }
+                audit
+                    .record(conn, &AuditedChange::deleted("decoder", decoder_id))
+                    .await
+            })
+        })
+        .await
+    }
+
+    async fn get_decoder(&self, decoder_id: &str) -> Result<Option<Decoder>> {
+        sqlite::get_decoder(&self.pool, decoder_id)
+            .await?
+            .map(Self::build_decoder)
+            .transpose()
+    }
+
+    async fn list_decoders(&self) -> Result<Vec<Decoder>> {
+        sqlite::get_decoders(&self.pool)
+            .await?
+            .into_iter()
+            .map(Self::build_decoder)
+            .collect()
+    }
+
+    async fn list_decoders_with_address(&self, address: u16) -> Result<Vec<Decoder>> {
+        sqlite::get_decoders_by_address(&self.pool, i64::from(address))
+            .await?
+            .into_iter()
+            .map(Self::build_decoder)
+            .collect()
+    }
+
+    async fn find_address_conflicts(&self) -> Result<Vec<AddressConflict>> {
+        let rows = sqlite::get_address_conflicts(&self.pool).await?;
+
+        // Rows come ordered by address, so each run of equal addresses is a
+        // conflict.
+        let mut conflicts: Vec<AddressConflict> = Vec::new();
+        for row in rows {
+            let address = u16::try_from(row.address)
+                .with_context(|| format!("invalid decoders row id={}", row.decoder_id))?;
+            let installation = DecoderInstallation {
+                owned_rolling_stock_id: row.owned_rolling_stock_id,
+                decoder_id: row.decoder_id,
+            };
+            match conflicts.last_mut() {
+                Some(conflict) if conflict.address == address => {
+                    conflict.installations.push(installation)
+                }
+                _ => conflicts.push(AddressConflict {
+                    address,
+                    installations: vec![installation],
+                }),
+            }
+        }
+
+        Ok(conflicts)
+    }
+
+    async fn attach_decoder(
+        &self,
+        owned_rolling_stock_id: &str,
+        decoder_id: &str,
+    ) -> Result<Vec<DecoderWarning>> {
+        let owned_rolling_stock_id = owned_rolling_stock_id.to_string();
+        let decoder_id = decoder_id.to_string();
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let decoder = sqlite::get_decoder(&mut *conn, &decoder_id)
+                    .await?
+                    .map(Self::build_decoder)
+                    .transpose()?
+                    .ok_or_else(|| anyhow!("decoder not found id={}", decoder_id))?;
+                let rolling_stock_interface =
+                    sqlite::get_owned_rolling_stock_dcc_interface(&mut *conn, &owned_rolling_stock_id)
+                        .await?
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "owned_rolling_stock not found id={}",
+                                owned_rolling_stock_id
+                            )
+                        })?;
+                if let Some(installed_in) =
+                    sqlite::get_decoder_installation(&mut *conn, &decoder_id).await?
+                    && installed_in != owned_rolling_stock_id
+                {
+                    return Err(anyhow!(DecoderError::AlreadyInstalled {
+                        decoder_id,
+                        installed_in,
+                    }));
+                }
+
+                // An interface the catalog stores but the domain does not know
+                // cannot be compared; the decoder is installed all the same.
+                let rolling_stock_interface = rolling_stock_interface.and_then(|value| {
+                    DccInterface::from_str(&value)
+                        .inspect_err(|_| {
+                            warn!(
+                                "Unknown dcc_interface {value:?} for owned rolling stock {owned_rolling_stock_id}"
+                            )
+                        })
+                        .ok()
+                });
+                let warnings = decoder.installation_warnings(rolling_stock_interface);
+                for warning in &warnings {
+                    warn!(
+                        "Installing decoder {decoder_id} in owned rolling stock {owned_rolling_stock_id}: {warning:?}"
+                    );
+                }
+
+                sqlite::set_owned_rolling_stock_decoder(
+                    &mut *conn,
+                    &owned_rolling_stock_id,
+                    Some(&decoder_id),
+                )
+                .await?;
+                let change = AuditedChange::updated(
+                    "owned_rolling_stock",
+                    &owned_rolling_stock_id,
+                    &serde_json::json!({ "decoder_id": decoder_id }),
+                )?;
+                audit.record(conn, &change).await?;
+                Ok(warnings)
+            })
+        })
+        .await
+    }
+
+    async fn detach_decoder(&self, owned_rolling_stock_id: &str) -> Result<Option<String>> {
+        let owned_rolling_stock_id = owned_rolling_stock_id.to_string();
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let owned_row =
+                    sqlite::get_owned_rolling_stock(&mut *conn, owned_rolling_stock_id.clone())
+                        .await?
+                        .ok_or_else(|| {
+                            anyhow!(
+                                "owned_rolling_stock not found id={}",
+                                owned_rolling_stock_id
+                            )
+                        })?;
+                if owned_row.decoder_id.is_some() {
+                    sqlite::set_owned_rolling_stock_decoder(
+                        &mut *conn,
+                        &owned_rolling_stock_id,
+                        None,
+                    )
+                    .await?;
+                    let change = AuditedChange::updated(
+                        "owned_rolling_stock",
+                        &owned_rolling_stock_id,
+                        &serde_json::json!({ "decoder_id": null }),
+                    )?;
+                    audit.record(conn, &change).await?;
+                }
+                Ok(owned_row.decoder_id)
+            })
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::domain::repository::CollectionRepository;
+    use crate::collecting::infrastructure::sqlite_repo::SqliteCollectionRepository;
+    use crate::collecting::infrastructure::testing::CollectingTestDb;
+    use pretty_assertions::assert_eq;
+
+    fn decoder(id: &str, dcc_interface: DccInterface) -> Decoder {
+        Decoder {
+            id: id.to_string(),
+            manufacturer: "ESU".to_string(),
+            model: "LokSound 5".to_string(),
+            dcc_interface: Some(dcc_interface),
+            address: 3,
+            sound_project: Some("BR 218".to_string()),
+            firmware: Some("5.10.2".to_string()),
+            purchase_price: Some(MonetaryAmount::new(11_900, Currency::EUR)),
+        }
+    }
+
+    /// A collection with one item owning one rolling stock with the
+    /// `dcc_interface` stored in the catalog; returns the owned rolling
+    /// stock id.
+    async fn setup_owned_rolling_stock(pool: &SqlitePool, dcc_interface: &str) -> Result<String> {
+        let catalog_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let rolling_stock_id = &catalog_data.rolling_stock_ids[0];
+        sqlx::query("UPDATE rolling_stocks SET dcc_interface = ?2 WHERE id = ?1")
+            .bind(rolling_stock_id)
+            .bind(dcc_interface)
+            .execute(pool)
+            .await?;
+
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let item_id = collecting_db
+            .insert_collection_item(&collection_id, &catalog_data.railway_model_id)
+            .await?;
+        collecting_db
+            .insert_owned_rolling_stock(&item_id, rolling_stock_id)
+            .await
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_attached_decoders_are_loaded_with_the_collection(pool: SqlitePool) -> Result<()> {
+        let owned_id = setup_owned_rolling_stock(&pool, "MTC_21").await?;
+        let repo = SqliteDecoderRepository::new(pool.clone());
+        repo.create_decoder(&decoder("dec-1", DccInterface::Mtc21))
+            .await?;
+
+        let warnings = repo.attach_decoder(&owned_id, "dec-1").await?;
+
+        assert_eq!(warnings, vec![]);
+        let collection = SqliteCollectionRepository::new(pool.clone())
+            .get_collection()
+            .await?;
+        let installed = collection.items[0].rolling_stocks[0]
+            .decoder
+            .as_ref()
+            .expect("installed decoder");
+        assert_eq!(installed.id, "dec-1");
+        assert_eq!(installed.dcc_interface, Some(DccInterface::Mtc21));
+        assert_eq!(installed.sound_project.as_deref(), Some("BR 218"));
+        assert_eq!(
+            installed.purchase_price.as_ref().map(|p| p.amount),
+            Some(11_900)
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_a_mismatched_interface_is_attached_with_a_warning(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let owned_id = setup_owned_rolling_stock(&pool, "NEXT_18").await?;
+        let repo = SqliteDecoderRepository::new(pool.clone());
+        repo.create_decoder(&decoder("dec-1", DccInterface::Plux22))
+            .await?;
+
+        let warnings = repo.attach_decoder(&owned_id, "dec-1").await?;
+
+        assert_eq!(
+            warnings,
+            vec![DecoderWarning::InterfaceMismatch {
+                decoder: DccInterface::Plux22,
+                rolling_stock: DccInterface::Next18,
+            }]
+        );
+        let owned_row = sqlite::get_owned_rolling_stock(&pool, owned_id)
+            .await?
+            .unwrap();
+        assert_eq!(owned_row.decoder_id.as_deref(), Some("dec-1"));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_detach_decoder(pool: SqlitePool) -> Result<()> {
+        let owned_id = setup_owned_rolling_stock(&pool, "MTC_21").await?;
+        let repo = SqliteDecoderRepository::new(pool.clone());
+        repo.create_decoder(&decoder("dec-1", DccInterface::Mtc21))
+            .await?;
+        repo.attach_decoder(&owned_id, "dec-1").await?;
+
+        assert_eq!(
+            repo.detach_decoder(&owned_id).await?.as_deref(),
+            Some("dec-1")
+        );
+        assert_eq!(repo.detach_decoder(&owned_id).await?, None);
+
+        let collection = SqliteCollectionRepository::new(pool.clone())
+            .get_collection()
+            .await?;
+        assert!(collection.items[0].rolling_stocks[0].decoder.is_none());
+        // The decoder is kept in the inventory.
+        assert_eq!(repo.list_decoders().await?.len(), 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_a_decoder_is_installed_in_one_rolling_stock_at_a_time(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let owned_id = setup_owned_rolling_stock(&pool, "MTC_21").await?;
+        let item_id = sqlite::get_owned_rolling_stock(&pool, owned_id.clone())
+            .await?
+            .unwrap()
+            .collection_item_id;
+        sqlx::query(
+            "INSERT INTO owned_rolling_stocks (id, collection_item_id) VALUES ('ors-2', ?1)",
+        )
+        .bind(&item_id)
+        .execute(&pool)
+        .await?;
+        let repo = SqliteDecoderRepository::new(pool.clone());
+        repo.create_decoder(&decoder("dec-1", DccInterface::Mtc21))
+            .await?;
+        repo.attach_decoder(&owned_id, "dec-1").await?;
+
+        let err = repo.attach_decoder("ors-2", "dec-1").await.unwrap_err();
+
+        assert_eq!(
+            err.downcast_ref::<DecoderError>(),
+            Some(&DecoderError::AlreadyInstalled {
+                decoder_id: "dec-1".to_string(),
+                installed_in: owned_id.clone(),
+            })
+        );
+        // Attaching it again where it is installed is fine.
+        assert_eq!(repo.attach_decoder(&owned_id, "dec-1").await?, vec![]);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_decoders_sharing_an_address_are_conflicts(pool: SqlitePool) -> Result<()> {
+        let owned_id = setup_owned_rolling_stock(&pool, "MTC_21").await?;
+        let item_id = sqlite::get_owned_rolling_stock(&pool, owned_id.clone())
+            .await?
+            .unwrap()
+            .collection_item_id;
+        sqlx::query(
+            "INSERT INTO owned_rolling_stocks (id, collection_item_id) VALUES ('ors-2', ?1)",
+        )
+        .bind(&item_id)
+        .execute(&pool)
+        .await?;
+        let repo = SqliteDecoderRepository::new(pool.clone());
+        let mut second = decoder("dec-2", DccInterface::Mtc21);
+        for d in [&decoder("dec-1", DccInterface::Mtc21), &second] {
+            repo.create_decoder(d).await?;
+        }
+        // A decoder in the drawer does not conflict with anything.
+        repo.create_decoder(&decoder("dec-3", DccInterface::Mtc21))
+            .await?;
+        repo.attach_decoder(&owned_id, "dec-1").await?;
+        repo.attach_decoder("ors-2", "dec-2").await?;
+
+        let mut installations = vec![
+            DecoderInstallation {
+                owned_rolling_stock_id: owned_id.clone(),
+                decoder_id: "dec-1".to_string(),
+            },
+            DecoderInstallation {
+                owned_rolling_stock_id: "ors-2".to_string(),
+                decoder_id: "dec-2".to_string(),
+            },
+        ];
+        installations.sort_by(|a, b| a.owned_rolling_stock_id.cmp(&b.owned_rolling_stock_id));
+        assert_eq!(
+            repo.find_address_conflicts().await?,
+            vec![AddressConflict {
+                address: 3,
+                installations,
+            }]
+        );
+
+        second.address = 4;
+        repo.update_decoder(&second).await?;
+        assert_eq!(repo.find_address_conflicts().await?, vec![]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,285 @@
+use crate::audit::infrastructure::sink::{AuditSink, AuditedChange, SqliteAuditSink};
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::contact::Contact;
+use crate::collecting::domain::loan::{Loan, LoanError};
+use crate::collecting::domain::repository::LoanRepository;
+use crate::collecting::infrastructure::entities::LoanRow;
+use crate::collecting::infrastructure::sqlite;
+use crate::core::infrastructure::transaction::with_transaction;
+use anyhow::{Result, anyhow};
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+
+pub struct SqliteLoanRepository {
+    pool: SqlitePool,
+    audit: Arc<dyn AuditSink>,
+}
+
+impl SqliteLoanRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            audit: Arc::new(SqliteAuditSink::default()),
+        }
+    }
+
+    /// Record the changes made by this repository in `audit`.
+    pub fn with_audit_sink(mut self, audit: Arc<dyn AuditSink>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    fn to_row(loan: &Loan) -> LoanRow {
+        LoanRow {
+            id: loan.id.clone(),
+            collection_item_id: loan.collection_item_id.clone(),
+            borrower_name: loan.borrower.name.clone(),
+            borrower_email: loan.borrower.email.clone(),
+            borrower_phone: loan.borrower.phone.clone(),
+            loaned_on: loan.loaned_on,
+            due_back: loan.due_back,
+            returned_on: loan.returned_on,
+        }
+    }
+
+    fn build_loan(row: LoanRow) -> Loan {
+        Loan {
+            id: row.id,
+            collection_item_id: row.collection_item_id,
+            borrower: Contact {
+                name: row.borrower_name,
+                email: row.borrower_email,
+                phone: row.borrower_phone,
+            },
+            loaned_on: row.loaned_on,
+            due_back: row.due_back,
+            returned_on: row.returned_on,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl LoanRepository for SqliteLoanRepository {
+    async fn lend_item(&self, loan: &Loan) -> Result<()> {
+        let row = Self::to_row(loan);
+        let change = AuditedChange::created("loan", &loan.id, loan)?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let item_id = row.collection_item_id.clone();
+                if sqlite::get_collection_item(&mut *conn, item_id.clone())
+                    .await?
+                    .is_none()
+                {
+                    return Err(anyhow!("collection item not found id={}", item_id));
+                }
+                if sqlite::is_collection_item_sold(&mut *conn, &item_id).await? {
+                    return Err(LoanError::Sold(item_id).into());
+                }
+                if sqlite::get_outstanding_loan(&mut *conn, &item_id)
+                    .await?
+                    .is_some()
+                {
+                    return Err(LoanError::OnLoan(item_id).into());
+                }
+                sqlite::insert_loan(&mut *conn, &row).await?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn return_item(
+        &self,
+        collection_item_id: &CollectionItemId,
+        returned_on: NaiveDate,
+    ) -> Result<Loan> {
+        let collection_item_id = collection_item_id.clone();
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let Some(row) =
+                    sqlite::get_outstanding_loan(&mut *conn, &collection_item_id).await?
+                else {
+                    return Err(LoanError::NotOnLoan(collection_item_id).into());
+                };
+                let loan = Self::build_loan(row).returned(returned_on)?;
+                sqlite::set_loan_returned(&mut *conn, &loan.id, returned_on).await?;
+                let change = AuditedChange::updated("loan", &loan.id, &loan)?;
+                audit.record(conn, &change).await?;
+                Ok(loan)
+            })
+        })
+        .await
+    }
+
+    async fn list_outstanding_loans(
+        &self,
+        overdue_only: bool,
+        today: NaiveDate,
+    ) -> Result<Vec<Loan>> {
+        let rows = sqlite::get_outstanding_loans(&self.pool, overdue_only.then_some(today)).await?;
+        Ok(rows.into_iter().map(Self::build_loan).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::domain::repository::CollectionRepository;
+    use crate::collecting::infrastructure::sqlite_repo::SqliteCollectionRepository;
+    use crate::collecting::infrastructure::testing::CollectingTestDb;
+    use pretty_assertions::assert_eq;
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    fn loan(id: &str, item_id: &CollectionItemId, due_back: Option<NaiveDate>) -> Loan {
+        Loan::new(
+            id.to_string(),
+            item_id.clone(),
+            Contact {
+                name: "Mario Rossi".to_string(),
+                email: Some("mario@example.com".to_string()),
+                phone: None,
+            },
+            date(5, 1),
+            due_back,
+        )
+        .unwrap()
+    }
+
+    /// A collection with two items of the same railway model; returns their
+    /// ids and the purchase info id of the first one.
+    async fn setup_items(
+        pool: &SqlitePool,
+    ) -> Result<(CollectionItemId, CollectionItemId, String)> {
+        let catalog_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection = collecting_db
+            .setup_minimal_collection(&catalog_data.railway_model_id, Vec::new())
+            .await?;
+        let other_id = collecting_db
+            .insert_collection_item(&collection.collection_id, &catalog_data.railway_model_id)
+            .await?;
+        Ok((
+            CollectionItemId::try_from(collection.collection_item_id)?,
+            CollectionItemId::try_from(other_id)?,
+            collection.purchase_info_id,
+        ))
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_items_on_loan_cannot_be_lent_again(pool: SqlitePool) -> Result<()> {
+        let (item_id, _, _) = setup_items(&pool).await?;
+        let repo = SqliteLoanRepository::new(pool.clone());
+        let collections = SqliteCollectionRepository::new(pool.clone());
+        let is_on_loan = || async {
+            let collection = collections.get_collection().await?;
+            let item = collection.items.iter().find(|i| i.id == item_id).unwrap();
+            Ok::<_, anyhow::Error>(item.is_on_loan)
+        };
+
+        repo.lend_item(&loan("loan-1", &item_id, None)).await?;
+        assert!(is_on_loan().await?);
+        let err = repo
+            .lend_item(&loan("loan-2", &item_id, None))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<LoanError>(),
+            Some(&LoanError::OnLoan(item_id.clone()))
+        );
+
+        let returned = repo.return_item(&item_id, date(5, 20)).await?;
+        assert_eq!(returned.id, "loan-1");
+        assert_eq!(returned.returned_on, Some(date(5, 20)));
+        assert!(!is_on_loan().await?);
+        let err = repo.return_item(&item_id, date(5, 21)).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<LoanError>(),
+            Some(&LoanError::NotOnLoan(item_id.clone()))
+        );
+
+        // Once back, the item can go out again.
+        repo.lend_item(&loan("loan-2", &item_id, None)).await?;
+        assert!(
+            repo.lend_item(&loan("loan-3", &CollectionItemId::default(), None))
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_outstanding_loans_can_be_filtered_to_overdue_ones(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let (item_id, other_id, _) = setup_items(&pool).await?;
+        let repo = SqliteLoanRepository::new(pool.clone());
+        repo.lend_item(&loan("loan-1", &item_id, Some(date(5, 15))))
+            .await?;
+        repo.lend_item(&loan("loan-2", &other_id, None)).await?;
+
+        let ids = |loans: Vec<Loan>| loans.into_iter().map(|l| l.id).collect::<Vec<_>>();
+        assert_eq!(
+            ids(repo.list_outstanding_loans(false, date(5, 10)).await?),
+            vec!["loan-1", "loan-2"]
+        );
+        assert!(
+            repo.list_outstanding_loans(true, date(5, 15))
+                .await?
+                .is_empty()
+        );
+        let overdue = repo.list_outstanding_loans(true, date(5, 16)).await?;
+        assert_eq!(overdue, vec![loan("loan-1", &item_id, Some(date(5, 15)))]);
+
+        repo.return_item(&item_id, date(6, 1)).await?;
+        assert!(
+            repo.list_outstanding_loans(true, date(6, 2))
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_items_on_loan_cannot_be_sold(pool: SqlitePool) -> Result<()> {
+        let (item_id, _, purchase_info_id) = setup_items(&pool).await?;
+        let repo = SqliteLoanRepository::new(pool.clone());
+        repo.lend_item(&loan("loan-1", &item_id, None)).await?;
+
+        let mut row = sqlite::get_purchase_info(&pool, purchase_info_id.clone())
+            .await?
+            .unwrap();
+        row.purchase_type = Some("sold".to_string());
+        row.sale_date = Some(date(5, 10));
+        let err = sqlite::update_purchase_info(&pool, &row).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<LoanError>(),
+            Some(&LoanError::OnLoan(item_id.clone()))
+        );
+
+        repo.return_item(&item_id, date(5, 5)).await?;
+        sqlite::update_purchase_info(&pool, &row).await?;
+        let err = repo
+            .lend_item(&loan("loan-2", &item_id, None))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<LoanError>(),
+            Some(&LoanError::Sold(item_id.clone()))
+        );
+
+        Ok(())
+    }
+}
@@ -1,97 +1,628 @@
+use crate::audit::infrastructure::sink::{AuditSink, AuditedChange, SqliteAuditSink};
+use crate::catalog::domain::ProductCode;
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::infrastructure::cache::RailwayModelCache;
+use crate::collecting::domain::bulk_update::{
+    BulkUpdateOptions, BulkUpdateReport, CollectionItemFilter, CollectionItemPatch, LocationUpdate,
+};
 use crate::collecting::domain::collection::Collection;
 use crate::collecting::domain::collection_id::CollectionId;
 use crate::collecting::domain::collection_item::CollectionItem;
 use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::consistency::{
+    self, ConsistencyReport, Inconsistency, InconsistencyKind, RepairAction,
+};
+use crate::collecting::domain::cost_breakdown::{ItemCostBreakdown, ItemCostInputs};
+use crate::collecting::domain::discount;
+use crate::collecting::domain::display_currency::StoredItemAmounts;
+use crate::collecting::domain::insurance_report::{
+    InsuranceReport, InsuranceReportLine, ValuationMode,
+};
+use crate::collecting::domain::item_duplication::{DuplicateItemError, DuplicateItemOptions};
+use crate::collecting::domain::modification::{Modification, ModificationType};
+use crate::collecting::domain::monetary_adjustment::MonetaryAdjustment;
 use crate::collecting::domain::owned_rolling_stock::OwnedRollingStock;
-use crate::collecting::domain::purchase_info::PurchaseInfo;
+use crate::collecting::domain::purchase_info::{PurchaseInfo, PurchasedInfo};
+use crate::collecting::domain::recent_activity::{ActivityEvent, ActivityKind, MAX_VIEWS_PER_ITEM};
+use crate::collecting::domain::recompute_diff::{CollectionRecomputeDiff, TotalValueDiff};
 use crate::collecting::domain::repository::CollectionRepository;
+use crate::collecting::domain::statistics::{CollectionStatistics, CurrencyValue, RealizedGain};
 use crate::collecting::domain::summary::CollectionSummary;
+use crate::collecting::domain::value_snapshot::ValueSnapshot;
+use crate::collecting::domain::year_review::{ManufacturerPurchases, YearPurchase, YearReview};
 use crate::collecting::infrastructure::entities::{
-    CollectionItemRow, CollectionRow, OwnedRollingStockRow, PurchaseInfoRow,
+    CollectionDetailRow, CollectionItemRow, CollectionRow, CollectionSummaryRow, CurrencyTotalRow,
+    DecoderRow, InsuranceReportRow, ModificationRow, MonetaryAdjustmentRow, OwnedRollingStockRow,
+    PurchaseInfoRow, ValueSnapshotRow, YearPurchaseRow,
 };
+use crate::collecting::infrastructure::purchase_date::parse_purchase_date;
 use crate::collecting::infrastructure::sqlite;
-use crate::core::domain::MonetaryAmount;
+use crate::collecting::infrastructure::sqlite_decoder_repo::SqliteDecoderRepository;
+use crate::core::domain::page::{KeysetPage, PageKey};
+use crate::core::domain::{Currency, MonetaryAmount};
+use crate::core::infrastructure::transaction::with_transaction;
 use anyhow::{Context, Result, anyhow};
-use itertools::Itertools;
+use chrono::{DateTime, NaiveDate, Utc};
+use log::warn;
 use sqlx::SqlitePool;
-use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// How `get_collection` handles a purchase info row that cannot be mapped to
+/// a `PurchaseInfo` (for example an unknown purchase type or currency).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PurchaseInfoValidation {
+    /// Fail the whole load with an error describing the row.
+    Strict,
+    /// Load the item without purchase info and describe the problem in
+    /// `CollectionItem::purchase_info_error`.
+    #[default]
+    Lenient,
+}
+
+/// An owned rolling stock row with the row of the decoder installed in it.
+struct OwnedRollingStockDetail {
+    row: OwnedRollingStockRow,
+    decoder: Option<DecoderRow>,
+    is_modified: bool,
+}
+
+/// A collection item whose detail rows are still being read.
+struct PendingCollectionItem {
+    sort_key: String,
+    row: CollectionItemRow,
+    is_on_loan: bool,
+    /// The stored suggested retail price of the item's railway model, as
+    /// amount and currency code.
+    msrp: (Option<i64>, Option<String>),
+    owned_rolling_stocks: Vec<OwnedRollingStockDetail>,
+    /// The first purchase info row; an item is expected to have at most one.
+    purchase_info: Option<PurchaseInfoRow>,
+}
+
+impl PendingCollectionItem {
+    /// Add the owned rolling stock and purchase info of one more detail row.
+    ///
+    /// An item with several of both is repeated once per combination, so a
+    /// row already seen is skipped: owned rolling stocks arrive grouped by id
+    /// and only the first purchase info is kept.
+    fn add(
+        &mut self,
+        owned_rolling_stock: Option<OwnedRollingStockDetail>,
+        purchase_info: Option<PurchaseInfoRow>,
+    ) {
+        if let Some(owned) = owned_rolling_stock
+            && self
+                .owned_rolling_stocks
+                .last()
+                .is_none_or(|last| last.row.id != owned.row.id)
+        {
+            self.owned_rolling_stocks.push(owned);
+        }
+        if self.purchase_info.is_none() {
+            self.purchase_info = purchase_info;
+        }
+    }
+}
+
+/// Groups the joined detail rows of a collection, in which the rows of one
+/// item are contiguous, into collection items with their keys.
+struct CollectionItemGrouper {
+    purchase_info_validation: PurchaseInfoValidation,
+    items: Vec<(PageKey, CollectionItem)>,
+    pending: Option<PendingCollectionItem>,
+}
+
+impl CollectionItemGrouper {
+    fn new(purchase_info_validation: PurchaseInfoValidation) -> Self {
+        Self {
+            purchase_info_validation,
+            items: Vec::new(),
+            pending: None,
+        }
+    }
+
+    /// Add one detail row. The pending item is complete once a row of the
+    /// next one arrives.
+    fn push(&mut self, detail_row: CollectionDetailRow) -> Result<()> {
+        let sort_key = detail_row.sort_key.clone();
+        let is_on_loan = detail_row.item_is_on_loan;
+        let msrp = (
+            detail_row.model_msrp_amount,
+            detail_row.model_msrp_currency.clone(),
+        );
+        let (item_row, owned_rolling_stock, purchase_info) =
+            SqliteCollectionRepository::split_detail_row(detail_row)?;
+
+        match self.pending.as_mut() {
+            Some(item) if item.row.id == item_row.id => {
+                item.add(owned_rolling_stock, purchase_info)
+            }
+            _ => {
+                let mut item = PendingCollectionItem {
+                    sort_key,
+                    row: item_row,
+                    is_on_loan,
+                    msrp,
+                    owned_rolling_stocks: Vec::new(),
+                    purchase_info: None,
+                };
+                item.add(owned_rolling_stock, purchase_info);
+                if let Some(done) = self.pending.replace(item) {
+                    self.complete(done)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn complete(&mut self, item: PendingCollectionItem) -> Result<()> {
+        let key = PageKey {
+            sort_key: item.sort_key.clone(),
+            id: item.row.id.to_string(),
+        };
+        let item =
+            SqliteCollectionRepository::build_collection_item(item, self.purchase_info_validation)?;
+        self.items.push((key, item));
+        Ok(())
+    }
+
+    /// The collection items, in the order of their rows, with their keys.
+    fn finish(mut self) -> Result<Vec<(PageKey, CollectionItem)>> {
+        if let Some(done) = self.pending.take() {
+            self.complete(done)?;
+        }
+        Ok(self.items)
+    }
+}
 
 pub struct SqliteCollectionRepository {
     pool: SqlitePool,
+    purchase_info_validation: PurchaseInfoValidation,
+    catalog_cache: RailwayModelCache,
+    audit: Arc<dyn AuditSink>,
 }
 
 impl SqliteCollectionRepository {
     pub fn new(pool: SqlitePool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            purchase_info_validation: PurchaseInfoValidation::default(),
+            catalog_cache: RailwayModelCache::default(),
+            audit: Arc::new(SqliteAuditSink::default()),
+        }
+    }
+
+    /// Look railway model summaries up in `cache` (usually the
+    /// application-wide one).
+    pub fn with_catalog_cache(mut self, cache: RailwayModelCache) -> Self {
+        self.catalog_cache = cache;
+        self
+    }
+
+    /// Use `validation` for purchase info rows that cannot be mapped.
+    pub fn with_purchase_info_validation(mut self, validation: PurchaseInfoValidation) -> Self {
+        self.purchase_info_validation = validation;
+        self
+    }
+
+    /// Record the changes made by this repository in `audit`.
+    pub fn with_audit_sink(mut self, audit: Arc<dyn AuditSink>) -> Self {
+        self.audit = audit;
+        self
     }
 }
 
 impl SqliteCollectionRepository {
     // Helper to build Collection from CollectionRow and items
     fn build_collection(row: CollectionRow, items: Vec<CollectionItem>) -> Result<Collection> {
-        let collection_id = CollectionId::try_from(row.id).map_err(|e| anyhow!(e))?;
-
+        let summary = Self::build_stored_summary(&row);
         Ok(Collection {
-            id: collection_id,
+            id: row.id,
             name: row.name,
-            summary: CollectionSummary {
-                locomotives_count: row.locomotives_count as u16,
-                passenger_cars_count: row.passenger_cars_count as u16,
-                freight_cars_count: row.freight_cars_count as u16,
-                train_sets_count: row.train_sets_count as u16,
-                railcars_count: row.railcars_count as u16,
-                electric_multiple_units_count: row.electric_multiple_units_count as u16,
-            },
+            summary,
             total_value: MonetaryAmount::from_db(
                 row.total_value_amount,
                 Some(&row.total_value_currency),
             )
             .map_err(|e| anyhow!(e.to_string()))
             .context("Failed to parse collection total value from DB")?,
+            load_warnings: Self::load_warnings(&items),
             items,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
         })
     }
 
+    /// One warning per item whose purchase info could not be loaded.
+    fn load_warnings(items: &[CollectionItem]) -> Vec<String> {
+        items
+            .iter()
+            .filter_map(|item| {
+                item.purchase_info_error
+                    .as_ref()
+                    .map(|error| format!("collection item {}: {error}", item.id))
+            })
+            .collect()
+    }
+
+    fn build_stored_summary(row: &CollectionRow) -> CollectionSummary {
+        CollectionSummary {
+            locomotives_count: row.locomotives_count as u16,
+            passenger_cars_count: row.passenger_cars_count as u16,
+            freight_cars_count: row.freight_cars_count as u16,
+            train_sets_count: row.train_sets_count as u16,
+            railcars_count: row.railcars_count as u16,
+            electric_multiple_units_count: row.electric_multiple_units_count as u16,
+        }
+    }
+
+    fn build_computed_summary(row: &CollectionSummaryRow) -> CollectionSummary {
+        CollectionSummary {
+            locomotives_count: row.locomotives_count as u16,
+            passenger_cars_count: row.passenger_cars_count as u16,
+            freight_cars_count: row.freight_cars_count as u16,
+            train_sets_count: row.train_sets_count as u16,
+            railcars_count: row.railcars_count as u16,
+            electric_multiple_units_count: row.electric_multiple_units_count as u16,
+        }
+    }
+
+    /// Build the per-currency total value diff.
+    ///
+    /// The stored total is denominated in a single currency, so its old amount
+    /// is reported for that currency and every other currency starts from zero.
+    /// The stored currency is always included, even when no item is priced in
+    /// it. Entries are ordered by currency code.
+    fn build_total_value_diffs(
+        stored_currency: Currency,
+        stored_amount: u64,
+        new_totals: &[(Currency, u64)],
+    ) -> Vec<TotalValueDiff> {
+        let mut diffs: Vec<TotalValueDiff> = new_totals
+            .iter()
+            .map(|(currency, amount)| TotalValueDiff {
+                currency: *currency,
+                old_amount: if *currency == stored_currency {
+                    stored_amount
+                } else {
+                    0
+                },
+                new_amount: *amount,
+            })
+            .collect();
+
+        if !diffs.iter().any(|d| d.currency == stored_currency) {
+            diffs.push(TotalValueDiff {
+                currency: stored_currency,
+                old_amount: stored_amount,
+                new_amount: 0,
+            });
+        }
+
+        diffs.sort_by_key(|d| d.currency.code());
+        diffs
+    }
+
+    /// Split a joined detail row into the item row and, when present, its
+    /// owned rolling stock (with the installed decoder) and purchase info
+    /// rows.
+    fn split_detail_row(
+        row: CollectionDetailRow,
+    ) -> Result<(
+        CollectionItemRow,
+        Option<OwnedRollingStockDetail>,
+        Option<PurchaseInfoRow>,
+    )> {
+        let decoder = match row.decoder_id {
+            None => None,
+            Some(decoder_id) => Some(DecoderRow {
+                manufacturer: row.decoder_manufacturer.unwrap_or_default(),
+                model: row.decoder_model.unwrap_or_default(),
+                dcc_interface: row.decoder_interface,
+                address: row.decoder_address.with_context(|| {
+                    format!("missing address in decoders row id={}", decoder_id)
+                })?,
+                sound_project: row.decoder_sound_project,
+                firmware: row.decoder_firmware,
+                purchase_price_amount: row.decoder_price_amount,
+                purchase_price_currency: row.decoder_price_currency,
+                id: decoder_id,
+            }),
+        };
+        let owned_rolling_stock = row.owned_id.map(|id| OwnedRollingStockDetail {
+            row: OwnedRollingStockRow {
+                id,
+                collection_item_id: row.item_id.clone(),
+                rolling_stock_id: row.owned_rolling_stock_id,
+                notes: row.owned_notes,
+                decoder_id: decoder.as_ref().map(|d| d.id.clone()),
+                location_id: row.owned_location_id,
+            },
+            decoder,
+            is_modified: row.owned_is_modified,
+        });
+
+        let purchase_info = match row.purchase_id {
+            None => None,
+            Some(purchase_id) => Some(PurchaseInfoRow {
+                purchase_date: row.purchase_date.with_context(|| {
+                    format!(
+                        "missing purchase_date in purchase_infos row id={}",
+                        purchase_id
+                    )
+                })?,
+                purchase_id,
+                collection_item_id: row.item_id.clone(),
+                purchase_type: row.purchase_type,
+                seller_id: row.seller_id,
+                buyer_id: row.buyer_id,
+                sale_date: row.sale_date,
+                purchased_price_amount: row.purchased_price_amount,
+                purchased_price_currency: row.purchased_price_currency,
+                sale_price_amount: row.sale_price_amount,
+                sale_price_currency: row.sale_price_currency,
+                deposit_amount: row.deposit_amount,
+                deposit_currency: row.deposit_currency,
+                preorder_total_amount: row.preorder_total_amount,
+                preorder_total_currency: row.preorder_total_currency,
+                expected_date: row.expected_date,
+                version: row.purchase_version.unwrap_or_default(),
+                created_at: row.purchase_created_at,
+                updated_at: row.purchase_updated_at,
+            }),
+        };
+
+        let item = CollectionItemRow {
+            id: row.item_id,
+            collection_id: row.collection_id,
+            item_number: row.item_number,
+            railway_model_id: row.railway_model_id,
+            conditions: row.conditions,
+            notes: row.notes,
+            location_id: row.item_location_id,
+            replacement_value_amount: row.item_replacement_value_amount,
+            replacement_value_currency: row.item_replacement_value_currency,
+            version: row.item_version,
+            created_at: row.item_created_at,
+            updated_at: row.item_updated_at,
+        };
+
+        Ok((item, owned_rolling_stock, purchase_info))
+    }
+
     fn build_collection_item(
-        row: CollectionItemRow,
-        owned_rolling_stocks_map: &HashMap<CollectionItemId, Vec<OwnedRollingStockRow>>,
-        purchase_info_map: &HashMap<CollectionItemId, Vec<PurchaseInfoRow>>,
+        item: PendingCollectionItem,
+        purchase_info_validation: PurchaseInfoValidation,
     ) -> Result<CollectionItem> {
-        let collection_item_id = CollectionItemId::try_from(&row.id).map_err(|e| anyhow!(e))?;
+        let PendingCollectionItem {
+            sort_key: _,
+            row,
+            is_on_loan,
+            msrp,
+            owned_rolling_stocks,
+            purchase_info,
+        } = item;
 
-        let owned_rolling_stocks = owned_rolling_stocks_map
-            .get(&collection_item_id)
-            .map(|owned_rs_list| {
-                owned_rs_list
-                    .iter()
-                    .map(|rs_row| OwnedRollingStock {
-                        id: rs_row.id.clone(),
-                        rolling_stock_id: rs_row
-                            .rolling_stock_id
-                            .clone()
-                            .unwrap_or_else(|| rs_row.id.clone()),
-                        notes: rs_row.notes.clone().unwrap_or_default(),
-                    })
-                    .collect()
+        let owned_rolling_stocks = owned_rolling_stocks
+            .into_iter()
+            .map(|owned| {
+                Ok(OwnedRollingStock {
+                    id: owned.row.id,
+                    rolling_stock_id: owned.row.rolling_stock_id,
+                    notes: owned.row.notes.unwrap_or_default(),
+                    decoder: owned
+                        .decoder
+                        .map(SqliteDecoderRepository::build_decoder)
+                        .transpose()?,
+                    location_id: owned.row.location_id,
+                    is_modified: owned.is_modified,
+                })
             })
-            .unwrap_or_default();
+            .collect::<Result<Vec<_>>>()?;
+
+        let (purchase_info, purchase_info_error) = match &purchase_info {
+            None => (None, None),
+            Some(pi_row) => match Self::build_purchase_info(pi_row) {
+                Ok(purchase_info) => (Some(purchase_info), None),
+                Err(e) => match purchase_info_validation {
+                    PurchaseInfoValidation::Strict => return Err(e),
+                    PurchaseInfoValidation::Lenient => {
+                        warn!(
+                            "Skipping purchase info of collection item {}: {e:#}",
+                            row.id
+                        );
+                        (None, Some(format!("{e:#}")))
+                    }
+                },
+            },
+        };
+
+        let replacement_value = MonetaryAmount::from_db(
+            row.replacement_value_amount.unwrap_or(0),
+            row.replacement_value_currency.as_deref(),
+        )
+        .with_context(|| format!("invalid replacement value of collection_item id={}", row.id))?;
+        let msrp =
+            MonetaryAmount::from_db(msrp.0.unwrap_or(0), msrp.1.as_deref()).with_context(|| {
+                format!("invalid msrp of railway_model id={}", row.railway_model_id)
+            })?;
+        let item_number = u32::try_from(row.item_number)
+            .with_context(|| format!("invalid item number of collection_item id={}", row.id))?;
 
-        Ok(CollectionItem {
-            id: collection_item_id.clone(),
+        let mut item = CollectionItem {
+            id: row.id,
+            item_number,
             railway_model_id: row.railway_model_id,
-            conditions: row.conditions.clone(),
-            notes: row.notes.clone(),
+            railway_model: None,
+            conditions: row.conditions,
+            notes: row.notes,
             rolling_stocks: owned_rolling_stocks,
-            purchase_info: purchase_info_map
-                .get(&collection_item_id)
-                .and_then(|pi_list| pi_list.first())
-                .and_then(|pi_row| Self::build_purchase_info(pi_row).ok()),
+            location_id: row.location_id,
+            replacement_value,
+            is_on_loan,
+            purchase_info,
+            purchase_info_error,
+            discount_percentage: None,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        };
+        item.discount_percentage = item.discount_percentage(msrp.as_ref());
+        Ok(item)
+    }
+
+    /// Fill in the railway model summaries of `collection_items` from the
+    /// catalog cache.
+    async fn attach_railway_models(&self, collection_items: &mut [CollectionItem]) -> Result<()> {
+        let railway_model_ids: Vec<RailwayModelId> = collection_items
+            .iter()
+            .map(|item| item.railway_model_id)
+            .collect();
+        let railway_models = self
+            .catalog_cache
+            .get_many(&self.pool, &railway_model_ids)
+            .await?;
+        for item in collection_items {
+            item.railway_model = railway_models.get(&item.railway_model_id).cloned();
+        }
+        Ok(())
+    }
+
+    fn build_currency_value(currency: &str, amount: i64) -> Result<CurrencyValue> {
+        Ok(CurrencyValue {
+            currency: Currency::from_code(currency)
+                .map_err(|e| anyhow!(e))
+                .with_context(|| format!("invalid currency={}", currency))?,
+            amount: u64::try_from(amount)
+                .with_context(|| format!("negative total value for currency={}", currency))?,
+        })
+    }
+
+    fn build_realized_gain(currency: &str, amount: i64) -> Result<RealizedGain> {
+        Ok(RealizedGain {
+            currency: Currency::from_code(currency)
+                .map_err(|e| anyhow!(e))
+                .with_context(|| format!("invalid purchase price currency={}", currency))?,
+            amount,
+        })
+    }
+
+    fn build_year_purchase(row: YearPurchaseRow) -> Result<YearPurchase> {
+        let price = Self::build_currency_value(&row.currency, row.amount)?;
+        Ok(YearPurchase {
+            collection_item_id: row.collection_item_id,
+            manufacturer: row.manufacturer,
+            product_code: row.product_code,
+            price: MonetaryAmount::new(price.amount, price.currency),
+        })
+    }
+
+    fn build_insurance_report_line(
+        row: InsuranceReportRow,
+        valuation: ValuationMode,
+    ) -> Result<InsuranceReportLine> {
+        let context = || {
+            format!(
+                "invalid insurance report data of collection_item id={}",
+                row.collection_item_id
+            )
+        };
+        let purchase_price = MonetaryAmount::from_db(
+            row.purchased_price_amount.unwrap_or(0),
+            row.purchased_price_currency.as_deref(),
+        )
+        .with_context(context)?;
+        let replacement_value = MonetaryAmount::from_db(
+            row.replacement_value_amount.unwrap_or(0),
+            row.replacement_value_currency.as_deref(),
+        )
+        .with_context(context)?;
+
+        Ok(InsuranceReportLine {
+            insured_value: valuation
+                .insured_value(purchase_price.as_ref(), replacement_value.as_ref()),
+            collection_item_id: row.collection_item_id,
+            manufacturer: row.manufacturer,
+            product_code: ProductCode(row.product_code),
+            description: row.description,
+            road_numbers: row
+                .road_numbers
+                .map(|numbers| numbers.lines().map(str::to_string).collect())
+                .unwrap_or_default(),
+            purchase_date: row.purchase_date,
+            purchase_price,
+            replacement_value,
+        })
+    }
+
+    fn build_monetary_adjustment(row: &MonetaryAdjustmentRow) -> Result<MonetaryAdjustment> {
+        let currency = Currency::from_code(&row.currency)
+            .map_err(|e| anyhow!(e))
+            .with_context(|| format!("invalid monetary_adjustments row id={}", row.id))?;
+        Ok(MonetaryAdjustment {
+            id: row.id.clone(),
+            amount: row.amount,
+            currency,
+            date: row.adjustment_date,
+            reason: row.reason.clone(),
+        })
+    }
+
+    fn to_modification_row(
+        owned_rolling_stock_id: &str,
+        modification: &Modification,
+    ) -> Result<ModificationRow> {
+        let cost_amount = modification
+            .cost
+            .as_ref()
+            .map(|c| i64::try_from(c.amount))
+            .transpose()
+            .with_context(|| format!("modification cost overflow id={}", modification.id))?;
+        Ok(ModificationRow {
+            id: modification.id.clone(),
+            owned_rolling_stock_id: owned_rolling_stock_id.to_string(),
+            modification_type: modification.modification_type.to_string(),
+            description: modification.description.clone(),
+            modification_date: modification.date,
+            cost_amount,
+            cost_currency: modification
+                .cost
+                .as_ref()
+                .map(|c| c.currency.code().to_string()),
+        })
+    }
+
+    fn build_modification(row: ModificationRow) -> Result<Modification> {
+        let context = || format!("invalid modifications row id={}", row.id);
+        let modification_type =
+            ModificationType::from_str(&row.modification_type).with_context(context)?;
+        let cost = match (row.cost_amount, &row.cost_currency) {
+            (Some(amount), Some(currency)) => Some(MonetaryAmount::new(
+                u64::try_from(amount).with_context(context)?,
+                Currency::from_code(currency)
+                    .map_err(|e| anyhow!(e))
+                    .with_context(context)?,
+            )),
+            _ => None,
+        };
+        Ok(Modification {
+            id: row.id,
+            modification_type,
+            description: row.description,
+            date: row.modification_date,
+            cost,
         })
     }
 
     fn build_purchase_info(pi_row: &PurchaseInfoRow) -> Result<PurchaseInfo> {
+        Self::map_purchase_info(pi_row)
+            .with_context(|| format!("invalid purchase_infos row id={}", pi_row.purchase_id))
+    }
+
+    fn map_purchase_info(pi_row: &PurchaseInfoRow) -> Result<PurchaseInfo> {
         let purchase_type = pi_row.purchase_type.as_deref();
-        let purchase_date = pi_row.purchase_date;
+        let purchase_date = parse_purchase_date(&pi_row.purchase_date)?;
         match purchase_type {
             Some("purchased") => {
                 let price = MonetaryAmount::from_db(
@@ -148,7 +679,8 @@ impl SqliteCollectionRepository {
                     },
                 ))
             }
-            _ => Err(anyhow!("Invalid purchase type")),
+            Some(other) => Err(anyhow!("Invalid purchase type {:?}", other)),
+            None => Err(anyhow!("Missing purchase type")),
         }
     }
 }
@@ -156,10 +688,9 @@ impl SqliteCollectionRepository {
 #[async_trait::async_trait]
 impl CollectionRepository for SqliteCollectionRepository {
     async fn get_collection(&self) -> Result<Collection> {
-        // For simplicity and matching the use case "get collection", we assume a single user collection for now
-        // or getting the first one found. If none exists, we might need to return a default or error.
-        // For this iteration, let's try to fetch the first collection.
-        let collection_id = CollectionId::default();
+        // The application manages a single user collection for now, stored
+        // under the well-known default id.
+        let collection_id = CollectionId::DEFAULT;
 
         let collection_row = sqlite::get_collection(&self.pool, collection_id).await?;
         if collection_row.is_none() {
@@ -169,125 +700,3001 @@ impl CollectionRepository for SqliteCollectionRepository {
 
         let collection_row =
             collection_row.expect("Expect collection row to be present after None check");
-        let collection_id = CollectionId::try_from(&collection_row.id).map_err(|e| anyhow!(e))?;
-        let collection_item_rows = sqlite::get_collection_items(&self.pool, &collection_id).await?;
 
-        let owned_rolling_stock_rows =
-            sqlite::get_owned_rolling_stocks(&self.pool, &collection_id).await?;
-        let owned_rolling_stocks_map = owned_rolling_stock_rows
+        let mut grouper = CollectionItemGrouper::new(self.purchase_info_validation);
+        sqlite::for_each_collection_detail(&self.pool, &collection_row.id, |detail_row| {
+            grouper.push(detail_row)
+        })
+        .await?;
+        let mut collection_items: Vec<CollectionItem> = grouper
+            .finish()?
             .into_iter()
-            .map(|owned_rs| {
-                (
-                    CollectionItemId::try_from(&owned_rs.collection_item_id).unwrap(),
-                    owned_rs,
-                )
+            .map(|(_, item)| item)
+            .collect();
+        self.attach_railway_models(&mut collection_items).await?;
+
+        Self::build_collection(collection_row, collection_items)
+    }
+
+    async fn list_collection_items(
+        &self,
+        after: Option<&PageKey>,
+        limit: u32,
+    ) -> Result<KeysetPage<CollectionItem>> {
+        let collection_id = CollectionId::DEFAULT;
+
+        let mut grouper = CollectionItemGrouper::new(self.purchase_info_validation);
+        sqlite::for_each_collection_items_page_detail(
+            &self.pool,
+            &collection_id,
+            after,
+            limit.saturating_add(1),
+            |detail_row| grouper.push(detail_row),
+        )
+        .await?;
+        let page = KeysetPage::from_rows(grouper.finish()?, limit, |(key, _)| key.clone());
+
+        let mut collection_items: Vec<CollectionItem> =
+            page.items.into_iter().map(|(_, item)| item).collect();
+        self.attach_railway_models(&mut collection_items).await?;
+
+        Ok(KeysetPage {
+            items: collection_items,
+            next: page.next,
+        })
+    }
+
+    async fn find_item_by_number(
+        &self,
+        collection_id: &CollectionId,
+        item_number: u32,
+    ) -> Result<Option<CollectionItem>> {
+        let mut grouper = CollectionItemGrouper::new(self.purchase_info_validation);
+        sqlite::for_each_collection_item_by_number_detail(
+            &self.pool,
+            collection_id,
+            item_number,
+            |detail_row| grouper.push(detail_row),
+        )
+        .await?;
+        let mut collection_items: Vec<CollectionItem> = grouper
+            .finish()?
+            .into_iter()
+            .map(|(_, item)| item)
+            .collect();
+        self.attach_railway_models(&mut collection_items).await?;
+
+        Ok(collection_items.pop())
+    }
+
+    async fn duplicate_collection_item(
+        &self,
+        item_id: &CollectionItemId,
+        options: &DuplicateItemOptions,
+    ) -> Result<CollectionItemId> {
+        let item_id = item_id.clone();
+        let options = *options;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let item = sqlite::get_collection_item(&mut *conn, item_id.clone())
+                    .await?
+                    .ok_or_else(|| anyhow!("collection_item not found id={}", item_id))?;
+                if !options.allow_duplicates {
+                    let collection_item_ids = sqlite::find_collection_items_by_railway_model(
+                        &mut *conn,
+                        &item.collection_id,
+                        &item.railway_model_id,
+                    )
+                    .await?;
+                    if !collection_item_ids.is_empty() {
+                        return Err(DuplicateItemError::AlreadyInCollection {
+                            railway_model_id: item.railway_model_id,
+                            collection_item_ids,
+                        }
+                        .into());
+                    }
+                }
+
+                let duplicate_id = CollectionItemId::from(Uuid::new_v4());
+                let source_id = item_id.to_string();
+                sqlite::copy_collection_item(&mut *conn, &duplicate_id.to_string(), &source_id)
+                    .await?;
+                let mut owned_rolling_stock_ids = Vec::new();
+                if options.clone_rolling_stocks {
+                    for source in
+                        sqlite::get_collection_item_owned_rolling_stock_ids(&mut *conn, &source_id)
+                            .await?
+                    {
+                        let owned_rolling_stock_id = Uuid::new_v4().to_string();
+                        sqlite::copy_owned_rolling_stock(
+                            &mut *conn,
+                            &owned_rolling_stock_id,
+                            &duplicate_id.to_string(),
+                            &source,
+                        )
+                        .await?;
+                        owned_rolling_stock_ids.push(owned_rolling_stock_id);
+                    }
+                }
+
+                let change = AuditedChange::created(
+                    "collection_item",
+                    duplicate_id.to_string(),
+                    &serde_json::json!({
+                        "duplicate_of": source_id,
+                        "owned_rolling_stock_ids": owned_rolling_stock_ids,
+                    }),
+                )?;
+                audit.record(conn, &change).await?;
+                Ok(duplicate_id)
             })
-            .into_group_map();
+        })
+        .await
+    }
+
+    async fn bulk_update_items(
+        &self,
+        filter: &CollectionItemFilter,
+        patch: &CollectionItemPatch,
+        options: &BulkUpdateOptions,
+    ) -> Result<BulkUpdateReport> {
+        let filter = filter.clone();
+        let patch = patch.clone().normalized()?;
+        let options = *options;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                if let Some(LocationUpdate::MoveTo { location_id }) = &patch.location {
+                    sqlite::get_storage_location(&mut *conn, location_id)
+                        .await?
+                        .ok_or_else(|| anyhow!("storage location not found id={}", location_id))?;
+                }
+                let rows = sqlite::find_collection_items_by_filter(&mut *conn, &filter).await?;
+                let changed_ids: Vec<String> = rows
+                    .iter()
+                    .filter(|row| {
+                        patch.changes(row.conditions.as_deref(), row.location_id.as_deref())
+                    })
+                    .map(|row| row.id.clone())
+                    .collect();
+                let report = BulkUpdateReport {
+                    matched: u32::try_from(rows.len()).context("matched items out of range")?,
+                    changed: u32::try_from(changed_ids.len())
+                        .context("changed items out of range")?,
+                    limit: options.effective_limit(),
+                    dry_run: options.dry_run,
+                };
+                options.check_limit(report.changed)?;
+                if options.dry_run {
+                    return Ok(report);
+                }
+
+                for id in changed_ids {
+                    sqlite::patch_collection_item(&mut *conn, &id, &patch).await?;
+                    let change = AuditedChange::updated("collection_item", id, &patch)?;
+                    audit.record(conn, &change).await?;
+                }
+                Ok(report)
+            })
+        })
+        .await
+    }
+
+    async fn add_purchase(
+        &self,
+        item_id: &CollectionItemId,
+        purchase: &PurchasedInfo,
+    ) -> Result<()> {
+        let item_id = item_id.clone();
+        let purchase = purchase.clone();
+        let change = AuditedChange::created(
+            "purchase_info",
+            &purchase.id,
+            &serde_json::json!({
+                "collection_item_id": item_id,
+                "purchase": PurchaseInfo::Purchased(purchase.clone()),
+            }),
+        )?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                sqlite::get_collection_item(&mut *conn, item_id.clone())
+                    .await?
+                    .ok_or_else(|| anyhow!("collection_item not found id={}", item_id))?;
+                if sqlite::has_purchase_info(&mut *conn, &item_id).await? {
+                    return Err(anyhow!(
+                        "collection_item already has a purchase info id={}",
+                        item_id
+                    ));
+                }
+                sqlite::insert_purchased_info(&mut *conn, &item_id, &purchase).await?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
 
-        let purchase_info_rows = sqlite::get_purchase_infos(&self.pool, &collection_id).await?;
-        let purchase_info_map = purchase_info_rows
+    async fn list_stored_item_amounts(
+        &self,
+        collection_id: &CollectionId,
+    ) -> Result<Vec<StoredItemAmounts>> {
+        sqlite::get_stored_item_amounts(&self.pool, collection_id)
+            .await?
             .into_iter()
-            .map(|purchase_info| {
-                (
-                    CollectionItemId::try_from(&purchase_info.collection_item_id).unwrap(),
-                    purchase_info,
+            .map(|row| {
+                let price = MonetaryAmount::from_db(
+                    row.price_amount.unwrap_or(0),
+                    row.price_currency.as_deref(),
+                )
+                .with_context(|| {
+                    format!(
+                        "invalid price of collection_item id={}",
+                        row.collection_item_id
+                    )
+                })?;
+                let replacement_value = MonetaryAmount::from_db(
+                    row.replacement_value_amount.unwrap_or(0),
+                    row.replacement_value_currency.as_deref(),
                 )
+                .with_context(|| {
+                    format!(
+                        "invalid replacement value of collection_item id={}",
+                        row.collection_item_id
+                    )
+                })?;
+                Ok(StoredItemAmounts {
+                    collection_item_id: row.collection_item_id,
+                    price,
+                    replacement_value,
+                })
             })
-            .into_group_map();
+            .collect()
+    }
+
+    async fn recompute_collection(
+        &self,
+        collection_id: &CollectionId,
+    ) -> Result<CollectionRecomputeDiff> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .context("starting recompute transaction")?;
+
+        let collection_row = sqlite::get_collection(&mut *tx, collection_id.clone())
+            .await?
+            .ok_or_else(|| anyhow!("collection not found id={}", collection_id))?;
+        let summary_row = sqlite::compute_collection_summary(&mut *tx, collection_id).await?;
+        let total_rows = sqlite::compute_collection_total_values(&mut *tx, collection_id).await?;
 
-        let mut collection_items = Vec::new();
-        for collection_item_row in collection_item_rows {
-            let item = Self::build_collection_item(
-                collection_item_row,
-                &owned_rolling_stocks_map,
-                &purchase_info_map,
-            )?;
-            collection_items.push(item);
+        let stored_currency = Currency::from_code(&collection_row.total_value_currency)
+            .map_err(|e| anyhow!(e))
+            .context("Failed to parse collection total value currency from DB")?;
+        let stored_amount = u64::try_from(collection_row.total_value_amount)
+            .context("Failed to parse collection total value amount from DB")?;
+
+        let mut new_totals = Vec::with_capacity(total_rows.len());
+        for row in total_rows {
+            let currency = Currency::from_code(&row.currency)
+                .map_err(|e| anyhow!(e))
+                .with_context(|| format!("invalid purchase price currency={}", row.currency))?;
+            let amount = u64::try_from(row.amount)
+                .with_context(|| format!("negative total value for currency={}", row.currency))?;
+            new_totals.push((currency, amount));
         }
 
-        Self::build_collection(collection_row, collection_items)
-    }
-}
+        // A collection stores its total in a single currency: when every priced
+        // item shares one currency the total moves to it, otherwise the stored
+        // currency is kept and only amounts in that currency are summed.
+        let (total_currency, total_amount) = match new_totals.as_slice() {
+            [(currency, amount)] => (*currency, *amount),
+            _ => (
+                stored_currency,
+                new_totals
+                    .iter()
+                    .find(|(currency, _)| *currency == stored_currency)
+                    .map(|(_, amount)| *amount)
+                    .unwrap_or(0),
+            ),
+        };
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::catalog::infrastructure::testing::CatalogTestDb;
-    use crate::collecting::infrastructure::testing::CollectingTestDb;
-    use crate::core::domain::currency::Currency;
-    use pretty_assertions::assert_eq;
+        sqlite::update_collection_totals(
+            &mut *tx,
+            collection_id,
+            &summary_row,
+            i64::try_from(total_amount).context("collection total value overflow")?,
+            total_currency.code(),
+        )
+        .await?;
 
-    #[sqlx::test(migrations = "./migrations")]
-    async fn test_get_collection_empty(pool: SqlitePool) {
-        let repo = SqliteCollectionRepository::new(pool.clone());
-        let collection = repo
-            .get_collection()
+        let diff = CollectionRecomputeDiff {
+            collection_id: collection_id.clone(),
+            old_summary: Self::build_stored_summary(&collection_row),
+            new_summary: Self::build_computed_summary(&summary_row),
+            total_values: Self::build_total_value_diffs(
+                stored_currency,
+                stored_amount,
+                &new_totals,
+            ),
+        };
+        let change = AuditedChange::updated("collection", collection_id.to_string(), &diff)?;
+        self.audit.record(&mut tx, &change).await?;
+
+        tx.commit()
             .await
-            .expect("Failed to get collection");
+            .context("committing recompute transaction")?;
 
-        // As per current implementation logic: "return Ok(Collection { ... })" if not found
-        assert_eq!(collection.name, "My Collection");
-        assert_eq!(collection.items.len(), 0);
+        Ok(diff)
     }
 
-    // TODO: Enable this test after fixing the issues with test data setup
-    #[ignore]
-    #[sqlx::test(migrations = "./migrations")]
-    async fn test_get_collection_with_data(pool: SqlitePool) -> Result<()> {
-        let catalog_db = CatalogTestDb::new(pool.clone());
-        let catalog_test_data = catalog_db.setup_railway_model().await?;
+    async fn check_collection_consistency(
+        &self,
+        collection_id: &CollectionId,
+        repair: bool,
+    ) -> Result<ConsistencyReport> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .context("starting consistency check transaction")?;
 
-        let collecting_db = CollectingTestDb::new(pool.clone());
-        let railway_model_id = &catalog_test_data.railway_model_id;
-        let rolling_stock_ids: Vec<&str> = catalog_test_data
-            .rolling_stock_ids
+        let collection_row = sqlite::get_collection(&mut *tx, collection_id.clone())
+            .await?
+            .ok_or_else(|| anyhow!("collection not found id={}", collection_id))?;
+        let missing_rows =
+            sqlite::find_missing_rolling_stock_references(&mut *tx, collection_id).await?;
+        let summary_row = sqlite::compute_collection_summary(&mut *tx, collection_id).await?;
+        let currency_rows = sqlite::get_purchase_currencies(&mut *tx, collection_id).await?;
+
+        let mut inconsistencies: Vec<Inconsistency> = missing_rows
             .iter()
-            .map(|s| s.as_str()) // or .map(|s| &**s)
+            .map(|row| Inconsistency {
+                kind: InconsistencyKind::MissingRollingStock,
+                subject_id: row.owned_rolling_stock_id.clone(),
+                details: format!(
+                    "item {} refers to the missing rolling stock {}",
+                    row.collection_item_id, row.rolling_stock_id
+                ),
+                repair: RepairAction::ClearRollingStockReference,
+            })
             .collect();
-        let collection_test_data = collecting_db
-            .setup_minimal_collection(railway_model_id, rolling_stock_ids.clone())
-            .await?;
-        let collection_id = collection_test_data.collection_id;
+        inconsistencies.extend(consistency::summary_counter_mismatches(
+            collection_id,
+            &Self::build_stored_summary(&collection_row),
+            &Self::build_computed_summary(&summary_row),
+        ));
+        for row in &currency_rows {
+            let currencies = [
+                ("purchase price", &row.purchased_price_currency),
+                ("sale price", &row.sale_price_currency),
+                ("deposit", &row.deposit_currency),
+                ("pre-order total", &row.preorder_total_currency),
+            ];
+            for (amount, currency) in currencies {
+                if let Some(code) = currency
+                    && Currency::from_code(code).is_err()
+                {
+                    inconsistencies.push(Inconsistency {
+                        kind: InconsistencyKind::UnsupportedCurrency,
+                        subject_id: row.purchase_id.clone(),
+                        details: format!("the {amount} is in the unsupported currency {code:?}"),
+                        repair: RepairAction::ChooseSupportedCurrency,
+                    });
+                }
+            }
+        }
 
-        let repo = SqliteCollectionRepository::new(pool.clone());
-        let collection = repo
-            .get_collection()
+        let mut report = ConsistencyReport {
+            collection_id: collection_id.clone(),
+            inconsistencies,
+            repaired: false,
+        };
+        if !repair || !report.has_safe_repairs() {
+            return Ok(report);
+        }
+
+        let owned_rolling_stock_ids: Vec<String> = missing_rows
+            .into_iter()
+            .map(|row| row.owned_rolling_stock_id)
+            .collect();
+        sqlite::clear_rolling_stock_references(&mut *tx, &owned_rolling_stock_ids).await?;
+        sqlite::update_collection_summary_counters(&mut *tx, collection_id, &summary_row).await?;
+
+        report.repaired = true;
+        let change = AuditedChange::updated("collection", collection_id.to_string(), &report)?;
+        self.audit.record(&mut tx, &change).await?;
+
+        tx.commit()
             .await
-            .expect("Failed to get collection");
+            .context("committing consistency repairs")?;
 
-        assert_eq!(collection.id.to_string(), collection_id);
-        assert_eq!(collection.summary.locomotives_count, 0);
-        assert_eq!(collection.summary.passenger_cars_count, 0);
-        assert_eq!(collection.summary.freight_cars_count, 0);
-        assert_eq!(collection.summary.train_sets_count, 0);
-        assert_eq!(collection.summary.railcars_count, 0);
-        assert_eq!(collection.summary.electric_multiple_units_count, 0);
-        assert!(collection.total_value.is_some());
-        assert_eq!(collection.items.len(), 1);
-        assert_eq!(
-            collection.items[0].railway_model_id,
-            railway_model_id.to_string()
-        );
+        Ok(report)
+    }
 
-        assert_eq!(collection.items[0].rolling_stocks.len(), 1);
-        assert_eq!(
-            collection.items[0].rolling_stocks[0].rolling_stock_id,
-            rolling_stock_ids[0].to_string()
-        );
+    async fn get_collection_statistics(
+        &self,
+        collection_id: &CollectionId,
+    ) -> Result<CollectionStatistics> {
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .context("acquiring a connection for collection statistics")?;
+        sqlite::get_collection(&mut *conn, collection_id.clone())
+            .await?
+            .ok_or_else(|| anyhow!("collection not found id={}", collection_id))?;
+        let total_rows = sqlite::compute_collection_total_values(&mut *conn, collection_id).await?;
+        let gain_rows =
+            sqlite::compute_collection_realized_gains(&mut *conn, collection_id).await?;
+        let discount_rows =
+            sqlite::get_collection_discount_prices(&mut *conn, collection_id).await?;
 
-        assert!(collection.items[0].purchase_info.is_some());
-        let purchase_info = collection.items[0].purchase_info.as_ref().unwrap();
-        match purchase_info {
-            PurchaseInfo::Purchased(purchased_info) => {
-                assert_eq!(purchased_info.id, collection_test_data.purchase_info_id);
-                let price = purchased_info.price.as_ref().expect("price present");
-                assert_eq!(price.amount, 0);
-                assert_eq!(price.currency, Currency::EUR);
-                assert_eq!(purchased_info.seller, None);
-            }
-            other => panic!("Expected purchase info to be Purchased, got: {:?}", other),
-        }
+        let total_values = total_rows
+            .iter()
+            .map(|row| Self::build_currency_value(&row.currency, row.amount))
+            .collect::<Result<Vec<_>>>()?;
+        let realized_gains = gain_rows
+            .iter()
+            .map(|row| Self::build_realized_gain(&row.currency, row.amount))
+            .collect::<Result<Vec<_>>>()?;
+        let discounts = discount_rows
+            .iter()
+            .map(|row| {
+                let price = MonetaryAmount::from_db(row.price_amount, Some(&row.price_currency))
+                    .context("invalid purchase price")?;
+                let msrp = MonetaryAmount::from_db(row.msrp_amount, Some(&row.msrp_currency))
+                    .context("invalid msrp")?;
+                Ok(price
+                    .zip(msrp)
+                    .and_then(|(price, msrp)| discount::discount_percentage(&price, &msrp)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(CollectionStatistics {
+            collection_id: collection_id.clone(),
+            total_values,
+            realized_gains,
+            average_discount: discount::average_discount(discounts.into_iter().flatten()),
+        })
+    }
+
+    async fn get_year_review(&self, collection_id: &CollectionId, year: i32) -> Result<YearReview> {
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .context("acquiring a connection for the year review")?;
+        sqlite::get_collection(&mut *conn, collection_id.clone())
+            .await?
+            .ok_or_else(|| anyhow!("collection not found id={}", collection_id))?;
+        let counts =
+            sqlite::count_year_purchases_and_sales(&mut *conn, collection_id, year).await?;
+        let spending_rows = sqlite::get_year_spending(&mut *conn, collection_id, year).await?;
+        let purchase_rows =
+            sqlite::get_year_biggest_purchases(&mut *conn, collection_id, year).await?;
+        let manufacturer_row =
+            sqlite::get_year_top_manufacturer(&mut *conn, collection_id, year).await?;
+        let proceeds_rows = sqlite::get_year_sale_proceeds(&mut *conn, collection_id, year).await?;
+        let net_rows = sqlite::get_year_net_proceeds(&mut *conn, collection_id, year).await?;
+
+        let mut review = YearReview::empty(collection_id.clone(), year);
+        review.items_bought =
+            u32::try_from(counts.items_bought).context("items bought out of range")?;
+        review.items_sold = u32::try_from(counts.items_sold).context("items sold out of range")?;
+        review.total_spent = spending_rows
+            .iter()
+            .map(|row| Self::build_currency_value(&row.currency, row.amount))
+            .collect::<Result<Vec<_>>>()?;
+        review.biggest_purchases = purchase_rows
+            .into_iter()
+            .map(Self::build_year_purchase)
+            .collect::<Result<Vec<_>>>()?;
+        review.top_manufacturer = manufacturer_row
+            .map(|row| -> Result<ManufacturerPurchases> {
+                Ok(ManufacturerPurchases {
+                    manufacturer_id: row.manufacturer_id,
+                    name: row.name,
+                    items_bought: u32::try_from(row.items_bought)
+                        .context("manufacturer items bought out of range")?,
+                })
+            })
+            .transpose()?;
+        review.sale_proceeds = proceeds_rows
+            .iter()
+            .map(|row| Self::build_currency_value(&row.currency, row.amount))
+            .collect::<Result<Vec<_>>>()?;
+        review.net_proceeds = net_rows
+            .iter()
+            .map(|row| Self::build_realized_gain(&row.currency, row.amount))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(review)
+    }
+
+    async fn get_insurance_report(
+        &self,
+        collection_id: &CollectionId,
+        valuation: ValuationMode,
+    ) -> Result<InsuranceReport> {
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .context("acquiring a connection for the insurance report")?;
+        sqlite::get_collection(&mut *conn, collection_id.clone())
+            .await?
+            .ok_or_else(|| anyhow!("collection not found id={}", collection_id))?;
+        let rows = sqlite::get_insurance_report_rows(&mut *conn, collection_id).await?;
+
+        let lines = rows
+            .into_iter()
+            .map(|row| Self::build_insurance_report_line(row, valuation))
+            .collect::<Result<Vec<_>>>()?;
+        InsuranceReport::new(collection_id.clone(), valuation, lines)
+            .map_err(|e| anyhow!(e))
+            .with_context(|| {
+                format!(
+                    "totalling insurance report of collection id={}",
+                    collection_id
+                )
+            })
+    }
+
+    async fn add_monetary_adjustment(
+        &self,
+        purchase_id: &str,
+        adjustment: &MonetaryAdjustment,
+    ) -> Result<CollectionId> {
+        let row = MonetaryAdjustmentRow {
+            id: adjustment.id.clone(),
+            purchase_id: purchase_id.to_string(),
+            amount: adjustment.amount,
+            currency: adjustment.currency.code().to_string(),
+            adjustment_date: adjustment.date,
+            reason: adjustment.reason.clone(),
+            created_at: None,
+            updated_at: None,
+        };
+        let change = AuditedChange::created(
+            "monetary_adjustment",
+            &adjustment.id,
+            &serde_json::json!({ "purchase_id": purchase_id, "adjustment": adjustment }),
+        )?;
+        let adjustment = adjustment.clone();
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let purchase_row = sqlite::get_purchase_info(&mut *conn, row.purchase_id.clone())
+                    .await?
+                    .ok_or_else(|| anyhow!("purchase_info not found id={}", row.purchase_id))?;
+                let purchase_info = Self::build_purchase_info(&purchase_row)?;
+
+                let mut adjustments =
+                    sqlite::get_monetary_adjustments(&mut *conn, &row.purchase_id)
+                        .await?
+                        .iter()
+                        .map(Self::build_monetary_adjustment)
+                        .collect::<Result<Vec<_>>>()?;
+                adjustments.push(adjustment);
+                purchase_info.acquisition_cost(&adjustments)?;
+
+                sqlite::insert_monetary_adjustment(&mut *conn, &row).await?;
 
+                let item_row = sqlite::get_collection_item(
+                    &mut *conn,
+                    purchase_row.collection_item_id.clone(),
+                )
+                .await?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "collection_item not found id={}",
+                        purchase_row.collection_item_id
+                    )
+                })?;
+                audit.record(conn, &change).await?;
+                Ok(item_row.collection_id)
+            })
+        })
+        .await
+    }
+
+    async fn list_monetary_adjustments(
+        &self,
+        purchase_id: &str,
+    ) -> Result<Vec<MonetaryAdjustment>> {
+        sqlite::get_monetary_adjustments(&self.pool, purchase_id)
+            .await?
+            .iter()
+            .map(Self::build_monetary_adjustment)
+            .collect()
+    }
+
+    async fn take_value_snapshot(
+        &self,
+        collection_id: &CollectionId,
+        date: NaiveDate,
+    ) -> Result<ValueSnapshot> {
+        let collection_id = collection_id.clone();
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let collection_row = sqlite::get_collection(&mut *conn, collection_id.clone())
+                    .await?
+                    .ok_or_else(|| anyhow!("collection not found id={}", collection_id))?;
+                let mut total_rows =
+                    sqlite::compute_collection_total_values(&mut *conn, &collection_id).await?;
+                // Keep a point in the history for collections without priced
+                // items, valued at zero in their stored currency.
+                if total_rows.is_empty() {
+                    total_rows.push(CurrencyTotalRow {
+                        currency: collection_row.total_value_currency,
+                        amount: 0,
+                    });
+                }
+
+                let values = total_rows
+                    .iter()
+                    .map(|row| Self::build_currency_value(&row.currency, row.amount))
+                    .collect::<Result<Vec<_>>>()?;
+                let rows: Vec<ValueSnapshotRow> = total_rows
+                    .into_iter()
+                    .map(|row| ValueSnapshotRow {
+                        collection_id: collection_id.clone(),
+                        snapshot_date: date,
+                        currency: row.currency,
+                        amount: row.amount,
+                    })
+                    .collect();
+                sqlite::replace_value_snapshot(&mut *conn, &collection_id, date, &rows).await?;
+
+                let snapshot = ValueSnapshot {
+                    collection_id,
+                    date,
+                    values,
+                };
+                let change = AuditedChange::created(
+                    "value_snapshot",
+                    snapshot.collection_id.to_string(),
+                    &snapshot,
+                )?;
+                audit.record(conn, &change).await?;
+                Ok(snapshot)
+            })
+        })
+        .await
+    }
+
+    async fn get_value_history(
+        &self,
+        collection_id: &CollectionId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<ValueSnapshot>> {
+        let rows = sqlite::get_value_snapshots(&self.pool, collection_id, from, to).await?;
+
+        // Rows come ordered by date, so each run of equal dates is a snapshot.
+        let mut snapshots: Vec<ValueSnapshot> = Vec::new();
+        for row in rows {
+            let value =
+                Self::build_currency_value(&row.currency, row.amount).with_context(|| {
+                    format!(
+                        "invalid collection_value_snapshots row collection_id={} date={}",
+                        row.collection_id, row.snapshot_date
+                    )
+                })?;
+            match snapshots.last_mut() {
+                Some(snapshot) if snapshot.date == row.snapshot_date => snapshot.values.push(value),
+                _ => snapshots.push(ValueSnapshot {
+                    collection_id: row.collection_id,
+                    date: row.snapshot_date,
+                    values: vec![value],
+                }),
+            }
+        }
+
+        Ok(snapshots)
+    }
+
+    async fn add_modification(
+        &self,
+        owned_rolling_stock_id: &str,
+        modification: &Modification,
+    ) -> Result<()> {
+        let row = Self::to_modification_row(owned_rolling_stock_id, modification)?;
+        let change = AuditedChange::created(
+            "modification",
+            &modification.id,
+            &serde_json::json!({
+                "owned_rolling_stock_id": owned_rolling_stock_id,
+                "modification": modification,
+            }),
+        )?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                sqlite::get_owned_rolling_stock(&mut *conn, row.owned_rolling_stock_id.clone())
+                    .await?
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "owned_rolling_stock not found id={}",
+                            row.owned_rolling_stock_id
+                        )
+                    })?;
+                sqlite::insert_modification(&mut *conn, &row).await?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn update_modification(&self, modification: &Modification) -> Result<()> {
+        // The owned rolling stock a modification belongs to is not updated.
+        let row = Self::to_modification_row("", modification)?;
+        let change = AuditedChange::updated("modification", &modification.id, modification)?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                if !sqlite::update_modification(&mut *conn, &row).await? {
+                    return Err(anyhow!("modification not found id={}", row.id));
+                }
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn delete_modification(&self, modification_id: &str) -> Result<()> {
+        let modification_id = modification_id.to_string();
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                if !sqlite::delete_modification(&mut *conn, &modification_id).await? {
+                    return Err(anyhow!("modification not found id={}", modification_id));
+                }
+                audit
+                    .record(
+                        conn,
+                        &AuditedChange::deleted("modification", modification_id),
+                    )
+                    .await
+            })
+        })
+        .await
+    }
+
+    async fn list_modifications(&self, owned_rolling_stock_id: &str) -> Result<Vec<Modification>> {
+        sqlite::get_modifications(&self.pool, owned_rolling_stock_id)
+            .await?
+            .into_iter()
+            .map(Self::build_modification)
+            .collect()
+    }
+
+    async fn get_item_cost_breakdown(
+        &self,
+        item_id: &CollectionItemId,
+    ) -> Result<ItemCostBreakdown> {
+        let mut conn = self
+            .pool
+            .acquire()
+            .await
+            .context("acquiring a connection for the cost breakdown")?;
+        sqlite::get_collection_item(&mut *conn, item_id.clone())
+            .await?
+            .ok_or_else(|| anyhow!("collection_item not found id={}", item_id))?;
+        let purchase_row = sqlite::get_item_purchase_info(&mut *conn, item_id).await?;
+        let mut inputs = ItemCostInputs::default();
+        if let Some(row) = &purchase_row {
+            inputs.adjustments = sqlite::get_monetary_adjustments(&mut *conn, &row.purchase_id)
+                .await?
+                .iter()
+                .map(Self::build_monetary_adjustment)
+                .collect::<Result<Vec<_>>>()?;
+        }
+        inputs.decoders = sqlite::get_item_decoders(&mut *conn, item_id)
+            .await?
+            .into_iter()
+            .map(SqliteDecoderRepository::build_decoder)
+            .collect::<Result<Vec<_>>>()?;
+        inputs.modifications = sqlite::get_item_modifications(&mut *conn, item_id)
+            .await?
+            .into_iter()
+            .map(Self::build_modification)
+            .collect::<Result<Vec<_>>>()?;
+
+        let purchase_info = purchase_row
+            .as_ref()
+            .map(Self::build_purchase_info)
+            .transpose()?;
+        Ok(ItemCostBreakdown::build(
+            item_id.clone(),
+            purchase_info.as_ref(),
+            &inputs,
+        )?)
+    }
+
+    async fn record_item_view(
+        &self,
+        item_id: &CollectionItemId,
+        viewed_at: DateTime<Utc>,
+    ) -> Result<()> {
+        let item_id = item_id.clone();
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                sqlite::get_collection_item(&mut *conn, item_id.clone())
+                    .await?
+                    .ok_or_else(|| anyhow!("collection_item not found id={}", item_id))?;
+                sqlite::record_item_view(conn, &item_id, viewed_at, MAX_VIEWS_PER_ITEM).await
+            })
+        })
+        .await
+    }
+
+    async fn get_recent_activity(&self, limit: u32) -> Result<Vec<ActivityEvent>> {
+        let rows = sqlite::get_recent_activity(&self.pool, limit).await?;
+        let railway_model_ids: Vec<RailwayModelId> =
+            rows.iter().map(|row| row.railway_model_id).collect();
+        let railway_models = self
+            .catalog_cache
+            .get_many(&self.pool, &railway_model_ids)
+            .await?;
+
+        rows.iter()
+            .map(|row| {
+                Ok(ActivityEvent {
+                    kind: ActivityKind::from_str(&row.kind)
+                        .with_context(|| format!("invalid activity kind {}", row.kind))?,
+                    collection_item_id: row.collection_item_id.clone(),
+                    railway_model: railway_models.get(&row.railway_model_id).cloned(),
+                    occurred_at: row.occurred_at,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::domain::bulk_update::BulkUpdateError;
+    use crate::collecting::domain::monetary_adjustment::AdjustmentError;
+    use crate::collecting::domain::value_policy::CollectionValuePolicy;
+    use crate::collecting::infrastructure::testing::{CollectingTestData, CollectingTestDb};
+    use crate::core::domain::currency::Currency;
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_collection_empty(pool: SqlitePool) {
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection = repo
+            .get_collection()
+            .await
+            .expect("Failed to get collection");
+
+        // As per current implementation logic: "return Ok(Collection { ... })" if not found
+        assert_eq!(collection.name, "My Collection");
+        assert_eq!(collection.items.len(), 0);
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_collection_with_data(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let railway_model_id = &catalog_test_data.railway_model_id;
+        let rolling_stock_ids: Vec<&str> = catalog_test_data
+            .rolling_stock_ids
+            .iter()
+            .map(|s| s.as_str()) // or .map(|s| &**s)
+            .collect();
+        let collection_test_data = collecting_db
+            .setup_minimal_collection(railway_model_id, rolling_stock_ids.clone())
+            .await?;
+        let collection_id = collection_test_data.collection_id;
+
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection = repo
+            .get_collection()
+            .await
+            .expect("Failed to get collection");
+
+        assert_eq!(collection.id.to_string(), collection_id);
+        assert_eq!(collection.summary.locomotives_count, 0);
+        assert_eq!(collection.summary.passenger_cars_count, 0);
+        assert_eq!(collection.summary.freight_cars_count, 0);
+        assert_eq!(collection.summary.train_sets_count, 0);
+        assert_eq!(collection.summary.railcars_count, 0);
+        assert_eq!(collection.summary.electric_multiple_units_count, 0);
+        assert!(collection.total_value.is_some());
+        assert_eq!(collection.items.len(), 1);
+        assert_eq!(&collection.items[0].railway_model_id, railway_model_id);
+
+        assert_eq!(collection.items[0].rolling_stocks.len(), 1);
+        assert_eq!(
+            collection.items[0].rolling_stocks[0].rolling_stock_id,
+            Some(rolling_stock_ids[0].to_string())
+        );
+
+        assert!(collection.items[0].purchase_info.is_some());
+        let purchase_info = collection.items[0].purchase_info.as_ref().unwrap();
+        match purchase_info {
+            PurchaseInfo::Purchased(purchased_info) => {
+                assert_eq!(purchased_info.id, collection_test_data.purchase_info_id);
+                let price = purchased_info.price.as_ref().expect("price present");
+                assert_eq!(price.amount, 0);
+                assert_eq!(price.currency, Currency::EUR);
+                assert_eq!(purchased_info.seller, None);
+            }
+            other => panic!("Expected purchase info to be Purchased, got: {:?}", other),
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_collection_looks_railway_models_up_in_the_catalog_cache(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        collecting_db
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, vec![])
+            .await?;
+        let cache = RailwayModelCache::default();
+        let repo = SqliteCollectionRepository::new(pool.clone()).with_catalog_cache(cache.clone());
+
+        let collection = repo.get_collection().await?;
+        let railway_model = collection.items[0]
+            .railway_model
+            .as_ref()
+            .expect("railway model summary");
+        assert_eq!(railway_model.manufacturer, "ACME");
+        assert_eq!(
+            railway_model.description,
+            "FS Class E656 electric locomotive"
+        );
+
+        repo.get_collection().await?;
+        let metrics = cache.metrics();
+        assert_eq!(metrics.misses, 1);
+        assert_eq!(metrics.hits, 1);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_collection_exposes_timestamps_and_tolerates_legacy_nulls(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        collecting_db
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, vec![])
+            .await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+
+        let collection = repo.get_collection().await?;
+        assert!(collection.items[0].created_at.is_some());
+        assert!(collection.items[0].updated_at.is_some());
+
+        // Rows written before timestamps were recorded have NULLs.
+        sqlx::query("UPDATE collection_items SET created_at = NULL, updated_at = NULL")
+            .execute(&pool)
+            .await?;
+        sqlx::query("UPDATE purchase_infos SET created_at = NULL, updated_at = NULL")
+            .execute(&pool)
+            .await?;
+
+        let collection = repo.get_collection().await?;
+        assert_eq!(collection.items[0].created_at, None);
+        assert_eq!(collection.items[0].updated_at, None);
+        assert!(collection.items[0].purchase_info.is_some());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_concurrent_inserts_get_sequential_item_numbers(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+
+        let inserts: Vec<_> = (0..10)
+            .map(|_| {
+                let pool = pool.clone();
+                let collection_id = collection_id.clone();
+                let railway_model_id = catalog_test_data.railway_model_id;
+                tokio::spawn(async move {
+                    let id = uuid::Uuid::new_v4().to_string();
+                    sqlite::insert_collection_item(&pool, &id, &collection_id, &railway_model_id)
+                        .await
+                })
+            })
+            .collect();
+        for insert in inserts {
+            insert.await??;
+        }
+
+        let numbers = |pool: SqlitePool| async move {
+            sqlx::query_scalar::<_, i64>(
+                "SELECT item_number FROM collection_items ORDER BY item_number",
+            )
+            .fetch_all(&pool)
+            .await
+        };
+        assert_eq!(numbers(pool.clone()).await?, (1..=10).collect::<Vec<_>>());
+
+        // A deleted item's number is not handed out again.
+        sqlx::query("DELETE FROM collection_items WHERE item_number = 10")
+            .execute(&pool)
+            .await?;
+        collecting_db
+            .insert_collection_item(&collection_id, &catalog_test_data.railway_model_id)
+            .await?;
+        assert_eq!(
+            numbers(pool.clone()).await?,
+            (1..=9).chain([11]).collect::<Vec<_>>()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_find_item_by_number(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collecting_test_data = collecting_db
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, vec![])
+            .await?;
+        let second_id = collecting_db
+            .insert_collection_item(
+                &collecting_test_data.collection_id,
+                &catalog_test_data.railway_model_id,
+            )
+            .await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection_id = CollectionId::try_from(collecting_test_data.collection_id.as_str())?;
+
+        let item = repo
+            .find_item_by_number(&collection_id, 2)
+            .await?
+            .expect("item number 2");
+        assert_eq!(item.id.to_string(), second_id);
+        assert_eq!(item.item_number, 2);
+        assert_eq!(
+            repo.get_collection()
+                .await?
+                .items
+                .iter()
+                .map(|item| item.item_number)
+                .collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert!(repo.find_item_by_number(&collection_id, 3).await?.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_collection_reports_malformed_collection_item_id(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_test_data = catalog_db.setup_railway_model().await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+
+        // A child row pointing at an item whose id is not a UUID, as a
+        // manual edit could leave behind.
+        sqlx::query(
+            "INSERT INTO collection_items (id, collection_id, railway_model_id) VALUES ('not-a-uuid', ?1, ?2)",
+        )
+        .bind(&collection_id)
+        .bind(catalog_test_data.railway_model_id)
+        .execute(&pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO owned_rolling_stocks (id, collection_item_id) VALUES ('ors-1', 'not-a-uuid')",
+        )
+        .execute(&pool)
+        .await?;
+
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let err = repo.get_collection().await.unwrap_err();
+
+        // The id fails to decode while the detail rows are read.
+        let message = format!("{err:#}");
+        assert!(message.contains("collection details"), "{message}");
+        assert!(message.contains("decoding column"), "{message}");
+        assert!(message.contains("not-a-uuid"), "{message}");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_collection_keeps_unresolved_rolling_stock_references_empty(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let rolling_stock_id = catalog_test_data.rolling_stock_ids[0].as_str();
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let data = collecting_db
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, vec![rolling_stock_id])
+            .await?;
+        // One owned instance without a catalog reference...
+        let unlinked_id = collecting_db
+            .insert_owned_rolling_stock(&data.collection_item_id, rolling_stock_id)
+            .await?;
+        sqlx::query("UPDATE owned_rolling_stocks SET rolling_stock_id = NULL WHERE id = ?1")
+            .bind(&unlinked_id)
+            .execute(&pool)
+            .await?;
+        // ...and one whose catalog rolling stock was removed behind its back.
+        let mut conn = pool.acquire().await?;
+        sqlx::query("PRAGMA foreign_keys = OFF")
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("DELETE FROM rolling_stocks")
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("PRAGMA foreign_keys = ON")
+            .execute(&mut *conn)
+            .await?;
+        drop(conn);
+
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection = repo.get_collection().await?;
+
+        let rolling_stocks = &collection.items[0].rolling_stocks;
+        assert_eq!(rolling_stocks.len(), 2);
+        assert!(
+            rolling_stocks
+                .iter()
+                .all(|rs| rs.rolling_stock_id.is_none())
+        );
+        assert!(rolling_stocks.iter().any(|rs| rs.id == unlinked_id));
+        let json = serde_json::to_value(rolling_stocks)?;
+        assert!(json[0]["rolling_stock_id"].is_null());
+
+        let collection_id = CollectionId::try_from(data.collection_id.as_str())?;
+        repo.recompute_collection(&collection_id).await?;
+
+        Ok(())
+    }
+
+    /// The invalid purchase info edits covered by the validation tests, with
+    /// the text the resulting error must mention.
+    const INVALID_PURCHASE_INFOS: [(&str, &str); 3] = [
+        (
+            "UPDATE purchase_infos SET purchase_type = 'gift'",
+            "\"gift\"",
+        ),
+        (
+            "UPDATE purchase_infos SET purchased_price_currency = 'XYZ'",
+            "XYZ",
+        ),
+        (
+            "UPDATE purchase_infos SET purchase_date = 'last spring'",
+            "last spring",
+        ),
+    ];
+
+    /// Create a collection with one item and a valid purchase info, then
+    /// apply `edit` to the purchase info row. Returns its id.
+    ///
+    /// Data left by a previous call is removed first.
+    async fn setup_edited_purchase_info(pool: &SqlitePool, edit: &str) -> Result<String> {
+        for table in ["collections", "manufacturers", "railway_companies"] {
+            sqlx::query(&format!("DELETE FROM {table}"))
+                .execute(pool)
+                .await?;
+        }
+        let catalog_test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let data = CollectingTestDb::new(pool.clone())
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, vec![])
+            .await?;
+        sqlx::query(edit).execute(pool).await?;
+        Ok(data.purchase_info_id)
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_collection_lenient_surfaces_invalid_purchase_info(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        for (invalid_edit, expected) in INVALID_PURCHASE_INFOS {
+            let purchase_id = setup_edited_purchase_info(&pool, invalid_edit).await?;
+
+            let repo = SqliteCollectionRepository::new(pool.clone());
+            let collection = repo.get_collection().await?;
+
+            let item = &collection.items[0];
+            assert!(item.purchase_info.is_none());
+            let error = item.purchase_info_error.as_deref().expect("error surfaced");
+            assert!(error.contains(&purchase_id), "{error}");
+            assert!(error.contains(expected), "{error}");
+            assert_eq!(
+                collection.load_warnings,
+                vec![format!("collection item {}: {error}", item.id)]
+            );
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_collection_reads_legacy_purchase_dates(pool: SqlitePool) -> Result<()> {
+        let purchase_id = setup_edited_purchase_info(
+            &pool,
+            "UPDATE purchase_infos SET purchase_date = '01/03/2024'",
+        )
+        .await?;
+
+        let repo = SqliteCollectionRepository::new(pool.clone())
+            .with_purchase_info_validation(PurchaseInfoValidation::Strict);
+        let collection = repo.get_collection().await?;
+
+        match collection.items[0].purchase_info.as_ref() {
+            Some(PurchaseInfo::Purchased(info)) => {
+                assert_eq!(info.id, purchase_id);
+                assert_eq!(
+                    info.purchase_date,
+                    NaiveDate::from_ymd_opt(2024, 3, 1).unwrap()
+                );
+            }
+            other => panic!("unexpected purchase info {other:?}"),
+        }
+        assert!(collection.load_warnings.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_collection_strict_fails_on_invalid_purchase_info(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        for (invalid_edit, expected) in INVALID_PURCHASE_INFOS {
+            let purchase_id = setup_edited_purchase_info(&pool, invalid_edit).await?;
+
+            let repo = SqliteCollectionRepository::new(pool.clone())
+                .with_purchase_info_validation(PurchaseInfoValidation::Strict);
+            let err = repo.get_collection().await.unwrap_err();
+
+            let message = format!("{err:#}");
+            assert!(message.contains(&purchase_id), "{message}");
+            assert!(message.contains(expected), "{message}");
+        }
+
+        Ok(())
+    }
+
+    /// Insert an item of a new `category` model with a purchased price,
+    /// returning its purchase id.
+    async fn insert_priced_item(
+        pool: &SqlitePool,
+        catalog_db: &CatalogTestDb,
+        collecting_db: &CollectingTestDb,
+        collection_id: &str,
+        manufacturer_id: &str,
+        category: &str,
+        price: (i64, &str),
+    ) -> Result<String> {
+        let railway_model_id = catalog_db
+            .insert_railway_model(
+                &RailwayModelId::new(),
+                manufacturer_id,
+                &format!("P-{}", category),
+                "test model",
+                "electric",
+                "H0",
+                "IV",
+                category,
+            )
+            .await?;
+        let collection_item_id = collecting_db
+            .insert_collection_item(collection_id, &railway_model_id)
+            .await?;
+        let purchase_id = collecting_db
+            .insert_purchase_info(&collection_item_id)
+            .await?;
+        sqlx::query("UPDATE purchase_infos SET purchased_price_amount = ?1, purchased_price_currency = ?2 WHERE purchase_id = ?3")
+            .bind(price.0)
+            .bind(price.1)
+            .bind(&purchase_id)
+            .execute(pool)
+            .await?;
+        Ok(purchase_id)
+    }
+
+    fn refund(amount: i64, currency: Currency) -> MonetaryAdjustment {
+        MonetaryAdjustment {
+            id: uuid::Uuid::new_v4().to_string(),
+            amount,
+            currency,
+            date: chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            reason: "partial refund".to_string(),
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_partial_refund_reduces_the_collection_total(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let manufacturer_id = uuid::Uuid::new_v4().to_string();
+        catalog_db
+            .insert_manufacturer(&manufacturer_id, "ACME")
+            .await?;
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let purchase_id = insert_priced_item(
+            &pool,
+            &catalog_db,
+            &collecting_db,
+            &collection_id,
+            &manufacturer_id,
+            "LOCOMOTIVES",
+            (2500, "EUR"),
+        )
+        .await?;
+        insert_priced_item(
+            &pool,
+            &catalog_db,
+            &collecting_db,
+            &collection_id,
+            &manufacturer_id,
+            "FREIGHT_CARS",
+            (1000, "EUR"),
+        )
+        .await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+
+        let adjusted_collection_id = repo
+            .add_monetary_adjustment(&purchase_id, &refund(-700, Currency::EUR))
+            .await?;
+        let diff = repo.recompute_collection(&adjusted_collection_id).await?;
+
+        assert_eq!(adjusted_collection_id.to_string(), collection_id);
+        assert_eq!(
+            diff.total_values,
+            vec![TotalValueDiff {
+                currency: Currency::EUR,
+                old_amount: 0,
+                new_amount: 2500 - 700 + 1000,
+            }]
+        );
+        let adjustments = repo.list_monetary_adjustments(&purchase_id).await?;
+        assert_eq!(adjustments.len(), 1);
+        assert_eq!(adjustments[0].amount, -700);
+        assert_eq!(adjustments[0].reason, "partial refund");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_sold_items_move_from_the_total_to_realized_gains(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let manufacturer_id = uuid::Uuid::new_v4().to_string();
+        catalog_db
+            .insert_manufacturer(&manufacturer_id, "ACME")
+            .await?;
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let sold_id = insert_priced_item(
+            &pool,
+            &catalog_db,
+            &collecting_db,
+            &collection_id,
+            &manufacturer_id,
+            "LOCOMOTIVES",
+            (2500, "EUR"),
+        )
+        .await?;
+        insert_priced_item(
+            &pool,
+            &catalog_db,
+            &collecting_db,
+            &collection_id,
+            &manufacturer_id,
+            "FREIGHT_CARS",
+            (1000, "EUR"),
+        )
+        .await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection_id = CollectionId::try_from(collection_id.as_str())?;
+        repo.recompute_collection(&collection_id).await?;
+
+        repo.add_monetary_adjustment(&sold_id, &refund(-500, Currency::EUR))
+            .await?;
+        sqlx::query(
+            "UPDATE purchase_infos SET purchase_type = 'sold', sale_date = '2024-06-01', sale_price_amount = 3000, sale_price_currency = 'EUR' WHERE purchase_id = ?1",
+        )
+        .bind(&sold_id)
+        .execute(&pool)
+        .await?;
+        let diff = repo.recompute_collection(&collection_id).await?;
+        let statistics = repo.get_collection_statistics(&collection_id).await?;
+
+        assert_eq!(
+            diff.total_values,
+            vec![TotalValueDiff {
+                currency: Currency::EUR,
+                old_amount: 3500,
+                new_amount: 1000,
+            }]
+        );
+        assert_eq!(
+            statistics,
+            CollectionStatistics {
+                collection_id: collection_id.clone(),
+                total_values: vec![CurrencyValue {
+                    currency: Currency::EUR,
+                    amount: 1000,
+                }],
+                realized_gains: vec![RealizedGain {
+                    currency: Currency::EUR,
+                    amount: 3000 - (2500 - 500),
+                }],
+                average_discount: None,
+            }
+        );
+
+        // The statistics queries agree with the policy applied item by item.
+        let policy = CollectionValuePolicy;
+        let mut item_values = 0;
+        let mut item_gains = 0;
+        for item in repo.get_collection().await?.items {
+            let purchase_info = item.purchase_info.expect("purchase info");
+            let adjustments = repo.list_monetary_adjustments(purchase_info.id()).await?;
+            if let Some(value) = policy.item_value(&purchase_info, &adjustments)? {
+                item_values += value.amount;
+            }
+            if let Some(gain) = policy.realized_gain(&purchase_info, &adjustments)? {
+                item_gains += gain.amount;
+            }
+        }
+        assert_eq!(item_values, statistics.total_values[0].amount);
+        assert_eq!(item_gains, statistics.realized_gains[0].amount);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_discounts_need_an_msrp_in_the_purchase_currency(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let manufacturer_id = uuid::Uuid::new_v4().to_string();
+        catalog_db
+            .insert_manufacturer(&manufacturer_id, "ACME")
+            .await?;
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let mut purchase_ids = Vec::new();
+        for (category, price, msrp) in [
+            ("LOCOMOTIVES", 2000, Some((2500, "EUR"))),
+            ("FREIGHT_CARS", 2700, Some((3000, "EUR"))),
+            ("PASSENGER_CARS", 1000, Some((1200, "USD"))),
+            ("TRAIN_SETS", 1000, None),
+        ] {
+            let purchase_id = insert_priced_item(
+                &pool,
+                &catalog_db,
+                &collecting_db,
+                &collection_id,
+                &manufacturer_id,
+                category,
+                (price, "EUR"),
+            )
+            .await?;
+            sqlx::query(
+                "UPDATE railway_models SET msrp_amount = ?1, msrp_currency = ?2
+                WHERE id = (SELECT ci.railway_model_id FROM collection_items ci JOIN purchase_infos pi ON pi.collection_item_id = ci.id WHERE pi.purchase_id = ?3)",
+            )
+            .bind(msrp.map(|(amount, _)| amount))
+            .bind(msrp.map(|(_, currency)| currency))
+            .bind(&purchase_id)
+            .execute(&pool)
+            .await?;
+            purchase_ids.push(purchase_id);
+        }
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection_id = CollectionId::try_from(collection_id.as_str())?;
+
+        let items = repo.get_collection().await?.items;
+        let discounts: Vec<Option<Decimal>> = purchase_ids
+            .iter()
+            .map(|purchase_id| {
+                items
+                    .iter()
+                    .find(|item| item.purchase_info.as_ref().unwrap().id() == purchase_id)
+                    .unwrap()
+                    .discount_percentage
+            })
+            .collect();
+        assert_eq!(discounts, vec![Some(dec!(20)), Some(dec!(10)), None, None]);
+        let statistics = repo.get_collection_statistics(&collection_id).await?;
+        assert_eq!(statistics.average_discount, Some(dec!(15)));
+
+        Ok(())
+    }
+
+    /// Insert an item for a new railway model with `product_code`, and set
+    /// its purchase info with `set`, an `UPDATE purchase_infos ... SET`
+    /// clause. Returns the collection item and purchase ids.
+    async fn insert_reviewed_item(
+        pool: &SqlitePool,
+        catalog_db: &CatalogTestDb,
+        collecting_db: &CollectingTestDb,
+        collection_id: &str,
+        manufacturer_id: &str,
+        product_code: &str,
+        set: &str,
+    ) -> Result<(String, String)> {
+        let railway_model_id = catalog_db
+            .insert_railway_model(
+                &RailwayModelId::new(),
+                manufacturer_id,
+                product_code,
+                "test model",
+                "electric",
+                "H0",
+                "IV",
+                "LOCOMOTIVES",
+            )
+            .await?;
+        let collection_item_id = collecting_db
+            .insert_collection_item(collection_id, &railway_model_id)
+            .await?;
+        let purchase_id = collecting_db
+            .insert_purchase_info(&collection_item_id)
+            .await?;
+        sqlx::query(&format!(
+            "UPDATE purchase_infos SET {} WHERE purchase_id = ?1",
+            set
+        ))
+        .bind(&purchase_id)
+        .execute(pool)
+        .await?;
+        Ok((collection_item_id, purchase_id))
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_year_review_figures_follow_purchase_and_sale_dates(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let acme_id = uuid::Uuid::new_v4().to_string();
+        let roco_id = uuid::Uuid::new_v4().to_string();
+        catalog_db.insert_manufacturer(&acme_id, "ACME").await?;
+        catalog_db.insert_manufacturer(&roco_id, "Roco").await?;
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let insert = async |manufacturer_id: &str, product_code: &str, set: &str| {
+            insert_reviewed_item(
+                &pool,
+                &catalog_db,
+                &collecting_db,
+                &collection_id,
+                manufacturer_id,
+                product_code,
+                set,
+            )
+            .await
+        };
+        // 2023: bought, then sold at a loss in 2025.
+        insert(
+            &roco_id,
+            "R-2023",
+            "purchase_date = '2023-11-20', purchased_price_amount = 800, purchased_price_currency = 'USD', purchase_type = 'sold', sale_date = '2025-04-01', sale_price_amount = 500, sale_price_currency = 'USD'",
+        )
+        .await?;
+        // 2024: two ACME items and a Roco one, sold in 2025.
+        let (acme_2024_id, acme_2024_purchase_id) = insert(
+            &acme_id,
+            "A-2024",
+            "purchase_date = '2024-03-10', purchased_price_amount = 2500, purchased_price_currency = 'EUR'",
+        )
+        .await?;
+        insert(
+            &roco_id,
+            "R-2024",
+            "purchase_date = '2024-05-01', purchased_price_amount = 1000, purchased_price_currency = 'EUR', purchase_type = 'sold', sale_date = '2025-02-01', sale_price_amount = 1500, sale_price_currency = 'EUR'",
+        )
+        .await?;
+        let (acme_gbp_id, _) = insert(
+            &acme_id,
+            "A-2024-GBP",
+            "purchase_date = '2024-12-31', purchased_price_amount = 4000, purchased_price_currency = 'GBP'",
+        )
+        .await?;
+        // 2025: two Roco items, one with an unknown price, and an ACME
+        // preorder that is not bought yet.
+        let (roco_2025_id, _) = insert(
+            &roco_id,
+            "R-2025",
+            "purchase_date = '2025-01-01', purchased_price_amount = 3000, purchased_price_currency = 'EUR'",
+        )
+        .await?;
+        insert(
+            &roco_id,
+            "R-2025-UNPRICED",
+            "purchase_date = '2025-06-01', purchased_price_amount = NULL, purchased_price_currency = NULL",
+        )
+        .await?;
+        insert(
+            &acme_id,
+            "A-2025-PREORDER",
+            "purchase_date = '2025-03-01', purchase_type = 'preorder', purchased_price_amount = NULL, purchased_price_currency = NULL, deposit_amount = 1000, deposit_currency = 'EUR', preorder_total_amount = 9000, preorder_total_currency = 'EUR'",
+        )
+        .await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        repo.add_monetary_adjustment(&acme_2024_purchase_id, &refund(-500, Currency::EUR))
+            .await?;
+        let collection_id = CollectionId::try_from(collection_id.as_str())?;
+
+        let review_2024 = repo.get_year_review(&collection_id, 2024).await?;
+        let review_2025 = repo.get_year_review(&collection_id, 2025).await?;
+        let review_2026 = repo.get_year_review(&collection_id, 2026).await?;
+
+        assert_eq!(
+            review_2024,
+            YearReview {
+                items_bought: 3,
+                total_spent: vec![
+                    CurrencyValue {
+                        currency: Currency::EUR,
+                        amount: 2500 - 500 + 1000,
+                    },
+                    CurrencyValue {
+                        currency: Currency::GBP,
+                        amount: 4000,
+                    },
+                ],
+                biggest_purchases: vec![
+                    YearPurchase {
+                        collection_item_id: CollectionItemId::try_from(acme_2024_id.as_str())?,
+                        manufacturer: "ACME".to_string(),
+                        product_code: "A-2024".to_string(),
+                        price: MonetaryAmount::new(2000, Currency::EUR),
+                    },
+                    YearPurchase {
+                        collection_item_id: CollectionItemId::try_from(acme_gbp_id.as_str())?,
+                        manufacturer: "ACME".to_string(),
+                        product_code: "A-2024-GBP".to_string(),
+                        price: MonetaryAmount::new(4000, Currency::GBP),
+                    },
+                ],
+                top_manufacturer: Some(ManufacturerPurchases {
+                    manufacturer_id: acme_id.clone(),
+                    name: "ACME".to_string(),
+                    items_bought: 2,
+                }),
+                ..YearReview::empty(collection_id.clone(), 2024)
+            }
+        );
+        assert_eq!(
+            review_2025,
+            YearReview {
+                items_bought: 2,
+                total_spent: vec![CurrencyValue {
+                    currency: Currency::EUR,
+                    amount: 3000,
+                }],
+                biggest_purchases: vec![YearPurchase {
+                    collection_item_id: CollectionItemId::try_from(roco_2025_id.as_str())?,
+                    manufacturer: "Roco".to_string(),
+                    product_code: "R-2025".to_string(),
+                    price: MonetaryAmount::new(3000, Currency::EUR),
+                }],
+                top_manufacturer: Some(ManufacturerPurchases {
+                    manufacturer_id: roco_id.clone(),
+                    name: "Roco".to_string(),
+                    items_bought: 2,
+                }),
+                items_sold: 2,
+                sale_proceeds: vec![
+                    CurrencyValue {
+                        currency: Currency::EUR,
+                        amount: 1500,
+                    },
+                    CurrencyValue {
+                        currency: Currency::USD,
+                        amount: 500,
+                    },
+                ],
+                net_proceeds: vec![
+                    RealizedGain {
+                        currency: Currency::EUR,
+                        amount: 500,
+                    },
+                    RealizedGain {
+                        currency: Currency::USD,
+                        amount: -300,
+                    },
+                ],
+                ..YearReview::empty(collection_id.clone(), 2025)
+            }
+        );
+        assert_eq!(review_2026, YearReview::empty(collection_id, 2026));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_year_review_of_a_missing_collection_fails(pool: SqlitePool) {
+        let repo = SqliteCollectionRepository::new(pool);
+        let collection_id = CollectionId::from(uuid::Uuid::new_v4());
+
+        let result = repo.get_year_review(&collection_id, 2025).await;
+
+        assert!(result.is_err());
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_insurance_report_totals_the_items_not_sold(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let manufacturer_id = uuid::Uuid::new_v4().to_string();
+        catalog_db
+            .insert_manufacturer(&manufacturer_id, "ACME")
+            .await?;
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let mut purchase_ids = Vec::new();
+        for (category, price) in [
+            ("ELECTRIC_MULTIPLE_UNITS", (2500, "EUR")),
+            ("FREIGHT_CARS", (1000, "EUR")),
+            ("LOCOMOTIVES", (4000, "EUR")),
+            ("PASSENGER_CARS", (3000, "USD")),
+        ] {
+            purchase_ids.push(
+                insert_priced_item(
+                    &pool,
+                    &catalog_db,
+                    &collecting_db,
+                    &collection_id,
+                    &manufacturer_id,
+                    category,
+                    price,
+                )
+                .await?,
+            );
+        }
+        sqlx::query(
+            "UPDATE purchase_infos SET purchase_type = 'sold', sale_date = '2024-06-01', sale_price_amount = 3000, sale_price_currency = 'EUR' WHERE purchase_id = ?1",
+        )
+        .bind(&purchase_ids[0])
+        .execute(&pool)
+        .await?;
+
+        // The replacement value is entered on the item update path.
+        let item_id = sqlite::get_purchase_info(&pool, purchase_ids[2].clone())
+            .await?
+            .unwrap()
+            .collection_item_id;
+        let mut item = sqlite::get_collection_item(&pool, item_id.clone())
+            .await?
+            .unwrap();
+        item.replacement_value_amount = Some(6000);
+        item.replacement_value_currency = Some("EUR".to_string());
+        sqlite::update_collection_item(&pool, &item).await?;
+
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection_id = CollectionId::try_from(collection_id.as_str())?;
+        let totals = |report: &InsuranceReport| {
+            report
+                .totals
+                .iter()
+                .map(|t| (t.currency, t.amount))
+                .collect::<Vec<_>>()
+        };
+
+        let report = repo
+            .get_insurance_report(&collection_id, ValuationMode::PurchasePrice)
+            .await?;
+        let product_codes: Vec<&str> = report.lines.iter().map(|l| &*l.product_code).collect();
+        assert_eq!(
+            product_codes,
+            vec!["P-FREIGHT_CARS", "P-LOCOMOTIVES", "P-PASSENGER_CARS"]
+        );
+        assert_eq!(
+            totals(&report),
+            vec![(Currency::EUR, 5000), (Currency::USD, 3000)]
+        );
+
+        let report = repo
+            .get_insurance_report(&collection_id, ValuationMode::ReplacementValue)
+            .await?;
+        assert_eq!(
+            totals(&report),
+            vec![(Currency::EUR, 7000), (Currency::USD, 3000)]
+        );
+        let line = &report.lines[1];
+        assert_eq!(line.collection_item_id, item_id);
+        assert_eq!(line.purchase_price.as_ref().map(|p| p.amount), Some(4000));
+        assert_eq!(line.insured_value.as_ref().map(|v| v.amount), Some(6000));
+        let collection = repo.get_collection().await?;
+        let item = collection.items.iter().find(|i| i.id == item_id).unwrap();
+        assert_eq!(
+            item.replacement_value.as_ref().map(|v| v.amount),
+            Some(6000)
+        );
+
+        assert!(
+            repo.get_insurance_report(&CollectionId::default(), ValuationMode::PurchasePrice)
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_insurance_report_lists_road_numbers(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let catalog_data = catalog_db.setup_railway_model().await?;
+        catalog_db
+            .insert_rolling_stock(
+                "rs-2",
+                &catalog_data.railway_model_id,
+                "LOCOMOTIVE",
+                &catalog_data.railway_company_id,
+                1,
+            )
+            .await?;
+        sqlx::query("UPDATE rolling_stocks SET road_number = 'E.656.001' WHERE id = ?1")
+            .bind(&catalog_data.rolling_stock_ids[0])
+            .execute(&pool)
+            .await?;
+        sqlx::query("UPDATE rolling_stocks SET road_number = 'E.656.002' WHERE id = 'rs-2'")
+            .execute(&pool)
+            .await?;
+        let mut rolling_stock_ids: Vec<&str> = catalog_data
+            .rolling_stock_ids
+            .iter()
+            .map(String::as_str)
+            .collect();
+        rolling_stock_ids.push("rs-2");
+        let data = CollectingTestDb::new(pool.clone())
+            .setup_minimal_collection(&catalog_data.railway_model_id, rolling_stock_ids)
+            .await?;
+
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let report = repo
+            .get_insurance_report(
+                &CollectionId::try_from(data.collection_id.as_str())?,
+                ValuationMode::ReplacementValue,
+            )
+            .await?;
+
+        assert_eq!(report.lines.len(), 1);
+        assert_eq!(report.lines[0].road_numbers, vec!["E.656.001", "E.656.002"]);
+        assert!(report.lines[0].replacement_value.is_none());
+
+        Ok(())
+    }
+
+    fn day(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_value_snapshot_is_taken_once_per_day(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let manufacturer_id = uuid::Uuid::new_v4().to_string();
+        catalog_db
+            .insert_manufacturer(&manufacturer_id, "ACME")
+            .await?;
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection_id = CollectionId::try_from(collection_id.as_str())?;
+
+        let empty = repo.take_value_snapshot(&collection_id, day(1)).await?;
+        assert_eq!(
+            empty.values,
+            vec![CurrencyValue {
+                currency: Currency::EUR,
+                amount: 0,
+            }]
+        );
+
+        insert_priced_item(
+            &pool,
+            &catalog_db,
+            &collecting_db,
+            &collection_id.to_string(),
+            &manufacturer_id,
+            "LOCOMOTIVES",
+            (2500, "EUR"),
+        )
+        .await?;
+        insert_priced_item(
+            &pool,
+            &catalog_db,
+            &collecting_db,
+            &collection_id.to_string(),
+            &manufacturer_id,
+            "PASSENGER_CARS",
+            (1500, "USD"),
+        )
+        .await?;
+        let snapshot = repo.take_value_snapshot(&collection_id, day(1)).await?;
+
+        // The second snapshot of the day replaces the first one.
+        let history = repo
+            .get_value_history(&collection_id, day(1), day(1))
+            .await?;
+        assert_eq!(history, vec![snapshot.clone()]);
+        assert_eq!(
+            snapshot.values,
+            vec![
+                CurrencyValue {
+                    currency: Currency::EUR,
+                    amount: 2500,
+                },
+                CurrencyValue {
+                    currency: Currency::USD,
+                    amount: 1500,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_value_history_returns_the_snapshots_in_range(pool: SqlitePool) -> Result<()> {
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let other_id = CollectionId::default();
+        sqlx::query(
+            "INSERT INTO collections (id, name, total_value_amount, total_value_currency) VALUES (?1, 'Other Collection', 0, 'USD')",
+        )
+        .bind(&other_id)
+        .execute(&pool)
+        .await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection_id = CollectionId::try_from(collection_id.as_str())?;
+        for d in [3, 1, 5, 7] {
+            repo.take_value_snapshot(&collection_id, day(d)).await?;
+        }
+        repo.take_value_snapshot(&other_id, day(4)).await?;
+
+        let history = repo
+            .get_value_history(&collection_id, day(2), day(5))
+            .await?;
+
+        let dates: Vec<NaiveDate> = history.iter().map(|s| s.date).collect();
+        assert_eq!(dates, vec![day(3), day(5)]);
+        assert!(history.iter().all(|s| s.collection_id == collection_id));
+        assert!(
+            repo.get_value_history(&collection_id, day(8), day(30))
+                .await?
+                .is_empty()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_over_refund_is_rejected(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let manufacturer_id = uuid::Uuid::new_v4().to_string();
+        catalog_db
+            .insert_manufacturer(&manufacturer_id, "ACME")
+            .await?;
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        let purchase_id = insert_priced_item(
+            &pool,
+            &catalog_db,
+            &collecting_db,
+            &collection_id,
+            &manufacturer_id,
+            "LOCOMOTIVES",
+            (2500, "EUR"),
+        )
+        .await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        repo.add_monetary_adjustment(&purchase_id, &refund(-2000, Currency::EUR))
+            .await?;
+
+        let err = repo
+            .add_monetary_adjustment(&purchase_id, &refund(-600, Currency::EUR))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<AdjustmentError>(),
+            Some(&AdjustmentError::NegativeCost {
+                cost: 2500,
+                adjusted: -100,
+            })
+        );
+        let err = repo
+            .add_monetary_adjustment(&purchase_id, &refund(-100, Currency::USD))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<AdjustmentError>(),
+            Some(AdjustmentError::CurrencyMismatch { .. })
+        ));
+
+        // Only the accepted refund is stored.
+        assert_eq!(repo.list_monetary_adjustments(&purchase_id).await?.len(), 1);
+
+        Ok(())
+    }
+
+    async fn setup_owned_rolling_stock(pool: &SqlitePool) -> Result<CollectingTestData> {
+        let catalog_test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let rolling_stock_ids: Vec<&str> = catalog_test_data
+            .rolling_stock_ids
+            .iter()
+            .map(|s| s.as_str())
+            .collect();
+        CollectingTestDb::new(pool.clone())
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, rolling_stock_ids)
+            .await
+    }
+
+    fn modification(id: &str, cost: Option<MonetaryAmount>) -> Modification {
+        Modification {
+            id: id.to_string(),
+            modification_type: ModificationType::Weathering,
+            description: "weathered with oils".to_string(),
+            date: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            cost,
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_modifications_crud(pool: SqlitePool) -> Result<()> {
+        let test_data = setup_owned_rolling_stock(&pool).await?;
+        let owned_id = &test_data.owned_rolling_stock_ids[0];
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        assert!(!repo.get_collection().await?.items[0].rolling_stocks[0].is_modified);
+
+        repo.add_modification(owned_id, &modification("mod-1", None))
+            .await?;
+        let mut lighting = modification("mod-2", Some(MonetaryAmount::new(2_490, Currency::EUR)));
+        lighting.modification_type = ModificationType::Lighting;
+        lighting.date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        repo.add_modification(owned_id, &lighting).await?;
+
+        let modifications = repo.list_modifications(owned_id).await?;
+        let ids: Vec<&str> = modifications.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["mod-2", "mod-1"]);
+        assert_eq!(
+            modifications[0].modification_type,
+            ModificationType::Lighting
+        );
+        assert_eq!(
+            modifications[0]
+                .cost
+                .as_ref()
+                .map(|c| (c.amount, c.currency)),
+            Some((2_490, Currency::EUR))
+        );
+        assert!(repo.get_collection().await?.items[0].rolling_stocks[0].is_modified);
+
+        lighting.description = "interior lighting".to_string();
+        lighting.cost = None;
+        repo.update_modification(&lighting).await?;
+        let updated = &repo.list_modifications(owned_id).await?[0];
+        assert_eq!(updated.description, "interior lighting");
+        assert!(updated.cost.is_none());
+
+        repo.delete_modification("mod-1").await?;
+        repo.delete_modification("mod-2").await?;
+        assert!(repo.list_modifications(owned_id).await?.is_empty());
+        assert!(!repo.get_collection().await?.items[0].rolling_stocks[0].is_modified);
+
+        assert!(repo.delete_modification("mod-1").await.is_err());
+        assert!(repo.update_modification(&lighting).await.is_err());
+        assert!(
+            repo.add_modification("missing", &modification("mod-3", None))
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_modification_costs_roll_up_into_the_cost_of_ownership(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let test_data = setup_owned_rolling_stock(&pool).await?;
+        let owned_id = &test_data.owned_rolling_stock_ids[0];
+        sqlx::query(
+            "UPDATE purchase_infos SET purchased_price_amount = 10000 WHERE purchase_id = ?1",
+        )
+        .bind(&test_data.purchase_info_id)
+        .execute(&pool)
+        .await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        repo.add_monetary_adjustment(&test_data.purchase_info_id, &refund(-500, Currency::EUR))
+            .await?;
+        for (id, amount) in [("mod-1", 1_500), ("mod-2", 2_490)] {
+            let cost = MonetaryAmount::new(amount, Currency::EUR);
+            repo.add_modification(owned_id, &modification(id, Some(cost)))
+                .await?;
+        }
+
+        let item = repo.get_collection().await?.items.remove(0);
+        let adjustments = repo
+            .list_monetary_adjustments(&test_data.purchase_info_id)
+            .await?;
+        let modifications = repo.list_modifications(owned_id).await?;
+
+        let total = item.total_cost_of_ownership(&adjustments, &modifications)?;
+        assert_eq!(total.map(|c| c.amount), Some(10_000 - 500 + 1_500 + 2_490));
+        let acquisition = item.total_cost_of_ownership(&adjustments, &[])?;
+        assert_eq!(acquisition.map(|c| c.amount), Some(9_500));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_item_cost_breakdown_totals_each_currency(pool: SqlitePool) -> Result<()> {
+        let test_data = setup_owned_rolling_stock(&pool).await?;
+        let owned_id = &test_data.owned_rolling_stock_ids[0];
+        sqlx::query(
+            "UPDATE purchase_infos SET purchased_price_amount = 10000 WHERE purchase_id = ?1",
+        )
+        .bind(&test_data.purchase_info_id)
+        .execute(&pool)
+        .await?;
+        sqlx::query("INSERT INTO decoders (id, manufacturer, model, address, purchase_price_amount, purchase_price_currency, created_at, updated_at) VALUES ('dec-1', 'ESU', 'LokSound 5', 3, 11900, 'USD', '2024-01-01', '2024-01-01')")
+            .execute(&pool)
+            .await?;
+        sqlx::query("UPDATE owned_rolling_stocks SET decoder_id = 'dec-1' WHERE id = ?1")
+            .bind(owned_id)
+            .execute(&pool)
+            .await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        repo.add_monetary_adjustment(&test_data.purchase_info_id, &refund(-500, Currency::EUR))
+            .await?;
+        let costs = [
+            ("mod-1", MonetaryAmount::new(1_500, Currency::EUR)),
+            ("mod-2", MonetaryAmount::new(1_000, Currency::USD)),
+        ];
+        for (id, cost) in costs {
+            repo.add_modification(owned_id, &modification(id, Some(cost)))
+                .await?;
+        }
+        repo.add_modification(owned_id, &modification("mod-3", None))
+            .await?;
+
+        let item_id = CollectionItemId::try_from(test_data.collection_item_id.as_str())?;
+        let breakdown = repo.get_item_cost_breakdown(&item_id).await?;
+
+        assert_eq!(breakdown.entries.len(), 4);
+        assert_eq!(breakdown.unpriced, 1);
+        assert_eq!(
+            breakdown.totals,
+            vec![
+                MonetaryAmount::new(10_000 - 500 + 1_500, Currency::EUR),
+                MonetaryAmount::new(11_900 + 1_000, Currency::USD),
+            ]
+        );
+        assert!(
+            repo.get_item_cost_breakdown(&CollectionItemId::default())
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2024, 3, day)
+            .unwrap()
+            .and_hms_opt(hour, 0, 0)
+            .unwrap()
+            .and_utc()
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_recent_activity_is_newest_first(pool: SqlitePool) -> Result<()> {
+        let catalog_test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let collection_id = collecting_db.insert_collection("My Collection").await?;
+        let railway_model_id = &catalog_test_data.railway_model_id;
+        let first = collecting_db
+            .insert_collection_item(&collection_id, railway_model_id)
+            .await?;
+        let second = collecting_db
+            .insert_collection_item(&collection_id, railway_model_id)
+            .await?;
+        for (id, created_at, updated_at) in [
+            (&first, at(1, 10), at(3, 10)),
+            (&second, at(2, 10), at(2, 10)),
+        ] {
+            sqlx::query(
+                "UPDATE collection_items SET created_at = ?2, updated_at = ?3 WHERE id = ?1",
+            )
+            .bind(id)
+            .bind(created_at)
+            .bind(updated_at)
+            .execute(&pool)
+            .await?;
+        }
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let second_id = CollectionItemId::try_from(second.as_str())?;
+        repo.record_item_view(&second_id, at(4, 9)).await?;
+        repo.record_item_view(&second_id, at(4, 10)).await?;
+
+        let activity = repo.get_recent_activity(10).await?;
+
+        let events: Vec<(ActivityKind, String, DateTime<Utc>)> = activity
+            .iter()
+            .map(|e| (e.kind, e.collection_item_id.to_string(), e.occurred_at))
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                (ActivityKind::Viewed, second.clone(), at(4, 10)),
+                (ActivityKind::Modified, first.clone(), at(3, 10)),
+                (ActivityKind::Added, second.clone(), at(2, 10)),
+                (ActivityKind::Added, first.clone(), at(1, 10)),
+            ]
+        );
+        assert!(activity[0].railway_model.is_some());
+        assert_eq!(repo.get_recent_activity(2).await?.len(), 2);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_item_views_are_capped_per_item(pool: SqlitePool) -> Result<()> {
+        let test_data = setup_owned_rolling_stock(&pool).await?;
+        let item_id = CollectionItemId::try_from(test_data.collection_item_id.as_str())?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+
+        for hour in 0..MAX_VIEWS_PER_ITEM + 2 {
+            repo.record_item_view(&item_id, at(5, hour)).await?;
+        }
+
+        let views: Vec<DateTime<Utc>> = sqlx::query_scalar(
+            "SELECT viewed_at FROM item_views WHERE collection_item_id = ?1 ORDER BY viewed_at",
+        )
+        .bind(&item_id)
+        .fetch_all(&pool)
+        .await?;
+        assert_eq!(views.len(), MAX_VIEWS_PER_ITEM as usize);
+        assert_eq!(views[0], at(5, 2));
+        assert!(
+            repo.record_item_view(&CollectionItemId::default(), at(5, 0))
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    /// A stored item with conditions, notes and a purchase, whose rolling
+    /// stock has a serial number and is stored apart.
+    async fn setup_item_to_duplicate(pool: &SqlitePool) -> Result<CollectingTestData> {
+        let data = setup_owned_rolling_stock(pool).await?;
+        sqlx::query("INSERT INTO storage_locations (id, name, created_at, updated_at) VALUES ('box-1', 'Box 1', '2024-01-01', '2024-01-01')")
+            .execute(pool)
+            .await?;
+        sqlx::query("UPDATE collection_items SET conditions = 'mint', notes = 'runs well', location_id = 'box-1' WHERE id = ?1")
+            .bind(&data.collection_item_id)
+            .execute(pool)
+            .await?;
+        sqlx::query("UPDATE owned_rolling_stocks SET notes = 'serial 1234', location_id = 'box-1' WHERE id = ?1")
+            .bind(&data.owned_rolling_stock_ids[0])
+            .execute(pool)
+            .await?;
+        Ok(data)
+    }
+
+    fn find_item<'a>(collection: &'a Collection, id: &str) -> &'a CollectionItem {
+        collection
+            .items
+            .iter()
+            .find(|item| item.id.to_string() == id)
+            .expect("item in the collection")
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_duplicate_collection_item_copies_the_template_fields(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let data = setup_item_to_duplicate(&pool).await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let item_id = CollectionItemId::try_from(&data.collection_item_id)?;
+        let options = DuplicateItemOptions {
+            clone_rolling_stocks: true,
+            allow_duplicates: true,
+        };
+
+        let duplicate_id = repo.duplicate_collection_item(&item_id, &options).await?;
+
+        let collection = repo.get_collection().await?;
+        assert_eq!(collection.items.len(), 2);
+        let original = find_item(&collection, &data.collection_item_id);
+        let duplicate = find_item(&collection, &duplicate_id.to_string());
+        assert_ne!(duplicate.id, original.id);
+        assert_eq!(duplicate.railway_model_id, original.railway_model_id);
+        assert_eq!(duplicate.railway_model, original.railway_model);
+        assert_eq!(duplicate.conditions.as_deref(), Some("mint"));
+        assert_eq!(duplicate.conditions, original.conditions);
+        assert_eq!(duplicate.notes, original.notes);
+        assert!(original.location_id.is_some());
+        assert_eq!(duplicate.location_id, None);
+        assert!(duplicate.replacement_value.is_none());
+        assert!(!duplicate.is_on_loan);
+        assert!(original.purchase_info.is_some());
+        assert!(duplicate.purchase_info.is_none());
+        assert!(duplicate.created_at.is_some());
+
+        assert_eq!(
+            duplicate.rolling_stocks.len(),
+            original.rolling_stocks.len()
+        );
+        let (cloned, source) = (&duplicate.rolling_stocks[0], &original.rolling_stocks[0]);
+        assert_ne!(cloned.id, source.id);
+        assert_eq!(cloned.rolling_stock_id, source.rolling_stock_id);
+        assert_eq!(source.notes, "serial 1234");
+        assert_eq!(cloned.notes, "");
+        assert_eq!(source.location_id.as_deref(), Some("box-1"));
+        assert_eq!(cloned.location_id, None);
+        assert!(cloned.decoder.is_none());
+        assert!(!cloned.is_modified);
+
+        let without_rolling_stocks = DuplicateItemOptions {
+            clone_rolling_stocks: false,
+            allow_duplicates: true,
+        };
+        let duplicate_id = repo
+            .duplicate_collection_item(&item_id, &without_rolling_stocks)
+            .await?;
+        let collection = repo.get_collection().await?;
+        let duplicate = find_item(&collection, &duplicate_id.to_string());
+        assert!(duplicate.rolling_stocks.is_empty());
+        assert_eq!(duplicate.notes.as_deref(), Some("runs well"));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_duplicate_collection_item_detects_duplicates_unless_allowed(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let data = setup_item_to_duplicate(&pool).await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let item_id = CollectionItemId::try_from(&data.collection_item_id)?;
+        let options = DuplicateItemOptions {
+            clone_rolling_stocks: true,
+            allow_duplicates: false,
+        };
+
+        let error = repo
+            .duplicate_collection_item(&item_id, &options)
+            .await
+            .unwrap_err();
+
+        let railway_model_id = repo.get_collection().await?.items[0].railway_model_id;
+        assert_eq!(
+            error.downcast_ref::<DuplicateItemError>(),
+            Some(&DuplicateItemError::AlreadyInCollection {
+                railway_model_id,
+                collection_item_ids: vec![data.collection_item_id.clone()],
+            })
+        );
+        assert_eq!(repo.get_collection().await?.items.len(), 1);
+
+        let missing = CollectionItemId::from(Uuid::new_v4());
+        assert!(
+            repo.duplicate_collection_item(&missing, &options)
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    /// Three ACME locomotives and a Roco freight car, and an empty
+    /// storage location "shelf-1". Returns the ACME manufacturer id.
+    async fn setup_bulk_update_items(pool: &SqlitePool) -> Result<String> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let acme_id = uuid::Uuid::new_v4().to_string();
+        let roco_id = uuid::Uuid::new_v4().to_string();
+        catalog_db.insert_manufacturer(&acme_id, "ACME").await?;
+        catalog_db.insert_manufacturer(&roco_id, "Roco").await?;
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        for (manufacturer_id, category) in [
+            (&acme_id, "LOCOMOTIVES"),
+            (&acme_id, "LOCOMOTIVES"),
+            (&acme_id, "LOCOMOTIVES"),
+            (&roco_id, "FREIGHT_CARS"),
+        ] {
+            let railway_model_id = catalog_db
+                .insert_railway_model(
+                    &RailwayModelId::new(),
+                    manufacturer_id,
+                    "P-1",
+                    "test model",
+                    "electric",
+                    "H0",
+                    "IV",
+                    category,
+                )
+                .await?;
+            collecting_db
+                .insert_collection_item(&collection_id, &railway_model_id)
+                .await?;
+        }
+        sqlite::insert_storage_location(
+            pool,
+            &crate::collecting::infrastructure::entities::StorageLocationRow {
+                id: "shelf-1".to_string(),
+                name: "Shelf 1".to_string(),
+                description: None,
+                parent_id: None,
+            },
+        )
+        .await?;
+        Ok(acme_id)
+    }
+
+    fn shelf_patch() -> CollectionItemPatch {
+        CollectionItemPatch {
+            conditions: Some("mint".to_string()),
+            location: Some(LocationUpdate::MoveTo {
+                location_id: "shelf-1".to_string(),
+            }),
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_bulk_update_dry_run_writes_nothing(pool: SqlitePool) -> Result<()> {
+        let acme_id = setup_bulk_update_items(&pool).await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let filter = CollectionItemFilter {
+            manufacturer_id: Some(acme_id),
+            ..CollectionItemFilter::default()
+        };
+        let options = BulkUpdateOptions {
+            dry_run: true,
+            limit: Some(1),
+        };
+
+        let report = repo
+            .bulk_update_items(&filter, &shelf_patch(), &options)
+            .await?;
+
+        assert_eq!(
+            report,
+            BulkUpdateReport {
+                matched: 3,
+                changed: 3,
+                limit: 1,
+                dry_run: true,
+            }
+        );
+        let collection = repo.get_collection().await?;
+        assert!(
+            collection
+                .items
+                .iter()
+                .all(|item| item.conditions.is_none() && item.location_id.is_none())
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_bulk_update_over_the_limit_is_refused(pool: SqlitePool) -> Result<()> {
+        setup_bulk_update_items(&pool).await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let options = BulkUpdateOptions {
+            dry_run: false,
+            limit: Some(3),
+        };
+
+        let err = repo
+            .bulk_update_items(&CollectionItemFilter::default(), &shelf_patch(), &options)
+            .await
+            .expect_err("four items are over the limit");
+
+        assert_eq!(
+            err.downcast_ref::<BulkUpdateError>(),
+            Some(&BulkUpdateError::LimitExceeded {
+                changed: 4,
+                limit: 3,
+            })
+        );
+        let collection = repo.get_collection().await?;
+        assert!(
+            collection
+                .items
+                .iter()
+                .all(|item| item.conditions.is_none())
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_bulk_update_patches_the_matching_items(pool: SqlitePool) -> Result<()> {
+        let acme_id = setup_bulk_update_items(&pool).await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let filter = CollectionItemFilter {
+            manufacturer_id: Some(acme_id),
+            category: Some(crate::catalog::domain::category::Category::Locomotives),
+            ..CollectionItemFilter::default()
+        };
+        // One of the locomotives already has the patched values.
+        sqlx::query("UPDATE collection_items SET conditions = 'mint', location_id = 'shelf-1' WHERE id = (SELECT ci.id FROM collection_items ci JOIN railway_models rm ON rm.id = ci.railway_model_id WHERE rm.category = 'LOCOMOTIVES' LIMIT 1)")
+            .execute(&pool)
+            .await?;
+
+        let report = repo
+            .bulk_update_items(&filter, &shelf_patch(), &BulkUpdateOptions::default())
+            .await?;
+        let again = repo
+            .bulk_update_items(&filter, &shelf_patch(), &BulkUpdateOptions::default())
+            .await?;
+
+        assert_eq!(
+            report,
+            BulkUpdateReport {
+                matched: 3,
+                changed: 2,
+                limit: BulkUpdateOptions::DEFAULT_LIMIT,
+                dry_run: false,
+            }
+        );
+        assert_eq!(again.changed, 0);
+        let collection = repo.get_collection().await?;
+        let shelved: Vec<bool> = collection
+            .items
+            .iter()
+            .map(|item| {
+                item.conditions.as_deref() == Some("mint")
+                    && item.location_id.as_deref() == Some("shelf-1")
+            })
+            .collect();
+        assert_eq!(shelved.iter().filter(|&&shelved| shelved).count(), 3);
+        let freight_car = collection
+            .items
+            .iter()
+            .find(|item| item.location_id.is_none())
+            .expect("the freight car is not moved");
+        assert_eq!(freight_car.conditions, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_recompute_collection_returns_diff(pool: SqlitePool) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let manufacturer_id = uuid::Uuid::new_v4().to_string();
+        catalog_db
+            .insert_manufacturer(&manufacturer_id, "ACME")
+            .await?;
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+
+        insert_priced_item(
+            &pool,
+            &catalog_db,
+            &collecting_db,
+            &collection_id,
+            &manufacturer_id,
+            "LOCOMOTIVES",
+            (2500, "EUR"),
+        )
+        .await?;
+        insert_priced_item(
+            &pool,
+            &catalog_db,
+            &collecting_db,
+            &collection_id,
+            &manufacturer_id,
+            "PASSENGER_CARS",
+            (1500, "USD"),
+        )
+        .await?;
+
+        // Seed an inconsistent summary row
+        sqlx::query(
+            "UPDATE collections SET locomotives_count = 5, passenger_cars_count = 0, total_value_amount = 999 WHERE id = ?1",
+        )
+        .bind(&collection_id)
+        .execute(&pool)
+        .await?;
+
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection_id =
+            CollectionId::try_from(collection_id.as_str()).map_err(|e| anyhow!(e))?;
+        let diff = repo.recompute_collection(&collection_id).await?;
+
+        assert_eq!(diff.collection_id, collection_id);
+        assert_eq!(diff.old_summary.locomotives_count, 5);
+        assert_eq!(diff.old_summary.passenger_cars_count, 0);
+        assert_eq!(
+            diff.new_summary,
+            CollectionSummary {
+                locomotives_count: 1,
+                passenger_cars_count: 1,
+                ..CollectionSummary::default()
+            }
+        );
+        assert_eq!(
+            diff.total_values,
+            vec![
+                TotalValueDiff {
+                    currency: Currency::EUR,
+                    old_amount: 999,
+                    new_amount: 2500,
+                },
+                TotalValueDiff {
+                    currency: Currency::USD,
+                    old_amount: 0,
+                    new_amount: 1500,
+                },
+            ]
+        );
+
+        // The recomputed values are persisted, keeping the stored currency
+        let collection = repo.get_collection().await?;
+        assert_eq!(collection.summary, diff.new_summary);
+        let total_value = collection.total_value.expect("total value");
+        assert_eq!(total_value.amount, 2500);
+        assert_eq!(total_value.currency, Currency::EUR);
+
+        // Recomputing again leaves the counters untouched
+        let diff = repo.recompute_collection(&collection_id).await?;
+        assert_eq!(diff.old_summary, diff.new_summary);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_recompute_collection_moves_total_to_single_currency(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let catalog_db = CatalogTestDb::new(pool.clone());
+        let collecting_db = CollectingTestDb::new(pool.clone());
+        let manufacturer_id = uuid::Uuid::new_v4().to_string();
+        catalog_db
+            .insert_manufacturer(&manufacturer_id, "ACME")
+            .await?;
+        let collection_id = collecting_db.insert_collection("Test Collection").await?;
+        insert_priced_item(
+            &pool,
+            &catalog_db,
+            &collecting_db,
+            &collection_id,
+            &manufacturer_id,
+            "TRAIN_SETS",
+            (4200, "GBP"),
+        )
+        .await?;
+
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let collection_id =
+            CollectionId::try_from(collection_id.as_str()).map_err(|e| anyhow!(e))?;
+        let diff = repo.recompute_collection(&collection_id).await?;
+
+        assert_eq!(diff.new_summary.train_sets_count, 1);
+        let total_value = repo
+            .get_collection()
+            .await?
+            .total_value
+            .expect("total value");
+        assert_eq!(total_value.amount, 4200);
+        assert_eq!(total_value.currency, Currency::GBP);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_recompute_collection_not_found(pool: SqlitePool) {
+        let repo = SqliteCollectionRepository::new(pool);
+
+        let result = repo.recompute_collection(&CollectionId::default()).await;
+
+        assert!(result.is_err());
+    }
+
+    /// Seed one inconsistency of each kind in the collection of
+    /// `setup_owned_rolling_stock`.
+    async fn seed_inconsistencies(pool: &SqlitePool, data: &CollectingTestData) -> Result<()> {
+        let mut conn = pool.acquire().await?;
+        sqlx::query("PRAGMA foreign_keys = OFF")
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("UPDATE owned_rolling_stocks SET rolling_stock_id = 'removed' WHERE id = ?1")
+            .bind(&data.owned_rolling_stock_ids[0])
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query("PRAGMA foreign_keys = ON")
+            .execute(&mut *conn)
+            .await?;
+        drop(conn);
+
+        // The triggers count the item as a locomotive, the stored counter
+        // then drifts
+        sqlx::query(
+            "UPDATE railway_models SET category = 'LOCOMOTIVES' WHERE id = (
+                SELECT railway_model_id FROM collection_items WHERE id = ?1)",
+        )
+        .bind(&data.collection_item_id)
+        .execute(pool)
+        .await?;
+        sqlx::query("UPDATE collections SET locomotives_count = 4 WHERE id = ?1")
+            .bind(&data.collection_id)
+            .execute(pool)
+            .await?;
+        sqlx::query(
+            "UPDATE purchase_infos SET purchased_price_currency = 'ITL' WHERE purchase_id = ?1",
+        )
+        .bind(&data.purchase_info_id)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_check_collection_consistency_reports_each_inconsistency(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let data = setup_owned_rolling_stock(&pool).await?;
+        let collection_id =
+            CollectionId::try_from(data.collection_id.as_str()).map_err(|e| anyhow!(e))?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+
+        let report = repo
+            .check_collection_consistency(&collection_id, false)
+            .await?;
+        assert!(report.is_consistent());
+
+        seed_inconsistencies(&pool, &data).await?;
+        let report = repo
+            .check_collection_consistency(&collection_id, false)
+            .await?;
+
+        let found: Vec<(InconsistencyKind, &str, RepairAction)> = report
+            .inconsistencies
+            .iter()
+            .map(|i| (i.kind, i.subject_id.as_str(), i.repair))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    InconsistencyKind::MissingRollingStock,
+                    data.owned_rolling_stock_ids[0].as_str(),
+                    RepairAction::ClearRollingStockReference,
+                ),
+                (
+                    InconsistencyKind::SummaryCounterMismatch,
+                    data.collection_id.as_str(),
+                    RepairAction::RecomputeSummaryCounters,
+                ),
+                (
+                    InconsistencyKind::UnsupportedCurrency,
+                    data.purchase_info_id.as_str(),
+                    RepairAction::ChooseSupportedCurrency,
+                ),
+            ]
+        );
+        assert!(!report.repaired);
+
+        // Without the repair flag nothing is written
+        let again = repo
+            .check_collection_consistency(&collection_id, false)
+            .await?;
+        assert_eq!(again, report);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_check_collection_consistency_applies_the_safe_repairs(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let data = setup_owned_rolling_stock(&pool).await?;
+        let collection_id =
+            CollectionId::try_from(data.collection_id.as_str()).map_err(|e| anyhow!(e))?;
+        seed_inconsistencies(&pool, &data).await?;
+        let repo = SqliteCollectionRepository::new(pool.clone());
+
+        let report = repo
+            .check_collection_consistency(&collection_id, true)
+            .await?;
+        assert!(report.repaired);
+        assert_eq!(report.inconsistencies.len(), 3);
+
+        let rolling_stock_id: Option<String> =
+            sqlx::query_scalar("SELECT rolling_stock_id FROM owned_rolling_stocks WHERE id = ?1")
+                .bind(&data.owned_rolling_stock_ids[0])
+                .fetch_one(&pool)
+                .await?;
+        assert_eq!(rolling_stock_id, None);
+        let collection = repo.get_collection().await?;
+        assert_eq!(collection.summary.locomotives_count, 1);
+
+        // Only the currency is left, for the collector to fix
+        let report = repo
+            .check_collection_consistency(&collection_id, true)
+            .await?;
+        let kinds: Vec<InconsistencyKind> = report.inconsistencies.iter().map(|i| i.kind).collect();
+        assert_eq!(kinds, vec![InconsistencyKind::UnsupportedCurrency]);
+        assert!(!report.repaired);
+
+        Ok(())
+    }
+
+    /// Compare loading a 2,000 item collection with the joined detail query
+    /// against the four separate queries it replaced.
+    ///
+    /// Run with `cargo test bench_get_collection -- --ignored --nocapture`.
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_list_collection_items_walks_every_item_once(pool: SqlitePool) -> Result<()> {
+        const ITEMS: i64 = 1_000;
+
+        let catalog_test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        sqlite::ensure_default_collection(&pool).await?;
+        // Legacy items without a timestamp, and items sharing one, so that
+        // pages also break between rows with the same sort key.
+        sqlx::query(
+            "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < ?1)
+             INSERT INTO collection_items (id, collection_id, railway_model_id, conditions, created_at)
+             SELECT printf('00000000-0000-0000-0000-%012d', (i * 7919) % ?1), ?2, ?3, 'NEW',
+                 CASE WHEN i % 10 = 0 THEN NULL ELSE printf('2024-01-%02d 10:00:00', i % 28 + 1) END
+             FROM n",
+        )
+        .bind(ITEMS)
+        .bind(CollectionId::DEFAULT)
+        .bind(catalog_test_data.railway_model_id)
+        .execute(&pool)
+        .await?;
+        // Every item has an owned rolling stock and a purchase info, and some
+        // have a second owned rolling stock: an item spans several rows.
+        sqlx::query(
+            "INSERT INTO owned_rolling_stocks (id, collection_item_id, rolling_stock_id)
+             SELECT 'ors-' || id, id, ?1 FROM collection_items
+             UNION ALL
+             SELECT 'ors2-' || id, id, ?1 FROM collection_items WHERE rowid % 3 = 0",
+        )
+        .bind(&catalog_test_data.rolling_stock_ids[0])
+        .execute(&pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO purchase_infos (purchase_id, collection_item_id, purchase_type, purchase_date, purchased_price_amount, purchased_price_currency)
+             SELECT 'pi-' || id, id, 'purchased', '2024-01-01', 10000, 'EUR' FROM collection_items",
+        )
+        .execute(&pool)
+        .await?;
+
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let mut seen = std::collections::HashSet::new();
+        let mut after = None;
+        let mut pages = 0;
+        loop {
+            let page = repo.list_collection_items(after.as_ref(), 64).await?;
+            assert!(page.items.len() <= 64);
+            for item in &page.items {
+                assert!(seen.insert(item.id.to_string()), "{} listed twice", item.id);
+                assert!(item.railway_model.is_some());
+                assert!(item.purchase_info.is_some());
+                assert!(!item.rolling_stocks.is_empty());
+            }
+            pages += 1;
+            match page.next {
+                Some(next) => after = Some(next),
+                None => break,
+            }
+        }
+
+        assert_eq!(seen.len(), ITEMS as usize);
+        assert_eq!(pages, 16);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    #[ignore = "benchmark"]
+    async fn bench_get_collection(pool: SqlitePool) -> Result<()> {
+        const ITEMS: i64 = 2_000;
+        const RUNS: u32 = 10;
+
+        let catalog_test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        sqlite::ensure_default_collection(&pool).await?;
+        sqlx::query(
+            "WITH RECURSIVE n(i) AS (SELECT 1 UNION ALL SELECT i + 1 FROM n WHERE i < ?1)
+             INSERT INTO collection_items (id, collection_id, railway_model_id, conditions)
+             SELECT printf('00000000-0000-0000-0000-%012d', i), ?2, ?3, 'NEW' FROM n",
+        )
+        .bind(ITEMS)
+        .bind(CollectionId::DEFAULT)
+        .bind(catalog_test_data.railway_model_id)
+        .execute(&pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO owned_rolling_stocks (id, collection_item_id, rolling_stock_id)
+             SELECT 'ors-' || id, id, ?1 FROM collection_items",
+        )
+        .bind(&catalog_test_data.rolling_stock_ids[0])
+        .execute(&pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO purchase_infos (purchase_id, collection_item_id, purchase_type, purchase_date, purchased_price_amount, purchased_price_currency)
+             SELECT 'pi-' || id, id, 'PURCHASED', '2024-01-01', 10000, 'EUR' FROM collection_items",
+        )
+        .execute(&pool)
+        .await?;
+
+        let collection_id = CollectionId::DEFAULT;
+        let started = std::time::Instant::now();
+        for _ in 0..RUNS {
+            sqlite::get_collection(&pool, collection_id.clone()).await?;
+            sqlite::get_collection_items(&pool, &collection_id).await?;
+            sqlite::get_owned_rolling_stocks(&pool, &collection_id).await?;
+            sqlite::get_purchase_infos(&pool, &collection_id).await?;
+        }
+        let separate_queries = started.elapsed() / RUNS;
+
+        let started = std::time::Instant::now();
+        for _ in 0..RUNS {
+            sqlite::get_collection(&pool, collection_id.clone()).await?;
+            let mut rows = 0;
+            sqlite::for_each_collection_detail(&pool, &collection_id, |_| {
+                rows += 1;
+                Ok(())
+            })
+            .await?;
+            assert_eq!(rows, ITEMS);
+        }
+        let joined_query = started.elapsed() / RUNS;
+
+        let repo = SqliteCollectionRepository::new(pool.clone());
+        let started = std::time::Instant::now();
+        for _ in 0..RUNS {
+            let collection = repo.get_collection().await?;
+            assert_eq!(collection.items.len(), ITEMS as usize);
+        }
+        let mapped = started.elapsed() / RUNS;
+
+        eprintln!(
+            "get_collection with {ITEMS} items: separate queries {separate_queries:?}, joined query {joined_query:?}, joined query mapped to a Collection {mapped:?}"
+        );
         Ok(())
     }
 }
@@ -0,0 +1,494 @@
+use crate::audit::infrastructure::sink::{AuditSink, AuditedChange, SqliteAuditSink};
+use crate::collecting::domain::repository::StorageLocationRepository;
+use crate::collecting::domain::storage_location::{
+    StorageLocation, StorageLocationError, StoredItem,
+};
+use crate::collecting::infrastructure::entities::{StorageLocationRow, StoredItemRow};
+use crate::collecting::infrastructure::sqlite;
+use crate::core::infrastructure::transaction::with_transaction;
+use anyhow::{Context, Result, anyhow};
+use sqlx::{SqliteConnection, SqlitePool};
+use std::sync::Arc;
+
+pub struct SqliteStorageLocationRepository {
+    pool: SqlitePool,
+    audit: Arc<dyn AuditSink>,
+}
+
+impl SqliteStorageLocationRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            audit: Arc::new(SqliteAuditSink::default()),
+        }
+    }
+
+    /// Record the changes made by this repository in `audit`.
+    pub fn with_audit_sink(mut self, audit: Arc<dyn AuditSink>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    fn to_row(location: &StorageLocation) -> StorageLocationRow {
+        StorageLocationRow {
+            id: location.id.clone(),
+            name: location.name.clone(),
+            description: location.description.clone(),
+            parent_id: location.parent_id.clone(),
+        }
+    }
+
+    fn build_location(row: StorageLocationRow) -> StorageLocation {
+        StorageLocation {
+            id: row.id,
+            name: row.name,
+            description: row.description,
+            parent_id: row.parent_id,
+        }
+    }
+
+    fn build_stored_item(row: StoredItemRow) -> StoredItem {
+        StoredItem {
+            collection_item_id: row.collection_item_id,
+            railway_model_id: row.railway_model_id,
+            owned_rolling_stock_id: row.owned_rolling_stock_id,
+            location_id: row.location_id,
+        }
+    }
+
+    /// Every stored location, failing unless the one with `location_id`
+    /// (when given) is among them.
+    async fn load_locations(
+        conn: &mut SqliteConnection,
+        location_id: Option<&str>,
+    ) -> Result<Vec<StorageLocation>> {
+        let locations: Vec<StorageLocation> = sqlite::get_storage_locations(&mut *conn)
+            .await?
+            .into_iter()
+            .map(Self::build_location)
+            .collect();
+        if let Some(location_id) = location_id
+            && !locations.iter().any(|l| l.id == location_id)
+        {
+            return Err(anyhow!("storage location not found id={}", location_id));
+        }
+        Ok(locations)
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageLocationRepository for SqliteStorageLocationRepository {
+    async fn create_location(&self, location: &StorageLocation) -> Result<()> {
+        let location = location.clone();
+        let change = AuditedChange::created("storage_location", &location.id, &location)?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let locations = Self::load_locations(conn, location.parent_id.as_deref()).await?;
+                location.check_parent(&locations)?;
+                sqlite::insert_storage_location(&mut *conn, &Self::to_row(&location)).await?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn update_location(&self, location: &StorageLocation) -> Result<()> {
+        let location = location.clone();
+        let change = AuditedChange::updated("storage_location", &location.id, &location)?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let mut locations = Self::load_locations(conn, Some(&location.id)).await?;
+                if let Some(parent_id) = &location.parent_id
+                    && !locations.iter().any(|l| &l.id == parent_id)
+                {
+                    return Err(anyhow!("storage location not found id={}", parent_id));
+                }
+                // Check the parent chain as it will be stored.
+                locations.retain(|l| l.id != location.id);
+                locations.push(location.clone());
+                location.check_parent(&locations)?;
+
+                sqlite::update_storage_location(&mut *conn, &Self::to_row(&location)).await?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn delete_location(&self, location_id: &str, reassign_to: Option<&str>) -> Result<()> {
+        let location_id = location_id.to_string();
+        let reassign_to = reassign_to.map(String::from);
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let locations = Self::load_locations(conn, Some(&location_id)).await?;
+                let (items, nested) =
+                    sqlite::count_storage_location_contents(conn, &location_id).await?;
+
+                if items > 0 || nested > 0 {
+                    let Some(target_id) = reassign_to else {
+                        return Err(StorageLocationError::NotEmpty {
+                            location_id,
+                            items: u32::try_from(items)?,
+                            locations: u32::try_from(nested)?,
+                        }
+                        .into());
+                    };
+                    let target = locations
+                        .iter()
+                        .find(|l| l.id == target_id)
+                        .with_context(|| format!("storage location not found id={}", target_id))?;
+                    if target.is_within(&location_id, &locations) {
+                        return Err(StorageLocationError::InvalidReassignment {
+                            location_id,
+                            target_id,
+                        }
+                        .into());
+                    }
+                    sqlite::move_storage_location_contents(conn, &location_id, &target_id).await?;
+                }
+
+                sqlite::delete_storage_location(&mut *conn, &location_id).await?;
+                audit
+                    .record(
+                        conn,
+                        &AuditedChange::deleted("storage_location", location_id),
+                    )
+                    .await
+            })
+        })
+        .await
+    }
+
+    async fn get_location(&self, location_id: &str) -> Result<Option<StorageLocation>> {
+        let row = sqlite::get_storage_location(&self.pool, location_id).await?;
+        Ok(row.map(Self::build_location))
+    }
+
+    async fn list_locations(&self) -> Result<Vec<StorageLocation>> {
+        let rows = sqlite::get_storage_locations(&self.pool).await?;
+        Ok(rows.into_iter().map(Self::build_location).collect())
+    }
+
+    async fn move_item(&self, collection_item_id: &str, location_id: Option<&str>) -> Result<()> {
+        let collection_item_id = collection_item_id.to_string();
+        let location_id = location_id.map(String::from);
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                if let Some(location_id) = &location_id
+                    && sqlite::get_storage_location(&mut *conn, location_id)
+                        .await?
+                        .is_none()
+                {
+                    return Err(anyhow!("storage location not found id={}", location_id));
+                }
+                if !sqlite::set_collection_item_location(
+                    &mut *conn,
+                    &collection_item_id,
+                    location_id.as_deref(),
+                )
+                .await?
+                {
+                    return Err(anyhow!(
+                        "collection item not found id={}",
+                        collection_item_id
+                    ));
+                }
+                let change = AuditedChange::updated(
+                    "collection_item",
+                    &collection_item_id,
+                    &serde_json::json!({ "location_id": location_id }),
+                )?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn move_rolling_stock(
+        &self,
+        owned_rolling_stock_id: &str,
+        location_id: Option<&str>,
+    ) -> Result<()> {
+        let owned_rolling_stock_id = owned_rolling_stock_id.to_string();
+        let location_id = location_id.map(String::from);
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                if let Some(location_id) = &location_id
+                    && sqlite::get_storage_location(&mut *conn, location_id)
+                        .await?
+                        .is_none()
+                {
+                    return Err(anyhow!("storage location not found id={}", location_id));
+                }
+                if !sqlite::set_owned_rolling_stock_location(
+                    &mut *conn,
+                    &owned_rolling_stock_id,
+                    location_id.as_deref(),
+                )
+                .await?
+                {
+                    return Err(anyhow!(
+                        "owned rolling stock not found id={}",
+                        owned_rolling_stock_id
+                    ));
+                }
+                let change = AuditedChange::updated(
+                    "owned_rolling_stock",
+                    &owned_rolling_stock_id,
+                    &serde_json::json!({ "location_id": location_id }),
+                )?;
+                audit.record(conn, &change).await
+            })
+        })
+        .await
+    }
+
+    async fn find_items_by_location(
+        &self,
+        location_id: &str,
+        recursive: bool,
+    ) -> Result<Vec<StoredItem>> {
+        if sqlite::get_storage_location(&self.pool, location_id)
+            .await?
+            .is_none()
+        {
+            return Err(anyhow!("storage location not found id={}", location_id));
+        }
+        let rows = sqlite::find_items_by_location(&self.pool, location_id, recursive).await?;
+        Ok(rows.into_iter().map(Self::build_stored_item).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::domain::railway_model_id::RailwayModelId;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::domain::collection_item_id::CollectionItemId;
+    use crate::collecting::infrastructure::testing::{CollectingTestData, CollectingTestDb};
+    use pretty_assertions::assert_eq;
+
+    fn location(id: &str, parent_id: Option<&str>) -> StorageLocation {
+        StorageLocation {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: None,
+            parent_id: parent_id.map(String::from),
+        }
+    }
+
+    struct Fixture {
+        repo: SqliteStorageLocationRepository,
+        collection: CollectingTestData,
+        railway_model_id: RailwayModelId,
+    }
+
+    impl Fixture {
+        /// A cabinet holding a shelf holding a box, and a display case; and a
+        /// collection item with two owned rolling stocks.
+        async fn setup(pool: &SqlitePool) -> Result<Self> {
+            let catalog_db = CatalogTestDb::new(pool.clone());
+            let catalog_data = catalog_db.setup_railway_model().await?;
+            catalog_db
+                .insert_rolling_stock(
+                    "rs-2",
+                    &catalog_data.railway_model_id,
+                    "locomotive",
+                    &catalog_data.railway_company_id,
+                    0,
+                )
+                .await?;
+            let collection = CollectingTestDb::new(pool.clone())
+                .setup_minimal_collection(
+                    &catalog_data.railway_model_id,
+                    vec![catalog_data.rolling_stock_ids[0].as_str(), "rs-2"],
+                )
+                .await?;
+
+            let repo = SqliteStorageLocationRepository::new(pool.clone());
+            for location in [
+                location("cabinet", None),
+                location("shelf", Some("cabinet")),
+                location("box", Some("shelf")),
+                location("display", None),
+            ] {
+                repo.create_location(&location).await?;
+            }
+            Ok(Self {
+                repo,
+                collection,
+                railway_model_id: catalog_data.railway_model_id,
+            })
+        }
+
+        /// The collection item, or its `owned`-th rolling stock, kept in
+        /// `location_id`.
+        fn stored(&self, owned: Option<usize>, location_id: &str) -> StoredItem {
+            StoredItem {
+                collection_item_id: CollectionItemId::try_from(
+                    self.collection.collection_item_id.as_str(),
+                )
+                .unwrap(),
+                railway_model_id: self.railway_model_id,
+                owned_rolling_stock_id: owned
+                    .map(|i| self.collection.owned_rolling_stock_ids[i].clone()),
+                location_id: location_id.to_string(),
+            }
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_locations_nest_without_cycles(pool: SqlitePool) -> Result<()> {
+        let Fixture { repo, .. } = Fixture::setup(&pool).await?;
+
+        let names: Vec<String> = repo
+            .list_locations()
+            .await?
+            .into_iter()
+            .map(|l| l.name)
+            .collect();
+        assert_eq!(names, vec!["box", "cabinet", "display", "shelf"]);
+
+        // The shelf moves into the display case, the box with it.
+        repo.update_location(&location("shelf", Some("display")))
+            .await?;
+        assert_eq!(
+            repo.get_location("shelf")
+                .await?
+                .unwrap()
+                .parent_id
+                .as_deref(),
+            Some("display")
+        );
+
+        let err = repo
+            .update_location(&location("display", Some("box")))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<StorageLocationError>(),
+            Some(&StorageLocationError::Cycle {
+                location_id: "display".to_string(),
+                parent_id: "box".to_string(),
+            })
+        );
+        assert_eq!(repo.get_location("display").await?.unwrap().parent_id, None);
+
+        assert!(
+            repo.create_location(&location("crate", Some("missing")))
+                .await
+                .is_err()
+        );
+        assert!(
+            repo.update_location(&location("missing", None))
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_items_are_found_in_nested_locations(pool: SqlitePool) -> Result<()> {
+        let fixture = Fixture::setup(&pool).await?;
+        let (repo, collection) = (&fixture.repo, &fixture.collection);
+        repo.move_item(&collection.collection_item_id, Some("shelf"))
+            .await?;
+        repo.move_rolling_stock(&collection.owned_rolling_stock_ids[1], Some("box"))
+            .await?;
+
+        assert_eq!(
+            repo.find_items_by_location("shelf", false).await?,
+            vec![fixture.stored(None, "shelf")]
+        );
+        assert_eq!(
+            repo.find_items_by_location("cabinet", true).await?,
+            vec![
+                fixture.stored(Some(1), "box"),
+                fixture.stored(None, "shelf"),
+            ]
+        );
+        assert!(
+            repo.find_items_by_location("cabinet", false)
+                .await?
+                .is_empty()
+        );
+        assert!(repo.find_items_by_location("missing", true).await.is_err());
+
+        // Back with the rest of the item.
+        repo.move_rolling_stock(&collection.owned_rolling_stock_ids[1], None)
+            .await?;
+        assert!(repo.find_items_by_location("box", false).await?.is_empty());
+
+        assert!(
+            repo.move_item(&collection.collection_item_id, Some("missing"))
+                .await
+                .is_err()
+        );
+        assert!(repo.move_item("missing", Some("box")).await.is_err());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_locations_with_items_are_deleted_only_with_a_reassignment(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let fixture = Fixture::setup(&pool).await?;
+        let (repo, collection) = (&fixture.repo, &fixture.collection);
+        repo.move_item(&collection.collection_item_id, Some("shelf"))
+            .await?;
+
+        let err = repo.delete_location("shelf", None).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<StorageLocationError>(),
+            Some(&StorageLocationError::NotEmpty {
+                location_id: "shelf".to_string(),
+                items: 1,
+                locations: 1,
+            })
+        );
+        let err = repo
+            .delete_location("shelf", Some("box"))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<StorageLocationError>(),
+            Some(&StorageLocationError::InvalidReassignment {
+                location_id: "shelf".to_string(),
+                target_id: "box".to_string(),
+            })
+        );
+        assert!(repo.get_location("shelf").await?.is_some());
+
+        repo.delete_location("shelf", Some("cabinet")).await?;
+        assert!(repo.get_location("shelf").await?.is_none());
+        assert_eq!(
+            repo.get_location("box")
+                .await?
+                .unwrap()
+                .parent_id
+                .as_deref(),
+            Some("cabinet")
+        );
+        assert_eq!(
+            repo.find_items_by_location("cabinet", false).await?,
+            vec![fixture.stored(None, "cabinet")]
+        );
+
+        repo.delete_location("display", None).await?;
+        assert!(repo.delete_location("display", None).await.is_err());
+
+        Ok(())
+    }
+}
@@ -5,10 +5,13 @@
 //! purchase_infos). They are intended for use in tests where quickly setting up
 //! a collection and its items is useful.
 
-use crate::collecting::domain::collection::DEFAULT_COLLECTION_ID;
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::infrastructure::sqlite;
+use crate::core::infrastructure::transaction::with_transaction;
 use anyhow::{Context, Result};
-use chrono::Local;
-use sqlx::SqlitePool;
+use chrono::{Local, Utc};
+use sqlx::{SqliteExecutor, SqlitePool};
 use uuid::Uuid;
 
 /// Test helper for inserting collecting-related rows.
@@ -32,17 +35,7 @@ impl CollectingTestDb {
     ///
     /// Creates a row in `collections` with a generated TEXT id and the provided name.
     pub async fn insert_collection(&self, name: &str) -> Result<String> {
-        let id = Uuid::parse_str(DEFAULT_COLLECTION_ID)
-            .unwrap_or_default()
-            .to_string();
-        let sql = "INSERT INTO collections (id, name, total_value_amount, total_value_currency) VALUES (?1, ?2, 0, 'EUR')";
-        sqlx::query(sql)
-            .bind(&id)
-            .bind(name)
-            .execute(&self.db_pool)
-            .await
-            .with_context(|| format!("inserting collection id={} name={}", id, name))?;
-        Ok(id)
+        insert_collection(&self.db_pool, name).await
     }
 
     /// Insert a collection item for `collection_id` referencing `railway_model_id`.
@@ -51,22 +44,10 @@ impl CollectingTestDb {
     pub async fn insert_collection_item(
         &self,
         collection_id: &str,
-        railway_model_id: &str,
+        railway_model_id: &RailwayModelId,
     ) -> Result<String> {
         let id = Uuid::new_v4().to_string();
-        let sql = "INSERT INTO collection_items (id, collection_id, railway_model_id) VALUES (?1, ?2, ?3)";
-        sqlx::query(sql)
-            .bind(&id)
-            .bind(collection_id)
-            .bind(railway_model_id)
-            .execute(&self.db_pool)
-            .await
-            .with_context(|| {
-                format!(
-                    "inserting collection_item id={} collection_id={}",
-                    id, collection_id
-                )
-            })?;
+        sqlite::insert_collection_item(&self.db_pool, &id, collection_id, railway_model_id).await?;
         Ok(id)
     }
 
@@ -79,19 +60,13 @@ impl CollectingTestDb {
         rolling_stock_id: &str,
     ) -> Result<String> {
         let id = Uuid::new_v4().to_string();
-        let sql = "INSERT INTO owned_rolling_stocks (id, collection_item_id, rolling_stock_id) VALUES (?1, ?2, ?3)";
-        sqlx::query(sql)
-            .bind(&id)
-            .bind(collection_item_id)
-            .bind(rolling_stock_id)
-            .execute(&self.db_pool)
-            .await
-            .with_context(|| {
-                format!(
-                    "inserting owned_rolling_stock id={} collection_item_id={}",
-                    id, collection_item_id
-                )
-            })?;
+        sqlite::insert_owned_rolling_stock(
+            &self.db_pool,
+            &id,
+            collection_item_id,
+            rolling_stock_id,
+        )
+        .await?;
         Ok(id)
     }
 
@@ -101,30 +76,7 @@ impl CollectingTestDb {
     /// `purchase_date` to today (YYYY-MM-DD), and a default purchased_price_amount of 0
     /// with currency "EUR". Adjust as needed in tests.
     pub async fn insert_purchase_info(&self, collection_item_id: &str) -> Result<String> {
-        let purchase_id = Uuid::new_v4().to_string();
-        let purchase_type = "purchased";
-        let purchase_date = Local::now().format("%Y-%m-%d").to_string();
-        let purchased_price_amount: i64 = 0;
-        let purchased_price_currency: &str = "EUR";
-
-        let sql = "INSERT INTO purchase_infos (purchase_id, collection_item_id, purchase_type, purchase_date, purchased_price_amount, purchased_price_currency) VALUES (?1, ?2, ?3, ?4, ?5, ?6)";
-        sqlx::query(sql)
-            .bind(&purchase_id)
-            .bind(collection_item_id)
-            .bind(purchase_type)
-            .bind(&purchase_date)
-            .bind(purchased_price_amount)
-            .bind(purchased_price_currency)
-            .execute(&self.db_pool)
-            .await
-            .with_context(|| {
-                format!(
-                    "inserting purchase_info purchase_id={} collection_item_id={}",
-                    purchase_id, collection_item_id
-                )
-            })?;
-
-        Ok(purchase_id)
+        insert_purchase_info(&self.db_pool, collection_item_id).await
     }
 
     /// Create a minimal collection containing one railway model and optional rolling stocks.
@@ -132,42 +84,109 @@ impl CollectingTestDb {
     /// - `railway_model_id`: id of the railway model to add to the collection
     /// - `rolling_stock_ids`: list of rolling stock ids to attach to the created collection item
     ///
+    /// All rows are inserted in a single transaction, so a failing insert
+    /// (for example an unknown rolling stock id) leaves the database untouched.
+    ///
     /// Returns the generated ids collected in `CollectingTestData`.
     pub async fn setup_minimal_collection(
         &self,
-        railway_model_id: &str,
+        railway_model_id: &RailwayModelId,
         rolling_stock_ids: Vec<&str>,
     ) -> Result<CollectingTestData> {
-        // Create collection
-        let collection_name = "Test Collection";
-        let collection_id = self.insert_collection(collection_name).await?;
-
-        // Create collection item referencing the provided railway model
-        let collection_item_id = self
-            .insert_collection_item(&collection_id, railway_model_id)
-            .await?;
-
-        // Attach provided rolling stocks (if any)
-        let mut owned_rolling_stock_ids = Vec::new();
-        for rs_id in rolling_stock_ids {
-            // Note: ignore individual insert errors propagate up to caller
-            let owned_rolling_stock_id = self
-                .insert_owned_rolling_stock(&collection_item_id, rs_id)
+        let railway_model_id = *railway_model_id;
+        let rolling_stock_ids: Vec<String> =
+            rolling_stock_ids.into_iter().map(String::from).collect();
+
+        with_transaction(&self.db_pool, move |conn| {
+            Box::pin(async move {
+                // Create collection
+                let collection_id = insert_collection(&mut *conn, "Test Collection").await?;
+
+                // Create collection item referencing the provided railway model
+                let collection_item_id = Uuid::new_v4().to_string();
+                sqlite::insert_collection_item(
+                    &mut *conn,
+                    &collection_item_id,
+                    &collection_id,
+                    &railway_model_id,
+                )
                 .await?;
-            owned_rolling_stock_ids.push(owned_rolling_stock_id);
-        }
 
-        let purchase_info_id = self.insert_purchase_info(&collection_item_id).await?;
+                // Attach provided rolling stocks (if any)
+                let mut owned_rolling_stock_ids = Vec::new();
+                for rs_id in &rolling_stock_ids {
+                    let owned_rolling_stock_id = Uuid::new_v4().to_string();
+                    sqlite::insert_owned_rolling_stock(
+                        &mut *conn,
+                        &owned_rolling_stock_id,
+                        &collection_item_id,
+                        rs_id,
+                    )
+                    .await?;
+                    owned_rolling_stock_ids.push(owned_rolling_stock_id);
+                }
 
-        Ok(CollectingTestData {
-            collection_id,
-            collection_item_id,
-            owned_rolling_stock_ids,
-            purchase_info_id,
+                let purchase_info_id =
+                    insert_purchase_info(&mut *conn, &collection_item_id).await?;
+
+                Ok(CollectingTestData {
+                    collection_id,
+                    collection_item_id,
+                    owned_rolling_stock_ids,
+                    purchase_info_id,
+                })
+            })
         })
+        .await
     }
 }
 
+/// Insert the default collection named `name` and return its id.
+async fn insert_collection<'e, E: SqliteExecutor<'e>>(executor: E, name: &str) -> Result<String> {
+    let id = CollectionId::DEFAULT.to_string();
+    let sql = "INSERT INTO collections (id, name, total_value_amount, total_value_currency, created_at, updated_at) VALUES (?1, ?2, 0, 'EUR', ?3, ?3)";
+    sqlx::query(sql)
+        .bind(&id)
+        .bind(name)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| format!("inserting collection id={} name={}", id, name))?;
+    Ok(id)
+}
+
+/// Insert a default purchase_info row for `collection_item_id` and return its id.
+async fn insert_purchase_info<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    collection_item_id: &str,
+) -> Result<String> {
+    let purchase_id = Uuid::new_v4().to_string();
+    let purchase_type = "purchased";
+    let purchase_date = Local::now().format("%Y-%m-%d").to_string();
+    let purchased_price_amount: i64 = 0;
+    let purchased_price_currency: &str = "EUR";
+
+    let sql = "INSERT INTO purchase_infos (purchase_id, collection_item_id, purchase_type, purchase_date, purchased_price_amount, purchased_price_currency, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?7)";
+    sqlx::query(sql)
+        .bind(&purchase_id)
+        .bind(collection_item_id)
+        .bind(purchase_type)
+        .bind(&purchase_date)
+        .bind(purchased_price_amount)
+        .bind(purchased_price_currency)
+        .bind(Utc::now())
+        .execute(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "inserting purchase_info purchase_id={} collection_item_id={}",
+                purchase_id, collection_item_id
+            )
+        })?;
+
+    Ok(purchase_id)
+}
+
 /// Collected ids produced by `CollectingTestDb::setup_minimal_collection`.
 ///
 /// - `collection_id`: id of the created collection
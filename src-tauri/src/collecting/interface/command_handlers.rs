@@ -5,12 +5,77 @@
 //! invocations and map application errors into `CommandError` values suitable
 //! for returning over the IPC boundary.
 
+use crate::catalog::domain::radius::Radius;
+use crate::catalog::infrastructure::cache::RailwayModelCache;
+use crate::catalog::infrastructure::sqlite_repo::SqliteCatalogRepository;
+use crate::collecting::application::add_monetary_adjustment::AddMonetaryAdjustmentUseCase;
+use crate::collecting::application::add_purchase::AddPurchaseUseCase;
+use crate::collecting::application::attach_decoder::AttachDecoderUseCase;
+use crate::collecting::application::bulk_update_items::BulkUpdateItemsUseCase;
+use crate::collecting::application::check_collection_consistency::CheckCollectionConsistencyUseCase;
+use crate::collecting::application::convert_display_currency::ConvertDisplayCurrencyUseCase;
+use crate::collecting::application::create_consist::CreateConsistUseCase;
+use crate::collecting::application::create_decoder::CreateDecoderUseCase;
+use crate::collecting::application::detach_decoder::DetachDecoderUseCase;
+use crate::collecting::application::duplicate_collection_item::DuplicateCollectionItemUseCase;
+use crate::collecting::application::export_insurance_report::ExportInsuranceReportUseCase;
+use crate::collecting::application::find_item_by_number::FindItemByNumberUseCase;
+use crate::collecting::application::generate_year_review::GenerateYearReviewUseCase;
+use crate::collecting::application::get_address_conflicts::GetAddressConflictsUseCase;
 use crate::collecting::application::get_collection::GetCollectionUseCase;
+use crate::collecting::application::get_collection_statistics::GetCollectionStatisticsUseCase;
+use crate::collecting::application::get_item_cost_breakdown::GetItemCostBreakdownUseCase;
+use crate::collecting::application::get_recent_activity::GetRecentActivityUseCase;
+use crate::collecting::application::get_value_history::GetValueHistoryUseCase;
+use crate::collecting::application::list_collection_items::ListCollectionItemsUseCase;
+use crate::collecting::application::list_consists::ListConsistsUseCase;
+use crate::collecting::application::list_decoders::ListDecodersUseCase;
+use crate::collecting::application::parse_quick_add::ParseQuickAddUseCase;
+use crate::collecting::application::recompute_collection::RecomputeCollectionUseCase;
+use crate::collecting::application::record_item_view::RecordItemViewUseCase;
+use crate::collecting::application::take_value_snapshot::TakeValueSnapshotUseCase;
+use crate::collecting::application::update_consist::UpdateConsistUseCase;
+use crate::collecting::application::update_decoder::UpdateDecoderUseCase;
+use crate::collecting::domain::bulk_update::{
+    BulkUpdateError, BulkUpdateOptions, BulkUpdateReport, CollectionItemFilter, CollectionItemPatch,
+};
 use crate::collecting::domain::collection::Collection;
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::collection_item::CollectionItem;
+use crate::collecting::domain::collection_item_id::CollectionItemId;
+use crate::collecting::domain::consist::{ConsistDetails, ConsistError, ConsistPlan};
+use crate::collecting::domain::consistency::ConsistencyReport;
+use crate::collecting::domain::cost_breakdown::ItemCostBreakdown;
+use crate::collecting::domain::decoder::{
+    AddressConflict, Decoder, DecoderDetails, DecoderError, DecoderWarning, SavedDecoder,
+};
+use crate::collecting::domain::display_currency::DisplayCurrencyListing;
+use crate::collecting::domain::insurance_report::{InsuranceReport, ValuationMode};
+use crate::collecting::domain::item_duplication::{DuplicateItemError, DuplicateItemOptions};
+use crate::collecting::domain::monetary_adjustment::{AdjustmentError, MonetaryAdjustment};
+use crate::collecting::domain::purchase_info::{NewPurchase, PreOrderError, PurchasedInfo};
+use crate::collecting::domain::quick_add::{QuickAddError, QuickAddProposal};
+use crate::collecting::domain::recent_activity::ActivityEvent;
+use crate::collecting::domain::recompute_diff::CollectionRecomputeDiff;
+use crate::collecting::domain::statistics::CollectionStatistics;
+use crate::collecting::domain::value_snapshot::ValueSnapshot;
+use crate::collecting::domain::year_review::YearReview;
+use crate::collecting::infrastructure::sqlite_consist_repo::SqliteConsistRepository;
+use crate::collecting::infrastructure::sqlite_decoder_repo::SqliteDecoderRepository;
 use crate::collecting::infrastructure::sqlite_repo::SqliteCollectionRepository;
+use crate::collecting::interface::events::SummaryRecomputed;
+use crate::core::domain::Currency;
+use crate::core::domain::length::Length;
+use crate::core::domain::page::{InvalidCursor, Page};
 use crate::core::infrastructure::error::CommandError;
+use crate::exchange_rates::infrastructure::sqlite_repo::SqliteExchangeRateRepository;
+use crate::settings::infrastructure::sqlite_repo::SqliteSettingsRepository;
 use crate::state::AppState;
+use chrono::NaiveDate;
+use log::error;
+use rust_decimal::Decimal;
 use std::sync::Arc;
+use tauri_specta::Event;
 
 /// Tauri command to retrieve the current collection.
 ///
@@ -21,14 +86,19 @@ use std::sync::Arc;
 ///
 /// Parameters:
 /// - `state`: Tauri-managed application state which provides a database pool.
+/// - `catalog_cache`: the shared cache of railway model summaries.
 ///
 /// Returns:
 /// - `Ok(Collection)` when retrieval succeeds.
 /// - `Err(CommandError)` when the use-case returns an error.
 #[tauri::command]
 #[specta::specta]
-pub async fn get_collection(state: tauri::State<'_, AppState>) -> Result<Collection, CommandError> {
-    let repo = SqliteCollectionRepository::new(state.db_pool());
+pub async fn get_collection(
+    state: tauri::State<'_, AppState>,
+    catalog_cache: tauri::State<'_, RailwayModelCache>,
+) -> Result<Collection, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool())
+        .with_catalog_cache(catalog_cache.inner().clone());
     let use_case = GetCollectionUseCase::new(Arc::new(repo));
 
     match use_case.execute().await {
@@ -37,6 +107,869 @@ pub async fn get_collection(state: tauri::State<'_, AppState>) -> Result<Collect
     }
 }
 
+/// Tauri command to list the collection items one page at a time.
+///
+/// Items are listed in the order they were added. Pass `cursor: null` for
+/// the first page and the returned `next_cursor` for the following ones,
+/// until it is `null`. `limit` is clamped to a sensible maximum.
+///
+/// Parameters:
+/// - `catalog_cache`: the shared cache of railway model summaries.
+///
+/// Returns:
+/// - `Ok(Page<CollectionItem>)` with the items of the page.
+/// - `Err(CommandError::Validation)` when `cursor` is not a cursor returned
+///   by this command.
+/// - `Err(CommandError)` for any other failure.
+#[tauri::command]
+#[specta::specta]
+pub async fn list_collection_items(
+    state: tauri::State<'_, AppState>,
+    catalog_cache: tauri::State<'_, RailwayModelCache>,
+    cursor: Option<String>,
+    limit: u32,
+) -> Result<Page<CollectionItem>, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool())
+        .with_catalog_cache(catalog_cache.inner().clone());
+    let use_case = ListCollectionItemsUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(cursor.as_deref(), limit)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to look a collection item up by its short number.
+///
+/// Parameters:
+/// - `collection_id`: the identifier of the collection.
+/// - `item_number`: the number of the item within the collection.
+/// - `catalog_cache`: the shared cache of railway model summaries.
+///
+/// Returns:
+/// - `Ok(Some(CollectionItem))` with the item.
+/// - `Ok(None)` when the collection has no item with that number, for
+///   example because it was deleted.
+/// - `Err(CommandError)` when reading fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn find_item_by_number(
+    state: tauri::State<'_, AppState>,
+    catalog_cache: tauri::State<'_, RailwayModelCache>,
+    collection_id: CollectionId,
+    item_number: u32,
+) -> Result<Option<CollectionItem>, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool())
+        .with_catalog_cache(catalog_cache.inner().clone());
+    let use_case = FindItemByNumberUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&collection_id, item_number)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to add a copy of a collection item, for a second
+/// identical purchase.
+///
+/// The copy references the same railway model with the same conditions and
+/// notes; the purchase info and storage location are left for the collector
+/// to fill in.
+///
+/// Parameters:
+/// - `item_id`: the identifier of the collection item to copy.
+/// - `options`: whether to copy the owned rolling stocks, and whether to
+///   add the copy when the collection already has the railway model.
+///
+/// Returns:
+/// - `Ok(CollectionItemId)` with the identifier of the copy.
+/// - `Err(CommandError::Validation)` when the collection already has the
+///   railway model and `options.allow_duplicates` is `false`.
+/// - `Err(CommandError)` when the item does not exist or the copy fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn duplicate_collection_item(
+    state: tauri::State<'_, AppState>,
+    item_id: CollectionItemId,
+    options: DuplicateItemOptions,
+) -> Result<CollectionItemId, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = DuplicateCollectionItemUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&item_id, &options)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to change the condition or storage location of every
+/// collection item matching a filter at once.
+///
+/// The items are changed in a single transaction. At most
+/// `BulkUpdateOptions::DEFAULT_LIMIT` items are changed unless
+/// `options.limit` allows more, and with `options.dry_run` nothing is
+/// written: the report tells how many items would change.
+///
+/// Parameters:
+/// - `filter`: the items to change; an empty filter matches every item.
+/// - `patch`: the fields to set, fields left `null` are not changed.
+/// - `options`: whether to only count the items, and the limit to apply.
+///
+/// Returns:
+/// - `Ok(BulkUpdateReport)` with how many items matched and changed.
+/// - `Err(CommandError::Validation)` when the patch is empty or more items
+///   would change than the limit allows.
+/// - `Err(CommandError)` when the storage location does not exist or the
+///   update fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn bulk_update_items(
+    state: tauri::State<'_, AppState>,
+    filter: CollectionItemFilter,
+    patch: CollectionItemPatch,
+    options: BulkUpdateOptions,
+) -> Result<BulkUpdateReport, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = BulkUpdateItemsUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&filter, &patch, &options)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to break down what owning a collection item has cost.
+///
+/// The purchase price (net of its monetary adjustments), the decoders
+/// installed in the item's rolling stocks and the modifications made to
+/// them are listed one by one and totalled per currency; those with an
+/// unknown price are only counted.
+///
+/// Parameters:
+/// - `item_id`: the identifier of the collection item.
+///
+/// Returns:
+/// - `Ok(ItemCostBreakdown)` with the costs and their totals.
+/// - `Err(CommandError::Validation)` when an adjustment does not match the
+///   purchase currency.
+/// - `Err(CommandError)` when the item does not exist or reading fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_item_cost_breakdown(
+    state: tauri::State<'_, AppState>,
+    item_id: CollectionItemId,
+) -> Result<ItemCostBreakdown, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = GetItemCostBreakdownUseCase::new(Arc::new(repo));
+
+    use_case.execute(&item_id).await.map_err(to_command_error)
+}
+
+/// Tauri command to record that the collector opened a collection item.
+///
+/// Only the latest views of each item are kept; older ones are pruned as
+/// the new view is recorded.
+///
+/// Parameters:
+/// - `item_id`: the identifier of the collection item.
+///
+/// Returns:
+/// - `Ok(())` when the view is recorded.
+/// - `Err(CommandError)` when the item does not exist or writing fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn record_item_view(
+    state: tauri::State<'_, AppState>,
+    item_id: CollectionItemId,
+) -> Result<(), CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = RecordItemViewUseCase::new(Arc::new(repo));
+
+    use_case.execute(&item_id).await.map_err(to_command_error)
+}
+
+/// Tauri command to list the latest items added, modified and viewed, for
+/// the home screen.
+///
+/// Each item is listed at most once per kind of event, at its latest one,
+/// and the events are sorted newest first.
+///
+/// Parameters:
+/// - `limit`: how many events to return at most.
+///
+/// Returns:
+/// - `Ok(Vec<ActivityEvent>)` with the events tagged by their kind.
+/// - `Err(CommandError)` when reading fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_recent_activity(
+    state: tauri::State<'_, AppState>,
+    limit: u32,
+) -> Result<Vec<ActivityEvent>, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = GetRecentActivityUseCase::new(Arc::new(repo));
+
+    use_case.execute(limit).await.map_err(to_command_error)
+}
+
+/// Tauri command to parse a command palette quick-add entry, such as
+/// `acme 60211 2x €129.90 2024-03-15`, into a collection item draft.
+///
+/// The model is looked up in the catalog by its product code; when several
+/// models match the candidates are returned for the collector to pick one.
+/// Nothing is stored.
+///
+/// Returns:
+/// - `Ok(QuickAddProposal)` with the draft and the matching models.
+/// - `Err(CommandError::InvalidField)` when the entry cannot be parsed.
+#[tauri::command]
+#[specta::specta]
+pub async fn parse_quick_add(
+    state: tauri::State<'_, AppState>,
+    input: String,
+) -> Result<QuickAddProposal, CommandError> {
+    let repo = SqliteCatalogRepository::new(state.db_pool());
+    let use_case = ParseQuickAddUseCase::new(Arc::new(repo));
+
+    use_case.execute(&input).await.map_err(to_command_error)
+}
+
+/// Tauri command to recompute a collection's summary counters and total value.
+///
+/// The counters and totals are recalculated from the collection items in a
+/// single transaction and stored. On success a `SummaryRecomputed` event is
+/// emitted with the same diff that is returned, so other views can refresh.
+///
+/// The counters are kept up to date by database triggers as items change, so
+/// this is a repair tool for counters that drifted (for example after the
+/// database was edited by hand); the total value is only updated here and
+/// by `add_monetary_adjustment`.
+///
+/// Parameters:
+/// - `id`: the identifier of the collection to recompute.
+///
+/// Returns:
+/// - `Ok(CollectionRecomputeDiff)` with the old and new values.
+/// - `Err(CommandError)` when the collection does not exist or the
+///   recomputation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn recompute_collection(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    id: CollectionId,
+) -> Result<CollectionRecomputeDiff, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = RecomputeCollectionUseCase::new(Arc::new(repo));
+
+    let diff = use_case
+        .execute(&id)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))?;
+
+    if let Err(e) = SummaryRecomputed(diff.clone()).emit(&app) {
+        error!("Failed to emit SummaryRecomputed event: {e}");
+    }
+
+    Ok(diff)
+}
+
+/// Tauri command to check a collection against the catalog it refers to.
+///
+/// The report lists the owned rolling stocks that refer to rolling stocks
+/// missing from the catalog, the summary counters that no longer match the
+/// categories of the items' railway models and the purchase infos with
+/// unsupported currencies, each with the action that repairs it.
+///
+/// Parameters:
+/// - `id`: the identifier of the collection to check.
+/// - `repair`: whether to apply the safe repairs, in a single transaction;
+///   unsupported currencies are only reported.
+///
+/// Returns:
+/// - `Ok(ConsistencyReport)` describing the collection before any repair.
+/// - `Err(CommandError)` when the collection does not exist or the check
+///   fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn check_collection_consistency(
+    state: tauri::State<'_, AppState>,
+    id: CollectionId,
+    repair: bool,
+) -> Result<ConsistencyReport, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = CheckCollectionConsistencyUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&id, repair)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to compute a collection's value and realized gains.
+///
+/// Sold items are left out of the value and count in the realized gains
+/// (sale price minus what they cost) instead. Unlike the stored total value,
+/// the figures are computed from the collection items on every call.
+///
+/// Parameters:
+/// - `id`: the identifier of the collection.
+///
+/// Returns:
+/// - `Ok(CollectionStatistics)` with one entry per currency.
+/// - `Err(CommandError)` when the collection does not exist or the
+///   computation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_collection_statistics(
+    state: tauri::State<'_, AppState>,
+    id: CollectionId,
+) -> Result<CollectionStatistics, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = GetCollectionStatisticsUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&id)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to summarize what was bought and sold in a collection
+/// during a calendar year.
+///
+/// Pre-ordered items are left out of the purchases; costs are net of their
+/// monetary adjustments. A year with no purchases nor sales returns zeroed
+/// figures.
+///
+/// Parameters:
+/// - `id`: the identifier of the collection.
+/// - `year`: the calendar year to review.
+///
+/// Returns:
+/// - `Ok(YearReview)` with the monetary figures given per currency.
+/// - `Err(CommandError)` when the collection does not exist or the
+///   computation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn generate_year_review(
+    state: tauri::State<'_, AppState>,
+    id: CollectionId,
+    year: i32,
+) -> Result<YearReview, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = GenerateYearReviewUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&id, year)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to list a collection's items for its insurer.
+///
+/// Each item that is not sold is listed with its catalog description,
+/// product code and road numbers, its purchase date and price and the
+/// replacement value entered for it, and the report ends with the insured
+/// values totalled per currency.
+///
+/// Parameters:
+/// - `id`: the identifier of the collection.
+/// - `valuation`: whether items are insured at their purchase price or at
+///   their replacement value (falling back to the purchase price).
+///
+/// Returns:
+/// - `Ok(InsuranceReport)` with the items ordered by manufacturer and
+///   product code.
+/// - `Err(CommandError)` when the collection does not exist or the report
+///   cannot be computed.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_insurance_report(
+    state: tauri::State<'_, AppState>,
+    id: CollectionId,
+    valuation: ValuationMode,
+) -> Result<InsuranceReport, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = ExportInsuranceReportUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&id, valuation)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to record the current value of a collection for charting.
+///
+/// A collection has at most one snapshot per day: taking it again on the
+/// same day replaces the values recorded earlier. Snapshots are also taken
+/// automatically after the demo data is imported.
+///
+/// Parameters:
+/// - `id`: the identifier of the collection.
+///
+/// Returns:
+/// - `Ok(ValueSnapshot)` with today's values, one entry per currency.
+/// - `Err(CommandError)` when the collection does not exist or the snapshot
+///   cannot be stored.
+#[tauri::command]
+#[specta::specta]
+pub async fn take_value_snapshot(
+    state: tauri::State<'_, AppState>,
+    id: CollectionId,
+) -> Result<ValueSnapshot, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = TakeValueSnapshotUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&id)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to list how the value of a collection changed over time.
+///
+/// Parameters:
+/// - `id`: the identifier of the collection.
+/// - `from`, `to`: the first and last day of the range, both included.
+///
+/// Returns:
+/// - `Ok(Vec<ValueSnapshot>)` with the snapshots taken in the range, oldest
+///   first; empty when none was taken.
+/// - `Err(CommandError)` when the snapshots cannot be read.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_value_history(
+    state: tauri::State<'_, AppState>,
+    id: CollectionId,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<ValueSnapshot>, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = GetValueHistoryUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&id, from, to)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to record the purchase of a collection item.
+///
+/// `purchase.price` is in the smallest unit of `purchase.currency`; when no
+/// currency is given the preferred currency of the settings is used. The
+/// collection total value is not updated (see `recompute_collection`).
+///
+/// Parameters:
+/// - `item_id`: the identifier of the purchased collection item.
+///
+/// Returns:
+/// - `Ok(PurchasedInfo)` with the stored purchase.
+/// - `Err(CommandError)` when the item does not exist or already has a
+///   purchase info.
+#[tauri::command]
+#[specta::specta]
+pub async fn add_purchase(
+    state: tauri::State<'_, AppState>,
+    item_id: CollectionItemId,
+    purchase: NewPurchase,
+) -> Result<PurchasedInfo, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let settings = SqliteSettingsRepository::new(state.db_pool());
+    let use_case = AddPurchaseUseCase::new(Arc::new(repo), Arc::new(settings));
+
+    use_case
+        .execute(&item_id, purchase)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to list the amounts of every collection item converted
+/// into a display currency.
+///
+/// The stored amounts are returned unchanged next to their converted values,
+/// which use the stored exchange rates; nothing is written.
+///
+/// Parameters:
+/// - `id`: the identifier of the collection.
+/// - `display_currency`: the currency to display, or `null` for the
+///   preferred currency of the settings.
+///
+/// Returns:
+/// - `Ok(DisplayCurrencyListing)` with the stored and display amounts, and
+///   the currencies with no exchange rate into the display currency.
+/// - `Err(CommandError)` when the amounts cannot be loaded.
+#[tauri::command]
+#[specta::specta]
+pub async fn convert_display_currency(
+    state: tauri::State<'_, AppState>,
+    id: CollectionId,
+    display_currency: Option<Currency>,
+) -> Result<DisplayCurrencyListing, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let exchange_rates = SqliteExchangeRateRepository::new(state.db_pool());
+    let settings = SqliteSettingsRepository::new(state.db_pool());
+    let use_case = ConvertDisplayCurrencyUseCase::new(
+        Arc::new(repo),
+        Arc::new(exchange_rates),
+        Arc::new(settings),
+    );
+
+    use_case
+        .execute(&id, display_currency)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to record a refund or other adjustment of a purchase.
+///
+/// The signed `amount` (in the smallest currency unit, negative for a
+/// refund) is netted out of the purchase cost, keeping the original price.
+/// The collection total value is then recomputed and a `SummaryRecomputed`
+/// event is emitted with the diff.
+///
+/// Parameters:
+/// - `purchase_id`: the identifier of the adjusted purchase info.
+/// - `currency`: must be the currency of the purchase price.
+/// - `reason`: why the cost was adjusted.
+///
+/// Returns:
+/// - `Ok(MonetaryAdjustment)` with the stored adjustment.
+/// - `Err(CommandError::Validation)` when the purchase has no price, the
+///   currency differs or the adjustment would bring the cost below zero.
+/// - `Err(CommandError)` for any other failure.
+#[tauri::command]
+#[specta::specta]
+pub async fn add_monetary_adjustment(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    purchase_id: String,
+    amount: i64,
+    currency: Currency,
+    date: NaiveDate,
+    reason: String,
+) -> Result<MonetaryAdjustment, CommandError> {
+    let repo = SqliteCollectionRepository::new(state.db_pool());
+    let use_case = AddMonetaryAdjustmentUseCase::new(Arc::new(repo));
+
+    let (adjustment, diff) = use_case
+        .execute(&purchase_id, amount, currency, date, &reason)
+        .await
+        .map_err(to_command_error)?;
+
+    if let Err(e) = SummaryRecomputed(diff).emit(&app) {
+        error!("Failed to emit SummaryRecomputed event: {e}");
+    }
+
+    Ok(adjustment)
+}
+
+/// Tauri command to list the decoders in the inventory.
+///
+/// Returns:
+/// - `Ok(Vec<Decoder>)` ordered by manufacturer and model.
+/// - `Err(CommandError)` when the decoders cannot be read.
+#[tauri::command]
+#[specta::specta]
+pub async fn list_decoders(
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<Decoder>, CommandError> {
+    let repo = SqliteDecoderRepository::new(state.db_pool());
+    let use_case = ListDecodersUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute()
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to add a decoder to the inventory.
+///
+/// A decoder on an address other decoders already use is stored all the
+/// same, with a warning.
+///
+/// Parameters:
+/// - `decoder`: the decoder details; the text fields are trimmed.
+///
+/// Returns:
+/// - `Ok(SavedDecoder)` with the stored decoder, its generated id and the
+///   warnings.
+/// - `Err(CommandError::Validation)` when the address is outside the DCC
+///   range 1-10239.
+/// - `Err(CommandError)` when the decoder cannot be stored.
+#[tauri::command]
+#[specta::specta]
+pub async fn create_decoder(
+    state: tauri::State<'_, AppState>,
+    decoder: DecoderDetails,
+) -> Result<SavedDecoder, CommandError> {
+    let repo = SqliteDecoderRepository::new(state.db_pool());
+    let use_case = CreateDecoderUseCase::new(Arc::new(repo));
+
+    use_case.execute(decoder).await.map_err(to_command_error)
+}
+
+/// Tauri command to change the details of a decoder.
+///
+/// The address is checked as by `create_decoder`.
+///
+/// Parameters:
+/// - `id`: the identifier of the decoder.
+/// - `decoder`: the new decoder details, replacing all the stored ones.
+///
+/// Returns:
+/// - `Ok(SavedDecoder)` with the stored decoder and the warnings.
+/// - `Err(CommandError::Validation)` when the address is outside the DCC
+///   range.
+/// - `Err(CommandError)` when the decoder does not exist or cannot be
+///   stored.
+#[tauri::command]
+#[specta::specta]
+pub async fn update_decoder(
+    state: tauri::State<'_, AppState>,
+    id: String,
+    decoder: DecoderDetails,
+) -> Result<SavedDecoder, CommandError> {
+    let repo = SqliteDecoderRepository::new(state.db_pool());
+    let use_case = UpdateDecoderUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&id, decoder)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to find the owned rolling stocks whose decoders share a
+/// DCC address, so they would respond to each other's commands.
+///
+/// Returns:
+/// - `Ok(Vec<AddressConflict>)` with one entry per shared address, ordered
+///   by address; empty when there is none.
+/// - `Err(CommandError)` when the decoders cannot be read.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_address_conflicts(
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<AddressConflict>, CommandError> {
+    let repo = SqliteDecoderRepository::new(state.db_pool());
+    let use_case = GetAddressConflictsUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute()
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to install a decoder in an owned rolling stock.
+///
+/// The decoder installed there before, if any, is uninstalled. A decoder
+/// whose connector differs from the DCC interface the catalog stores for the
+/// rolling stock is installed all the same, with a warning.
+///
+/// Parameters:
+/// - `owned_rolling_stock_id`: the identifier of the owned rolling stock.
+/// - `decoder_id`: the identifier of the decoder.
+///
+/// Returns:
+/// - `Ok(Vec<DecoderWarning>)` listing what does not fit; empty when
+///   nothing.
+/// - `Err(CommandError::Validation)` when the decoder is installed in
+///   another rolling stock.
+/// - `Err(CommandError)` when either does not exist or the change cannot be
+///   stored.
+#[tauri::command]
+#[specta::specta]
+pub async fn attach_decoder(
+    state: tauri::State<'_, AppState>,
+    owned_rolling_stock_id: String,
+    decoder_id: String,
+) -> Result<Vec<DecoderWarning>, CommandError> {
+    let repo = SqliteDecoderRepository::new(state.db_pool());
+    let use_case = AttachDecoderUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&owned_rolling_stock_id, &decoder_id)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to remove the decoder installed in an owned rolling stock.
+///
+/// The decoder stays in the inventory.
+///
+/// Parameters:
+/// - `owned_rolling_stock_id`: the identifier of the owned rolling stock.
+///
+/// Returns:
+/// - `Ok(Some(id))` with the identifier of the removed decoder, `Ok(None)`
+///   when none was installed.
+/// - `Err(CommandError)` when the owned rolling stock does not exist or the
+///   change cannot be stored.
+#[tauri::command]
+#[specta::specta]
+pub async fn detach_decoder(
+    state: tauri::State<'_, AppState>,
+    owned_rolling_stock_id: String,
+) -> Result<Option<String>, CommandError> {
+    let repo = SqliteDecoderRepository::new(state.db_pool());
+    let use_case = DetachDecoderUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&owned_rolling_stock_id)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to list the consists, checked against the layout.
+///
+/// Parameters:
+/// - `longest_siding_mm`: the length of the longest siding in millimeters,
+///   to tell which trains fit on it.
+/// - `layout_radius_mm`: the radius of the tightest curve of the layout in
+///   millimeters, to warn about vehicles needing wider curves.
+///
+/// Returns:
+/// - `Ok(Vec<ConsistPlan>)` ordered by name.
+/// - `Err(CommandError::InvalidField)` when a layout measure is not a
+///   positive length.
+/// - `Err(CommandError)` when the consists cannot be read.
+#[tauri::command]
+#[specta::specta]
+pub async fn list_consists(
+    state: tauri::State<'_, AppState>,
+    longest_siding_mm: Option<f64>,
+    layout_radius_mm: Option<f64>,
+) -> Result<Vec<ConsistPlan>, CommandError> {
+    let (longest_siding, layout_radius) = layout(longest_siding_mm, layout_radius_mm)?;
+    let repo = SqliteConsistRepository::new(state.db_pool());
+    let use_case = ListConsistsUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(longest_siding, layout_radius)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to assemble a consist from owned rolling stocks.
+///
+/// Parameters:
+/// - `consist`: the name and the vehicles, from head to tail.
+/// - `longest_siding_mm`, `layout_radius_mm`: the layout to check the
+///   consist against, as for `list_consists`.
+///
+/// Returns:
+/// - `Ok(ConsistPlan)` with the stored consist and its generated id.
+/// - `Err(CommandError::Validation)` when the name is blank or a vehicle
+///   appears twice.
+/// - `Err(CommandError)` when an owned rolling stock does not exist or the
+///   consist cannot be stored.
+#[tauri::command]
+#[specta::specta]
+pub async fn create_consist(
+    state: tauri::State<'_, AppState>,
+    consist: ConsistDetails,
+    longest_siding_mm: Option<f64>,
+    layout_radius_mm: Option<f64>,
+) -> Result<ConsistPlan, CommandError> {
+    let (longest_siding, layout_radius) = layout(longest_siding_mm, layout_radius_mm)?;
+    let repo = SqliteConsistRepository::new(state.db_pool());
+    let use_case = CreateConsistUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(consist, longest_siding, layout_radius)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to rename a consist or change its vehicles.
+///
+/// Parameters:
+/// - `id`: the identifier of the consist.
+/// - `consist`: the new name and vehicles, replacing the stored ones.
+/// - `longest_siding_mm`, `layout_radius_mm`: the layout to check the
+///   consist against, as for `list_consists`.
+///
+/// Returns:
+/// - `Ok(ConsistPlan)` with the stored consist.
+/// - `Err(CommandError::Validation)` when the name is blank or a vehicle
+///   appears twice.
+/// - `Err(CommandError)` when the consist or an owned rolling stock does not
+///   exist, or the consist cannot be stored.
+#[tauri::command]
+#[specta::specta]
+pub async fn update_consist(
+    state: tauri::State<'_, AppState>,
+    id: String,
+    consist: ConsistDetails,
+    longest_siding_mm: Option<f64>,
+    layout_radius_mm: Option<f64>,
+) -> Result<ConsistPlan, CommandError> {
+    let (longest_siding, layout_radius) = layout(longest_siding_mm, layout_radius_mm)?;
+    let repo = SqliteConsistRepository::new(state.db_pool());
+    let use_case = UpdateConsistUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(&id, consist, longest_siding, layout_radius)
+        .await
+        .map_err(to_command_error)
+}
+
+/// The layout measures the consist commands receive in millimeters.
+fn layout(
+    longest_siding_mm: Option<f64>,
+    layout_radius_mm: Option<f64>,
+) -> Result<(Option<Length>, Option<Radius>), CommandError> {
+    let millimeters = |field: &str, value: f64| {
+        Decimal::try_from(value)
+            .ok()
+            .filter(|mm| mm.is_sign_positive() && !mm.is_zero())
+            .ok_or_else(|| CommandError::InvalidField {
+                field: field.to_string(),
+                message: format!("{} is not a positive length in millimeters", value),
+            })
+    };
+    let longest_siding = longest_siding_mm
+        .map(|mm| millimeters("longest_siding_mm", mm).map(Length::Millimeters))
+        .transpose()?;
+    let layout_radius = layout_radius_mm
+        .map(|mm| {
+            millimeters("layout_radius_mm", mm).and_then(|mm| {
+                Radius::from_millimeters(mm).map_err(|e| CommandError::InvalidField {
+                    field: "layout_radius_mm".to_string(),
+                    message: e.to_string(),
+                })
+            })
+        })
+        .transpose()?;
+    Ok((longest_siding, layout_radius))
+}
+
+/// Map a rejected page cursor, monetary adjustment, decoder installation or
+/// consist to `CommandError::Validation`, an inconsistent preorder to
+/// `InvalidField`, anything else to `Unknown`.
+fn to_command_error(e: anyhow::Error) -> CommandError {
+    if let Some(preorder_error) = e.downcast_ref::<PreOrderError>() {
+        CommandError::InvalidField {
+            field: preorder_error.field().to_string(),
+            message: preorder_error.to_string(),
+        }
+    } else if let Some(quick_add_error) = e.downcast_ref::<QuickAddError>() {
+        CommandError::InvalidField {
+            field: quick_add_error.field().to_string(),
+            message: quick_add_error.to_string(),
+        }
+    } else if e.downcast_ref::<InvalidCursor>().is_some()
+        || e.downcast_ref::<AdjustmentError>().is_some()
+        || e.downcast_ref::<DecoderError>().is_some()
+        || e.downcast_ref::<ConsistError>().is_some()
+        || e.downcast_ref::<DuplicateItemError>().is_some()
+        || e.downcast_ref::<BulkUpdateError>().is_some()
+    {
+        CommandError::Validation(e.to_string())
+    } else {
+        CommandError::Unknown(e.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -58,4 +991,46 @@ mod tests {
         assert_eq!(found_collection.name, "My Collection");
         assert_eq!(found_collection.items.len(), 0);
     }
+
+    #[test]
+    fn inconsistent_preorders_are_field_errors() {
+        let e = anyhow::Error::new(PreOrderError::DepositExceedsTotal {
+            deposit: 1500,
+            total_price: 1000,
+        })
+        .context("updating purchase_info purchase_id=pre1");
+
+        match to_command_error(e) {
+            CommandError::InvalidField { field, message } => {
+                assert_eq!(field, "deposit");
+                assert_eq!(
+                    message,
+                    "the deposit of 1500 exceeds the total price of 1000"
+                );
+            }
+            other => panic!("unexpected error {other:?}"),
+        }
+    }
+
+    #[test]
+    fn layout_measures_must_be_positive_lengths() {
+        let (siding, radius) = layout(Some(1200.0), Some(360.0)).unwrap();
+        assert_eq!(
+            siding.map(|s| s.as_millimeters()),
+            Some(Decimal::from(1200))
+        );
+        assert_eq!(radius, Some(Radius::H0_R1));
+        assert!(matches!(layout(None, None), Ok((None, None))));
+
+        for (siding, radius, expected) in [
+            (Some(0.0), None, "longest_siding_mm"),
+            (None, Some(-360.0), "layout_radius_mm"),
+            (Some(f64::NAN), None, "longest_siding_mm"),
+        ] {
+            match layout(siding, radius) {
+                Err(CommandError::InvalidField { field, .. }) => assert_eq!(field, expected),
+                other => panic!("unexpected result {other:?}"),
+            }
+        }
+    }
 }
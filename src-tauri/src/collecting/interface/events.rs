@@ -0,0 +1,9 @@
+//! Events emitted to the Tauri frontend by the `collecting` feature.
+
+use crate::collecting::domain::recompute_diff::CollectionRecomputeDiff;
+use serde::{Deserialize, Serialize};
+
+/// Emitted after a collection's summary counters and total value have been
+/// recomputed, carrying what changed.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type, tauri_specta::Event)]
+pub struct SummaryRecomputed(pub CollectionRecomputeDiff);
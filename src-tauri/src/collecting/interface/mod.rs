@@ -1 +1,2 @@
 pub mod command_handlers;
+pub mod events;
@@ -11,7 +11,7 @@ use serde::{Deserialize, Serialize};
 /// The enum uses a small, explicit set of currencies for now. Use
 /// `Currency::from_code` to obtain a `Currency` value from an ISO-style
 /// currency code (case-insensitive).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, specta::Type)]
 pub enum Currency {
     /// Euro
     EUR,
@@ -24,6 +24,9 @@ pub enum Currency {
 }
 
 impl Currency {
+    /// Every supported currency.
+    pub const ALL: [Currency; 4] = [Currency::EUR, Currency::USD, Currency::GBP, Currency::JPY];
+
     /// Parse an ISO-style currency code (case-insensitive) into a `Currency`.
     ///
     /// Returns `Ok(Currency)` for known codes (`"EUR"`, `"USD"`, `"GBP"`,
@@ -46,6 +49,29 @@ impl Currency {
         }
     }
 
+    /// Return the ISO 4217 code for this currency (for example `"EUR"`).
+    ///
+    /// This is the inverse of `Currency::from_code`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::EUR => "EUR",
+            Currency::USD => "USD",
+            Currency::GBP => "GBP",
+            Currency::JPY => "JPY",
+        }
+    }
+
+    /// Return the number of decimal places of the currency's smallest unit.
+    ///
+    /// `MonetaryAmount` stores amounts in this unit: cents (2) for EUR, USD
+    /// and GBP, whole yen (0) for JPY.
+    pub fn decimal_places(&self) -> u32 {
+        match self {
+            Currency::EUR | Currency::USD | Currency::GBP => 2,
+            Currency::JPY => 0,
+        }
+    }
+
     /// Return the Unicode symbol commonly used for this currency.
     ///
     /// Note: this is a simple helper for UI formatting; for full localization
@@ -72,6 +98,13 @@ mod tests {
         assert_eq!(Currency::from_code("JPY").unwrap(), Currency::JPY);
     }
 
+    #[test]
+    fn currency_code_round_trips() {
+        for currency in Currency::ALL {
+            assert_eq!(Currency::from_code(currency.code()).unwrap(), currency);
+        }
+    }
+
     #[test]
     fn currency_from_code_err() {
         assert!(Currency::from_code("ABC").is_err());
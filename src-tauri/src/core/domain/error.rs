@@ -15,6 +15,10 @@ pub enum Error {
     #[error("Cannot add MonetaryAmount with different currencies")]
     CurrencyMismatch,
 
+    /// Text that is not an amount with its currency.
+    #[error("Invalid monetary amount: {0}")]
+    InvalidAmount(String),
+
     /// Arithmetic overflow while adding monetary amounts.
     #[error("Monetary amount overflow when adding")]
     Overflow,
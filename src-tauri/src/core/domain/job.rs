@@ -0,0 +1,125 @@
+//! Long running maintenance jobs and their status.
+//!
+//! Jobs run in the background (see `JobRunner`), so commands return straight
+//! away and the UI polls `Job` to show what is going on.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Identifier for a job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, specta::Type)]
+#[serde(transparent)]
+#[specta(transparent)]
+pub struct JobId(pub Uuid);
+
+impl Default for JobId {
+    /// Generate a new `JobId` with a random v4 UUID.
+    fn default() -> Self {
+        JobId(Uuid::new_v4())
+    }
+}
+
+impl fmt::Display for JobId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// What a job does; at most one job of each kind runs at a time.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum JobKind {
+    /// Scan the database for corruption and orphaned rows, without repairing
+    /// them.
+    IntegrityCheck,
+    /// Record today's value of the default collection.
+    ValueSnapshot,
+}
+
+/// Where a job is in its lifecycle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum JobStatus {
+    /// The job was started but did not run yet.
+    Queued,
+    /// The job is running.
+    Running,
+    /// The job completed.
+    Succeeded,
+    /// The job stopped with an error (see `Job::error`).
+    Failed,
+    /// The job was cancelled before it completed.
+    Cancelled,
+}
+
+impl JobStatus {
+    /// Whether the job is over, one way or another.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            JobStatus::Succeeded | JobStatus::Failed | JobStatus::Cancelled
+        )
+    }
+}
+
+/// How far a running job got, as reported by the job itself.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct JobProgress {
+    /// The units of work done so far.
+    pub done: u32,
+
+    /// The units of work in total.
+    pub total: u32,
+}
+
+/// A job started by the `JobRunner`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct Job {
+    /// The job identifier.
+    pub id: JobId,
+
+    /// What the job does.
+    pub kind: JobKind,
+
+    /// Where the job is in its lifecycle.
+    pub status: JobStatus,
+
+    /// Why the job failed, for `JobStatus::Failed`.
+    pub error: Option<String>,
+
+    /// The last progress reported, if any.
+    pub progress: Option<JobProgress>,
+
+    /// When the job was started.
+    pub queued_at: DateTime<Utc>,
+
+    /// When the job finished, if it did.
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// Why a job cannot be started or cancelled.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum JobError {
+    #[error("a {0:?} job is already running")]
+    AlreadyRunning(JobKind),
+
+    #[error("job not found id={0}")]
+    NotFound(JobId),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_completed_failed_and_cancelled_jobs_are_finished() {
+        assert!(!JobStatus::Queued.is_finished());
+        assert!(!JobStatus::Running.is_finished());
+        assert!(JobStatus::Succeeded.is_finished());
+        assert!(JobStatus::Failed.is_finished());
+        assert!(JobStatus::Cancelled.is_finished());
+    }
+}
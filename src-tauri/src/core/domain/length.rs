@@ -11,12 +11,16 @@
 //!   invalid input.
 //! - Conversions between units are available via `get_value_as` which
 //!   uses the `MeasureUnit` conversion utilities.
+//! - Equality and ordering compare the millimeter forms of the two lengths,
+//!   with the `MeasureUnit::same_as` tolerance, so they do not depend on
+//!   which side is converted.
 //! - The module also provides `serde` helpers to (de)serialize `Length`
 //!   and optional `Length` values in a unit-aware way.
 
 use crate::core::domain::measure_units::MeasureUnit;
 use ::serde::{Deserialize, Serialize};
 use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
 use std::cmp::Ordering;
 use std::fmt::Formatter;
 use std::{cmp, fmt, ops};
@@ -30,8 +34,11 @@ use thiserror::Error;
 /// Invariants:
 /// - Quantities must be non-negative. Use `Length::try_new` to validate
 ///   input without panicking.
-/// - Equality and ordering compare values after converting to the left-hand
-///   side's unit (so comparisons are unit-agnostic but deterministic).
+/// - Equality and ordering compare both values converted to millimeters.
+///   Lengths less than `LENGTH_TOLERANCE_MM` apart are equal, which absorbs
+///   the rounding of the conversion factors: `1 in == 25.4 mm` holds either
+///   way round. The tolerance makes equality non-transitive for values
+///   right at its edge.
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, specta::Type)]
 pub enum Length {
     /// A length expressed in inches.
@@ -46,6 +53,10 @@ pub enum Length {
     Millimeters(Decimal),
 }
 
+/// How far apart, in millimeters, two lengths can be and still compare
+/// equal; the tolerance of `MeasureUnit::same_as`.
+pub const LENGTH_TOLERANCE_MM: Decimal = dec!(0.01);
+
 #[derive(Debug, PartialEq, Error)]
 pub enum LengthError {
     #[error("invalid length value")]
@@ -112,6 +123,26 @@ impl Length {
                 .convert(self.quantity())
         }
     }
+
+    /// Returns this `Length` converted to `measure_unit`.
+    ///
+    /// Unlike `get_value_as`, which only pairs units of the same system, the
+    /// conversion goes through millimeters, so any two units convert.
+    pub fn convert_to(&self, measure_unit: MeasureUnit) -> Length {
+        if self.measure_unit() == measure_unit {
+            *self
+        } else {
+            Length::new(
+                measure_unit.from_millimeters(self.as_millimeters()),
+                measure_unit,
+            )
+        }
+    }
+
+    /// Returns this `Length` in millimeters, the form used to compare lengths
+    pub fn as_millimeters(&self) -> Decimal {
+        self.measure_unit().to_millimeters(self.quantity())
+    }
 }
 
 impl Default for Length {
@@ -141,9 +172,11 @@ impl ops::Add for Length {
 
 impl cmp::PartialEq for Length {
     fn eq(&self, other: &Self) -> bool {
-        let value1 = self.quantity();
-        let value2 = other.get_value_as(self.measure_unit());
-        value1 == value2
+        MeasureUnit::Millimeters.same_as(
+            self.as_millimeters(),
+            MeasureUnit::Millimeters,
+            other.as_millimeters(),
+        )
     }
 }
 
@@ -151,9 +184,11 @@ impl cmp::Eq for Length {}
 
 impl cmp::PartialOrd for Length {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let value1 = self.quantity();
-        let value2 = other.get_value_as(self.measure_unit());
-        value1.partial_cmp(&value2)
+        if self == other {
+            Some(Ordering::Equal)
+        } else {
+            self.as_millimeters().partial_cmp(&other.as_millimeters())
+        }
     }
 }
 
@@ -473,6 +508,102 @@ mod test {
             assert!(l2 > l1);
             assert!(l3 > l1);
         }
+
+        #[test]
+        fn it_should_compare_lengths_in_different_units_both_ways() {
+            let inch = Length::new(dec!(1.0), MeasureUnit::Inches);
+            let millimeters = Length::new(dec!(25.4), MeasureUnit::Millimeters);
+
+            assert_eq!(inch, millimeters);
+            assert_eq!(millimeters, inch);
+            assert_eq!(Some(Ordering::Equal), millimeters.partial_cmp(&inch));
+        }
+
+        #[test]
+        fn it_should_compare_lengths_across_measure_systems() {
+            let meters = Length::new(dec!(1609.34), MeasureUnit::Meters);
+            let mile = Length::new(dec!(1.0), MeasureUnit::Miles);
+            let kilometer = Length::new(dec!(1.0), MeasureUnit::Kilometers);
+
+            assert_eq!(meters, mile);
+            assert!(kilometer < mile);
+            assert!(Length::new(dec!(1.0), MeasureUnit::Inches) < meters);
+        }
+
+        #[test]
+        fn it_should_convert_lengths_across_measure_systems() {
+            let mile = Length::new(dec!(1.0), MeasureUnit::Miles);
+
+            let meters = mile.convert_to(MeasureUnit::Meters);
+            assert_eq!(MeasureUnit::Meters, meters.measure_unit());
+            assert_eq!(dec!(1609.34), meters.quantity());
+
+            let inches =
+                Length::new(dec!(1.0), MeasureUnit::Meters).convert_to(MeasureUnit::Inches);
+            assert_eq!(MeasureUnit::Inches, inches.measure_unit());
+            assert_eq!(dec!(39.37), inches.quantity().round_dp(2));
+            assert_eq!(mile, mile.convert_to(MeasureUnit::Miles));
+        }
+
+        const UNITS: [MeasureUnit; 5] = [
+            MeasureUnit::Millimeters,
+            MeasureUnit::Inches,
+            MeasureUnit::Meters,
+            MeasureUnit::Miles,
+            MeasureUnit::Kilometers,
+        ];
+
+        /// Lengths of every unit, including pairs that are equal only after
+        /// a rounded conversion.
+        fn length_grid() -> Vec<Length> {
+            let quantities = [
+                dec!(0),
+                dec!(0.001),
+                dec!(0.005),
+                dec!(0.5),
+                dec!(1),
+                dec!(1.5),
+                dec!(10),
+                dec!(25.4),
+                dec!(39.3701),
+                dec!(100),
+                dec!(1000),
+                dec!(1234.56),
+                dec!(1609.34),
+            ];
+            UNITS
+                .iter()
+                .flat_map(|&unit| quantities.iter().map(move |&q| Length::new(q, unit)))
+                .collect()
+        }
+
+        #[test]
+        fn equality_is_symmetric() {
+            let grid = length_grid();
+            for a in &grid {
+                for b in &grid {
+                    assert_eq!(a == b, b == a, "{a} == {b} differs from {b} == {a}");
+                }
+            }
+        }
+
+        #[test]
+        fn ordering_is_consistent_with_equality() {
+            let grid = length_grid();
+            for a in &grid {
+                for b in &grid {
+                    let ordering = a.partial_cmp(b);
+                    assert!(ordering.is_some(), "{a} and {b} are not comparable");
+                    assert_eq!(a == b, ordering == Some(Ordering::Equal), "{a} vs {b}");
+                    assert_eq!(
+                        ordering.map(Ordering::reverse),
+                        b.partial_cmp(a),
+                        "{a} vs {b}"
+                    );
+                    assert_eq!(a < b, b > a, "{a} < {b}");
+                }
+            }
+        }
     }
 
     mod serde {
@@ -5,6 +5,7 @@ use rust_decimal_macros::*;
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::Formatter;
+use strum_macros::{EnumString, IntoStaticStr};
 
 #[derive(Debug)]
 pub struct MeasureUnitConverter {
@@ -43,7 +44,20 @@ impl fmt::Display for MeasureUnitConverter {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    EnumString,
+    IntoStaticStr,
+    specta::Type,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[strum(ascii_case_insensitive)]
 pub enum MeasureUnit {
     Millimeters,
     Inches,
@@ -64,6 +78,30 @@ impl MeasureUnit {
         }
     }
 
+    /// Convert `value`, expressed in this measure unit, to millimeters.
+    ///
+    /// Every unit converts to millimeters, unlike `to` which only pairs
+    /// units of the same system. Values too large for a `Decimal` saturate.
+    pub fn to_millimeters(&self, value: Decimal) -> Decimal {
+        value.saturating_mul(self.millimeters_per_unit())
+    }
+
+    /// Convert `millimeters` to this measure unit; the inverse of
+    /// `to_millimeters`.
+    pub fn from_millimeters(&self, millimeters: Decimal) -> Decimal {
+        millimeters / self.millimeters_per_unit()
+    }
+
+    fn millimeters_per_unit(&self) -> Decimal {
+        match self {
+            MeasureUnit::Millimeters => Decimal::ONE,
+            MeasureUnit::Inches => MeasureUnit::INCHES_TO_MILLIMETERS,
+            MeasureUnit::Meters => MeasureUnit::METERS_TO_MILLIMETERS,
+            MeasureUnit::Kilometers => MeasureUnit::KILOMETERS_TO_MILLIMETERS,
+            MeasureUnit::Miles => MeasureUnit::MILES_TO_MILLIMETERS,
+        }
+    }
+
     pub fn same_as(&self, value: Decimal, other_mu: MeasureUnit, other_value: Decimal) -> bool {
         let value_converted = self.to(other_mu).convert(value);
         let diff = other_value - value_converted;
@@ -126,6 +164,8 @@ impl MeasureUnit {
     const MILES_TO_KILOMETERS: Decimal = dec!(1.60934);
     const KILOMETERS_TO_MILES: Decimal = dec!(0.621371);
     const METERS_TO_MILLIMETERS: Decimal = dec!(1000.0);
+    const KILOMETERS_TO_MILLIMETERS: Decimal = dec!(1000000.0);
+    const MILES_TO_MILLIMETERS: Decimal = dec!(1609340.0);
     const MILLIMETERS_TO_METERS: Decimal = dec!(0.001);
 }
 
@@ -189,5 +229,31 @@ mod tests {
             let converted = from_mu.to(to_mu).convert(value);
             assert_eq!(expected, converted);
         }
+
+        #[rstest]
+        #[case(MeasureUnit::Millimeters, dec!(2.5))]
+        #[case(MeasureUnit::Inches, dec!(63.50))]
+        #[case(MeasureUnit::Meters, dec!(2500.00))]
+        #[case(MeasureUnit::Kilometers, dec!(2500000.00))]
+        #[case(MeasureUnit::Miles, dec!(4023350.00))]
+        fn it_should_convert_every_measure_unit_to_millimeters(
+            #[case] measure_unit: MeasureUnit,
+            #[case] expected: Decimal,
+        ) {
+            assert_eq!(expected, measure_unit.to_millimeters(dec!(2.5)));
+        }
+
+        #[rstest]
+        #[case(MeasureUnit::Millimeters)]
+        #[case(MeasureUnit::Inches)]
+        #[case(MeasureUnit::Meters)]
+        #[case(MeasureUnit::Kilometers)]
+        #[case(MeasureUnit::Miles)]
+        fn it_should_convert_millimeters_back_to_every_measure_unit(
+            #[case] measure_unit: MeasureUnit,
+        ) {
+            let millimeters = measure_unit.to_millimeters(dec!(2.5));
+            assert_eq!(dec!(2.5), measure_unit.from_millimeters(millimeters));
+        }
     }
 }
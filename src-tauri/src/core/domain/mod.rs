@@ -1,9 +1,11 @@
 pub mod address;
 pub mod currency;
 pub mod error;
+pub mod job;
 pub mod length;
 pub mod measure_units;
 pub mod monetary_amount;
+pub mod page;
 pub mod trn;
 
 pub use currency::Currency;
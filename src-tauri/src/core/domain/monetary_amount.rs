@@ -5,7 +5,8 @@
 //! yen for JPY) together with a `Currency`.
 //!
 //! The module provides helpers to build an instance from database parts
-//! (`MonetaryAmount::from_db`), to add values when currencies match
+//! (`MonetaryAmount::from_db`), to parse the amounts the collector types
+//! (`MonetaryAmount::parse`), to add values when currencies match
 //! (`add_same_currency`) and to format the value for display.
 
 use crate::core::domain::error::Error;
@@ -41,7 +42,7 @@ use crate::core::domain::currency::Currency;
 /// let none = MonetaryAmount::from_db(0, None).unwrap();
 /// assert!(none.is_none());
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
 pub struct MonetaryAmount {
     /// Amount stored in the smallest unit (e.g. cents for EUR/USD/GBP).
     pub amount: u64,
@@ -91,6 +92,46 @@ impl MonetaryAmount {
         }
     }
 
+    /// Parse an amount as typed by the collector: a number with the symbol or
+    /// the code of its currency before or after it, like `"€129.90"`,
+    /// `"129,90 EUR"` or `"¥1000"`.
+    ///
+    /// Either `.` or `,` separates the decimals; thousands separators are not
+    /// accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidAmount` when the currency is missing or
+    /// unknown, the number is malformed or has more decimals than the
+    /// currency's smallest unit, and `Error::Overflow` when it does not fit.
+    pub fn parse(input: &str) -> Result<MonetaryAmount> {
+        let input = input.trim();
+        let invalid = || Error::InvalidAmount(input.to_string());
+        let (currency, number) = Currency::ALL
+            .iter()
+            .find_map(|currency| {
+                strip_currency(input, currency.symbol())
+                    .or_else(|| strip_currency(input, currency.code()))
+                    .map(|number| (*currency, number.trim()))
+            })
+            .ok_or_else(invalid)?;
+
+        let (major, minor) = match number.split_once(['.', ',']) {
+            Some((major, minor)) => (major, minor),
+            None => (number, ""),
+        };
+        let places = currency.decimal_places() as usize;
+        let is_digits = |s: &str| s.chars().all(|c| c.is_ascii_digit());
+        if major.is_empty() || !is_digits(major) || !is_digits(minor) || minor.len() > places {
+            return Err(invalid());
+        }
+        let amount = format!("{major}{minor:0<places$}")
+            .parse::<u64>()
+            .map_err(|_| Error::Overflow)?;
+
+        Ok(MonetaryAmount::new(amount, currency))
+    }
+
     /// Add two `MonetaryAmount` values with the same currency.
     ///
     /// Returns an error when the currencies differ or when the addition would
@@ -124,6 +165,22 @@ impl MonetaryAmount {
     }
 }
 
+/// `input` without the currency `tag` (a symbol or a code, ignoring case) at
+/// its start or end, `None` when the tag is not there.
+fn strip_currency<'a>(input: &'a str, tag: &str) -> Option<&'a str> {
+    let len = tag.len();
+    if input.len() >= len {
+        if input.is_char_boundary(len) && input[..len].eq_ignore_ascii_case(tag) {
+            return Some(&input[len..]);
+        }
+        let start = input.len() - len;
+        if input.is_char_boundary(start) && input[start..].eq_ignore_ascii_case(tag) {
+            return Some(&input[..start]);
+        }
+    }
+    None
+}
+
 impl fmt::Display for MonetaryAmount {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.currency {
@@ -178,6 +235,37 @@ mod tests {
         assert!(MonetaryAmount::from_db(-1, Some("EUR")).is_err());
     }
 
+    #[rstest]
+    #[case("€129.90", 12990, Currency::EUR)]
+    #[case("129,90 EUR", 12990, Currency::EUR)]
+    #[case("eur 129", 12900, Currency::EUR)]
+    #[case("12.5€", 1250, Currency::EUR)]
+    #[case("$12.34", 1234, Currency::USD)]
+    #[case(" £5 ", 500, Currency::GBP)]
+    #[case("¥1000", 1000, Currency::JPY)]
+    #[case("JPY1000", 1000, Currency::JPY)]
+    fn monetary_parse_ok(#[case] input: &str, #[case] amount: u64, #[case] currency: Currency) {
+        let m = MonetaryAmount::parse(input).unwrap();
+        assert_eq!(m.amount, amount);
+        assert_eq!(m.currency, currency);
+    }
+
+    #[rstest]
+    #[case("129.90")]
+    #[case("€")]
+    #[case("€12.345")]
+    #[case("¥10.5")]
+    #[case("€1.299,90")]
+    #[case("CHF 10")]
+    #[case("€-5")]
+    #[case("€.50")]
+    fn monetary_parse_invalid(#[case] input: &str) {
+        assert!(matches!(
+            MonetaryAmount::parse(input),
+            Err(Error::InvalidAmount(_))
+        ));
+    }
+
     #[rstest]
     #[case(100, 250, Currency::EUR, 350)]
     fn add_same_currency_ok(
@@ -0,0 +1,229 @@
+//! Keyset pagination.
+//!
+//! Large listings are read one page at a time in `(sort key, id)` order. A
+//! page starts right after the last row of the previous one
+//! (`WHERE (sort_key, id) > (?, ?)`), so reading page 200 costs the same as
+//! reading the first one, unlike `OFFSET`.
+//!
+//! The position is handed to the caller as an opaque cursor string that
+//! names the listing it belongs to and carries a checksum, so a cursor from
+//! another listing, an edited cursor or garbage is rejected with
+//! `InvalidCursor` instead of silently starting somewhere else.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Upper bound for the number of rows returned in one page.
+pub const MAX_PAGE_SIZE: u32 = 200;
+
+/// Cursor format version, bumped whenever the encoding changes.
+const CURSOR_VERSION: u32 = 1;
+
+/// One page of a listing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct Page<T> {
+    /// The rows of this page, in listing order.
+    pub items: Vec<T>,
+    /// The cursor to pass to get the next page, or `None` on the last page.
+    pub next_cursor: Option<String>,
+}
+
+/// The keys of a row in a keyset-paginated listing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageKey {
+    pub sort_key: String,
+    pub id: String,
+}
+
+/// A page as read by a repository: the rows and, when more follow, the keys
+/// of the last one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeysetPage<T> {
+    pub items: Vec<T>,
+    pub next: Option<PageKey>,
+}
+
+impl<T> KeysetPage<T> {
+    /// Build a page from up to `limit + 1` rows read in listing order: the
+    /// extra row only tells that another page follows and is dropped.
+    ///
+    /// `key` returns the keys of a row.
+    pub fn from_rows(mut rows: Vec<T>, limit: u32, key: impl Fn(&T) -> PageKey) -> Self {
+        let limit = usize::try_from(limit).unwrap_or(usize::MAX);
+        let next = if rows.len() > limit {
+            rows.truncate(limit);
+            rows.last().map(key)
+        } else {
+            None
+        };
+        KeysetPage { items: rows, next }
+    }
+
+    /// Convert the rows with `f`, keeping the keys.
+    pub fn try_map<U, E>(self, f: impl FnMut(T) -> Result<U, E>) -> Result<KeysetPage<U>, E> {
+        Ok(KeysetPage {
+            items: self.items.into_iter().map(f).collect::<Result<_, _>>()?,
+            next: self.next,
+        })
+    }
+
+    /// The page returned to the caller, with the next page keys encoded as a
+    /// cursor of `listing`.
+    pub fn into_page(self, listing: &str) -> Page<T> {
+        Page {
+            items: self.items,
+            next_cursor: self.next.map(|key| encode_cursor(listing, &key)),
+        }
+    }
+}
+
+/// A page cursor that is malformed, was tampered with or belongs to another
+/// listing.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid page cursor: {0}")]
+pub struct InvalidCursor(pub String);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CursorPayload {
+    v: u32,
+    listing: String,
+    sort_key: String,
+    id: String,
+    checksum: String,
+}
+
+/// Encode the keys of the last row of a page of `listing` as a cursor.
+pub fn encode_cursor(listing: &str, key: &PageKey) -> String {
+    let payload = CursorPayload {
+        v: CURSOR_VERSION,
+        listing: listing.to_string(),
+        sort_key: key.sort_key.clone(),
+        id: key.id.clone(),
+        checksum: checksum(listing, key),
+    };
+    let json = serde_json::to_vec(&payload).expect("cursor payload serializes");
+    json.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+/// Decode a cursor produced by `encode_cursor` for `listing`.
+pub fn decode_cursor(listing: &str, cursor: &str) -> Result<PageKey, InvalidCursor> {
+    let invalid = |reason: &str| InvalidCursor(reason.to_string());
+
+    if !cursor.len().is_multiple_of(2) || !cursor.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid("not an encoded cursor"));
+    }
+    let bytes = (0..cursor.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&cursor[i..i + 2], 16))
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(|_| invalid("not an encoded cursor"))?;
+    let payload: CursorPayload =
+        serde_json::from_slice(&bytes).map_err(|_| invalid("not an encoded cursor"))?;
+
+    if payload.v != CURSOR_VERSION {
+        return Err(invalid("unsupported cursor version"));
+    }
+    if payload.listing != listing {
+        return Err(InvalidCursor(format!(
+            "cursor belongs to the {} listing",
+            payload.listing
+        )));
+    }
+    let key = PageKey {
+        sort_key: payload.sort_key,
+        id: payload.id,
+    };
+    if payload.checksum != checksum(listing, &key) {
+        return Err(invalid("checksum mismatch"));
+    }
+    Ok(key)
+}
+
+/// FNV-1a over the listing and the keys. It is not a secret: it only makes
+/// an edited cursor fail loudly.
+fn checksum(listing: &str, key: &PageKey) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in [listing, &key.sort_key, &key.id] {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn key() -> PageKey {
+        PageKey {
+            sort_key: "E656".to_string(),
+            id: "4bd8c0c4-5b4c-4bc4-a8a4-0165b1a8c8e7".to_string(),
+        }
+    }
+
+    #[test]
+    fn cursors_round_trip() {
+        let cursor = encode_cursor("railway_models", &key());
+
+        assert_eq!(decode_cursor("railway_models", &cursor), Ok(key()));
+    }
+
+    #[test]
+    fn garbage_is_rejected() {
+        for cursor in ["", "xyz", "abc", "7b7d", "not a cursor"] {
+            assert!(
+                decode_cursor("railway_models", cursor).is_err(),
+                "{cursor:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn cursors_of_another_listing_are_rejected() {
+        let cursor = encode_cursor("collection_items", &key());
+
+        let err = decode_cursor("railway_models", &cursor).unwrap_err();
+        assert!(err.to_string().contains("collection_items"));
+    }
+
+    #[test]
+    fn edited_cursors_are_rejected() {
+        let cursor = encode_cursor("railway_models", &key());
+        let json = String::from_utf8(
+            (0..cursor.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&cursor[i..i + 2], 16).unwrap())
+                .collect(),
+        )
+        .unwrap();
+        let edited: String = json
+            .replace("E656", "E657")
+            .bytes()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+
+        assert_eq!(
+            decode_cursor("railway_models", &edited),
+            Err(InvalidCursor("checksum mismatch".to_string()))
+        );
+    }
+
+    #[test]
+    fn from_rows_drops_the_look_ahead_row() {
+        let key_of = |n: &u32| PageKey {
+            sort_key: n.to_string(),
+            id: n.to_string(),
+        };
+
+        let page = KeysetPage::from_rows(vec![1, 2, 3], 2, key_of);
+        assert_eq!(page.items, vec![1, 2]);
+        assert_eq!(page.next, Some(key_of(&2)));
+
+        let last = KeysetPage::from_rows(vec![1, 2], 2, key_of);
+        assert_eq!(last.items, vec![1, 2]);
+        assert_eq!(last.next, None);
+    }
+}
@@ -0,0 +1,27 @@
+//! Optimistic concurrency control for guarded updates.
+//!
+//! Rows that can be edited from more than one window carry an integer
+//! `version` column. A guarded update only matches the row when its version
+//! is still the one the caller read (`WHERE id = ? AND version = ?`) and
+//! increments it; when no row is affected the update fails with a
+//! `ConflictError` carrying the row as it is now stored, so the UI can offer
+//! to merge or overwrite.
+
+use std::fmt::Debug;
+
+/// A guarded update lost the race against a concurrent write.
+///
+/// Returned (wrapped in `anyhow::Error`) by the version-guarded update
+/// helpers; use `downcast_ref::<ConflictError<Row>>()` to recover it.
+#[derive(Debug, thiserror::Error)]
+#[error("{entity} {id} was modified concurrently (expected version {expected_version})")]
+pub struct ConflictError<T: Debug> {
+    /// The kind of row that was updated, for example `"collection_item"`.
+    pub entity: &'static str,
+    /// The id of the row that was updated.
+    pub id: String,
+    /// The version the caller expected the row to have.
+    pub expected_version: i64,
+    /// The row as currently stored, or `None` when it has been deleted.
+    pub current: Option<T>,
+}
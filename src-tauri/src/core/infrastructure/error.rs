@@ -21,6 +21,27 @@ pub enum CommandError {
     #[error("database error: {0}")]
     DatabaseError(String),
 
+    /// The requested data does not exist (for example a missing exchange rate).
+    ///
+    /// The inner `String` describes what was looked up, so the UI can tell
+    /// the user what is missing.
+    #[error("not found: {0}")]
+    NotFound(String),
+
+    /// An argument was rejected (for example a malformed page cursor).
+    ///
+    /// The inner `String` says what is wrong with it.
+    #[error("invalid argument: {0}")]
+    Validation(String),
+
+    /// A field of an argument was rejected (for example a preorder deposit
+    /// larger than its total price).
+    ///
+    /// `field` names the offending field, so the UI can show `message` next
+    /// to it.
+    #[error("invalid {field}: {message}")]
+    InvalidField { field: String, message: String },
+
     /// A catch-all for unexpected errors that don't map to a specific variant.
     ///
     /// The inner `String` can include a short debug message suitable for
@@ -0,0 +1,128 @@
+//! SQLite column support for the id newtypes.
+//!
+//! Ids are stored as `TEXT` columns holding their string form (a hyphenated
+//! UUID for the UUID-based ids). `text_id_column!` implements `sqlx::Type`,
+//! `Encode` and `Decode` for an id type from its `Display` and
+//! `TryFrom<&str>` implementations, so ids bind and read back without going
+//! through `String`. A stored value the id type rejects fails with a column
+//! decode error; sqlx names the column (by name when the row is read with
+//! `FromRow`, by position when it is read with the `query_as!` macros).
+//!
+//! The `uuid` crate's own sqlx support stores a UUID as a 16 byte `BLOB`,
+//! which does not match the existing `TEXT` columns; that is why the UUID
+//! ids are not `#[sqlx(transparent)]`.
+
+/// Implement the `TEXT` column traits for an id type implementing
+/// `Display` and `TryFrom<&str>`.
+macro_rules! text_id_column {
+    ($id:ty) => {
+        impl sqlx::Type<sqlx::Sqlite> for $id {
+            fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+                <String as sqlx::Type<sqlx::Sqlite>>::type_info()
+            }
+
+            fn compatible(ty: &sqlx::sqlite::SqliteTypeInfo) -> bool {
+                <String as sqlx::Type<sqlx::Sqlite>>::compatible(ty)
+            }
+        }
+
+        impl<'q> sqlx::Encode<'q, sqlx::Sqlite> for $id {
+            fn encode_by_ref(
+                &self,
+                buf: &mut Vec<sqlx::sqlite::SqliteArgumentValue<'q>>,
+            ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+                <String as sqlx::Encode<'q, sqlx::Sqlite>>::encode(self.to_string(), buf)
+            }
+        }
+
+        impl<'r> sqlx::Decode<'r, sqlx::Sqlite> for $id {
+            fn decode(
+                value: sqlx::sqlite::SqliteValueRef<'r>,
+            ) -> Result<Self, sqlx::error::BoxDynError> {
+                let text = <&str as sqlx::Decode<'r, sqlx::Sqlite>>::decode(value)?;
+                <$id>::try_from(text).map_err(Into::into)
+            }
+        }
+    };
+}
+
+pub(crate) use text_id_column;
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog::domain::railway_model_id::RailwayModelId;
+    use crate::catalog::domain::rolling_stock_id::RollingStockId;
+    use crate::collecting::domain::collection_id::CollectionId;
+    use crate::collecting::domain::collection_item_id::CollectionItemId;
+    use crate::db::init_in_memory_db_pool;
+    use pretty_assertions::assert_eq;
+    use sqlx::{Decode, Encode, Sqlite, SqlitePool, Type};
+
+    async fn round_trip<T>(pool: &SqlitePool, id: &T) -> (T, String)
+    where
+        T: for<'q> Encode<'q, Sqlite> + for<'r> Decode<'r, Sqlite> + Type<Sqlite> + Send + Sync,
+        T: Unpin,
+    {
+        let typed: (T,) = sqlx::query_as("SELECT ?1")
+            .bind(id)
+            .fetch_one(pool)
+            .await
+            .expect("read the id back");
+        let text: (String,) = sqlx::query_as("SELECT ?1")
+            .bind(id)
+            .fetch_one(pool)
+            .await
+            .expect("read the id as text");
+        (typed.0, text.0)
+    }
+
+    #[tokio::test]
+    async fn ids_round_trip_as_text() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+
+        let collection_id = CollectionId::default();
+        assert_eq!(
+            round_trip(&pool, &collection_id).await,
+            (collection_id.clone(), collection_id.to_string())
+        );
+        let item_id = CollectionItemId::default();
+        assert_eq!(
+            round_trip(&pool, &item_id).await,
+            (item_id.clone(), item_id.to_string())
+        );
+        let rolling_stock_id = RollingStockId::new();
+        assert_eq!(
+            round_trip(&pool, &rolling_stock_id).await,
+            (rolling_stock_id, rolling_stock_id.to_string())
+        );
+        let railway_model_id = RailwayModelId::new();
+        assert_eq!(
+            round_trip(&pool, &railway_model_id).await,
+            (railway_model_id, railway_model_id.to_string())
+        );
+    }
+
+    #[derive(Debug, sqlx::FromRow)]
+    #[allow(dead_code)]
+    struct IdRow {
+        collection_id: CollectionId,
+    }
+
+    #[tokio::test]
+    async fn invalid_stored_ids_fail_naming_the_column() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+
+        let err = sqlx::query_as::<_, IdRow>("SELECT 'not-a-uuid' AS collection_id")
+            .fetch_one(&pool)
+            .await
+            .unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("collection_id"), "{message}");
+        assert!(message.contains("not-a-uuid"), "{message}");
+        let legacy = sqlx::query_scalar::<_, RailwayModelId>("SELECT 'rm-42'")
+            .fetch_one(&pool)
+            .await;
+        assert!(legacy.is_err());
+    }
+}
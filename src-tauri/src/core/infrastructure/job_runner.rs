@@ -0,0 +1,363 @@
+//! Runs maintenance jobs in the background and keeps track of their status.
+//!
+//! A job is an async function of the pool and a `ProgressReporter`, spawned
+//! on Tauri's async runtime so the command that started it returns straight
+//! away. `JobRunner` records every job from `Queued` to the status it
+//! finished with, refuses to start a second job of a kind that is already
+//! queued or running, and cancels a job by aborting its task: the job stops
+//! at its next `.await`, so a transaction it had open is rolled back.
+
+use crate::core::domain::job::{Job, JobError, JobId, JobKind, JobProgress, JobStatus};
+use chrono::Utc;
+use log::{error, info};
+use sqlx::SqlitePool;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tauri::async_runtime::{self, JoinHandle};
+
+/// How many finished jobs are kept for `JobRunner::list`.
+const MAX_FINISHED_JOBS: usize = 20;
+
+/// A job and the handle that cancels it.
+struct JobEntry {
+    job: Job,
+    abort: Option<JoinHandle<()>>,
+}
+
+#[derive(Default)]
+struct Jobs {
+    entries: HashMap<JobId, JobEntry>,
+}
+
+impl Jobs {
+    /// Move the job with `id` to `status`, unless it already finished (for
+    /// example a job cancelled while completing keeps `Cancelled`).
+    fn transition(&mut self, id: &JobId, status: JobStatus, error: Option<String>) {
+        if let Some(entry) = self.entries.get_mut(id)
+            && !entry.job.status.is_finished()
+        {
+            entry.job.status = status;
+            entry.job.error = error;
+            if status.is_finished() {
+                entry.job.finished_at = Some(Utc::now());
+                entry.abort = None;
+            }
+        }
+    }
+
+    /// Drop the oldest finished jobs beyond `MAX_FINISHED_JOBS`.
+    fn prune(&mut self) {
+        let mut finished: Vec<(JobId, chrono::DateTime<Utc>)> = self
+            .entries
+            .values()
+            .filter(|entry| entry.job.status.is_finished())
+            .map(|entry| (entry.job.id, entry.job.queued_at))
+            .collect();
+        if finished.len() <= MAX_FINISHED_JOBS {
+            return;
+        }
+        finished.sort_by_key(|(_, queued_at)| *queued_at);
+        let excess = finished.len() - MAX_FINISHED_JOBS;
+        for (id, _) in finished.into_iter().take(excess) {
+            self.entries.remove(&id);
+        }
+    }
+}
+
+/// Lets a running job report how far it got.
+#[derive(Clone)]
+pub struct ProgressReporter {
+    id: JobId,
+    jobs: Arc<Mutex<Jobs>>,
+}
+
+impl ProgressReporter {
+    /// Record that `done` of `total` units of work are done.
+    pub fn report(&self, done: u32, total: u32) {
+        let mut jobs = self.jobs.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(entry) = jobs.entries.get_mut(&self.id) {
+            entry.job.progress = Some(JobProgress { done, total });
+        }
+    }
+}
+
+/// Spawns jobs and tracks their status.
+///
+/// Cloning is cheap; clones share the jobs.
+#[derive(Clone)]
+pub struct JobRunner {
+    pool: SqlitePool,
+    jobs: Arc<Mutex<Jobs>>,
+}
+
+impl JobRunner {
+    /// Create a runner whose jobs use `pool`.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            jobs: Arc::new(Mutex::new(Jobs::default())),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Jobs> {
+        self.jobs.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Start `job` as a job of `kind`, returning its id.
+    ///
+    /// The job runs on Tauri's async runtime, so this can be called from a
+    /// sync command, outside any runtime.
+    ///
+    /// # Errors
+    ///
+    /// `JobError::AlreadyRunning` when a job of `kind` is queued or running.
+    pub fn spawn<F, Fut>(&self, kind: JobKind, job: F) -> Result<JobId, JobError>
+    where
+        F: FnOnce(SqlitePool, ProgressReporter) -> Fut + Send + 'static,
+        Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+    {
+        // The lock is held until the abort handle is stored, so the task
+        // always finds its entry.
+        let mut jobs = self.lock();
+        if jobs
+            .entries
+            .values()
+            .any(|entry| entry.job.kind == kind && !entry.job.status.is_finished())
+        {
+            return Err(JobError::AlreadyRunning(kind));
+        }
+
+        let id = JobId::default();
+        jobs.entries.insert(
+            id,
+            JobEntry {
+                job: Job {
+                    id,
+                    kind,
+                    status: JobStatus::Queued,
+                    error: None,
+                    progress: None,
+                    queued_at: Utc::now(),
+                    finished_at: None,
+                },
+                abort: None,
+            },
+        );
+
+        let pool = self.pool.clone();
+        let reporter = ProgressReporter {
+            id,
+            jobs: Arc::clone(&self.jobs),
+        };
+        let state = Arc::clone(&self.jobs);
+        let handle = async_runtime::spawn(async move {
+            state.lock().unwrap_or_else(|e| e.into_inner()).transition(
+                &id,
+                JobStatus::Running,
+                None,
+            );
+            let outcome = job(pool, reporter).await;
+            let mut jobs = state.lock().unwrap_or_else(|e| e.into_inner());
+            match outcome {
+                Ok(()) => {
+                    info!("Job {kind:?} id={id} succeeded");
+                    jobs.transition(&id, JobStatus::Succeeded, None);
+                }
+                Err(e) => {
+                    error!("Job {kind:?} id={id} failed: {e:#}");
+                    jobs.transition(&id, JobStatus::Failed, Some(format!("{e:#}")));
+                }
+            }
+        });
+        if let Some(entry) = jobs.entries.get_mut(&id) {
+            entry.abort = Some(handle);
+        }
+        jobs.prune();
+
+        Ok(id)
+    }
+
+    /// Every job known to the runner, the most recent first.
+    pub fn list(&self) -> Vec<Job> {
+        let mut jobs: Vec<Job> = self
+            .lock()
+            .entries
+            .values()
+            .map(|entry| entry.job.clone())
+            .collect();
+        jobs.sort_by_key(|job| Reverse(job.queued_at));
+        jobs
+    }
+
+    /// The job with `id`, if the runner knows it.
+    pub fn get(&self, id: &JobId) -> Option<Job> {
+        self.lock().entries.get(id).map(|entry| entry.job.clone())
+    }
+
+    /// Cancel the job with `id`, returning `false` when it had already
+    /// finished.
+    ///
+    /// # Errors
+    ///
+    /// `JobError::NotFound` when the runner does not know the job.
+    pub fn cancel(&self, id: &JobId) -> Result<bool, JobError> {
+        let mut jobs = self.lock();
+        let entry = jobs.entries.get_mut(id).ok_or(JobError::NotFound(*id))?;
+        if entry.job.status.is_finished() {
+            return Ok(false);
+        }
+        if let Some(abort) = entry.abort.take() {
+            abort.abort();
+        }
+        jobs.transition(id, JobStatus::Cancelled, None);
+        info!("Job id={id} cancelled");
+
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use pretty_assertions::assert_eq;
+    use std::time::Duration;
+    use tokio::sync::oneshot;
+
+    /// Wait until the job with `id` reaches `status`.
+    async fn wait_for(runner: &JobRunner, id: &JobId, status: JobStatus) -> Job {
+        for _ in 0..200 {
+            let job = runner.get(id).expect("job");
+            if job.status == status {
+                return job;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("job id={id} never reached {status:?}");
+    }
+
+    #[test]
+    fn a_job_can_be_started_outside_a_runtime() {
+        // Sync commands such as `start_job` run on the main thread, where no
+        // runtime is entered
+        let pool = async_runtime::block_on(SqlitePool::connect("sqlite::memory:"))
+            .expect("in-memory database");
+        let runner = JobRunner::new(pool);
+
+        let id = runner
+            .spawn(JobKind::IntegrityCheck, |pool, _| async move {
+                sqlx::query("SELECT 1").execute(&pool).await?;
+                Ok(())
+            })
+            .expect("job started");
+
+        for _ in 0..200 {
+            if runner.get(&id).expect("job").status == JobStatus::Succeeded {
+                return;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        panic!("job id={id} never succeeded");
+    }
+
+    #[sqlx::test]
+    async fn a_job_goes_from_queued_to_succeeded(pool: SqlitePool) {
+        let runner = JobRunner::new(pool);
+        let (start, started) = oneshot::channel::<()>();
+
+        let id = runner
+            .spawn(JobKind::ValueSnapshot, |pool, progress| async move {
+                started.await?;
+                progress.report(1, 2);
+                sqlx::query("SELECT 1").execute(&pool).await?;
+                progress.report(2, 2);
+                Ok(())
+            })
+            .expect("job started");
+
+        let job = wait_for(&runner, &id, JobStatus::Running).await;
+        assert_eq!(job.kind, JobKind::ValueSnapshot);
+        assert_eq!(job.progress, None);
+
+        start.send(()).expect("job waiting");
+        let job = wait_for(&runner, &id, JobStatus::Succeeded).await;
+        assert_eq!(job.progress, Some(JobProgress { done: 2, total: 2 }));
+        assert!(job.finished_at.is_some());
+        assert_eq!(runner.list(), vec![job]);
+    }
+
+    #[sqlx::test]
+    async fn a_failed_job_keeps_its_error(pool: SqlitePool) {
+        let runner = JobRunner::new(pool);
+
+        let id = runner
+            .spawn(JobKind::IntegrityCheck, |_, _| async {
+                Err(anyhow!("database disk image is malformed"))
+            })
+            .expect("job started");
+
+        let job = wait_for(&runner, &id, JobStatus::Failed).await;
+        assert_eq!(
+            job.error.as_deref(),
+            Some("database disk image is malformed")
+        );
+    }
+
+    #[sqlx::test]
+    async fn only_one_job_of_a_kind_runs_at_a_time(pool: SqlitePool) {
+        let runner = JobRunner::new(pool);
+        let sleeping = |_, _| async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        };
+
+        let id = runner
+            .spawn(JobKind::IntegrityCheck, sleeping)
+            .expect("job started");
+        assert_eq!(
+            runner.spawn(JobKind::IntegrityCheck, sleeping),
+            Err(JobError::AlreadyRunning(JobKind::IntegrityCheck))
+        );
+        let other = runner
+            .spawn(JobKind::ValueSnapshot, sleeping)
+            .expect("another kind");
+
+        assert_eq!(runner.cancel(&id), Ok(true));
+        assert!(runner.spawn(JobKind::IntegrityCheck, sleeping).is_ok());
+        assert_eq!(runner.list().len(), 3);
+        runner.cancel(&other).expect("known job");
+    }
+
+    #[sqlx::test]
+    async fn a_cancelled_job_stops_running(pool: SqlitePool) {
+        let runner = JobRunner::new(pool);
+        let (done, mut completed) = oneshot::channel::<()>();
+
+        let id = runner
+            .spawn(JobKind::ValueSnapshot, |_, _| async move {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                let _ = done.send(());
+                Ok(())
+            })
+            .expect("job started");
+        wait_for(&runner, &id, JobStatus::Running).await;
+
+        assert_eq!(runner.cancel(&id), Ok(true));
+        let job = runner.get(&id).expect("job");
+        assert_eq!(job.status, JobStatus::Cancelled);
+        assert!(job.finished_at.is_some());
+
+        // The aborted task drops its sender instead of completing
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(
+            completed.try_recv(),
+            Err(oneshot::error::TryRecvError::Closed)
+        );
+        assert_eq!(runner.cancel(&id), Ok(false));
+
+        let unknown = JobId::default();
+        assert_eq!(runner.cancel(&unknown), Err(JobError::NotFound(unknown)));
+    }
+}
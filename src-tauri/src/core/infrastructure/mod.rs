@@ -1 +1,7 @@
+pub mod batch_insert;
+pub mod conflict;
 pub mod error;
+pub mod id_column;
+pub mod job_runner;
+pub mod retry;
+pub mod transaction;
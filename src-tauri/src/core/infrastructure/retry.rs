@@ -0,0 +1,264 @@
+//! Retry helper for transient SQLite lock contention.
+//!
+//! The pool waits up to `busy_timeout` for a lock, but a burst of writes can
+//! still outlast it and fail with `SQLITE_BUSY` (or `SQLITE_LOCKED`).
+//! `retry_on_busy` re-runs a single write statement with exponential backoff
+//! and jitter when it fails with one of those codes; every other error,
+//! constraint violations included, is returned straight away.
+//!
+//! Only standalone statements are retried. A statement inside a transaction
+//! cannot be retried on its own once the transaction has read data, so
+//! multi-statement transactions are left to fail as a whole.
+
+use log::debug;
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// SQLite primary result code for "database is locked" by another connection.
+const SQLITE_BUSY: i32 = 5;
+
+/// SQLite primary result code for a table lock held within the same connection
+/// or shared cache.
+const SQLITE_LOCKED: i32 = 6;
+
+/// How often and how long `retry_on_busy` waits between attempts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, the first one included.
+    pub max_attempts: u32,
+    /// Delay before the second attempt; it doubles after every attempt.
+    pub initial_delay: Duration,
+    /// Upper bound for the delay between two attempts.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(25),
+            max_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The delay before attempt `attempt + 1`, with up to half of it taken
+    /// off at random so that competing writers do not retry in lockstep.
+    fn delay(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        let jitter = backoff.mul_f64(random_fraction() / 2.0);
+        backoff - jitter
+    }
+}
+
+/// A random value in `[0, 1)`, from the randomly seeded std hasher.
+fn random_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Whether `error` is a busy/locked error worth retrying.
+///
+/// SQLite reports extended result codes (for example `SQLITE_BUSY_SNAPSHOT`),
+/// whose low byte is the primary code.
+pub fn is_transient(error: &sqlx::Error) -> bool {
+    match error {
+        sqlx::Error::Database(db_error) => db_error
+            .code()
+            .and_then(|code| code.parse::<i32>().ok())
+            .is_some_and(|code| matches!(code & 0xff, SQLITE_BUSY | SQLITE_LOCKED)),
+        _ => false,
+    }
+}
+
+/// Run `operation`, retrying it according to `policy` while it fails with a
+/// transient busy/locked error.
+///
+/// `context` describes the statement in the debug log written before each
+/// retry. The last error is returned once the attempts are used up.
+pub async fn retry_on_busy<T, F, Fut>(
+    policy: &RetryPolicy,
+    context: &str,
+    mut operation: F,
+) -> Result<T, sqlx::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, sqlx::Error>>,
+{
+    let mut attempt = 1;
+    loop {
+        match operation().await {
+            Err(e) if attempt < policy.max_attempts && is_transient(&e) => {
+                let delay = policy.delay(attempt);
+                debug!(
+                    "{context}: {e}, retrying in {delay:?} (attempt {attempt}/{})",
+                    policy.max_attempts
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions};
+    use sqlx::{ConnectOptions, SqlitePool};
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use uuid::Uuid;
+
+    /// A database file that is removed (with its WAL files) when dropped.
+    struct TempDb(PathBuf);
+
+    impl TempDb {
+        fn new() -> Self {
+            TempDb(std::env::temp_dir().join(format!("rusty-shed-retry-{}.db", Uuid::new_v4())))
+        }
+
+        /// Connection options that fail immediately on a held lock.
+        fn options(&self) -> SqliteConnectOptions {
+            SqliteConnectOptions::new()
+                .filename(&self.0)
+                .create_if_missing(true)
+                .journal_mode(SqliteJournalMode::Wal)
+                .busy_timeout(Duration::ZERO)
+        }
+    }
+
+    impl Drop for TempDb {
+        fn drop(&mut self) {
+            for suffix in ["", "-wal", "-shm"] {
+                let mut path = self.0.clone().into_os_string();
+                path.push(suffix);
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+    fn quick_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            initial_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(100),
+        }
+    }
+
+    async fn setup(db: &TempDb) -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(db.options())
+            .await
+            .expect("open pool");
+        sqlx::query("CREATE TABLE t (id INTEGER PRIMARY KEY)")
+            .execute(&pool)
+            .await
+            .expect("create table");
+        pool
+    }
+
+    async fn insert(pool: &SqlitePool, attempts: &AtomicU32, id: i64) -> Result<(), sqlx::Error> {
+        attempts.fetch_add(1, Ordering::SeqCst);
+        sqlx::query("INSERT INTO t (id) VALUES (?1)")
+            .bind(id)
+            .execute(pool)
+            .await
+            .map(|_| ())
+    }
+
+    #[tokio::test]
+    async fn retries_until_a_held_write_lock_is_released() {
+        let db = TempDb::new();
+        let pool = setup(&db).await;
+
+        // A second connection holds the write lock for a while.
+        let mut holder = db.options().connect().await.expect("connect");
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut holder)
+            .await
+            .expect("begin");
+        let release = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            sqlx::query("COMMIT")
+                .execute(&mut holder)
+                .await
+                .expect("commit");
+        });
+
+        let attempts = AtomicU32::new(0);
+        retry_on_busy(&quick_policy(20), "inserting t", || {
+            insert(&pool, &attempts, 1)
+        })
+        .await
+        .expect("insert after retrying");
+        release.await.expect("release task");
+
+        assert!(attempts.load(Ordering::SeqCst) > 1);
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM t")
+            .fetch_one(&pool)
+            .await
+            .expect("count");
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let db = TempDb::new();
+        let pool = setup(&db).await;
+
+        let mut holder = db.options().connect().await.expect("connect");
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut holder)
+            .await
+            .expect("begin");
+
+        let attempts = AtomicU32::new(0);
+        let err = retry_on_busy(&quick_policy(3), "inserting t", || {
+            insert(&pool, &attempts, 1)
+        })
+        .await
+        .unwrap_err();
+
+        assert!(is_transient(&err));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_constraint_violations() {
+        let db = TempDb::new();
+        let pool = setup(&db).await;
+        let attempts = AtomicU32::new(0);
+        insert(&pool, &attempts, 1).await.expect("first insert");
+
+        let attempts = AtomicU32::new(0);
+        let err = retry_on_busy(&quick_policy(5), "inserting t", || {
+            insert(&pool, &attempts, 1)
+        })
+        .await
+        .unwrap_err();
+
+        assert!(!is_transient(&err));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn delay_backs_off_exponentially_up_to_the_maximum() {
+        let policy = quick_policy(10);
+        for (attempt, expected) in [(1, 10), (2, 20), (3, 40), (4, 80), (5, 100), (9, 100)] {
+            let delay = policy.delay(attempt);
+            let expected = Duration::from_millis(expected);
+            assert!(delay <= expected, "attempt {attempt}: {delay:?}");
+            assert!(delay >= expected / 2, "attempt {attempt}: {delay:?}");
+        }
+    }
+}
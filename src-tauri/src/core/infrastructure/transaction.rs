@@ -0,0 +1,100 @@
+//! Transaction helper shared by the SQLite repositories.
+//!
+//! `with_transaction` runs a multi-step operation on a single connection
+//! inside a transaction: the transaction is committed when the operation
+//! returns `Ok` and rolled back when it returns `Err`, so callers can no
+//! longer forget one of the two. The sqlite helper functions that take a
+//! generic `SqliteExecutor` accept the `&mut SqliteConnection` handed to the
+//! operation, so the same function runs either standalone (with the pool) or
+//! as one step of a transaction.
+
+use anyhow::{Context, Result};
+use log::warn;
+use sqlx::{SqliteConnection, SqlitePool};
+use std::future::Future;
+use std::pin::Pin;
+
+/// The boxed future returned by a `with_transaction` operation.
+pub type TransactionFuture<'c, T> = Pin<Box<dyn Future<Output = Result<T>> + Send + 'c>>;
+
+/// Run `operation` inside a transaction on a connection from `pool`.
+///
+/// The operation receives the transaction's connection and returns a boxed
+/// future (`Box::pin(async move { ... })`). Data used by the operation must
+/// be moved into the closure rather than borrowed.
+///
+/// # Errors
+///
+/// Returns the operation's error after rolling back, or an error when the
+/// transaction cannot be started or committed.
+pub async fn with_transaction<T, F>(pool: &SqlitePool, operation: F) -> Result<T>
+where
+    F: for<'c> FnOnce(&'c mut SqliteConnection) -> TransactionFuture<'c, T>,
+{
+    let mut tx = pool.begin().await.context("starting transaction")?;
+
+    match operation(&mut tx).await {
+        Ok(value) => {
+            tx.commit().await.context("committing transaction")?;
+            Ok(value)
+        }
+        Err(e) => {
+            if let Err(rollback_error) = tx.rollback().await {
+                warn!("Failed to roll back transaction: {rollback_error}");
+            }
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_in_memory_db_pool;
+    use anyhow::anyhow;
+    use pretty_assertions::assert_eq;
+
+    async fn count_settings(pool: &SqlitePool) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM settings")
+            .fetch_one(pool)
+            .await
+            .expect("count settings")
+    }
+
+    #[tokio::test]
+    async fn with_transaction_commits_on_success() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+
+        let value = with_transaction(&pool, |conn| {
+            Box::pin(async move {
+                sqlx::query("INSERT INTO settings (key, value) VALUES ('a', '1')")
+                    .execute(&mut *conn)
+                    .await?;
+                Ok(42)
+            })
+        })
+        .await
+        .expect("transaction");
+
+        assert_eq!(value, 42);
+        assert_eq!(count_settings(&pool).await, 1);
+    }
+
+    #[tokio::test]
+    async fn with_transaction_rolls_back_on_error() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+
+        let result: Result<()> = with_transaction(&pool, |conn| {
+            Box::pin(async move {
+                sqlx::query("INSERT INTO settings (key, value) VALUES ('a', '1')")
+                    .execute(&mut *conn)
+                    .await?;
+                Err(anyhow!("second step failed"))
+            })
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(count_settings(&pool).await, 0);
+    }
+}
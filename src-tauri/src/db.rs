@@ -5,11 +5,23 @@
 //! compile time and can be run by code that uses the provided
 //! `MIGRATOR` value.
 
-use log::error;
+pub mod integrity;
+pub mod pool_metrics;
+
+pub use integrity::{IntegrityReport, integrity_report};
+pub use pool_metrics::{PoolMetrics, PoolMonitor};
+
+use log::{error, warn};
+use serde::{Deserialize, Serialize};
 use sqlx::migrate::Migrator;
-use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
-use sqlx::{Sqlite, migrate::MigrateDatabase};
-use std::path::PathBuf;
+use sqlx::sqlite::{
+    SqliteConnectOptions, SqliteJournalMode, SqlitePool, SqlitePoolOptions, SqliteSynchronous,
+};
+use std::ffi::OsString;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
 use thiserror::Error;
 use uuid::Uuid;
 use xdg::BaseDirectories;
@@ -20,58 +32,299 @@ use xdg::BaseDirectories;
 /// The path is relative to the crate root (the `Cargo.toml` of this crate).
 pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
 
+/// How long a connection waits for a lock held by another connection before
+/// failing with "database is locked".
+const BUSY_TIMEOUT: Duration = Duration::from_millis(5000);
+
+/// The maximum number of pooled connections unless configured otherwise.
+pub const DEFAULT_MAX_CONNECTIONS: u32 = 5;
+
+/// Environment variable overriding the maximum number of pooled connections.
+///
+/// Like the database location, the limit cannot live in the `settings` table
+/// because it is needed before the database is opened.
+pub const DB_MAX_CONNECTIONS_ENV_VAR: &str = "RUSTY_SHED_DB_MAX_CONNECTIONS";
+
+/// Environment variable overriding the location of the database file.
+pub const DB_PATH_ENV_VAR: &str = "RUSTY_SHED_DB_PATH";
+
+/// Name of the XDG config file that may hold a database location override.
+///
+/// The override cannot live in the `settings` table because that table is
+/// stored inside the very database it would locate.
+const DB_PATH_CONFIG_FILE: &str = "db_path";
+
+/// Where the database location was taken from, in priority order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub enum DbPathSource {
+    /// Passed explicitly to `init_db_pool` (the `--db-path` argument).
+    Explicit,
+    /// Read from the `RUSTY_SHED_DB_PATH` environment variable.
+    Environment,
+    /// Read from the `db_path` file in the XDG config directory.
+    ConfigFile,
+    /// The XDG data directory default.
+    Default,
+}
+
+/// The database file location together with the source that supplied it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DbLocation {
+    pub path: PathBuf,
+    pub source: DbPathSource,
+}
+
 /// Initialize and return a SQLite connection pool for the application.
 ///
 /// This function performs the following steps:
 ///
-/// - Determine an application data file location using the XDG Base
-///   Directories standard (via the `xdg` crate) with the prefix
-///   `rusty_shed`. If that fails it falls back to `./rusty_shed.db`.
-/// - Ensure the parent directory of the chosen path exists so SQLite can
-///   create the file.
-/// - Build a `sqlite:` database URL and create the database file if it
-///   does not already exist.
-/// - Connect a `SqlitePool` (max 5 connections) to the database, run the
-///   embedded migrations, and return the pool.
+/// - Determine the database location (see `resolve_db_location`): `db_path`
+///   when given, otherwise the `RUSTY_SHED_DB_PATH` environment variable,
+///   otherwise the XDG config file override, otherwise the XDG data
+///   directory default (falling back to `./rusty_shed.db`).
+/// - Validate the location: it must not be a directory and its parent
+///   directory must exist or be creatable.
+/// - Connect a `SqlitePool` to the database, creating the file if it does
+///   not already exist. The pool holds at most `RUSTY_SHED_DB_MAX_CONNECTIONS`
+///   connections (default 5, see `resolve_max_connections`). Every connection is configured
+///   with the pragmas described in `configure_connection`.
+/// - Run the embedded migrations and return the pool.
 ///
 /// This function will execute the embedded migrations (from `MIGRATOR`)
 /// against the newly-created pool before returning. If migration
 /// execution fails the error will be returned.
 ///
-/// Returns `Ok(SqlitePool)` on success or a `SqliteDbError` on failure.
-pub async fn init_db_pool() -> Result<SqlitePool, SqliteDbError> {
-    // Determine app data directory using xdg crate
-    let db_path = {
-        let bd = BaseDirectories::with_prefix("rusty_shed");
-        bd.place_data_file("rusty_shed.db").unwrap_or_else(|e| {
-            error!("Failed to determine data file path via XDG: {e}");
-            PathBuf::from("rusty_shed.db")
-        })
-    };
+/// Returns the pool and the location it was opened from on success, or a
+/// `SqliteDbError` on failure.
+pub async fn init_db_pool(
+    db_path: Option<PathBuf>,
+) -> Result<(SqlitePool, DbLocation), SqliteDbError> {
+    let location = resolve_db_location(db_path);
+    validate_db_path(&location.path)?;
 
-    // Ensure parent directory exists so SQLite can create the file
-    if let Some(parent) = db_path.parent() {
-        std::fs::create_dir_all(parent).map_err(sqlx::Error::Io)?;
-    }
+    let pool = open_db_pool(&location.path, resolve_max_connections()).await?;
+    Ok((pool, location))
+}
 
-    let db_url = format!("sqlite:{}", db_path.display());
-    error!("Opening SQLite DB at {}", db_url);
+/// Open (creating if needed) the database file at `db_path` with a pool of
+/// at most `max_connections`, run the embedded migrations and return the
+/// pool.
+async fn open_db_pool(db_path: &Path, max_connections: u32) -> Result<SqlitePool, SqliteDbError> {
+    error!("Opening SQLite DB at {}", db_path.display());
 
-    if !Sqlite::database_exists(&db_url).await.unwrap_or(false) {
-        Sqlite::create_database(&db_url).await?;
-    }
+    let options =
+        configure_connection(SqliteConnectOptions::new().filename(db_path)).create_if_missing(true);
 
     let pool = SqlitePoolOptions::new()
-        .max_connections(5)
-        .connect(&db_url)
+        .max_connections(max_connections)
+        .connect_with(options)
         .await?;
 
     // Run embedded migrations before returning the pool
-    MIGRATOR.run(&pool).await?;
+    if let Err(source) = MIGRATOR.run(&pool).await {
+        let status = migration_status(&pool).await.ok();
+        return Err(SqliteDbError::MigrationFailed { source, status });
+    }
 
     Ok(pool)
 }
 
+/// Apply the connection pragmas used for every pooled connection.
+///
+/// - `journal_mode=WAL` lets readers proceed while a write is in progress
+///   (in-memory databases ignore this and keep their `memory` journal);
+/// - `foreign_keys=ON` enforces the `REFERENCES` constraints in the schema;
+/// - `busy_timeout` makes concurrent writers wait instead of failing with
+///   "database is locked";
+/// - `synchronous=NORMAL` is the recommended durability level with WAL.
+fn configure_connection(options: SqliteConnectOptions) -> SqliteConnectOptions {
+    options
+        .journal_mode(SqliteJournalMode::Wal)
+        .foreign_keys(true)
+        .busy_timeout(BUSY_TIMEOUT)
+        .synchronous(SqliteSynchronous::Normal)
+}
+
+/// Resolve the location of the application database file.
+///
+/// Sources are tried in priority order: the explicit `db_path`, the
+/// `RUSTY_SHED_DB_PATH` environment variable, the `db_path` file in the XDG
+/// config directory and finally the XDG data directory default. This is the
+/// location `init_db_pool` opens, so it can be reported to users (for example
+/// in bug reports).
+pub fn resolve_db_location(db_path: Option<PathBuf>) -> DbLocation {
+    select_db_location(
+        db_path,
+        std::env::var_os(DB_PATH_ENV_VAR),
+        read_db_path_config_file(),
+    )
+}
+
+/// Pick the first non-empty location, in priority order.
+fn select_db_location(
+    explicit: Option<PathBuf>,
+    environment: Option<OsString>,
+    config_file: Option<PathBuf>,
+) -> DbLocation {
+    let non_empty = |path: &PathBuf| !path.as_os_str().is_empty();
+
+    if let Some(path) = explicit.filter(non_empty) {
+        return DbLocation {
+            path,
+            source: DbPathSource::Explicit,
+        };
+    }
+    if let Some(path) = environment.map(PathBuf::from).filter(non_empty) {
+        return DbLocation {
+            path,
+            source: DbPathSource::Environment,
+        };
+    }
+    if let Some(path) = config_file.filter(non_empty) {
+        return DbLocation {
+            path,
+            source: DbPathSource::ConfigFile,
+        };
+    }
+    DbLocation {
+        path: default_db_path(),
+        source: DbPathSource::Default,
+    }
+}
+
+/// Read the database location override from the XDG config directory.
+fn read_db_path_config_file() -> Option<PathBuf> {
+    let config_file =
+        BaseDirectories::with_prefix("rusty_shed").find_config_file(DB_PATH_CONFIG_FILE)?;
+
+    match std::fs::read_to_string(&config_file) {
+        Ok(contents) => Some(PathBuf::from(contents.trim())),
+        Err(e) => {
+            warn!("Failed to read {}: {e}", config_file.display());
+            None
+        }
+    }
+}
+
+/// The default location: `rusty_shed.db` in the XDG data directory, or in
+/// the working directory when the data directory cannot be determined.
+fn default_db_path() -> PathBuf {
+    let bd = BaseDirectories::with_prefix("rusty_shed");
+    bd.place_data_file("rusty_shed.db").unwrap_or_else(|e| {
+        error!("Failed to determine data file path via XDG: {e}");
+        PathBuf::from("rusty_shed.db")
+    })
+}
+
+/// The maximum number of pooled connections: `RUSTY_SHED_DB_MAX_CONNECTIONS`
+/// when it holds a positive number, otherwise `DEFAULT_MAX_CONNECTIONS`.
+pub fn resolve_max_connections() -> u32 {
+    parse_max_connections(std::env::var_os(DB_MAX_CONNECTIONS_ENV_VAR))
+}
+
+fn parse_max_connections(value: Option<OsString>) -> u32 {
+    let Some(value) = value else {
+        return DEFAULT_MAX_CONNECTIONS;
+    };
+    match value.to_str().map(|v| v.trim().parse::<u32>()) {
+        Some(Ok(max)) if max > 0 => max,
+        _ => {
+            warn!(
+                "Ignoring invalid {DB_MAX_CONNECTIONS_ENV_VAR}={}, using {DEFAULT_MAX_CONNECTIONS}",
+                value.to_string_lossy()
+            );
+            DEFAULT_MAX_CONNECTIONS
+        }
+    }
+}
+
+/// Check that `db_path` can hold the database file, creating its parent
+/// directory if needed.
+fn validate_db_path(db_path: &Path) -> Result<(), SqliteDbError> {
+    let invalid = |reason: String| SqliteDbError::InvalidPath {
+        path: db_path.to_path_buf(),
+        reason,
+    };
+
+    if db_path.is_dir() {
+        return Err(invalid("the path is a directory".to_string()));
+    }
+
+    // Ensure parent directory exists so SQLite can create the file
+    if let Some(parent) = db_path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            invalid(format!(
+                "cannot create parent directory {}: {e}",
+                parent.display()
+            ))
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Return the versions of the migrations successfully applied to `pool`.
+///
+/// Versions are read from the `_sqlx_migrations` bookkeeping table maintained
+/// by `sqlx` and returned in ascending order.
+pub async fn applied_migrations(pool: &SqlitePool) -> Result<Vec<i64>, SqliteDbError> {
+    let versions = sqlx::query_scalar(
+        "SELECT version FROM _sqlx_migrations WHERE success = 1 ORDER BY version",
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(versions)
+}
+
+/// The state of the embedded migrations in a database.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct MigrationStatus {
+    /// Versions successfully applied, in ascending order.
+    pub applied: Vec<i64>,
+    /// Embedded versions not yet applied, in ascending order.
+    pub pending: Vec<i64>,
+    /// The version of a migration that started but did not complete, if any.
+    pub dirty: Option<i64>,
+}
+
+impl fmt::Display for MigrationStatus {
+    /// Format the status for the logs, e.g. `applied [1, 2], pending [3], dirty 3`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "applied {:?}, pending {:?}", self.applied, self.pending)?;
+        match self.dirty {
+            Some(version) => write!(f, ", dirty {}", version),
+            None => write!(f, ", no dirty migration"),
+        }
+    }
+}
+
+/// Compare the migrations recorded in `pool` with the embedded `MIGRATOR`.
+///
+/// The `_sqlx_migrations` bookkeeping table keeps a row with `success = 0`
+/// for a migration that failed halfway; it is reported as `dirty` (and is
+/// also listed as pending, since it still has to be applied).
+pub async fn migration_status(pool: &SqlitePool) -> Result<MigrationStatus, SqliteDbError> {
+    let applied = applied_migrations(pool).await?;
+    let dirty: Option<i64> = sqlx::query_scalar(
+        "SELECT version FROM _sqlx_migrations WHERE success = 0 ORDER BY version LIMIT 1",
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    let pending = MIGRATOR
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| m.version)
+        .filter(|version| !applied.contains(version))
+        .collect();
+
+    Ok(MigrationStatus {
+        applied,
+        pending,
+        dirty,
+    })
+}
+
 /// Initialize and return an in-memory SQLite connection pool for tests.
 ///
 /// This creates a unique, named in-memory database using a generated UUID
@@ -84,7 +337,8 @@ pub async fn init_db_pool() -> Result<SqlitePool, SqliteDbError> {
 /// The function will:
 /// - generate a UUID-based name for the in-memory DB,
 /// - construct the named in-memory SQLite URL with `mode=memory&cache=shared`,
-/// - create a `SqlitePool` (max 5 connections),
+/// - create a `SqlitePool` (`DEFAULT_MAX_CONNECTIONS`) configured with the same
+///   pragmas as `init_db_pool`,
 /// - run the embedded migrations (`MIGRATOR`) against that pool, and
 /// - return the migrated, ready-to-use pool.
 ///
@@ -102,10 +356,11 @@ pub async fn init_in_memory_db_pool() -> Result<SqlitePool, SqliteDbError> {
     // still allowing the pool's connections to share the same DB.
     let id = Uuid::new_v4();
     let db_url = format!("sqlite:file:memdb-{}?mode=memory&cache=shared", id);
+    let options = configure_connection(SqliteConnectOptions::from_str(&db_url)?);
 
     let pool = SqlitePoolOptions::new()
-        .max_connections(5)
-        .connect(&db_url)
+        .max_connections(DEFAULT_MAX_CONNECTIONS)
+        .connect_with(options)
         .await?;
 
     // Run migrations against the in-memory database before returning.
@@ -124,6 +379,20 @@ pub enum SqliteDbError {
     /// Errors related to running embedded migrations.
     #[error("migration error: {0}")]
     MigrationError(#[from] sqlx::migrate::MigrateError),
+
+    /// Running the embedded migrations at startup failed.
+    ///
+    /// `status` describes the migrations recorded in the database at the time
+    /// of the failure, when it could be read.
+    #[error("migration error: {source}")]
+    MigrationFailed {
+        source: sqlx::migrate::MigrateError,
+        status: Option<MigrationStatus>,
+    },
+
+    /// The configured database location cannot be used.
+    #[error("invalid database path {}: {reason}", path.display())]
+    InvalidPath { path: PathBuf, reason: String },
 }
 
 #[cfg(test)]
@@ -144,4 +413,207 @@ mod tests {
         let v: i64 = row.get("v");
         assert_eq!(v, 1);
     }
+
+    #[tokio::test]
+    async fn applied_migrations_lists_embedded_migrations() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+
+        let versions = applied_migrations(&pool).await.expect("applied migrations");
+
+        assert!(!versions.is_empty());
+        let expected: Vec<i64> = MIGRATOR.iter().map(|m| m.version).collect();
+        assert_eq!(versions, expected);
+    }
+
+    /// A fresh, not yet created, temporary directory.
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("rusty_shed-{}", Uuid::new_v4()))
+    }
+
+    /// A file-backed pool in a fresh temporary directory.
+    async fn temp_file_pool() -> (SqlitePool, PathBuf) {
+        let dir = temp_dir();
+        let (pool, _) = init_db_pool(Some(dir.join("nested").join("rusty_shed.db")))
+            .await
+            .expect("open file pool");
+        (pool, dir)
+    }
+
+    #[tokio::test]
+    async fn file_db_pool_connections_use_wal_and_foreign_keys() {
+        let (pool, dir) = temp_file_pool().await;
+
+        let mut conn = pool.acquire().await.expect("acquire connection");
+        let foreign_keys: i64 = sqlx::query_scalar("PRAGMA foreign_keys")
+            .fetch_one(&mut *conn)
+            .await
+            .expect("foreign_keys");
+        let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+            .fetch_one(&mut *conn)
+            .await
+            .expect("journal_mode");
+        let busy_timeout: i64 = sqlx::query_scalar("PRAGMA busy_timeout")
+            .fetch_one(&mut *conn)
+            .await
+            .expect("busy_timeout");
+
+        assert_eq!(foreign_keys, 1);
+        assert_eq!(journal_mode, "wal");
+        assert_eq!(busy_timeout, 5000);
+
+        drop(conn);
+        pool.close().await;
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[tokio::test]
+    async fn in_memory_db_pool_enforces_foreign_keys() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+
+        let foreign_keys: i64 = sqlx::query_scalar("PRAGMA foreign_keys")
+            .fetch_one(&pool)
+            .await
+            .expect("foreign_keys");
+        assert_eq!(foreign_keys, 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_writes_do_not_fail_with_database_locked() {
+        let (pool, dir) = temp_file_pool().await;
+
+        let writes = (0..20).map(|i| {
+            let pool = pool.clone();
+            tokio::spawn(async move {
+                let mut tx = pool.begin().await?;
+                sqlx::query("INSERT INTO settings (key, value) VALUES (?1, ?2)")
+                    .bind(format!("key-{}", i))
+                    .bind(i.to_string())
+                    .execute(&mut *tx)
+                    .await?;
+                tx.commit().await
+            })
+        });
+        for write in writes.collect::<Vec<_>>() {
+            write.await.expect("join").expect("concurrent write");
+        }
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM settings")
+            .fetch_one(&pool)
+            .await
+            .expect("count");
+        assert_eq!(count, 20);
+
+        pool.close().await;
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[tokio::test]
+    async fn file_db_pool_applies_the_configured_connection_limit() {
+        let dir = temp_dir();
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+
+        let pool = open_db_pool(&dir.join("rusty_shed.db"), 3)
+            .await
+            .expect("open file pool");
+        assert_eq!(pool.options().get_max_connections(), 3);
+        assert_eq!(PoolMonitor::new(pool.clone()).metrics().max_connections, 3);
+
+        pool.close().await;
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn parse_max_connections_falls_back_to_the_default() {
+        assert_eq!(parse_max_connections(None), DEFAULT_MAX_CONNECTIONS);
+        assert_eq!(parse_max_connections(Some(OsString::from("8"))), 8);
+        assert_eq!(parse_max_connections(Some(OsString::from(" 2 "))), 2);
+        assert_eq!(
+            parse_max_connections(Some(OsString::from("0"))),
+            DEFAULT_MAX_CONNECTIONS
+        );
+        assert_eq!(
+            parse_max_connections(Some(OsString::from("many"))),
+            DEFAULT_MAX_CONNECTIONS
+        );
+    }
+
+    #[test]
+    fn select_db_location_follows_priority_order() {
+        let explicit = Some(PathBuf::from("/explicit.db"));
+        let environment = Some(OsString::from("/env.db"));
+        let config_file = Some(PathBuf::from("/config.db"));
+
+        let location = select_db_location(explicit, environment.clone(), config_file.clone());
+        assert_eq!(location.source, DbPathSource::Explicit);
+
+        let location = select_db_location(None, environment, config_file.clone());
+        assert_eq!(location.source, DbPathSource::Environment);
+        assert_eq!(location.path, PathBuf::from("/env.db"));
+
+        let location = select_db_location(None, Some(OsString::new()), config_file);
+        assert_eq!(location.source, DbPathSource::ConfigFile);
+
+        let location = select_db_location(None, None, None);
+        assert_eq!(location.source, DbPathSource::Default);
+    }
+
+    #[tokio::test]
+    async fn init_db_pool_uses_environment_override() {
+        let dir = temp_dir();
+        let db_path = dir.join("portable").join("shed.db");
+
+        // SAFETY: no other test reads or writes this variable.
+        unsafe { std::env::set_var(DB_PATH_ENV_VAR, &db_path) };
+        let result = init_db_pool(None).await;
+        unsafe { std::env::remove_var(DB_PATH_ENV_VAR) };
+
+        let (pool, location) = result.expect("init db pool");
+        assert_eq!(location.source, DbPathSource::Environment);
+        assert_eq!(location.path, db_path);
+        assert!(db_path.is_file());
+
+        pool.close().await;
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[tokio::test]
+    async fn init_db_pool_rejects_a_directory() {
+        let dir = temp_dir();
+        std::fs::create_dir_all(&dir).expect("create temp dir");
+
+        let err = init_db_pool(Some(dir.clone())).await.unwrap_err();
+        assert!(matches!(err, SqliteDbError::InvalidPath { .. }));
+        assert!(err.to_string().contains("is a directory"));
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[tokio::test]
+    async fn migration_status_reports_nothing_pending_after_migrations() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+
+        let status = migration_status(&pool).await.expect("migration status");
+
+        let expected: Vec<i64> = MIGRATOR.iter().map(|m| m.version).collect();
+        assert_eq!(status.applied, expected);
+        assert!(status.pending.is_empty());
+        assert_eq!(status.dirty, None);
+    }
+
+    #[tokio::test]
+    async fn migration_status_reports_pending_and_dirty_migrations() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let last = MIGRATOR.iter().last().expect("embedded migration").version;
+        sqlx::query("UPDATE _sqlx_migrations SET success = 0 WHERE version = ?1")
+            .bind(last)
+            .execute(&pool)
+            .await
+            .expect("mark migration dirty");
+
+        let status = migration_status(&pool).await.expect("migration status");
+
+        assert_eq!(status.pending, vec![last]);
+        assert_eq!(status.dirty, Some(last));
+        assert!(status.to_string().ends_with(&format!("dirty {}", last)));
+    }
 }
@@ -0,0 +1,419 @@
+//! Integrity scan for the SQLite database.
+//!
+//! Manual edits or interrupted writes can leave rows pointing at parents that
+//! no longer exist (for example owned rolling stocks of a deleted collection
+//! item). `integrity_report` runs SQLite's own checks and looks for orphans
+//! along every relationship of the catalog and collecting schemas; in repair
+//! mode the orphans are removed in a single transaction.
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use sqlx::{Connection, SqliteConnection, SqlitePool};
+
+use crate::db::SqliteDbError;
+
+/// What the repair does with a row whose parent is missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Repair {
+    /// Delete the row (the child is meaningless without its parent).
+    Delete,
+    /// Clear the dangling reference (the reference is optional).
+    SetNull,
+}
+
+/// A child-to-parent relationship checked for orphans.
+struct Relationship {
+    table: &'static str,
+    id_column: &'static str,
+    column: &'static str,
+    parent_table: &'static str,
+    repair: Repair,
+}
+
+/// Every relationship of the catalog and collecting schemas, parents first so
+/// a repair pass removes a parent before looking at its children.
+const RELATIONSHIPS: &[Relationship] = &[
+    Relationship {
+        table: "railway_models",
+        id_column: "id",
+        column: "manufacturer_id",
+        parent_table: "manufacturers",
+        repair: Repair::Delete,
+    },
+    Relationship {
+        table: "rolling_stocks",
+        id_column: "id",
+        column: "railway_model_id",
+        parent_table: "railway_models",
+        repair: Repair::Delete,
+    },
+    Relationship {
+        table: "rolling_stocks",
+        id_column: "id",
+        column: "railway_company_id",
+        parent_table: "railway_companies",
+        repair: Repair::Delete,
+    },
+    Relationship {
+        table: "collection_items",
+        id_column: "id",
+        column: "collection_id",
+        parent_table: "collections",
+        repair: Repair::Delete,
+    },
+    Relationship {
+        table: "collection_items",
+        id_column: "id",
+        column: "railway_model_id",
+        parent_table: "railway_models",
+        repair: Repair::Delete,
+    },
+    Relationship {
+        table: "owned_rolling_stocks",
+        id_column: "id",
+        column: "collection_item_id",
+        parent_table: "collection_items",
+        repair: Repair::Delete,
+    },
+    Relationship {
+        table: "owned_rolling_stocks",
+        id_column: "id",
+        column: "rolling_stock_id",
+        parent_table: "rolling_stocks",
+        repair: Repair::SetNull,
+    },
+    Relationship {
+        table: "purchase_infos",
+        id_column: "purchase_id",
+        column: "collection_item_id",
+        parent_table: "collection_items",
+        repair: Repair::Delete,
+    },
+];
+
+impl Relationship {
+    // Identifiers come from `RELATIONSHIPS` only, never from user input.
+    fn orphans_sql(&self) -> String {
+        format!(
+            "SELECT c.{id} FROM {table} c WHERE c.{column} IS NOT NULL AND NOT EXISTS (SELECT 1 FROM {parent} p WHERE p.id = c.{column}) ORDER BY c.{id}",
+            id = self.id_column,
+            table = self.table,
+            column = self.column,
+            parent = self.parent_table,
+        )
+    }
+
+    fn repair_sql(&self) -> String {
+        let orphan_filter = format!(
+            "{column} IS NOT NULL AND NOT EXISTS (SELECT 1 FROM {parent} p WHERE p.id = {table}.{column})",
+            table = self.table,
+            column = self.column,
+            parent = self.parent_table,
+        );
+        match self.repair {
+            Repair::Delete => format!("DELETE FROM {} WHERE {}", self.table, orphan_filter),
+            Repair::SetNull => format!(
+                "UPDATE {table} SET {column} = NULL WHERE {filter}",
+                table = self.table,
+                column = self.column,
+                filter = orphan_filter,
+            ),
+        }
+    }
+}
+
+/// Rows of `table` whose `column` points at a missing row of `parent_table`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct OrphanedRows {
+    /// The table holding the orphaned rows.
+    pub table: String,
+    /// The column holding the dangling reference.
+    pub column: String,
+    /// The table the reference should point to.
+    pub parent_table: String,
+    /// The number of orphaned rows.
+    pub count: u32,
+    /// The ids of the orphaned rows.
+    pub ids: Vec<String>,
+}
+
+/// Foreign key violations reported by `PRAGMA foreign_key_check` for a table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ForeignKeyViolations {
+    /// The table holding the violating rows.
+    pub table: String,
+    /// The table the violated foreign key refers to.
+    pub parent_table: String,
+    /// The number of violating rows.
+    pub count: u32,
+}
+
+/// The outcome of `integrity_report`.
+///
+/// The findings describe the database as it was before any repair.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct IntegrityReport {
+    /// Problems reported by `PRAGMA integrity_check` (empty when it reports "ok").
+    pub integrity_errors: Vec<String>,
+    /// Violations reported by `PRAGMA foreign_key_check`, grouped by table.
+    pub foreign_key_violations: Vec<ForeignKeyViolations>,
+    /// Orphaned rows, one entry per relationship that has any.
+    pub orphans: Vec<OrphanedRows>,
+    /// Whether the orphans were repaired.
+    pub repaired: bool,
+}
+
+impl IntegrityReport {
+    /// Returns `true` when no problem was found.
+    pub fn is_clean(&self) -> bool {
+        self.integrity_errors.is_empty()
+            && self.foreign_key_violations.is_empty()
+            && self.orphans.is_empty()
+    }
+}
+
+/// Scan the database for corruption, foreign key violations and orphans.
+///
+/// With `repair` set, orphans are then removed in a single transaction:
+/// rows that cannot exist without their parent are deleted and optional
+/// dangling references are set to `NULL`. Relationships are repaired parents
+/// first, so rows left without a parent by the repair itself (for example
+/// the items of a deleted railway model) are removed in the same pass.
+///
+/// Problems reported by `PRAGMA integrity_check` are never repaired.
+pub async fn integrity_report(
+    pool: &SqlitePool,
+    repair: bool,
+) -> Result<IntegrityReport, SqliteDbError> {
+    let integrity_errors: Vec<String> = sqlx::query_scalar("PRAGMA integrity_check")
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .filter(|message: &String| message != "ok")
+        .collect();
+
+    let foreign_key_violations = sqlx::query_as::<_, (String, String, u32)>(
+        "SELECT \"table\", parent, COUNT(*) FROM pragma_foreign_key_check GROUP BY \"table\", parent ORDER BY \"table\", parent",
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .map(|(table, parent_table, count)| ForeignKeyViolations {
+        table,
+        parent_table,
+        count,
+    })
+    .collect();
+
+    let mut orphans = Vec::new();
+    for relationship in RELATIONSHIPS {
+        let ids: Vec<String> = sqlx::query_scalar(&relationship.orphans_sql())
+            .fetch_all(pool)
+            .await?;
+        if !ids.is_empty() {
+            orphans.push(OrphanedRows {
+                table: relationship.table.to_string(),
+                column: relationship.column.to_string(),
+                parent_table: relationship.parent_table.to_string(),
+                count: ids.len() as u32,
+                ids,
+            });
+        }
+    }
+
+    let mut report = IntegrityReport {
+        integrity_errors,
+        foreign_key_violations,
+        orphans,
+        repaired: false,
+    };
+
+    if repair && !report.orphans.is_empty() {
+        let mut conn = pool.acquire().await?;
+        // Enforcement would reject deleting a railway model still referenced
+        // by collection items (`ON DELETE RESTRICT`), even when deferred; the
+        // pragma cannot change inside a transaction, so it is switched off
+        // around it and always switched back on before the connection is
+        // returned to the pool; a connection it cannot be switched back on
+        // for is closed instead.
+        sqlx::query("PRAGMA foreign_keys = OFF")
+            .execute(&mut *conn)
+            .await?;
+        let result = repair_orphans(&mut conn).await;
+        if let Err(e) = sqlx::query("PRAGMA foreign_keys = ON")
+            .execute(&mut *conn)
+            .await
+        {
+            conn.close_on_drop();
+            return Err(e.into());
+        }
+        result?;
+        report.repaired = true;
+    }
+
+    Ok(report)
+}
+
+/// Remove every orphan, parents first, in a transaction on `conn`.
+async fn repair_orphans(conn: &mut SqliteConnection) -> Result<(), SqliteDbError> {
+    let mut tx = conn.begin().await?;
+    for relationship in RELATIONSHIPS {
+        if let Err(e) = sqlx::query(&relationship.repair_sql())
+            .execute(&mut *tx)
+            .await
+        {
+            if let Err(rollback_error) = tx.rollback().await {
+                warn!("Failed to roll back orphan repair: {rollback_error}");
+            }
+            return Err(e.into());
+        }
+    }
+    tx.commit().await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog::infrastructure::testing::CatalogTestDb;
+    use crate::collecting::infrastructure::testing::CollectingTestDb;
+    use anyhow::Result;
+    use pretty_assertions::assert_eq;
+
+    /// Run `sql` with foreign key enforcement off, as a manual edit would.
+    async fn execute_unchecked(pool: &SqlitePool, sql: &str) -> Result<()> {
+        let mut conn = pool.acquire().await?;
+        sqlx::query("PRAGMA foreign_keys = OFF")
+            .execute(&mut *conn)
+            .await?;
+        sqlx::query(sql).execute(&mut *conn).await?;
+        sqlx::query("PRAGMA foreign_keys = ON")
+            .execute(&mut *conn)
+            .await?;
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn integrity_report_is_clean_for_consistent_data(pool: SqlitePool) -> Result<()> {
+        let catalog_test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        CollectingTestDb::new(pool.clone())
+            .setup_minimal_collection(
+                &catalog_test_data.railway_model_id,
+                vec![catalog_test_data.rolling_stock_ids[0].as_str()],
+            )
+            .await?;
+
+        let report = integrity_report(&pool, false).await?;
+
+        assert!(report.is_clean(), "{report:?}");
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn integrity_report_detects_and_repairs_orphans(pool: SqlitePool) -> Result<()> {
+        let catalog_test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        let rolling_stock_id = catalog_test_data.rolling_stock_ids[0].clone();
+        let data = CollectingTestDb::new(pool.clone())
+            .setup_minimal_collection(
+                &catalog_test_data.railway_model_id,
+                vec![rolling_stock_id.as_str()],
+            )
+            .await?;
+
+        // A purchase info left behind by a deleted item, and an owned
+        // rolling stock whose catalog entry disappeared.
+        execute_unchecked(
+            &pool,
+            "INSERT INTO purchase_infos (purchase_id, collection_item_id, purchase_date) VALUES ('stale-purchase', 'deleted-item', '2024-01-01')",
+        )
+        .await?;
+        execute_unchecked(
+            &pool,
+            &format!("DELETE FROM rolling_stocks WHERE id = '{rolling_stock_id}'"),
+        )
+        .await?;
+
+        let report = integrity_report(&pool, false).await?;
+
+        assert!(report.integrity_errors.is_empty());
+        assert!(!report.repaired);
+        assert_eq!(
+            report.orphans,
+            vec![
+                OrphanedRows {
+                    table: "owned_rolling_stocks".to_string(),
+                    column: "rolling_stock_id".to_string(),
+                    parent_table: "rolling_stocks".to_string(),
+                    count: 1,
+                    ids: data.owned_rolling_stock_ids.clone(),
+                },
+                OrphanedRows {
+                    table: "purchase_infos".to_string(),
+                    column: "collection_item_id".to_string(),
+                    parent_table: "collection_items".to_string(),
+                    count: 1,
+                    ids: vec!["stale-purchase".to_string()],
+                },
+            ]
+        );
+        assert_eq!(
+            report.foreign_key_violations,
+            vec![
+                ForeignKeyViolations {
+                    table: "owned_rolling_stocks".to_string(),
+                    parent_table: "rolling_stocks".to_string(),
+                    count: 1,
+                },
+                ForeignKeyViolations {
+                    table: "purchase_infos".to_string(),
+                    parent_table: "collection_items".to_string(),
+                    count: 1,
+                },
+            ]
+        );
+
+        let report = integrity_report(&pool, true).await?;
+        assert!(report.repaired);
+        assert_eq!(report.orphans.len(), 2);
+
+        assert!(integrity_report(&pool, false).await?.is_clean());
+        // The owned rolling stock is kept, without its catalog reference.
+        let rolling_stock_ref: Option<String> =
+            sqlx::query_scalar("SELECT rolling_stock_id FROM owned_rolling_stocks WHERE id = ?1")
+                .bind(&data.owned_rolling_stock_ids[0])
+                .fetch_one(&pool)
+                .await?;
+        assert_eq!(rolling_stock_ref, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn repair_removes_orphans_of_orphans(pool: SqlitePool) -> Result<()> {
+        let catalog_test_data = CatalogTestDb::new(pool.clone())
+            .setup_railway_model()
+            .await?;
+        CollectingTestDb::new(pool.clone())
+            .setup_minimal_collection(&catalog_test_data.railway_model_id, vec![])
+            .await?;
+
+        // Dropping the manufacturer orphans the railway model, which the
+        // collection item still references (`ON DELETE RESTRICT`).
+        execute_unchecked(&pool, "DELETE FROM manufacturers").await?;
+
+        let report = integrity_report(&pool, true).await?;
+        assert!(report.repaired);
+
+        assert!(integrity_report(&pool, false).await?.is_clean());
+        let items: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM collection_items")
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(items, 0);
+
+        Ok(())
+    }
+}
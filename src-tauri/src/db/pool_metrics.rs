@@ -0,0 +1,180 @@
+//! Connection pool observability.
+//!
+//! `PoolMonitor` periodically samples the pool: its size, the idle
+//! connections and how long acquiring a connection takes. A long acquire
+//! wait means every connection was busy, which is the first thing to check
+//! when the application feels sluggish. The numbers are logged at debug level
+//! and reported by `get_app_info`.
+
+use log::debug;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A snapshot of the connection pool.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct PoolMetrics {
+    /// The configured maximum number of connections.
+    pub max_connections: u32,
+    /// The number of open connections, idle or in use.
+    pub size: u32,
+    /// The number of open connections not in use.
+    pub idle: u32,
+    /// How many acquire waits were sampled.
+    pub acquire_samples: u32,
+    /// The most recent acquire wait, in milliseconds.
+    pub last_acquire_wait_ms: f64,
+    /// The longest acquire wait sampled, in milliseconds.
+    pub max_acquire_wait_ms: f64,
+    /// The mean acquire wait, in milliseconds.
+    pub mean_acquire_wait_ms: f64,
+}
+
+#[derive(Debug, Default)]
+struct AcquireWaits {
+    samples: u32,
+    total: Duration,
+    max: Duration,
+    last: Duration,
+}
+
+impl AcquireWaits {
+    fn record(&mut self, wait: Duration) {
+        self.samples = self.samples.saturating_add(1);
+        self.total += wait;
+        self.max = self.max.max(wait);
+        self.last = wait;
+    }
+
+    fn mean(&self) -> Duration {
+        if self.samples == 0 {
+            Duration::ZERO
+        } else {
+            self.total / self.samples
+        }
+    }
+}
+
+/// Samples a pool and keeps its acquire wait statistics.
+///
+/// Cloning is cheap; clones share the statistics.
+#[derive(Debug, Clone)]
+pub struct PoolMonitor {
+    pool: SqlitePool,
+    waits: Arc<Mutex<AcquireWaits>>,
+}
+
+impl PoolMonitor {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            waits: Arc::new(Mutex::new(AcquireWaits::default())),
+        }
+    }
+
+    /// The current pool size and idle connections, with the acquire wait
+    /// statistics sampled so far.
+    pub fn metrics(&self) -> PoolMetrics {
+        let waits = self.waits.lock().unwrap_or_else(|e| e.into_inner());
+        PoolMetrics {
+            max_connections: self.pool.options().get_max_connections(),
+            size: self.pool.size(),
+            idle: u32::try_from(self.pool.num_idle()).unwrap_or(u32::MAX),
+            acquire_samples: waits.samples,
+            last_acquire_wait_ms: as_millis(waits.last),
+            max_acquire_wait_ms: as_millis(waits.max),
+            mean_acquire_wait_ms: as_millis(waits.mean()),
+        }
+    }
+
+    /// Time acquiring a connection, record it and log the resulting metrics.
+    ///
+    /// The connection is released immediately. A failed acquire (for example
+    /// a timeout on an exhausted pool) is recorded with the time it took.
+    pub async fn sample(&self) -> PoolMetrics {
+        let started = Instant::now();
+        let acquired = self.pool.acquire().await;
+        let wait = started.elapsed();
+        if let Err(e) = &acquired {
+            debug!("Connection pool sample failed to acquire a connection: {e}");
+        }
+        drop(acquired);
+
+        self.waits
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .record(wait);
+
+        let metrics = self.metrics();
+        debug!(
+            "Connection pool: size={} idle={} max={} acquire_wait={:.3}ms (mean {:.3}ms, max {:.3}ms over {} samples)",
+            metrics.size,
+            metrics.idle,
+            metrics.max_connections,
+            metrics.last_acquire_wait_ms,
+            metrics.mean_acquire_wait_ms,
+            metrics.max_acquire_wait_ms,
+            metrics.acquire_samples
+        );
+        metrics
+    }
+
+    /// Sample the pool every `interval`, until the pool is closed.
+    pub async fn run(self, interval: Duration) {
+        while !self.pool.is_closed() {
+            tokio::time::sleep(interval).await;
+            self.sample().await;
+        }
+    }
+}
+
+fn as_millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_in_memory_db_pool;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn metrics_before_sampling_report_no_waits() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+
+        let metrics = PoolMonitor::new(pool).metrics();
+
+        assert_eq!(metrics.max_connections, 5);
+        assert_eq!(metrics.acquire_samples, 0);
+        assert_eq!(metrics.max_acquire_wait_ms, 0.0);
+        assert_eq!(metrics.mean_acquire_wait_ms, 0.0);
+    }
+
+    #[tokio::test]
+    async fn sample_records_acquire_waits() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let monitor = PoolMonitor::new(pool);
+
+        monitor.sample().await;
+        let metrics = monitor.clone().sample().await;
+
+        // Clones share the statistics.
+        assert_eq!(metrics.acquire_samples, 2);
+        assert_eq!(monitor.metrics().acquire_samples, 2);
+        assert!(metrics.size >= 1);
+        assert!(metrics.idle <= metrics.size);
+        assert!(metrics.max_acquire_wait_ms >= metrics.mean_acquire_wait_ms);
+    }
+
+    #[test]
+    fn mean_of_recorded_waits() {
+        let mut waits = AcquireWaits::default();
+        waits.record(Duration::from_millis(10));
+        waits.record(Duration::from_millis(30));
+
+        assert_eq!(waits.mean(), Duration::from_millis(20));
+        assert_eq!(waits.max, Duration::from_millis(30));
+        assert_eq!(waits.last, Duration::from_millis(30));
+    }
+}
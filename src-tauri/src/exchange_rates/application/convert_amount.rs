@@ -0,0 +1,29 @@
+use crate::core::domain::{Currency, MonetaryAmount};
+use crate::exchange_rates::domain::conversion::ConversionPreview;
+use crate::exchange_rates::domain::repository::ExchangeRateRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct ConvertAmountUseCase {
+    repo: Arc<dyn ExchangeRateRepository>,
+}
+
+impl ConvertAmountUseCase {
+    pub fn new(repo: Arc<dyn ExchangeRateRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Convert `amount_minor` (in the smallest unit of `from`) into `to`.
+    ///
+    /// Fails with `ExchangeRateError::MissingRate` when no stored rate links
+    /// the two currencies.
+    pub async fn execute(
+        &self,
+        amount_minor: u64,
+        from: Currency,
+        to: Currency,
+    ) -> Result<ConversionPreview> {
+        let rates = self.repo.get_all().await?;
+        Ok(rates.convert(&MonetaryAmount::new(amount_minor, from), to)?)
+    }
+}
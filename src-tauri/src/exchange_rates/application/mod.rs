@@ -0,0 +1,2 @@
+pub mod convert_amount;
+pub mod update_exchange_rate;
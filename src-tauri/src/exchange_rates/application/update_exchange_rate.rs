@@ -0,0 +1,64 @@
+use crate::core::domain::Currency;
+use crate::exchange_rates::domain::error::ExchangeRateError;
+use crate::exchange_rates::domain::exchange_rate::ExchangeRate;
+use crate::exchange_rates::domain::repository::ExchangeRateRepository;
+use anyhow::Result;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+pub struct UpdateExchangeRateUseCase {
+    repo: Arc<dyn ExchangeRateRepository>,
+}
+
+impl UpdateExchangeRateUseCase {
+    pub fn new(repo: Arc<dyn ExchangeRateRepository>) -> Self {
+        Self { repo }
+    }
+
+    /// Store `rate` as the value of one unit of `from` in `to`.
+    ///
+    /// The rate must be positive and the currencies must differ.
+    pub async fn execute(
+        &self,
+        from: Currency,
+        to: Currency,
+        rate: Decimal,
+    ) -> Result<ExchangeRate> {
+        if from == to {
+            return Err(ExchangeRateError::SameCurrency(from).into());
+        }
+        if rate <= Decimal::ZERO {
+            return Err(ExchangeRateError::NonPositiveRate(rate).into());
+        }
+
+        self.repo.upsert(from, to, rate).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_in_memory_db_pool;
+    use crate::exchange_rates::infrastructure::sqlite_repo::SqliteExchangeRateRepository;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn invalid_rates_are_rejected() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let use_case =
+            UpdateExchangeRateUseCase::new(Arc::new(SqliteExchangeRateRepository::new(pool)));
+
+        assert!(
+            use_case
+                .execute(Currency::EUR, Currency::EUR, dec!(1))
+                .await
+                .is_err()
+        );
+        assert!(
+            use_case
+                .execute(Currency::EUR, Currency::USD, dec!(0))
+                .await
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Currency conversion over the stored exchange rate table.
+//!
+//! `ExchangeRates` resolves a rate between two currencies by trying, in
+//! order: the direct rate, the inverse of the opposite rate and finally a
+//! cross rate through `BASE_CURRENCY`. Amounts are converted in their
+//! smallest units and rounded to the nearest unit of the target currency.
+
+use crate::core::domain::{Currency, MonetaryAmount};
+use crate::exchange_rates::domain::error::ExchangeRateError;
+use crate::exchange_rates::domain::exchange_rate::ExchangeRate;
+use chrono::NaiveDateTime;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+type Result<T> = std::result::Result<T, ExchangeRateError>;
+
+/// The currency used to derive cross rates when no direct rate is stored.
+pub const BASE_CURRENCY: Currency = Currency::EUR;
+
+/// The result of converting an amount, with the rate that was applied.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct ConversionPreview {
+    /// The converted amount, in the target currency.
+    pub amount: MonetaryAmount,
+
+    /// How many units of the target currency one unit of the source
+    /// currency is worth.
+    pub rate: Decimal,
+
+    /// When the applied rate was last updated; for cross rates this is the
+    /// older of the two rates involved. `None` when both currencies are the
+    /// same.
+    pub rate_updated_at: Option<NaiveDateTime>,
+}
+
+/// The full set of stored exchange rates, indexed by currency pair.
+#[derive(Debug, Clone, Default)]
+pub struct ExchangeRates {
+    rates: HashMap<(Currency, Currency), ExchangeRate>,
+}
+
+impl ExchangeRates {
+    /// Build the table from the stored rates.
+    pub fn new(rates: Vec<ExchangeRate>) -> Self {
+        Self {
+            rates: rates.into_iter().map(|r| ((r.from, r.to), r)).collect(),
+        }
+    }
+
+    /// Convert `amount` into `to`, using the best available rate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExchangeRateError::MissingRate` when no rate links the two
+    /// currencies and `ExchangeRateError::Overflow` when the result does not
+    /// fit in a `u64`.
+    pub fn convert(&self, amount: &MonetaryAmount, to: Currency) -> Result<ConversionPreview> {
+        let (rate, rate_updated_at) = self.rate(amount.currency, to)?;
+
+        let major = Decimal::from(amount.amount) / Self::unit(amount.currency);
+        let converted = (major * rate * Self::unit(to))
+            .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+            .to_u64()
+            .ok_or(ExchangeRateError::Overflow)?;
+
+        Ok(ConversionPreview {
+            amount: MonetaryAmount::new(converted, to),
+            rate,
+            rate_updated_at,
+        })
+    }
+
+    /// Resolve the rate from `from` to `to` together with its timestamp.
+    fn rate(&self, from: Currency, to: Currency) -> Result<(Decimal, Option<NaiveDateTime>)> {
+        if from == to {
+            return Ok((Decimal::ONE, None));
+        }
+
+        if let Some((rate, updated_at)) = self.pair_rate(from, to) {
+            return Ok((rate, Some(updated_at)));
+        }
+
+        match (
+            self.pair_rate(from, BASE_CURRENCY),
+            self.pair_rate(BASE_CURRENCY, to),
+        ) {
+            (Some((first, first_updated_at)), Some((second, second_updated_at))) => Ok((
+                first * second,
+                Some(first_updated_at.min(second_updated_at)),
+            )),
+            _ => Err(ExchangeRateError::MissingRate(from, to)),
+        }
+    }
+
+    /// The direct rate for the pair, or the inverse of the opposite rate.
+    fn pair_rate(&self, from: Currency, to: Currency) -> Option<(Decimal, NaiveDateTime)> {
+        if from == to {
+            return None;
+        }
+        if let Some(r) = self.rates.get(&(from, to)) {
+            return Some((r.rate, r.updated_at));
+        }
+        self.rates
+            .get(&(to, from))
+            .map(|r| (Decimal::ONE / r.rate, r.updated_at))
+    }
+
+    /// The number of smallest units in one major unit of `currency`.
+    fn unit(currency: Currency) -> Decimal {
+        Decimal::from(10u64.pow(currency.decimal_places()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    fn at(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 1, day)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    fn rate(from: Currency, to: Currency, rate: Decimal, day: u32) -> ExchangeRate {
+        ExchangeRate {
+            from,
+            to,
+            rate,
+            updated_at: at(day),
+        }
+    }
+
+    #[test]
+    fn convert_uses_direct_rate() {
+        let rates = ExchangeRates::new(vec![rate(Currency::GBP, Currency::EUR, dec!(1.1622), 3)]);
+
+        let preview = rates
+            .convert(&MonetaryAmount::new(4500, Currency::GBP), Currency::EUR)
+            .unwrap();
+
+        assert_eq!(preview.amount.amount, 5230);
+        assert_eq!(preview.amount.currency, Currency::EUR);
+        assert_eq!(preview.rate, dec!(1.1622));
+        assert_eq!(preview.rate_updated_at, Some(at(3)));
+    }
+
+    #[test]
+    fn convert_uses_inverse_rate() {
+        let rates = ExchangeRates::new(vec![rate(Currency::EUR, Currency::USD, dec!(1.25), 3)]);
+
+        let preview = rates
+            .convert(&MonetaryAmount::new(1000, Currency::USD), Currency::EUR)
+            .unwrap();
+
+        assert_eq!(preview.amount.amount, 800);
+        assert_eq!(preview.rate, dec!(0.8));
+    }
+
+    #[test]
+    fn convert_crosses_through_base_currency() {
+        let rates = ExchangeRates::new(vec![
+            rate(Currency::GBP, Currency::EUR, dec!(1.2), 5),
+            rate(Currency::EUR, Currency::JPY, dec!(160), 2),
+        ]);
+
+        let preview = rates
+            .convert(&MonetaryAmount::new(1000, Currency::GBP), Currency::JPY)
+            .unwrap();
+
+        // 10.00 GBP -> 12.00 EUR -> 1920 JPY (no minor units)
+        assert_eq!(preview.amount.amount, 1920);
+        assert_eq!(preview.amount.currency, Currency::JPY);
+        assert_eq!(preview.rate, dec!(192.0));
+        assert_eq!(preview.rate_updated_at, Some(at(2)));
+    }
+
+    #[test]
+    fn convert_same_currency_is_identity() {
+        let rates = ExchangeRates::default();
+
+        let preview = rates
+            .convert(&MonetaryAmount::new(1234, Currency::USD), Currency::USD)
+            .unwrap();
+
+        assert_eq!(preview.amount.amount, 1234);
+        assert_eq!(preview.rate, Decimal::ONE);
+        assert_eq!(preview.rate_updated_at, None);
+    }
+
+    #[test]
+    fn convert_without_rate_fails() {
+        let rates = ExchangeRates::new(vec![rate(Currency::GBP, Currency::EUR, dec!(1.2), 1)]);
+
+        let err = rates
+            .convert(&MonetaryAmount::new(1000, Currency::USD), Currency::GBP)
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ExchangeRateError::MissingRate(Currency::USD, Currency::GBP)
+        ));
+    }
+}
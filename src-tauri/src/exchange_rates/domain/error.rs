@@ -0,0 +1,24 @@
+use crate::core::domain::Currency;
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+/// Error types for exchange rate operations.
+#[derive(Debug, Error)]
+pub enum ExchangeRateError {
+    /// No stored rate (direct, inverse or through the base currency) links
+    /// the two currencies.
+    #[error("no exchange rate from {} to {}", .0.code(), .1.code())]
+    MissingRate(Currency, Currency),
+
+    /// Exchange rates must be strictly positive.
+    #[error("exchange rate must be positive, got {0}")]
+    NonPositiveRate(Decimal),
+
+    /// A rate from a currency to itself is always 1 and cannot be stored.
+    #[error("cannot store an exchange rate from {} to itself", .0.code())]
+    SameCurrency(Currency),
+
+    /// The converted amount does not fit the `MonetaryAmount` range.
+    #[error("converted amount overflows")]
+    Overflow,
+}
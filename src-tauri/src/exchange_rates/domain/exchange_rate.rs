@@ -0,0 +1,23 @@
+use crate::core::domain::Currency;
+use chrono::NaiveDateTime;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// A stored conversion rate between two currencies.
+///
+/// One unit of `from` is worth `rate` units of `to` (major units, e.g. euros
+/// rather than cents).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ExchangeRate {
+    /// The currency being converted from.
+    pub from: Currency,
+
+    /// The currency being converted to.
+    pub to: Currency,
+
+    /// How many units of `to` one unit of `from` is worth.
+    pub rate: Decimal,
+
+    /// When the rate was last updated.
+    pub updated_at: NaiveDateTime,
+}
@@ -0,0 +1,4 @@
+pub mod conversion;
+pub mod error;
+pub mod exchange_rate;
+pub mod repository;
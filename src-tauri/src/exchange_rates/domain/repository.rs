@@ -0,0 +1,19 @@
+use crate::core::domain::Currency;
+use crate::exchange_rates::domain::conversion::ExchangeRates;
+use crate::exchange_rates::domain::exchange_rate::ExchangeRate;
+use rust_decimal::Decimal;
+
+#[async_trait::async_trait]
+pub trait ExchangeRateRepository: Send + Sync {
+    /// Load every stored exchange rate.
+    async fn get_all(&self) -> anyhow::Result<ExchangeRates>;
+
+    /// Insert or replace the rate from `from` to `to`, refreshing its
+    /// timestamp.
+    async fn upsert(
+        &self,
+        from: Currency,
+        to: Currency,
+        rate: Decimal,
+    ) -> anyhow::Result<ExchangeRate>;
+}
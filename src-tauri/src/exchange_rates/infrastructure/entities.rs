@@ -0,0 +1,17 @@
+//! Database row representations for the `exchange_rates` feature.
+//!
+//! These structs mirror the columns defined in the
+//! `0004_create_exchange_rates_table` migration and are intended only as a thin
+//! database representation (FromRow). Conversion to rich domain types should
+//! happen in the repository layer.
+
+use chrono::NaiveDateTime;
+
+/// Row mapping for the `exchange_rates` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct ExchangeRateRow {
+    pub from_currency: String,
+    pub to_currency: String,
+    pub rate: String,
+    pub updated_at: NaiveDateTime,
+}
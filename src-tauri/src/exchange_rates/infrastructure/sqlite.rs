@@ -0,0 +1,78 @@
+//! SQLite helper functions (crate-internal) used to read and write exchange rates.
+//!
+//! Rates are stored as decimal strings so no precision is lost; parsing into
+//! domain types happens in the repository layer.
+
+use anyhow::{Context, Result};
+use sqlx::{SqliteExecutor, SqlitePool};
+
+use crate::exchange_rates::infrastructure::entities::ExchangeRateRow;
+
+/// Fetch every stored exchange rate.
+pub async fn fetch_exchange_rates(pool: &SqlitePool) -> Result<Vec<ExchangeRateRow>> {
+    let sql = "SELECT from_currency, to_currency, rate, updated_at FROM exchange_rates ORDER BY from_currency, to_currency";
+
+    let rows = sqlx::query_as::<_, ExchangeRateRow>(sql)
+        .fetch_all(pool)
+        .await
+        .context("fetching exchange_rates")?;
+
+    Ok(rows)
+}
+
+/// Insert or update the rate for `(from_currency, to_currency)`, returning
+/// the stored row.
+pub async fn upsert_exchange_rate<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    from_currency: &str,
+    to_currency: &str,
+    rate: &str,
+) -> Result<ExchangeRateRow> {
+    let sql = "INSERT INTO exchange_rates (from_currency, to_currency, rate) VALUES (?1, ?2, ?3)
+        ON CONFLICT(from_currency, to_currency) DO UPDATE SET rate = excluded.rate, updated_at = CURRENT_TIMESTAMP
+        RETURNING from_currency, to_currency, rate, updated_at";
+
+    let row = sqlx::query_as::<_, ExchangeRateRow>(sql)
+        .bind(from_currency)
+        .bind(to_currency)
+        .bind(rate)
+        .fetch_one(executor)
+        .await
+        .with_context(|| {
+            format!(
+                "upserting exchange_rate from={} to={}",
+                from_currency, to_currency
+            )
+        })?;
+
+    Ok(row)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn upsert_exchange_rate_inserts_and_updates(pool: SqlitePool) -> Result<()> {
+        upsert_exchange_rate(&pool, "GBP", "EUR", "1.16").await?;
+        upsert_exchange_rate(&pool, "USD", "EUR", "0.92").await?;
+        let updated = upsert_exchange_rate(&pool, "GBP", "EUR", "1.17").await?;
+        assert_eq!(updated.rate, "1.17");
+
+        let rows = fetch_exchange_rates(&pool).await?;
+        let rates: Vec<(&str, &str, &str)> = rows
+            .iter()
+            .map(|r| {
+                (
+                    r.from_currency.as_str(),
+                    r.to_currency.as_str(),
+                    r.rate.as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(rates, vec![("GBP", "EUR", "1.17"), ("USD", "EUR", "0.92")]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+use crate::audit::infrastructure::sink::{AuditSink, AuditedChange, SqliteAuditSink};
+use crate::core::domain::Currency;
+use crate::core::infrastructure::transaction::with_transaction;
+use crate::exchange_rates::domain::conversion::ExchangeRates;
+use crate::exchange_rates::domain::exchange_rate::ExchangeRate;
+use crate::exchange_rates::domain::repository::ExchangeRateRepository;
+use crate::exchange_rates::infrastructure::entities::ExchangeRateRow;
+use crate::exchange_rates::infrastructure::sqlite;
+use anyhow::{Context, Result, anyhow};
+use rust_decimal::Decimal;
+use serde_json::json;
+use sqlx::SqlitePool;
+use std::str::FromStr;
+use std::sync::Arc;
+
+pub struct SqliteExchangeRateRepository {
+    pool: SqlitePool,
+    audit: Arc<dyn AuditSink>,
+}
+
+impl SqliteExchangeRateRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            audit: Arc::new(SqliteAuditSink::default()),
+        }
+    }
+
+    /// Record the changes made by this repository in `audit`.
+    pub fn with_audit_sink(mut self, audit: Arc<dyn AuditSink>) -> Self {
+        self.audit = audit;
+        self
+    }
+}
+
+impl SqliteExchangeRateRepository {
+    fn build_exchange_rate(row: ExchangeRateRow) -> Result<ExchangeRate> {
+        let context = || {
+            format!(
+                "invalid exchange_rate from={} to={}",
+                row.from_currency, row.to_currency
+            )
+        };
+
+        Ok(ExchangeRate {
+            from: Currency::from_code(&row.from_currency)
+                .map_err(|e| anyhow!(e))
+                .with_context(context)?,
+            to: Currency::from_code(&row.to_currency)
+                .map_err(|e| anyhow!(e))
+                .with_context(context)?,
+            rate: Decimal::from_str(&row.rate).with_context(context)?,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ExchangeRateRepository for SqliteExchangeRateRepository {
+    async fn get_all(&self) -> Result<ExchangeRates> {
+        let rates = sqlite::fetch_exchange_rates(&self.pool)
+            .await?
+            .into_iter()
+            .map(Self::build_exchange_rate)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(ExchangeRates::new(rates))
+    }
+
+    async fn upsert(&self, from: Currency, to: Currency, rate: Decimal) -> Result<ExchangeRate> {
+        let rate = rate.to_string();
+        let change = AuditedChange::updated(
+            "exchange_rate",
+            format!("{}/{}", from.code(), to.code()),
+            &json!({ "rate": rate }),
+        )?;
+        let audit = Arc::clone(&self.audit);
+
+        let row = with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                let row =
+                    sqlite::upsert_exchange_rate(&mut *conn, from.code(), to.code(), &rate).await?;
+                audit.record(conn, &change).await?;
+                Ok(row)
+            })
+        })
+        .await?;
+
+        Self::build_exchange_rate(row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::MonetaryAmount;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn upserted_rates_are_used_for_conversion(pool: SqlitePool) -> Result<()> {
+        let repo = SqliteExchangeRateRepository::new(pool);
+
+        let stored = repo.upsert(Currency::USD, Currency::EUR, dec!(0.9)).await?;
+        assert_eq!(stored.rate, dec!(0.9));
+
+        let preview = repo
+            .get_all()
+            .await?
+            .convert(&MonetaryAmount::new(1000, Currency::USD), Currency::EUR)?;
+        assert_eq!(preview.amount.amount, 900);
+        assert_eq!(preview.rate_updated_at, Some(stored.updated_at));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn upserted_rates_are_audited(pool: SqlitePool) -> Result<()> {
+        let repo = SqliteExchangeRateRepository::new(pool.clone());
+
+        repo.upsert(Currency::USD, Currency::EUR, dec!(0.9)).await?;
+        repo.upsert(Currency::USD, Currency::EUR, dec!(0.92))
+            .await?;
+
+        let entries: Vec<(String, String, String, String)> = sqlx::query_as(
+            "SELECT operation, entity_type, entity_id, changes FROM audit_log ORDER BY id",
+        )
+        .fetch_all(&pool)
+        .await?;
+        let expected = |rate: &str| {
+            (
+                "update".to_string(),
+                "exchange_rate".to_string(),
+                "USD/EUR".to_string(),
+                json!({ "rate": rate }).to_string(),
+            )
+        };
+        assert_eq!(entries, vec![expected("0.9"), expected("0.92")]);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn invalid_stored_rate_is_reported(pool: SqlitePool) -> Result<()> {
+        sqlite::upsert_exchange_rate(&pool, "USD", "EUR", "not-a-number").await?;
+
+        let repo = SqliteExchangeRateRepository::new(pool);
+        let err = repo.get_all().await.unwrap_err();
+        assert!(format!("{:#}", err).contains("invalid exchange_rate from=USD to=EUR"));
+
+        Ok(())
+    }
+}
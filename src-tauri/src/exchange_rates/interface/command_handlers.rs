@@ -0,0 +1,115 @@
+//! Command handlers exposed to the Tauri frontend for the `exchange_rates` feature.
+//!
+//! These functions act as a thin adapter between the Tauri IPC layer and the
+//! application/use-case layer. They translate incoming requests into use-case
+//! invocations and map application errors into `CommandError` values suitable
+//! for returning over the IPC boundary.
+
+use crate::core::domain::Currency;
+use crate::core::infrastructure::error::CommandError;
+use crate::exchange_rates::application::convert_amount::ConvertAmountUseCase;
+use crate::exchange_rates::application::update_exchange_rate::UpdateExchangeRateUseCase;
+use crate::exchange_rates::domain::conversion::ConversionPreview;
+use crate::exchange_rates::domain::error::ExchangeRateError;
+use crate::exchange_rates::domain::exchange_rate::ExchangeRate;
+use crate::exchange_rates::infrastructure::sqlite_repo::SqliteExchangeRateRepository;
+use crate::state::AppState;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+/// Tauri command to preview the conversion of an amount between currencies.
+///
+/// `amount_minor` is expressed in the smallest unit of `from` (for example
+/// cents). The stored rate is used directly, inverted, or crossed through
+/// the base currency (EUR) when needed.
+///
+/// Returns:
+/// - `Ok(ConversionPreview)` with the converted amount, the applied rate and
+///   its timestamp.
+/// - `Err(CommandError::NotFound)` when no rate links the two currencies.
+/// - `Err(CommandError)` for any other failure.
+#[tauri::command]
+#[specta::specta]
+pub async fn convert_amount(
+    state: tauri::State<'_, AppState>,
+    amount_minor: u64,
+    from: Currency,
+    to: Currency,
+) -> Result<ConversionPreview, CommandError> {
+    let repo = SqliteExchangeRateRepository::new(state.db_pool());
+    let use_case = ConvertAmountUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(amount_minor, from, to)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Tauri command to store the exchange rate from `from` to `to`.
+///
+/// Returns:
+/// - `Ok(ExchangeRate)` with the stored rate and its timestamp.
+/// - `Err(CommandError)` when the rate is invalid or cannot be saved.
+#[tauri::command]
+#[specta::specta]
+pub async fn update_exchange_rate(
+    state: tauri::State<'_, AppState>,
+    from: Currency,
+    to: Currency,
+    rate: Decimal,
+) -> Result<ExchangeRate, CommandError> {
+    let repo = SqliteExchangeRateRepository::new(state.db_pool());
+    let use_case = UpdateExchangeRateUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(from, to, rate)
+        .await
+        .map_err(to_command_error)
+}
+
+/// Map a missing rate to `CommandError::NotFound`, anything else to `Unknown`.
+fn to_command_error(e: anyhow::Error) -> CommandError {
+    match e.downcast_ref::<ExchangeRateError>() {
+        Some(ExchangeRateError::MissingRate(..)) => CommandError::NotFound(e.to_string()),
+        _ => CommandError::Unknown(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_in_memory_db_pool;
+    use pretty_assertions::assert_eq;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn command_update_exchange_rate_is_used_by_convert_amount() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let repo = Arc::new(SqliteExchangeRateRepository::new(pool));
+
+        UpdateExchangeRateUseCase::new(repo.clone())
+            .execute(Currency::GBP, Currency::EUR, dec!(1.1622))
+            .await
+            .expect("update_exchange_rate");
+
+        let preview = ConvertAmountUseCase::new(repo)
+            .execute(4500, Currency::GBP, Currency::EUR)
+            .await
+            .expect("convert_amount");
+        assert_eq!(preview.amount.amount, 5230);
+        assert_eq!(preview.amount.currency, Currency::EUR);
+    }
+
+    #[tokio::test]
+    async fn command_convert_amount_without_rate_is_not_found() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let use_case = ConvertAmountUseCase::new(Arc::new(SqliteExchangeRateRepository::new(pool)));
+
+        let err = use_case
+            .execute(4500, Currency::GBP, Currency::USD)
+            .await
+            .map_err(to_command_error)
+            .unwrap_err();
+        assert!(matches!(err, CommandError::NotFound(_)));
+    }
+}
@@ -1,21 +1,45 @@
 use tauri::Manager;
 
+mod app_info;
 mod db;
+mod seed;
 mod state;
 
+pub mod audit;
 pub mod catalog;
 pub mod collecting;
 pub mod core;
+pub mod exchange_rates;
+pub mod settings;
 
 #[cfg(test)]
 pub mod test_utils;
 
+use crate::app_info::AppInfo;
+use crate::catalog::infrastructure::cache::RailwayModelCache;
+use crate::collecting::application::take_value_snapshot::TakeValueSnapshotUseCase;
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::infrastructure::sqlite::{
+    ensure_default_collection, normalize_purchase_dates,
+};
+use crate::collecting::infrastructure::sqlite_repo::SqliteCollectionRepository;
+use crate::core::domain::job::{Job, JobId, JobKind};
+use crate::core::infrastructure::error::CommandError;
+use crate::core::infrastructure::job_runner::{JobRunner, ProgressReporter};
+use crate::seed::{DemoDataError, DemoDataSummary, load_demo_data as load_demo_data_into};
 use crate::state::AppState;
-use db::{MIGRATOR, init_db_pool};
-use log::{LevelFilter, error};
+use db::{
+    DbLocation, IntegrityReport, MIGRATOR, PoolMonitor, SqliteDbError, init_db_pool,
+    integrity_report, migration_status,
+};
+use log::{LevelFilter, error, info};
 use specta_typescript::{BigIntExportBehavior, Typescript};
+use sqlx::SqlitePool;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 use tauri_plugin_log::{RotationStrategy, Target, TargetKind};
-use tauri_specta::{Builder, collect_commands};
+use tauri_specta::{Builder, collect_commands, collect_events};
 
 #[tauri::command]
 #[specta::specta]
@@ -30,22 +54,249 @@ fn get_app_version() -> String {
     env!("CARGO_PKG_VERSION").to_string()
 }
 
+#[tauri::command]
+#[specta::specta]
+async fn get_app_info(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+    db_location: tauri::State<'_, DbLocation>,
+    pool_monitor: tauri::State<'_, PoolMonitor>,
+    catalog_cache: tauri::State<'_, RailwayModelCache>,
+) -> Result<AppInfo, CommandError> {
+    let migration_status = migration_status(&state.db_pool())
+        .await
+        .map_err(|e| CommandError::DatabaseError(e.to_string()))?;
+
+    let log_dir = match app.path().app_log_dir() {
+        Ok(dir) => Some(dir.display().to_string()),
+        Err(e) => {
+            error!("Failed to resolve the log directory: {e}");
+            None
+        }
+    };
+
+    Ok(AppInfo {
+        app_version: app.package_info().version.to_string(),
+        db_path: db_location.path.display().to_string(),
+        db_path_source: db_location.source,
+        migration_status,
+        log_dir,
+        pool: pool_monitor.metrics(),
+        catalog_cache: catalog_cache.metrics(),
+    })
+}
+
+// Maintenance
+
+/// Scan the database for corruption, foreign key violations and orphaned
+/// rows; with `repair` set, orphaned rows are removed as well.
+#[tauri::command]
+#[specta::specta]
+async fn check_database_integrity(
+    state: tauri::State<'_, AppState>,
+    repair: bool,
+) -> Result<IntegrityReport, CommandError> {
+    integrity_report(&state.db_pool(), repair)
+        .await
+        .map_err(|e| CommandError::DatabaseError(e.to_string()))
+}
+
+/// Start a maintenance job of `kind` in the background, returning its id;
+/// its status is then read with `list_jobs`.
+#[tauri::command]
+#[specta::specta]
+fn start_job(
+    job_runner: tauri::State<'_, JobRunner>,
+    kind: JobKind,
+) -> Result<JobId, CommandError> {
+    let started = match kind {
+        JobKind::IntegrityCheck => job_runner.spawn(kind, run_integrity_check),
+        JobKind::ValueSnapshot => job_runner.spawn(kind, run_value_snapshot),
+    };
+    started.map_err(|e| CommandError::Validation(e.to_string()))
+}
+
+/// List the maintenance jobs, the most recent first.
+#[tauri::command]
+#[specta::specta]
+fn list_jobs(job_runner: tauri::State<'_, JobRunner>) -> Vec<Job> {
+    job_runner.list()
+}
+
+/// Cancel the maintenance job with `id`; `false` when it had already
+/// finished.
+#[tauri::command]
+#[specta::specta]
+fn cancel_job(job_runner: tauri::State<'_, JobRunner>, id: JobId) -> Result<bool, CommandError> {
+    job_runner
+        .cancel(&id)
+        .map_err(|e| CommandError::NotFound(e.to_string()))
+}
+
+/// The `JobKind::IntegrityCheck` job: fails when the database has problems,
+/// which `check_database_integrity` then lists.
+async fn run_integrity_check(pool: SqlitePool, progress: ProgressReporter) -> anyhow::Result<()> {
+    progress.report(0, 1);
+    let report = integrity_report(&pool, false).await?;
+    progress.report(1, 1);
+    if !report.is_clean() {
+        anyhow::bail!("the database has integrity problems");
+    }
+    Ok(())
+}
+
+/// The `JobKind::ValueSnapshot` job.
+async fn run_value_snapshot(pool: SqlitePool, progress: ProgressReporter) -> anyhow::Result<()> {
+    progress.report(0, 1);
+    let collection_id = CollectionId::DEFAULT;
+    TakeValueSnapshotUseCase::new(Arc::new(SqliteCollectionRepository::new(pool)))
+        .execute(&collection_id)
+        .await?;
+    progress.report(1, 1);
+    Ok(())
+}
+
+/// Drop every cached railway model summary, so that they are read from the
+/// catalog again.
+#[tauri::command]
+#[specta::specta]
+fn clear_cache(catalog_cache: tauri::State<'_, RailwayModelCache>) {
+    catalog_cache.clear();
+}
+
+// Demo data
+
+/// Fill the database with the demo catalog and collection. Refuses when the
+/// collection already has items, unless `force` is set.
+#[tauri::command]
+#[specta::specta]
+async fn load_demo_data(
+    state: tauri::State<'_, AppState>,
+    force: bool,
+) -> Result<DemoDataSummary, CommandError> {
+    load_demo_data_into(&state.db_pool(), force)
+        .await
+        .map_err(|e| match e.downcast_ref::<DemoDataError>() {
+            Some(refused) => CommandError::Unknown(refused.to_string()),
+            None => CommandError::DatabaseError(e.to_string()),
+        })
+}
+
+/// The database location passed on the command line as `--db-path <path>`.
+fn db_path_arg() -> Option<PathBuf> {
+    let mut args = std::env::args_os().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--db-path" {
+            return args.next().map(PathBuf::from);
+        }
+    }
+    None
+}
+
+/// Log why the database could not be initialized, including which migration
+/// broke when the failure happened while migrating.
+fn log_db_init_failure(e: &SqliteDbError) {
+    error!("Database initialization failed: {e}");
+    if let SqliteDbError::MigrationFailed {
+        status: Some(status),
+        ..
+    } = e
+    {
+        error!("Migration status: {status}");
+    }
+}
+
+/// How often the connection pool is sampled (see `PoolMonitor`).
+const POOL_SAMPLE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Location of the generated TypeScript bindings, relative to this crate.
+const BINDINGS_PATH: &str = "../src/lib/bindings.ts";
+
+/// Build the `tauri-specta` builder collecting every command and event exposed
+/// to the frontend (and, through their signatures, every exported type).
+fn specta_builder() -> Builder<tauri::Wry> {
+    Builder::<tauri::Wry>::new()
+        .commands(collect_commands![
+            is_db_initialized,
+            crate::collecting::interface::command_handlers::get_collection,
+            crate::collecting::interface::command_handlers::list_collection_items,
+            crate::collecting::interface::command_handlers::find_item_by_number,
+            crate::collecting::interface::command_handlers::duplicate_collection_item,
+            crate::collecting::interface::command_handlers::bulk_update_items,
+            crate::collecting::interface::command_handlers::get_item_cost_breakdown,
+            crate::collecting::interface::command_handlers::record_item_view,
+            crate::collecting::interface::command_handlers::get_recent_activity,
+            crate::collecting::interface::command_handlers::parse_quick_add,
+            crate::collecting::interface::command_handlers::recompute_collection,
+            crate::collecting::interface::command_handlers::check_collection_consistency,
+            crate::collecting::interface::command_handlers::get_collection_statistics,
+            crate::collecting::interface::command_handlers::generate_year_review,
+            crate::collecting::interface::command_handlers::export_insurance_report,
+            crate::collecting::interface::command_handlers::take_value_snapshot,
+            crate::collecting::interface::command_handlers::get_value_history,
+            crate::collecting::interface::command_handlers::add_monetary_adjustment,
+            crate::collecting::interface::command_handlers::add_purchase,
+            crate::collecting::interface::command_handlers::convert_display_currency,
+            crate::collecting::interface::command_handlers::list_decoders,
+            crate::collecting::interface::command_handlers::create_decoder,
+            crate::collecting::interface::command_handlers::update_decoder,
+            crate::collecting::interface::command_handlers::attach_decoder,
+            crate::collecting::interface::command_handlers::detach_decoder,
+            crate::collecting::interface::command_handlers::get_address_conflicts,
+            crate::collecting::interface::command_handlers::list_consists,
+            crate::collecting::interface::command_handlers::create_consist,
+            crate::collecting::interface::command_handlers::update_consist,
+            crate::catalog::interface::command_handlers::search_catalog,
+            crate::catalog::interface::command_handlers::list_railway_models,
+            crate::catalog::interface::command_handlers::get_reference_data,
+            crate::catalog::interface::command_handlers::validate_product_code,
+            crate::catalog::interface::command_handlers::validate_road_number,
+            crate::catalog::interface::command_handlers::find_possible_duplicates,
+            crate::catalog::interface::command_handlers::merge_railway_models,
+            crate::catalog::interface::command_handlers::compare_rolling_stocks,
+            crate::catalog::interface::command_handlers::ingest_announcements,
+            crate::catalog::interface::command_handlers::lookup_product_codes,
+            crate::catalog::interface::command_handlers::create_livery,
+            crate::catalog::interface::command_handlers::update_livery,
+            crate::catalog::interface::command_handlers::suggest_liveries,
+            crate::catalog::interface::command_handlers::calculate_scale_speed,
+            crate::catalog::interface::layout_tools::calculate_gradient,
+            crate::catalog::interface::layout_tools::check_vertical_clearance,
+            crate::catalog::interface::layout_tools::estimate_turnout_ladder_length,
+            crate::settings::interface::command_handlers::get_settings,
+            crate::settings::interface::command_handlers::update_settings,
+            crate::exchange_rates::interface::command_handlers::convert_amount,
+            crate::exchange_rates::interface::command_handlers::update_exchange_rate,
+            crate::audit::interface::command_handlers::get_audit_log,
+            get_app_version,
+            get_app_info,
+            check_database_integrity,
+            start_job,
+            list_jobs,
+            cancel_job,
+            clear_cache,
+            load_demo_data
+        ])
+        .events(collect_events![
+            crate::collecting::interface::events::SummaryRecomputed
+        ])
+}
+
+/// The TypeScript exporter configuration used for `bindings.ts`.
+fn typescript_config() -> Typescript {
+    Typescript::default().bigint(BigIntExportBehavior::BigInt)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let is_dev_build = cfg!(debug_assertions);
 
-    let builder = Builder::<tauri::Wry>::new().commands(collect_commands![
-        is_db_initialized,
-        crate::collecting::interface::command_handlers::get_collection,
-        get_app_version
-    ]);
-
-    let ts_config = Typescript::default().bigint(BigIntExportBehavior::BigInt);
+    let builder = specta_builder();
 
     // 2. Export the bindings (This creates the TS file)
     #[cfg(debug_assertions)] // Only export during development
     builder
-        .export(ts_config, "../src/lib/bindings.ts")
+        .export(typescript_config(), BINDINGS_PATH)
         .expect("Failed to export typescript bindings");
 
     let level = if is_dev_build {
@@ -69,14 +320,27 @@ pub fn run() {
                 .build(),
         )
         .invoke_handler(builder.invoke_handler())
-        .setup(|app| {
+        .setup(move |app| {
+            builder.mount_events(app);
+
             // 1. Initialize the pool
-            let pool = tauri::async_runtime::block_on(async {
-                init_db_pool().await.map_err(|e| anyhow::anyhow!(e))
+            let (pool, db_location) = tauri::async_runtime::block_on(async {
+                init_db_pool(db_path_arg()).await.map_err(|e| {
+                    log_db_init_failure(&e);
+                    anyhow::anyhow!(e)
+                })
             })?;
 
             // 2. Initial management of state
             app.manage(AppState::new(pool.clone()));
+            app.manage(db_location);
+
+            let pool_monitor = PoolMonitor::new(pool.clone());
+            app.manage(pool_monitor.clone());
+            tauri::async_runtime::spawn(pool_monitor.run(POOL_SAMPLE_INTERVAL));
+
+            app.manage(RailwayModelCache::default());
+            app.manage(JobRunner::new(pool.clone()));
 
             // 3. Show the main window IMMEDIATELY to avoid blank screen
             // The UI can handle the "not initialized" state gracefully
@@ -91,10 +355,23 @@ pub fn run() {
             // 4. Run migrations in an async task (non-blocking)
             tauri::async_runtime::spawn(async move {
                 let state_ref = handle.state::<AppState>();
-                let _ = MIGRATOR
-                    .run(&state_ref.db_pool())
-                    .await
-                    .map_err(|e| anyhow::anyhow!(e));
+                let pool = state_ref.db_pool();
+                if let Err(e) = MIGRATOR.run(&pool).await {
+                    error!("Database migration failed: {e}");
+                    match migration_status(&pool).await {
+                        Ok(status) => error!("Migration status: {status}"),
+                        Err(e) => error!("Failed to read the migration status: {e}"),
+                    }
+                } else {
+                    if let Err(e) = ensure_default_collection(&pool).await {
+                        error!("Failed to create the default collection: {e}");
+                    }
+                    match normalize_purchase_dates(&pool).await {
+                        Ok(0) => {}
+                        Ok(rewritten) => info!("Rewrote {rewritten} legacy purchase dates to ISO"),
+                        Err(e) => error!("Failed to normalize purchase dates: {e}"),
+                    }
+                }
 
                 state_ref.set_initialized();
             });
@@ -104,3 +381,28 @@ pub fn run() {
         .run(tauri::generate_context!())
         .expect("Error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typescript_bindings_are_up_to_date() {
+        let checked_in =
+            std::fs::read_to_string(BINDINGS_PATH).expect("Failed to read checked-in bindings");
+
+        let generated_path =
+            std::env::temp_dir().join(format!("rusty_shed_bindings_{}.ts", uuid::Uuid::new_v4()));
+        specta_builder()
+            .export(typescript_config(), &generated_path)
+            .expect("Failed to export typescript bindings");
+        let generated =
+            std::fs::read_to_string(&generated_path).expect("Failed to read generated bindings");
+        let _ = std::fs::remove_file(&generated_path);
+
+        assert!(
+            checked_in == generated,
+            "{BINDINGS_PATH} is out of date: run a debug build (`pnpm tauri dev`) to regenerate it"
+        );
+    }
+}
@@ -0,0 +1,979 @@
+//! Demo data for evaluating the application with a populated database.
+//!
+//! `load_demo_data` inserts a small curated catalog (a few manufacturers and
+//! railway companies, ten railway models across categories with their rolling
+//! stocks and technical specifications) and fills the default collection with
+//! purchased, pre-ordered and sold items. The content is the embedded
+//! `seed/demo_data.json`; catalog rows reference each other by name and
+//! collection items reference railway models by product code, so every run
+//! generates fresh ids.
+
+use anyhow::{Context, Result, anyhow};
+use chrono::{Local, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{SqliteConnection, SqlitePool};
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::audit::domain::audit_entry::AuditOrigin;
+use crate::audit::infrastructure::sink::{AuditSink, AuditedChange, SqliteAuditSink};
+use crate::catalog::domain::Scale;
+use crate::catalog::domain::category::RollingStockCategory;
+use crate::catalog::domain::epoch::Epoch;
+use crate::catalog::domain::epoch_years::{EpochYears, ValidationWarning};
+use crate::catalog::domain::period_of_activity::PeriodOfActivity;
+use crate::catalog::domain::radius::Radius;
+use crate::catalog::domain::railway_model_id::RailwayModelId;
+use crate::catalog::domain::road_number::check_road_number;
+use crate::catalog::domain::scale_standards::StandardsWarning;
+use crate::collecting::domain::collection::DEFAULT_COLLECTION_ID;
+use crate::collecting::domain::collection_id::CollectionId;
+use crate::collecting::domain::repository::CollectionRepository;
+use crate::collecting::infrastructure::sqlite::ensure_default_collection;
+use crate::collecting::infrastructure::sqlite_repo::SqliteCollectionRepository;
+use crate::core::infrastructure::batch_insert::BatchInsert;
+use crate::core::infrastructure::transaction::with_transaction;
+
+/// The embedded demo data set.
+const DEMO_DATA: &str = include_str!("seed/demo_data.json");
+
+/// Why the demo data was not loaded.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum DemoDataError {
+    /// The default collection already has items; pass `force` to add the
+    /// demo data anyway.
+    #[error("the collection already has {0} items")]
+    CollectionNotEmpty(i64),
+}
+
+/// What `load_demo_data` inserted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+pub struct DemoDataSummary {
+    /// Manufacturers created (existing ones with the same name are reused).
+    pub manufacturers: u32,
+    /// Railway companies created (existing ones with the same name are reused).
+    pub railway_companies: u32,
+    /// Railway models created.
+    pub railway_models: u32,
+    /// Rolling stocks created.
+    pub rolling_stocks: u32,
+    /// Items added to the default collection.
+    pub collection_items: u32,
+    /// Rolling stocks at odds with the standards of their scale; they are
+    /// inserted all the same.
+    pub standards_warnings: Vec<StandardsWarning>,
+    /// Rolling stocks whose railway company was not operating in the epoch
+    /// of their model, or with a likely mistyped road number; they are
+    /// inserted all the same.
+    pub validation_warnings: Vec<ValidationWarning>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DemoData {
+    manufacturers: Vec<SeedManufacturer>,
+    railway_companies: Vec<SeedRailwayCompany>,
+    railway_models: Vec<SeedRailwayModel>,
+    collection: Vec<SeedCollectionItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SeedManufacturer {
+    name: String,
+    registered_company_name: Option<String>,
+    country_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SeedRailwayCompany {
+    name: String,
+    registered_company_name: Option<String>,
+    country_code: Option<String>,
+    status: Option<String>,
+    operating_since: Option<NaiveDate>,
+    operating_until: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SeedRailwayModel {
+    /// The manufacturer name.
+    manufacturer: String,
+    product_code: String,
+    description: String,
+    details: Option<String>,
+    power_method: String,
+    scale: String,
+    epoch: String,
+    category: String,
+    delivery_date: Option<String>,
+    availability_status: Option<String>,
+    rolling_stocks: Vec<SeedRollingStock>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct SeedRollingStock {
+    category: String,
+    /// The railway company name.
+    railway_company: String,
+    livery: Option<String>,
+    length_millimeters: Option<f64>,
+    technical_minimum_radius_mm: Option<f64>,
+    technical_coupling: Option<String>,
+    technical_flywheel_fitted: Option<String>,
+    technical_body_shell: Option<String>,
+    technical_chassis: Option<String>,
+    technical_interior_lights: Option<String>,
+    technical_lights: Option<String>,
+    technical_sprung_buffers: Option<String>,
+    type_name: Option<String>,
+    road_number: Option<String>,
+    series: Option<String>,
+    depot: Option<String>,
+    electric_multiple_unit_type: Option<String>,
+    freight_car_type: Option<String>,
+    locomotive_type: Option<String>,
+    passenger_car_type: Option<String>,
+    railcar_type: Option<String>,
+    service_level: Option<String>,
+    dcc_interface: Option<String>,
+    control: Option<String>,
+    is_dummy: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct SeedCollectionItem {
+    /// The product code of one of the seeded railway models.
+    product_code: String,
+    conditions: Option<String>,
+    notes: Option<String>,
+    purchase: SeedPurchase,
+}
+
+/// A purchase info row; amounts are in minor units, all in `currency`.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum SeedPurchase {
+    Purchased {
+        date: NaiveDate,
+        price: i64,
+        currency: String,
+        seller: Option<String>,
+    },
+    Preorder {
+        date: NaiveDate,
+        deposit: i64,
+        total: i64,
+        currency: String,
+        expected_date: Option<NaiveDate>,
+        seller: Option<String>,
+    },
+    Sold {
+        date: NaiveDate,
+        price: i64,
+        sale_date: NaiveDate,
+        sale_price: i64,
+        currency: String,
+        buyer: Option<String>,
+    },
+}
+
+/// Load the demo catalog and collection.
+///
+/// Refuses with `DemoDataError::CollectionNotEmpty` when the default
+/// collection already has items, unless `force` is set. Everything is
+/// inserted in one transaction; the collection counters and total value are
+/// recomputed afterwards and today's value snapshot is taken. The audit log
+/// records the import as an update of the default collection, with the
+/// summary as its changes.
+pub async fn load_demo_data(pool: &SqlitePool, force: bool) -> Result<DemoDataSummary> {
+    let data: DemoData = serde_json::from_str(DEMO_DATA).context("parsing the demo data")?;
+
+    let summary = with_transaction(pool, move |conn| {
+        Box::pin(async move {
+            let items: i64 =
+                sqlx::query_scalar("SELECT COUNT(*) FROM collection_items WHERE collection_id = ?")
+                    .bind(DEFAULT_COLLECTION_ID)
+                    .fetch_one(&mut *conn)
+                    .await
+                    .context("counting collection items")?;
+            if items > 0 && !force {
+                return Err(anyhow!(DemoDataError::CollectionNotEmpty(items)));
+            }
+
+            let summary = insert_demo_data(&mut *conn, &data).await?;
+            let change = AuditedChange::updated("collection", DEFAULT_COLLECTION_ID, &summary)?;
+            SqliteAuditSink::new(AuditOrigin::Import)
+                .record(conn, &change)
+                .await?;
+            Ok(summary)
+        })
+    })
+    .await?;
+
+    let collection_id = CollectionId::DEFAULT;
+    let repo = SqliteCollectionRepository::new(pool.clone())
+        .with_audit_sink(Arc::new(SqliteAuditSink::new(AuditOrigin::Import)));
+    repo.recompute_collection(&collection_id).await?;
+    repo.take_value_snapshot(&collection_id, Local::now().date_naive())
+        .await?;
+
+    Ok(summary)
+}
+
+async fn insert_demo_data(conn: &mut SqliteConnection, data: &DemoData) -> Result<DemoDataSummary> {
+    let mut summary = DemoDataSummary {
+        manufacturers: 0,
+        railway_companies: 0,
+        railway_models: 0,
+        rolling_stocks: 0,
+        collection_items: 0,
+        standards_warnings: Vec::new(),
+        validation_warnings: Vec::new(),
+    };
+
+    let mut manufacturer_ids = HashMap::new();
+    for manufacturer in &data.manufacturers {
+        let (id, created) = insert_manufacturer(conn, manufacturer).await?;
+        summary.manufacturers += u32::from(created);
+        manufacturer_ids.insert(manufacturer.name.as_str(), id);
+    }
+
+    // name -> (id, period of activity)
+    let mut railway_companies = HashMap::new();
+    for company in &data.railway_companies {
+        let (id, period_of_activity, created) = insert_railway_company(conn, company).await?;
+        summary.railway_companies += u32::from(created);
+        railway_companies.insert(company.name.as_str(), (id, period_of_activity));
+    }
+    let epoch_years = EpochYears::default();
+
+    // Ids are generated here, so the models, their rolling stocks and the
+    // collection items are inserted in batches.
+    let mut new_railway_models = Vec::with_capacity(data.railway_models.len());
+    let mut new_rolling_stocks = Vec::new();
+    // product code -> (railway model id, rolling stock ids)
+    let mut railway_models = HashMap::new();
+    for model in &data.railway_models {
+        let manufacturer_id = manufacturer_ids
+            .get(model.manufacturer.as_str())
+            .with_context(|| format!("unknown manufacturer {:?}", model.manufacturer))?;
+        let railway_model_id = RailwayModelId::new();
+        let standards = Scale::try_from(model.scale.as_str())
+            .with_context(|| format!("invalid scale {:?}", model.scale))?
+            .standards();
+
+        let mut rolling_stock_ids = Vec::with_capacity(model.rolling_stocks.len());
+        for rolling_stock in &model.rolling_stocks {
+            let (railway_company_id, period_of_activity) = railway_companies
+                .get(rolling_stock.railway_company.as_str())
+                .with_context(|| {
+                    format!(
+                        "unknown railway company {:?}",
+                        rolling_stock.railway_company
+                    )
+                })?;
+            let rolling_stock_id = Uuid::new_v4().to_string();
+            if let Some(radius_mm) = rolling_stock.technical_minimum_radius_mm {
+                let radius = Decimal::try_from(radius_mm)
+                    .ok()
+                    .and_then(|mm| Radius::from_millimeters(mm).ok())
+                    .with_context(|| format!("invalid minimum radius {radius_mm}"))?;
+                summary
+                    .standards_warnings
+                    .extend(standards.check_minimum_radius(&rolling_stock_id, radius));
+            }
+            if let Some(period_of_activity) = period_of_activity {
+                summary
+                    .validation_warnings
+                    .extend(epoch_years.check_period_of_activity(
+                        &rolling_stock_id,
+                        &Epoch::from(model.epoch.as_str()),
+                        railway_company_id,
+                        period_of_activity,
+                    ));
+            }
+            if let Some(road_number) = &rolling_stock.road_number {
+                let category = RollingStockCategory::from_str(&rolling_stock.category)
+                    .with_context(|| {
+                        format!(
+                            "invalid rolling stock category {:?}",
+                            rolling_stock.category
+                        )
+                    })?;
+                summary.validation_warnings.extend(check_road_number(
+                    &rolling_stock_id,
+                    &rolling_stock.railway_company,
+                    category,
+                    road_number,
+                ));
+            }
+            new_rolling_stocks.push(NewRollingStock {
+                id: rolling_stock_id.clone(),
+                railway_model_id,
+                railway_company_id: railway_company_id.clone(),
+                rolling_stock,
+            });
+            rolling_stock_ids.push(rolling_stock_id);
+        }
+        new_railway_models.push(NewRailwayModel {
+            id: railway_model_id,
+            manufacturer_id: manufacturer_id.clone(),
+            model,
+        });
+        railway_models.insert(
+            model.product_code.as_str(),
+            (railway_model_id, rolling_stock_ids),
+        );
+    }
+    summary.railway_models = count_rows(insert_railway_models(conn, &new_railway_models).await?);
+    summary.rolling_stocks = count_rows(insert_rolling_stocks(conn, &new_rolling_stocks).await?);
+
+    ensure_default_collection(&mut *conn).await?;
+    let mut new_collection_items = Vec::with_capacity(data.collection.len());
+    // (owned rolling stock id, collection item id, rolling stock id)
+    let mut new_owned_rolling_stocks = Vec::new();
+    for item in &data.collection {
+        let (railway_model_id, rolling_stock_ids) = railway_models
+            .get(item.product_code.as_str())
+            .with_context(|| format!("unknown product code {:?}", item.product_code))?;
+
+        let collection_item_id = Uuid::new_v4().to_string();
+        for rolling_stock_id in rolling_stock_ids {
+            new_owned_rolling_stocks.push((
+                Uuid::new_v4().to_string(),
+                collection_item_id.clone(),
+                rolling_stock_id.as_str(),
+            ));
+        }
+        new_collection_items.push((collection_item_id, *railway_model_id, item));
+    }
+    summary.collection_items =
+        count_rows(insert_collection_items(conn, &new_collection_items).await?);
+    BatchInsert::new(
+        "owned_rolling_stocks",
+        &["id", "collection_item_id", "rolling_stock_id"],
+    )
+    .execute(conn, &new_owned_rolling_stocks, |values, row| {
+        values.push_bind(&row.0).push_bind(&row.1).push_bind(row.2);
+    })
+    .await?;
+    for (collection_item_id, _, item) in &new_collection_items {
+        insert_purchase_info(conn, collection_item_id, &item.purchase).await?;
+    }
+
+    Ok(summary)
+}
+
+/// Insert a manufacturer unless one with the same name exists. Returns its id
+/// and whether it was created.
+async fn insert_manufacturer(
+    conn: &mut SqliteConnection,
+    manufacturer: &SeedManufacturer,
+) -> Result<(String, bool)> {
+    let created = sqlx::query(
+        "INSERT INTO manufacturers (id, name, registered_company_name, country_code, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?5) ON CONFLICT (name) DO NOTHING",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(&manufacturer.name)
+    .bind(&manufacturer.registered_company_name)
+    .bind(&manufacturer.country_code)
+    .bind(Utc::now())
+    .execute(&mut *conn)
+    .await
+    .with_context(|| format!("inserting manufacturer name={}", manufacturer.name))?
+    .rows_affected()
+        > 0;
+
+    let id = sqlx::query_scalar("SELECT id FROM manufacturers WHERE name = ?1")
+        .bind(&manufacturer.name)
+        .fetch_one(&mut *conn)
+        .await
+        .with_context(|| format!("fetching manufacturer name={}", manufacturer.name))?;
+
+    Ok((id, created))
+}
+
+/// Insert a railway company unless one with the same name exists. Returns
+/// its id, its stored period of activity (an existing one keeps its own) and
+/// whether it was created.
+async fn insert_railway_company(
+    conn: &mut SqliteConnection,
+    company: &SeedRailwayCompany,
+) -> Result<(String, Option<PeriodOfActivity>, bool)> {
+    let created = sqlx::query(
+        "INSERT INTO railway_companies (id, name, registered_company_name, country_code, status, operating_since, operating_until, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?8) ON CONFLICT (name) DO NOTHING",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(&company.name)
+    .bind(&company.registered_company_name)
+    .bind(&company.country_code)
+    .bind(&company.status)
+    .bind(company.operating_since)
+    .bind(company.operating_until)
+    .bind(Utc::now())
+    .execute(&mut *conn)
+    .await
+    .with_context(|| format!("inserting railway_company name={}", company.name))?
+    .rows_affected()
+        > 0;
+
+    let (id, status, operating_since, operating_until): (
+        String,
+        Option<String>,
+        Option<NaiveDate>,
+        Option<NaiveDate>,
+    ) = sqlx::query_as(
+        "SELECT id, status, operating_since, operating_until FROM railway_companies WHERE name = ?1",
+    )
+    .bind(&company.name)
+    .fetch_one(&mut *conn)
+    .await
+    .with_context(|| format!("fetching railway_company name={}", company.name))?;
+
+    let period_of_activity =
+        (operating_since.is_some() || operating_until.is_some()).then(|| PeriodOfActivity {
+            operating_since,
+            operating_until,
+            status: status
+                .as_deref()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or_default(),
+        });
+
+    Ok((id, period_of_activity, created))
+}
+
+/// A railway model to insert, with its generated id.
+struct NewRailwayModel<'a> {
+    id: RailwayModelId,
+    manufacturer_id: String,
+    model: &'a SeedRailwayModel,
+}
+
+/// A rolling stock to insert, with its generated id.
+struct NewRollingStock<'a> {
+    id: String,
+    railway_model_id: RailwayModelId,
+    railway_company_id: String,
+    rolling_stock: &'a SeedRollingStock,
+}
+
+fn count_rows(rows: u64) -> u32 {
+    u32::try_from(rows).unwrap_or(u32::MAX)
+}
+
+async fn insert_railway_models(
+    conn: &mut SqliteConnection,
+    models: &[NewRailwayModel<'_>],
+) -> Result<u64> {
+    let now = Utc::now();
+    BatchInsert::new(
+        "railway_models",
+        &[
+            "id",
+            "manufacturer_id",
+            "product_code",
+            "description",
+            "details",
+            "power_method",
+            "scale",
+            "epoch",
+            "category",
+            "delivery_date",
+            "availability_status",
+            "created_at",
+            "updated_at",
+        ],
+    )
+    .execute(conn, models, |values, new| {
+        let model = new.model;
+        values
+            .push_bind(new.id)
+            .push_bind(&new.manufacturer_id)
+            .push_bind(&model.product_code)
+            .push_bind(&model.description)
+            .push_bind(&model.details)
+            .push_bind(&model.power_method)
+            .push_bind(&model.scale)
+            .push_bind(&model.epoch)
+            .push_bind(&model.category)
+            .push_bind(&model.delivery_date)
+            .push_bind(&model.availability_status)
+            .push_bind(now)
+            .push_bind(now);
+    })
+    .await
+}
+
+async fn insert_rolling_stocks(
+    conn: &mut SqliteConnection,
+    rolling_stocks: &[NewRollingStock<'_>],
+) -> Result<u64> {
+    let now = Utc::now();
+    BatchInsert::new(
+        "rolling_stocks",
+        &[
+            "id",
+            "railway_model_id",
+            "category",
+            "railway_company_id",
+            "livery",
+            "length_millimeters",
+            "technical_minimum_radius_mm",
+            "technical_coupling",
+            "technical_flywheel_fitted",
+            "technical_body_shell",
+            "technical_chassis",
+            "technical_interior_lights",
+            "technical_lights",
+            "technical_sprung_buffers",
+            "type_name",
+            "road_number",
+            "series",
+            "depot",
+            "electric_multiple_unit_type",
+            "freight_car_type",
+            "locomotive_type",
+            "passenger_car_type",
+            "railcar_type",
+            "service_level",
+            "dcc_interface",
+            "control",
+            "is_dummy",
+            "created_at",
+            "updated_at",
+        ],
+    )
+    .execute(conn, rolling_stocks, |values, new| {
+        let rolling_stock = new.rolling_stock;
+        values
+            .push_bind(&new.id)
+            .push_bind(new.railway_model_id)
+            .push_bind(&rolling_stock.category)
+            .push_bind(&new.railway_company_id)
+            .push_bind(&rolling_stock.livery)
+            .push_bind(rolling_stock.length_millimeters)
+            .push_bind(rolling_stock.technical_minimum_radius_mm)
+            .push_bind(&rolling_stock.technical_coupling)
+            .push_bind(&rolling_stock.technical_flywheel_fitted)
+            .push_bind(&rolling_stock.technical_body_shell)
+            .push_bind(&rolling_stock.technical_chassis)
+            .push_bind(&rolling_stock.technical_interior_lights)
+            .push_bind(&rolling_stock.technical_lights)
+            .push_bind(&rolling_stock.technical_sprung_buffers)
+            .push_bind(&rolling_stock.type_name)
+            .push_bind(&rolling_stock.road_number)
+            .push_bind(&rolling_stock.series)
+            .push_bind(&rolling_stock.depot)
+            .push_bind(&rolling_stock.electric_multiple_unit_type)
+            .push_bind(&rolling_stock.freight_car_type)
+            .push_bind(&rolling_stock.locomotive_type)
+            .push_bind(&rolling_stock.passenger_car_type)
+            .push_bind(&rolling_stock.railcar_type)
+            .push_bind(&rolling_stock.service_level)
+            .push_bind(&rolling_stock.dcc_interface)
+            .push_bind(&rolling_stock.control)
+            .push_bind(rolling_stock.is_dummy)
+            .push_bind(now)
+            .push_bind(now);
+    })
+    .await
+}
+
+/// Insert the collection items, given as (collection item id, railway model
+/// id, seed item), into the default collection.
+async fn insert_collection_items(
+    conn: &mut SqliteConnection,
+    items: &[(String, RailwayModelId, &SeedCollectionItem)],
+) -> Result<u64> {
+    let now = Utc::now();
+    BatchInsert::new(
+        "collection_items",
+        &[
+            "id",
+            "collection_id",
+            "railway_model_id",
+            "conditions",
+            "notes",
+            "created_at",
+            "updated_at",
+        ],
+    )
+    .execute(conn, items, |values, (id, railway_model_id, item)| {
+        values
+            .push_bind(id)
+            .push_bind(DEFAULT_COLLECTION_ID)
+            .push_bind(*railway_model_id)
+            .push_bind(&item.conditions)
+            .push_bind(&item.notes)
+            .push_bind(now)
+            .push_bind(now);
+    })
+    .await
+}
+
+async fn insert_purchase_info(
+    conn: &mut SqliteConnection,
+    collection_item_id: &str,
+    purchase: &SeedPurchase,
+) -> Result<()> {
+    let sql = "INSERT INTO purchase_infos (purchase_id, collection_item_id, purchase_type, purchase_date, seller_id, buyer_id, sale_date, purchased_price_amount, purchased_price_currency, sale_price_amount, sale_price_currency, deposit_amount, deposit_currency, preorder_total_amount, preorder_total_currency, expected_date, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?17)";
+    let query = sqlx::query(sql)
+        .bind(Uuid::new_v4().to_string())
+        .bind(collection_item_id);
+
+    let query = match purchase {
+        SeedPurchase::Purchased {
+            date,
+            price,
+            currency,
+            seller,
+        } => query
+            .bind("purchased")
+            .bind(date)
+            .bind(seller)
+            .bind(None::<String>)
+            .bind(None::<NaiveDate>)
+            .bind(price)
+            .bind(currency)
+            .bind(None::<i64>)
+            .bind(None::<String>)
+            .bind(None::<i64>)
+            .bind(None::<String>)
+            .bind(None::<i64>)
+            .bind(None::<String>)
+            .bind(None::<NaiveDate>),
+        SeedPurchase::Preorder {
+            date,
+            deposit,
+            total,
+            currency,
+            expected_date,
+            seller,
+        } => query
+            .bind("preorder")
+            .bind(date)
+            .bind(seller)
+            .bind(None::<String>)
+            .bind(None::<NaiveDate>)
+            .bind(None::<i64>)
+            .bind(None::<String>)
+            .bind(None::<i64>)
+            .bind(None::<String>)
+            .bind(deposit)
+            .bind(currency)
+            .bind(total)
+            .bind(currency)
+            .bind(expected_date),
+        SeedPurchase::Sold {
+            date,
+            price,
+            sale_date,
+            sale_price,
+            currency,
+            buyer,
+        } => query
+            .bind("sold")
+            .bind(date)
+            .bind(None::<String>)
+            .bind(buyer)
+            .bind(sale_date)
+            .bind(price)
+            .bind(currency)
+            .bind(sale_price)
+            .bind(currency)
+            .bind(None::<i64>)
+            .bind(None::<String>)
+            .bind(None::<i64>)
+            .bind(None::<String>)
+            .bind(None::<NaiveDate>),
+    };
+
+    query
+        .bind(Utc::now())
+        .execute(&mut *conn)
+        .await
+        .with_context(|| {
+            format!(
+                "inserting purchase_info collection_item_id={}",
+                collection_item_id
+            )
+        })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collecting::infrastructure::sqlite;
+    use pretty_assertions::assert_eq;
+
+    async fn count(pool: &SqlitePool, table: &str) -> Result<i64> {
+        let sql = format!("SELECT COUNT(*) FROM {table}");
+        Ok(sqlx::query_scalar(&sql).fetch_one(pool).await?)
+    }
+
+    #[test]
+    fn demo_data_is_consistent() {
+        let data: DemoData = serde_json::from_str(DEMO_DATA).expect("valid demo data");
+
+        assert_eq!(data.railway_models.len(), 10);
+        for model in &data.railway_models {
+            assert!(
+                data.manufacturers
+                    .iter()
+                    .any(|m| m.name == model.manufacturer),
+                "unknown manufacturer {}",
+                model.manufacturer
+            );
+            assert!(!model.rolling_stocks.is_empty());
+        }
+        for item in &data.collection {
+            assert!(
+                data.railway_models
+                    .iter()
+                    .any(|m| m.product_code == item.product_code),
+                "unknown product code {}",
+                item.product_code
+            );
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn load_demo_data_populates_an_empty_database(pool: SqlitePool) -> Result<()> {
+        let summary = load_demo_data(&pool, false).await?;
+
+        assert_eq!(
+            summary,
+            DemoDataSummary {
+                manufacturers: 3,
+                railway_companies: 3,
+                railway_models: 10,
+                rolling_stocks: 12,
+                collection_items: 9,
+                standards_warnings: vec![],
+                validation_warnings: vec![],
+            }
+        );
+        assert_eq!(count(&pool, "manufacturers").await?, 3);
+        assert_eq!(count(&pool, "railway_companies").await?, 3);
+        assert_eq!(count(&pool, "railway_models").await?, 10);
+        assert_eq!(count(&pool, "rolling_stocks").await?, 12);
+        assert_eq!(count(&pool, "collection_items").await?, 9);
+        assert_eq!(count(&pool, "owned_rolling_stocks").await?, 11);
+        assert_eq!(count(&pool, "purchase_infos").await?, 9);
+
+        let purchase_types: Vec<(String, i64)> = sqlx::query_as(
+            "SELECT purchase_type, COUNT(*) FROM purchase_infos GROUP BY purchase_type ORDER BY purchase_type",
+        )
+        .fetch_all(&pool)
+        .await?;
+        assert_eq!(
+            purchase_types,
+            vec![
+                ("preorder".to_string(), 2),
+                ("purchased".to_string(), 6),
+                ("sold".to_string(), 1),
+            ]
+        );
+
+        // Every seeded item maps to the domain and the counters are current.
+        let collection = SqliteCollectionRepository::new(pool.clone())
+            .get_collection()
+            .await?;
+        assert_eq!(collection.items.len(), 9);
+        assert!(
+            collection
+                .items
+                .iter()
+                .all(|i| i.purchase_info_error.is_none())
+        );
+
+        let collection_id = CollectionId::DEFAULT;
+        let row = sqlite::get_collection(&pool, collection_id).await?.unwrap();
+        assert_eq!(row.locomotives_count, 4);
+        assert_eq!(row.passenger_cars_count, 2);
+        assert_eq!(row.freight_cars_count, 2);
+        assert_eq!(row.electric_multiple_units_count, 1);
+        assert_eq!(
+            row.total_value_amount,
+            21990 + 25900 + 9500 + 5490 + 8990 + 3290
+        );
+        assert_eq!(row.total_value_currency, "EUR");
+        assert_eq!(count(&pool, "collection_value_snapshots").await?, 1);
+
+        // The import and the follow-up writes are audited as such.
+        let origins: Vec<(String, String)> =
+            sqlx::query_as("SELECT entity_type, origin FROM audit_log ORDER BY id")
+                .fetch_all(&pool)
+                .await?;
+        assert_eq!(
+            origins,
+            vec![
+                ("collection".to_string(), "import".to_string()),
+                ("collection".to_string(), "import".to_string()),
+                ("value_snapshot".to_string(), "import".to_string()),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn load_demo_data_refuses_a_non_empty_collection(pool: SqlitePool) -> Result<()> {
+        load_demo_data(&pool, false).await?;
+
+        let err = load_demo_data(&pool, false).await.unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DemoDataError>(),
+            Some(&DemoDataError::CollectionNotEmpty(9))
+        );
+        assert_eq!(count(&pool, "railway_models").await?, 10);
+        assert_eq!(count(&pool, "collection_items").await?, 9);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn load_demo_data_with_force_adds_to_a_non_empty_collection(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        load_demo_data(&pool, false).await?;
+
+        let summary = load_demo_data(&pool, true).await?;
+
+        // Manufacturers and railway companies are matched by name.
+        assert_eq!(summary.manufacturers, 0);
+        assert_eq!(summary.railway_companies, 0);
+        assert_eq!(summary.railway_models, 10);
+        assert_eq!(count(&pool, "manufacturers").await?, 3);
+        assert_eq!(count(&pool, "railway_models").await?, 20);
+        assert_eq!(count(&pool, "collection_items").await?, 18);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn rolling_stocks_below_the_minimum_radius_of_their_scale_are_flagged(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let mut data: DemoData = serde_json::from_str(DEMO_DATA)?;
+        data.railway_models[0].rolling_stocks[0].technical_minimum_radius_mm = Some(192.0);
+        let mut conn = pool.acquire().await?;
+
+        let summary = insert_demo_data(&mut conn, &data).await?;
+
+        let [
+            StandardsWarning::RadiusBelowMinimum {
+                rolling_stock_id,
+                declared_radius_mm,
+                minimum_radius_mm,
+            },
+        ] = summary.standards_warnings.as_slice()
+        else {
+            panic!("unexpected warnings {:?}", summary.standards_warnings);
+        };
+        assert_eq!((*declared_radius_mm, *minimum_radius_mm), (192.0, 356.0));
+        // The rolling stock is stored all the same.
+        let radius: Option<f64> = sqlx::query_scalar(
+            "SELECT technical_minimum_radius_mm FROM rolling_stocks WHERE id = ?1",
+        )
+        .bind(rolling_stock_id)
+        .fetch_one(&pool)
+        .await?;
+        assert_eq!(radius, Some(192.0));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn rolling_stocks_of_railways_not_operating_in_their_epoch_are_flagged(
+        pool: SqlitePool,
+    ) -> Result<()> {
+        let mut data: DemoData = serde_json::from_str(DEMO_DATA)?;
+        let model = data
+            .railway_models
+            .iter_mut()
+            .find(|m| m.product_code == "73262")
+            .expect("a DB model");
+        model.epoch = "VI".to_string();
+        // An existing railway company keeps its own period of activity.
+        sqlx::query(
+            "INSERT INTO railway_companies (id, name, operating_since, operating_until) VALUES ('sbb', 'SBB', '1902-01-01', '1980-12-31')",
+        )
+        .execute(&pool)
+        .await?;
+        let mut conn = pool.acquire().await?;
+
+        let summary = insert_demo_data(&mut conn, &data).await?;
+
+        let companies: Vec<&str> = summary
+            .validation_warnings
+            .iter()
+            .filter_map(|warning| match warning {
+                ValidationWarning::EpochOutsidePeriodOfActivity {
+                    railway_company_id, ..
+                } => Some(railway_company_id.as_str()),
+                _ => None,
+            })
+            .collect();
+        let db_id: String =
+            sqlx::query_scalar("SELECT id FROM railway_companies WHERE name = 'DB'")
+                .fetch_one(&pool)
+                .await?;
+        assert_eq!(companies, vec![db_id.as_str(), "sbb"]);
+        let ValidationWarning::EpochOutsidePeriodOfActivity {
+            epoch,
+            operating_until,
+            ..
+        } = &summary.validation_warnings[0]
+        else {
+            panic!("not an epoch warning");
+        };
+        assert_eq!(epoch, &Epoch::from("VI"));
+        assert_eq!(operating_until, &NaiveDate::from_ymd_opt(1993, 12, 31));
+        // The rolling stocks are stored all the same.
+        assert_eq!(summary.rolling_stocks, 12);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn rolling_stocks_with_mistyped_road_numbers_are_flagged(pool: SqlitePool) -> Result<()> {
+        let mut data: DemoData = serde_json::from_str(DEMO_DATA)?;
+        let rolling_stock = data
+            .railway_models
+            .iter_mut()
+            .flat_map(|m| m.rolling_stocks.iter_mut())
+            .find(|rs| rs.road_number.as_deref() == Some("61 83 19-90 103-8"))
+            .expect("a UIC coach");
+        rolling_stock.road_number = Some("61 83 19-90  103-4".to_string());
+        let mut conn = pool.acquire().await?;
+
+        let summary = insert_demo_data(&mut conn, &data).await?;
+
+        let warnings: Vec<(&str, Option<&str>)> = summary
+            .validation_warnings
+            .iter()
+            .filter_map(|warning| match warning {
+                ValidationWarning::SuspiciousRoadNumber {
+                    road_number,
+                    suggestion,
+                    ..
+                } => Some((road_number.as_str(), suggestion.as_deref())),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            warnings,
+            vec![("61 83 19-90  103-4", Some("61 83 19-90 103-8"))]
+        );
+        assert_eq!(summary.rolling_stocks, 12);
+
+        Ok(())
+    }
+}
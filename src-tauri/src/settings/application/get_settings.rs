@@ -0,0 +1,18 @@
+use crate::settings::domain::app_settings::AppSettings;
+use crate::settings::domain::repository::SettingsRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct GetSettingsUseCase {
+    repo: Arc<dyn SettingsRepository>,
+}
+
+impl GetSettingsUseCase {
+    pub fn new(repo: Arc<dyn SettingsRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self) -> Result<AppSettings> {
+        self.repo.get().await
+    }
+}
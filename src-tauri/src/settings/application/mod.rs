@@ -0,0 +1,2 @@
+pub mod get_settings;
+pub mod update_settings;
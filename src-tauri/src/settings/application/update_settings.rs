@@ -0,0 +1,18 @@
+use crate::settings::domain::app_settings::AppSettings;
+use crate::settings::domain::repository::SettingsRepository;
+use anyhow::Result;
+use std::sync::Arc;
+
+pub struct UpdateSettingsUseCase {
+    repo: Arc<dyn SettingsRepository>,
+}
+
+impl UpdateSettingsUseCase {
+    pub fn new(repo: Arc<dyn SettingsRepository>) -> Self {
+        Self { repo }
+    }
+
+    pub async fn execute(&self, settings: AppSettings) -> Result<AppSettings> {
+        self.repo.update(&settings).await
+    }
+}
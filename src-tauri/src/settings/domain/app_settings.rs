@@ -0,0 +1,99 @@
+use crate::catalog::domain::Scale;
+use crate::core::domain::Currency;
+use crate::core::domain::length::Length;
+use crate::core::domain::measure_units::MeasureUnit;
+use crate::settings::domain::date_format::DateFormat;
+use serde::{Deserialize, Serialize};
+
+/// User preferences for the application.
+///
+/// Settings not yet stored fall back to the values returned by
+/// `AppSettings::default()`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct AppSettings {
+    /// The currency used to display monetary amounts.
+    pub preferred_currency: Currency,
+
+    /// The measure unit used to display lengths.
+    pub preferred_measure_unit: MeasureUnit,
+
+    /// The scale pre-selected for new catalog entries.
+    pub default_scale: Scale,
+
+    /// The format used to display dates.
+    pub date_format: DateFormat,
+}
+
+/// How many decimal places `AppSettings::display_length` keeps.
+const LENGTH_DISPLAY_DECIMALS: u32 = 2;
+
+impl AppSettings {
+    /// `length` converted to the preferred measure unit and rounded for
+    /// display.
+    pub fn display_length(&self, length: &Length) -> Length {
+        let converted = length.convert_to(self.preferred_measure_unit);
+        Length::new(
+            converted
+                .quantity()
+                .round_dp(LENGTH_DISPLAY_DECIMALS)
+                .normalize(),
+            self.preferred_measure_unit,
+        )
+    }
+
+    /// `length` formatted in the preferred measure unit, for example
+    /// `8.66 in`.
+    pub fn format_length(&self, length: &Length) -> String {
+        self.display_length(length).to_string()
+    }
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            preferred_currency: Currency::EUR,
+            preferred_measure_unit: MeasureUnit::Millimeters,
+            default_scale: Scale::H0,
+            date_format: DateFormat::Iso,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    fn preferring(preferred_measure_unit: MeasureUnit) -> AppSettings {
+        AppSettings {
+            preferred_measure_unit,
+            ..AppSettings::default()
+        }
+    }
+
+    #[rstest]
+    #[case(Length::new(dec!(220), MeasureUnit::Millimeters), MeasureUnit::Inches, "8.66 in")]
+    #[case(Length::new(dec!(1.0), MeasureUnit::Inches), MeasureUnit::Millimeters, "25.4 mm")]
+    #[case(Length::new(dec!(1500), MeasureUnit::Millimeters), MeasureUnit::Meters, "1.5 m")]
+    #[case(Length::new(dec!(2), MeasureUnit::Miles), MeasureUnit::Kilometers, "3.22 km")]
+    #[case(Length::new(dec!(1), MeasureUnit::Kilometers), MeasureUnit::Inches, "39370.08 in")]
+    #[case(Length::new(dec!(42), MeasureUnit::Inches), MeasureUnit::Inches, "42 in")]
+    fn it_should_format_lengths_in_the_preferred_measure_unit(
+        #[case] length: Length,
+        #[case] preferred: MeasureUnit,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(expected, preferring(preferred).format_length(&length));
+    }
+
+    #[test]
+    fn it_should_display_lengths_in_millimeters_by_default() {
+        let length =
+            AppSettings::default().display_length(&Length::new(dec!(3), MeasureUnit::Inches));
+
+        assert_eq!(MeasureUnit::Millimeters, length.measure_unit());
+        assert_eq!(dec!(76.2), length.quantity());
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+/// The date format used to display dates in the user interface.
+///
+/// Dates are always stored as ISO `YYYY-MM-DD`; this preference only affects
+/// how they are rendered.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Serialize,
+    Deserialize,
+    EnumString,
+    Display,
+    specta::Type,
+)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[strum(ascii_case_insensitive)]
+pub enum DateFormat {
+    /// ISO 8601 format (`YYYY-MM-DD`)
+    #[default]
+    Iso,
+    /// Day first (`DD/MM/YYYY`)
+    DayMonthYear,
+    /// Month first (`MM/DD/YYYY`)
+    MonthDayYear,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+    use std::str::FromStr;
+
+    #[rstest]
+    #[case(DateFormat::Iso, "ISO")]
+    #[case(DateFormat::DayMonthYear, "DAY_MONTH_YEAR")]
+    #[case(DateFormat::MonthDayYear, "MONTH_DAY_YEAR")]
+    fn date_format_round_trips(#[case] format: DateFormat, #[case] expected: &str) {
+        assert_eq!(format.to_string(), expected);
+        assert_eq!(DateFormat::from_str(expected).unwrap(), format);
+    }
+}
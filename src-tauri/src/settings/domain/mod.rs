@@ -0,0 +1,3 @@
+pub mod app_settings;
+pub mod date_format;
+pub mod repository;
@@ -0,0 +1,10 @@
+use crate::settings::domain::app_settings::AppSettings;
+
+#[async_trait::async_trait]
+pub trait SettingsRepository: Send + Sync {
+    /// Load the current settings, using defaults for values not yet stored.
+    async fn get(&self) -> anyhow::Result<AppSettings>;
+
+    /// Persist `settings`, leaving any unknown keys in storage untouched.
+    async fn update(&self, settings: &AppSettings) -> anyhow::Result<AppSettings>;
+}
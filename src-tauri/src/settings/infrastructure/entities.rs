@@ -0,0 +1,13 @@
+//! Database row representations for the `settings` feature.
+//!
+//! These structs mirror the columns defined in the
+//! `0003_create_settings_table` migration and are intended only as a thin
+//! database representation (FromRow). Conversion to rich domain types should
+//! happen in the repository layer.
+
+/// Row mapping for the `settings` table.
+#[derive(Debug, sqlx::FromRow)]
+pub struct SettingRow {
+    pub key: String,
+    pub value: String,
+}
@@ -0,0 +1,5 @@
+pub mod entities;
+
+pub mod sqlite;
+
+pub mod sqlite_repo;
@@ -0,0 +1,61 @@
+//! SQLite helper functions (crate-internal) used to read and write settings.
+//!
+//! Settings are stored as key/value pairs. Writes only ever upsert the keys
+//! they are given, so rows for keys unknown to this version of the
+//! application are preserved.
+
+use anyhow::{Context, Result};
+use sqlx::{SqliteExecutor, SqlitePool};
+
+use crate::settings::infrastructure::entities::SettingRow;
+
+/// Fetch every stored setting.
+pub async fn fetch_settings(pool: &SqlitePool) -> Result<Vec<SettingRow>> {
+    let rows = sqlx::query_as::<_, SettingRow>("SELECT key, value FROM settings ORDER BY key")
+        .fetch_all(pool)
+        .await
+        .context("fetching settings")?;
+
+    Ok(rows)
+}
+
+/// Insert or update the setting `key` with `value`.
+pub async fn upsert_setting<'e, E: SqliteExecutor<'e>>(
+    executor: E,
+    key: &str,
+    value: &str,
+) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO settings (key, value) VALUES (?1, ?2)
+        ON CONFLICT(key) DO UPDATE SET value = excluded.value, updated_at = CURRENT_TIMESTAMP",
+    )
+    .bind(key)
+    .bind(value)
+    .execute(executor)
+    .await
+    .with_context(|| format!("upserting setting key={}", key))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn upsert_setting_inserts_and_updates(pool: SqlitePool) -> Result<()> {
+        upsert_setting(&pool, "a", "1").await?;
+        upsert_setting(&pool, "b", "2").await?;
+        upsert_setting(&pool, "a", "3").await?;
+
+        let rows = fetch_settings(&pool).await?;
+        let pairs: Vec<(&str, &str)> = rows
+            .iter()
+            .map(|r| (r.key.as_str(), r.value.as_str()))
+            .collect();
+        assert_eq!(pairs, vec![("a", "3"), ("b", "2")]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,221 @@
+use crate::audit::infrastructure::sink::{AuditSink, AuditedChange, SqliteAuditSink};
+use crate::catalog::domain::Scale;
+use crate::core::domain::Currency;
+use crate::core::domain::measure_units::MeasureUnit;
+use crate::core::infrastructure::transaction::with_transaction;
+use crate::settings::domain::app_settings::AppSettings;
+use crate::settings::domain::date_format::DateFormat;
+use crate::settings::domain::repository::SettingsRepository;
+use crate::settings::infrastructure::entities::SettingRow;
+use crate::settings::infrastructure::sqlite;
+use anyhow::{Context, Result, anyhow};
+use serde_json::json;
+use sqlx::SqlitePool;
+use std::str::FromStr;
+use std::sync::Arc;
+
+const PREFERRED_CURRENCY: &str = "preferred_currency";
+const PREFERRED_MEASURE_UNIT: &str = "preferred_measure_unit";
+const DEFAULT_SCALE: &str = "default_scale";
+const DATE_FORMAT: &str = "date_format";
+
+pub struct SqliteSettingsRepository {
+    pool: SqlitePool,
+    audit: Arc<dyn AuditSink>,
+}
+
+impl SqliteSettingsRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self {
+            pool,
+            audit: Arc::new(SqliteAuditSink::default()),
+        }
+    }
+
+    /// Record the changes made by this repository in `audit`.
+    pub fn with_audit_sink(mut self, audit: Arc<dyn AuditSink>) -> Self {
+        self.audit = audit;
+        self
+    }
+}
+
+impl SqliteSettingsRepository {
+    /// Build `AppSettings` from the stored rows, starting from the defaults.
+    ///
+    /// Rows with keys this version does not know about are ignored.
+    fn build_app_settings(rows: Vec<SettingRow>) -> Result<AppSettings> {
+        let mut settings = AppSettings::default();
+
+        for row in rows {
+            let context = || format!("invalid value for setting key={}", row.key);
+            match row.key.as_str() {
+                PREFERRED_CURRENCY => {
+                    settings.preferred_currency = Currency::from_code(&row.value)
+                        .map_err(|e| anyhow!(e))
+                        .with_context(context)?;
+                }
+                PREFERRED_MEASURE_UNIT => {
+                    settings.preferred_measure_unit =
+                        MeasureUnit::from_str(&row.value).with_context(context)?;
+                }
+                DEFAULT_SCALE => {
+                    settings.default_scale =
+                        Scale::try_from(row.value.as_str()).with_context(context)?;
+                }
+                DATE_FORMAT => {
+                    settings.date_format =
+                        DateFormat::from_str(&row.value).with_context(context)?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(settings)
+    }
+
+    /// Encode the known settings as `(key, value)` pairs for storage.
+    fn to_values(settings: &AppSettings) -> Vec<(&'static str, String)> {
+        let measure_unit: &'static str = settings.preferred_measure_unit.into();
+        vec![
+            (
+                PREFERRED_CURRENCY,
+                settings.preferred_currency.code().to_string(),
+            ),
+            (PREFERRED_MEASURE_UNIT, measure_unit.to_string()),
+            (DEFAULT_SCALE, settings.default_scale.label().to_string()),
+            (DATE_FORMAT, settings.date_format.to_string()),
+        ]
+    }
+}
+
+#[async_trait::async_trait]
+impl SettingsRepository for SqliteSettingsRepository {
+    async fn get(&self) -> Result<AppSettings> {
+        let rows = sqlite::fetch_settings(&self.pool).await?;
+        Self::build_app_settings(rows)
+    }
+
+    async fn update(&self, settings: &AppSettings) -> Result<AppSettings> {
+        let changes = Self::to_values(settings)
+            .into_iter()
+            .map(|(key, value)| {
+                let change = AuditedChange::updated("setting", key, &json!({ "value": value }))?;
+                Ok((key, value, change))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let audit = Arc::clone(&self.audit);
+
+        with_transaction(&self.pool, move |conn| {
+            Box::pin(async move {
+                for (key, value, change) in &changes {
+                    sqlite::upsert_setting(&mut *conn, key, value).await?;
+                    audit.record(&mut *conn, change).await?;
+                }
+                Ok(())
+            })
+        })
+        .await?;
+
+        self.get().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::infrastructure::sqlite::fetch_settings;
+    use pretty_assertions::assert_eq;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_returns_defaults_for_empty_table(pool: SqlitePool) -> Result<()> {
+        let repo = SqliteSettingsRepository::new(pool);
+
+        let settings = repo.get().await?;
+
+        assert_eq!(settings, AppSettings::default());
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_update_round_trips(pool: SqlitePool) -> Result<()> {
+        let repo = SqliteSettingsRepository::new(pool);
+        let settings = AppSettings {
+            preferred_currency: Currency::GBP,
+            preferred_measure_unit: MeasureUnit::Inches,
+            default_scale: Scale::Scale00,
+            date_format: DateFormat::DayMonthYear,
+        };
+
+        let updated = repo.update(&settings).await?;
+
+        assert_eq!(updated, settings);
+        assert_eq!(repo.get().await?, settings);
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_update_preserves_unknown_keys(pool: SqlitePool) -> Result<()> {
+        sqlx::query("INSERT INTO settings (key, value) VALUES ('future_setting', 'keep me')")
+            .execute(&pool)
+            .await?;
+        let repo = SqliteSettingsRepository::new(pool.clone());
+
+        repo.update(&AppSettings::default()).await?;
+
+        let rows = fetch_settings(&pool).await?;
+        let future = rows
+            .iter()
+            .find(|r| r.key == "future_setting")
+            .expect("unknown key should be preserved");
+        assert_eq!(future.value, "keep me");
+        assert_eq!(rows.len(), 5);
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_update_is_audited(pool: SqlitePool) -> Result<()> {
+        let repo = SqliteSettingsRepository::new(pool.clone());
+        let settings = AppSettings {
+            preferred_currency: Currency::GBP,
+            ..AppSettings::default()
+        };
+
+        repo.update(&settings).await?;
+
+        let entries: Vec<(String, String, String)> =
+            sqlx::query_as("SELECT operation, entity_id, changes FROM audit_log WHERE entity_type = 'setting' ORDER BY id")
+                .fetch_all(&pool)
+                .await?;
+        let keys: Vec<&str> = entries.iter().map(|(_, key, _)| key.as_str()).collect();
+        assert_eq!(
+            keys,
+            vec![
+                PREFERRED_CURRENCY,
+                PREFERRED_MEASURE_UNIT,
+                DEFAULT_SCALE,
+                DATE_FORMAT
+            ]
+        );
+        assert!(
+            entries
+                .iter()
+                .all(|(operation, _, _)| operation == "update")
+        );
+        assert_eq!(entries[0].2, json!({ "value": "GBP" }).to_string());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_rejects_invalid_values(pool: SqlitePool) -> Result<()> {
+        sqlx::query("INSERT INTO settings (key, value) VALUES ('preferred_currency', 'XYZ')")
+            .execute(&pool)
+            .await?;
+        let repo = SqliteSettingsRepository::new(pool);
+
+        let err = repo.get().await.unwrap_err();
+
+        assert!(err.to_string().contains("preferred_currency"));
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+//! Command handlers exposed to the Tauri frontend for the `settings` feature.
+//!
+//! These functions act as a thin adapter between the Tauri IPC layer and the
+//! application/use-case layer. They translate incoming requests into use-case
+//! invocations and map application errors into `CommandError` values suitable
+//! for returning over the IPC boundary.
+
+use crate::core::infrastructure::error::CommandError;
+use crate::settings::application::get_settings::GetSettingsUseCase;
+use crate::settings::application::update_settings::UpdateSettingsUseCase;
+use crate::settings::domain::app_settings::AppSettings;
+use crate::settings::infrastructure::sqlite_repo::SqliteSettingsRepository;
+use crate::state::AppState;
+use std::sync::Arc;
+
+/// Tauri command to retrieve the user settings.
+///
+/// Settings that have never been saved are returned with their default value.
+///
+/// Returns:
+/// - `Ok(AppSettings)` when retrieval succeeds.
+/// - `Err(CommandError)` when the settings cannot be loaded.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_settings(state: tauri::State<'_, AppState>) -> Result<AppSettings, CommandError> {
+    let repo = SqliteSettingsRepository::new(state.db_pool());
+    let use_case = GetSettingsUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute()
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+/// Tauri command to save the user settings.
+///
+/// Returns:
+/// - `Ok(AppSettings)` with the settings as stored after the update.
+/// - `Err(CommandError)` when the settings cannot be saved.
+#[tauri::command]
+#[specta::specta]
+pub async fn update_settings(
+    state: tauri::State<'_, AppState>,
+    settings: AppSettings,
+) -> Result<AppSettings, CommandError> {
+    let repo = SqliteSettingsRepository::new(state.db_pool());
+    let use_case = UpdateSettingsUseCase::new(Arc::new(repo));
+
+    use_case
+        .execute(settings)
+        .await
+        .map_err(|e| CommandError::Unknown(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::domain::Currency;
+    use crate::db::init_in_memory_db_pool;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn command_update_settings_is_visible_to_get_settings() {
+        let pool = init_in_memory_db_pool().await.expect("init in-memory pool");
+        let repo = Arc::new(SqliteSettingsRepository::new(pool));
+
+        let settings = AppSettings {
+            preferred_currency: Currency::USD,
+            ..AppSettings::default()
+        };
+        UpdateSettingsUseCase::new(repo.clone())
+            .execute(settings.clone())
+            .await
+            .expect("update_settings");
+
+        let found = GetSettingsUseCase::new(repo)
+            .execute()
+            .await
+            .expect("get_settings");
+        assert_eq!(found, settings);
+    }
+}
@@ -1,4 +1,7 @@
 use rand::distr::{Alphanumeric, SampleString};
+use std::sync::Once;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 
 /// Generate a random alphanumeric `String` of length `len`.
 ///
@@ -6,3 +9,93 @@ use rand::distr::{Alphanumeric, SampleString};
 pub fn random_str(len: usize) -> String {
     Alphanumeric.sample_string(&mut rand::rng(), len)
 }
+
+/// Return the `detail` lines of `EXPLAIN QUERY PLAN` for `sql`.
+///
+/// Unbound `?N` parameters are treated as `NULL`, which does not change the
+/// chosen plan.
+pub async fn query_plan(pool: &sqlx::SqlitePool, sql: &str) -> Vec<String> {
+    use sqlx::Row;
+
+    sqlx::query(&format!("EXPLAIN QUERY PLAN {}", sql))
+        .fetch_all(pool)
+        .await
+        .expect("explain query plan")
+        .iter()
+        .map(|row| row.get::<String, _>("detail"))
+        .collect()
+}
+
+/// Assert that `sql` reads every table through an index (no `SCAN` step).
+pub async fn assert_uses_indexes(pool: &sqlx::SqlitePool, sql: &str) {
+    assert_uses_indexes_except(pool, sql, &[]).await;
+}
+
+/// Like `assert_uses_indexes`, allowing the given `SCAN` steps: for example
+/// `"SCAN page"` for the rows of a `page` CTE that is already limited.
+pub async fn assert_uses_indexes_except(pool: &sqlx::SqlitePool, sql: &str, allowed: &[&str]) {
+    let plan = query_plan(pool, sql).await;
+    assert!(
+        !plan
+            .iter()
+            .any(|step| step.starts_with("SCAN") && !allowed.contains(&step.as_str())),
+        "expected no full table scan, got plan {:?}",
+        plan
+    );
+}
+
+/// Counts the statements run by pools from `statement_counting_pool`.
+///
+/// Such pools log every statement at `Error` level, which no other pool uses
+/// for `sqlx::query`, so this process-wide logger can tell them apart. Only
+/// one test should count statements at a time.
+struct StatementCounter;
+
+static STATEMENTS_RUN: AtomicUsize = AtomicUsize::new(0);
+
+impl log::Log for StatementCounter {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.target() == "sqlx::query" && metadata.level() == log::Level::Error
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            STATEMENTS_RUN.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// A single-connection pool on the database of `pool` whose statements are
+/// counted by `statements_run`.
+pub async fn statement_counting_pool(pool: &sqlx::SqlitePool) -> sqlx::SqlitePool {
+    use sqlx::ConnectOptions;
+
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        log::set_logger(&StatementCounter).expect("no other logger in tests");
+        log::set_max_level(log::LevelFilter::Error.max(log::max_level()));
+    });
+
+    let options = (*pool.connect_options())
+        .clone()
+        .log_statements(log::LevelFilter::Error)
+        .log_slow_statements(log::LevelFilter::Error, Duration::ZERO);
+    let counting_pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .expect("open statement counting pool");
+    // Open the connection now, so that its setup is not counted later.
+    sqlx::query("SELECT 1")
+        .execute(&counting_pool)
+        .await
+        .expect("warm up statement counting pool");
+    counting_pool
+}
+
+/// How many statements pools from `statement_counting_pool` have run so far.
+pub fn statements_run() -> usize {
+    STATEMENTS_RUN.load(Ordering::SeqCst)
+}
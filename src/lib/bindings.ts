@@ -18,6 +18,7 @@ async isDbInitialized() : Promise<boolean> {
  * 
  * Parameters:
  * - `state`: Tauri-managed application state which provides a database pool.
+ * - `catalog_cache`: the shared cache of railway model summaries.
  * 
  * Returns:
  * - `Ok(Collection)` when retrieval succeeds.
@@ -31,316 +32,4056 @@ async getCollection() : Promise<Result<Collection, CommandError>> {
     else return { status: "error", error: e  as any };
 }
 },
-async getAppVersion() : Promise<string> {
-    return await TAURI_INVOKE("get_app_version");
+/**
+ * Tauri command to list the collection items one page at a time.
+ * 
+ * Items are listed in the order they were added. Pass `cursor: null` for
+ * the first page and the returned `next_cursor` for the following ones,
+ * until it is `null`. `limit` is clamped to a sensible maximum.
+ * 
+ * Parameters:
+ * - `catalog_cache`: the shared cache of railway model summaries.
+ * 
+ * Returns:
+ * - `Ok(Page<CollectionItem>)` with the items of the page.
+ * - `Err(CommandError::Validation)` when `cursor` is not a cursor returned
+ * by this command.
+ * - `Err(CommandError)` for any other failure.
+ */
+async listCollectionItems(cursor: string | null, limit: number) : Promise<Result<Page<CollectionItem>, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_collection_items", { cursor, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
 }
+},
+/**
+ * Tauri command to look a collection item up by its short number.
+ * 
+ * Parameters:
+ * - `collection_id`: the identifier of the collection.
+ * - `item_number`: the number of the item within the collection.
+ * - `catalog_cache`: the shared cache of railway model summaries.
+ * 
+ * Returns:
+ * - `Ok(Some(CollectionItem))` with the item.
+ * - `Ok(None)` when the collection has no item with that number, for
+ * example because it was deleted.
+ * - `Err(CommandError)` when reading fails.
+ */
+async findItemByNumber(collectionId: string, itemNumber: number) : Promise<Result<CollectionItem | null, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("find_item_by_number", { collectionId, itemNumber }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
 }
-
-/** user-defined events **/
-
-
-
-/** user-defined constants **/
-
-
-
-/** user-defined types **/
-
+},
 /**
- * Represents a user-owned collection of items.
+ * Tauri command to add a copy of a collection item, for a second
+ * identical purchase.
  * 
- * A `Collection` contains identifying information, a few aggregated summary
- * values and the list of `CollectionItem` entries that make up the
- * collection. It is intentionally lightweight to keep IPC payloads small.
+ * The copy references the same railway model with the same conditions and
+ * notes; the purchase info and storage location are left for the collector
+ * to fill in.
+ * 
+ * Parameters:
+ * - `item_id`: the identifier of the collection item to copy.
+ * - `options`: whether to copy the owned rolling stocks, and whether to
+ * add the copy when the collection already has the railway model.
+ * 
+ * Returns:
+ * - `Ok(CollectionItemId)` with the identifier of the copy.
+ * - `Err(CommandError::Validation)` when the collection already has the
+ * railway model and `options.allow_duplicates` is `false`.
+ * - `Err(CommandError)` when the item does not exist or the copy fails.
+ */
+async duplicateCollectionItem(itemId: string, options: DuplicateItemOptions) : Promise<Result<string, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("duplicate_collection_item", { itemId, options }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to change the condition or storage location of every
+ * collection item matching a filter at once.
+ * 
+ * The items are changed in a single transaction. At most
+ * `BulkUpdateOptions::DEFAULT_LIMIT` items are changed unless
+ * `options.limit` allows more, and with `options.dry_run` nothing is
+ * written: the report tells how many items would change.
+ * 
+ * Parameters:
+ * - `filter`: the items to change; an empty filter matches every item.
+ * - `patch`: the fields to set, fields left `null` are not changed.
+ * - `options`: whether to only count the items, and the limit to apply.
+ * 
+ * Returns:
+ * - `Ok(BulkUpdateReport)` with how many items matched and changed.
+ * - `Err(CommandError::Validation)` when the patch is empty or more items
+ * would change than the limit allows.
+ * - `Err(CommandError)` when the storage location does not exist or the
+ * update fails.
+ */
+async bulkUpdateItems(filter: CollectionItemFilter, patch: CollectionItemPatch, options: BulkUpdateOptions) : Promise<Result<BulkUpdateReport, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("bulk_update_items", { filter, patch, options }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to break down what owning a collection item has cost.
+ * 
+ * The purchase price (net of its monetary adjustments), the decoders
+ * installed in the item's rolling stocks and the modifications made to
+ * them are listed one by one and totalled per currency; those with an
+ * unknown price are only counted.
+ * 
+ * Parameters:
+ * - `item_id`: the identifier of the collection item.
+ * 
+ * Returns:
+ * - `Ok(ItemCostBreakdown)` with the costs and their totals.
+ * - `Err(CommandError::Validation)` when an adjustment does not match the
+ * purchase currency.
+ * - `Err(CommandError)` when the item does not exist or reading fails.
+ */
+async getItemCostBreakdown(itemId: string) : Promise<Result<ItemCostBreakdown, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_item_cost_breakdown", { itemId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to record that the collector opened a collection item.
+ * 
+ * Only the latest views of each item are kept; older ones are pruned as
+ * the new view is recorded.
+ * 
+ * Parameters:
+ * - `item_id`: the identifier of the collection item.
+ * 
+ * Returns:
+ * - `Ok(())` when the view is recorded.
+ * - `Err(CommandError)` when the item does not exist or writing fails.
+ */
+async recordItemView(itemId: string) : Promise<Result<null, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("record_item_view", { itemId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to list the latest items added, modified and viewed, for
+ * the home screen.
+ * 
+ * Each item is listed at most once per kind of event, at its latest one,
+ * and the events are sorted newest first.
+ * 
+ * Parameters:
+ * - `limit`: how many events to return at most.
+ * 
+ * Returns:
+ * - `Ok(Vec<ActivityEvent>)` with the events tagged by their kind.
+ * - `Err(CommandError)` when reading fails.
+ */
+async getRecentActivity(limit: number) : Promise<Result<ActivityEvent[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_recent_activity", { limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to parse a command palette quick-add entry, such as
+ * `acme 60211 2x €129.90 2024-03-15`, into a collection item draft.
+ * 
+ * The model is looked up in the catalog by its product code; when several
+ * models match the candidates are returned for the collector to pick one.
+ * Nothing is stored.
+ * 
+ * Returns:
+ * - `Ok(QuickAddProposal)` with the draft and the matching models.
+ * - `Err(CommandError::InvalidField)` when the entry cannot be parsed.
+ */
+async parseQuickAdd(input: string) : Promise<Result<QuickAddProposal, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("parse_quick_add", { input }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to recompute a collection's summary counters and total value.
+ * 
+ * The counters and totals are recalculated from the collection items in a
+ * single transaction and stored. On success a `SummaryRecomputed` event is
+ * emitted with the same diff that is returned, so other views can refresh.
+ * 
+ * The counters are kept up to date by database triggers as items change, so
+ * this is a repair tool for counters that drifted (for example after the
+ * database was edited by hand); the total value is only updated here and
+ * by `add_monetary_adjustment`.
+ * 
+ * Parameters:
+ * - `id`: the identifier of the collection to recompute.
+ * 
+ * Returns:
+ * - `Ok(CollectionRecomputeDiff)` with the old and new values.
+ * - `Err(CommandError)` when the collection does not exist or the
+ * recomputation fails.
+ */
+async recomputeCollection(id: string) : Promise<Result<CollectionRecomputeDiff, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("recompute_collection", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to check a collection against the catalog it refers to.
+ * 
+ * The report lists the owned rolling stocks that refer to rolling stocks
+ * missing from the catalog, the summary counters that no longer match the
+ * categories of the items' railway models and the purchase infos with
+ * unsupported currencies, each with the action that repairs it.
+ * 
+ * Parameters:
+ * - `id`: the identifier of the collection to check.
+ * - `repair`: whether to apply the safe repairs, in a single transaction;
+ * unsupported currencies are only reported.
+ * 
+ * Returns:
+ * - `Ok(ConsistencyReport)` describing the collection before any repair.
+ * - `Err(CommandError)` when the collection does not exist or the check
+ * fails.
+ */
+async checkCollectionConsistency(id: string, repair: boolean) : Promise<Result<ConsistencyReport, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_collection_consistency", { id, repair }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to compute a collection's value and realized gains.
+ * 
+ * Sold items are left out of the value and count in the realized gains
+ * (sale price minus what they cost) instead. Unlike the stored total value,
+ * the figures are computed from the collection items on every call.
+ * 
+ * Parameters:
+ * - `id`: the identifier of the collection.
+ * 
+ * Returns:
+ * - `Ok(CollectionStatistics)` with one entry per currency.
+ * - `Err(CommandError)` when the collection does not exist or the
+ * computation fails.
+ */
+async getCollectionStatistics(id: string) : Promise<Result<CollectionStatistics, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_collection_statistics", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to summarize what was bought and sold in a collection
+ * during a calendar year.
+ * 
+ * Pre-ordered items are left out of the purchases; costs are net of their
+ * monetary adjustments. A year with no purchases nor sales returns zeroed
+ * figures.
+ * 
+ * Parameters:
+ * - `id`: the identifier of the collection.
+ * - `year`: the calendar year to review.
+ * 
+ * Returns:
+ * - `Ok(YearReview)` with the monetary figures given per currency.
+ * - `Err(CommandError)` when the collection does not exist or the
+ * computation fails.
+ */
+async generateYearReview(id: string, year: number) : Promise<Result<YearReview, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("generate_year_review", { id, year }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to list a collection's items for its insurer.
+ * 
+ * Each item that is not sold is listed with its catalog description,
+ * product code and road numbers, its purchase date and price and the
+ * replacement value entered for it, and the report ends with the insured
+ * values totalled per currency.
+ * 
+ * Parameters:
+ * - `id`: the identifier of the collection.
+ * - `valuation`: whether items are insured at their purchase price or at
+ * their replacement value (falling back to the purchase price).
+ * 
+ * Returns:
+ * - `Ok(InsuranceReport)` with the items ordered by manufacturer and
+ * product code.
+ * - `Err(CommandError)` when the collection does not exist or the report
+ * cannot be computed.
+ */
+async exportInsuranceReport(id: string, valuation: ValuationMode) : Promise<Result<InsuranceReport, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_insurance_report", { id, valuation }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to record the current value of a collection for charting.
+ * 
+ * A collection has at most one snapshot per day: taking it again on the
+ * same day replaces the values recorded earlier. Snapshots are also taken
+ * automatically after the demo data is imported.
+ * 
+ * Parameters:
+ * - `id`: the identifier of the collection.
+ * 
+ * Returns:
+ * - `Ok(ValueSnapshot)` with today's values, one entry per currency.
+ * - `Err(CommandError)` when the collection does not exist or the snapshot
+ * cannot be stored.
+ */
+async takeValueSnapshot(id: string) : Promise<Result<ValueSnapshot, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("take_value_snapshot", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to list how the value of a collection changed over time.
+ * 
+ * Parameters:
+ * - `id`: the identifier of the collection.
+ * - `from`, `to`: the first and last day of the range, both included.
+ * 
+ * Returns:
+ * - `Ok(Vec<ValueSnapshot>)` with the snapshots taken in the range, oldest
+ * first; empty when none was taken.
+ * - `Err(CommandError)` when the snapshots cannot be read.
+ */
+async getValueHistory(id: string, from: string, to: string) : Promise<Result<ValueSnapshot[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_value_history", { id, from, to }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to record a refund or other adjustment of a purchase.
+ * 
+ * The signed `amount` (in the smallest currency unit, negative for a
+ * refund) is netted out of the purchase cost, keeping the original price.
+ * The collection total value is then recomputed and a `SummaryRecomputed`
+ * event is emitted with the diff.
+ * 
+ * Parameters:
+ * - `purchase_id`: the identifier of the adjusted purchase info.
+ * - `currency`: must be the currency of the purchase price.
+ * - `reason`: why the cost was adjusted.
+ * 
+ * Returns:
+ * - `Ok(MonetaryAdjustment)` with the stored adjustment.
+ * - `Err(CommandError::Validation)` when the purchase has no price, the
+ * currency differs or the adjustment would bring the cost below zero.
+ * - `Err(CommandError)` for any other failure.
+ */
+async addMonetaryAdjustment(purchaseId: string, amount: bigint, currency: Currency, date: string, reason: string) : Promise<Result<MonetaryAdjustment, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_monetary_adjustment", { purchaseId, amount, currency, date, reason }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to record the purchase of a collection item.
+ * 
+ * `purchase.price` is in the smallest unit of `purchase.currency`; when no
+ * currency is given the preferred currency of the settings is used. The
+ * collection total value is not updated (see `recompute_collection`).
+ * 
+ * Parameters:
+ * - `item_id`: the identifier of the purchased collection item.
+ * 
+ * Returns:
+ * - `Ok(PurchasedInfo)` with the stored purchase.
+ * - `Err(CommandError)` when the item does not exist or already has a
+ * purchase info.
+ */
+async addPurchase(itemId: string, purchase: NewPurchase) : Promise<Result<PurchasedInfo, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_purchase", { itemId, purchase }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to list the amounts of every collection item converted
+ * into a display currency.
+ * 
+ * The stored amounts are returned unchanged next to their converted values,
+ * which use the stored exchange rates; nothing is written.
+ * 
+ * Parameters:
+ * - `id`: the identifier of the collection.
+ * - `display_currency`: the currency to display, or `null` for the
+ * preferred currency of the settings.
+ * 
+ * Returns:
+ * - `Ok(DisplayCurrencyListing)` with the stored and display amounts, and
+ * the currencies with no exchange rate into the display currency.
+ * - `Err(CommandError)` when the amounts cannot be loaded.
+ */
+async convertDisplayCurrency(id: string, displayCurrency: Currency | null) : Promise<Result<DisplayCurrencyListing, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("convert_display_currency", { id, displayCurrency }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to list the decoders in the inventory.
+ * 
+ * Returns:
+ * - `Ok(Vec<Decoder>)` ordered by manufacturer and model.
+ * - `Err(CommandError)` when the decoders cannot be read.
+ */
+async listDecoders() : Promise<Result<Decoder[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_decoders") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to add a decoder to the inventory.
+ * 
+ * A decoder on an address other decoders already use is stored all the
+ * same, with a warning.
+ * 
+ * Parameters:
+ * - `decoder`: the decoder details; the text fields are trimmed.
+ * 
+ * Returns:
+ * - `Ok(SavedDecoder)` with the stored decoder, its generated id and the
+ * warnings.
+ * - `Err(CommandError::Validation)` when the address is outside the DCC
+ * range 1-10239.
+ * - `Err(CommandError)` when the decoder cannot be stored.
+ */
+async createDecoder(decoder: DecoderDetails) : Promise<Result<SavedDecoder, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_decoder", { decoder }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to change the details of a decoder.
+ * 
+ * The address is checked as by `create_decoder`.
+ * 
+ * Parameters:
+ * - `id`: the identifier of the decoder.
+ * - `decoder`: the new decoder details, replacing all the stored ones.
+ * 
+ * Returns:
+ * - `Ok(SavedDecoder)` with the stored decoder and the warnings.
+ * - `Err(CommandError::Validation)` when the address is outside the DCC
+ * range.
+ * - `Err(CommandError)` when the decoder does not exist or cannot be
+ * stored.
+ */
+async updateDecoder(id: string, decoder: DecoderDetails) : Promise<Result<SavedDecoder, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_decoder", { id, decoder }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to install a decoder in an owned rolling stock.
+ * 
+ * The decoder installed there before, if any, is uninstalled. A decoder
+ * whose connector differs from the DCC interface the catalog stores for the
+ * rolling stock is installed all the same, with a warning.
+ * 
+ * Parameters:
+ * - `owned_rolling_stock_id`: the identifier of the owned rolling stock.
+ * - `decoder_id`: the identifier of the decoder.
+ * 
+ * Returns:
+ * - `Ok(Vec<DecoderWarning>)` listing what does not fit; empty when
+ * nothing.
+ * - `Err(CommandError::Validation)` when the decoder is installed in
+ * another rolling stock.
+ * - `Err(CommandError)` when either does not exist or the change cannot be
+ * stored.
+ */
+async attachDecoder(ownedRollingStockId: string, decoderId: string) : Promise<Result<DecoderWarning[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("attach_decoder", { ownedRollingStockId, decoderId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to remove the decoder installed in an owned rolling stock.
+ * 
+ * The decoder stays in the inventory.
+ * 
+ * Parameters:
+ * - `owned_rolling_stock_id`: the identifier of the owned rolling stock.
+ * 
+ * Returns:
+ * - `Ok(Some(id))` with the identifier of the removed decoder, `Ok(None)`
+ * when none was installed.
+ * - `Err(CommandError)` when the owned rolling stock does not exist or the
+ * change cannot be stored.
+ */
+async detachDecoder(ownedRollingStockId: string) : Promise<Result<string | null, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("detach_decoder", { ownedRollingStockId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to find the owned rolling stocks whose decoders share a
+ * DCC address, so they would respond to each other's commands.
+ * 
+ * Returns:
+ * - `Ok(Vec<AddressConflict>)` with one entry per shared address, ordered
+ * by address; empty when there is none.
+ * - `Err(CommandError)` when the decoders cannot be read.
+ */
+async getAddressConflicts() : Promise<Result<AddressConflict[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_address_conflicts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to list the consists, checked against the layout.
+ * 
+ * Parameters:
+ * - `longest_siding_mm`: the length of the longest siding in millimeters,
+ *   to tell which trains fit on it.
+ * - `layout_radius_mm`: the radius of the tightest curve of the layout in
+ *   millimeters, to warn about vehicles needing wider curves.
+ * 
+ * Returns:
+ * - `Ok(Vec<ConsistPlan>)` ordered by name.
+ * - `Err(CommandError::InvalidField)` when a layout measure is not a
+ *   positive length.
+ * - `Err(CommandError)` when the consists cannot be read.
+ */
+async listConsists(longestSidingMm: number | null, layoutRadiusMm: number | null) : Promise<Result<ConsistPlan[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_consists", { longestSidingMm, layoutRadiusMm }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to assemble a consist from owned rolling stocks.
+ * 
+ * Parameters:
+ * - `consist`: the name and the vehicles, from head to tail.
+ * - `longest_siding_mm`, `layout_radius_mm`: the layout to check the
+ *   consist against, as for `list_consists`.
+ * 
+ * Returns:
+ * - `Ok(ConsistPlan)` with the stored consist and its generated id.
+ * - `Err(CommandError::Validation)` when the name is blank or a vehicle
+ *   appears twice.
+ * - `Err(CommandError)` when an owned rolling stock does not exist or the
+ *   consist cannot be stored.
+ */
+async createConsist(consist: ConsistDetails, longestSidingMm: number | null, layoutRadiusMm: number | null) : Promise<Result<ConsistPlan, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_consist", { consist, longestSidingMm, layoutRadiusMm }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to rename a consist or change its vehicles.
+ * 
+ * Parameters:
+ * - `id`: the identifier of the consist.
+ * - `consist`: the new name and vehicles, replacing the stored ones.
+ * - `longest_siding_mm`, `layout_radius_mm`: the layout to check the
+ *   consist against, as for `list_consists`.
+ * 
+ * Returns:
+ * - `Ok(ConsistPlan)` with the stored consist.
+ * - `Err(CommandError::Validation)` when the name is blank or a vehicle
+ *   appears twice.
+ * - `Err(CommandError)` when the consist or an owned rolling stock does not
+ *   exist, or the consist cannot be stored.
+ */
+async updateConsist(id: string, consist: ConsistDetails, longestSidingMm: number | null, layoutRadiusMm: number | null) : Promise<Result<ConsistPlan, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_consist", { id, consist, longestSidingMm, layoutRadiusMm }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to search the catalog for railway models.
+ * 
+ * Intended for autocomplete pickers: the query is trimmed, queries shorter
+ * than two characters return an empty list and `limit` is clamped to a
+ * sensible maximum. Results are ranked with exact product code matches
+ * first.
+ * 
+ * Returns:
+ * - `Ok(Vec<RailwayModelSummary>)` with the matching models.
+ * - `Err(CommandError)` when the search fails.
+ */
+async searchCatalog(query: string, limit: number) : Promise<Result<RailwayModelSummary[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("search_catalog", { query, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to list the railway models one page at a time.
+ * 
+ * Models are ordered by product code and come with their rolling stock
+ * count and the range of their lengths over buffers, all read with a single
+ * query per page. Pass `cursor: null` for the first page and the returned
+ * `next_cursor` for the following ones, until it is `null`. `limit` is
+ * clamped to a sensible maximum.
+ * 
+ * Returns:
+ * - `Ok(Page<RailwayModelListEntry>)` with the models of the page.
+ * - `Err(CommandError::Validation)` when `cursor` is not a cursor returned
+ * by this command.
+ * - `Err(CommandError)` for any other failure.
+ */
+async listRailwayModels(cursor: string | null, limit: number) : Promise<Result<Page<RailwayModelListEntry>, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_railway_models", { cursor, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command returning the option lists for the catalog forms.
+ * 
+ * Returns manufacturers, railway companies, scales, categories, DCC
+ * interfaces and coupling sockets in one payload, so a form can populate
+ * all of its dropdowns with a single call.
+ * 
+ * Returns:
+ * - `Ok(ReferenceData)` with every option list.
+ * - `Err(CommandError)` when reading from the database fails.
+ */
+async getReferenceData() : Promise<Result<ReferenceData, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_reference_data") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to check a product code before submitting the "add model"
+ * form.
+ * 
+ * The code is trimmed and compared case-insensitively against the models
+ * already registered for `manufacturer_id`, using the same normalization as
+ * model creation.
+ * 
+ * Returns:
+ * - `Ok(ProductCodeValidation)` describing whether the code is available,
+ * already used or malformed.
+ * - `Err(CommandError)` when the lookup fails.
+ */
+async validateProductCode(manufacturerId: string, productCode: string) : Promise<Result<ProductCodeValidation, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("validate_product_code", { manufacturerId, productCode }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to check the road number of a rolling stock as it is
+ * typed, for example "E.656 077" or the UIC number "61 83 19-90 105-3".
+ * 
+ * The check never blocks saving: a warning comes with the normalized or
+ * corrected road number to suggest, when one can be worked out.
+ * 
+ * Parameters:
+ * - `railway`: the name of the railway company of the rolling stock.
+ * - `category`: the rolling stock category.
+ * - `road_number`: the road number as typed.
+ */
+async validateRoadNumber(railway: string, category: RollingStockCategory, roadNumber: string) : Promise<ValidationOutcome> {
+    return await TAURI_INVOKE("validate_road_number", { railway, category, roadNumber });
+},
+/**
+ * Tauri command to find the railway models that are probably duplicates,
+ * for example the same model imported from two sources.
+ * 
+ * Only models from the same manufacturer and scale are compared, by the
+ * trigram similarity of their descriptions. `threshold` (from 0 to 1) is
+ * clamped to a sensible minimum.
+ * 
+ * Returns:
+ * - `Ok(Vec<DuplicateCandidate>)` with the candidate pairs, most similar
+ * first.
+ * - `Err(CommandError)` when reading from the database fails.
+ */
+async findPossibleDuplicates(threshold: number) : Promise<Result<DuplicateCandidate[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("find_possible_duplicates", { threshold }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to merge a duplicate railway model into the one kept.
+ * 
+ * The rolling stocks of `remove_id` and the collection items referencing it
+ * move to `keep_id`, then `remove_id` is deleted, all in one transaction.
+ * 
+ * Arguments:
+ * - `catalog_cache`: the shared cache of railway model summaries, from
+ * which both models are dropped.
+ * 
+ * Returns:
+ * - `Ok(ModelMerge)` with what was moved.
+ * - `Err(CommandError::Validation)` when the ids are the same or the models
+ * are from different manufacturers.
+ * - `Err(CommandError)` for any other failure, for example an unknown id.
+ */
+async mergeRailwayModels(keepId: string, removeId: string) : Promise<Result<ModelMerge, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("merge_railway_models", { keepId, removeId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to compare two rolling stocks side by side, for choosing
+ * between models of the same prototype.
+ * 
+ * Their shared fields and those of their category are lined up and marked
+ * as equal, different or missing.
+ * 
+ * Parameters:
+ * - `a`: the identifier of the first rolling stock.
+ * - `b`: the identifier of the second rolling stock.
+ * 
+ * Returns:
+ * - `Ok(ComparisonReport)` with the fields of both.
+ * - `Err(CommandError::Validation)` when the rolling stocks are of
+ * different categories.
+ * - `Err(CommandError)` when a rolling stock does not exist or reading
+ * fails.
+ */
+async compareRollingStocks(a: string, b: string) : Promise<Result<ComparisonReport, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("compare_rolling_stocks", { a, b }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to ingest a list of new items announced by a manufacturer.
+ * 
+ * Product codes the catalog does not know become announced railway models;
+ * for the known ones only the delivery date and the suggested retail price
+ * are refreshed, so the same list can be ingested again.
+ * 
+ * Parameters:
+ * - `batch`: the manufacturer and its announced items.
+ * 
+ * Returns:
+ * - `Ok(AnnouncementIngest)` with what happened to each item.
+ * - `Err(CommandError::Validation)` when the manufacturer is unknown, a
+ * product code or description is blank, or a product code is repeated;
+ * nothing is stored.
+ * - `Err(CommandError)` when writing fails.
+ */
+async ingestAnnouncements(batch: AnnouncementBatch) : Promise<Result<AnnouncementIngest, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("ingest_announcements", { batch }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to tell which of a list of product codes, for example
+ * those of an order confirmation, are in the catalog and in the collection.
+ * 
+ * Manufacturers and product codes are compared ignoring case and
+ * surrounding blanks.
+ * 
+ * Parameters:
+ * - `queries`: the manufacturer and product code pairs to look up, at most
+ * `MAX_LOOKUP_CODES` of them.
+ * 
+ * Returns:
+ * - `Ok(Vec<ProductCodeLookup>)` with the railway model of each pair, if
+ * any, and whether it is owned, in the order of `queries`.
+ * - `Err(CommandError::Validation)` when there are too many pairs.
+ * - `Err(CommandError)` when the lookup fails.
+ */
+async lookupProductCodes(queries: ProductCodeQuery[]) : Promise<Result<ProductCodeLookup[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("lookup_product_codes", { queries }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to add a livery to a railway company.
+ * 
+ * Parameters:
+ * - `livery`: the livery details; the text fields are trimmed.
+ * 
+ * Returns:
+ * - `Ok(Livery)` with the stored livery and its generated id.
+ * - `Err(CommandError::Validation)` when the name is blank or the period of
+ * use ends before it starts.
+ * - `Err(CommandError)` when the livery cannot be stored, for example when
+ * the railway company already has a livery with this name.
+ */
+async createLivery(livery: LiveryDetails) : Promise<Result<Livery, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("create_livery", { livery }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to change the details of a livery.
+ * 
+ * The details are checked as by `create_livery`.
+ * 
+ * Parameters:
+ * - `id`: the identifier of the livery.
+ * - `livery`: the new livery details, replacing all the stored ones.
+ * 
+ * Returns:
+ * - `Ok(Livery)` with the stored livery.
+ * - `Err(CommandError::Validation)` when the details are not valid.
+ * - `Err(CommandError)` when the livery does not exist or cannot be stored.
+ */
+async updateLivery(id: string, livery: LiveryDetails) : Promise<Result<Livery, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_livery", { id, livery }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to suggest the liveries of a railway company, for the
+ * livery field of the rolling stock form.
+ * 
+ * Parameters:
+ * - `railway_id`: the identifier of the railway company of the rolling
+ * stock.
+ * - `prefix`: what was typed so far; blank suggests every livery.
+ * 
+ * Returns:
+ * - `Ok(Vec<Livery>)` with the exact name match first, then the name and
+ * word prefix matches, at most `limit` of them.
+ * - `Err(CommandError)` when the liveries cannot be read.
+ */
+async suggestLiveries(railwayId: string, prefix: string, limit: number) : Promise<Result<Livery[], CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("suggest_liveries", { railwayId, prefix, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to convert the speed of a model timed over a stretch of
+ * track into the speed of its prototype, for the utilities panel.
+ * 
+ * Parameters:
+ * - `distance`: the length of track the model ran.
+ * - `seconds`: how long the run took.
+ * 
+ * Returns:
+ * - `Ok(ScaleSpeed)` with the model and prototype speeds, and how long a
+ * run over 1 m takes at 100 scale km/h.
+ * - `Err(CommandError::InvalidField)` when `seconds` is not positive.
+ */
+async calculateScaleSpeed(distance: Length, seconds: string, scale: Scale) : Promise<Result<ScaleSpeed, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("calculate_scale_speed", { distance, seconds, scale }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to compute the gradient of a track climbing `rise` over
+ * `run`, in any units.
+ * 
+ * Returns:
+ * - `Ok(f64)` with the gradient as a percentage.
+ * - `Err(CommandError::Validation)` when `run` is zero or a length is
+ * negative.
+ */
+async calculateGradient(rise: Length, run: Length) : Promise<Result<number, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("calculate_gradient", { rise, run }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to check that an overhead structure `clearance` above the
+ * rails lets the trains of `scale` through.
+ * 
+ * Returns:
+ * - `Ok(ClearanceCheck)` with the clearance, the minimum of the scale and
+ * whether the structure fits.
+ * - `Err(CommandError::Validation)` when `clearance` is negative.
+ */
+async checkVerticalClearance(clearance: Length, scale: Scale) : Promise<Result<ClearanceCheck, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_vertical_clearance", { clearance, scale }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to estimate how long a ladder of turnouts fanning out into
+ * `tracks` parallel tracks is, along the straight track.
+ * 
+ * Parameters:
+ * - `turnout_length`: the length of one turnout.
+ * - `frog_number`: the `n` of the `1:n` frog of the turnouts.
+ * - `track_spacing`: the distance between the centers of two tracks.
+ * 
+ * Returns:
+ * - `Ok(f64)` with the length of the ladder in millimeters.
+ * - `Err(CommandError::Validation)` for less than two tracks, a frog number
+ * that is not positive or a negative length.
+ */
+async estimateTurnoutLadderLength(tracks: number, turnoutLength: Length, frogNumber: string, trackSpacing: Length) : Promise<Result<number, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("estimate_turnout_ladder_length", { tracks, turnoutLength, frogNumber, trackSpacing }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to retrieve the user settings.
+ * 
+ * Settings that have never been saved are returned with their default value.
+ * 
+ * Returns:
+ * - `Ok(AppSettings)` when retrieval succeeds.
+ * - `Err(CommandError)` when the settings cannot be loaded.
+ */
+async getSettings() : Promise<Result<AppSettings, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to save the user settings.
+ * 
+ * Returns:
+ * - `Ok(AppSettings)` with the settings as stored after the update.
+ * - `Err(CommandError)` when the settings cannot be saved.
+ */
+async updateSettings(settings: AppSettings) : Promise<Result<AppSettings, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_settings", { settings }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to preview the conversion of an amount between currencies.
+ * 
+ * `amount_minor` is expressed in the smallest unit of `from` (for example
+ * cents). The stored rate is used directly, inverted, or crossed through
+ * the base currency (EUR) when needed.
+ * 
+ * Returns:
+ * - `Ok(ConversionPreview)` with the converted amount, the applied rate and
+ * its timestamp.
+ * - `Err(CommandError::NotFound)` when no rate links the two currencies.
+ * - `Err(CommandError)` for any other failure.
+ */
+async convertAmount(amountMinor: bigint, from: Currency, to: Currency) : Promise<Result<ConversionPreview, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("convert_amount", { amountMinor, from, to }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to store the exchange rate from `from` to `to`.
+ * 
+ * Returns:
+ * - `Ok(ExchangeRate)` with the stored rate and its timestamp.
+ * - `Err(CommandError)` when the rate is invalid or cannot be saved.
+ */
+async updateExchangeRate(from: Currency, to: Currency, rate: string) : Promise<Result<ExchangeRate, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_exchange_rate", { from, to, rate }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Tauri command to list the recorded data-changing operations one page at
+ * a time, newest first.
+ * 
+ * Pass `cursor: null` for the first page and the returned `next_cursor`
+ * for the following ones, until it is `null`. `limit` is clamped to a
+ * sensible maximum.
+ * 
+ * Parameters:
+ * - `filter`: restricts the listing to an entity type, an entity id or an
+ * origin; `null` fields match every entry.
+ * 
+ * Returns:
+ * - `Ok(Page<AuditEntry>)` with the entries of the page.
+ * - `Err(CommandError::Validation)` when `cursor` is not a cursor returned
+ * by this command.
+ * - `Err(CommandError)` for any other failure.
+ */
+async getAuditLog(filter: AuditLogFilter, cursor: string | null, limit: number) : Promise<Result<Page<AuditEntry>, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_audit_log", { filter, cursor, limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAppVersion() : Promise<string> {
+    return await TAURI_INVOKE("get_app_version");
+},
+async getAppInfo() : Promise<Result<AppInfo, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_app_info") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Scan the database for corruption, foreign key violations and orphaned
+ * rows; with `repair` set, orphaned rows are removed as well.
+ */
+async checkDatabaseIntegrity(repair: boolean) : Promise<Result<IntegrityReport, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_database_integrity", { repair }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Start a maintenance job of `kind` in the background, returning its id;
+ * its status is then read with `list_jobs`.
+ */
+async startJob(kind: JobKind) : Promise<Result<string, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("start_job", { kind }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * List the maintenance jobs, the most recent first.
+ */
+async listJobs() : Promise<Job[]> {
+    return await TAURI_INVOKE("list_jobs");
+},
+/**
+ * Cancel the maintenance job with `id`; `false` when it had already
+ * finished.
+ */
+async cancelJob(id: string) : Promise<Result<boolean, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cancel_job", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Drop every cached railway model summary, so that they are read from the
+ * catalog again.
+ */
+async clearCache() : Promise<null> {
+    return await TAURI_INVOKE("clear_cache");
+},
+/**
+ * Fill the database with the demo catalog and collection. Refuses when the
+ * collection already has items, unless `force` is set.
+ */
+async loadDemoData(force: boolean) : Promise<Result<DemoDataSummary, CommandError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("load_demo_data", { force }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+}
+}
+
+/** user-defined events **/
+
+
+export const events = __makeEvents__<{
+summaryRecomputed: SummaryRecomputed
+}>({
+summaryRecomputed: "summary-recomputed"
+})
+
+/** user-defined constants **/
+
+
+
+/** user-defined types **/
+
+/**
+ * Something that happened to a collection item.
+ */
+export type ActivityEvent = { 
+/**
+ * What happened.
+ */
+kind: ActivityKind; 
+/**
+ * The collection item it happened to.
+ */
+collection_item_id: string; 
+/**
+ * The manufacturer, product code and description of the item's
+ * railway model, or `None` when it is missing from the catalog.
+ */
+railway_model: RailwayModelSummary | null; 
+/**
+ * When it happened (UTC).
+ */
+occurred_at: string }
+/**
+ * What happened to a collection item.
+ */
+export type ActivityKind = 
+/**
+ * The item was added to the collection.
+ */
+"ADDED" | 
+/**
+ * The item was last updated.
+ */
+"MODIFIED" | 
+/**
+ * The collector last opened the item.
+ */
+"VIEWED"
+/**
+ * Owned rolling stocks fitted with decoders programmed to the same DCC
+ * address.
+ */
+export type AddressConflict = { 
+/**
+ * The shared address.
+ */
+address: number; 
+/**
+ * The rolling stocks using the address, with their decoders; at least
+ * two.
+ */
+installations: DecoderInstallation[] }
+/**
+ * An item of an announcement batch.
+ */
+export type AnnouncedModel = { 
+/**
+ * The manufacturer's product code; it identifies the model on
+ * re-ingestion (ignoring case).
+ */
+product_code: string; 
+/**
+ * The description of the model.
+ */
+description: string; 
+/**
+ * The category of the model.
+ */
+category: Category; 
+/**
+ * The power method of the model.
+ */
+power_method: PowerMethod; 
+/**
+ * The scale of the model.
+ */
+scale: Scale; 
+/**
+ * The era of the model.
+ */
+epoch: string; 
+/**
+ * When the model is expected to be delivered, when announced, like
+ * `"2026"`, `"2026/05"` or `"2026/Q3"`.
+ */
+delivery_date: string | null; 
+/**
+ * The manufacturer's suggested retail price, when announced.
+ */
+msrp: MonetaryAmount | null }
+/**
+ * A list of new items announced by a manufacturer.
+ */
+export type AnnouncementBatch = { 
+/**
+ * The name of the manufacturer, as stored in the catalog (ignoring
+ * case).
+ */
+manufacturer: string; 
+/**
+ * The announced items.
+ */
+entries: AnnouncedModel[] }
+/**
+ * What ingesting an announcement batch did, for the import summary.
+ */
+export type AnnouncementIngest = { 
+/**
+ * Every entry of the batch, in order.
+ */
+entries: IngestedAnnouncement[]; 
+/**
+ * How many models were created.
+ */
+created: number; 
+/**
+ * How many known models were updated.
+ */
+updated: number; 
+/**
+ * How many known models were left unchanged.
+ */
+unchanged: number }
+/**
+ * Diagnostic information about the running application.
+ * 
+ * Returned by the `get_app_info` command so the UI can display where data is
+ * stored and which schema version is in use (useful for bug reports).
+ */
+export type AppInfo = { 
+/**
+ * The application version, as declared in the Tauri configuration.
+ */
+app_version: string; 
+/**
+ * The resolved path of the SQLite database file.
+ */
+db_path: string; 
+/**
+ * Where the database path was taken from (argument, environment,
+ * config file or default).
+ */
+db_path_source: DbPathSource; 
+/**
+ * The applied, pending and dirty database migrations.
+ */
+migration_status: MigrationStatus; 
+/**
+ * The directory where log files are written, when it can be resolved.
+ */
+log_dir: string | null; 
+/**
+ * The connection pool size, idle connections and acquire waits.
+ */
+pool: PoolMetrics; 
+/**
+ * The railway model cache size and its hits and misses.
+ */
+catalog_cache: CatalogCacheMetrics }
+/**
+ * User preferences for the application.
+ * 
+ * Settings not yet stored fall back to the values returned by
+ * `AppSettings::default()`.
+ */
+export type AppSettings = { 
+/**
+ * The currency used to display monetary amounts.
+ */
+preferred_currency: Currency; 
+/**
+ * The measure unit used to display lengths.
+ */
+preferred_measure_unit: MeasureUnit; 
+/**
+ * The scale pre-selected for new catalog entries.
+ */
+default_scale: Scale; 
+/**
+ * The format used to display dates.
+ */
+date_format: DateFormat }
+/**
+ * A recorded data-changing operation, to find out what changed and when.
+ */
+export type AuditEntry = { 
+/**
+ * Identifier of the entry; later entries have greater ids.
+ */
+id: bigint; 
+/**
+ * When the change was made (UTC).
+ */
+occurred_at: string; 
+/**
+ * What was done to the entity.
+ */
+operation: AuditOperation; 
+/**
+ * The kind of entity changed, for example `decoder` or `consist`.
+ */
+entity_type: string; 
+/**
+ * The identifier of the entity changed.
+ */
+entity_id: string; 
+/**
+ * A JSON object summarising what was written: the stored fields for a
+ * creation or an update, `null` for a deletion.
+ */
+changes: string; 
+/**
+ * Where the change came from.
+ */
+origin: AuditOrigin }
+/**
+ * Which audit entries to list; `None` fields match every entry.
+ */
+export type AuditLogFilter = { 
+/**
+ * Only entries about this kind of entity.
+ */
+entity_type: string | null; 
+/**
+ * Only entries about the entity with this identifier.
+ */
+entity_id: string | null; 
+/**
+ * Only entries with this origin.
+ */
+origin: AuditOrigin | null }
+/**
+ * What a data-changing operation did to an entity.
+ */
+export type AuditOperation = "create" | "update" | "delete"
+/**
+ * Where a data-changing operation came from.
+ */
+export type AuditOrigin = 
+/**
+ * A command invoked from the UI.
+ */
+"command" | 
+/**
+ * Loading the demo data.
+ */
+"import"
+/**
+ * Lifecycle availability status of a railway model.
+ * 
+ * The enum variants represent common product lifecycle states. When
+ * serialized via `serde` the variants use SCREAMING_SNAKE_CASE; likewise
+ * string parsing via `strum` expects SCREAMING_SNAKE_CASE but is
+ * case-insensitive.
+ */
+export type AvailabilityStatus = 
+/**
+ * The railway model is just announced and not yet available.
+ */
+"ANNOUNCED" | 
+/**
+ * The railway model is available for purchase.
+ */
+"AVAILABLE" | 
+/**
+ * The railway model production / release has been cancelled or delayed
+ * (not proceeding as previously announced).
+ */
+"CANCELLED" | 
+/**
+ * The railway model has been discontinued and is no longer produced.
+ */
+"DISCONTINUED"
+/**
+ * The construction type of rolling stock's body shell.
+ * 
+ * This enum describes the material / manufacturing technique used for the outer
+ * body shell of a model (for example, a plastic injection-moulded shell vs a
+ * metal die-cast shell). It is used in technical specifications to describe
+ * the build of the vehicle's exterior.
+ */
+export type BodyShellType = 
+/**
+ * Plastic body shell construction (typically injection moulded plastic).
+ */
+"PLASTIC" | 
+/**
+ * Metal die-cast body shell construction (heavier, metal cast components).
+ */
+"METAL_DIE_CAST"
+/**
+ * How a bulk update is run.
+ */
+export type BulkUpdateOptions = { 
+/**
+ * Count the items that would change without writing anything.
+ */
+dry_run: boolean; 
+/**
+ * The most items the update may change, overriding `DEFAULT_LIMIT`.
+ */
+limit: number | null }
+/**
+ * What a bulk update did, or would do for a dry run.
+ */
+export type BulkUpdateReport = { 
+/**
+ * How many items match the filter.
+ */
+matched: number; 
+/**
+ * How many of them were changed (or would be, for a dry run); items
+ * that already have the patched values are left untouched.
+ */
+changed: number; 
+/**
+ * The most items the update may change.
+ */
+limit: number; 
+/**
+ * Whether nothing was written.
+ */
+dry_run: boolean }
+/**
+ * A snapshot of the railway model cache.
+ */
+export type CatalogCacheMetrics = { 
+/**
+ * The maximum number of cached summaries.
+ */
+capacity: number; 
+/**
+ * The number of cached summaries.
+ */
+entries: number; 
+/**
+ * How many lookups were answered from the cache.
+ */
+hits: number; 
+/**
+ * How many lookups had to query the database.
+ */
+misses: number }
+/**
+ * The enumeration of the railway model categories.
+ */
+export type Category = 
+/**
+ * Independent traction units powered by steam, diesel, or electricity
+ * used to pull unpowered vehicles.
+ */
+"LOCOMOTIVES" | 
+/**
+ * Pre-configured groups of permanently or semi-permanently coupled
+ * vehicles, such as high-speed trains.
+ */
+"TRAIN_SETS" | 
+/**
+ * All-in-one entry-level packages typically including a train,
+ * track, and a power controller.
+ */
+"STARTER_SETS" | 
+/**
+ * Vehicles designed for the transport of physical goods,
+ * raw materials, or equipment.
+ */
+"FREIGHT_CARS" | 
+/**
+ * Vehicles designed for the transport of people, typically
+ * including seating, lighting, and climate control.
+ */
+"PASSENGER_CARS" | 
+/**
+ * Self-propelled train sets consisting of multiple carriages
+ * using electricity as their motive power.
+ */
+"ELECTRIC_MULTIPLE_UNITS" | 
+/**
+ * Lightweight, self-propelled vehicles (usually a single unit)
+ * designed for passenger service on branch lines.
+ */
+"RAILCARS"
+/**
+ * The construction type of a rolling stock's chassis.
+ * 
+ * This enum indicates the material or manufacturing technique used for the
+ * chassis (the structural frame and underbody) of a model. It is part of
+ * the technical specifications describing the build quality and expected
+ * properties of the model's underframe.
+ */
+export type ChassisType = 
+/**
+ * Plastic chassis construction (typically injection-moulded plastic).
+ */
+"PLASTIC" | 
+/**
+ * Metal die-cast chassis construction (heavier, metal cast chassis parts).
+ */
+"METAL_DIE_CAST"
+/**
+ * A vertical clearance checked against the minimum of a scale.
+ */
+export type ClearanceCheck = { 
+/**
+ * The clearance checked, in millimeters.
+ */
+clearance_mm: number; 
+/**
+ * The minimum clearance of the scale, in millimeters.
+ */
+minimum_mm: number; 
+/**
+ * Whether the clearance is at least the minimum.
+ */
+fits: boolean }
+/**
+ * Represents a user-owned collection of items.
+ * 
+ * A `Collection` contains identifying information, a few aggregated summary
+ * values and the list of `CollectionItem` entries that make up the
+ * collection. It is intentionally lightweight to keep IPC payloads small.
+ * 
+ * Default behaviour:
+ * - `Collection::default()` returns an empty collection with a generated id,
+ * the name "My Collection", a `CollectionSummary::default()` and no
+ * `total_value` (i.e. `None`), created and updated now. This mirrors
+ * previous code paths that returned a default when no database row existed.
+ */
+export type Collection = { 
+/**
+ * Unique identifier for the collection (typically a UUID stored as a string).
+ */
+id: string; 
+/**
+ * Display name for this collection.
+ */
+name: string; 
+/**
+ * Precomputed summary counts (e.g. total items, tracked vs untracked).
+ */
+summary: CollectionSummary; 
+/**
+ * Optional total monetary value of the collection. Use `MonetaryAmount`
+ * to preserve currency and decimal precision.
+ */
+total_value: MonetaryAmount | null; 
+/**
+ * The list of items contained in this collection.
+ */
+items: CollectionItem[]; 
+/**
+ * Problems found while loading the collection, one per stored row that
+ * could not be read (for example a purchase date in an unrecognized
+ * format). The affected items are still listed, without the bad data.
+ */
+load_warnings: string[]; 
+/**
+ * When the collection was created (UTC).
+ */
+created_at: string; 
+/**
+ * When the collection was last updated (UTC).
+ */
+updated_at: string }
+/**
+ * A single item within a user's collection.
+ * 
+ * A `CollectionItem` represents a reference to a catalog `RailwayModel` along
+ * with ownership-specific data such as the rolling stock instances owned by
+ * the collector and purchase information. It is intentionally a lightweight
+ * entity that is meaningful only inside the context of its parent
+ * `Collection` (the aggregate root).
+ */
+export type CollectionItem = { 
+/**
+ * Unique identifier for this collection item (e.g. UUID).
+ */
+id: string; 
+/**
+ * The short number of the item within its collection, for referring to
+ * it out loud; numbers follow the order items were added and are never
+ * reused after a deletion.
+ */
+item_number: number; 
+/**
+ * Link to the corresponding catalog `RailwayModel` this item represents.
+ * 
+ * This is a reference to the canonical model in the catalog; use this
+ * to look up full catalog details (manufacturer, product codes, etc.).
+ */
+railway_model_id: string; 
+/**
+ * The manufacturer, product code and description of the railway model,
+ * or `None` when it is missing from the catalog.
+ */
+railway_model: RailwayModelSummary | null; 
+/**
+ * Condition of the item as recorded by the owner (e.g. "mint", "used").
+ */
+conditions: string | null; 
+/**
+ * Free-form notes provided by the owner for this collection item.
+ */
+notes: string | null; 
+/**
+ * The specific rolling stock instances owned that correspond to this model.
+ */
+rolling_stocks: OwnedRollingStock[]; 
+/**
+ * The storage location the item is kept in (see `StorageLocation`), or
+ * `None` when it was not recorded.
+ */
+location_id: string | null; 
+/**
+ * What replacing the item would cost today, as entered by the collector
+ * for insurance purposes, or `None` when it was not entered.
+ */
+replacement_value: MonetaryAmount | null; 
+/**
+ * Whether the item is lent out (see `Loan`).
+ */
+is_on_loan: boolean; 
+/**
+ * Optional purchase information associated with this collection item.
+ */
+purchase_info: PurchaseInfo | null; 
+/**
+ * Why the stored purchase information could not be loaded, when it is
+ * present but invalid (for example an unknown purchase type). In that
+ * case `purchase_info` is `None`.
+ */
+purchase_info_error: string | null; 
+/**
+ * The discount on the railway model's suggested retail price of the
+ * price paid, as a percentage (see `CollectionItem::discount_percentage`).
+ */
+discount_percentage: string | null; 
+/**
+ * When the item was added to the collection (UTC), or `None` for items
+ * added before timestamps were recorded.
+ */
+created_at: string | null; 
+/**
+ * When the item was last updated (UTC), or `None` for items added
+ * before timestamps were recorded.
+ */
+updated_at: string | null }
+/**
+ * The collection items a bulk update applies to; every criterion given
+ * must match, and an empty filter matches every item.
+ */
+export type CollectionItemFilter = { 
+/**
+ * Only the items of this collection.
+ */
+collection_id: string | null; 
+/**
+ * Only the items whose railway model is from this manufacturer.
+ */
+manufacturer_id: string | null; 
+/**
+ * Only the items whose railway model is in this category.
+ */
+category: Category | null; 
+/**
+ * Only the items kept in this storage location (not in the locations
+ * nested in it).
+ */
+location_id: string | null; 
+/**
+ * Only the items with exactly this condition.
+ */
+conditions: string | null }
+/**
+ * The fields a bulk update sets; fields left `None` are not changed.
+ */
+export type CollectionItemPatch = { 
+/**
+ * The condition to record for the items (e.g. "mint", "used").
+ */
+conditions: string | null; 
+/**
+ * Where the items are now kept.
+ */
+location: LocationUpdate | null }
+/**
+ * The outcome of recomputing a collection's summary counters and total value.
+ * 
+ * Both the values stored before the recomputation and the freshly computed
+ * ones are reported, so the UI can show what changed (for example
+ * "locomotives: 12 → 13").
+ */
+export type CollectionRecomputeDiff = { 
+/**
+ * The recomputed collection.
+ */
+collection_id: string; 
+/**
+ * The summary counters stored before the recomputation.
+ */
+old_summary: CollectionSummary; 
+/**
+ * The summary counters computed from the collection items.
+ */
+new_summary: CollectionSummary; 
+/**
+ * The total value per currency, before and after the recomputation.
+ */
+total_values: TotalValueDiff[] }
+/**
+ * Monetary figures about a collection, computed from its items on demand.
+ * 
+ * Both figures follow `CollectionValuePolicy`: sold items are left out of
+ * the value and count in the realized gains instead. Collections priced in
+ * several currencies get one entry per currency, ordered by currency code.
+ */
+export type CollectionStatistics = { 
+/**
+ * The collection these figures are about.
+ */
+collection_id: string; 
+/**
+ * The value of the items still in the collection, per currency.
+ */
+total_values: CurrencyValue[]; 
+/**
+ * The gains realized by selling items, per currency.
+ */
+realized_gains: RealizedGain[]; 
+/**
+ * The average discount on the suggested retail price of the purchased
+ * and sold items, as a percentage (see `discount::average_discount`);
+ * `None` when no item has a price and an MSRP in the same currency.
+ */
+average_discount: string | null }
+/**
+ * A statistical summary of a model railway collection.
+ * 
+ * This struct provides a high-level overview of the total quantities
+ * of different types of rolling stock within a specific inventory or sub-collection.
+ */
+export type CollectionSummary = { 
+/**
+ * The total number of independent traction units (Steam, Diesel, Electric).
+ */
+locomotives_count: number; 
+/**
+ * The total number of individual passenger-carrying vehicles.
+ */
+passenger_cars_count: number; 
+/**
+ * The total number of individual goods-transporting vehicles.
+ */
+freight_cars_count: number; 
+/**
+ * The number of complete train sets (e.g., starter sets or fixed formations).
+ * 
+ * Note: Depending on implementation, the individual cars within these sets
+ * may or may not be included in the other specific counts.
+ */
+train_sets_count: number; 
+/**
+ * The number of self-propelled, typically single-unit passenger vehicles.
+ */
+railcars_count: number; 
+/**
+ * The number of self-propelled, multi-unit electric passenger formations.
+ */
+electric_multiple_units_count: number }
+/**
+ * Application-level error returned by command handlers in the core infrastructure.
+ * 
+ * Variants are simple wrappers around strings to keep boundaries between
+ * infrastructure and domain code straightforward. Prefer constructing
+ * `CommandError::DatabaseError` when an underlying DB call fails, and
+ * `CommandError::Unknown` for unexpected failures.
+ */
+export type CommandError = 
+/**
+ * Represents an error coming from the database layer.
+ * 
+ * The inner `String` should contain a concise, non-sensitive description
+ * of the underlying database failure.
+ */
+{ DatabaseError: string } | 
+/**
+ * The requested data does not exist (for example a missing exchange rate).
+ * 
+ * The inner `String` describes what was looked up, so the UI can tell
+ * the user what is missing.
+ */
+{ NotFound: string } | 
+/**
+ * An argument was rejected (for example a malformed page cursor).
+ * 
+ * The inner `String` says what is wrong with it.
+ */
+{ Validation: string } | 
+/**
+ * A field of an argument was rejected (for example a preorder deposit
+ * larger than its total price).
+ * 
+ * `field` names the offending field, so the UI can show `message` next
+ * to it.
+ */
+{ InvalidField: { field: string; message: string } } | 
+/**
+ * A catch-all for unexpected errors that don't map to a specific variant.
+ * 
+ * The inner `String` can include a short debug message suitable for
+ * logging; avoid placing secrets here.
+ */
+{ Unknown: string }
+/**
+ * A field lined up by the comparison.
+ */
+export type ComparedField = 
+/**
+ * The railway company.
+ */
+"RAILWAY" | 
+/**
+ * The era of the railway model.
+ */
+"EPOCH" | 
+/**
+ * The livery description.
+ */
+"LIVERY" | 
+/**
+ * The class name of a locomotive, the type name of anything else.
+ */
+"TYPE_NAME" | 
+/**
+ * The locomotive, car, multiple unit or railcar type.
+ */
+"TYPE" | 
+/**
+ * The identification marking.
+ */
+"ROAD_NUMBER" | 
+/**
+ * The prototype series.
+ */
+"SERIES" | 
+/**
+ * The depot.
+ */
+"DEPOT" | 
+/**
+ * The travel classes of a passenger car.
+ */
+"SERVICE_LEVEL" | 
+/**
+ * The length over buffers.
+ */
+"LENGTH" | 
+/**
+ * The minimum drivable radius.
+ */
+"MINIMUM_RADIUS" | 
+/**
+ * The coupling socket and features.
+ */
+"COUPLING" | 
+/**
+ * The DCC decoder interface.
+ */
+"DCC_INTERFACE" | 
+/**
+ * The control method.
+ */
+"CONTROL" | 
+/**
+ * Whether it has no motor.
+ */
+"DUMMY" | 
+/**
+ * Whether it has lights.
+ */
+"LIGHTS" | 
+/**
+ * Whether it has interior lights.
+ */
+"INTERIOR_LIGHTS"
+/**
+ * The side by side comparison of two rolling stocks.
+ */
+export type ComparisonReport = { 
+/**
+ * The first rolling stock.
+ */
+left_id: string; 
+/**
+ * The second rolling stock.
+ */
+right_id: string; 
+/**
+ * The category of both rolling stocks.
+ */
+category: RollingStockCategory; 
+/**
+ * The fields of the category, in display order.
+ */
+fields: FieldComparison[] }
+/**
+ * The fields of a consist the collector enters, to create or update one.
+ */
+export type ConsistDetails = { 
+/**
+ * The name of the train.
+ */
+name: string; 
+/**
+ * The owned rolling stocks making up the train, from head to tail.
+ */
+owned_rolling_stock_ids: string[] }
+/**
+ * A consist with the outcome of its checks, as shown by the planner.
+ */
+export type ConsistPlan = { 
+/**
+ * Unique identifier for the consist.
+ */
+id: string; 
+/**
+ * The name of the train.
+ */
+name: string; 
+/**
+ * The owned rolling stocks making up the train, from head to tail.
+ */
+owned_rolling_stock_ids: string[]; 
+/**
+ * The length of the train in millimeters, without the vehicles whose
+ * length is unknown.
+ */
+total_length_mm: number; 
+/**
+ * Whether the train fits on the longest siding, or `None` when no
+ * siding length was given.
+ */
+fits: boolean | null; 
+/**
+ * What makes the checks unreliable or the train unfit for the layout;
+ * empty when nothing.
+ */
+warnings: ConsistWarning[] }
+/**
+ * A problem found while checking a consist.
+ */
+export type ConsistWarning = 
+/**
+ * The catalog has no length over buffers for the vehicle, so the train
+ * is longer than its total length.
+ */
+{ type: "missing_length"; owned_rolling_stock_id: string } | 
+/**
+ * The vehicle needs wider curves than the tightest ones of the layout.
+ */
+{ type: "radius_too_tight"; owned_rolling_stock_id: string; minimum_radius_mm: number; layout_radius_mm: number }
+/**
+ * The outcome of checking the consistency of a collection.
+ * 
+ * The inconsistencies describe the collection as it was before any repair.
+ */
+export type ConsistencyReport = { 
+/**
+ * The collection that was checked.
+ */
+collection_id: string; 
+/**
+ * The inconsistencies found, grouped by kind.
+ */
+inconsistencies: Inconsistency[]; 
+/**
+ * Whether the safe repairs were applied.
+ */
+repaired: boolean }
+/**
+ * The control method for this railway model.
+ * 
+ * The `Control` enum captures whether a model is DCC-ready, has a decoder
+ * fitted, has a sound-equipped decoder, or has no DCC support at all.
+ * 
+ * Variants:
+ * - `DccReady`: The model is prepared for a DCC decoder (e.g. a standard
+ * decoder plug is present) but no decoder is installed.
+ * - `DccFitted`: A DCC decoder has been installed.
+ * - `DccSound`: A DCC decoder with a sound module is installed.
+ * - `NoDcc`: The model does not support DCC (no standard interface present);
+ * installation may require model-specific wiring or a hardwired decoder.
+ */
+export type Control = 
+/**
+ * The model can be fitted with a dcc decoder.
+ */
+"DCC_READY" | 
+/**
+ * The model has a dcc decoder installed.
+ */
+"DCC_FITTED" | 
+/**
+ * The model has a dcc decoder installed with the sound module.
+ */
+"DCC_SOUND" | 
+/**
+ * The model has no dcc support (like no standard decoder plug)
+ */
+"NO_DCC"
+/**
+ * The result of converting an amount, with the rate that was applied.
+ */
+export type ConversionPreview = { 
+/**
+ * The converted amount, in the target currency.
+ */
+amount: MonetaryAmount; 
+/**
+ * How many units of the target currency one unit of the source
+ * currency is worth.
+ */
+rate: string; 
+/**
+ * When the applied rate was last updated; for cross rates this is the
+ * older of the two rates involved. `None` when both currencies are the
+ * same.
+ */
+rate_updated_at: string | null }
+/**
+ * A single cost in the breakdown.
+ */
+export type CostEntry = { 
+/**
+ * Where the cost comes from.
+ */
+kind: CostKind; 
+/**
+ * The identifier of the purchase info, decoder or modification.
+ */
+id: string; 
+/**
+ * The seller, the decoder manufacturer and model, or what the
+ * modification was, when known.
+ */
+description: string | null; 
+/**
+ * What it cost.
+ */
+cost: MonetaryAmount }
+/**
+ * Where a cost of ownership comes from.
+ */
+export type CostKind = 
+/**
+ * The price paid for the item, net of its adjustments.
+ */
+"PURCHASE" | 
+/**
+ * A decoder installed in one of its rolling stocks.
+ */
+"DECODER" | 
+/**
+ * A modification made to one of its rolling stocks.
+ */
+"MODIFICATION"
+export type CouplingSocket = "NONE" | 
+/**
+ * Receptacle for Replaceable Coupling Heads in Scales TT and N
+ */
+"NEM_355" | 
+/**
+ * Coupler Head for Scale N
+ */
+"NEM_356" | 
+/**
+ * Coupler Head for Scale N
+ */
+"NEM_357" | 
+/**
+ * Coupler Head for Scale TT
+ */
+"NEM_359" | 
+/**
+ * Standard Coupling for Scale H0
+ */
+"NEM_360" | 
+/**
+ * NEM shaft 362 with close coupling mechanism
+ */
+"NEM_362" | 
+/**
+ * Coupler Head for Scale 0
+ */
+"NEM_365"
+/**
+ * Currency codes supported by the application.
+ * 
+ * The enum uses a small, explicit set of currencies for now. Use
+ * `Currency::from_code` to obtain a `Currency` value from an ISO-style
+ * currency code (case-insensitive).
+ */
+export type Currency = 
+/**
+ * Euro
+ */
+"EUR" | 
+/**
+ * United States Dollar
+ */
+"USD" | 
+/**
+ * Great Britain Pound
+ */
+"GBP" | 
+/**
+ * Japanese Yen
+ */
+"JPY"
+/**
+ * An amount in a single currency.
+ */
+export type CurrencyValue = { 
+/**
+ * The currency of the amount.
+ */
+currency: Currency; 
+/**
+ * The amount in the smallest currency unit.
+ */
+amount: bigint }
+/**
+ * The date format used to display dates in the user interface.
+ * 
+ * Dates are always stored as ISO `YYYY-MM-DD`; this preference only affects
+ * how they are rendered.
+ */
+export type DateFormat = 
+/**
+ * ISO 8601 format (`YYYY-MM-DD`)
+ */
+"Iso" | 
+/**
+ * Day first (`DD/MM/YYYY`)
+ */
+"DayMonthYear" | 
+/**
+ * Month first (`MM/DD/YYYY`)
+ */
+"MonthDayYear"
+/**
+ * Where the database location was taken from, in priority order.
+ */
+export type DbPathSource = 
+/**
+ * Passed explicitly to `init_db_pool` (the `--db-path` argument).
+ */
+"Explicit" | 
+/**
+ * Read from the `RUSTY_SHED_DB_PATH` environment variable.
+ */
+"Environment" | 
+/**
+ * Read from the `db_path` file in the XDG config directory.
+ */
+"ConfigFile" | 
+/**
+ * The XDG data directory default.
+ */
+"Default"
+/**
+ * The NMRA and NEM Connectors for digital control (DCC)
+ * 
+ * # Description
+ * The NMRA and NEM adopted standard mechanical and electrical interfaces to connect Multifunction
+ * Decoders to a locomotive's electrical system. These plugs and sockets make it simpler to install
+ * a decoder into a suitably equipped locomotive.
+ * 
+ * In many cases a blanking plug must be removed before installing the decoder. If a locomotive
+ * is not DCC-Ready it will lack an interface and must use a Hardwired Decoder or a drop-in
+ * replacement DCC control board (if available) for that specific model.
+ */
+export type DccInterface = 
+/**
+ * 6 Pin standard mechanical and electrical interfaces (NMRA Small)
+ */
+"NEM_651" | 
+/**
+ * 8 Pin standard mechanical and electrical interfaces (NMRA Medium)
+ */
+"NEM_652" | 
+/**
+ * 4 Pin standard mechanical and electrical interfaces (NMRA Large)
+ */
+"NEM_654" | 
+/**
+ * The PluX8 connector consists of two rows of 4 pins.
+ */
+"PLUX_8" | "PLUX_12" | 
+/**
+ * The PluX16 connector consists of two rows of 8 pins.
+ */
+"PLUX_16" | 
+/**
+ * The PluX22 connector consists of two rows of 11 pins.
+ */
+"PLUX_22" | 
+/**
+ * standard connector for extremely tight applications, such as TT and N scale locomotives (NEM 662)
+ */
+"NEXT_18" | "NEXT_18_S" | 
+/**
+ * 21MTC Connector interface is a standard adopted by both the NMRA and NEM (NEM 660).
+ * Its name comes from 21 pin Marklin/Trix Connector, developed by Marklin and ESU.
+ */
+"MTC_21"
+/**
+ * A DCC decoder owned by the collector.
+ * 
+ * Decoders are tracked on their own, since they move between locomotives:
+ * an owned rolling stock references the decoder installed in it (see
+ * `OwnedRollingStock::decoder`), and a decoder is installed in at most one
+ * rolling stock at a time.
+ */
+export type Decoder = { 
+/**
+ * Unique identifier for this decoder (e.g. UUID in the DB).
+ */
+id: string; 
+/**
+ * The decoder manufacturer, for example "ESU" or "Zimo".
+ */
+manufacturer: string; 
+/**
+ * The manufacturer's model name or product code, for example
+ * "LokSound 5".
+ */
+model: string; 
+/**
+ * The connector of the decoder, or `None` for a hardwired decoder.
+ */
+dcc_interface: DccInterface | null; 
+/**
+ * The DCC address the decoder is programmed to, between
+ * `Decoder::MIN_ADDRESS` and `Decoder::MAX_ADDRESS`.
+ */
+address: number; 
+/**
+ * The sound project loaded on a sound decoder.
+ */
+sound_project: string | null; 
+/**
+ * The firmware version the decoder runs.
+ */
+firmware: string | null; 
+/**
+ * What was paid for the decoder, when known.
+ */
+purchase_price: MonetaryAmount | null }
+/**
+ * The fields of a decoder the collector enters, to create or update one.
+ */
+export type DecoderDetails = { 
+/**
+ * The decoder manufacturer.
+ */
+manufacturer: string; 
+/**
+ * The manufacturer's model name or product code.
+ */
+model: string; 
+/**
+ * The connector of the decoder, or `None` for a hardwired decoder.
+ */
+dcc_interface: DccInterface | null; 
+/**
+ * The DCC address the decoder is programmed to.
+ */
+address: number; 
+/**
+ * The sound project loaded on a sound decoder.
+ */
+sound_project: string | null; 
+/**
+ * The firmware version the decoder runs.
+ */
+firmware: string | null; 
+/**
+ * What was paid for the decoder, when known.
+ */
+purchase_price: MonetaryAmount | null }
+/**
+ * A decoder installed in an owned rolling stock.
+ */
+export type DecoderInstallation = { 
+/**
+ * The owned rolling stock the decoder is installed in.
+ */
+owned_rolling_stock_id: string; 
+/**
+ * The installed decoder.
+ */
+decoder_id: string }
+/**
+ * A problem with a decoder operation that did not prevent it.
+ */
+export type DecoderWarning = 
+/**
+ * The decoder connector differs from the DCC interface stored for the
+ * rolling stock it was installed in.
+ */
+{ type: "interface_mismatch"; decoder: DccInterface; rolling_stock: DccInterface } | 
+/**
+ * Other decoders are programmed to the same address; rolling stocks
+ * fitted with them respond to the same commands on the layout.
+ */
+{ type: "duplicate_address"; address: number; decoder_ids: string[] }
+/**
+ * What `load_demo_data` inserted.
+ */
+export type DemoDataSummary = { 
+/**
+ * Manufacturers created (existing ones with the same name are reused).
+ */
+manufacturers: number; 
+/**
+ * Railway companies created (existing ones with the same name are reused).
+ */
+railway_companies: number; 
+/**
+ * Railway models created.
+ */
+railway_models: number; 
+/**
+ * Rolling stocks created.
+ */
+rolling_stocks: number; 
+/**
+ * Items added to the default collection.
+ */
+collection_items: number; 
+/**
+ * Rolling stocks at odds with the standards of their scale; they are
+ * inserted all the same.
+ */
+standards_warnings: StandardsWarning[]; 
+/**
+ * Rolling stocks whose railway company was not operating in the epoch
+ * of their model; they are inserted all the same.
+ */
+validation_warnings: ValidationWarning[] }
+/**
+ * A stored amount together with its value in the display currency.
+ */
+export type DisplayAmount = { 
+/**
+ * The amount as stored, in the currency it was entered in.
+ */
+stored: MonetaryAmount; 
+/**
+ * The amount in the display currency, or `None` when no exchange rate
+ * links the two currencies.
+ */
+display: MonetaryAmount | null; 
+/**
+ * The rate applied to the stored amount, `None` when not converted.
+ */
+rate: string | null }
+/**
+ * The amounts of every item of a collection, for display in one currency.
+ */
+export type DisplayCurrencyListing = { 
+/**
+ * The currency the amounts are displayed in.
+ */
+display_currency: Currency; 
+/**
+ * The items in the order they were added.
+ */
+items: ItemDisplayAmounts[]; 
+/**
+ * The stored currencies with no exchange rate into the display currency;
+ * their amounts have no display value.
+ */
+missing_rates: Currency[] }
+/**
+ * Two railway models from the same manufacturer and scale whose
+ * descriptions are so alike that they are probably the same model, for
+ * example imported from two sources.
+ */
+export type DuplicateCandidate = { 
+/**
+ * One of the two models.
+ */
+model: RailwayModelSummary; 
+/**
+ * The model that is probably a duplicate of `model`.
+ */
+duplicate: RailwayModelSummary; 
+/**
+ * How alike the two descriptions are (see `description_similarity`),
+ * from 0 (nothing in common) to 1 (the same once normalized).
+ */
+similarity: number }
+/**
+ * What to duplicate along with a collection item.
+ */
+export type DuplicateItemOptions = { 
+/**
+ * Whether the duplicate owns the same catalog rolling stocks as the
+ * original, each with a fresh owned rolling stock id.
+ */
+clone_rolling_stocks: boolean; 
+/**
+ * Whether to duplicate the item even though the collection already has
+ * an item for its railway model. When `false` the duplicate is refused
+ * with `DuplicateItemError::AlreadyInCollection`, so the collector can
+ * confirm first.
+ */
+allow_duplicates: boolean }
+/**
+ * The cars that form a complete EMU set can usually be separated by function into four types:
+ * power car, motor car, driving car, and trailer car.
+ * 
+ * Each car can have more than one function, such as a motor-driving car or power-driving car.
+ */
+export type ElectricMultipleUnitType = 
+/**
+ * Driving cars are similar to a cab car, containing a driver's cab for controlling the train.
+ * An EMU will usually have two driving cars at its outer ends.
+ */
+"DRIVING_CAR" | 
+/**
+ * High-speed rail is a type of rail system that runs significantly faster than traditional
+ * rail, using an integrated system of specialised rolling stock and dedicated tracks.
+ */
+"HIGH_SPEED_TRAIN" | 
+/**
+ * Motor cars carry the traction motors to move the train, and are often combined with the
+ * power car to avoid high-voltage inter-car connections.
+ */
+"MOTOR_CAR" | 
+/**
+ * A power car carries the necessary equipment to draw power from the electrified
+ * infrastructure, such as pickup shoes for third rail systems and pantographs for
+ * overhead systems, and transformers.
+ */
+"POWER_CAR" | 
+/**
+ * Trailer cars are any cars (sometimes semi-permanently coupled) that carry little or no
+ * traction or power related equipment, and are similar to passenger cars in a
+ * locomotive-hauled train.
+ */
+"TRAILER_CAR" | 
+/**
+ * A trainset is working as whole unit
+ */
+"TRAIN_SET"
+/**
+ * An enum variant as shown in the catalog forms.
+ */
+export type EnumInfo<T> = { 
+/**
+ * The variant, as stored and sent back to the backend.
+ */
+value: T; 
+/**
+ * The short, human-cased label.
+ */
+label: string; 
+/**
+ * A sentence describing the variant.
+ */
+description: string }
+/**
+ * A stored conversion rate between two currencies.
+ * 
+ * One unit of `from` is worth `rate` units of `to` (major units, e.g. euros
+ * rather than cents).
+ */
+export type ExchangeRate = { 
+/**
+ * The currency being converted from.
+ */
+from: Currency; 
+/**
+ * The currency being converted to.
+ */
+to: Currency; 
+/**
+ * How many units of `to` one unit of `from` is worth.
+ */
+rate: string; 
+/**
+ * When the rate was last updated.
+ */
+updated_at: string }
+/**
+ * Represents the availability or relevance of a specific model feature.
+ * 
+ * This is often used for technical specifications where a feature might
+ * exist, be intentionally absent, or simply not be relevant to that
+ * specific class of locomotive or rolling stock.
+ */
+export type FeatureFlag = 
+/**
+ * The feature is present and functional on the model.
+ */
+"YES" | 
+/**
+ * The feature is not present on the model, though it might be
+ * expected or available on similar models.
+ */
+"NO" | 
+/**
+ * The feature is not relevant for this type of equipment.
+ * 
+ * For example, a "Pantograph Type" flag would be `NotApplicable`
+ * for a Steam Locomotive.
+ */
+"NOT_APPLICABLE"
+/**
+ * A field of both rolling stocks.
+ */
+export type FieldComparison = { 
+/**
+ * The field compared.
+ */
+field: ComparedField; 
+/**
+ * The value of the first rolling stock, as shown.
+ */
+left: string | null; 
+/**
+ * The value of the second rolling stock, as shown.
+ */
+right: string | null; 
+/**
+ * How the two values compare.
+ */
+outcome: FieldOutcome }
+/**
+ * How the two values of a field compare.
+ */
+export type FieldOutcome = 
+/**
+ * Both rolling stocks have the same value.
+ */
+"EQUAL" | 
+/**
+ * Both rolling stocks have a value, and they differ.
+ */
+"DIFFERENT" | 
+/**
+ * At least one of the rolling stocks has no value.
+ */
+"MISSING"
+/**
+ * Foreign key violations reported by `PRAGMA foreign_key_check` for a table.
+ */
+export type ForeignKeyViolations = { 
+/**
+ * The table holding the violating rows.
+ */
+table: string; 
+/**
+ * The table the violated foreign key refers to.
+ */
+parent_table: string; 
+/**
+ * The number of violating rows.
+ */
+count: number }
+/**
+ * Represents the various types of freight rolling stock used in rail transport.
+ * 
+ * These classifications are based on the physical design and the specific
+ * cargo requirements, such as climate control, weather protection, or
+ * specialized loading mechanisms.
+ */
+export type FreightCarType = 
+/**
+ * Specialized wagons for transporting motor vehicles, often multi-deck.
+ */
+"AUTO_TRANSPORT_CARS" | 
+/**
+ * A wagon equipped with a handbrake or a cabin for a brakeman,
+ * historically used to assist in braking the train.
+ */
+"BRAKE_WAGON" | 
+/**
+ * Flat or skeleton-framed wagons designed specifically to carry
+ * standardized shipping containers (ISO containers).
+ */
+"CONTAINER_CARS" | 
+/**
+ * Fully enclosed wagons used for goods that must be protected
+ * from weather and theft.
+ */
+"COVERED_FREIGHT_CARS" | 
+/**
+ * Wagons with a lowered center section designed to carry tall
+ * loads like intermodal trailers or containers within height clearances.
+ */
+"DEEP_WELL_FLAT_CARS" | 
+/**
+ * Open-top wagons with a mechanism to tilt the body to unload
+ * bulk materials like sand or gravel.
+ */
+"DUMP_CARS" | 
+/**
+ * Open-topped rail vehicles used for transporting loose bulk
+ * materials such as coal, ore, or scrap metal.
+ */
+"GONDOLA" | 
+/**
+ * Heavy-duty flat wagons designed for extremely heavy or
+ * oversized loads, often featuring many axles.
+ */
+"HEAVY_GOODS_WAGONS" | 
+/**
+ * Wagons with a roof that is hinged on one side, allowing
+ * for top-loading of weather-sensitive bulk goods.
+ */
+"HINGED_COVER_WAGONS" | 
+/**
+ * Wagons with a floor that slopes toward one or more discharge
+ * doors, used for the rapid unloading of bulk materials.
+ */
+"HOPPER_WAGON" | 
+/**
+ * Insulated wagons equipped with cooling systems for
+ * transporting perishable goods.
+ */
+"REFRIGERATOR_CARS" | 
+/**
+ * Specialized wagons for transporting pressurized or
+ * non-pressurized powders and granulated materials in silos.
+ */
+"SILO_CONTAINER_CARS" | 
+/**
+ * Wagons with a flexible tarpaulin cover that slides open
+ * for easy side-loading of palletized goods.
+ */
+"SLIDE_TARPAULIN_WAGON" | 
+/**
+ * Boxcars with large sliding doors that make up the entire
+ * side of the wagon, allowing for forklift access.
+ */
+"SLIDING_WALL_BOXCARS" | 
+/**
+ * Wagons designed for niche cargo that does not fit
+ * into standard classifications.
+ */
+"SPECIAL_TRANSPORT" | 
+/**
+ * Flat wagons equipped with vertical posts (stakes) along
+ * the sides to secure long loads like timber or pipes.
+ */
+"STAKE_WAGONS" | 
+/**
+ * Wagons with a roof that swings to the side to provide
+ * a wide opening for top-loading bulk cargo.
+ */
+"SWING_ROOF_WAGON" | 
+/**
+ * Enclosed pressurized or non-pressurized vessels for
+ * transporting liquids, gases, or chemicals.
+ */
+"TANK_CARS" | 
+/**
+ * Wagons with several overlapping "hoods" that slide
+ * over each other, used for protecting steel coils or heavy machinery.
+ */
+"TELESCOPE_HOOD_WAGONS"
+/**
+ * Something that no longer adds up in a collection.
+ */
+export type Inconsistency = { 
+/**
+ * What is wrong.
+ */
+kind: InconsistencyKind; 
+/**
+ * The id of the row at fault: the owned rolling stock, the collection
+ * or the purchase info.
+ */
+subject_id: string; 
+/**
+ * A description of the problem, for the collector.
+ */
+details: string; 
+/**
+ * What repairs it.
+ */
+repair: RepairAction }
+/**
+ * The kind of an inconsistency.
+ */
+export type InconsistencyKind = 
+/**
+ * An owned rolling stock refers to a rolling stock missing from the
+ * catalog.
+ */
+"MISSING_ROLLING_STOCK" | 
+/**
+ * A summary counter of the collection does not match the categories of
+ * the railway models of its items.
+ */
+"SUMMARY_COUNTER_MISMATCH" | 
+/**
+ * A purchase info holds a currency the application does not support.
+ */
+"UNSUPPORTED_CURRENCY"
+/**
+ * What ingesting an announced item did.
+ */
+export type IngestOutcome = 
+/**
+ * A new announced model was added to the catalog.
+ */
+"CREATED" | 
+/**
+ * The delivery date or the suggested retail price of a known model
+ * changed.
+ */
+"UPDATED" | 
+/**
+ * The model was already in the catalog as announced.
+ */
+"UNCHANGED"
+/**
+ * An announced item after ingestion.
+ */
+export type IngestedAnnouncement = { 
+/**
+ * The product code of the item.
+ */
+product_code: string; 
+/**
+ * The railway model the item created or matched.
+ */
+railway_model_id: string; 
+/**
+ * What ingesting it did.
+ */
+outcome: IngestOutcome }
+/**
+ * The items of a collection listed for its insurer, with their values.
+ * 
+ * Sold items are no longer insured and are left out. Totals are per
+ * currency, ordered by currency code, and leave out the items whose value is
+ * unknown.
+ */
+export type InsuranceReport = { 
+/**
+ * The collection the report is about.
+ */
+collection_id: string; 
+/**
+ * How the items were valued.
+ */
+valuation: ValuationMode; 
+/**
+ * The insured items, ordered by manufacturer and product code.
+ */
+lines: InsuranceReportLine[]; 
+/**
+ * The sum of the insured values, per currency.
+ */
+totals: CurrencyValue[] }
+/**
+ * An item listed in an `InsuranceReport`.
+ */
+export type InsuranceReportLine = { 
+/**
+ * The collection item.
+ */
+collection_item_id: string; 
+/**
+ * The manufacturer name of its railway model.
+ */
+manufacturer: string; 
+/**
+ * The manufacturer-assigned product code of its railway model.
+ */
+product_code: string; 
+/**
+ * The description of its railway model.
+ */
+description: string; 
+/**
+ * The road numbers of its rolling stocks, when the catalog has them.
+ */
+road_numbers: string[]; 
+/**
+ * When the item was bought, when recorded.
+ */
+purchase_date: string | null; 
+/**
+ * What the item was bought for, when known.
+ */
+purchase_price: MonetaryAmount | null; 
+/**
+ * What replacing the item would cost, when entered.
+ */
+replacement_value: MonetaryAmount | null; 
+/**
+ * The value insured under the report valuation (see
+ * `ValuationMode::insured_value`), or `None` when it is unknown.
+ */
+insured_value: MonetaryAmount | null }
+/**
+ * The outcome of `integrity_report`.
+ * 
+ * The findings describe the database as it was before any repair.
+ */
+export type IntegrityReport = { 
+/**
+ * Problems reported by `PRAGMA integrity_check` (empty when it reports "ok").
+ */
+integrity_errors: string[]; 
+/**
+ * Violations reported by `PRAGMA foreign_key_check`, grouped by table.
+ */
+foreign_key_violations: ForeignKeyViolations[]; 
+/**
+ * Orphaned rows, one entry per relationship that has any.
+ */
+orphans: OrphanedRows[]; 
+/**
+ * Whether the orphans were repaired.
+ */
+repaired: boolean }
+/**
+ * What owning a collection item has cost, for its detail screen.
+ */
+export type ItemCostBreakdown = { 
+/**
+ * The collection item the costs are for.
+ */
+collection_item_id: string; 
+/**
+ * Every known cost: the purchase first, then the decoders and the
+ * modifications.
+ */
+entries: CostEntry[]; 
+/**
+ * How many decoders and modifications have no known price and are
+ * left out of the totals.
+ */
+unpriced: number; 
+/**
+ * The costs added up per currency, in the order the currencies first
+ * appear in `entries`.
+ */
+totals: MonetaryAmount[] }
+/**
+ * The amounts of a collection item, in their stored and display currencies.
+ */
+export type ItemDisplayAmounts = { 
+collection_item_id: string; 
+/**
+ * What the item cost (see `StoredItemAmounts::price`).
+ */
+price: DisplayAmount | null; 
+/**
+ * What replacing the item would cost today.
+ */
+replacement_value: DisplayAmount | null }
+/**
+ * A job started by the `JobRunner`.
+ */
+export type Job = { 
+/**
+ * The job identifier.
+ */
+id: string; 
+/**
+ * What the job does.
+ */
+kind: JobKind; 
+/**
+ * Where the job is in its lifecycle.
+ */
+status: JobStatus; 
+/**
+ * Why the job failed, for `JobStatus::Failed`.
+ */
+error: string | null; 
+/**
+ * The last progress reported, if any.
+ */
+progress: JobProgress | null; 
+/**
+ * When the job was started.
+ */
+queued_at: string; 
+/**
+ * When the job finished, if it did.
+ */
+finished_at: string | null }
+/**
+ * What a job does; at most one job of each kind runs at a time.
+ */
+export type JobKind = 
+/**
+ * Scan the database for corruption and orphaned rows, without repairing
+ * them.
+ */
+"INTEGRITY_CHECK" | 
+/**
+ * Record today's value of the default collection.
+ */
+"VALUE_SNAPSHOT"
+/**
+ * How far a running job got, as reported by the job itself.
+ */
+export type JobProgress = { 
+/**
+ * The units of work done so far.
+ */
+done: number; 
+/**
+ * The units of work in total.
+ */
+total: number }
+/**
+ * Where a job is in its lifecycle.
+ */
+export type JobStatus = 
+/**
+ * The job was started but did not run yet.
+ */
+"QUEUED" | 
+/**
+ * The job is running.
+ */
+"RUNNING" | 
+/**
+ * The job completed.
+ */
+"SUCCEEDED" | 
+/**
+ * The job stopped with an error (see `Job::error`).
+ */
+"FAILED" | 
+/**
+ * The job was cancelled before it completed.
+ */
+"CANCELLED"
+/**
+ * A physical length value paired with its measure unit.
+ * 
+ * The `Length` enum is the canonical representation for lengths in the
+ * domain. Each variant stores a `Decimal` quantity for a specific unit.
+ * 
+ * Invariants:
+ * - Quantities must be non-negative. Use `Length::try_new` to validate
+ * input without panicking.
+ * - Equality and ordering compare both values converted to millimeters.
+ * Lengths less than `LENGTH_TOLERANCE_MM` apart are equal, which absorbs
+ * the rounding of the conversion factors: `1 in == 25.4 mm` holds either
+ * way round. The tolerance makes equality non-transitive for values
+ * right at its edge.
+ */
+export type Length = 
+/**
+ * A length expressed in inches.
+ */
+{ Inches: string } | 
+/**
+ * A length expressed in kilometers.
+ */
+{ Kilometers: string } | 
+/**
+ * A length expressed in meters.
+ */
+{ Meters: string } | 
+/**
+ * A length expressed in miles.
+ */
+{ Miles: string } | 
+/**
+ * A length expressed in millimeters.
+ */
+{ Millimeters: string }
+/**
+ * A livery of a railway company.
+ */
+export type Livery = { 
+/**
+ * Unique identifier for this livery (e.g. UUID in the DB).
+ */
+id: string; 
+/**
+ * The railway company that applied this livery.
+ */
+railway_company_id: string; 
+/**
+ * The canonical name of the livery, for example "XMPR" or
+ * "Castano/Isabella".
+ */
+name: string; 
+/**
+ * A longer description of the colours and markings.
+ */
+description: string | null; 
+/**
+ * When the livery was applied.
+ */
+period_of_use: PeriodOfUse }
+/**
+ * The fields of a livery the collector enters, to create or update one.
+ */
+export type LiveryDetails = { 
+/**
+ * The railway company that applied the livery.
+ */
+railway_company_id: string; 
+/**
+ * The canonical name of the livery.
+ */
+name: string; 
+/**
+ * A longer description of the colours and markings.
+ */
+description: string | null; 
+/**
+ * When the livery was applied.
+ */
+period_of_use: PeriodOfUse }
+/**
+ * A change of the storage location of collection items.
+ */
+export type LocationUpdate = 
+/**
+ * Move the items to the storage location with `location_id`.
+ */
+{ type: "move_to"; location_id: string } | 
+/**
+ * Forget where the items are kept.
+ */
+{ type: "clear" }
+/**
+ * Specifies the primary motive power source for a locomotive.
+ * 
+ * This classification determines the operational requirements, such as
+ * fueling infrastructure or overhead electrification.
+ */
+export type LocomotiveType = 
+/**
+ * Locomotives powered by an external combustion engine, typically
+ * using a boiler to produce steam from coal, wood, or oil.
+ */
+"STEAM_LOCOMOTIVE" | 
+/**
+ * Locomotives powered by an internal combustion engine, usually
+ * driving an electric generator or a hydraulic transmission.
+ */
+"DIESEL_LOCOMOTIVE" | 
+/**
+ * Locomotives that draw power from external sources, such as
+ * overhead catenary wires or a third rail.
+ */
+"ELECTRIC_LOCOMOTIVE"
+/**
+ * A manufacturer entry for form dropdowns.
+ */
+export type ManufacturerOption = { 
+/**
+ * The manufacturer identifier.
+ */
+id: string; 
+/**
+ * The manufacturer name (e.g. ACME, Roco).
+ */
+name: string }
+/**
+ * How many of the items bought during the year came from a manufacturer.
+ */
+export type ManufacturerPurchases = { 
+manufacturer_id: string; 
+/**
+ * The name of the manufacturer.
+ */
+name: string; 
+/**
+ * How many items from the manufacturer were bought during the year.
+ */
+items_bought: number }
+export type MeasureUnit = "Millimeters" | "Inches" | "Meters" | "Miles" | "Kilometers"
+/**
+ * The state of the embedded migrations in a database.
+ */
+export type MigrationStatus = { 
+/**
+ * Versions successfully applied, in ascending order.
+ */
+applied: bigint[]; 
+/**
+ * Embedded versions not yet applied, in ascending order.
+ */
+pending: bigint[]; 
+/**
+ * The version of a migration that started but did not complete, if any.
+ */
+dirty: bigint | null }
+/**
+ * What merging a duplicate railway model into the one kept changed.
+ */
+export type ModelMerge = { 
+/**
+ * The model that was kept.
+ */
+kept_id: string; 
+/**
+ * The duplicate, which no longer exists.
+ */
+removed_id: string; 
+/**
+ * How many collection items now reference the kept model instead.
+ */
+collection_items_moved: number; 
+/**
+ * How many rolling stocks were moved to the kept model.
+ */
+rolling_stocks_moved: number }
+/**
+ * A signed correction of what was paid for a collection item.
+ * 
+ * Adjustments are recorded against a purchase info instead of editing its
+ * price, so the history is kept: a partial refund from the shop is a
+ * negative adjustment, a fee paid afterwards (for example customs) a
+ * positive one. They are netted out of the purchase cost by
+ * `PurchaseInfo::acquisition_cost` and of the collection total value.
+ */
+export type MonetaryAdjustment = { 
+/**
+ * Unique identifier for this adjustment.
+ */
+id: string; 
+/**
+ * The signed amount in the smallest currency unit; negative for refunds.
+ */
+amount: bigint; 
+/**
+ * Currency of the amount; the same as the adjusted purchase price.
+ */
+currency: Currency; 
+/**
+ * Date of the adjustment (ISO `YYYY-MM-DD`).
+ */
+date: string; 
+/**
+ * Why the cost was adjusted, for example "partial refund, damaged box".
+ */
+reason: string }
+/**
+ * A monetary amount in the smallest currency unit together with its currency.
+ * 
+ * `MonetaryAmount` stores the raw integer amount (e.g. cents) in `amount` and
+ * the `currency` that the amount is denominated in. Prefer using the provided
+ * constructors rather than populating fields directly.
+ * 
+ * # Examples
+ * 
+ * Basic construction and display:
+ * 
+ * ```rust
+ * # use rusty_shed_lib::core::domain::{Currency, MonetaryAmount};
+ * let m = MonetaryAmount::new(1050, Currency::EUR);
+ * assert_eq!(m.to_string(), "10.50 €");
+ * ```
+ * 
+ * Constructing from DB parts (nullable currency):
+ * 
+ * ```rust
+ * # use rusty_shed_lib::core::domain::{Currency, MonetaryAmount};
+ * let m = MonetaryAmount::from_db(1234, Some("USD")).unwrap();
+ * assert_eq!(m.unwrap().currency, Currency::USD);
+ * let none = MonetaryAmount::from_db(0, None).unwrap();
+ * assert!(none.is_none());
+ * ```
+ */
+export type MonetaryAmount = { 
+/**
+ * Amount stored in the smallest unit (e.g. cents for EUR/USD/GBP).
+ */
+amount: bigint; 
+/**
+ * Currency of the amount.
+ */
+currency: Currency }
+/**
+ * A purchase entered by the collector, before it is stored.
+ * 
+ * The currency may be left out, in which case the purchase is recorded in
+ * the preferred currency of the settings (see `AppSettings`).
+ */
+export type NewPurchase = { 
+/**
+ * Date when the item was purchased (ISO `YYYY-MM-DD`).
+ */
+purchase_date: string; 
+/**
+ * The price paid in the smallest unit of the currency, if known.
+ */
+price: bigint | null; 
+/**
+ * The currency of the price, or `None` for the preferred currency.
+ */
+currency: Currency | null; 
+/**
+ * Optional seller identifier or human-friendly name.
+ */
+seller: string | null }
+/**
+ * Rows of `table` whose `column` points at a missing row of `parent_table`.
+ */
+export type OrphanedRows = { 
+/**
+ * The table holding the orphaned rows.
+ */
+table: string; 
+/**
+ * The column holding the dangling reference.
+ */
+column: string; 
+/**
+ * The table the reference should point to.
+ */
+parent_table: string; 
+/**
+ * The number of orphaned rows.
+ */
+count: number; 
+/**
+ * The ids of the orphaned rows.
+ */
+ids: string[] }
+/**
+ * A lightweight view of rolling stock that references catalog model data.
+ * 
+ * This struct intentionally contains only the minimal information needed by
+ * the collecting domain to reference a catalog `RollingStock` and basic
+ * provenance. Detailed model information lives in the catalog domain and
+ * should not be duplicated here. Fields like railway and epoch are no longer
+ * stored on the owned_rolling_stocks table and should be obtained from the
+ * catalog when needed.
+ */
+export type OwnedRollingStock = { 
+/**
+ * Unique identifier for this owned rolling stock record (e.g. UUID in the DB).
+ */
+id: string; 
+/**
+ * Identifier of the related rolling stock in the catalog, or `None` when
+ * the owned instance is not linked to a catalog rolling stock (or the
+ * linked one no longer exists).
+ */
+rolling_stock_id: string | null; 
+/**
+ * Free-form notes associated with this owned instance.
+ * Use this for short owner notes or a brief textual label.
+ */
+notes: string; 
+/**
+ * The DCC decoder installed in this rolling stock, if any.
+ */
+decoder: Decoder | null; 
+/**
+ * The storage location of this rolling stock when it is kept apart from
+ * the rest of its item, for example a car of a split set; `None` when it
+ * is kept with the item.
+ */
+location_id: string | null; 
+/**
+ * Whether any modification (weathering, lighting, ...) was recorded for
+ * this rolling stock.
+ */
+is_modified: boolean }
+/**
+ * One page of a listing.
+ */
+export type Page<T> = { 
+/**
+ * The rows of this page, in listing order.
+ */
+items: T[]; 
+/**
+ * The cursor to pass to get the next page, or `None` on the last page.
+ */
+next_cursor: string | null }
+/**
+ * The types for passenger car rolling stocks
+ */
+export type PassengerCarType = 
+/**
+ * A car usually placed between the locomotive and the rest of the train,
+ * featuring a wide-open interior for carrying checked baggage.
+ */
+"BAGGAGE_CAR" | 
+/**
+ * A car providing limited food service (snacks/drinks) and a counter,
+ * smaller in scale than a full Dining Car.
+ */
+"BUFFET_CAR" | 
+/**
+ * A hybrid car containing separate sections for both passengers and
+ * freight or baggage.
+ */
+"COMBINE_CAR" | 
+/**
+ * A coach featuring a side corridor connecting individual private
+ * compartments, each with face-to-face seating rows.
+ */
+"COMPARTMENT_COACH" | 
+/**
+ * A car dedicated to full-service meal preparation and seating for passengers.
+ */
+"DINING_CAR" | 
+/**
+ * A car with two levels of passenger seating to increase capacity
+ * without increasing train length.
+ */
+"DOUBLE_DECKER" | 
+/**
+ * A car with a glass-roofed section raised above the normal roofline,
+ * allowing 360-degree views of the scenery.
+ */
+"DOME_CAR" | 
+/**
+ * A control car equipped with a driver's cab, allowing the locomotive
+ * to be operated from the opposite end in a push-pull configuration.
+ */
+"DRIVING_TRAILER" | 
+/**
+ * A car featuring a bar and informal public seating, often used
+ * as a social space.
+ */
+"LOUNGE" | 
+/**
+ * The final car of a train, often featuring large windows or an open
+ * rear platform for scenic viewing.
+ */
+"OBSERVATION" | 
+/**
+ * A coach with a central aisle and rows of seats similar to an
+ * airliner's cabin layout.
+ */
+"OPEN_COACH" | 
+/**
+ * A specialized car used for sorting mail while in transit to
+ * expedite delivery.
+ */
+"RAILWAY_POST_OFFICE" | 
+/**
+ * A car with berths or private rooms for overnight travel.
+ * Also known as a "Sleeper" or "Pullman car."
+ */
+"SLEEPING_CAR" | 
+/**
+ * A "couchette" or "Sleeperette" car, providing reclining seats or
+ * basic fold-down bunks for a more economical overnight option.
+ */
+"SLEEPERETTE"
+/**
+ * The period a livery was applied in; either end may be unknown.
+ */
+export type PeriodOfUse = { 
+/**
+ * the date when the livery was first applied
+ */
+since: string | null; 
+/**
+ * the date when the livery was last applied, if not in use anymore
+ */
+until: string | null }
+/**
+ * A snapshot of the connection pool.
+ */
+export type PoolMetrics = { 
+/**
+ * The configured maximum number of connections.
+ */
+max_connections: number; 
+/**
+ * The number of open connections, idle or in use.
+ */
+size: number; 
+/**
+ * The number of open connections not in use.
+ */
+idle: number; 
+/**
+ * How many acquire waits were sampled.
+ */
+acquire_samples: number; 
+/**
+ * The most recent acquire wait, in milliseconds.
+ */
+last_acquire_wait_ms: number; 
+/**
+ * The longest acquire wait sampled, in milliseconds.
+ */
+max_acquire_wait_ms: number; 
+/**
+ * The mean acquire wait, in milliseconds.
+ */
+mean_acquire_wait_ms: number }
+/**
+ * Power method used by rolling stocks.
+ * 
+ * This enum represents how a model locomotive obtains electrical power.
+ * The `Display` implementation returns a human-friendly name for each variant.
+ */
+export type PowerMethod = 
+/**
+ * Alternating current (AC) power collection.
+ */
+"A_C" | 
+/**
+ * Direct current (DC) power collection.
+ */
+"D_C" | 
+/**
+ * Trix Express three-rail power pickup system.
+ */
+"TRIX_EXPRESS"
+/**
+ * Details for a pre-order entry.
+ * 
+ * Preorders record at least the deposit paid and the total price expected
+ * for the item. Both monetary amounts must use the same currency and the
+ * deposit cannot exceed the total price (see `validate`); deserializing a
+ * preorder that breaks either rule fails.
+ */
+export type PreOrderInfo = { 
+/**
+ * Unique identifier for this preorder record.
+ */
+id: string; 
+/**
+ * Date when the preorder was placed (ISO `YYYY-MM-DD`).
+ */
+order_date: string; 
+/**
+ * Amount paid as deposit (in smallest unit + currency).
+ */
+deposit: MonetaryAmount; 
+/**
+ * Total price for the pre-ordered item (in smallest unit + currency).
+ */
+total_price: MonetaryAmount; 
+/**
+ * Optional seller identifier or shop name.
+ */
+seller: string | null; 
+/**
+ * Optional expected delivery date (ETA) for the preorder.
+ */
+expected_date: string | null }
+/**
+ * What the catalog and the collection know about a product code.
+ */
+export type ProductCodeLookup = { 
+/**
+ * The manufacturer, as it was looked up.
+ */
+manufacturer: string; 
+/**
+ * The product code, as it was looked up.
+ */
+product_code: string; 
+/**
+ * The railway model with this product code, if the catalog has it.
+ */
+railway_model_id: string | null; 
+/**
+ * Whether a collection item already refers to the railway model.
+ */
+owned: boolean }
+/**
+ * A product code to look up.
+ */
+export type ProductCodeQuery = { 
+/**
+ * The name of the manufacturer, as stored in the catalog.
+ */
+manufacturer: string; 
+/**
+ * The manufacturer's product code.
+ */
+product_code: string }
+/**
+ * The outcome of checking a product code before creating a railway model.
+ * 
+ * Used by the "add model" form to give inline feedback; the checks use the
+ * same normalization as `ProductCode::try_from`, so a code reported as
+ * `Available` is accepted on creation.
+ */
+export type ProductCodeValidation = 
+/**
+ * No model from the manufacturer uses this product code.
+ */
+"Available" | 
+/**
+ * A model from the same manufacturer already uses this product code
+ * (compared case-insensitively); carries its id and description.
+ */
+{ Duplicate: { existing_model_id: string; description: string } } | 
+/**
+ * The product code is not well-formed; `reason` explains why.
+ */
+{ InvalidFormat: { reason: string } }
+/**
+ * Purchase information associated with a `CollectionItem`.
+ * 
+ * A collection item may have acquisition-related metadata that describes how
+ * the user obtained (or intends to obtain) the item. This enum models the
+ * three supported acquisition states:
+ * 
+ * - `Purchased`: the item was purchased and its value is counted in the
+ * collection total.
+ * - `Sold`: the item was sold; we keep a record of the original purchase
+ * and the sale, but the item is excluded from the collection's monetary
+ * total value and counts in its realized gains instead.
+ * - `PreOrdered`: the item is pre-ordered from a seller; the collector may
+ * have paid a deposit and the full total price is known as well.
+ * 
+ * `CollectionValuePolicy` is the single place implementing these rules.
+ */
+export type PurchaseInfo = 
+/**
+ * A standard purchase record.
+ */
+{ kind: "purchased"; data: PurchasedInfo } | 
+/**
+ * A sold item record that preserves original purchase data and the
+ * subsequent sale information.
+ */
+{ kind: "sold"; data: SoldInfo } | 
+/**
+ * A preorder record with deposit and total price information.
+ */
+{ kind: "preordered"; data: PreOrderInfo }
+/**
+ * Details for a purchased item.
+ * 
+ * This struct holds the canonical purchase identifier and optional price
+ * information. `price` is optional to support legacy records where the
+ * monetary amount or currency was not stored.
+ */
+export type PurchasedInfo = { 
+/**
+ * Unique identifier for this purchase record (for example a UUID).
+ */
+id: string; 
+/**
+ * Date when the item was purchased (ISO `YYYY-MM-DD`).
+ */
+purchase_date: string; 
+/**
+ * The price paid when purchasing the item, if known.
+ * 
+ * Represented as a domain `MonetaryAmount` (amount in smallest unit + currency).
+ */
+price: MonetaryAmount | null; 
+/**
+ * Optional seller identifier or human-friendly name.
+ */
+seller: string | null }
+/**
+ * A collection item typed in the command palette, to confirm before it is
+ * added.
+ */
+export type QuickAddDraft = { 
+/**
+ * The start of the manufacturer name, for example "acme", when typed.
+ */
+manufacturer_hint: string | null; 
+/**
+ * The product code of the model.
+ */
+product_code: string; 
+/**
+ * How many were bought; 1 when not typed.
+ */
+quantity: number; 
+/**
+ * The price paid, when typed.
+ */
+price: MonetaryAmount | null; 
+/**
+ * The purchase date, when typed.
+ */
+purchase_date: string | null }
+/**
+ * The catalog models a quick-add draft may refer to.
+ */
+export type QuickAddMatch = 
+/**
+ * A single model has the product code (from the hinted manufacturer).
+ */
+{ type: "resolved"; model: RailwayModelSummary } | 
+/**
+ * Several models have the product code, or none has it exactly but some
+ * come close; the collector picks one.
+ */
+{ type: "ambiguous"; candidates: RailwayModelSummary[] } | 
+/**
+ * No model comes close.
+ */
+{ type: "not_found" }
+/**
+ * A parsed quick-add entry together with the models it may refer to.
+ */
+export type QuickAddProposal = { draft: QuickAddDraft; model: QuickAddMatch }
+/**
+ * The types for railcar rolling stocks
+ * 
+ * A railcar is a self-propelled railway vehicle designed to transport passengers.
+ * The term _"railcar"_ is usually used in reference to a train consisting of a single coach
+ * (carriage, car), with a driver's cab at one or both ends.
+ * 
+ * In its simplest form, a "railcar" may also be little more than a motorized railway handcar
+ * or draisine.
+ */
+export type RailcarType = 
+/**
+ * A self-propelled passenger vehicles also capable of hauling a train.
+ */
+"POWER_CAR" | 
+/**
+ * Trailer cars are any cars (sometimes semi-permanently coupled) that carry little or no
+ * traction or power related equipment, and are similar to passenger cars in a
+ * locomotive-hauled train.
+ */
+"TRAILER_CAR"
+/**
+ * A railway company entry for form dropdowns.
+ */
+export type RailwayCompanyOption = { 
+/**
+ * The railway company identifier.
+ */
+id: string; 
+/**
+ * The common (usually abbreviated) name, for example `"FS"`.
+ */
+name: string; 
+/**
+ * The legally registered company name, when known.
+ */
+registered_company_name: string | null; 
+/**
+ * Whether the company is still active, when known.
+ */
+status: RailwayStatus | null }
+/**
+ * A railway model as shown in the catalog listing: its summary, with how
+ * many rolling stocks it has and the range of their lengths over buffers.
+ */
+export type RailwayModelListEntry = { 
+/**
+ * The railway model summary.
+ */
+summary: RailwayModelSummary; 
+/**
+ * The number of rolling stocks of the model.
+ */
+rolling_stocks_count: number; 
+/**
+ * The shortest length over buffers of its rolling stocks, in
+ * millimeters, when at least one is known.
+ */
+min_length_over_buffers_mm: number | null; 
+/**
+ * The longest length over buffers of its rolling stocks, in
+ * millimeters, when at least one is known.
+ */
+max_length_over_buffers_mm: number | null }
+/**
+ * A lightweight projection of a catalog `RailwayModel`.
+ * 
+ * Summaries carry just enough information to render a model in pickers
+ * and search results (for example when adding a new collection item)
+ * without loading the full aggregate and its rolling stocks.
+ */
+export type RailwayModelSummary = { 
+/**
+ * Unique identifier for the railway model.
+ */
+id: string; 
+/**
+ * The manufacturer name (e.g. ACME, Roco).
+ */
+manufacturer: string; 
+/**
+ * Manufacturer-assigned product code.
+ */
+product_code: string; 
+/**
+ * Human-readable description of the model.
+ */
+description: string; 
+/**
+ * The scale of the model (e.g. H0, N).
+ */
+scale: Scale }
+export type RailwayStatus = "ACTIVE" | "INACTIVE"
+/**
+ * The sale prices of sold items minus what they cost, in a single currency.
+ */
+export type RealizedGain = { 
+/**
+ * The currency of the amount.
  */
-export type Collection = { 
+currency: Currency; 
 /**
- * Unique identifier for the collection (typically a UUID stored as a string).
+ * The gain in the smallest currency unit; negative for a loss.
  */
-id: string; 
+amount: bigint }
 /**
- * Display name for this collection.
+ * The option lists needed to populate the catalog forms.
+ * 
+ * Everything an "add model" form needs for its select inputs is returned in
+ * a single payload, so the frontend can fill every dropdown with one call.
+ * Every enum option comes with its label and description, in declaration
+ * order.
  */
-name: string; 
+export type ReferenceData = { 
 /**
- * Precomputed summary counts (e.g. total items, tracked vs untracked).
+ * Manufacturers, ordered by name.
  */
-summary: CollectionSummary; 
+manufacturers: ManufacturerOption[]; 
 /**
- * Optional total monetary value of the collection. Use `MonetaryAmount`
- * to preserve currency and decimal precision.
+ * Railway companies, ordered by name.
  */
-total_value: MonetaryAmount | null; 
+railway_companies: RailwayCompanyOption[]; 
 /**
- * The list of items contained in this collection.
+ * The available model scales.
  */
-items: CollectionItem[] }
+scales: EnumInfo<Scale>[]; 
 /**
- * A single item within a user's collection.
- * 
- * A `CollectionItem` represents a reference to a catalog `RailwayModel` along
- * with ownership-specific data such as the rolling stock instances owned by
- * the collector and purchase information. It is intentionally a lightweight
- * entity that is meaningful only inside the context of its parent
- * `Collection` (the aggregate root).
+ * The railway model categories.
  */
-export type CollectionItem = { 
+categories: EnumInfo<Category>[]; 
 /**
- * Unique identifier for this collection item (e.g. UUID).
+ * The rolling stock categories.
  */
-id: string; 
+rolling_stock_categories: EnumInfo<RollingStockCategory>[]; 
 /**
- * Link to the corresponding catalog `RailwayModel` this item represents.
- * 
- * This is a reference to the canonical model in the catalog; use this
- * to look up full catalog details (manufacturer, product codes, etc.).
+ * The locomotive types.
  */
-railway_model_id: string; 
+locomotive_types: EnumInfo<LocomotiveType>[]; 
 /**
- * Condition of the item as recorded by the owner (e.g. "mint", "used").
+ * The freight car types.
  */
-conditions: string | null; 
+freight_car_types: EnumInfo<FreightCarType>[]; 
 /**
- * Free-form notes provided by the owner for this collection item.
+ * The passenger car types.
  */
-notes: string | null; 
+passenger_car_types: EnumInfo<PassengerCarType>[]; 
 /**
- * The specific rolling stock instances owned that correspond to this model.
+ * The electric multiple unit car types.
  */
-rolling_stocks: OwnedRollingStock[]; 
+electric_multiple_unit_types: EnumInfo<ElectricMultipleUnitType>[]; 
 /**
- * Optional purchase information associated with this collection item.
+ * The railcar types.
  */
-purchase_info: PurchaseInfo | null }
+railcar_types: EnumInfo<RailcarType>[]; 
 /**
- * Summary counters for a `Collection` domain object.
+ * The power methods.
  */
-export type CollectionSummary = { 
+power_methods: EnumInfo<PowerMethod>[]; 
 /**
- * Number of locomotives in the collection.
+ * The availability statuses of railway models.
  */
-locomotives_count: number; 
+availability_statuses: EnumInfo<AvailabilityStatus>[]; 
 /**
- * Number of passenger cars in the collection.
+ * The body shell constructions.
  */
-passenger_cars_count: number; 
+body_shell_types: EnumInfo<BodyShellType>[]; 
 /**
- * Number of freight cars in the collection.
+ * The chassis constructions.
  */
-freight_cars_count: number; 
+chassis_types: EnumInfo<ChassisType>[]; 
 /**
- * Number of train sets in the collection.
+ * The DCC support levels.
  */
-train_sets_count: number; 
+controls: EnumInfo<Control>[]; 
 /**
- * Number of railcars in the collection.
+ * The DCC decoder interfaces.
  */
-railcars_count: number; 
+dcc_interfaces: EnumInfo<DccInterface>[]; 
 /**
- * Number of electric multiple units (EMUs) in the collection.
+ * The coupling sockets.
  */
-electric_multiple_units_count: number }
+coupling_sockets: EnumInfo<CouplingSocket>[]; 
 /**
- * Application-level error returned by command handlers in the core infrastructure.
- * 
- * Variants are simple wrappers around strings to keep boundaries between
- * infrastructure and domain code straightforward. Prefer constructing
- * `CommandError::DatabaseError` when an underlying DB call fails, and
- * `CommandError::Unknown` for unexpected failures.
+ * The feature flag values.
  */
-export type CommandError = 
+feature_flags: EnumInfo<FeatureFlag>[]; 
 /**
- * Represents an error coming from the database layer.
- * 
- * The inner `String` should contain a concise, non-sensitive description
- * of the underlying database failure.
+ * The passenger service levels.
  */
-{ DatabaseError: string } | 
+service_levels: EnumInfo<ServiceLevel>[]; 
 /**
- * A catch-all for unexpected errors that don't map to a specific variant.
- * 
- * The inner `String` can include a short debug message suitable for
- * logging; avoid placing secrets here.
+ * The track gauges.
  */
-{ Unknown: string }
+track_gauges: EnumInfo<TrackGauge>[]; 
 /**
- * Currency codes supported by the application.
- * 
- * The enum uses a small, explicit set of currencies for now. Use
- * `Currency::from_code` to obtain a `Currency` value from an ISO-style
- * currency code (case-insensitive).
+ * The railway company statuses.
  */
-export type Currency = 
+railway_statuses: EnumInfo<RailwayStatus>[] }
 /**
- * Euro
+ * What repairs an inconsistency.
  */
-"EUR" | 
+export type RepairAction = 
 /**
- * United States Dollar
+ * Clear the reference of the owned rolling stock, keeping the rest of
+ * it.
  */
-"USD" | 
+"CLEAR_ROLLING_STOCK_REFERENCE" | 
 /**
- * Great Britain Pound
+ * Recompute the summary counters of the collection from its items.
  */
-"GBP" | 
+"RECOMPUTE_SUMMARY_COUNTERS" | 
 /**
- * Japanese Yen
+ * Edit the purchase to pick a supported currency; the amounts cannot be
+ * converted without knowing what was meant.
  */
-"JPY"
+"CHOOSE_SUPPORTED_CURRENCY"
 /**
- * A monetary amount in the smallest currency unit together with its currency.
- * 
- * `MonetaryAmount` stores the raw integer amount (e.g. cents) in `amount` and
- * the `currency` that the amount is denominated in. Prefer using the provided
- * constructors rather than populating fields directly.
- * 
- * # Examples
- * 
- * Basic construction and display:
- * 
- * ```rust
- * # use rusty_shed_lib::core::domain::{Currency, MonetaryAmount};
- * let m = MonetaryAmount::new(1050, Currency::EUR);
- * assert_eq!(m.to_string(), "10.50 €");
- * ```
- * 
- * Constructing from DB parts (nullable currency):
+ * High-level classification for different types of railway rolling stock.
  * 
- * ```rust
- * # use rusty_shed_lib::core::domain::{Currency, MonetaryAmount};
- * let m = MonetaryAmount::from_db(1234, Some("USD")).unwrap();
- * assert_eq!(m.unwrap().currency, Currency::USD);
- * let none = MonetaryAmount::from_db(0, None).unwrap();
- * assert!(none.is_none());
- * ```
+ * This categorization distinguishes between traction units, hauled vehicles,
+ * and self-propelled passenger units.
  */
-export type MonetaryAmount = { 
+export type RollingStockCategory = 
 /**
- * Amount stored in the smallest unit (e.g. cents for EUR/USD/GBP).
+ * Independent traction units used to haul unpowered vehicles.
+ * Includes steam, diesel, and electric motive power.
  */
-amount: bigint; 
+"LOCOMOTIVE" | 
 /**
- * Currency of the amount.
+ * Vehicles designed specifically for the transport of goods,
+ * raw materials, or heavy equipment.
  */
-currency: Currency }
+"FREIGHT_CAR" | 
 /**
- * A lightweight view of rolling stock that references catalog model data.
- * 
- * This struct intentionally contains only the minimal information needed by
- * the collecting domain to reference a catalog `RollingStock` and basic
- * provenance. Detailed model information lives in the catalog domain and
- * should not be duplicated here. Fields like railway and epoch are no longer
- * stored on the owned_rolling_stocks table and should be obtained from the
- * catalog when needed.
+ * Vehicles designed for passenger transport, usually featuring
+ * interior seating and climate control.
  */
-export type OwnedRollingStock = { 
+"PASSENGER_CAR" | 
 /**
- * Unique identifier for this owned rolling stock record (e.g. UUID in the DB).
+ * Self-propelled, multi-unit passenger trains that use
+ * electricity as their motive power (EMUs).
  */
-id: string; 
+"ELECTRIC_MULTIPLE_UNIT" | 
 /**
- * Identifier of the related rolling stock in the catalog (or the owned rolling stock id when catalog id is not available).
+ * Lightweight, self-propelled single vehicles designed for
+ * lower-capacity passenger service on branch lines.
  */
-rolling_stock_id: string; 
+"RAILCAR"
 /**
- * Free-form notes associated with this owned instance.
- * Use this for short owner notes or a brief textual label.
+ * A decoder as stored, with the warnings about its details.
  */
-notes: string }
+export type SavedDecoder = { 
 /**
- * Details for a pre-order entry.
+ * The stored decoder.
+ */
+decoder: Decoder; 
+/**
+ * What is wrong with the decoder without preventing it from being
+ * stored; empty when nothing.
+ */
+warnings: DecoderWarning[] }
+/**
+ * Model railway scales supported by the application.
  * 
- * Preorders record at least the deposit paid and the total price expected
- * for the item. Both monetary amounts must use the same currency; use the
- * `validate_currencies_match` helper to assert that condition.
+ * Each variant corresponds to a commonly used hobbyist scale name (for example
+ * `H0` or `00`). Use `Scale::ratio()` to obtain the numeric ratio that follows
+ * the `1:` notation (e.g. `Scale::H0` -> `1:87`). The `Display` implementation
+ * produces a human-friendly string such as `H0 (1:87)`.
  */
-export type PreOrderInfo = { 
+export type Scale = 
 /**
- * Unique identifier for this preorder record.
+ * H0 scale (1:87)
  */
-id: string; 
+"H0" | 
 /**
- * Date when the preorder was placed (ISO `YYYY-MM-DD`).
+ * H0 narrow/metric (1:87)
  */
-order_date: string; 
+"H0m" | 
 /**
- * Amount paid as deposit (in smallest unit + currency).
+ * H0e (1:87)
  */
-deposit: MonetaryAmount; 
+"H0e" | 
 /**
- * Total price for the pre-ordered item (in smallest unit + currency).
+ * N scale (1:160)
  */
-total_price: MonetaryAmount; 
+"N" | 
 /**
- * Optional seller identifier or shop name.
+ * TT scale (1:120)
  */
-seller: string | null; 
+"TT" | 
 /**
- * Optional expected delivery date (ETA) for the preorder.
+ * Z scale (1:220)
  */
-expected_date: string | null }
+"Z" | 
 /**
- * Purchase information associated with a `CollectionItem`.
- * 
- * A collection item may have acquisition-related metadata that describes how
- * the user obtained (or intends to obtain) the item. This enum models the
- * three supported acquisition states:
- * 
- * - `Purchased`: the item was purchased and its value is counted in the
- * collection total (unless explicitly excluded elsewhere).
- * - `Sold`: the item was sold; we keep a record of the original purchase
- * and the sale, but the item is excluded from the collection's monetary
- * total value.
- * - `PreOrdered`: the item is pre-ordered from a seller; the collector may
- * have paid a deposit and the full total price is known as well.
+ * G scale (garden) (1:22.5)
  */
-export type PurchaseInfo = 
+"G" | 
 /**
- * A standard purchase record.
+ * 1 scale (1:32)
  */
-{ kind: "purchased"; data: PurchasedInfo } | 
+"Scale1" | 
 /**
- * A sold item record that preserves original purchase data and the
- * subsequent sale information.
+ * 0 scale (1:43.5)
  */
-{ kind: "sold"; data: SoldInfo } | 
+"Scale0" | 
 /**
- * A preorder record with deposit and total price information.
+ * 00 (double-zero) scale (1:76.2)
  */
-{ kind: "preordered"; data: PreOrderInfo }
+"Scale00"
 /**
- * Details for a purchased item.
- * 
- * This struct holds the canonical purchase identifier and optional price
- * information. `price` is optional to support legacy records where the
- * monetary amount or currency was not stored.
+ * The speed of a model timed over a stretch of track, with the speed of
+ * the prototype it stands for.
  */
-export type PurchasedInfo = { 
+export type ScaleSpeed = { 
 /**
- * Unique identifier for this purchase record (for example a UUID).
+ * The speed of the model, in km/h.
  */
-id: string; 
+model_speed_kmh: number; 
 /**
- * Date when the item was purchased (ISO `YYYY-MM-DD`).
+ * The speed of the prototype at the scale of the model, in km/h.
  */
-purchase_date: string; 
+prototype_speed_kmh: number; 
 /**
- * The price paid when purchasing the item, if known.
- * 
- * Represented as a domain `MonetaryAmount` (amount in smallest unit + currency).
+ * How many seconds a model of the same scale takes over 1 m of track
+ * when running at 100 scale km/h, to time the runs against.
  */
-price: MonetaryAmount | null; 
+reference_seconds_per_meter: number }
 /**
- * Optional seller identifier or human-friendly name.
+ * Represents the service class(es) for a rolling stock or service.
+ * 
+ * | **Variant**                      | **Description**           |
+ * |:---------------------------------|:--------------------------|
+ * | `ServiceLevel::First`            | `1st class`               |
+ * | `ServiceLevel::Second`           | `2nd class`               |
+ * | `ServiceLevel::Third`            | `3rd class`               |
+ * | `ServiceLevel::FirstSecond`      | `Mixed 1st/2nd class`     |
+ * | `ServiceLevel::SecondThird`      | `Mixed 2nd/3rd class`     |
+ * | `ServiceLevel::FirstSecondThird` | `Mixed 1st/2nd/3rd class` |
+ * 
+ * Parsing: `TryFrom<&str>` is implemented and accepts the string forms above
+ * (whitespace is trimmed). Formatting: `Display` is implemented and produces
+ * the corresponding string representation.
  */
-seller: string | null }
+export type ServiceLevel = "FIRST" | "SECOND" | "THIRD" | "FIRST_SECOND" | "SECOND_THIRD" | "FIRST_SECOND_THIRD"
 /**
  * Details for an item that was sold.
  * 
  * We keep both the original purchase information and the sale data so the
  * application can show provenance (what was paid originally) together with
- * the sale outcome. The item should be excluded from collection value
- * aggregations once sold.
+ * the sale outcome. Once sold, the item is excluded from collection value
+ * aggregations (see `CollectionValuePolicy`).
  */
 export type SoldInfo = { 
 /**
@@ -378,6 +4119,206 @@ buyer: string | null;
  * originally sold the item or the intermediary that handled the sale).
  */
 seller: string | null }
+/**
+ * A catalog value at odds with the standards of its scale.
+ */
+export type StandardsWarning = 
+/**
+ * The rolling stock declares a minimum radius tighter than the minimum
+ * of its scale.
+ */
+{ type: "radius_below_minimum"; rolling_stock_id: string; declared_radius_mm: number; minimum_radius_mm: number }
+/**
+ * Emitted after a collection's summary counters and total value have been
+ * recomputed, carrying what changed.
+ */
+export type SummaryRecomputed = CollectionRecomputeDiff
+/**
+ * The total value of a collection in a single currency, before and after a
+ * recomputation.
+ * 
+ * Amounts are in the smallest currency unit, as in `MonetaryAmount`.
+ */
+export type TotalValueDiff = { 
+/**
+ * The currency of both amounts.
+ */
+currency: Currency; 
+/**
+ * The amount before the recomputation.
+ */
+old_amount: bigint; 
+/**
+ * The amount after the recomputation.
+ */
+new_amount: bigint }
+/**
+ * In rail transport, track gauge is the distance between the two rails of a railway track.
+ * All vehicles on a rail network must have wheel sets that are compatible with the track gauge.
+ * 
+ * Since many different track gauges exist worldwide, gauge differences often present a barrier to wider operation on
+ * railway networks.
+ */
+export type TrackGauge = 
+/**
+ * In modern usage, the term "broad gauge" generally refers to track spaced significantly wider than
+ * 1,435 mm (4 ft 8+1⁄2 inches).
+ * 
+ * Broad gauge is the dominant gauge in countries in Indian subcontinent, the former Soviet Union (CIS states,
+ * Baltic states, Georgia and Ukraine), Mongolia and Finland, Spain, Portugal, Argentina, Chile and Ireland.
+ * It is also use for the suburban railway systems in South Australia, and Victoria, Australia.
+ */
+"BROAD" | 
+/**
+ * The term "medium gauge" had different meanings throughout history, depending on the local dominant gauge in use.
+ */
+"MEDIUM" | 
+/**
+ * Very narrow gauges of under 2 feet (610 mm) were used for some industrial railways in space-restricted
+ * environments such as mines or farms. The French company Decauville developed 500 mm (19+3⁄4 in) and
+ * 400 mm (15+3⁄4 in) tracks, mainly for mines; Heywood developed 15 in (381 mm) gauge for estate railways.
+ * The most common minimum-gauges were 15 in (381 mm), 400 mm (15+3⁄4 in), 16 in (406 mm), 18 in (457 mm),
+ * 500 mm (19+3⁄4 in) or 20 in (508 mm).
+ */
+"MINIMUM" | 
+/**
+ * In modern usage, the term "narrow gauge" generally refers to track spaced significantly narrower than 1,435 mm
+ * (4 ft 8+1⁄2 in).
+ */
+"NARROW" | 
+/**
+ * In modern usage the term "standard gauge" refers to 1,435 mm (4 ft 8+1⁄2 inches).
+ * Standard gauge is dominant in a majority of countries, including those in North America, most of western Europe,
+ * North Africa and the Middle East, and in China.
+ */
+"STANDARD"
+/**
+ * The outcome of checking a road number; the road number is saved either
+ * way.
+ */
+export type ValidationOutcome = 
+/**
+ * The road number follows a known convention, or none is known.
+ */
+{ type: "ok" } | 
+/**
+ * The road number is likely mistyped; `suggestion` is the corrected
+ * road number, when one can be worked out.
+ */
+{ type: "warning"; reason: string; suggestion: string | null }
+/**
+ * A catalog value unlikely to be right; the value is saved all the same.
+ */
+export type ValidationWarning = 
+/**
+ * The railway company of the rolling stock was not operating during the
+ * epoch of its model.
+ */
+{ type: "epoch_outside_period_of_activity"; rolling_stock_id: string; railway_company_id: string; epoch: string; epoch_years: YearRange; operating_since: string | null; operating_until: string | null } | 
+/**
+ * The road number of the rolling stock is likely mistyped (see
+ * `validate_road_number`).
+ */
+{ type: "suspicious_road_number"; rolling_stock_id: string; road_number: string; reason: string; suggestion: string | null }
+/**
+ * How an insurance report values the items of a collection.
+ */
+export type ValuationMode = 
+/**
+ * Each item at the price it was bought for.
+ */
+"purchase_price" | 
+/**
+ * Each item at what replacing it would cost, as entered by the
+ * collector; items without a replacement value fall back to their
+ * purchase price.
+ */
+"replacement_value"
+/**
+ * The value of a collection as it was on a given day.
+ * 
+ * Snapshots are taken at most once per day: taking one again on the same
+ * day replaces it. The values follow `CollectionValuePolicy`, with one entry
+ * per currency ordered by currency code; a collection with no priced items
+ * has a single zero value in its stored total value currency.
+ */
+export type ValueSnapshot = { 
+/**
+ * The collection this snapshot is about.
+ */
+collection_id: string; 
+/**
+ * The day the snapshot was taken.
+ */
+date: string; 
+/**
+ * The value of the items in the collection on that day, per currency.
+ */
+values: CurrencyValue[] }
+/**
+ * An item bought during the year under review.
+ */
+export type YearPurchase = { 
+collection_item_id: string; 
+/**
+ * The name of the manufacturer of the railway model.
+ */
+manufacturer: string; 
+/**
+ * The product code of the railway model.
+ */
+product_code: string; 
+/**
+ * What the item cost, net of its monetary adjustments.
+ */
+price: MonetaryAmount }
+/**
+ * A span of years, from `since` up to `until` (excluded); open-ended when
+ * `until` is `None`.
+ */
+export type YearRange = { since: number; until: number | null }
+/**
+ * What was bought and sold in a collection during one calendar year.
+ */
+export type YearReview = { 
+/**
+ * The collection these figures are about.
+ */
+collection_id: string; 
+/**
+ * The calendar year under review.
+ */
+year: number; 
+/**
+ * How many items were bought during the year.
+ */
+items_bought: number; 
+/**
+ * What the items bought during the year cost, per currency.
+ */
+total_spent: CurrencyValue[]; 
+/**
+ * The most expensive item bought during the year, per currency.
+ */
+biggest_purchases: YearPurchase[]; 
+/**
+ * The manufacturer of most of the items bought during the year, `None`
+ * when nothing was bought. Ties go to the first manufacturer by name.
+ */
+top_manufacturer: ManufacturerPurchases | null; 
+/**
+ * How many items were sold during the year.
+ */
+items_sold: number; 
+/**
+ * What the items sold during the year were sold for, per currency.
+ */
+sale_proceeds: CurrencyValue[]; 
+/**
+ * The sale prices of the items sold during the year minus what they
+ * cost, per currency (see `CollectionValuePolicy::realized_gain`).
+ */
+net_proceeds: RealizedGain[] }
 
 /** tauri-specta globals **/
 